@@ -517,6 +517,9 @@ fn end_for(reason: DetachReason) -> AttachEnd {
         DetachReason::ClientRequest => AttachEnd::Detached,
         DetachReason::SessionEnded => AttachEnd::SessionEnded,
         DetachReason::Transport => transport_lost(),
+        DetachReason::ShareEnded => {
+            AttachEnd::Error("share link expired or was revoked".to_string())
+        }
     }
 }
 
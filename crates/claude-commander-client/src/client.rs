@@ -15,11 +15,13 @@ use std::time::Duration;
 use claude_commander_protocol::api::{
     AgentStatesSnapshot, BranchInfo, CreateOptions, CreateSessionOpts, DiffSide, NewComment,
     OperationStatus, PreviewData, ProgramInfo, ReviewSnapshot, SessionDetail, SetProgramsRequest,
-    ToggleReviewed, WorkspaceSnapshot,
+    ToggleReviewed, UndoResult, WorkspaceSnapshot,
 };
 use claude_commander_protocol::comment::{ApplyOutcome, Comment};
 use claude_commander_protocol::github::{CloneJob, CloneJobId, CloneRequest, GithubRepo};
+use claude_commander_protocol::scrollback::{ScrollbackHit, ScrollbackReveal, ScrollbackSearch};
 use claude_commander_protocol::session::{ProjectId, SessionId};
+use claude_commander_protocol::task::TaskStart;
 use claude_commander_protocol::ws::AttachKind;
 use reqwest::{Client, RequestBuilder, Response, StatusCode, Url};
 use serde::Serialize;
@@ -247,6 +249,16 @@ impl RemoteClient {
         response.text().await.map_err(error::body_error)
     }
 
+    /// [`Self::get_text`] where a 404 means "absent" rather than an error.
+    async fn get_text_opt(&self, url: Url) -> ClientResult<Option<String>> {
+        let response = self.send(self.client.get(url)).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response = self.check(response).await?;
+        response.text().await.map(Some).map_err(error::body_error)
+    }
+
     async fn get_bytes(&self, url: Url) -> ClientResult<Vec<u8>> {
         let response = self.send(self.client.get(url)).await?;
         let response = self.check(response).await?;
@@ -439,6 +451,15 @@ impl RemoteClient {
         self.get_json_opt(url).await
     }
 
+    /// The tail of a session's agent pane (`GET /api/sessions/{id}/pane?lines=`);
+    /// `None` when the session has no live pane.
+    pub async fn session_pane(&self, id: SessionId, lines: usize) -> ClientResult<Option<String>> {
+        let mut url = self.session_url(id, &["pane"]);
+        url.query_pairs_mut()
+            .append_pair("lines", &lines.to_string());
+        self.get_text_opt(url).await
+    }
+
     /// Preview payload for a session (`GET /api/sessions/{id}/preview?lines=`).
     pub async fn session_preview(
         &self,
@@ -517,6 +538,16 @@ impl RemoteClient {
         self.delete_ok(self.session_url(id, &[])).await
     }
 
+    /// Archive a session (stop it, remove its worktree, keep the branch).
+    pub async fn archive_session(&self, id: SessionId) -> ClientResult<()> {
+        self.post_empty_ok(self.session_url(id, &["archive"])).await
+    }
+
+    /// Restore an archived session and relaunch it resumed.
+    pub async fn restore_session(&self, id: SessionId) -> ClientResult<()> {
+        self.post_empty_ok(self.session_url(id, &["restore"])).await
+    }
+
     pub async fn rename_session(&self, id: SessionId, title: String) -> ClientResult<()> {
         let body = serde_json::json!({ "op": "rename", "title": title });
         self.patch_json_ok(self.session_url(id, &[]), &body).await
@@ -527,6 +558,18 @@ impl RemoteClient {
         self.patch_json_ok(self.session_url(id, &[]), &body).await
     }
 
+    /// Replace a session's tags (PATCH `set_tags` op).
+    pub async fn set_tags(&self, id: SessionId, tags: Vec<String>) -> ClientResult<()> {
+        let body = serde_json::json!({ "op": "set_tags", "tags": tags });
+        self.patch_json_ok(self.session_url(id, &[]), &body).await
+    }
+
+    /// Replace a session's notes (PATCH `set_notes` op).
+    pub async fn set_notes(&self, id: SessionId, notes: String) -> ClientResult<()> {
+        let body = serde_json::json!({ "op": "set_notes", "notes": notes });
+        self.patch_json_ok(self.session_url(id, &[]), &body).await
+    }
+
     /// Change a session's launch program (PATCH `change_program` op).
     pub async fn change_program(&self, id: SessionId, program: String) -> ClientResult<()> {
         let body = serde_json::json!({ "op": "change_program", "program": program });
@@ -566,6 +609,61 @@ impl RemoteClient {
             .await
     }
 
+    /// `POST /sessions/{id}/tasks/run` — start task `name` (every task when
+    /// `None`); returns one result per task attempted.
+    pub async fn run_tasks(
+        &self,
+        id: SessionId,
+        name: Option<String>,
+    ) -> ClientResult<Vec<TaskStart>> {
+        let body = serde_json::json!({ "name": name });
+        self.post_json(self.session_url(id, &["tasks", "run"]), &body)
+            .await
+    }
+
+    /// `POST /sessions/{id}/tasks/send-failure` — hand a failed task's output
+    /// to the agent; returns the task sent.
+    pub async fn send_task_failure(
+        &self,
+        id: SessionId,
+        name: Option<String>,
+    ) -> ClientResult<String> {
+        let body = serde_json::json!({ "name": name });
+        self.post_json(self.session_url(id, &["tasks", "send-failure"]), &body)
+            .await
+    }
+
+    /// `GET /scrollback/search` — search every session's scrollback on the
+    /// server.
+    pub async fn search_scrollback(
+        &self,
+        search: &ScrollbackSearch,
+    ) -> ClientResult<Vec<ScrollbackHit>> {
+        let mut url = self.endpoint(&["scrollback", "search"]);
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("pattern", &search.pattern);
+            if search.regex {
+                query.append_pair("regex", "true");
+            }
+            if let Some(limit) = search.limit {
+                query.append_pair("limit", &limit.to_string());
+            }
+        }
+        self.get_json(url).await
+    }
+
+    /// `POST /sessions/{id}/scrollback/reveal` — scroll the pane's copy-mode to
+    /// a search hit's line.
+    pub async fn reveal_scrollback_line(
+        &self,
+        id: SessionId,
+        reveal: &ScrollbackReveal,
+    ) -> ClientResult<()> {
+        self.post_json_ok(self.session_url(id, &["scrollback", "reveal"]), reveal)
+            .await
+    }
+
     pub async fn mark_unread(&self, ids: Vec<SessionId>) -> ClientResult<()> {
         // Batch counterpart to `mark_read`: `POST /api/sessions/unread` with
         // `{ "ids": [...] }`. Unknown ids are silently skipped server-side,
@@ -692,6 +790,13 @@ impl RemoteClient {
             .await
     }
 
+    /// Undo the server's most recent session delete, project removal or
+    /// cascade abandon, returning what was undone.
+    pub async fn undo(&self) -> ClientResult<String> {
+        let result: UndoResult = self.post_empty_json(self.endpoint(&["undo"])).await?;
+        Ok(result.summary)
+    }
+
     pub async fn push_stack(&self, id: SessionId) -> ClientResult<OperationStatus> {
         self.post_empty_json(self.session_url(id, &["push-stack"]))
             .await
@@ -198,7 +198,8 @@ const OPENCODE_FOOTER_LINES: usize = 3;
 const OPENCODE_PERMISSION_MARKER: &str = "Permission required";
 
 /// The agent CLI harness backing a session, derived from its `program` command.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum AgentKind {
     /// Anthropic Claude Code (`claude`).
     Claude,
@@ -1,6 +1,6 @@
 //! Commander API — unified service layer for CLI and TUI consumers.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
@@ -31,10 +31,14 @@ use crate::git::{
     run_clone,
 };
 use crate::reviewed::ReviewedStore;
+use crate::scrollback::{
+    INCREMENTAL_ROWS, ScrollbackHit, ScrollbackIndex, ScrollbackPattern, ScrollbackReveal,
+    ScrollbackSearch, Splice,
+};
 use crate::session::{
-    AgentState, CascadeOutcome, ProjectId, ScanResult, SessionId, SessionManager, SessionStatus,
-    WorktreeSession, apply_assignment, clear_override_and_reassign, decide_branch_reconcile,
-    program_with_agent_flags,
+    AgentState, CascadeOutcome, HookEvent, HookTransition, ProjectId, ScanResult, SectionSignals,
+    SessionId, SessionManager, SessionStatus, TaskStart, WorktreeSession, apply_assignment,
+    clear_override_and_reassign, decide_branch_reconcile, program_with_agent_flags,
 };
 use crate::telemetry::{ConfigSnapshot, EnvFingerprint, FrontendInfo, Telemetry};
 use crate::tmux::{AgentStateDetector, StatusBarInfo, TmuxExecutor};
@@ -97,6 +101,9 @@ pub struct CommanderService {
     /// [`Self::start_clone`] and polled through [`Self::clone_job`]. In-memory
     /// only — see [`CloneJobs`] for why a clone cannot outlive its process.
     clone_jobs: CloneJobs,
+    /// Every session's indexed tmux history, behind [`Self::search_scrollback`].
+    /// Kept current by the scrollback loop; persisted beside state.json.
+    scrollback: Arc<tokio::sync::Mutex<ScrollbackIndex>>,
 }
 
 /// Max entries kept in the operation ledger before the oldest are evicted.
@@ -135,6 +142,9 @@ impl CommanderService {
         let data_dir = store.data_dir();
         let comments = Arc::new(CommentStore::new(data_dir.join("comments")));
         let reviewed = Arc::new(ReviewedStore::new(data_dir.join("reviewed")));
+        let scrollback = Arc::new(tokio::sync::Mutex::new(ScrollbackIndex::new(
+            data_dir.join("scrollback"),
+        )));
         let telemetry = init_telemetry(&config_store, &store, &frontend);
         // NB: the idle-hibernation loop is NOT started here. `new` is shared by
         // one-shot CLI commands (via `for_cli`), and a tokio runtime is always
@@ -145,6 +155,9 @@ impl CommanderService {
             manager.tmux.clone(),
             AGENT_STATE_CACHE_TTL,
         )));
+        // One snapshot, shared with the manager so reassignment it runs itself
+        // (e.g. on a task finishing) sees the polled agent states.
+        let agent_states_cache = manager.agent_states.clone();
         Self {
             manager,
             store,
@@ -156,10 +169,7 @@ impl CommanderService {
             next_op_id: Arc::new(std::sync::atomic::AtomicU64::new(1)),
             gh_available: Arc::new(tokio::sync::OnceCell::new()),
             agent_detector,
-            agent_states_cache: Arc::new(tokio::sync::RwLock::new(AgentStatesSnapshot {
-                states: BTreeMap::new(),
-                commander_running: false,
-            })),
+            agent_states_cache,
             agent_states_primed: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             pull_status: Arc::new(std::sync::Mutex::new(BTreeMap::new())),
             last_pr_check: Arc::new(std::sync::Mutex::new(None)),
@@ -167,6 +177,7 @@ impl CommanderService {
             background_started: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             tmux_ok_cache: Arc::new(std::sync::Mutex::new(None)),
             clone_jobs: CloneJobs::new(),
+            scrollback,
         }
     }
 
@@ -510,14 +521,40 @@ impl CommanderService {
             None
         };
 
+        let resources = if found.status.is_active() {
+            self.manager
+                .session_resource_usage(&found.tmux_session_name)
+                .await
+        } else {
+            None
+        };
+
         Ok(Some(SessionDetail {
             info: session_info_from_session(&found, &project_name),
             agent_state,
             diff_stat,
             pane_content,
+            resources,
         }))
     }
 
+    /// A session's [`SessionBrief`](crate::conversation::SessionBrief) for the
+    /// conversation agent: its detail with the last `lines` of its pane, plus
+    /// the review comments not yet sent to it.
+    pub async fn session_brief(
+        &self,
+        query: &str,
+        lines: usize,
+    ) -> Result<Option<crate::conversation::SessionBrief>> {
+        let Some(detail) = self.get_session_detail(query, Some(lines)).await? else {
+            return Ok(None);
+        };
+        let comments = self.list_comments(&detail.info.session_id).await?;
+        Ok(Some(crate::conversation::SessionBrief::new(
+            &detail, &comments,
+        )))
+    }
+
     pub async fn get_pane_content(
         &self,
         query: &str,
@@ -543,6 +580,43 @@ impl CommanderService {
         Ok(crate::cli::find_session(&state, query).map(|s| s.tmux_session_name.clone()))
     }
 
+    /// Resolve a session query to its agent pane's tmux session name for a
+    /// **spectator**, only if that pane is live right now. Unlike
+    /// [`Self::resolve_attach_session`] this has no side effects: a viewer
+    /// never revives a dead session (relaunching the agent) or stamps
+    /// `last_attached_at`. `None` when nothing matches, the session is archived
+    /// or not attachable, or its tmux session is gone or its pane has died.
+    pub async fn resolve_spectator_session(&self, query: &str) -> Result<Option<String>> {
+        let tmux_name = {
+            let state = self.store.read().await;
+            match crate::cli::find_session(&state, query) {
+                Some(s) if s.status.can_attach() && s.archived.is_none() => {
+                    s.tmux_session_name.clone()
+                }
+                _ => return Ok(None),
+            }
+        };
+        if !self.manager.tmux.session_exists(&tmux_name).await?
+            || self
+                .manager
+                .tmux
+                .is_pane_dead(&tmux_name)
+                .await
+                .unwrap_or(false)
+        {
+            return Ok(None);
+        }
+        Ok(Some(tmux_name))
+    }
+
+    /// The PTY size a spectator of `tmux_name` attaches at: the size of the
+    /// window it watches, not of the viewer's terminal, so the read-only
+    /// client never resizes the pane (see
+    /// [`TmuxExecutor::window_client_size`](crate::tmux::TmuxExecutor::window_client_size)).
+    pub async fn spectator_size(&self, tmux_name: &str) -> Result<(u16, u16)> {
+        Ok(self.manager.tmux.window_client_size(tmux_name).await?)
+    }
+
     /// Resolve a session query to its **shell** pane's tmux session name,
     /// creating the paired shell session on demand (the `Ctrl+\` partner). The
     /// shell counterpart of [`Self::resolve_tmux_session`], mirroring
@@ -913,6 +987,9 @@ impl CommanderService {
             debug!("Failed to reconcile session branches: {e}");
         }
 
+        // 3c. Watch task runs the previous process left running to completion.
+        self.manager.resume_task_watchers().await;
+
         // 4. Reconcile section assignments against current config.
         self.reconcile_all_section_assignments().await?;
 
@@ -926,17 +1003,105 @@ impl CommanderService {
     pub async fn reconcile_all_section_assignments(&self) -> Result<()> {
         let sections =
             crate::session::effective_sections(&self.config_store.read().sections).into_owned();
+        let agent_states = self.cached_agent_states().await;
         let now = chrono::Utc::now();
         self.store
             .mutate(move |state| {
                 for session in state.sessions.values_mut() {
-                    crate::session::apply_assignment(session, &sections, now);
+                    let signals = SectionSignals::for_session(&agent_states, &session.id, now);
+                    crate::session::apply_assignment(session, &sections, signals);
                 }
             })
             .await?;
         Ok(())
     }
 
+    /// Re-run section assignment after a live signal (agent state, unread,
+    /// elapsed time) moved, when some section actually reads one. Checks under
+    /// the read lock first so a tick that moves nothing doesn't write the
+    /// state file.
+    async fn reassign_live_sections(&self) -> Result<()> {
+        let sections =
+            crate::session::effective_sections(&self.config_store.read().sections).into_owned();
+        if !sections
+            .iter()
+            .any(|s| s.reads_live_signals() || s.reads_age())
+        {
+            return Ok(());
+        }
+        let agent_states = self.cached_agent_states().await;
+        let now = chrono::Utc::now();
+        let changed = {
+            let state = self.store.read().await;
+            state.sessions.values().any(|session| {
+                let signals = SectionSignals::for_session(&agent_states, &session.id, now);
+                crate::session::assignment_would_change(session, &sections, signals)
+            })
+        };
+        if !changed {
+            return Ok(());
+        }
+        self.store
+            .mutate(move |state| {
+                for session in state.sessions.values_mut() {
+                    let signals = SectionSignals::for_session(&agent_states, &session.id, now);
+                    crate::session::apply_assignment(session, &sections, signals);
+                }
+            })
+            .await
+    }
+
+    /// Recompute each session's diffstat against its review base when some
+    /// section reads it, then re-run section assignment. A session whose
+    /// worktree can't be diffed keeps its last figure.
+    async fn refresh_diff_stats(&self) -> Result<()> {
+        let sections =
+            crate::session::effective_sections(&self.config_store.read().sections).into_owned();
+        if !sections.iter().any(|s| s.reads_diff_stat()) {
+            return Ok(());
+        }
+        let targets: Vec<(SessionId, PathBuf, ReviewBase)> = {
+            let state = self.store.read().await;
+            state
+                .sessions
+                .values()
+                .filter(|s| s.status != SessionStatus::Creating)
+                .map(|s| (s.id, s.worktree_path.clone(), ReviewBase::of(s)))
+                .collect()
+        };
+        let stats: Vec<(SessionId, crate::api::DiffStat)> =
+            futures::stream::iter(targets.into_iter().map(|(id, path, base)| async move {
+                let base = base.git_ref(&path).await;
+                let target = crate::git::diff_target(&path, &base).await;
+                crate::git::diff_stat_counts(&path, &target)
+                    .await
+                    .map(|stat| (id, stat))
+            }))
+            .buffer_unordered(PR_FANOUT_CONCURRENCY)
+            .filter_map(|stat| async move { stat })
+            .collect()
+            .await;
+        let agent_states = self.cached_agent_states().await;
+        let now = chrono::Utc::now();
+        self.store
+            .mutate(move |state| {
+                for (id, stat) in stats {
+                    if let Some(session) = state.get_session_mut(&id) {
+                        session.diff_stat = Some(stat);
+                        let signals = SectionSignals::for_session(&agent_states, &id, now);
+                        apply_assignment(session, &sections, signals);
+                    }
+                }
+            })
+            .await
+    }
+
+    /// The agent states from the last poll, keyed by session, for
+    /// [`SectionSignals`].
+    async fn cached_agent_states(&self) -> BTreeMap<SessionId, AgentState> {
+        self.agent_states_cache.read().await.states.clone()
+    }
+
     /// Re-run section assignment for a single session against current config.
     /// Used after creating a session, where the rest of the set is already
     /// reconciled. With no `[[sections]]` configured, the baked-in default
@@ -944,11 +1109,13 @@ impl CommanderService {
     pub async fn reconcile_one_section_assignment(&self, session_id: SessionId) -> Result<()> {
         let sections =
             crate::session::effective_sections(&self.config_store.read().sections).into_owned();
+        let agent_states = self.cached_agent_states().await;
         let now = chrono::Utc::now();
         self.store
             .mutate(move |state| {
                 if let Some(session) = state.get_session_mut(&session_id) {
-                    crate::session::apply_assignment(session, &sections, now);
+                    let signals = SectionSignals::for_session(&agent_states, &session_id, now);
+                    crate::session::apply_assignment(session, &sections, signals);
                 }
             })
             .await?;
@@ -1118,13 +1285,17 @@ impl CommanderService {
     pub async fn apply_pr_results(&self, results: Vec<(SessionId, PrCheckResult)>) -> Result<()> {
         let sections =
             crate::session::effective_sections(&self.config_store.read().sections).into_owned();
+        let agent_states = self.cached_agent_states().await;
         let now = chrono::Utc::now();
-        self.store
+        let pr_transitions = self
+            .store
             .mutate(move |state| {
+                let mut transitions = Vec::new();
                 for (session_id, result) in &results {
                     let Some(session) = state.get_session_mut(session_id) else {
                         continue;
                     };
+                    let before = session.pr_state;
                     match result {
                         PrCheckResult::Found(info) => {
                             session.pr_number = Some(info.number);
@@ -1135,6 +1306,8 @@ impl CommanderService {
                             session.pr_merged = info.merged();
                             session.review_decision = info.review_decision;
                             session.pr_reviewers = info.reviewers.clone();
+                            session.pr_author = info.author.clone();
+                            session.pr_checks = Some(info.checks);
                             session.pr_base_branch = info.base_ref_name.clone();
                         }
                         PrCheckResult::NotFound => {
@@ -1146,16 +1319,39 @@ impl CommanderService {
                             session.pr_merged = false;
                             session.review_decision = None;
                             session.pr_reviewers.clear();
+                            session.pr_author = None;
+                            session.pr_checks = None;
                             session.pr_base_branch = None;
                         }
                         PrCheckResult::FetchFailed => {}
                     }
+                    if session.pr_state != before {
+                        transitions.push((*session_id, before, session.pr_state));
+                    }
                 }
                 for session in state.sessions.values_mut() {
-                    crate::session::apply_assignment(session, &sections, now);
+                    let signals = SectionSignals::for_session(&agent_states, &session.id, now);
+                    crate::session::apply_assignment(session, &sections, signals);
                 }
+                transitions
             })
             .await?;
+        for (session_id, from, to) in pr_transitions {
+            self.manager.spawn_session_hooks(
+                HookEvent::PrStateChange,
+                session_id,
+                Some(HookTransition {
+                    from: pr_state_label(from).to_string(),
+                    to: pr_state_label(to).to_string(),
+                }),
+            );
+            // The session's own PR landing is a merge too, alongside the
+            // cascade merges that fire `post_merge` from the session manager.
+            if to == Some(crate::git::PrState::Merged) {
+                self.manager
+                    .spawn_session_hooks(HookEvent::PostMerge, session_id, None);
+            }
+        }
 
         // Push refreshed status bars to running sessions' tmux panes. Snapshot
         // under the lock, then release before the async tmux I/O.
@@ -1180,11 +1376,23 @@ impl CommanderService {
     /// Mark a batch of sessions unread (agent-finished transitions detected by
     /// the poll loop). Paired with [`Self::mark_read`].
     pub async fn mark_unread(&self, ids: Vec<SessionId>) -> Result<()> {
+        self.set_unread(ids, true).await
+    }
+
+    /// Set the unread flag on `ids` and re-run section assignment for them in
+    /// the same write, so an `unread` predicate moves the card at once.
+    async fn set_unread(&self, ids: Vec<SessionId>, unread: bool) -> Result<()> {
+        let sections =
+            crate::session::effective_sections(&self.config_store.read().sections).into_owned();
+        let agent_states = self.cached_agent_states().await;
+        let now = chrono::Utc::now();
         self.store
             .mutate(move |state| {
                 for id in &ids {
                     if let Some(session) = state.get_session_mut(id) {
-                        session.unread = true;
+                        session.unread = unread;
+                        let signals = SectionSignals::for_session(&agent_states, id, now);
+                        apply_assignment(session, &sections, signals);
                     }
                 }
             })
@@ -1242,6 +1450,28 @@ impl CommanderService {
         self.manager.delete_session(id).await
     }
 
+    /// Archive a session: stop it and remove its worktree, keeping the branch,
+    /// the session row and any uncommitted work (on a hidden ref).
+    pub async fn archive_session(&self, id: &SessionId) -> Result<()> {
+        self.telemetry.feature("session.archive");
+        self.manager.archive_session(id).await
+    }
+
+    /// Restore an archived session: recreate its worktree, re-apply the kept
+    /// uncommitted work, and relaunch the agent with `--resume`.
+    pub async fn restore_session(&self, id: &SessionId) -> Result<()> {
+        self.telemetry.feature("session.restore");
+        self.manager.restore_session(id).await
+    }
+
+    /// Undo the most recent session delete, project removal or cascade
+    /// abandon still within the undo window. Returns what was undone, e.g.
+    /// `deleted session "x"`.
+    pub async fn undo(&self) -> Result<String> {
+        self.telemetry.feature("undo");
+        self.manager.undo_last().await
+    }
+
     /// Set a session's keep-alive flag (opt-out of auto-hibernation).
     pub async fn set_keep_alive(&self, id: &SessionId, keep_alive: bool) -> Result<bool> {
         self.telemetry.feature("session.set_keep_alive");
@@ -1547,7 +1777,31 @@ impl CommanderService {
             "Review the comments in {} and address them.",
             path.display()
         );
-        self.manager.tmux.send_keys(&tmux_name, &prompt).await?;
+        self.submit_prompt(session_id, &tmux_name, kind, &prompt)
+            .await?;
+
+        // Mark the delivered comments applied.
+        for ann in comments
+            .iter_mut()
+            .filter(|a| a.status != CommentStatus::Applied)
+        {
+            ann.status = CommentStatus::Applied;
+        }
+        self.comments.save(*session_id, &comments).await?;
+
+        Ok(ApplyOutcome::Applied { path, count })
+    }
+
+    /// Type `prompt` into the agent pane and submit it. Callers gate on agent
+    /// state first (see [`decide_send`]).
+    async fn submit_prompt(
+        &self,
+        session_id: &SessionId,
+        tmux_name: &str,
+        kind: AgentKind,
+        prompt: &str,
+    ) -> Result<()> {
+        self.manager.tmux.send_keys(tmux_name, prompt).await?;
         // Some harnesses (Codex) fold an Enter that arrives in the same terminal
         // read as the preceding text into the paste instead of submitting it,
         // leaving the prompt unsent in the composer; wait for the harness to
@@ -1556,7 +1810,7 @@ impl CommanderService {
         if let Some(delay) = kind.submit_key_delay() {
             tokio::time::sleep(delay).await;
         }
-        self.manager.tmux.send_keys(&tmux_name, "Enter").await?;
+        self.manager.tmux.send_keys(tmux_name, "Enter").await?;
 
         // Delivering a prompt flips an idle agent back to working without
         // attaching or changing status, so bump last_active_at: a concurrent
@@ -1570,17 +1824,201 @@ impl CommanderService {
                 }
             })
             .await?;
+        Ok(())
+    }
 
-        // Mark the delivered comments applied.
-        for ann in comments
-            .iter_mut()
-            .filter(|a| a.status != CommentStatus::Applied)
-        {
-            ann.status = CommentStatus::Applied;
+    // -- Project tasks --
+
+    /// Start project task `name`, or every task available to the session when
+    /// `None`, in the session's task window. Returns one result per task; a
+    /// named task that can't start is an error instead.
+    pub async fn run_tasks(
+        &self,
+        session_id: &SessionId,
+        name: Option<&str>,
+    ) -> Result<Vec<TaskStart>> {
+        self.telemetry.feature("tasks.run");
+        match name {
+            Some(name) => {
+                self.manager.run_task(session_id, name).await?;
+                Ok(vec![TaskStart {
+                    name: name.to_string(),
+                    error: None,
+                }])
+            }
+            None => self.manager.run_all_tasks(session_id).await,
         }
-        self.comments.save(*session_id, &comments).await?;
+    }
 
-        Ok(ApplyOutcome::Applied { path, count })
+    /// Hand a failed task's output to the session's agent: the output is
+    /// written to a temp file and a prompt pointing at it is submitted in the
+    /// agent pane. `name` picks the task; `None` takes the most recent failure.
+    /// Returns the name of the task that was sent.
+    ///
+    /// Gated on agent state like [`Self::apply_comments`], but nothing is
+    /// queued: a stopped agent, or one still at a permission prompt after the
+    /// bounded wait, is a [`SessionError::InvalidState`].
+    pub async fn send_task_failure(
+        &self,
+        session_id: &SessionId,
+        name: Option<&str>,
+    ) -> Result<String> {
+        self.telemetry.feature("tasks.send_failure");
+        let (run, output) = self.manager.failed_task_output(session_id, name).await?;
+        let (tmux_name, is_active, kind) = {
+            let state = self.store.read().await;
+            let s = state
+                .get_session(session_id)
+                .ok_or(SessionError::NotFound(*session_id))?;
+            (
+                s.tmux_session_name.clone(),
+                s.status.is_active(),
+                AgentKind::from_program(&s.program),
+            )
+        };
+        if !is_active {
+            return Err(SessionError::InvalidState(*session_id).into());
+        }
+
+        let mut detector = AgentStateDetector::new(self.manager.tmux.clone(), Duration::ZERO);
+        let ready = match decide_send(detector.detect(kind, &tmux_name).await) {
+            SendDecision::Now => true,
+            SendDecision::HoldUntilClear => wait_until_ready(&mut detector, kind, &tmux_name).await,
+        };
+        if !ready {
+            return Err(SessionError::InvalidState(*session_id).into());
+        }
+
+        let path = write_task_output(*session_id, &run.name, &output).await?;
+        self.submit_prompt(
+            session_id,
+            &tmux_name,
+            kind,
+            &task_failure_prompt(&run, &path),
+        )
+        .await?;
+        Ok(run.name)
+    }
+
+    // -- Scrollback search --
+
+    /// Bring the scrollback index up to date with every running session's
+    /// tmux history. Panes already indexed are captured incrementally (their
+    /// newest [`INCREMENTAL_ROWS`] history rows), falling back to the whole
+    /// history when more than that arrived; new ones are captured whole.
+    /// Captures run outside the index lock so a search never waits on tmux.
+    ///
+    /// `persist` writes the new rows and drops the files of deleted sessions.
+    /// Only the loop persists: a one-shot CLI search refreshes in memory, so it
+    /// can't interleave appends with a running TUI's loop.
+    pub async fn refresh_scrollback_index(&self, persist: bool) -> Result<()> {
+        let targets: Vec<(SessionId, String)> = {
+            let state = self.store.read().await;
+            state
+                .sessions
+                .values()
+                .filter(|s| s.status == SessionStatus::Running)
+                .map(|s| (s.id, s.tmux_session_name.clone()))
+                .collect()
+        };
+        let indexed: Vec<bool> = {
+            let mut index = self.scrollback.lock().await;
+            index.load().await?;
+            targets.iter().map(|(id, _)| index.contains(*id)).collect()
+        };
+
+        let mut captures = Vec::with_capacity(targets.len());
+        for ((id, name), indexed) in targets.iter().zip(indexed) {
+            let rows = indexed.then_some(INCREMENTAL_ROWS);
+            match self.manager.tmux.capture_history_rows(name, rows).await {
+                Ok((history_size, history)) => {
+                    let complete = rows.is_none_or(|n| n >= history_size);
+                    captures.push((*id, name, history, complete));
+                }
+                Err(e) => debug!("Scrollback capture of {id} failed: {e}"),
+            }
+        }
+        let overflowed: Vec<(SessionId, &String)> = {
+            let mut index = self.scrollback.lock().await;
+            captures
+                .into_iter()
+                .filter_map(|(id, name, history, complete)| {
+                    (index.ingest(id, history, complete) == Splice::NoOverlap).then_some((id, name))
+                })
+                .collect()
+        };
+        let mut full = Vec::with_capacity(overflowed.len());
+        for (id, name) in overflowed {
+            match self.manager.tmux.capture_history_rows(name, None).await {
+                Ok((_, history)) => full.push((id, history)),
+                Err(e) => debug!("Scrollback capture of {id} failed: {e}"),
+            }
+        }
+
+        let known: HashSet<SessionId> = if persist {
+            self.store.read().await.sessions.keys().copied().collect()
+        } else {
+            HashSet::new()
+        };
+        let mut index = self.scrollback.lock().await;
+        for (id, history) in full {
+            index.ingest(id, history, true);
+        }
+        if persist {
+            index.retain(|id| known.contains(id)).await?;
+            index.flush().await?;
+        }
+        Ok(())
+    }
+
+    /// Search every session's indexed scrollback. Without the background loop
+    /// (a one-shot CLI, or `scrollback_index_interval_secs = 0`) the index is
+    /// first refreshed in memory, so a search sees what the panes show now
+    /// rather than what was last saved.
+    pub async fn search_scrollback(&self, search: &ScrollbackSearch) -> Result<Vec<ScrollbackHit>> {
+        self.telemetry.feature("scrollback.search");
+        let pattern = ScrollbackPattern::new(search)?;
+        let looping = self.background_started.load(Ordering::Relaxed)
+            && self.config_store.read().scrollback_index_interval_secs > 0;
+        if !looping {
+            self.refresh_scrollback_index(false).await?;
+        }
+        let mut index = self.scrollback.lock().await;
+        index.load().await?;
+        Ok(index.search(&pattern, search.limit()))
+    }
+
+    /// Put a session's pane in copy-mode scrolled to a search hit's line, so
+    /// the next attach opens on it. The line is found again in a fresh capture
+    /// ([`crate::scrollback::locate`]); if it has since been trimmed from the
+    /// history, that's a [`SessionError::LineNotInScrollback`].
+    pub async fn reveal_scrollback_line(
+        &self,
+        session_id: &SessionId,
+        reveal: &ScrollbackReveal,
+    ) -> Result<()> {
+        let tmux_name = {
+            let state = self.store.read().await;
+            state
+                .get_session(session_id)
+                .ok_or(SessionError::NotFound(*session_id))?
+                .tmux_session_name
+                .clone()
+        };
+        let (_, rows) = self
+            .manager
+            .tmux
+            .capture_history_rows(&tmux_name, None)
+            .await?;
+        let row = crate::scrollback::locate(&rows, reveal)
+            .ok_or_else(|| SessionError::LineNotInScrollback(reveal.text.clone()))?;
+        self.manager
+            .tmux
+            .copy_mode_goto_line(
+                &tmux_name,
+                crate::scrollback::reveal_offset(rows.len(), row),
+            )
+            .await
     }
 
     // -- Workspace / tree (additive: everything the session tree needs) --
@@ -1779,11 +2217,52 @@ impl CommanderService {
         }
         self.ensure_session_exists(id).await?;
         self.telemetry.feature("session.rename");
+        self.edit_session_and_reassign(*id, move |s| s.title = title)
+            .await
+    }
+
+    /// Replace a session's tags, normalised by [`crate::session::normalize_tags`].
+    /// An empty list clears them.
+    pub async fn set_tags(&self, id: &SessionId, tags: Vec<String>) -> Result<()> {
+        self.ensure_session_exists(id).await?;
+        self.telemetry.feature("session.set_tags");
+        let tags = crate::session::normalize_tags(tags);
+        self.edit_session_and_reassign(*id, move |s| s.tags = tags)
+            .await
+    }
+
+    /// Replace a session's free-form notes. Surrounding whitespace is trimmed;
+    /// an empty string clears them.
+    pub async fn set_notes(&self, id: &SessionId, notes: impl Into<String>) -> Result<()> {
+        self.ensure_session_exists(id).await?;
+        self.telemetry.feature("session.set_notes");
+        let notes = notes.into().trim().to_string();
         let id = *id;
         self.store
             .mutate(move |state| {
                 if let Some(s) = state.get_session_mut(&id) {
-                    s.title = title;
+                    s.notes = notes;
+                }
+            })
+            .await
+    }
+
+    /// Apply a user edit to a field section predicates read (title, tags) and
+    /// re-run that session's section assignment in the same write.
+    async fn edit_session_and_reassign(
+        &self,
+        id: SessionId,
+        edit: impl FnOnce(&mut WorktreeSession) + Send + 'static,
+    ) -> Result<()> {
+        let sections =
+            crate::session::effective_sections(&self.config_store.read().sections).into_owned();
+        let agent_states = self.cached_agent_states().await;
+        let signals = SectionSignals::for_session(&agent_states, &id, chrono::Utc::now());
+        self.store
+            .mutate(move |state| {
+                if let Some(s) = state.get_session_mut(&id) {
+                    edit(s);
+                    apply_assignment(s, &sections, signals);
                 }
             })
             .await
@@ -1826,18 +2305,19 @@ impl CommanderService {
         self.telemetry.feature("session.set_section");
         let sections =
             crate::session::effective_sections(&self.config_store.read().sections).into_owned();
-        let now = chrono::Utc::now();
+        let agent_states = self.cached_agent_states().await;
         let id = *id;
+        let signals = SectionSignals::for_session(&agent_states, &id, chrono::Utc::now());
         self.store
             .mutate(move |state| {
                 if let Some(s) = state.get_session_mut(&id) {
                     match section {
                         Some(name) => {
                             s.section_override = Some(name);
-                            apply_assignment(s, &sections, now);
+                            apply_assignment(s, &sections, signals);
                         }
                         None => {
-                            clear_override_and_reassign(s, &sections, now);
+                            clear_override_and_reassign(s, &sections, signals);
                         }
                     }
                 }
@@ -1864,14 +2344,7 @@ impl CommanderService {
     /// Clear a session's unread flag (as the TUI does when attaching).
     pub async fn mark_read(&self, id: &SessionId) -> Result<()> {
         self.ensure_session_exists(id).await?;
-        let id = *id;
-        self.store
-            .mutate(move |state| {
-                if let Some(s) = state.get_session_mut(&id) {
-                    s.unread = false;
-                }
-            })
-            .await
+        self.set_unread(vec![*id], false).await
     }
 
     /// New-session dialog options: the default program, the configured program
@@ -1960,9 +2433,11 @@ impl CommanderService {
             );
             let mut detector = AgentStateDetector::new(self.manager.tmux.clone(), Duration::ZERO);
             let states = detector.detect_all(&active).await;
+            let viewers = session_viewers(&self.manager.tmux, &active).await;
             let mut cache = self.agent_states_cache.write().await;
             cache.states = states;
             cache.commander_running = commander_running;
+            cache.viewers = viewers;
             self.agent_states_primed
                 .store(true, std::sync::atomic::Ordering::Relaxed);
             return cache.clone();
@@ -1984,9 +2459,11 @@ impl CommanderService {
         let commander_enabled = self.config_store.read().commander_enabled;
         let commander_running =
             commander_enabled && crate::commander::is_running(&self.manager.tmux).await;
+        let viewers = session_viewers(&self.manager.tmux, &active).await;
         AgentStatesSnapshot {
             states,
             commander_running,
+            viewers,
         }
     }
 
@@ -1996,9 +2473,15 @@ impl CommanderService {
     /// Idempotent: a call on an already-empty cache is a no-op (returns whether
     /// it cleared anything). Called from the agent-state loop's quiet path.
     async fn clear_stale_agent_states(&self) -> bool {
-        let had_states = !self.agent_states_cache.read().await.states.is_empty();
+        let had_states = {
+            let cache = self.agent_states_cache.read().await;
+            !cache.states.is_empty() || !cache.viewers.is_empty()
+        };
         if had_states {
-            self.agent_states_cache.write().await.states.clear();
+            let mut cache = self.agent_states_cache.write().await;
+            cache.states.clear();
+            cache.viewers.clear();
+            drop(cache);
             self.store.notify_change();
         }
         had_states
@@ -2021,7 +2504,7 @@ impl CommanderService {
     /// agent loop maintains [`Self::agent_states`]' cache and persists unread
     /// transitions; the PR loop persists results via [`Self::apply_pr_results`];
     /// the pull loop feeds [`WorkspaceSnapshot::project_pull`]; the sync loop
-    /// reloads the state file. Each wakes the [`StateStore`] change-feed on a
+    /// reloads the state file; the undo loop expires the undo journal. Each wakes the [`StateStore`] change-feed on a
     /// real change (either via a persisted mutation or [`StateStore::notify_change`]),
     /// so a subscriber (the TUI's per-backend change-feed task) re-reads the
     /// relevant snapshot — no frontend-specific event plumbing crosses the
@@ -2058,6 +2541,8 @@ impl CommanderService {
                 config.project_pull_interval_secs,
             ),
             self.spawn_state_sync_loop(config.state_sync_interval_ms),
+            self.spawn_scrollback_index_loop(config.scrollback_index_interval_secs),
+            self.spawn_undo_expiry_loop(),
         ];
         BackgroundHandles { handles }
     }
@@ -2119,6 +2604,7 @@ impl CommanderService {
                 } else {
                     detector.detect_all(&sessions).await
                 };
+                let viewers = session_viewers(&tmux, &sessions).await;
                 if !poll_tick_should_send(
                     states.is_empty(),
                     commander_running,
@@ -2132,12 +2618,28 @@ impl CommanderService {
                 // Diff against the previous cache to flag agents that just
                 // finished a turn (Working→Idle), skipping the commander
                 // sentinel (it has no `WorktreeSession` to mark).
-                let prev = { cache.read().await.states.clone() };
+                let (prev, viewers_changed) = {
+                    let c = cache.read().await;
+                    (c.states.clone(), c.viewers != viewers)
+                };
                 let unread_ids: Vec<SessionId> = detect_unread_transitions(&prev, &states)
                     .into_iter()
                     .filter(|id| *id != sentinel)
                     .collect();
                 let states_changed = states != prev;
+                for (id, from, to) in agent_state_transitions(&prev, &states) {
+                    if id == sentinel {
+                        continue;
+                    }
+                    service.manager.spawn_session_hooks(
+                        HookEvent::AgentStateChange,
+                        id,
+                        Some(HookTransition {
+                            from: from.to_string(),
+                            to: to.to_string(),
+                        }),
+                    );
+                }
 
                 // Only write the cache when something changed: a rebuilt-but-
                 // identical map would serialize identically anyway (BTreeMap,
@@ -2149,6 +2651,9 @@ impl CommanderService {
                     if states_changed {
                         c.states = states;
                     }
+                    if viewers_changed {
+                        c.viewers = viewers;
+                    }
                     c.commander_running = commander_running;
                 }
                 primed.store(true, std::sync::atomic::Ordering::Relaxed);
@@ -2161,9 +2666,14 @@ impl CommanderService {
                 // wake triggers reads the fresh states.
                 if !unread_ids.is_empty() {
                     let _ = service.mark_unread(unread_ids).await;
-                } else if states_changed || commander_flipped {
+                } else if states_changed || viewers_changed || commander_flipped {
                     store.notify_change();
                 }
+                // Agent-state and inactivity predicates follow the poll; the
+                // unread path above already reassigned the marked sessions.
+                if let Err(e) = service.reassign_live_sections().await {
+                    debug!("Failed to reassign live sections: {e}");
+                }
             }
         })
     }
@@ -2233,6 +2743,14 @@ impl CommanderService {
                 if let Err(e) = service.reconcile_session_branches().await {
                     debug!("reconcile_session_branches failed: {e}");
                 }
+                // Local inputs first, so diffstat and age predicates keep
+                // moving cards even without `gh`.
+                if let Err(e) = service.refresh_diff_stats().await {
+                    debug!("refresh_diff_stats failed: {e}");
+                }
+                if let Err(e) = service.reassign_live_sections().await {
+                    debug!("reassign_live_sections failed: {e}");
+                }
                 if !service.gh_available().await {
                     continue;
                 }
@@ -2340,6 +2858,41 @@ impl CommanderService {
         })
     }
 
+    /// Keep the scrollback index current on a fixed cadence
+    /// ([`Self::refresh_scrollback_index`], persisting). No-op loop when
+    /// `interval_secs` is 0.
+    fn spawn_scrollback_index_loop(&self, interval_secs: u64) -> tokio::task::JoinHandle<()> {
+        let service = self.clone();
+        tokio::spawn(async move {
+            if interval_secs == 0 {
+                return;
+            }
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                if let Err(e) = service.refresh_scrollback_index(true).await {
+                    debug!("Scrollback index pass failed: {e}");
+                }
+            }
+        })
+    }
+
+    /// Expire undo journal entries once a minute, starting immediately, so
+    /// their hidden refs are deleted soon after the undo window closes even
+    /// when no further undoable action comes along.
+    fn spawn_undo_expiry_loop(&self) -> tokio::task::JoinHandle<()> {
+        let manager = self.manager.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(60));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                manager.expire_undo_entries().await;
+            }
+        })
+    }
+
     // -- Internal helpers for the workspace surface --
 
     /// Cached `gh --version` availability (computed once per process).
@@ -2550,6 +3103,35 @@ pub(crate) fn detect_unread_transitions(
         .collect()
 }
 
+/// `(session, from, to)` for every session whose detected agent state changed
+/// between polls — what the `agent_state_change` hooks fire on. A session
+/// absent from `prev` (first observation, or a cleared baseline) yields
+/// nothing, and so does a move to or from [`AgentState::Unknown`]: that is a
+/// detection miss, not something the agent did.
+pub(crate) fn agent_state_transitions(
+    prev: &BTreeMap<SessionId, AgentState>,
+    new: &BTreeMap<SessionId, AgentState>,
+) -> Vec<(SessionId, AgentState, AgentState)> {
+    new.iter()
+        .filter_map(|(id, to)| {
+            let from = *prev.get(id)?;
+            (from != *to && from != AgentState::Unknown && *to != AgentState::Unknown)
+                .then_some((*id, from, *to))
+        })
+        .collect()
+}
+
+/// Label for a PR state in `pr_state_change` hook variables; `none` when the
+/// session has no PR (it was never opened, or its branch was deleted).
+fn pr_state_label(state: Option<crate::git::PrState>) -> &'static str {
+    match state {
+        Some(crate::git::PrState::Open) => "open",
+        Some(crate::git::PrState::Closed) => "closed",
+        Some(crate::git::PrState::Merged) => "merged",
+        None => "none",
+    }
+}
+
 /// Append the commander's sentinel detection target to `active` when the
 /// commander is running, so its agent state is detected alongside real
 /// sessions. The sentinel is a reserved id with no `WorktreeSession`; callers
@@ -2571,6 +3153,28 @@ fn with_commander_target(
     active
 }
 
+/// How many clients are attached to each detection target's tmux session, from
+/// one tmux call. Unviewed sessions and the commander sentinel are left out; a
+/// failed call (no tmux server yet) reads as nobody viewing. Shared by the poll
+/// loop and both [`CommanderService::agent_states`] fallbacks.
+async fn session_viewers(
+    tmux: &TmuxExecutor,
+    targets: &[(SessionId, String, String)],
+) -> BTreeMap<SessionId, u32> {
+    if targets.is_empty() {
+        return BTreeMap::new();
+    }
+    let Ok(counts) = tmux.attached_client_counts().await else {
+        return BTreeMap::new();
+    };
+    let sentinel = crate::commander::commander_sentinel_id();
+    targets
+        .iter()
+        .filter(|(id, ..)| *id != sentinel)
+        .filter_map(|(id, name, _)| counts.get(name).map(|n| (*id, *n)))
+        .collect()
+}
+
 /// What tmux reports about one session's pane, as the startup reconciler sees
 /// it. Distinguishing [`Self::Unknown`] from [`Self::Absent`] is the whole
 /// point: a tmux *failure* tells us nothing about the session and must never
@@ -2654,6 +3258,30 @@ async fn write_apply_brief(session_id: SessionId, markdown: &str) -> Result<Path
     Ok(path)
 }
 
+/// Write a failed task's output next to the comment briefs (see
+/// [`write_apply_brief`] for why the OS temp dir).
+async fn write_task_output(session_id: SessionId, task: &str, output: &str) -> Result<PathBuf> {
+    let path = std::env::temp_dir().join(format!("cc-task-{}-{task}.log", session_id.as_uuid()));
+    tokio::fs::write(&path, output)
+        .await
+        .map_err(|e| crate::error::ConfigError::SaveFailed(e.to_string()))?;
+    Ok(path)
+}
+
+/// The prompt pointing the agent at a failed task's output.
+fn task_failure_prompt(run: &crate::session::TaskRun, output: &Path) -> String {
+    let exit = match run.exit_code {
+        Some(code) => format!("exited with code {code}"),
+        None => "was killed".to_string(),
+    };
+    format!(
+        "The `{}` task (`{}`) {exit}. Its output is in {}. Find the cause and fix it.",
+        run.name,
+        run.command,
+        output.display()
+    )
+}
+
 /// Poll the agent state, returning `true` once it leaves `WaitingForInput`, or
 /// `false` if it stays at a prompt past the bounded timeout.
 async fn wait_until_ready(
@@ -2849,8 +3477,9 @@ fn ensure_install_id(store: &Arc<StateStore>) -> String {
 pub use claude_commander_protocol::api::{
     AgentStatesSnapshot, BranchInfo, ChangeProgram, CreateOptions, CreateSessionOpts, DiffSide,
     DiffStat, NewComment, OperationKind, OperationOutcome, OperationStatus, PreviewData,
-    ProgramInfo, ProjectInfo, PullBlockReason, PullStatus, RenameSession, ReviewSnapshot,
-    ServerStatus, SessionDetail, SessionInfo, SetProgramsRequest, SetSection, ToggleReviewed,
+    ProgramInfo, ProjectInfo, PullBlockReason, PullStatus, RenameSession, ResourceUsage,
+    ReviewSnapshot, ServerStatus, SessionDetail, SessionInfo, SetNotes, SetProgramsRequest,
+    SetSection, SetTags, ShareRequest, ShareToken, TaskRequest, ToggleReviewed, UndoResult,
     WorkspaceSnapshot,
 };
 
@@ -2889,6 +3518,14 @@ pub(crate) fn session_info_from_session(
         keep_alive: session.keep_alive,
         worktree_path: session.worktree_path.to_string_lossy().into_owned(),
         tmux_session_name: session.tmux_session_name.clone(),
+        hook_runs: session.hook_runs.clone(),
+        ports: session.ports,
+        task_runs: session.task_runs.clone(),
+        pr_author: session.pr_author.clone(),
+        tags: session.tags.clone(),
+        notes: session.notes.clone(),
+        archived: session.archived.clone(),
+        pr_checks: session.pr_checks,
     }
 }
 
@@ -3314,6 +3951,7 @@ mod tests {
             agent_state: AgentState::Working,
             diff_stat: Some("3 files changed".to_string()),
             pane_content: None,
+            resources: None,
         };
         let json: serde_json::Value = serde_json::to_value(&detail).unwrap();
         assert_eq!(json["title"], "test");
@@ -4129,6 +4767,54 @@ mod tests {
         let _ = svc.session_manager().tmux.kill_session(tmux_name).await;
     }
 
+    #[tokio::test]
+    async fn spectator_lookup_neither_revives_nor_stamps() {
+        // A share-link viewer must not be able to relaunch an agent or bump a
+        // session up the MRU order: the spectator lookup only reports a pane
+        // that is already live.
+        let dir = tempfile::TempDir::new().unwrap();
+        let svc = service(&dir);
+        let tmux_name = "cc-spectate-live";
+        start_live_pane(&svc, &dir, tmux_name).await;
+        let live =
+            seed_session_for_tmux(&svc, &dir, tmux_name, SessionStatus::Running, false).await;
+        let dead = seed_session_for_tmux(
+            &svc,
+            &dir,
+            "cc-spectate-gone",
+            SessionStatus::Running,
+            false,
+        )
+        .await;
+
+        let resolved = svc
+            .resolve_spectator_session(&live.as_uuid().to_string())
+            .await
+            .unwrap();
+        assert_eq!(resolved.as_deref(), Some(tmux_name));
+        let gone = svc
+            .resolve_spectator_session(&dead.as_uuid().to_string())
+            .await
+            .unwrap();
+        assert_eq!(gone, None, "a session with no tmux is not spectatable");
+
+        {
+            let state = svc.store().read().await;
+            for sid in [live, dead] {
+                assert!(state.get_session(&sid).unwrap().last_attached_at.is_none());
+            }
+        }
+        assert!(
+            !svc.session_manager()
+                .tmux
+                .session_exists("cc-spectate-gone")
+                .await
+                .unwrap(),
+            "spectating must not recreate a dead session"
+        );
+        let _ = svc.session_manager().tmux.kill_session(tmux_name).await;
+    }
+
     #[tokio::test]
     async fn startup_reconcile_leaves_a_stopped_session_with_no_tmux_alone() {
         // The other half of the bidirectional rule: absence must not be
@@ -4208,6 +4894,8 @@ mod tests {
             labels: vec!["x".to_string()],
             review_decision: None,
             reviewers: vec![],
+            author: Some("alice".to_string()),
+            checks: crate::git::ChecksStatus::Failing,
             base_ref_name: Some("main".to_string()),
         };
         svc.apply_pr_results(vec![(sid, PrCheckResult::Found(info))])
@@ -4218,6 +4906,8 @@ mod tests {
             let s = state.get_session(&sid).unwrap();
             assert_eq!(s.pr_number, Some(42));
             assert_eq!(s.pr_base_branch.as_deref(), Some("main"));
+            assert_eq!(s.pr_author.as_deref(), Some("alice"));
+            assert_eq!(s.pr_checks, Some(crate::git::ChecksStatus::Failing));
         }
 
         // NotFound authoritatively clears; FetchFailed would preserve.
@@ -4228,6 +4918,8 @@ mod tests {
         let s = state.get_session(&sid).unwrap();
         assert!(s.pr_number.is_none());
         assert!(s.pr_base_branch.is_none());
+        assert!(s.pr_author.is_none());
+        assert!(s.pr_checks.is_none());
     }
 
     /// Run `git` in `dir`, panicking on failure. GPG signing is forced off so
@@ -4602,6 +5294,60 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn set_tags_normalises_and_reassigns_section() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let config = Config {
+            sections: vec![crate::session::SectionConfig {
+                name: "Blocked".to_string(),
+                matcher: crate::session::SectionMatch {
+                    has_tag: Some(crate::session::section::LabelPredicate::One(
+                        "blocked".to_string(),
+                    )),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            ..Config::default()
+        };
+        let svc = service_with_config(&dir, config);
+        let (_pid, sid) = seed_project_session(&svc).await;
+
+        svc.set_tags(
+            &sid,
+            vec![" #Blocked ".into(), "needs review".into(), "blocked".into()],
+        )
+        .await
+        .unwrap();
+        {
+            let state = svc.store().read().await;
+            let s = state.get_session(&sid).unwrap();
+            assert_eq!(s.tags, vec!["blocked", "needs-review"]);
+            assert_eq!(s.current_section.as_deref(), Some("Blocked"));
+        }
+
+        svc.set_tags(&sid, Vec::new()).await.unwrap();
+        let state = svc.store().read().await;
+        let s = state.get_session(&sid).unwrap();
+        assert!(s.tags.is_empty());
+        assert_eq!(s.current_section, None);
+    }
+
+    #[tokio::test]
+    async fn set_notes_trims_and_clears() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let svc = service(&dir);
+        let (_pid, sid) = seed_project_session(&svc).await;
+        svc.set_notes(&sid, "  waiting on API key\n").await.unwrap();
+        {
+            let state = svc.store().read().await;
+            assert_eq!(state.get_session(&sid).unwrap().notes, "waiting on API key");
+        }
+        svc.set_notes(&sid, "").await.unwrap();
+        let state = svc.store().read().await;
+        assert!(state.get_session(&sid).unwrap().notes.is_empty());
+    }
+
     #[tokio::test]
     async fn set_section_sets_override_and_clears_it() {
         let dir = tempfile::TempDir::new().unwrap();
@@ -4820,6 +5566,54 @@ mod tests {
         assert_eq!(detect_unread_transitions(&prev, &new), vec![sentinel]);
     }
 
+    #[test]
+    fn agent_state_transitions_skip_first_sightings_and_unknown() {
+        let changed = SessionId::new();
+        let steady = SessionId::new();
+        let fresh = SessionId::new();
+        let flaky = SessionId::new();
+        let prev = BTreeMap::from([
+            (changed, AgentState::Working),
+            (steady, AgentState::Idle),
+            (flaky, AgentState::Working),
+        ]);
+        let new = BTreeMap::from([
+            (changed, AgentState::WaitingForInput),
+            (steady, AgentState::Idle),
+            (fresh, AgentState::Working),
+            (flaky, AgentState::Unknown),
+        ]);
+        assert_eq!(
+            agent_state_transitions(&prev, &new),
+            vec![(changed, AgentState::Working, AgentState::WaitingForInput)]
+        );
+    }
+
+    #[test]
+    fn pr_state_label_names_missing_pr_none() {
+        assert_eq!(pr_state_label(Some(crate::git::PrState::Merged)), "merged");
+        assert_eq!(pr_state_label(None), "none");
+    }
+
+    #[test]
+    fn task_failure_prompt_names_task_exit_and_output() {
+        let run = crate::session::TaskRun {
+            name: "test".into(),
+            command: "cargo test".into(),
+            status: crate::session::TaskStatus::Failed,
+            exit_code: Some(101),
+            started_at: chrono::Utc::now(),
+            finished_at: Some(chrono::Utc::now()),
+            output_tail: String::new(),
+        };
+        let prompt = task_failure_prompt(&run, Path::new("/tmp/out.log"));
+        assert_eq!(
+            prompt,
+            "The `test` task (`cargo test`) exited with code 101. Its output is in \
+             /tmp/out.log. Find the cause and fix it."
+        );
+    }
+
     #[test]
     fn poll_tick_skip_and_send_decisions() {
         // Skip only when there's nothing to detect and the commander's running
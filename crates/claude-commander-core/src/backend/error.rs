@@ -71,11 +71,15 @@ impl From<CoreError> for BackendError {
                 SessionError::NotFound(_)
                 | SessionError::ProjectNotFound(_)
                 | SessionError::TmuxSessionNotFound(_)
-                | SessionError::FileNotInDiff(_),
+                | SessionError::FileNotInDiff(_)
+                | SessionError::TaskNotFound(_)
+                | SessionError::LineNotInScrollback(_),
             ) => BackendError::NotFound,
 
             CoreError::Session(
-                SessionError::InvalidName { .. } | SessionError::InvalidProgram(_),
+                SessionError::InvalidName { .. }
+                | SessionError::InvalidProgram(_)
+                | SessionError::InvalidSearchPattern(_),
             ) => BackendError::InvalidRequest(err.to_string()),
 
             // A refused clone source/destination name: nothing failed, the
@@ -126,6 +130,8 @@ mod tests {
             CoreError::Session(SessionError::ProjectNotFound("p".into())),
             CoreError::Session(SessionError::TmuxSessionNotFound("s".into())),
             CoreError::Session(SessionError::FileNotInDiff("a.rs".into())),
+            CoreError::Session(SessionError::TaskNotFound("test".into())),
+            CoreError::Session(SessionError::LineNotInScrollback("ok".into())),
         ] {
             assert!(
                 matches!(BackendError::from(e), BackendError::NotFound),
@@ -149,6 +155,11 @@ mod tests {
             BackendError::from(e),
             BackendError::InvalidRequest(_)
         ));
+        let e = CoreError::Session(SessionError::InvalidSearchPattern("(".into()));
+        assert!(matches!(
+            BackendError::from(e),
+            BackendError::InvalidRequest(_)
+        ));
     }
 
     #[test]
@@ -22,6 +22,8 @@ use crate::comment::ApplyOutcome;
 use crate::session::{ProjectId, ScanResult, SessionId};
 use crate::tmux::HeadlessAttach;
 use claude_commander_protocol::github::{CloneJob, CloneJobId, CloneRequest, GithubRepo};
+use claude_commander_protocol::scrollback::{ScrollbackHit, ScrollbackReveal, ScrollbackSearch};
+use claude_commander_protocol::task::TaskStart;
 
 use super::error::BResult;
 use super::run_local::run_local;
@@ -70,6 +72,16 @@ impl LocalBackend {
         Ok(Box::new(LocalAttachConnection { bridge }))
     }
 
+    /// Type `command` into a session's shell pane (created on demand) and
+    /// press Enter — the `shell` run mode of a custom palette command.
+    pub async fn run_in_shell_pane(&self, id: SessionId, command: &str) -> BResult<()> {
+        let manager = self.service.session_manager();
+        let shell = manager.ensure_shell_session(&id).await?;
+        manager.tmux.send_keys_literal(&shell, command).await?;
+        manager.tmux.send_keys(&shell, "Enter").await?;
+        Ok(())
+    }
+
     /// Resolve the Ctrl+\ shell-toggle partner for a tmux session reached via
     /// the in-session switcher (which lands on an arbitrary session by name, so
     /// there's no `SessionId`/[`AttachKind`] to flip). A Claude session toggles
@@ -229,6 +241,13 @@ impl CommanderBackend for LocalBackend {
         Ok(self.service.get_session_detail(query, lines).await?)
     }
 
+    async fn pane_content(&self, id: SessionId, lines: usize) -> BResult<Option<String>> {
+        Ok(self
+            .service
+            .get_pane_content(&id.to_string(), Some(lines))
+            .await?)
+    }
+
     async fn preview(&self, target: PreviewTarget) -> BResult<PreviewData> {
         Ok(self.service.preview(target).await?)
     }
@@ -291,6 +310,21 @@ impl CommanderBackend for LocalBackend {
         Ok(run_local(move || async move { svc.delete_session(&id).await }).await?)
     }
 
+    async fn archive_session(&self, id: SessionId) -> BResult<()> {
+        let svc = self.service.clone();
+        Ok(run_local(move || async move { svc.archive_session(&id).await }).await?)
+    }
+
+    async fn restore_session(&self, id: SessionId) -> BResult<()> {
+        let svc = self.service.clone();
+        Ok(run_local(move || async move { svc.restore_session(&id).await }).await?)
+    }
+
+    async fn undo(&self) -> BResult<String> {
+        let svc = self.service.clone();
+        Ok(run_local(move || async move { svc.undo().await }).await?)
+    }
+
     async fn rename_session(&self, id: SessionId, title: String) -> BResult<()> {
         // Store-only mutation → `Send`, delegate directly.
         Ok(self.service.rename_session(&id, title).await?)
@@ -306,6 +340,14 @@ impl CommanderBackend for LocalBackend {
         Ok(self.service.set_section(&id, section).await?)
     }
 
+    async fn set_tags(&self, id: SessionId, tags: Vec<String>) -> BResult<()> {
+        Ok(self.service.set_tags(&id, tags).await?)
+    }
+
+    async fn set_notes(&self, id: SessionId, notes: String) -> BResult<()> {
+        Ok(self.service.set_notes(&id, notes).await?)
+    }
+
     async fn mark_read(&self, id: SessionId) -> BResult<()> {
         Ok(self.service.mark_read(&id).await?)
     }
@@ -318,6 +360,22 @@ impl CommanderBackend for LocalBackend {
         Ok(self.service.mark_unread(ids).await?)
     }
 
+    async fn run_tasks(&self, id: SessionId, name: Option<String>) -> BResult<Vec<TaskStart>> {
+        Ok(self.service.run_tasks(&id, name.as_deref()).await?)
+    }
+
+    async fn send_task_failure(&self, id: SessionId, name: Option<String>) -> BResult<String> {
+        Ok(self.service.send_task_failure(&id, name.as_deref()).await?)
+    }
+
+    async fn search_scrollback(&self, search: ScrollbackSearch) -> BResult<Vec<ScrollbackHit>> {
+        Ok(self.service.search_scrollback(&search).await?)
+    }
+
+    async fn reveal_scrollback_line(&self, id: SessionId, reveal: ScrollbackReveal) -> BResult<()> {
+        Ok(self.service.reveal_scrollback_line(&id, &reveal).await?)
+    }
+
     async fn apply_pr_results(
         &self,
         results: Vec<(SessionId, crate::git::PrCheckResult)>,
@@ -627,6 +685,21 @@ mod tests {
         assert_eq!(state.get_session(&sid).unwrap().title, "renamed");
     }
 
+    #[tokio::test]
+    async fn set_tags_and_notes_delegate() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let be = backend(&dir);
+        let (_pid, sid) = seed(&be).await;
+        be.set_tags(sid, vec!["WIP".to_string()]).await.unwrap();
+        be.set_notes(sid, "check the flaky test".to_string())
+            .await
+            .unwrap();
+        let state = be.service().store().read().await;
+        let s = state.get_session(&sid).unwrap();
+        assert_eq!(s.tags, vec!["wip"]);
+        assert_eq!(s.notes, "check the flaky test");
+    }
+
     #[tokio::test]
     async fn rename_missing_session_maps_to_not_found() {
         let dir = tempfile::TempDir::new().unwrap();
@@ -25,6 +25,8 @@ use crate::session::{ProjectId, ScanResult, SessionId};
 use claude_commander_protocol::github::{
     CloneJob, CloneJobId, CloneRequest, CloneSource, CloneStatus, GithubRepo, redact_credentials,
 };
+use claude_commander_protocol::scrollback::{ScrollbackHit, ScrollbackReveal, ScrollbackSearch};
+use claude_commander_protocol::task::TaskStart;
 
 use super::{
     AttachConnection, AttachKind, BResult, BackendCapabilities, BackendChangeFeed,
@@ -40,6 +42,12 @@ pub struct MockBackend {
     fail: Mutex<bool>,
     /// Sessions passed to [`Self::delete_session`], for call-recording asserts.
     deleted: Mutex<Vec<SessionId>>,
+    /// Sessions passed to [`Self::archive_session`], for call-recording asserts.
+    archived: Mutex<Vec<SessionId>>,
+    /// Sessions passed to [`Self::restore_session`], for call-recording asserts.
+    restored: Mutex<Vec<SessionId>>,
+    /// Number of [`Self::undo`] calls, for call-recording asserts.
+    undos: Mutex<usize>,
     /// Options passed to [`Self::create_session`], for call-recording asserts.
     created: Mutex<Vec<CreateSessionOpts>>,
     /// Sessions passed to [`Self::reconcile_one_section`], for routing asserts.
@@ -53,6 +61,9 @@ pub struct MockBackend {
     /// `(session, program)` pairs passed to [`Self::change_program`], for
     /// call-recording asserts.
     program_changes: Mutex<Vec<(SessionId, String)>>,
+    /// `(session, task)` pairs passed to [`Self::run_tasks`], for
+    /// call-recording asserts.
+    task_runs: Mutex<Vec<(SessionId, Option<String>)>>,
     /// Count of [`Self::request_pr_refresh`] calls, for call-recording asserts.
     pr_refresh_calls: Mutex<usize>,
     /// Sessions passed to [`Self::mark_read`], for call-recording asserts.
@@ -99,6 +110,11 @@ pub struct MockBackend {
     /// answers with the first id rather than a fresh one — the contract a real
     /// backend's `POST /projects/ensure` provides.
     ensured_ids: Mutex<HashMap<std::path::PathBuf, ProjectId>>,
+    /// Hits served by [`Self::search_scrollback`] (every search gets them all),
+    /// set by [`Self::set_scrollback_hits`].
+    scrollback_hits: Mutex<Vec<ScrollbackHit>>,
+    /// `(session, reveal)` pairs passed to [`Self::reveal_scrollback_line`].
+    scrollback_reveals: Mutex<Vec<(SessionId, ScrollbackReveal)>>,
     conn_tx: watch::Sender<ConnectionState>,
     conn_rx: watch::Receiver<ConnectionState>,
     gen_tx: watch::Sender<u64>,
@@ -119,15 +135,20 @@ impl MockBackend {
             states: Mutex::new(AgentStatesSnapshot {
                 states: Default::default(),
                 commander_running: false,
+                viewers: Default::default(),
             }),
             branches: Mutex::new(Vec::new()),
             fail: Mutex::new(false),
             deleted: Mutex::new(Vec::new()),
+            archived: Mutex::new(Vec::new()),
+            restored: Mutex::new(Vec::new()),
+            undos: Mutex::new(0),
             created: Mutex::new(Vec::new()),
             reconciled: Mutex::new(Vec::new()),
             restarted: Mutex::new(Vec::new()),
             reset: Mutex::new(Vec::new()),
             program_changes: Mutex::new(Vec::new()),
+            task_runs: Mutex::new(Vec::new()),
             pr_refresh_calls: Mutex::new(0),
             read_marked: Mutex::new(Vec::new()),
             mark_read_gate: Mutex::new(None),
@@ -144,6 +165,8 @@ impl MockBackend {
             added_projects: Mutex::new(Vec::new()),
             ensured_projects: Mutex::new(Vec::new()),
             ensured_ids: Mutex::new(HashMap::new()),
+            scrollback_hits: Mutex::new(Vec::new()),
+            scrollback_reveals: Mutex::new(Vec::new()),
             conn_tx,
             conn_rx,
             gen_tx,
@@ -179,6 +202,21 @@ impl MockBackend {
         self.deleted.lock().unwrap().clone()
     }
 
+    /// Session ids passed to [`Self::archive_session`], in call order.
+    pub fn archived_sessions(&self) -> Vec<SessionId> {
+        self.archived.lock().unwrap().clone()
+    }
+
+    /// Session ids passed to [`Self::restore_session`], in call order.
+    pub fn restored_sessions(&self) -> Vec<SessionId> {
+        self.restored.lock().unwrap().clone()
+    }
+
+    /// How many times [`Self::undo`] was called.
+    pub fn undo_calls(&self) -> usize {
+        *self.undos.lock().unwrap()
+    }
+
     /// Options passed to [`Self::create_session`], in call order.
     pub fn created_sessions(&self) -> Vec<CreateSessionOpts> {
         self.created.lock().unwrap().clone()
@@ -194,6 +232,11 @@ impl MockBackend {
         self.program_changes.lock().unwrap().clone()
     }
 
+    /// `(session, task)` pairs passed to [`Self::run_tasks`], in call order.
+    pub fn task_runs(&self) -> Vec<(SessionId, Option<String>)> {
+        self.task_runs.lock().unwrap().clone()
+    }
+
     /// Sessions passed to [`Self::restart_session`], in call order.
     pub fn restarted_sessions(&self) -> Vec<SessionId> {
         self.restarted.lock().unwrap().clone()
@@ -273,6 +316,16 @@ impl MockBackend {
         self.ensured_projects.lock().unwrap().clone()
     }
 
+    /// Set the hits served by [`Self::search_scrollback`].
+    pub fn set_scrollback_hits(&self, hits: Vec<ScrollbackHit>) {
+        *self.scrollback_hits.lock().unwrap() = hits;
+    }
+
+    /// `(session, reveal)` pairs passed to [`Self::reveal_scrollback_line`].
+    pub fn scrollback_reveals(&self) -> Vec<(SessionId, ScrollbackReveal)> {
+        self.scrollback_reveals.lock().unwrap().clone()
+    }
+
     /// Force an issued job's status, so a test can drive a poll loop to a
     /// terminal outcome (success, failure, occupied destination) without a real
     /// clone. Ignores an id this mock never issued.
@@ -353,6 +406,11 @@ impl CommanderBackend for MockBackend {
         Ok(None)
     }
 
+    async fn pane_content(&self, _id: SessionId, _lines: usize) -> BResult<Option<String>> {
+        self.guard()?;
+        Ok(None)
+    }
+
     async fn preview(&self, _target: PreviewTarget) -> BResult<PreviewData> {
         self.unimpl()
     }
@@ -433,6 +491,24 @@ impl CommanderBackend for MockBackend {
         Ok(())
     }
 
+    async fn archive_session(&self, id: SessionId) -> BResult<()> {
+        self.guard()?;
+        self.archived.lock().unwrap().push(id);
+        Ok(())
+    }
+
+    async fn restore_session(&self, id: SessionId) -> BResult<()> {
+        self.guard()?;
+        self.restored.lock().unwrap().push(id);
+        Ok(())
+    }
+
+    async fn undo(&self) -> BResult<String> {
+        self.guard()?;
+        *self.undos.lock().unwrap() += 1;
+        Ok("deleted session \"mock\"".to_string())
+    }
+
     async fn rename_session(&self, _id: SessionId, _title: String) -> BResult<()> {
         self.guard()
     }
@@ -447,11 +523,44 @@ impl CommanderBackend for MockBackend {
         self.guard()
     }
 
+    async fn set_tags(&self, _id: SessionId, _tags: Vec<String>) -> BResult<()> {
+        self.guard()
+    }
+
+    async fn set_notes(&self, _id: SessionId, _notes: String) -> BResult<()> {
+        self.guard()
+    }
+
     async fn toggle_keep_alive(&self, _id: SessionId) -> BResult<bool> {
         self.guard()?;
         Ok(true)
     }
 
+    async fn run_tasks(&self, id: SessionId, name: Option<String>) -> BResult<Vec<TaskStart>> {
+        self.guard()?;
+        self.task_runs.lock().unwrap().push((id, name.clone()));
+        Ok(name
+            .into_iter()
+            .map(|name| TaskStart { name, error: None })
+            .collect())
+    }
+
+    async fn send_task_failure(&self, _id: SessionId, name: Option<String>) -> BResult<String> {
+        self.guard()?;
+        name.ok_or(BackendError::NotFound)
+    }
+
+    async fn search_scrollback(&self, _search: ScrollbackSearch) -> BResult<Vec<ScrollbackHit>> {
+        self.guard()?;
+        Ok(self.scrollback_hits.lock().unwrap().clone())
+    }
+
+    async fn reveal_scrollback_line(&self, id: SessionId, reveal: ScrollbackReveal) -> BResult<()> {
+        self.guard()?;
+        self.scrollback_reveals.lock().unwrap().push((id, reveal));
+        Ok(())
+    }
+
     async fn mark_read(&self, id: SessionId) -> BResult<()> {
         self.guard()?;
         let gate = self.mark_read_gate.lock().unwrap().clone();
@@ -45,11 +45,13 @@ use uuid::Uuid;
 use crate::api::{
     AgentStatesSnapshot, BranchInfo, CreateOptions, CreateSessionOpts, DiffSide, NewComment,
     OperationStatus, PreviewData, PreviewTarget, ProgramInfo, ReviewSnapshot, ServerStatus,
-    SessionDetail, WorkspaceSnapshot,
+    SessionDetail, SessionInfo, WorkspaceSnapshot,
 };
 use crate::comment::ApplyOutcome;
 use crate::session::{ProjectId, SessionId};
 use claude_commander_protocol::github::{CloneJob, CloneJobId, CloneRequest, GithubRepo};
+use claude_commander_protocol::scrollback::{ScrollbackHit, ScrollbackReveal, ScrollbackSearch};
+use claude_commander_protocol::task::TaskStart;
 
 pub use error::{BResult, BackendError};
 pub use local::LocalBackend;
@@ -211,7 +213,12 @@ pub use claude_commander_protocol::connection::ConnectionState;
 /// synchronously (no `.await` on the hot path).
 #[derive(Debug, Clone)]
 pub struct BackendView {
+    /// The live workspace, archived sessions removed (see
+    /// [`Self::set_snapshot`]).
     pub snapshot: WorkspaceSnapshot,
+    /// Archived sessions, split out of `snapshot` so the tree, board, grid and
+    /// palette never see them; only the Archived view lists these.
+    pub archived: Vec<SessionInfo>,
     pub agent_states: AgentStatesSnapshot,
     pub connection: ConnectionState,
 }
@@ -222,13 +229,34 @@ impl BackendView {
     pub fn connecting() -> Self {
         Self {
             snapshot: empty_snapshot(),
+            archived: Vec::new(),
             agent_states: AgentStatesSnapshot {
                 states: Default::default(),
                 commander_running: false,
+                viewers: Default::default(),
             },
             connection: ConnectionState::Connecting,
         }
     }
+
+    /// Install a fresh snapshot, moving its archived sessions (and their ids
+    /// in each project's `session_ids`) into [`Self::archived`]. Projects are
+    /// kept even when every session is archived.
+    pub fn set_snapshot(&mut self, mut snapshot: WorkspaceSnapshot) {
+        let (archived, live): (Vec<_>, Vec<_>) = std::mem::take(&mut snapshot.sessions)
+            .into_iter()
+            .partition(|s| s.archived.is_some());
+        if !archived.is_empty() {
+            let ids: std::collections::HashSet<SessionId> =
+                archived.iter().map(|s| s.session_id).collect();
+            for project in &mut snapshot.projects {
+                project.session_ids.retain(|id| !ids.contains(id));
+            }
+        }
+        snapshot.sessions = live;
+        self.snapshot = snapshot;
+        self.archived = archived;
+    }
 }
 
 /// An empty [`WorkspaceSnapshot`] placeholder (no projects/sessions). Used to
@@ -565,6 +593,11 @@ pub trait CommanderBackend: Send + Sync {
         lines: Option<usize>,
     ) -> BResult<Option<SessionDetail>>;
 
+    /// The last `lines` lines of a session's agent pane, without the diff and
+    /// shell captures [`Self::preview`] also takes. `None` when the session has
+    /// no live pane.
+    async fn pane_content(&self, id: SessionId, lines: usize) -> BResult<Option<String>>;
+
     /// Preview payload for a session or project (agent pane, diff, shell pane).
     async fn preview(&self, target: PreviewTarget) -> BResult<PreviewData>;
 
@@ -604,6 +637,16 @@ pub trait CommanderBackend: Send + Sync {
     /// doing the opposite.
     async fn restart_session_fresh(&self, id: SessionId) -> BResult<()>;
     async fn delete_session(&self, id: SessionId) -> BResult<()>;
+    /// Archive a session: stop it and remove its worktree, keeping the branch,
+    /// the session row and any uncommitted work. Hidden from the tree until
+    /// [`restore_session`](Self::restore_session).
+    async fn archive_session(&self, id: SessionId) -> BResult<()>;
+    /// Restore an archived session: recreate its worktree, re-apply the kept
+    /// uncommitted work, and relaunch the agent resumed.
+    async fn restore_session(&self, id: SessionId) -> BResult<()>;
+    /// Undo the most recent session delete, project removal or cascade abandon
+    /// on this host, returning what was undone.
+    async fn undo(&self) -> BResult<String>;
     async fn rename_session(&self, id: SessionId, title: String) -> BResult<()>;
     /// Change a session's launch program (the agent harness that runs) and
     /// relaunch its pane fresh so the new program takes effect. Runs on the
@@ -612,6 +655,10 @@ pub trait CommanderBackend: Send + Sync {
     async fn change_program(&self, id: SessionId, program: String) -> BResult<()>;
     /// Move a session to `section`, or clear its manual override (`None`).
     async fn set_section(&self, id: SessionId, section: Option<String>) -> BResult<()>;
+    /// Replace a session's tags (normalised by the owning host).
+    async fn set_tags(&self, id: SessionId, tags: Vec<String>) -> BResult<()>;
+    /// Replace a session's free-form notes; an empty string clears them.
+    async fn set_notes(&self, id: SessionId, notes: String) -> BResult<()>;
     /// Clear a session's unread flag.
     async fn mark_read(&self, id: SessionId) -> BResult<()>;
     /// Flip a session's keep-alive (hibernation-exempt) flag; returns the new
@@ -621,6 +668,21 @@ pub trait CommanderBackend: Send + Sync {
     /// Mark a batch of sessions unread (paired with [`Self::mark_read`]).
     async fn mark_unread(&self, ids: Vec<SessionId>) -> BResult<()>;
 
+    /// Start project task `name` — every task available to the session when
+    /// `None` — in its task window on the owning host. Returns one result per
+    /// task attempted.
+    async fn run_tasks(&self, id: SessionId, name: Option<String>) -> BResult<Vec<TaskStart>>;
+    /// Send a failed task's output to the session's agent: the named task's
+    /// failure, or the most recent one when `None`. Returns the task sent.
+    async fn send_task_failure(&self, id: SessionId, name: Option<String>) -> BResult<String>;
+
+    /// Search the scrollback of every session on this host. Hits come newest
+    /// first per session; a bad regex is an [`BackendError::InvalidRequest`].
+    async fn search_scrollback(&self, search: ScrollbackSearch) -> BResult<Vec<ScrollbackHit>>;
+    /// Put a session's pane in copy-mode at a search hit's line, ahead of an
+    /// attach. [`BackendError::NotFound`] once the line has left the history.
+    async fn reveal_scrollback_line(&self, id: SessionId, reveal: ScrollbackReveal) -> BResult<()>;
+
     /// Upload a pasted image (PNG bytes) for a session and inject its file path
     /// into the agent pane. Only meaningful for backends whose
     /// [`Self::capabilities`] set `client_side_image_paste` (i.e. remote): the
@@ -798,6 +860,41 @@ mod tests {
         assert_eq!(v.connection, ConnectionState::Connecting);
     }
 
+    #[test]
+    fn set_snapshot_splits_archived_sessions_out() {
+        use crate::session::{ArchiveInfo, WorktreeSession};
+        let pid = ProjectId::new();
+        let live = WorktreeSession::new(pid, "live", "live", "/tmp/a".into(), "claude");
+        let mut shelved = WorktreeSession::new(pid, "old", "old", "/tmp/b".into(), "claude");
+        shelved.archived = Some(ArchiveInfo {
+            archived_at: chrono::Utc::now(),
+            head: "abc".to_string(),
+            archive_ref: "refs/commander/archive/x".to_string(),
+            has_snapshot: false,
+        });
+        let mut snapshot = empty_snapshot();
+        snapshot.projects.push(crate::api::ProjectInfo {
+            id: pid,
+            name: "p".to_string(),
+            repo_path: "/tmp".into(),
+            main_branch: "main".to_string(),
+            session_ids: vec![live.id, shelved.id],
+            origin_url: None,
+        });
+        snapshot.sessions = vec![
+            crate::api::session_info_from_session(&live, "p"),
+            crate::api::session_info_from_session(&shelved, "p"),
+        ];
+
+        let mut v = BackendView::connecting();
+        v.set_snapshot(snapshot);
+        assert_eq!(v.snapshot.sessions.len(), 1);
+        assert_eq!(v.snapshot.sessions[0].session_id, live.id);
+        assert_eq!(v.snapshot.projects[0].session_ids, vec![live.id]);
+        assert_eq!(v.archived.len(), 1);
+        assert_eq!(v.archived[0].session_id, shelved.id);
+    }
+
     #[test]
     fn version_mismatch_flags_older_server() {
         // Server behind at the minor level, or the major level, warns.
@@ -25,6 +25,8 @@ use crate::api::{
 use crate::comment::{ApplyOutcome, Comment};
 use crate::session::{ProjectId, ScanResult, SessionId};
 use claude_commander_protocol::github::{CloneJob, CloneJobId, CloneRequest, GithubRepo};
+use claude_commander_protocol::scrollback::{ScrollbackHit, ScrollbackReveal, ScrollbackSearch};
+use claude_commander_protocol::task::TaskStart;
 
 use super::{
     AttachConnection, AttachKind, BResult, BackendCapabilities, BackendChangeFeed,
@@ -104,6 +106,10 @@ impl CommanderBackend for PlaceholderBackend {
         self.unavailable()
     }
 
+    async fn pane_content(&self, _id: SessionId, _lines: usize) -> BResult<Option<String>> {
+        self.unavailable()
+    }
+
     async fn preview(&self, _target: PreviewTarget) -> BResult<PreviewData> {
         self.unavailable()
     }
@@ -148,6 +154,18 @@ impl CommanderBackend for PlaceholderBackend {
         self.unavailable()
     }
 
+    async fn archive_session(&self, _id: SessionId) -> BResult<()> {
+        self.unavailable()
+    }
+
+    async fn restore_session(&self, _id: SessionId) -> BResult<()> {
+        self.unavailable()
+    }
+
+    async fn undo(&self) -> BResult<String> {
+        self.unavailable()
+    }
+
     async fn rename_session(&self, _id: SessionId, _title: String) -> BResult<()> {
         self.unavailable()
     }
@@ -160,6 +178,14 @@ impl CommanderBackend for PlaceholderBackend {
         self.unavailable()
     }
 
+    async fn set_tags(&self, _id: SessionId, _tags: Vec<String>) -> BResult<()> {
+        self.unavailable()
+    }
+
+    async fn set_notes(&self, _id: SessionId, _notes: String) -> BResult<()> {
+        self.unavailable()
+    }
+
     async fn mark_read(&self, _id: SessionId) -> BResult<()> {
         self.unavailable()
     }
@@ -172,6 +198,26 @@ impl CommanderBackend for PlaceholderBackend {
         self.unavailable()
     }
 
+    async fn run_tasks(&self, _id: SessionId, _name: Option<String>) -> BResult<Vec<TaskStart>> {
+        self.unavailable()
+    }
+
+    async fn send_task_failure(&self, _id: SessionId, _name: Option<String>) -> BResult<String> {
+        self.unavailable()
+    }
+
+    async fn search_scrollback(&self, _search: ScrollbackSearch) -> BResult<Vec<ScrollbackHit>> {
+        self.unavailable()
+    }
+
+    async fn reveal_scrollback_line(
+        &self,
+        _id: SessionId,
+        _reveal: ScrollbackReveal,
+    ) -> BResult<()> {
+        self.unavailable()
+    }
+
     async fn request_pr_refresh(&self) -> BResult<()> {
         self.unavailable()
     }
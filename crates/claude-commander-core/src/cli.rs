@@ -3,6 +3,7 @@
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 
+use crate::api::ResourceUsage;
 use crate::config::AppState;
 use crate::git::{PrState, ReviewDecision, effective_pr_state};
 use crate::session::{AgentState, WorktreeSession};
@@ -177,6 +178,8 @@ pub struct SessionJsonEntry {
     pub title: String,
     pub branch: String,
     pub status: String,
+    /// Whether the session is archived (its status is then `stopped`).
+    pub archived: bool,
     pub program: String,
     pub project_name: String,
     pub pr_number: Option<u32>,
@@ -186,6 +189,8 @@ pub struct SessionJsonEntry {
     pub pr_state: PrState,
     pub pr_draft: bool,
     pub pr_labels: Vec<String>,
+    pub tags: Vec<String>,
+    pub notes: String,
     pub created_at: DateTime<Utc>,
 }
 
@@ -196,6 +201,7 @@ impl SessionJsonEntry {
             title: info.title.clone(),
             branch: info.branch.clone(),
             status: info.status.to_string(),
+            archived: info.archived.is_some(),
             program: info.program.clone(),
             project_name: info.project_name.clone(),
             pr_number: info.pr_number,
@@ -203,6 +209,8 @@ impl SessionJsonEntry {
             pr_state: info.pr_state,
             pr_draft: info.pr_draft,
             pr_labels: info.pr_labels.clone(),
+            tags: info.tags.clone(),
+            notes: info.notes.clone(),
             created_at: info.created_at,
         }
     }
@@ -213,6 +221,7 @@ impl SessionJsonEntry {
             title: session.title.clone(),
             branch: session.branch.clone(),
             status: session.status.to_string(),
+            archived: session.archived.is_some(),
             program: session.program.clone(),
             project_name: project_name.to_string(),
             pr_number: session.pr_number,
@@ -220,6 +229,8 @@ impl SessionJsonEntry {
             pr_state: effective_pr_state(session.pr_state, session.pr_merged),
             pr_draft: session.pr_draft,
             pr_labels: session.pr_labels.clone(),
+            tags: session.tags.clone(),
+            notes: session.notes.clone(),
             created_at: session.created_at,
         }
     }
@@ -260,6 +271,8 @@ pub struct StatusJsonEntry {
     pub review_decision: Option<ReviewDecision>,
     pub pr_reviewers: Vec<String>,
     pub created_at: DateTime<Utc>,
+    /// Live RSS/CPU of the session's pane (`None` when not running).
+    pub resources: Option<ResourceUsage>,
 }
 
 impl StatusJsonEntry {
@@ -282,6 +295,7 @@ impl StatusJsonEntry {
             review_decision: info.review_decision,
             pr_reviewers: info.pr_reviewers.clone(),
             created_at: info.created_at,
+            resources: detail.resources,
         }
     }
 
@@ -308,10 +322,28 @@ impl StatusJsonEntry {
             review_decision: session.review_decision,
             pr_reviewers: session.pr_reviewers.clone(),
             created_at: session.created_at,
+            resources: None,
         }
     }
 }
 
+/// One-line resource summary, e.g. `412.3 MiB RSS, 37% CPU (scoped)`. Without a
+/// utilisation figure the CPU part falls back to cumulative CPU time.
+pub fn format_resource_usage(usage: &ResourceUsage) -> String {
+    let rss = usage.rss_bytes as f64 / (1024.0 * 1024.0);
+    let rss = if rss >= 1024.0 {
+        format!("{:.1} GiB", rss / 1024.0)
+    } else {
+        format!("{rss:.1} MiB")
+    };
+    let cpu = match usage.cpu_percent {
+        Some(pct) => format!("{pct:.0}% CPU"),
+        None => format!("{:.1}s CPU", usage.cpu_time_ms as f64 / 1000.0),
+    };
+    let scope = if usage.scoped { " (scoped)" } else { "" };
+    format!("{rss} RSS, {cpu}{scope}")
+}
+
 /// Format a human-readable status summary for a session.
 pub fn format_status_human(entry: &StatusJsonEntry) -> String {
     let mut lines = Vec::new();
@@ -330,6 +362,10 @@ pub fn format_status_human(entry: &StatusJsonEntry) -> String {
         lines.push(format!("Diff:    {}", stat.trim()));
     }
 
+    if let Some(ref usage) = entry.resources {
+        lines.push(format!("Usage:   {}", format_resource_usage(usage)));
+    }
+
     if let Some(pr) = entry.pr_number {
         let url = entry.pr_url.as_deref().unwrap_or("(no url)");
         lines.push(format!(
@@ -770,6 +806,32 @@ mod tests {
         assert!(!output.contains("PR:"));
         assert!(!output.contains("Review:"));
         assert!(!output.contains("Labels:"));
+        assert!(!output.contains("Usage:"));
+    }
+
+    #[test]
+    fn human_format_shows_resource_usage() {
+        let session = make_session("busy");
+        let mut entry = StatusJsonEntry::from_session(&session, "proj", AgentState::Working, None);
+        entry.resources = Some(ResourceUsage {
+            rss_bytes: 512 * 1024 * 1024,
+            cpu_time_ms: 90_000,
+            cpu_percent: Some(37.4),
+            scoped: true,
+        });
+        let output = format_status_human(&entry);
+        assert!(output.contains("Usage:   512.0 MiB RSS, 37% CPU (scoped)"));
+    }
+
+    #[test]
+    fn resource_usage_falls_back_to_cpu_time() {
+        let usage = ResourceUsage {
+            rss_bytes: 3 * 1024 * 1024 * 1024,
+            cpu_time_ms: 2500,
+            cpu_percent: None,
+            scoped: false,
+        };
+        assert_eq!(format_resource_usage(&usage), "3.0 GiB RSS, 2.5s CPU");
     }
 
     #[test]
@@ -224,6 +224,12 @@ pub struct Config {
     /// Interval in milliseconds for polling agent state (Working/Idle/Waiting) (0 = disabled)
     pub agent_state_poll_interval_ms: u64,
 
+    /// Interval in seconds for indexing running sessions' tmux scrollback for
+    /// search (the palette's scrollback mode, `claude-commander grep`). Only
+    /// new history is captured per pass. (0 = disabled; searches then index
+    /// on demand)
+    pub scrollback_index_interval_secs: u64,
+
     /// Enable automatic hibernation of idle sessions: a background loop stops
     /// the tmux process (freeing ~400MB per idle `claude`) for sessions that
     /// have been idle past `hibernate_idle_timeout_secs`, keeping the worktree
@@ -332,6 +338,11 @@ pub struct Config {
     #[serde(default)]
     pub in_progress_limit: Option<u32>,
 
+    /// Advisory WIP limit for each board swimlane's share of "In Progress"
+    /// (the catch-all's counterpart to `SectionConfig::lane_max_sessions`).
+    #[serde(default)]
+    pub in_progress_lane_limit: Option<u32>,
+
     /// Number of most-recently-attached sessions to surface in the "Recent"
     /// block at the top of the session list (across all servers). `0` hides
     /// the block entirely. Default 5.
@@ -355,6 +366,43 @@ pub struct Config {
     #[serde(default)]
     pub commander_dir: Option<PathBuf>,
 
+    /// Per-session resource limits (CPU, memory, process count) applied by
+    /// launching each agent pane in its own systemd scope, plus the
+    /// memory-pressure hibernation threshold. See [`ResourceLimitsConfig`].
+    #[serde(default)]
+    pub resource_limits: ResourceLimitsConfig,
+
+    /// Shell commands run at session lifecycle events (post-create,
+    /// pre-delete, post-merge, agent-state and PR-state changes). A project's
+    /// `.commander.toml` can add its own. See [`HooksConfig`].
+    #[serde(default)]
+    pub hooks: HooksConfig,
+
+    /// Per-session port blocks: each session gets its own range of free ports,
+    /// exported to its panes as `PORT` / `COMMANDER_PORT_<n>` and written to a
+    /// `.env` overlay in the worktree. See [`PortsConfig`].
+    #[serde(default)]
+    pub ports: PortsConfig,
+
+    /// Named project tasks (`test = "cargo test"`), run on demand in each
+    /// session's task window with their pass/fail status tracked. A project's
+    /// `.commander.toml` `[tasks]` table adds to (and overrides) these.
+    #[serde(default)]
+    pub tasks: std::collections::BTreeMap<String, String>,
+
+    /// SHA-256 digests of the `.commander.toml` files whose hooks and tasks
+    /// may run, recorded by `claude-commander trust`. A project file whose
+    /// exact contents aren't listed is ignored, so editing a trusted file
+    /// needs a fresh `trust`.
+    #[serde(default)]
+    pub trusted_project_files: Vec<String>,
+
+    /// User-defined command palette entries (`[[custom_commands]]`): shell
+    /// command templates run against the selected session. Bind one under
+    /// `[keybindings.custom]` by its name. See [`CustomCommand`].
+    #[serde(default)]
+    pub custom_commands: Vec<CustomCommand>,
+
     /// Conversation mode (TTS): speak the commander's replies aloud via an
     /// OpenAI-compatible TTS engine. Disabled by default.
     #[serde(default)]
@@ -366,6 +414,12 @@ pub struct Config {
     #[serde(default)]
     pub stt: SttConfig,
 
+    /// Spoken announcements of session state changes (a session waiting for
+    /// input, CI failing, a PR merged, …), read aloud with the conversation
+    /// TTS settings. Disabled by default. See [`AnnouncementsConfig`].
+    #[serde(default)]
+    pub announcements: AnnouncementsConfig,
+
     /// Usage-telemetry settings (on by default, opt-out). See [`TelemetryConfig`].
     #[serde(default)]
     pub telemetry: TelemetryConfig,
@@ -378,6 +432,304 @@ pub struct Config {
     pub remote_servers: Vec<RemoteServerConfig>,
 }
 
+/// Per-session resource limits.
+///
+/// When `enabled`, each agent pane is launched through `systemd-run --user
+/// --scope`, so the agent and everything it spawns (builds, test runners, dev
+/// servers) share one cgroup with the configured ceilings. Requires a systemd
+/// user manager; without one (or off Linux) sessions launch unwrapped. Usage
+/// (RSS and CPU) is reported either way — from the scope's cgroup when there
+/// is one, otherwise by walking the pane's process tree.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResourceLimitsConfig {
+    /// Launch a session's agent pane, shell pane and task windows each inside
+    /// a systemd scope carrying the limits below. Off by default.
+    pub enabled: bool,
+
+    /// CPU ceiling as a percentage of one core (`200` = two cores), passed as
+    /// `CPUQuota=`. `None` leaves CPU unlimited.
+    pub cpu_quota_percent: Option<u32>,
+
+    /// Memory ceiling in systemd size syntax (e.g. `"4G"`, `"512M"`), passed as
+    /// `MemoryMax=`. `None` leaves memory unlimited.
+    pub memory_max: Option<String>,
+
+    /// Maximum number of tasks (processes + threads) in the scope, passed as
+    /// `TasksMax=`. `None` leaves it unlimited.
+    pub pids_max: Option<u32>,
+
+    /// When the host's available memory drops below this percentage of total,
+    /// the hibernation loop hibernates the largest idle sessions (by RSS) until
+    /// the estimate recovers. Works without scopes, but only runs while
+    /// `hibernate_enabled` is on. `0` disables. Default 10.
+    #[serde(default = "default_memory_pressure_percent")]
+    pub memory_pressure_percent: u8,
+}
+
+impl Default for ResourceLimitsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            cpu_quota_percent: None,
+            memory_max: None,
+            pids_max: None,
+            memory_pressure_percent: default_memory_pressure_percent(),
+        }
+    }
+}
+
+/// Lifecycle hooks: shell commands run when a session reaches a lifecycle
+/// event.
+///
+/// Each command runs via `sh -c` in the session's worktree, with
+/// `COMMANDER_EVENT`, `COMMANDER_SESSION_ID`, `COMMANDER_SESSION_TITLE`,
+/// `COMMANDER_BRANCH`, `COMMANDER_WORKTREE` and `COMMANDER_PROJECT` set (plus
+/// `COMMANDER_FROM`/`COMMANDER_TO` for state changes). Commands for one event
+/// run in order; a project's `.commander.toml` `[hooks]` lists run after these.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// Run once the worktree exists, before the agent is launched (e.g.
+    /// `npm install`, copying `.env`).
+    pub post_create: Vec<String>,
+
+    /// Run before a session's worktree is removed.
+    pub pre_delete: Vec<String>,
+
+    /// Run after a session's PR is merged, and after a cascade merge brings
+    /// new commits into its worktree.
+    pub post_merge: Vec<String>,
+
+    /// Run when the agent's detected state changes (`COMMANDER_FROM` /
+    /// `COMMANDER_TO` hold the states, e.g. `working` → `idle`).
+    pub agent_state_change: Vec<String>,
+
+    /// Run when the session's PR state changes (`COMMANDER_FROM` /
+    /// `COMMANDER_TO` hold the states, e.g. `open` → `merged`).
+    pub pr_state_change: Vec<String>,
+
+    /// Wall-clock limit per command; an overrunning hook is killed along with
+    /// everything it spawned. Default 300.
+    #[serde(default = "default_hook_timeout_secs")]
+    pub timeout_secs: u64,
+
+    /// What a failing `post_create` or `pre_delete` hook does. `warn` (default)
+    /// records the failure and carries on; `block` aborts the create or delete.
+    /// The other events never block — they run after the fact.
+    pub on_failure: HookFailurePolicy,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self {
+            post_create: Vec::new(),
+            pre_delete: Vec::new(),
+            post_merge: Vec::new(),
+            agent_state_change: Vec::new(),
+            pr_state_change: Vec::new(),
+            timeout_secs: default_hook_timeout_secs(),
+            on_failure: HookFailurePolicy::default(),
+        }
+    }
+}
+
+impl HooksConfig {
+    /// The commands configured for `event`, in run order.
+    pub fn commands(&self, event: crate::session::HookEvent) -> &[String] {
+        use crate::session::HookEvent;
+        match event {
+            HookEvent::PostCreate => &self.post_create,
+            HookEvent::PreDelete => &self.pre_delete,
+            HookEvent::PostMerge => &self.post_merge,
+            HookEvent::AgentStateChange => &self.agent_state_change,
+            HookEvent::PrStateChange => &self.pr_state_change,
+        }
+    }
+
+    /// These hooks with a project's `.commander.toml` hooks layered on top:
+    /// project commands run after the global ones, and the project's
+    /// `timeout_secs` / `on_failure` win when set.
+    pub fn with_project(&self, project: &crate::config::ProjectHooksConfig) -> HooksConfig {
+        let join = |global: &[String], local: &[String]| {
+            global.iter().chain(local).cloned().collect::<Vec<_>>()
+        };
+        HooksConfig {
+            post_create: join(&self.post_create, &project.post_create),
+            pre_delete: join(&self.pre_delete, &project.pre_delete),
+            post_merge: join(&self.post_merge, &project.post_merge),
+            agent_state_change: join(&self.agent_state_change, &project.agent_state_change),
+            pr_state_change: join(&self.pr_state_change, &project.pr_state_change),
+            timeout_secs: project.timeout_secs.unwrap_or(self.timeout_secs),
+            on_failure: project.on_failure.unwrap_or(self.on_failure),
+        }
+    }
+}
+
+/// Per-session port allocation.
+///
+/// When `enabled`, each session is given a block of `block_size` ports from
+/// `range_start..=range_end` that no other session holds and that are free on
+/// the host when allocated. The block is persisted on the session and stays
+/// the same across restarts; it is released when the session is deleted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PortsConfig {
+    /// Allocate a port block per session and inject it into the agent and
+    /// shell panes. Off by default.
+    pub enabled: bool,
+
+    /// Lowest port handed out. Default 20000.
+    #[serde(default = "default_ports_range_start")]
+    pub range_start: u16,
+
+    /// Highest port handed out. Default 29999.
+    #[serde(default = "default_ports_range_end")]
+    pub range_end: u16,
+
+    /// Ports per session. `PORT` is the first; all of them are exported as
+    /// `COMMANDER_PORT_0..N`. Default 5.
+    #[serde(default = "default_ports_block_size")]
+    pub block_size: u16,
+
+    /// Name of the dotenv overlay written into each worktree (and excluded from
+    /// git) with the same variables. Empty disables the overlay. Default
+    /// `.env.commander`.
+    #[serde(default = "default_ports_env_file")]
+    pub env_file: String,
+
+    /// Extra per-session variables, exported alongside the ports. Values may
+    /// use `{port}`, `{port_0}`..`{port_N}`, `{session_id}` and `{branch}`,
+    /// e.g. `DATABASE_URL = "postgres://localhost/app_{port}"`.
+    pub env: std::collections::BTreeMap<String, String>,
+}
+
+impl Default for PortsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            range_start: default_ports_range_start(),
+            range_end: default_ports_range_end(),
+            block_size: default_ports_block_size(),
+            env_file: default_ports_env_file(),
+            env: std::collections::BTreeMap::new(),
+        }
+    }
+}
+
+/// A user-defined command palette entry (`[[custom_commands]]`).
+///
+/// `command` is a shell template run through `sh -c` on this machine. Its
+/// placeholders expand to the selected session's values (see
+/// [`CommandVars`]), each already shell-quoted, so a template never wraps a
+/// placeholder in quotes of its own.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomCommand {
+    /// Palette label. Also the key that binds the command under
+    /// `[keybindings.custom]`.
+    pub name: String,
+    /// Shell command template, e.g. `xdg-open https://grafana/d/x?branch={branch}`.
+    pub command: String,
+    /// Where the command runs. Default `detached`.
+    #[serde(default)]
+    pub run: CustomCommandRun,
+}
+
+/// Where a [`CustomCommand`] runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomCommandRun {
+    /// In the background with its output discarded.
+    #[default]
+    Detached,
+    /// Typed into the session's shell pane, for commands you want to watch or
+    /// interact with. Local sessions only.
+    Shell,
+    /// Run to completion, then its output is shown in a modal.
+    Modal,
+}
+
+/// The values a [`CustomCommand`] template can use. Unset values (a session
+/// without a PR, say) expand to an empty string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandVars {
+    /// `{worktree}`: the session's worktree path (on the host that owns it).
+    pub worktree: String,
+    /// `{branch}`
+    pub branch: String,
+    /// `{title}`: the session's display title.
+    pub title: String,
+    /// `{session_id}`
+    pub session_id: String,
+    /// `{project}`: the project's name.
+    pub project: String,
+    /// `{pr_url}`
+    pub pr_url: String,
+    /// `{pr_number}`
+    pub pr_number: String,
+}
+
+impl CustomCommand {
+    /// The command line with every placeholder replaced by its shell-quoted
+    /// value. Unknown `{...}` sequences are left as they are.
+    pub fn expand(&self, vars: &CommandVars) -> String {
+        expand_template(
+            &self.command,
+            &[
+                ("{worktree}", &vars.worktree),
+                ("{branch}", &vars.branch),
+                ("{title}", &vars.title),
+                ("{session_id}", &vars.session_id),
+                ("{project}", &vars.project),
+                ("{pr_url}", &vars.pr_url),
+                ("{pr_number}", &vars.pr_number),
+            ],
+        )
+    }
+}
+
+/// Replace each placeholder in a shell command template with its shell-quoted
+/// value. Unknown `{...}` sequences are left as they are.
+pub(crate) fn expand_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    // Single left-to-right pass, so a value that itself contains a
+    // placeholder is never expanded again.
+    'scan: while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        for (placeholder, value) in values {
+            if tail.starts_with(placeholder) {
+                out.push_str(&shell_quote(value));
+                rest = &tail[placeholder.len()..];
+                continue 'scan;
+            }
+        }
+        out.push('{');
+        rest = &tail[1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Quote `value` as one POSIX shell word: wrapped in single quotes, with each
+/// embedded `'` closed, escaped and reopened. Used for every value spliced
+/// into a command line, placeholder expansions and launch commands alike.
+pub(crate) fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// What a failing blocking-capable hook does. See [`HooksConfig::on_failure`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookFailurePolicy {
+    /// Record the failure and continue.
+    #[default]
+    Warn,
+    /// Abort the create or delete the hook guards.
+    Block,
+}
+
 /// Conversation-mode (text-to-speech) settings.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -398,9 +750,33 @@ pub struct ConversationConfig {
     /// without interactive approval prompts (which it can't answer headlessly).
     pub permission_mode: String,
 
+    /// Directory the agent runs in. `None` runs it in its own scratch dir
+    /// under the data dir, where its instructions go in a generated
+    /// `CLAUDE.md`; anywhere else they're appended to the system prompt, so a
+    /// repo's own `CLAUDE.md` is left alone.
+    pub cwd: Option<PathBuf>,
+
+    /// File whose contents replace the built-in voice-assistant instructions
+    /// (`{name}` expands to the agent's name). The CLI reference is still
+    /// appended. `None` uses the built-in ones.
+    pub system_prompt_file: Option<PathBuf>,
+
+    /// Further named agents (`[[conversation.profiles]]`), each with its own
+    /// working directory, instructions, voice and permissions. The settings
+    /// above are the default agent's; a profile overrides what it sets.
+    pub profiles: Vec<ConversationProfile>,
+
     /// Base URL of the OpenAI-compatible TTS API (include the `/v1`).
     pub base_url: String,
 
+    /// Local TTS command, run once per sentence in place of the HTTP API
+    /// (e.g. `piper --model ~/voices/en_US-amy-medium.onnx --output_file
+    /// {output}` or `espeak-ng --stdout`). Run through `sh -c`; the sentence
+    /// arrives on stdin unless the template uses `{text}`, and the audio is
+    /// read from `{output}` if used, else from stdout. `{voice}` and `{speed}`
+    /// expand too. `None` uses `base_url`.
+    pub tts_command: Option<String>,
+
     /// Model name sent in each request (engines serving one model ignore it).
     pub model: String,
 
@@ -419,6 +795,11 @@ pub struct ConversationConfig {
 
     /// Playback volume (0.0–2.0; 1.0 = unchanged).
     pub volume: f32,
+
+    /// When the user cuts in on a reply (barge-in), also interrupt the agent's
+    /// running turn rather than only silencing it. Off by default: the agent
+    /// may be midway through acting on the board.
+    pub interrupt_turn: bool,
 }
 
 impl Default for ConversationConfig {
@@ -430,17 +811,86 @@ impl Default for ConversationConfig {
             name: "Claudette".to_string(),
             command: "claude".to_string(),
             permission_mode: "auto".to_string(),
+            cwd: None,
+            system_prompt_file: None,
+            profiles: Vec::new(),
             base_url: "http://127.0.0.1:8002/v1".to_string(),
+            tts_command: None,
             model: "kokoro".to_string(),
             voice: None,
             response_format: "wav".to_string(),
             speed: 1.0,
             speak_scope: crate::conversation::SpeakScope::ProseOnly,
             volume: 1.0,
+            interrupt_turn: false,
         }
     }
 }
 
+impl ConversationConfig {
+    /// The profile called `name` (case-insensitively).
+    pub fn profile(&self, name: &str) -> Option<&ConversationProfile> {
+        self.profiles
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The settings agent `profile` runs with: these, with the profile's
+    /// overrides applied. `None`, or a name with no profile, is the default
+    /// agent.
+    pub fn agent(&self, profile: Option<&str>) -> ConversationConfig {
+        let mut conv = self.clone();
+        let Some(p) = profile.and_then(|name| self.profile(name)) else {
+            return conv;
+        };
+        conv.name = p.name.clone();
+        conv.cwd = p.cwd.clone();
+        conv.system_prompt_file = p.system_prompt_file.clone();
+        if let Some(voice) = &p.voice {
+            conv.voice = Some(voice.clone());
+        }
+        if let Some(scope) = p.speak_scope {
+            conv.speak_scope = scope;
+        }
+        if let Some(mode) = &p.permission_mode {
+            conv.permission_mode = mode.clone();
+        }
+        conv
+    }
+}
+
+/// A named conversation agent (`[[conversation.profiles]]`). Unset fields fall
+/// back to the default agent's `[conversation]` settings — except `cwd` and
+/// `system_prompt_file`, which are the profile's own.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConversationProfile {
+    /// What the agent is called: its chat label, what it's told its name is,
+    /// and what `listen-toggle --agent` selects it by.
+    pub name: String,
+
+    /// Directory the agent runs in, e.g. the repo it plans for. `None` gives it
+    /// a scratch dir of its own.
+    #[serde(default)]
+    pub cwd: Option<PathBuf>,
+
+    /// File whose contents replace the built-in instructions.
+    #[serde(default)]
+    pub system_prompt_file: Option<PathBuf>,
+
+    /// TTS voice.
+    #[serde(default)]
+    pub voice: Option<String>,
+
+    /// How much of each reply to speak.
+    #[serde(default)]
+    pub speak_scope: Option<crate::conversation::SpeakScope>,
+
+    /// `--permission-mode` for this agent, e.g. `plan` for one that should only
+    /// look.
+    #[serde(default)]
+    pub permission_mode: Option<String>,
+}
+
 /// Speech-to-text (voice-input) settings.
 ///
 /// Mirrors [`ConversationConfig`] for the transcription side: an
@@ -457,6 +907,14 @@ pub struct SttConfig {
     /// Base URL of the OpenAI-compatible transcription API (include the `/v1`).
     pub base_url: String,
 
+    /// Local transcription command, run once per recording in place of the
+    /// HTTP API (e.g. whisper.cpp's `whisper-cli -m ~/models/ggml-base.en.bin
+    /// -f {input} -nt -np`). Run through `sh -c`; the recording is written to
+    /// the WAV file `{input}` (or piped to stdin when the template doesn't use
+    /// it) and the transcript is read from stdout. `{language}` and `{prompt}`
+    /// expand too. `None` uses `base_url`.
+    pub command: Option<String>,
+
     /// Model name sent with each request (engines serving one model ignore it).
     pub model: String,
 
@@ -485,6 +943,30 @@ pub struct SttConfig {
     /// device is absent at record time, capture falls back to the default (with
     /// a warning) rather than failing.
     pub input_device: Option<String>,
+
+    /// Run simple spoken requests ("attach to session four", "open review for
+    /// the auth branch", "what's waiting on me") directly in the TUI, with a
+    /// spoken confirmation, instead of sending them to the conversation agent.
+    /// Anything not recognised still goes to the agent. On by default.
+    pub voice_commands: bool,
+
+    /// Hands-free recording: end each recording by itself once speech is
+    /// followed by `vad_silence_ms` of silence, trimming the silence around
+    /// the speech before it is transcribed. Alt-v still starts a recording
+    /// (and can still end it early). Off by default.
+    pub vad: bool,
+
+    /// Trailing silence, in milliseconds, that ends a hands-free recording.
+    pub vad_silence_ms: u64,
+
+    /// Level (RMS, 0.0–1.0) the microphone must reach to count as speech.
+    /// Raise it in a noisy room; lower it for a quiet microphone.
+    pub vad_threshold: f32,
+
+    /// With `vad`, start listening again after each utterance, until recording
+    /// is toggled off. Capture pauses while a reply is being spoken, so the
+    /// assistant never hears itself. Off by default.
+    pub continuous: bool,
 }
 
 impl Default for SttConfig {
@@ -495,12 +977,121 @@ impl Default for SttConfig {
             // Localhost placeholder, like the TTS default; override in config to
             // point at your transcription server.
             base_url: "http://127.0.0.1:8000/v1".to_string(),
+            command: None,
             model: "Systran/faster-whisper-base".to_string(),
             language: None,
             prompt: None,
             api_key: None,
             pause_media: true,
             input_device: None,
+            voice_commands: true,
+            vad: false,
+            vad_silence_ms: 1200,
+            vad_threshold: 0.02,
+            continuous: false,
+        }
+    }
+}
+
+/// Spoken-announcement settings.
+///
+/// Announcements are spoken through the conversation TTS engine
+/// ([`ConversationConfig`]'s `base_url`, voice, speed and volume), whether or
+/// not conversation mode itself is enabled.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnnouncementsConfig {
+    /// Master switch. Off by default.
+    pub enabled: bool,
+
+    /// Local-time window in which nothing is announced, as `"HH:MM-HH:MM"`
+    /// (e.g. `"22:00-07:00"`; a window may wrap midnight). Announcements
+    /// falling inside it are dropped, not deferred. `None` never goes quiet.
+    pub quiet_hours: Option<String>,
+
+    /// Minimum gap between two announcements, in seconds. Changes arriving
+    /// faster queue up (the oldest are dropped past a small backlog, and any
+    /// that waited over two minutes are dropped as stale).
+    pub min_interval_secs: u64,
+
+    /// Which transitions are announced, and what is said for each.
+    pub events: AnnouncementEvents,
+}
+
+impl Default for AnnouncementsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            quiet_hours: None,
+            min_interval_secs: 15,
+            events: AnnouncementEvents::default(),
+        }
+    }
+}
+
+/// Per-event announcement rules. An event left out of the config file keeps
+/// its default below; one that is present but sets only `template` is enabled.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnnouncementEvents {
+    /// A session's agent started waiting for input (a question or permission
+    /// prompt). On by default.
+    pub waiting_for_input: AnnouncementRule,
+    /// A session's agent went from working to idle. Off by default.
+    pub finished: AnnouncementRule,
+    /// A session's PR checks started failing. On by default.
+    pub ci_failed: AnnouncementRule,
+    /// A session's PR checks went green after pending or failing. Off by
+    /// default.
+    pub ci_passed: AnnouncementRule,
+    /// A session's PR was merged. On by default.
+    pub pr_merged: AnnouncementRule,
+    /// A session's PR was closed without merging. Off by default.
+    pub pr_closed: AnnouncementRule,
+    /// A reviewer requested changes on a session's PR. On by default.
+    pub changes_requested: AnnouncementRule,
+    /// A session's PR was approved. Off by default.
+    pub approved: AnnouncementRule,
+}
+
+impl Default for AnnouncementEvents {
+    fn default() -> Self {
+        let on = AnnouncementRule::default();
+        let off = AnnouncementRule {
+            enabled: false,
+            template: None,
+        };
+        Self {
+            waiting_for_input: on.clone(),
+            finished: off.clone(),
+            ci_failed: on.clone(),
+            ci_passed: off.clone(),
+            pr_merged: on.clone(),
+            pr_closed: off.clone(),
+            changes_requested: on,
+            approved: off,
+        }
+    }
+}
+
+/// Whether one kind of transition is announced, and with what words.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnnouncementRule {
+    pub enabled: bool,
+
+    /// What to say. Placeholders: `{session}` (`"Session 3, payments
+    /// refactor"`, or just the title for an unnumbered session), `{number}`,
+    /// `{title}`, `{branch}`, `{project}` and `{pr}` (the PR number). `None`
+    /// uses the event's built-in sentence.
+    pub template: Option<String>,
+}
+
+impl Default for AnnouncementRule {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            template: None,
         }
     }
 }
@@ -567,6 +1158,7 @@ impl Default for Config {
             nix_develop: true,
             state_sync_interval_ms: 2000,
             agent_state_poll_interval_ms: 3000,
+            scrollback_index_interval_secs: 15,
             hibernate_enabled: false,
             hibernate_idle_timeout_secs: default_hibernate_idle_timeout_secs(),
             hibernate_check_interval_secs: default_hibernate_check_interval_secs(),
@@ -587,12 +1179,20 @@ impl Default for Config {
             precompute_review_caches: true,
             sections: Vec::new(),
             in_progress_limit: None,
+            in_progress_lane_limit: None,
             recent_sessions_limit: default_recent_sessions_limit(),
             commander_enabled: false,
             commander_program: None,
             commander_dir: None,
+            resource_limits: ResourceLimitsConfig::default(),
+            hooks: HooksConfig::default(),
+            ports: PortsConfig::default(),
+            tasks: std::collections::BTreeMap::new(),
+            trusted_project_files: Vec::new(),
+            custom_commands: Vec::new(),
             conversation: ConversationConfig::default(),
             stt: SttConfig::default(),
+            announcements: AnnouncementsConfig::default(),
             telemetry: TelemetryConfig::default(),
             remote_servers: Vec::new(),
         }
@@ -631,6 +1231,30 @@ fn default_hibernate_check_interval_secs() -> u64 {
     600
 }
 
+fn default_memory_pressure_percent() -> u8 {
+    10
+}
+
+fn default_hook_timeout_secs() -> u64 {
+    300
+}
+
+fn default_ports_range_start() -> u16 {
+    20000
+}
+
+fn default_ports_range_end() -> u16 {
+    29999
+}
+
+fn default_ports_block_size() -> u16 {
+    5
+}
+
+fn default_ports_env_file() -> String {
+    ".env.commander".to_string()
+}
+
 fn default_pr_review_labels() -> Vec<String> {
     vec![
         "dev-review-required".to_string(),
@@ -1095,25 +1719,60 @@ mod tests {
     }
 
     use super::*;
+    use crate::config::ProjectHooksConfig;
+
+    #[test]
+    fn conversation_profile_overrides_the_default_agent() {
+        let conv: ConversationConfig = toml::from_str(
+            r#"
+            voice = "af_sky"
+            permission_mode = "auto"
+
+            [[profiles]]
+            name = "ops"
+            cwd = "/srv/ops"
+            permission_mode = "plan"
+            speak_scope = "verbatim"
+            "#,
+        )
+        .unwrap();
+
+        let ops = conv.agent(Some("OPS"));
+        assert_eq!(ops.name, "ops");
+        assert_eq!(ops.cwd, Some(PathBuf::from("/srv/ops")));
+        assert_eq!(ops.permission_mode, "plan");
+        assert_eq!(ops.speak_scope, crate::conversation::SpeakScope::Verbatim);
+        // Unset fields are the default agent's.
+        assert_eq!(ops.voice.as_deref(), Some("af_sky"));
+
+        // No profile, or an unknown one, is the default agent.
+        assert_eq!(conv.agent(None).name, "Claudette");
+        assert_eq!(conv.agent(Some("planner")).permission_mode, "auto");
+    }
 
     #[test]
     fn test_max_sessions_and_in_progress_limit_round_trip() {
         let toml_src = r#"
 in_progress_limit = 4
+in_progress_lane_limit = 2
 
 [[sections]]
 name = "Needs Review"
 has_label = "ready-for-review"
 max_sessions = 5
+lane_max_sessions = 1
 "#;
         let config: Config = toml::from_str(toml_src).expect("toml parse");
         assert_eq!(config.in_progress_limit, Some(4));
+        assert_eq!(config.in_progress_lane_limit, Some(2));
         assert_eq!(config.sections.len(), 1);
         assert_eq!(config.sections[0].max_sessions, Some(5));
+        assert_eq!(config.sections[0].lane_max_sessions, Some(1));
 
         // Defaults are unset.
         let default = Config::default();
         assert_eq!(default.in_progress_limit, None);
+        assert_eq!(default.in_progress_lane_limit, None);
     }
 
     #[test]
@@ -1138,7 +1797,7 @@ has_label = ["blocked", "waiting-on-author"]
         assert_eq!(config.sections.len(), 3);
         assert_eq!(config.sections[0].name, "Needs Review");
         assert_eq!(config.sections[1].name, "Drafts");
-        assert_eq!(config.sections[1].is_draft, Some(true));
+        assert_eq!(config.sections[1].matcher.is_draft, Some(true));
         assert_eq!(config.sections[2].name, "Blocked");
     }
 
@@ -1469,6 +2128,7 @@ command = "codex"
         assert!(config.resume_session);
         assert_eq!(config.state_sync_interval_ms, 2000);
         assert_eq!(config.agent_state_poll_interval_ms, 3000);
+        assert_eq!(config.scrollback_index_interval_secs, 15);
         assert!(config.ai_summary_enabled);
         assert_eq!(config.ai_summary_model, "claude-haiku-4-5-20251001");
         assert!(!config.show_session_program);
@@ -1663,6 +2323,151 @@ show_session_program = false
         assert_eq!(config.hibernate_check_interval_secs, 600);
     }
 
+    #[test]
+    fn test_resource_limits_defaults() {
+        let config = Config::default();
+        assert!(!config.resource_limits.enabled);
+        assert_eq!(config.resource_limits.cpu_quota_percent, None);
+        assert_eq!(config.resource_limits.memory_max, None);
+        assert_eq!(config.resource_limits.pids_max, None);
+        assert_eq!(config.resource_limits.memory_pressure_percent, 10);
+    }
+
+    #[test]
+    fn test_resource_limits_parse_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+            [resource_limits]
+            enabled = true
+            cpu_quota_percent = 200
+            memory_max = "4G"
+            pids_max = 512
+            "#,
+        )
+        .unwrap();
+        assert!(config.resource_limits.enabled);
+        assert_eq!(config.resource_limits.cpu_quota_percent, Some(200));
+        assert_eq!(config.resource_limits.memory_max.as_deref(), Some("4G"));
+        assert_eq!(config.resource_limits.pids_max, Some(512));
+        // Omitted field keeps its default rather than falling to 0.
+        assert_eq!(config.resource_limits.memory_pressure_percent, 10);
+    }
+
+    #[test]
+    fn test_hooks_parse_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+            [hooks]
+            post_create = ["npm install", "cp ../.env ."]
+            on_failure = "block"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.hooks.post_create.len(), 2);
+        assert!(config.hooks.pre_delete.is_empty());
+        assert_eq!(config.hooks.on_failure, HookFailurePolicy::Block);
+        // Omitted field keeps its default rather than falling to 0.
+        assert_eq!(config.hooks.timeout_secs, 300);
+    }
+
+    #[test]
+    fn test_hooks_with_project_appends_and_overrides() {
+        let global = HooksConfig {
+            post_create: vec!["global".to_string()],
+            ..HooksConfig::default()
+        };
+        let project = ProjectHooksConfig {
+            post_create: vec!["local".to_string()],
+            timeout_secs: Some(30),
+            ..ProjectHooksConfig::default()
+        };
+        let merged = global.with_project(&project);
+        assert_eq!(merged.post_create, vec!["global", "local"]);
+        assert_eq!(merged.timeout_secs, 30);
+        // Unset in the project file: the global policy stands.
+        assert_eq!(merged.on_failure, HookFailurePolicy::Warn);
+    }
+
+    #[test]
+    fn test_ports_parse_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+            [ports]
+            enabled = true
+            block_size = 3
+
+            [ports.env]
+            DATABASE_URL = "postgres://localhost/app_{port}"
+            "#,
+        )
+        .unwrap();
+        assert!(config.ports.enabled);
+        assert_eq!(config.ports.block_size, 3);
+        assert_eq!(config.ports.range_start, 20000);
+        assert_eq!(config.ports.env_file, ".env.commander");
+        assert_eq!(
+            config.ports.env.get("DATABASE_URL").map(String::as_str),
+            Some("postgres://localhost/app_{port}")
+        );
+    }
+
+    #[test]
+    fn test_tasks_parse_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+            [tasks]
+            test = "cargo test"
+            lint = "cargo clippy -- -D warnings"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.tasks.len(), 2);
+        assert_eq!(
+            config.tasks.get("test").map(String::as_str),
+            Some("cargo test")
+        );
+        assert!(Config::default().tasks.is_empty());
+    }
+
+    #[test]
+    fn test_custom_commands_parse_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+            [[custom_commands]]
+            name = "Open Grafana"
+            command = "xdg-open https://grafana.local/d/app?branch={branch}"
+
+            [[custom_commands]]
+            name = "e2e"
+            command = "make e2e"
+            run = "modal"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.custom_commands.len(), 2);
+        assert_eq!(config.custom_commands[0].run, CustomCommandRun::Detached);
+        assert_eq!(config.custom_commands[1].run, CustomCommandRun::Modal);
+        assert!(Config::default().custom_commands.is_empty());
+    }
+
+    #[test]
+    fn test_custom_command_expands_quoted_placeholders() {
+        let cmd = CustomCommand {
+            name: "x".to_string(),
+            command: "cd {worktree} && echo {branch}/{pr_number} {unknown} {".to_string(),
+            run: CustomCommandRun::Detached,
+        };
+        let vars = CommandVars {
+            worktree: "/tmp/it's here".to_string(),
+            branch: "{branch}".to_string(),
+            ..CommandVars::default()
+        };
+        assert_eq!(
+            cmd.expand(&vars),
+            "cd '/tmp/it'\\''s here' && echo '{branch}'/'' {unknown} {"
+        );
+    }
+
     #[test]
     fn test_commander_program_falls_back_to_first_program() {
         let config = Config {
@@ -22,6 +22,7 @@ struct InitSnapshot {
     diff_cache_ttl_ms: u64,
     ui_refresh_fps: u32,
     state_sync_interval_ms: u64,
+    scrollback_index_interval_secs: u64,
     commander_enabled: bool,
     hibernate_enabled: bool,
     hibernate_check_interval_secs: u64,
@@ -35,6 +36,7 @@ impl InitSnapshot {
             diff_cache_ttl_ms: config.diff_cache_ttl_ms,
             ui_refresh_fps: config.ui_refresh_fps,
             state_sync_interval_ms: config.state_sync_interval_ms,
+            scrollback_index_interval_secs: config.scrollback_index_interval_secs,
             commander_enabled: config.commander_enabled,
             hibernate_enabled: config.hibernate_enabled,
             hibernate_check_interval_secs: config.hibernate_check_interval_secs,
@@ -47,6 +49,7 @@ impl InitSnapshot {
             && self.diff_cache_ttl_ms == config.diff_cache_ttl_ms
             && self.ui_refresh_fps == config.ui_refresh_fps
             && self.state_sync_interval_ms == config.state_sync_interval_ms
+            && self.scrollback_index_interval_secs == config.scrollback_index_interval_secs
             && self.commander_enabled == config.commander_enabled
             && self.hibernate_enabled == config.hibernate_enabled
             && self.hibernate_check_interval_secs == config.hibernate_check_interval_secs
@@ -66,6 +69,7 @@ impl InitSnapshot {
 /// - `capture_cache_ttl_ms` / `diff_cache_ttl_ms` (cache durations)
 /// - `ui_refresh_fps` (event loop tick rate)
 /// - `state_sync_interval_ms` (state sync background task interval)
+/// - `scrollback_index_interval_secs` (scrollback index background task interval)
 /// - `commander_enabled` (captured by the agent-state poll task at spawn)
 /// - `hibernate_enabled` / `hibernate_check_interval_secs` (the hibernation
 ///   loop is spawned once, with a fixed interval, at construction; the idle
@@ -2,9 +2,10 @@
 //!
 //! Provides a data-driven keybinding table that maps key combinations to
 //! user commands. Keybindings are loaded from the `[keybindings]` section
-//! of `config.toml` and fall back to sensible defaults when omitted.
+//! of `config.toml` and fall back to sensible defaults when omitted. User-defined
+//! `[[custom_commands]]` are bound by name under `[keybindings.custom]`.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::str::FromStr;
 
@@ -47,13 +48,23 @@ pub enum BindableAction {
     DeleteSession,
     DeleteMergedPrSessions,
     RenameSession,
+    EditTags,
+    EditNotes,
+    ArchiveSession,
+    ShowArchived,
+    Undo,
     RestartSession,
     ResetSession,
     ChangeProgram,
     ToggleKeepAlive,
+    RunTasks,
+    SendTaskFailure,
     RemoveProject,
     OpenInEditor,
     OpenInfo,
+    SearchScrollback,
+    FilterSessions,
+    SaveFilter,
     OpenPullRequest,
     RefreshPrStatus,
     OpenCommander,
@@ -72,10 +83,15 @@ pub enum BindableAction {
     MoveToSection,
     ToggleViewMode,
     ToggleSection,
+    CycleSwimlanes,
+    ToggleLane,
+    CycleGridFilter,
+    ToggleGridZoom,
     TogglePane,
     TogglePaneReverse,
     ShrinkLeftPane,
     GrowLeftPane,
+    InspectPane,
     AddRemoteServer,
     RemoveRemoteServer,
     EditServerPrograms,
@@ -102,13 +118,23 @@ impl BindableAction {
         Self::SelectShell,
         Self::NewSession,
         Self::RenameSession,
+        Self::EditTags,
+        Self::EditNotes,
+        Self::ArchiveSession,
+        Self::ShowArchived,
+        Self::Undo,
         Self::RestartSession,
         Self::ResetSession,
         Self::ChangeProgram,
         Self::ToggleKeepAlive,
+        Self::RunTasks,
+        Self::SendTaskFailure,
         Self::DeleteSession,
         Self::OpenInEditor,
         Self::OpenInfo,
+        Self::SearchScrollback,
+        Self::FilterSessions,
+        Self::SaveFilter,
         // Stacked & Cascade
         Self::NewStackedSession,
         Self::PushStack,
@@ -133,11 +159,17 @@ impl BindableAction {
         Self::MoveToSection,
         Self::ToggleViewMode,
         Self::ToggleSection,
+        Self::CycleSwimlanes,
+        Self::ToggleLane,
+        // Watch grid
+        Self::CycleGridFilter,
+        Self::ToggleGridZoom,
         // Right pane (list views)
         Self::TogglePane,
         Self::TogglePaneReverse,
         Self::ShrinkLeftPane,
         Self::GrowLeftPane,
+        Self::InspectPane,
         // Review & AI
         Self::OpenReviewDiff,
         Self::GenerateSummary,
@@ -182,13 +214,23 @@ impl BindableAction {
             Self::DeleteSession => "delete_session",
             Self::DeleteMergedPrSessions => "delete_merged_pr_sessions",
             Self::RenameSession => "rename_session",
+            Self::EditTags => "edit_tags",
+            Self::EditNotes => "edit_notes",
+            Self::ArchiveSession => "archive_session",
+            Self::ShowArchived => "show_archived",
+            Self::Undo => "undo",
             Self::RestartSession => "restart_session",
             Self::ResetSession => "reset_session",
             Self::ChangeProgram => "change_program",
             Self::ToggleKeepAlive => "toggle_keep_alive",
+            Self::RunTasks => "run_tasks",
+            Self::SendTaskFailure => "send_task_failure",
             Self::RemoveProject => "remove_project",
             Self::OpenInEditor => "open_in_editor",
             Self::OpenInfo => "open_info",
+            Self::SearchScrollback => "search_scrollback",
+            Self::FilterSessions => "filter_sessions",
+            Self::SaveFilter => "save_filter",
             Self::OpenPullRequest => "open_pull_request",
             Self::RefreshPrStatus => "refresh_pr_status",
             Self::OpenCommander => "open_commander",
@@ -207,10 +249,15 @@ impl BindableAction {
             Self::MoveToSection => "move_to_section",
             Self::ToggleViewMode => "toggle_view_mode",
             Self::ToggleSection => "toggle_section",
+            Self::CycleSwimlanes => "cycle_swimlanes",
+            Self::ToggleLane => "toggle_lane",
+            Self::CycleGridFilter => "cycle_grid_filter",
+            Self::ToggleGridZoom => "toggle_grid_zoom",
             Self::TogglePane => "toggle_pane",
             Self::TogglePaneReverse => "toggle_pane_reverse",
             Self::ShrinkLeftPane => "shrink_left_pane",
             Self::GrowLeftPane => "grow_left_pane",
+            Self::InspectPane => "inspect_pane",
             Self::EditServerPrograms => "edit_server_programs",
             Self::AddRemoteServer => "add_remote_server",
             Self::RemoveRemoteServer => "remove_remote_server",
@@ -244,13 +291,23 @@ impl BindableAction {
             Self::DeleteSession => "Delete/kill session",
             Self::DeleteMergedPrSessions => "Delete sessions with merged PRs",
             Self::RenameSession => "Rename session",
+            Self::EditTags => "Edit session tags",
+            Self::EditNotes => "Edit session notes",
+            Self::ArchiveSession => "Archive session (remove worktree, keep branch)",
+            Self::ShowArchived => "Archived sessions…",
+            Self::Undo => "Undo last delete / project removal / cascade abandon",
             Self::RestartSession => "Restart session",
             Self::ResetSession => "Reset session (restart without resuming)",
             Self::ChangeProgram => "Change program (agent)…",
             Self::ToggleKeepAlive => "Toggle keep-alive (never auto-hibernate)",
+            Self::RunTasks => "Run project tasks",
+            Self::SendTaskFailure => "Send failed task output to agent",
             Self::RemoveProject => "Remove project",
             Self::OpenInEditor => "Open in editor/IDE",
             Self::OpenInfo => "Show session info",
+            Self::SearchScrollback => "Search all sessions' scrollback…",
+            Self::FilterSessions => "Filter sessions by query…",
+            Self::SaveFilter => "Save the current filter as…",
             Self::OpenPullRequest => "Open PR in browser",
             Self::RefreshPrStatus => "Refresh PR status",
             Self::OpenCommander => "Open commander session",
@@ -267,12 +324,19 @@ impl BindableAction {
             Self::GenerateSummary => "Generate AI summary",
             Self::ScanDirectory => "Scan directory for repos",
             Self::MoveToSection => "Move session to section…",
-            Self::ToggleViewMode => "Cycle view: project / sections / stacks / board",
+            Self::ToggleViewMode => "Cycle view: project / sections / stacks / board / grid",
             Self::ToggleSection => "Collapse/expand section",
+            Self::CycleSwimlanes => {
+                "Board: swimlanes by project / server / program / PR author / off"
+            }
+            Self::ToggleLane => "Board: fold/unfold the selected swimlane",
+            Self::CycleGridFilter => "Grid: show all / working / needs input",
+            Self::ToggleGridZoom => "Grid: zoom the selected tile",
             Self::TogglePane => "Cycle right pane: preview / info / shell",
             Self::TogglePaneReverse => "Cycle right pane (reverse)",
             Self::ShrinkLeftPane => "Narrow the session list",
             Self::GrowLeftPane => "Widen the session list",
+            Self::InspectPane => "Inspect the right pane: scroll, search, select and copy",
             Self::EditServerPrograms => "Edit server's program list…",
             Self::AddRemoteServer => "Add remote server",
             Self::RemoveRemoteServer => "Remove remote server",
@@ -312,13 +376,23 @@ impl BindableAction {
             Self::DeleteSession => "delete",
             Self::DeleteMergedPrSessions => "delete merged",
             Self::RenameSession => "rename",
+            Self::EditTags => "tags",
+            Self::EditNotes => "notes",
+            Self::ArchiveSession => "archive",
+            Self::ShowArchived => "archived",
+            Self::Undo => "undo",
             Self::RestartSession => "restart",
             Self::ResetSession => "reset",
             Self::ChangeProgram => "program",
             Self::ToggleKeepAlive => "keep alive",
+            Self::RunTasks => "tasks",
+            Self::SendTaskFailure => "send failure",
             Self::RemoveProject => "remove project",
             Self::OpenInEditor => "edit",
             Self::OpenInfo => "info",
+            Self::SearchScrollback => "find",
+            Self::FilterSessions => "filter",
+            Self::SaveFilter => "save filter",
             Self::OpenPullRequest => "open PR",
             Self::RefreshPrStatus => "refresh PR",
             Self::OpenCommander => "commander",
@@ -337,10 +411,15 @@ impl BindableAction {
             Self::MoveToSection => "move",
             Self::ToggleViewMode => "view",
             Self::ToggleSection => "collapse",
+            Self::CycleSwimlanes => "lanes",
+            Self::ToggleLane => "fold lane",
+            Self::CycleGridFilter => "filter",
+            Self::ToggleGridZoom => "zoom",
             Self::TogglePane => "pane",
             Self::TogglePaneReverse => "pane back",
             Self::ShrinkLeftPane => "narrower",
             Self::GrowLeftPane => "wider",
+            Self::InspectPane => "inspect",
             Self::EditServerPrograms => "server programs",
             Self::AddRemoteServer => "add server",
             Self::RemoveRemoteServer => "remove server",
@@ -367,13 +446,23 @@ impl BindableAction {
             | Self::SelectShell
             | Self::NewSession
             | Self::RenameSession
+            | Self::EditTags
+            | Self::EditNotes
+            | Self::ArchiveSession
+            | Self::ShowArchived
+            | Self::Undo
             | Self::RestartSession
             | Self::ResetSession
             | Self::ChangeProgram
             | Self::ToggleKeepAlive
+            | Self::RunTasks
+            | Self::SendTaskFailure
             | Self::DeleteSession
             | Self::OpenInEditor
-            | Self::OpenInfo => "Sessions",
+            | Self::OpenInfo
+            | Self::SearchScrollback
+            | Self::FilterSessions
+            | Self::SaveFilter => "Sessions",
             Self::NewStackedSession
             | Self::PushStack
             | Self::CascadeMergeMain
@@ -390,11 +479,17 @@ impl BindableAction {
             Self::AddRemoteServer | Self::RemoveRemoteServer | Self::EditServerPrograms => {
                 "Remote Servers"
             }
-            Self::MoveToSection | Self::ToggleViewMode | Self::ToggleSection => "Sections",
+            Self::MoveToSection
+            | Self::ToggleViewMode
+            | Self::ToggleSection
+            | Self::CycleSwimlanes
+            | Self::ToggleLane => "Sections",
+            Self::CycleGridFilter | Self::ToggleGridZoom => "Watch Grid",
             Self::TogglePane
             | Self::TogglePaneReverse
             | Self::ShrinkLeftPane
-            | Self::GrowLeftPane => "Right Pane",
+            | Self::GrowLeftPane
+            | Self::InspectPane => "Right Pane",
             Self::OpenReviewDiff
             | Self::GenerateSummary
             | Self::OpenCommander
@@ -435,13 +530,23 @@ impl FromStr for BindableAction {
             "delete_session" => Ok(Self::DeleteSession),
             "delete_merged_pr_sessions" => Ok(Self::DeleteMergedPrSessions),
             "rename_session" => Ok(Self::RenameSession),
+            "edit_tags" => Ok(Self::EditTags),
+            "edit_notes" => Ok(Self::EditNotes),
+            "archive_session" => Ok(Self::ArchiveSession),
+            "show_archived" => Ok(Self::ShowArchived),
+            "undo" => Ok(Self::Undo),
             "restart_session" => Ok(Self::RestartSession),
             "reset_session" => Ok(Self::ResetSession),
             "change_program" => Ok(Self::ChangeProgram),
             "toggle_keep_alive" => Ok(Self::ToggleKeepAlive),
+            "run_tasks" => Ok(Self::RunTasks),
+            "send_task_failure" => Ok(Self::SendTaskFailure),
             "remove_project" => Ok(Self::RemoveProject),
             "open_in_editor" => Ok(Self::OpenInEditor),
             "open_info" => Ok(Self::OpenInfo),
+            "search_scrollback" => Ok(Self::SearchScrollback),
+            "filter_sessions" => Ok(Self::FilterSessions),
+            "save_filter" => Ok(Self::SaveFilter),
             "open_pull_request" => Ok(Self::OpenPullRequest),
             "refresh_pr_status" => Ok(Self::RefreshPrStatus),
             "open_commander" => Ok(Self::OpenCommander),
@@ -460,10 +565,15 @@ impl FromStr for BindableAction {
             "move_to_section" => Ok(Self::MoveToSection),
             "toggle_view_mode" => Ok(Self::ToggleViewMode),
             "toggle_section" => Ok(Self::ToggleSection),
+            "cycle_swimlanes" => Ok(Self::CycleSwimlanes),
+            "toggle_lane" => Ok(Self::ToggleLane),
+            "cycle_grid_filter" => Ok(Self::CycleGridFilter),
+            "toggle_grid_zoom" => Ok(Self::ToggleGridZoom),
             "toggle_pane" => Ok(Self::TogglePane),
             "toggle_pane_reverse" => Ok(Self::TogglePaneReverse),
             "shrink_left_pane" => Ok(Self::ShrinkLeftPane),
             "grow_left_pane" => Ok(Self::GrowLeftPane),
+            "inspect_pane" => Ok(Self::InspectPane),
             "edit_server_programs" => Ok(Self::EditServerPrograms),
             "add_remote_server" => Ok(Self::AddRemoteServer),
             "remove_remote_server" => Ok(Self::RemoveRemoteServer),
@@ -645,6 +755,11 @@ pub struct KeyBindings {
     bindings: HashMap<BindableAction, Vec<KeyBinding>>,
     /// (KeyCode, KeyModifiers) → action (derived lookup table, not serialized)
     lookup: HashMap<(KeyCode, KeyModifiers), BindableAction>,
+    /// Custom-command name → bound keys (`[keybindings.custom]`). Unbound by
+    /// default; the names are matched against `[[custom_commands]]` at use.
+    custom: BTreeMap<String, Vec<KeyBinding>>,
+    /// (KeyCode, KeyModifiers) → custom-command name (derived, not serialized)
+    custom_lookup: HashMap<(KeyCode, KeyModifiers), String>,
 }
 
 impl KeyBindings {
@@ -661,6 +776,19 @@ impl KeyBindings {
         lookup
     }
 
+    /// Build the custom-command lookup table from its bindings map.
+    fn build_custom_lookup(
+        custom: &BTreeMap<String, Vec<KeyBinding>>,
+    ) -> HashMap<(KeyCode, KeyModifiers), String> {
+        let mut lookup = HashMap::new();
+        for (name, keys) in custom {
+            for key in keys {
+                lookup.insert((key.code, key.modifiers), name.clone());
+            }
+        }
+        lookup
+    }
+
     /// Resolve a crossterm key event to a bindable action.
     ///
     /// Returns `None` if the key is not bound to any action. Structural
@@ -672,6 +800,32 @@ impl KeyBindings {
         self.lookup.get(&(event.code, event.modifiers)).copied()
     }
 
+    /// Resolve a key event to the name of the custom command bound to it.
+    /// Checked before [`Self::resolve`], so a custom binding shadows a built-in
+    /// action on the same key.
+    pub fn resolve_custom(&self, event: &KeyEvent) -> Option<&str> {
+        if event.kind != KeyEventKind::Press {
+            return None;
+        }
+        self.custom_lookup
+            .get(&(event.code, event.modifiers))
+            .map(String::as_str)
+    }
+
+    /// The keys bound to the custom command `name`, formatted like
+    /// [`Self::keys_display`].
+    pub fn custom_keys_display(&self, name: &str) -> String {
+        self.custom
+            .get(name)
+            .map(|keys| {
+                keys.iter()
+                    .map(|k| k.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default()
+    }
+
     /// Get the key bindings for a specific action.
     pub fn keys_for(&self, action: BindableAction) -> &[KeyBinding] {
         self.bindings.get(&action).map_or(&[], |v| v.as_slice())
@@ -790,9 +944,15 @@ impl Default for KeyBindings {
             BindableAction::DeleteSession,
             vec![kb(KeyCode::Char('d'), none)],
         );
+        // Undo gets a plain `u`, as in most editors: a mistyped `d` wants a
+        // fast way back, and the toast after each delete names the key.
+        bindings.insert(BindableAction::Undo, vec![kb(KeyCode::Char('u'), none)]);
         // RenameSession has no default key — it's reachable via the command
         // palette. `r` is given to OpenReviewDiff so it pairs with the
-        // attached-session Alt-r review toggle.
+        // attached-session Alt-r review toggle. EditTags and EditNotes are
+        // palette-only for the same reason: occasional edits, not hotkeys.
+        // ArchiveSession and ShowArchived are palette-only too: archiving is a
+        // tidy-up step, not something to trigger by a stray keypress.
         bindings.insert(
             BindableAction::RestartSession,
             vec![kb(KeyCode::Char('R'), shift)],
@@ -804,6 +964,8 @@ impl Default for KeyBindings {
         // ToggleKeepAlive has no default key — it's reachable via the command
         // palette and can be bound explicitly in config. Keep-alive is a rarely
         // toggled, opt-in control, so it doesn't claim a top-level hotkey.
+        // RunTasks and SendTaskFailure are palette-only too: tasks are
+        // configured per project, so not every user has any to run.
         bindings.insert(
             BindableAction::RemoveProject,
             vec![kb(KeyCode::Char('D'), shift)],
@@ -817,6 +979,16 @@ impl Default for KeyBindings {
             vec![kb(KeyCode::Char('o'), none)],
         );
         bindings.insert(BindableAction::OpenInfo, vec![kb(KeyCode::Char('i'), none)]);
+        bindings.insert(
+            BindableAction::SearchScrollback,
+            vec![kb(KeyCode::Char('f'), ctrl)],
+        );
+        bindings.insert(
+            BindableAction::FilterSessions,
+            vec![kb(KeyCode::Char('F'), shift)],
+        );
+        // SaveFilter is palette-only: it names whatever filter is active, so
+        // it's only ever wanted right after writing one.
         bindings.insert(
             BindableAction::OpenCommander,
             vec![kb(KeyCode::Char('C'), shift)],
@@ -842,6 +1014,24 @@ impl Default for KeyBindings {
             vec![kb(KeyCode::Char('v'), none)],
         );
         bindings.insert(BindableAction::ToggleSection, vec![]);
+        bindings.insert(
+            BindableAction::CycleSwimlanes,
+            vec![kb(KeyCode::Char('L'), shift)],
+        );
+        bindings.insert(
+            BindableAction::ToggleLane,
+            vec![kb(KeyCode::Char('Z'), shift)],
+        );
+
+        // Watch grid (grid view only)
+        bindings.insert(
+            BindableAction::CycleGridFilter,
+            vec![kb(KeyCode::Char('f'), none)],
+        );
+        bindings.insert(
+            BindableAction::ToggleGridZoom,
+            vec![kb(KeyCode::Char('z'), none)],
+        );
 
         // Right pane (list views only)
         bindings.insert(BindableAction::TogglePane, vec![kb(KeyCode::Tab, none)]);
@@ -859,6 +1049,10 @@ impl Default for KeyBindings {
             BindableAction::GrowLeftPane,
             vec![kb(KeyCode::Char('>'), shift), kb(KeyCode::Char('>'), none)],
         );
+        bindings.insert(
+            BindableAction::InspectPane,
+            vec![kb(KeyCode::Char('/'), none)],
+        );
 
         // Scrolling
         bindings.insert(BindableAction::ScrollUp, vec![]);
@@ -892,7 +1086,12 @@ impl Default for KeyBindings {
         );
 
         let lookup = Self::build_lookup(&bindings);
-        Self { bindings, lookup }
+        Self {
+            bindings,
+            lookup,
+            custom: BTreeMap::new(),
+            custom_lookup: HashMap::new(),
+        }
     }
 }
 
@@ -900,17 +1099,26 @@ impl Default for KeyBindings {
 // Serde: serialize as { action_name = ["key1", "key2"] }
 // ---------------------------------------------------------------------------
 
+/// Key of the `[keybindings.custom]` sub-table (custom-command name → keys).
+const CUSTOM_TABLE: &str = "custom";
+
 impl Serialize for KeyBindings {
     fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
         use serde::ser::SerializeMap;
 
-        let mut map = serializer.serialize_map(Some(self.bindings.len()))?;
+        let len = self.bindings.len() + usize::from(!self.custom.is_empty());
+        let mut map = serializer.serialize_map(Some(len))?;
         // Serialize in a stable order
         let mut entries: Vec<_> = self.bindings.iter().collect();
         entries.sort_by_key(|(action, _)| action.config_name());
         for (action, keys) in entries {
             map.serialize_entry(action.config_name(), keys)?;
         }
+        // Last, so TOML writes it as a `[keybindings.custom]` sub-table after
+        // the plain keys.
+        if !self.custom.is_empty() {
+            map.serialize_entry(CUSTOM_TABLE, &self.custom)?;
+        }
         map.end()
     }
 }
@@ -938,6 +1146,11 @@ impl<'de> Visitor<'de> for KeyBindingsVisitor {
         let mut result = KeyBindings::default();
 
         while let Some(key) = map.next_key::<String>()? {
+            if key == CUSTOM_TABLE {
+                let custom: BTreeMap<String, OneOrMany> = map.next_value()?;
+                result.custom = custom.into_iter().map(|(k, v)| (k, v.0)).collect();
+                continue;
+            }
             match BindableAction::from_str(&key) {
                 Ok(action) => {
                     let keys: OneOrMany = map.next_value()?;
@@ -953,6 +1166,7 @@ impl<'de> Visitor<'de> for KeyBindingsVisitor {
 
         // Rebuild lookup after applying overrides
         result.lookup = KeyBindings::build_lookup(&result.bindings);
+        result.custom_lookup = KeyBindings::build_custom_lookup(&result.custom);
         Ok(result)
     }
 }
@@ -1574,6 +1788,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_custom_command_bindings_round_trip() {
+        let toml = r#"
+            quit = "q"
+
+            [custom]
+            "Open Grafana" = "Alt-g"
+            e2e = ["Ctrl-e", "F5"]
+        "#;
+        let kb: KeyBindings = toml::from_str(toml).unwrap();
+        let alt_g = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::ALT);
+        assert_eq!(kb.resolve_custom(&alt_g), Some("Open Grafana"));
+        let f5 = KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE);
+        assert_eq!(kb.resolve_custom(&f5), Some("e2e"));
+        assert_eq!(kb.custom_keys_display("e2e"), "Ctrl-e, F5");
+        assert_eq!(kb.custom_keys_display("missing"), "");
+
+        let serialized = toml::to_string_pretty(&kb).unwrap();
+        let again: KeyBindings = toml::from_str(&serialized).unwrap();
+        assert_eq!(again.resolve_custom(&alt_g), Some("Open Grafana"));
+        assert_eq!(KeyBindings::default().resolve_custom(&alt_g), None);
+    }
+
     #[test]
     fn test_unknown_action_skipped() {
         // Unknown action names (e.g. removed features like `pause_session`) are
@@ -1616,6 +1853,7 @@ mod tests {
                 "Pull Requests",
                 "Remote Servers",
                 "Sections",
+                "Watch Grid",
                 "Right Pane",
                 "Review & AI",
                 "Scrolling",
@@ -1768,6 +2006,38 @@ mod tests {
         assert_eq!(kb.resolve(&r), Some(BindableAction::OpenReviewDiff));
     }
 
+    #[test]
+    fn test_edit_tags_and_notes_unbound_by_default() {
+        // Tag and note edits are palette-only, like rename.
+        let kb = KeyBindings::default();
+        assert!(kb.keys_for(BindableAction::EditTags).is_empty());
+        assert!(kb.keys_for(BindableAction::EditNotes).is_empty());
+        assert_eq!(
+            "edit_tags".parse::<BindableAction>(),
+            Ok(BindableAction::EditTags)
+        );
+        assert!(BindableAction::ALL.contains(&BindableAction::EditNotes));
+    }
+
+    #[test]
+    fn test_archive_actions_unbound_by_default() {
+        let kb = KeyBindings::default();
+        assert!(kb.keys_for(BindableAction::ArchiveSession).is_empty());
+        assert!(kb.keys_for(BindableAction::ShowArchived).is_empty());
+        assert_eq!(
+            "show_archived".parse::<BindableAction>(),
+            Ok(BindableAction::ShowArchived)
+        );
+    }
+
+    #[test]
+    fn test_undo_defaults_to_u() {
+        let kb = KeyBindings::default();
+        let u = KeyEvent::new(KeyCode::Char('u'), KeyModifiers::NONE);
+        assert_eq!(kb.resolve(&u), Some(BindableAction::Undo));
+        assert_eq!("undo".parse::<BindableAction>(), Ok(BindableAction::Undo));
+    }
+
     #[test]
     fn test_reset_session_unbound_by_default() {
         // Reset is palette-only: no default hotkey. Shift-R stays the *resuming*
@@ -1800,4 +2070,80 @@ mod tests {
         let shift_k = KeyEvent::new(KeyCode::Char('K'), KeyModifiers::SHIFT);
         assert_eq!(kb.resolve(&shift_k), None);
     }
+
+    #[test]
+    fn test_task_actions_parse_and_are_unbound() {
+        let kb = KeyBindings::default();
+        for (name, action) in [
+            ("run_tasks", BindableAction::RunTasks),
+            ("send_task_failure", BindableAction::SendTaskFailure),
+        ] {
+            assert_eq!(name.parse::<BindableAction>().unwrap(), action);
+            assert_eq!(action.config_name(), name);
+            assert!(kb.keys_for(action).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_grid_actions_parse_and_default_keys() {
+        let kb = KeyBindings::default();
+        for (name, action, key) in [
+            ("cycle_grid_filter", BindableAction::CycleGridFilter, 'f'),
+            ("toggle_grid_zoom", BindableAction::ToggleGridZoom, 'z'),
+        ] {
+            assert_eq!(name.parse::<BindableAction>().unwrap(), action);
+            assert_eq!(action.config_name(), name);
+            let event = KeyEvent::new(KeyCode::Char(key), KeyModifiers::NONE);
+            assert_eq!(kb.resolve(&event), Some(action));
+        }
+    }
+
+    #[test]
+    fn test_swimlane_actions_parse_and_bind_shifted_keys() {
+        let kb = KeyBindings::default();
+        for (name, action, key) in [
+            ("cycle_swimlanes", BindableAction::CycleSwimlanes, 'L'),
+            ("toggle_lane", BindableAction::ToggleLane, 'Z'),
+        ] {
+            assert_eq!(name.parse::<BindableAction>().unwrap(), action);
+            assert_eq!(action.config_name(), name);
+            assert_eq!(action.section(), "Sections");
+            let event = KeyEvent::new(KeyCode::Char(key), KeyModifiers::SHIFT);
+            assert_eq!(kb.resolve(&event), Some(action));
+        }
+    }
+
+    #[test]
+    fn test_search_scrollback_parses_and_binds_ctrl_f() {
+        let kb = KeyBindings::default();
+        let action = BindableAction::SearchScrollback;
+        assert_eq!(
+            "search_scrollback".parse::<BindableAction>().unwrap(),
+            action
+        );
+        assert_eq!(action.section(), "Sessions");
+        let event = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL);
+        assert_eq!(kb.resolve(&event), Some(action));
+    }
+
+    #[test]
+    fn test_inspect_pane_parses_and_binds_slash() {
+        let kb = KeyBindings::default();
+        let action = BindableAction::InspectPane;
+        assert_eq!("inspect_pane".parse::<BindableAction>().unwrap(), action);
+        assert_eq!(action.section(), "Right Pane");
+        let event = KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE);
+        assert_eq!(kb.resolve(&event), Some(action));
+    }
+
+    #[test]
+    fn test_filter_sessions_parses_and_binds_shift_f() {
+        let kb = KeyBindings::default();
+        let action = BindableAction::FilterSessions;
+        assert_eq!("filter_sessions".parse::<BindableAction>().unwrap(), action);
+        assert_eq!(action.section(), "Sessions");
+        let event = KeyEvent::new(KeyCode::Char('F'), KeyModifiers::SHIFT);
+        assert_eq!(kb.resolve(&event), Some(action));
+        assert!(kb.keys_for(BindableAction::SaveFilter).is_empty());
+    }
 }
@@ -13,6 +13,7 @@ mod config;
 mod config_store;
 pub mod keybindings;
 mod migrations;
+mod project_file;
 pub mod storage;
 pub(crate) mod store;
 pub mod theme;
@@ -77,6 +78,7 @@ pub(crate) fn write_private_file(
     })
 }
 pub use keybindings::{BindableAction, KeyBinding, KeyBindings};
+pub use project_file::{PROJECT_FILE_NAME, ProjectFile, ProjectHooksConfig};
 pub use storage::*;
 pub use store::StateStore;
 pub use theme::{ColorValue, ThemeOverrides};
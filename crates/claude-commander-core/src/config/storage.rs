@@ -5,6 +5,7 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::error::{ConfigError, Result};
@@ -47,6 +48,110 @@ struct StackRetargetPlan {
     child_ids: Vec<SessionId>,
 }
 
+/// How long a destructive action stays undoable. Past this the entry is
+/// dropped and the refs holding its stashed worktree state are deleted.
+pub const UNDO_TTL: Duration = Duration::minutes(15);
+
+/// Most undo entries kept at once; the oldest is dropped first. Bounds the
+/// hidden refs a batch delete (e.g. "delete merged") leaves behind.
+pub const UNDO_JOURNAL_CAP: usize = 20;
+
+/// A removed worktree's state, pinned to a hidden ref so undo can put it back
+/// (see [`crate::git::snapshot_worktree`]).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorktreeStash {
+    /// The worktree's `HEAD` at removal; the branch is recreated here if it has
+    /// been deleted since.
+    pub head: String,
+    /// Ref holding the stash (`refs/commander/undo/<id>`).
+    pub stash_ref: String,
+    /// Whether `stash_ref` carries uncommitted changes on top of `head`.
+    pub has_snapshot: bool,
+}
+
+/// A session record removed by a delete, with what undo needs to bring its
+/// worktree back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemovedSession {
+    pub session: WorktreeSession,
+    /// The owning project's repository (the stash ref lives there).
+    pub repo_path: PathBuf,
+    /// `None` for an archived session — its archive ref already holds the
+    /// work — or when the stash could not be taken.
+    pub stash: Option<WorktreeStash>,
+}
+
+impl RemovedSession {
+    /// Hidden refs this record keeps alive, to delete once it can no longer be
+    /// undone.
+    fn refs(&self) -> impl Iterator<Item = &str> {
+        self.stash
+            .as_ref()
+            .map(|s| s.stash_ref.as_str())
+            .into_iter()
+            .chain(
+                self.session
+                    .archived
+                    .as_ref()
+                    .map(|a| a.archive_ref.as_str()),
+            )
+    }
+}
+
+/// A destructive action that can still be reversed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UndoAction {
+    /// A session delete. Stacked children re-pointed by the delete stay
+    /// re-pointed.
+    DeleteSession(RemovedSession),
+    /// A project removal, with every session it took down.
+    RemoveProject {
+        project: Project,
+        sessions: Vec<RemovedSession>,
+    },
+    /// Abandoning the cascade paused at `session_id`.
+    CascadeAbandon { session_id: SessionId },
+}
+
+impl UndoAction {
+    /// Short description for toasts and CLI output, e.g. `deleted session "x"`.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::DeleteSession(removed) => {
+                format!("deleted session \"{}\"", removed.session.title)
+            }
+            Self::RemoveProject { project, sessions } => format!(
+                "removed project \"{}\" ({} session{})",
+                project.name,
+                sessions.len(),
+                if sessions.len() == 1 { "" } else { "s" }
+            ),
+            Self::CascadeAbandon { .. } => "abandoned cascade".to_string(),
+        }
+    }
+
+    /// `(repo, ref)` pairs to delete once this entry is discarded.
+    pub fn refs(&self) -> Vec<(PathBuf, String)> {
+        let removed: Vec<&RemovedSession> = match self {
+            Self::DeleteSession(removed) => vec![removed],
+            Self::RemoveProject { sessions, .. } => sessions.iter().collect(),
+            Self::CascadeAbandon { .. } => Vec::new(),
+        };
+        removed
+            .into_iter()
+            .flat_map(|r| r.refs().map(|name| (r.repo_path.clone(), name.to_string())))
+            .collect()
+    }
+}
+
+/// One entry in [`AppState::undo_journal`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UndoEntry {
+    pub recorded_at: DateTime<Utc>,
+    pub action: UndoAction,
+}
+
 /// Persistent application state
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AppState {
@@ -77,6 +182,12 @@ pub struct AppState {
     #[serde(default)]
     pub cascade_paused_at: Option<SessionId>,
 
+    /// Recent destructive actions that can still be undone, oldest first.
+    /// Persisted so `claude-commander undo` reaches an action taken in the TUI;
+    /// entries expire after [`UNDO_TTL`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub undo_journal: Vec<UndoEntry>,
+
     /// Application version that last wrote this state. Informational only —
     /// **never gate a migration on this field.** It is stamped on every load
     /// and every save but never read to make a decision, so a comparison
@@ -461,6 +572,36 @@ impl AppState {
         (self.remove_session(session_id), pr_retargets)
     }
 
+    /// Journal `action` as undoable. Returns the entries it pushed out —
+    /// expired ones and any beyond [`UNDO_JOURNAL_CAP`] — whose refs the caller
+    /// should delete.
+    pub fn record_undo(&mut self, action: UndoAction, now: DateTime<Utc>) -> Vec<UndoEntry> {
+        let mut dropped = self.prune_undo_journal(now);
+        self.undo_journal.push(UndoEntry {
+            recorded_at: now,
+            action,
+        });
+        let excess = self.undo_journal.len().saturating_sub(UNDO_JOURNAL_CAP);
+        dropped.extend(self.undo_journal.drain(..excess));
+        dropped
+    }
+
+    /// Take the most recent undoable entry, if any, along with the expired
+    /// entries dropped on the way.
+    pub fn take_undo(&mut self, now: DateTime<Utc>) -> (Option<UndoEntry>, Vec<UndoEntry>) {
+        let dropped = self.prune_undo_journal(now);
+        (self.undo_journal.pop(), dropped)
+    }
+
+    /// Drop entries older than [`UNDO_TTL`], returning them.
+    pub fn prune_undo_journal(&mut self, now: DateTime<Utc>) -> Vec<UndoEntry> {
+        let (expired, live) = std::mem::take(&mut self.undo_journal)
+            .into_iter()
+            .partition(|e| now - e.recorded_at > UNDO_TTL);
+        self.undo_journal = live;
+        expired
+    }
+
     /// Get a session by ID
     pub fn get_session(&self, id: &SessionId) -> Option<&WorktreeSession> {
         self.sessions.get(id)
@@ -1163,6 +1304,92 @@ mod tests {
         assert!(state.remove_project(&ProjectId::new()).is_none());
     }
 
+    fn cascade_undo() -> UndoAction {
+        UndoAction::CascadeAbandon {
+            session_id: SessionId::new(),
+        }
+    }
+
+    #[test]
+    fn undo_journal_takes_newest_first_and_drops_expired_entries() {
+        let mut state = AppState::new();
+        let t0 = Utc::now();
+        let project = create_test_project();
+        let session = create_test_session(project.id);
+        state.record_undo(cascade_undo(), t0);
+        state.record_undo(
+            UndoAction::DeleteSession(RemovedSession {
+                session,
+                repo_path: PathBuf::from("/tmp/test"),
+                stash: None,
+            }),
+            t0 + Duration::minutes(1),
+        );
+
+        let (entry, dropped) = state.take_undo(t0 + Duration::minutes(2));
+        assert!(matches!(
+            entry.map(|e| e.action),
+            Some(UndoAction::DeleteSession(_))
+        ));
+        assert!(dropped.is_empty());
+
+        // The remaining entry is past its TTL by now: dropped, not returned.
+        let (entry, dropped) = state.take_undo(t0 + UNDO_TTL + Duration::minutes(1));
+        assert!(entry.is_none());
+        assert_eq!(dropped.len(), 1);
+        assert!(state.undo_journal.is_empty());
+    }
+
+    #[test]
+    fn undo_journal_is_capped_and_reports_evicted_refs() {
+        let mut state = AppState::new();
+        let now = Utc::now();
+        for _ in 0..UNDO_JOURNAL_CAP {
+            assert!(state.record_undo(cascade_undo(), now).is_empty());
+        }
+        let project = create_test_project();
+        let removed = RemovedSession {
+            session: create_test_session(project.id),
+            repo_path: PathBuf::from("/tmp/test"),
+            stash: Some(WorktreeStash {
+                head: "abc".to_string(),
+                stash_ref: "refs/commander/undo/x".to_string(),
+                has_snapshot: true,
+            }),
+        };
+        assert_eq!(
+            UndoAction::DeleteSession(removed.clone()).refs(),
+            vec![(
+                PathBuf::from("/tmp/test"),
+                "refs/commander/undo/x".to_string()
+            )]
+        );
+        let dropped = state.record_undo(UndoAction::DeleteSession(removed), now);
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(state.undo_journal.len(), UNDO_JOURNAL_CAP);
+    }
+
+    #[test]
+    fn undo_journal_roundtrips_and_is_omitted_when_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("state.json");
+        let mut state = AppState::new();
+        state.save_to(&path).unwrap();
+        let json = std::fs::read_to_string(&path).unwrap();
+        assert!(!json.contains("undo_journal"));
+
+        state.record_undo(cascade_undo(), Utc::now());
+        state.save_to(&path).unwrap();
+        let loaded = AppState::load_from(&path).unwrap();
+        assert!(matches!(
+            loaded.undo_journal.as_slice(),
+            [UndoEntry {
+                action: UndoAction::CascadeAbandon { .. },
+                ..
+            }]
+        ));
+    }
+
     #[test]
     fn test_cascade_paused_at_defaults_to_none() {
         let state = AppState::new();
@@ -1,4 +1,4 @@
-//! Session view mode (project / sections / stacks / board).
+//! Session view mode (project / sections / stacks / board / grid).
 //!
 //! Lives in `config` rather than `tui` because the user's last-selected view
 //! is persisted (in `tui.json`). The TUI re-exports it from `crate::tui::app`
@@ -7,10 +7,10 @@
 use serde::{Deserialize, Serialize};
 
 /// Which view is active. Cycled with the `ToggleViewMode` key (default `v`):
-/// Project → Sections → Section Stacks → Board → (repeat).
+/// Project → Sections → Section Stacks → Board → Grid → (repeat).
 ///
 /// The first three are single-pane **list** views (a tree of sections →
-/// projects → sessions); `Board` is the full-screen kanban board. There is no
+/// projects → sessions); `Board` and `Grid` are full-screen. There is no
 /// right pane in any view — session detail is the `i` Info modal.
 /// * `ProjectGrouped` — flat tree, sessions indented under their project,
 ///   stacks indented under their parent.
@@ -22,6 +22,8 @@ use serde::{Deserialize, Serialize};
 ///   back to `ProjectGrouped`).
 /// * `Board` — full-screen kanban board (sections as columns, sessions as
 ///   cards).
+/// * `Grid` — full-screen watch grid: one tile per active session showing the
+///   tail of its live agent pane.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ViewMode {
     ProjectGrouped,
@@ -33,27 +35,32 @@ pub enum ViewMode {
     SectionStacks,
     /// Full-screen kanban board (sections as columns, sessions as cards).
     Board,
+    /// Full-screen watch grid of live pane captures, one tile per session.
+    Grid,
 }
 
 impl ViewMode {
-    /// The next view in the cycle: Project → Sections → Stacks → Board → Project.
+    /// The next view in the cycle: Project → Sections → Stacks → Board → Grid →
+    /// Project.
     pub fn next(self) -> Self {
         match self {
             Self::ProjectGrouped => Self::SectionGrouped,
             Self::SectionGrouped => Self::SectionStacks,
             Self::SectionStacks => Self::Board,
-            Self::Board => Self::ProjectGrouped,
+            Self::Board => Self::Grid,
+            Self::Grid => Self::ProjectGrouped,
         }
     }
 
     /// Heading label rendered above the session tree (list views only; the
-    /// board draws its own top bar).
+    /// board and grid draw their own top bar).
     pub fn heading_label(self) -> &'static str {
         match self {
             Self::ProjectGrouped => " Sessions [Project]:",
             Self::SectionGrouped => " Sessions [Sections]:",
             Self::SectionStacks => " Sessions [Section Stacks]:",
             Self::Board => " Board:",
+            Self::Grid => " Grid:",
         }
     }
 
@@ -66,6 +73,16 @@ impl ViewMode {
     pub fn is_board(self) -> bool {
         matches!(self, Self::Board)
     }
+
+    /// Whether this is the watch grid.
+    pub fn is_grid(self) -> bool {
+        matches!(self, Self::Grid)
+    }
+
+    /// Whether this view takes over the whole screen (no session tree).
+    pub fn is_full_screen(self) -> bool {
+        matches!(self, Self::Board | Self::Grid)
+    }
 }
 
 #[cfg(test)]
@@ -73,11 +90,12 @@ mod tests {
     use super::*;
 
     #[test]
-    fn next_cycles_project_sections_stacks_board() {
+    fn next_cycles_project_sections_stacks_board_grid() {
         assert_eq!(ViewMode::ProjectGrouped.next(), ViewMode::SectionGrouped);
         assert_eq!(ViewMode::SectionGrouped.next(), ViewMode::SectionStacks);
         assert_eq!(ViewMode::SectionStacks.next(), ViewMode::Board);
-        assert_eq!(ViewMode::Board.next(), ViewMode::ProjectGrouped);
+        assert_eq!(ViewMode::Board.next(), ViewMode::Grid);
+        assert_eq!(ViewMode::Grid.next(), ViewMode::ProjectGrouped);
     }
 
     #[test]
@@ -0,0 +1,675 @@
+//! Spoken announcements of session state changes.
+//!
+//! "Session 3, payments refactor, is waiting for input" or "CI failed on stack
+//! base": [`Announcer`] diffs successive backend snapshots for the agent-state
+//! and PR transitions worth hearing about while away from the screen, renders
+//! the configured sentence for each enabled one, and queues it.
+//! [`Announcer::next`] hands them out one at a time — rate-limited, never
+//! during quiet hours, never while the conversation agent is mid-reply, and
+//! dropping any that went stale or stopped being true while they waited.
+//!
+//! The announcer is pure and unit-tested. [`AnnouncerHandle`] shares it
+//! between the backend change feeds (which observe every snapshot off the UI
+//! loop, so announcements keep coming during a tmux attach) and
+//! [`spawn_announcer`]'s task, which speaks through the conversation speaker.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::NaiveTime;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use super::commands::spoken_title;
+use super::media::MediaSignal;
+use super::speaker::{SpeakerCommand, SpeakerHandle, spawn_speaker};
+use crate::api::SessionInfo;
+use crate::config::{
+    AnnouncementEvents, AnnouncementRule, AnnouncementsConfig, ConversationConfig,
+};
+use crate::git::{ChecksStatus, PrState, ReviewDecision};
+use crate::session::{AgentState, SessionId};
+
+/// An announcement still queued after this long is dropped: by then it is
+/// news the user has likely seen for themselves.
+const STALE_AFTER: Duration = Duration::from_secs(120);
+
+/// Queued announcements beyond this many drop the oldest, so a burst of
+/// changes (a cascade, a CI run across a stack) can't talk for minutes.
+const MAX_PENDING: usize = 5;
+
+/// How often the announcer task checks for something to say.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A transition that can be announced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnouncementEvent {
+    WaitingForInput,
+    Finished,
+    CiFailed,
+    CiPassed,
+    PrMerged,
+    PrClosed,
+    ChangesRequested,
+    Approved,
+}
+
+impl AnnouncementEvent {
+    /// This event's rule in the config.
+    fn rule(self, events: &AnnouncementEvents) -> &AnnouncementRule {
+        match self {
+            Self::WaitingForInput => &events.waiting_for_input,
+            Self::Finished => &events.finished,
+            Self::CiFailed => &events.ci_failed,
+            Self::CiPassed => &events.ci_passed,
+            Self::PrMerged => &events.pr_merged,
+            Self::PrClosed => &events.pr_closed,
+            Self::ChangesRequested => &events.changes_requested,
+            Self::Approved => &events.approved,
+        }
+    }
+
+    /// What is said when the rule sets no template.
+    pub fn default_template(self) -> &'static str {
+        match self {
+            Self::WaitingForInput => "{session}, is waiting for input",
+            Self::Finished => "{session}, has finished",
+            Self::CiFailed => "CI failed on {title}",
+            Self::CiPassed => "CI passed on {title}",
+            Self::PrMerged => "{title} was merged",
+            Self::PrClosed => "{title} was closed",
+            Self::ChangesRequested => "Changes requested on {title}",
+            Self::Approved => "{title} was approved",
+        }
+    }
+
+    /// Whether the session is still in the state this event announced — a
+    /// queued "is waiting for input" is dropped once the agent is working
+    /// again.
+    fn holds(self, s: &Observed) -> bool {
+        match self {
+            Self::WaitingForInput => s.agent == Some(AgentState::WaitingForInput),
+            Self::Finished => s.agent == Some(AgentState::Idle),
+            Self::CiFailed => s.checks == Some(ChecksStatus::Failing),
+            Self::CiPassed => s.checks == Some(ChecksStatus::Passing),
+            Self::PrMerged => s.pr_state == Some(PrState::Merged),
+            Self::PrClosed => s.pr_state == Some(PrState::Closed),
+            Self::ChangesRequested => s.review == Some(ReviewDecision::ChangesRequested),
+            Self::Approved => s.review == Some(ReviewDecision::Approved),
+        }
+    }
+}
+
+/// The parts of a session transitions are detected on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Observed {
+    agent: Option<AgentState>,
+    /// `None` while the session has no PR.
+    pr_state: Option<PrState>,
+    checks: Option<ChecksStatus>,
+    review: Option<ReviewDecision>,
+}
+
+impl Observed {
+    fn of(session: &SessionInfo, agent: Option<AgentState>) -> Self {
+        let has_pr = session.pr_number.is_some();
+        Self {
+            agent,
+            pr_state: has_pr.then_some(session.pr_state),
+            checks: session.pr_checks.filter(|_| has_pr),
+            review: session.review_decision.filter(|_| has_pr),
+        }
+    }
+}
+
+/// The announceable events between two observations of one session. An
+/// agent move to or from [`AgentState::Unknown`] is a detection miss, not
+/// news. PR events need the PR to have been known before, so discovering an
+/// existing PR (say, after its server restarted) announces nothing.
+fn transitions(prev: &Observed, next: &Observed) -> Vec<AnnouncementEvent> {
+    use AnnouncementEvent as E;
+    let mut events = Vec::new();
+    if let (Some(from), Some(to)) = (prev.agent, next.agent)
+        && from != to
+        && from != AgentState::Unknown
+        && to != AgentState::Unknown
+    {
+        match to {
+            AgentState::WaitingForInput => events.push(E::WaitingForInput),
+            AgentState::Idle if from == AgentState::Working => events.push(E::Finished),
+            _ => {}
+        }
+    }
+    if prev.pr_state.is_none() {
+        return events;
+    }
+    if prev.pr_state == Some(PrState::Open) {
+        match next.pr_state {
+            Some(PrState::Merged) => events.push(E::PrMerged),
+            Some(PrState::Closed) => events.push(E::PrClosed),
+            _ => {}
+        }
+    }
+    if prev.checks != next.checks {
+        match next.checks {
+            Some(ChecksStatus::Failing) => events.push(E::CiFailed),
+            Some(ChecksStatus::Passing)
+                if matches!(
+                    prev.checks,
+                    Some(ChecksStatus::Pending | ChecksStatus::Failing)
+                ) =>
+            {
+                events.push(E::CiPassed)
+            }
+            _ => {}
+        }
+    }
+    if prev.review != next.review {
+        match next.review {
+            Some(ReviewDecision::ChangesRequested) => events.push(E::ChangesRequested),
+            Some(ReviewDecision::Approved) => events.push(E::Approved),
+            _ => {}
+        }
+    }
+    events
+}
+
+/// Fill in an announcement template for `session`. `number` is its number on
+/// the board, when it has one.
+pub fn render(template: &str, session: &SessionInfo, number: Option<usize>) -> String {
+    let title = spoken_title(&session.title);
+    let whole = match number {
+        Some(n) => format!("Session {n}, {title}"),
+        None => title.clone(),
+    };
+    let values = [
+        ("{session}", whole),
+        (
+            "{number}",
+            number.map(|n| n.to_string()).unwrap_or_default(),
+        ),
+        ("{title}", title),
+        ("{branch}", spoken_title(&session.branch)),
+        ("{project}", session.project_name.clone()),
+        (
+            "{pr}",
+            session.pr_number.map(|n| n.to_string()).unwrap_or_default(),
+        ),
+    ];
+    let mut out = template.to_string();
+    for (placeholder, value) in values {
+        out = out.replace(placeholder, &value);
+    }
+    out
+}
+
+/// A local-time window during which nothing is announced. Parsed from
+/// `"HH:MM-HH:MM"`; the window may wrap midnight (`"22:00-07:00"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+impl QuietHours {
+    /// Whether `time` falls inside the window (start inclusive, end exclusive).
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+impl FromStr for QuietHours {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| format!("expected HH:MM-HH:MM, got {s:?}"))?;
+        let time = |t: &str| {
+            NaiveTime::parse_from_str(t.trim(), "%H:%M").map_err(|e| format!("{t:?}: {e}"))
+        };
+        Ok(Self {
+            start: time(start)?,
+            end: time(end)?,
+        })
+    }
+}
+
+/// One queued announcement.
+#[derive(Debug)]
+struct Pending {
+    event: AnnouncementEvent,
+    backend: usize,
+    session: SessionId,
+    text: String,
+    queued_at: Instant,
+}
+
+/// Turns snapshot-to-snapshot changes into a paced queue of sentences to say.
+#[derive(Debug, Default)]
+pub struct Announcer {
+    config: AnnouncementsConfig,
+    quiet_hours: Option<QuietHours>,
+    /// Last observation of every session, per backend. A backend's first
+    /// snapshot only sets its baseline.
+    seen: HashMap<usize, HashMap<SessionId, Observed>>,
+    /// Board numbers, for `{session}` / `{number}`.
+    numbers: HashMap<SessionId, usize>,
+    pending: VecDeque<Pending>,
+    last_spoken: Option<Instant>,
+}
+
+impl Announcer {
+    /// Apply (new) settings. Disabling drops anything queued; an invalid
+    /// quiet-hours window is logged and ignored.
+    pub fn configure(&mut self, config: AnnouncementsConfig) {
+        self.quiet_hours =
+            config
+                .quiet_hours
+                .as_deref()
+                .and_then(|s| match s.parse::<QuietHours>() {
+                    Ok(q) => Some(q),
+                    Err(e) => {
+                        warn!("Ignoring invalid announcements.quiet_hours: {e}");
+                        None
+                    }
+                });
+        if !config.enabled {
+            self.pending.clear();
+        }
+        self.config = config;
+    }
+
+    /// Set the board numbers sessions are announced by.
+    pub fn set_numbers(&mut self, numbers: HashMap<SessionId, usize>) {
+        self.numbers = numbers;
+    }
+
+    /// Diff `backend`'s latest sessions and agent states against its previous
+    /// snapshot, queueing an announcement for each enabled transition. The
+    /// baseline is kept up to date even while announcements are off, so
+    /// turning them on never replays old changes.
+    pub fn observe(
+        &mut self,
+        backend: usize,
+        sessions: &[SessionInfo],
+        states: &BTreeMap<SessionId, AgentState>,
+        now: Instant,
+    ) {
+        let next: HashMap<SessionId, Observed> = sessions
+            .iter()
+            .map(|s| {
+                (
+                    s.session_id,
+                    Observed::of(s, states.get(&s.session_id).copied()),
+                )
+            })
+            .collect();
+        let Some(prev) = self.seen.insert(backend, next.clone()) else {
+            return;
+        };
+        if !self.config.enabled {
+            return;
+        }
+        for session in sessions {
+            let Some(before) = prev.get(&session.session_id) else {
+                continue;
+            };
+            for event in transitions(before, &next[&session.session_id]) {
+                let rule = event.rule(&self.config.events);
+                if !rule.enabled {
+                    continue;
+                }
+                let template = rule.template.as_deref().unwrap_or(event.default_template());
+                let number = self.numbers.get(&session.session_id).copied();
+                self.pending.push_back(Pending {
+                    event,
+                    backend,
+                    session: session.session_id,
+                    text: render(template, session, number),
+                    queued_at: now,
+                });
+            }
+        }
+        while self.pending.len() > MAX_PENDING {
+            self.pending.pop_front();
+        }
+    }
+
+    /// The next sentence to say, if one is due: not during quiet hours (which
+    /// drop the queue), not while `busy` (the conversation is recording or
+    /// replying), and not within `min_interval_secs` of the last one. Stale
+    /// announcements, and those whose state has since changed back, are
+    /// dropped on the way.
+    pub fn next(&mut self, now: Instant, local_time: NaiveTime, busy: bool) -> Option<String> {
+        let seen = &self.seen;
+        self.pending.retain(|p| {
+            now.duration_since(p.queued_at) < STALE_AFTER
+                && seen
+                    .get(&p.backend)
+                    .and_then(|s| s.get(&p.session))
+                    .is_some_and(|o| p.event.holds(o))
+        });
+        if self.pending.is_empty() {
+            return None;
+        }
+        if self.quiet_hours.is_some_and(|q| q.contains(local_time)) {
+            self.pending.clear();
+            return None;
+        }
+        let interval = Duration::from_secs(self.config.min_interval_secs);
+        if busy
+            || self
+                .last_spoken
+                .is_some_and(|t| now.duration_since(t) < interval)
+        {
+            return None;
+        }
+        let next = self.pending.pop_front()?;
+        self.last_spoken = Some(now);
+        Some(next.text)
+    }
+}
+
+/// Shared handle to the [`Announcer`]: fed by the backend change feeds,
+/// drained by [`spawn_announcer`]'s task, configured from the loop. Also
+/// carries the TTS settings the announcer task brings a speaker up with.
+#[derive(Clone, Default)]
+pub struct AnnouncerHandle {
+    announcer: Arc<Mutex<Announcer>>,
+    tts: Arc<Mutex<ConversationConfig>>,
+}
+
+impl AnnouncerHandle {
+    /// Apply the announcement and TTS settings.
+    pub fn configure(&self, config: AnnouncementsConfig, tts: ConversationConfig) {
+        self.announcer.lock().unwrap().configure(config);
+        *self.tts.lock().unwrap() = tts;
+    }
+
+    /// Whether announcements are switched on.
+    pub fn is_enabled(&self) -> bool {
+        self.announcer.lock().unwrap().config.enabled
+    }
+
+    /// See [`Announcer::set_numbers`].
+    pub fn set_numbers(&self, numbers: HashMap<SessionId, usize>) {
+        self.announcer.lock().unwrap().set_numbers(numbers);
+    }
+
+    /// See [`Announcer::observe`].
+    pub fn observe(
+        &self,
+        backend: usize,
+        sessions: &[SessionInfo],
+        states: &BTreeMap<SessionId, AgentState>,
+    ) {
+        self.announcer
+            .lock()
+            .unwrap()
+            .observe(backend, sessions, states, Instant::now());
+    }
+
+    /// See [`Announcer::next`]; uses the current local time.
+    fn next(&self, busy: bool) -> Option<String> {
+        let local_time = chrono::Local::now().time();
+        self.announcer
+            .lock()
+            .unwrap()
+            .next(Instant::now(), local_time, busy)
+    }
+}
+
+/// Start the task that speaks due announcements through `speaker`, checking
+/// `busy` (the conversation is recording or replying) first. When no speaker
+/// is up — conversation mode was never started, or is off — one is brought up
+/// with the handle's TTS settings and shared through `speaker`. Runs for the
+/// life of the process; it idles while announcements are off.
+pub fn spawn_announcer(
+    handle: AnnouncerHandle,
+    speaker: SpeakerHandle,
+    gate: Option<mpsc::UnboundedSender<MediaSignal>>,
+    busy: impl Fn() -> bool + Send + 'static,
+) {
+    tokio::spawn(async move {
+        let mut tick = tokio::time::interval(POLL_INTERVAL);
+        loop {
+            tick.tick().await;
+            if !handle.is_enabled() {
+                continue;
+            }
+            let Some(text) = handle.next(busy()) else {
+                continue;
+            };
+            if !speaker.is_present() {
+                let tts = handle.tts.lock().unwrap().clone();
+                match spawn_speaker(tts, gate.clone(), speaker.speaking()) {
+                    Ok(tx) => speaker.set(Some(tx)),
+                    Err(e) => {
+                        warn!(target: "conversation", "announcement dropped, TTS unavailable: {e}");
+                        continue;
+                    }
+                }
+            }
+            tracing::debug!(target: "conversation", "announce: {text:?}");
+            speaker.send(SpeakerCommand::Announce(text));
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::{ProjectId, WorktreeSession};
+    use std::path::PathBuf;
+
+    fn session(title: &str) -> SessionInfo {
+        let s = WorktreeSession::new(
+            ProjectId::new(),
+            title,
+            title,
+            PathBuf::from("/tmp/wt"),
+            "claude",
+        );
+        crate::api::session_info_from_session(&s, "shop")
+    }
+
+    fn with_pr(mut s: SessionInfo, checks: ChecksStatus) -> SessionInfo {
+        s.pr_number = Some(42);
+        s.pr_state = PrState::Open;
+        s.pr_checks = Some(checks);
+        s
+    }
+
+    fn enabled() -> Announcer {
+        let mut a = Announcer::default();
+        a.configure(AnnouncementsConfig {
+            enabled: true,
+            ..Default::default()
+        });
+        a
+    }
+
+    fn noon() -> NaiveTime {
+        NaiveTime::from_hms_opt(12, 0, 0).unwrap()
+    }
+
+    fn states(s: &SessionInfo, state: AgentState) -> BTreeMap<SessionId, AgentState> {
+        BTreeMap::from([(s.session_id, state)])
+    }
+
+    #[test]
+    fn announces_a_session_starting_to_wait() {
+        let mut a = enabled();
+        let s = session("payments-refactor");
+        a.set_numbers(HashMap::from([(s.session_id, 3)]));
+        let t0 = Instant::now();
+        // The first snapshot is only a baseline.
+        a.observe(
+            0,
+            &[s.clone()],
+            &states(&s, AgentState::WaitingForInput),
+            t0,
+        );
+        assert_eq!(a.next(t0, noon(), false), None);
+
+        a.observe(0, &[s.clone()], &states(&s, AgentState::Working), t0);
+        a.observe(
+            0,
+            &[s.clone()],
+            &states(&s, AgentState::WaitingForInput),
+            t0,
+        );
+        assert_eq!(
+            a.next(t0, noon(), false).as_deref(),
+            Some("Session 3, payments refactor, is waiting for input")
+        );
+        assert_eq!(a.next(t0, noon(), false), None);
+    }
+
+    #[test]
+    fn default_rules_skip_finished_and_honour_templates() {
+        let mut a = Announcer::default();
+        let mut config = AnnouncementsConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        config.events.ci_failed.template = Some("{project}: PR {pr} is red".into());
+        a.configure(config);
+        let s = session("stack-base");
+        let t0 = Instant::now();
+        a.observe(
+            0,
+            &[with_pr(s.clone(), ChecksStatus::Pending)],
+            &states(&s, AgentState::Working),
+            t0,
+        );
+        a.observe(
+            0,
+            &[with_pr(s.clone(), ChecksStatus::Failing)],
+            &states(&s, AgentState::Idle),
+            t0,
+        );
+        // Working → Idle is off by default; the CI failure uses the template.
+        assert_eq!(
+            a.next(t0, noon(), false).as_deref(),
+            Some("shop: PR 42 is red")
+        );
+        assert_eq!(a.next(t0, noon(), false), None);
+    }
+
+    #[test]
+    fn pr_transitions_need_a_known_pr_and_ignore_unknown_agent_states() {
+        let open = with_pr(session("x"), ChecksStatus::Pending);
+        let before = Observed::of(&open, Some(AgentState::Working));
+        let mut merged = open.clone();
+        merged.pr_state = PrState::Merged;
+        merged.review_decision = Some(ReviewDecision::Approved);
+        assert_eq!(
+            transitions(&before, &Observed::of(&merged, Some(AgentState::Unknown))),
+            vec![AnnouncementEvent::PrMerged, AnnouncementEvent::Approved]
+        );
+        // Discovering an already-merged PR is not news.
+        let no_pr = Observed::of(&session("x"), None);
+        assert!(transitions(&no_pr, &Observed::of(&merged, None)).is_empty());
+    }
+
+    #[test]
+    fn rate_limit_busy_and_staleness() {
+        let mut a = enabled();
+        let x = session("x");
+        let y = session("y");
+        let both = [x.clone(), y.clone()];
+        let working = BTreeMap::from([
+            (x.session_id, AgentState::Working),
+            (y.session_id, AgentState::Working),
+        ]);
+        let waiting = BTreeMap::from([
+            (x.session_id, AgentState::WaitingForInput),
+            (y.session_id, AgentState::WaitingForInput),
+        ]);
+        let t0 = Instant::now();
+        a.observe(0, &both, &working, t0);
+        a.observe(0, &both, &waiting, t0);
+
+        // Never over a reply in progress.
+        assert_eq!(a.next(t0, noon(), true), None);
+        assert_eq!(
+            a.next(t0, noon(), false).as_deref(),
+            Some("x, is waiting for input")
+        );
+        // The second waits out the interval.
+        assert_eq!(a.next(t0 + Duration::from_secs(5), noon(), false), None);
+        assert_eq!(
+            a.next(t0 + Duration::from_secs(15), noon(), false)
+                .as_deref(),
+            Some("y, is waiting for input")
+        );
+
+        // An announcement that is no longer true, or has waited too long, is
+        // dropped.
+        a.observe(0, &both, &working, t0);
+        a.observe(0, &both, &waiting, t0);
+        a.observe(0, &both, &states(&x, AgentState::Working), t0);
+        assert_eq!(
+            a.next(t0 + Duration::from_secs(30), noon(), false)
+                .as_deref(),
+            Some("y, is waiting for input")
+        );
+        a.observe(0, &both, &working, t0);
+        a.observe(0, &both, &waiting, t0);
+        assert_eq!(a.next(t0 + STALE_AFTER, noon(), false), None);
+    }
+
+    #[test]
+    fn quiet_hours_wrap_midnight_and_drop_the_queue() {
+        let q: QuietHours = "22:00-07:00".parse().unwrap();
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        assert!(q.contains(at(23, 30)) && q.contains(at(3, 0)));
+        assert!(!q.contains(at(7, 0)) && !q.contains(at(12, 0)));
+        assert!("22:00".parse::<QuietHours>().is_err());
+
+        let mut a = Announcer::default();
+        a.configure(AnnouncementsConfig {
+            enabled: true,
+            quiet_hours: Some("22:00-07:00".into()),
+            ..Default::default()
+        });
+        let s = session("x");
+        let t0 = Instant::now();
+        a.observe(0, &[s.clone()], &states(&s, AgentState::Working), t0);
+        a.observe(
+            0,
+            &[s.clone()],
+            &states(&s, AgentState::WaitingForInput),
+            t0,
+        );
+        assert_eq!(a.next(t0, at(23, 0), false), None);
+        assert_eq!(a.next(t0, noon(), false), None);
+    }
+
+    #[test]
+    fn disabled_keeps_the_baseline_without_queueing() {
+        let mut a = Announcer::default();
+        let s = session("x");
+        let t0 = Instant::now();
+        a.observe(0, &[s.clone()], &states(&s, AgentState::Working), t0);
+        a.observe(
+            0,
+            &[s.clone()],
+            &states(&s, AgentState::WaitingForInput),
+            t0,
+        );
+        a.configure(AnnouncementsConfig {
+            enabled: true,
+            ..Default::default()
+        });
+        assert_eq!(a.next(t0, noon(), false), None);
+    }
+}
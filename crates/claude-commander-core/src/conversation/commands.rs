@@ -0,0 +1,649 @@
+//! Voice commands: simple spoken requests the TUI runs itself.
+//!
+//! "Attach to session four", "open review for the auth branch" or "what's
+//! waiting on me" don't need a round trip through the conversation agent.
+//! [`recognise`] matches a transcript against a [`VoiceCatalog`] — the sessions
+//! on the board (by number, title or branch) and the palette commands — with
+//! the palette's own fuzzy matcher, and returns what to do plus a short spoken
+//! confirmation. It is deliberately conservative: every spoken word has to
+//! match something, and an ambiguous match is no match. Anything it doesn't
+//! recognise returns `None` and goes to the agent as before. Pure and
+//! unit-tested; the TUI builds the catalog and executes the result.
+
+use crate::config::BindableAction;
+use crate::fuzzy::fuzzy_score;
+use crate::session::{AgentState, SessionId};
+
+/// A session voice commands can refer to.
+#[derive(Debug, Clone)]
+pub struct VoiceSession {
+    pub id: SessionId,
+    /// The session's board number (the digit-jump number), when it has one.
+    pub number: Option<usize>,
+    pub title: String,
+    pub branch: String,
+    pub agent_state: Option<AgentState>,
+}
+
+/// Everything a transcript is matched against. Rebuilt by the TUI whenever its
+/// list refreshes and read off the UI loop by the transcript task.
+#[derive(Debug, Clone, Default)]
+pub struct VoiceCatalog {
+    pub sessions: Vec<VoiceSession>,
+    /// Palette commands voice may run (see [`voice_runnable`]), with their
+    /// palette labels.
+    pub commands: Vec<(BindableAction, &'static str)>,
+}
+
+/// What a recognised command does in the TUI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoiceAction {
+    /// Select the session and attach to it.
+    Attach(SessionId),
+    /// Select the session and open its review diff.
+    Review(SessionId),
+    /// Just select the session.
+    Select(SessionId),
+    /// Run a palette command against the current selection.
+    Run(BindableAction),
+}
+
+/// A transcript [`recognise`] understood.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recognised {
+    /// What to do; `None` for a question, where the reply is the answer.
+    pub action: Option<VoiceAction>,
+    /// Spoken confirmation (or answer).
+    pub reply: String,
+}
+
+/// Whether a palette command may be run by voice: only ones that move around,
+/// change what's shown, or open a dialog that still needs the keyboard to go
+/// ahead. `best_command` accepts a match on half a label, so anything that
+/// acts at once — pushing, merging, restarting, undoing, deleting — is left to
+/// the keyboard: a misheard word must never cost work. An allow-list, so a new
+/// palette command stays off voice until it's added here.
+pub fn voice_runnable(action: BindableAction) -> bool {
+    matches!(
+        action,
+        BindableAction::NavigateUp
+            | BindableAction::NavigateDown
+            | BindableAction::NextGroup
+            | BindableAction::PreviousGroup
+            | BindableAction::NavigateFirst
+            | BindableAction::NavigateLast
+            | BindableAction::NavigateLeft
+            | BindableAction::NavigateRight
+            | BindableAction::ListPageUp
+            | BindableAction::ListPageDown
+            | BindableAction::ScrollUp
+            | BindableAction::ScrollDown
+            | BindableAction::PageUp
+            | BindableAction::PageDown
+            | BindableAction::Select
+            | BindableAction::SelectShell
+            | BindableAction::NewSession
+            | BindableAction::NewStackedSession
+            | BindableAction::NewProject
+            | BindableAction::CloneRepository
+            | BindableAction::ScanDirectory
+            | BindableAction::RenameSession
+            | BindableAction::EditTags
+            | BindableAction::EditNotes
+            | BindableAction::ShowArchived
+            | BindableAction::OpenInfo
+            | BindableAction::SearchScrollback
+            | BindableAction::FilterSessions
+            | BindableAction::OpenPullRequest
+            | BindableAction::RefreshPrStatus
+            | BindableAction::ToggleConversationOverlay
+            | BindableAction::OpenReviewDiff
+            | BindableAction::ShowHelp
+            | BindableAction::ShowSettings
+            | BindableAction::ToggleViewMode
+            | BindableAction::ToggleSection
+            | BindableAction::CycleSwimlanes
+            | BindableAction::ToggleLane
+            | BindableAction::CycleGridFilter
+            | BindableAction::ToggleGridZoom
+            | BindableAction::TogglePane
+            | BindableAction::TogglePaneReverse
+            | BindableAction::ShrinkLeftPane
+            | BindableAction::GrowLeftPane
+            | BindableAction::InspectPane
+    )
+}
+
+/// Leading verbs that open a session's review diff.
+const REVIEW_VERBS: &[&str] = &[
+    "open the review for",
+    "open review for",
+    "open the review of",
+    "open review of",
+    "open the review",
+    "open review",
+    "show the diff for",
+    "show the diff of",
+    "show diff for",
+    "review",
+];
+
+/// Leading verbs that attach to a session.
+const ATTACH_VERBS: &[&str] = &[
+    "attach to",
+    "attach",
+    "switch to",
+    "jump to",
+    "go to",
+    "open",
+];
+
+/// Leading verbs that only select a session.
+const SELECT_VERBS: &[&str] = &[
+    "select",
+    "show me",
+    "show",
+    "focus on",
+    "focus",
+    "highlight",
+];
+
+/// Leading verbs that may precede a palette label ("open settings", "run
+/// refresh PR status").
+const COMMAND_VERBS: &[&str] = &["run", "open", "show me", "show", "go to"];
+
+/// Politeness and wake words stripped from the front of a transcript.
+const FILLER: &[&str] = &[
+    "please",
+    "can you",
+    "could you",
+    "would you",
+    "hey",
+    "ok",
+    "okay",
+    "now",
+    "and",
+    "so",
+];
+
+/// Words that carry no meaning when naming a session or a command.
+const NOISE: &[&str] = &[
+    "the", "a", "an", "my", "this", "that", "session", "branch", "worktree", "card", "number",
+];
+
+/// Match a transcript against the catalog. `None` means "not a command" — hand
+/// the transcript to the agent.
+pub fn recognise(transcript: &str, catalog: &VoiceCatalog) -> Option<Recognised> {
+    let normalised = normalise(transcript);
+    let phrase = strip_filler(&normalised);
+    if phrase.is_empty() {
+        return None;
+    }
+
+    if is_waiting_question(phrase) {
+        return Some(Recognised {
+            action: None,
+            reply: waiting_reply(&catalog.sessions),
+        });
+    }
+
+    // Session verbs first: "open settings" only falls through to the palette
+    // when no session answers to "settings".
+    type Verb = fn(SessionId) -> VoiceAction;
+    let verbs: [(&[&str], Verb); 3] = [
+        (REVIEW_VERBS, VoiceAction::Review),
+        (ATTACH_VERBS, VoiceAction::Attach),
+        (SELECT_VERBS, VoiceAction::Select),
+    ];
+    for (list, make) in verbs {
+        if let Some(target) = strip_verb(phrase, list)
+            && let Some(session) = resolve_session(target, &catalog.sessions)
+        {
+            let action = make(session.id);
+            let title = spoken_title(&session.title);
+            let reply = match action {
+                VoiceAction::Attach(_) => format!("Attaching to {title}."),
+                VoiceAction::Review(_) => format!("Opening the review for {title}."),
+                _ => format!("Selected {title}."),
+            };
+            return Some(Recognised {
+                action: Some(action),
+                reply,
+            });
+        }
+    }
+
+    let (action, label) = match_command(phrase, &catalog.commands)?;
+    Some(Recognised {
+        action: Some(VoiceAction::Run(action)),
+        reply: format!("Okay, {}.", label.to_lowercase()),
+    })
+}
+
+/// Lowercase, drop apostrophes ("what's" → "whats"), turn every other
+/// non-alphanumeric into a space and collapse runs of whitespace.
+fn normalise(text: &str) -> String {
+    let cleaned: String = text
+        .to_lowercase()
+        .chars()
+        .filter(|c| !matches!(c, '\'' | '’'))
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn strip_filler(mut phrase: &str) -> &str {
+    loop {
+        let before = phrase;
+        for filler in FILLER {
+            if let Some(rest) = strip_words(phrase, filler) {
+                phrase = rest;
+            }
+        }
+        phrase = phrase.strip_suffix(" please").unwrap_or(phrase);
+        if phrase == before {
+            return phrase;
+        }
+    }
+}
+
+/// `phrase` without the leading whole-word `prefix`, if it starts with it.
+fn strip_words<'a>(phrase: &'a str, prefix: &str) -> Option<&'a str> {
+    let rest = phrase.strip_prefix(prefix)?;
+    if rest.is_empty() {
+        Some(rest)
+    } else {
+        rest.strip_prefix(' ')
+    }
+}
+
+/// The target after the first matching verb. Verb lists run longest first, so
+/// "open review for x" isn't read as "open" + "review for x".
+fn strip_verb<'a>(phrase: &'a str, verbs: &[&str]) -> Option<&'a str> {
+    verbs
+        .iter()
+        .find_map(|verb| strip_words(phrase, verb))
+        .filter(|target| !target.is_empty())
+}
+
+/// The one session `target` names: by board number ("four", "4", "number
+/// twelve"), else by title or branch. `None` when nothing or more than one
+/// session matches equally well.
+fn resolve_session<'a>(target: &str, sessions: &'a [VoiceSession]) -> Option<&'a VoiceSession> {
+    let words: Vec<&str> = target.split(' ').filter(|w| !NOISE.contains(w)).collect();
+    if words.is_empty() {
+        return None;
+    }
+    if let Some(n) = parse_number(&words) {
+        return sessions.iter().find(|s| s.number == Some(n));
+    }
+
+    let mut best: Option<(i64, &VoiceSession)> = None;
+    let mut tied = false;
+    for session in sessions {
+        let score = [&session.title, &session.branch]
+            .into_iter()
+            .filter_map(|name| match_words(&normalise(name), &words))
+            .max();
+        match (score, best) {
+            (None, _) => {}
+            (Some(s), Some((b, _))) if s < b => {}
+            (Some(s), Some((b, _))) if s == b => tied = true,
+            (Some(s), _) => {
+                best = Some((s, session));
+                tied = false;
+            }
+        }
+    }
+    if tied { None } else { best.map(|(_, s)| s) }
+}
+
+/// The palette command `phrase` names, taken as spoken or without a leading
+/// verb — labels like "Show help" carry their own.
+fn match_command(
+    phrase: &str,
+    commands: &[(BindableAction, &'static str)],
+) -> Option<(BindableAction, &'static str)> {
+    [Some(phrase), strip_verb(phrase, COMMAND_VERBS)]
+        .into_iter()
+        .flatten()
+        .find_map(|p| best_command(p, commands))
+}
+
+/// The one command whose label `phrase` matches. Every spoken word must match a
+/// word of the label, and together they must cover at least half of it —
+/// "refresh" alone is "Refresh PR status", "status" alone is nothing.
+fn best_command(
+    phrase: &str,
+    commands: &[(BindableAction, &'static str)],
+) -> Option<(BindableAction, &'static str)> {
+    let words: Vec<&str> = phrase.split(' ').filter(|w| !NOISE.contains(w)).collect();
+    if words.is_empty() {
+        return None;
+    }
+
+    let mut best: Option<(i64, (BindableAction, &'static str))> = None;
+    let mut tied = false;
+    for &(action, label) in commands {
+        let label_norm = normalise(label);
+        let label_words: Vec<&str> = label_norm
+            .split(' ')
+            .filter(|w| !NOISE.contains(w))
+            .collect();
+        let covered = label_words
+            .iter()
+            .filter(|lw| words.iter().any(|w| word_score(lw, w).is_some()))
+            .count();
+        if covered * 2 < label_words.len() {
+            continue;
+        }
+        let Some(score) = match_words(&label_norm, &words) else {
+            continue;
+        };
+        match best {
+            Some((b, _)) if score < b => {}
+            Some((b, _)) if score == b => tied = true,
+            _ => {
+                best = Some((score, (action, label)));
+                tied = false;
+            }
+        }
+    }
+    if tied { None } else { best.map(|(_, c)| c) }
+}
+
+/// Score `words` against the (normalised) `name`: each spoken word takes its
+/// best-matching word of the name. `None` unless every spoken word matches.
+fn match_words(name: &str, words: &[&str]) -> Option<i64> {
+    let name_words: Vec<&str> = name.split(' ').collect();
+    words.iter().try_fold(0, |total, w| {
+        let best = name_words.iter().filter_map(|n| word_score(n, w)).max()?;
+        Some(total + best)
+    })
+}
+
+/// Fuzzy-match one spoken word against one word of a name. Either side may be
+/// the longer ("refreshing" against "refresh"), so the shorter is matched
+/// inside the longer — but only when it is a prefix of it or covers most of
+/// it, since a short word is a subsequence of nearly anything.
+fn word_score(name_word: &str, spoken: &str) -> Option<i64> {
+    let (long, short) = if name_word.len() >= spoken.len() {
+        (name_word, spoken)
+    } else {
+        (spoken, name_word)
+    };
+    let prefix = short.len() >= 3 && long.starts_with(short);
+    if !prefix && short.len() * 4 < long.len() * 3 {
+        return None;
+    }
+    fuzzy_score(long, short)
+}
+
+/// A board number spoken as digits or words, up to ninety-nine.
+fn parse_number(words: &[&str]) -> Option<usize> {
+    const UNITS: &[&str] = &[
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: &[&str] = &[
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    let unit = |w: &&str| UNITS.iter().position(|u| u == w);
+    let tens = |w: &&str| TENS.iter().position(|t| t == w).map(|i| (i + 2) * 10);
+    match words {
+        [w] => w.parse().ok().or_else(|| unit(w)).or_else(|| tens(w)),
+        [t, u] => Some(tens(t)? + unit(u).filter(|n| (1..10).contains(n))?),
+        _ => None,
+    }
+}
+
+/// "What's waiting on me", "who needs me", "is anything waiting" and the like.
+/// Matched as whole phrases so "what needs to change in the parser" still goes
+/// to the agent.
+fn is_waiting_question(phrase: &str) -> bool {
+    const WAITING_SUBJECTS: &[&str] = &[
+        "whats",
+        "what is",
+        "whos",
+        "who is",
+        "is anything",
+        "anything",
+        "is anyone",
+        "which sessions are",
+        "what sessions are",
+    ];
+    const WAITING_TAILS: &[&str] = &["", " on me", " for me", " on my input", " for my input"];
+    const NEEDS_SUBJECTS: &[&str] = &[
+        "what",
+        "who",
+        "which sessions",
+        "what sessions",
+        "does anything",
+        "does anyone",
+    ];
+    const NEEDS_TAILS: &[&str] = &[" me", " my attention", " my input", " attention", " input"];
+
+    let phrase = phrase
+        .strip_suffix(" right now")
+        .or_else(|| phrase.strip_suffix(" now"))
+        .unwrap_or(phrase);
+    let waiting = WAITING_SUBJECTS.iter().any(|s| {
+        phrase
+            .strip_prefix(s)
+            .and_then(|r| r.strip_prefix(" waiting"))
+            .is_some_and(|tail| WAITING_TAILS.contains(&tail))
+    });
+    let needs = NEEDS_SUBJECTS.iter().any(|s| {
+        phrase
+            .strip_prefix(s)
+            .and_then(|r| r.strip_prefix(" needs").or_else(|| r.strip_prefix(" need")))
+            .is_some_and(|tail| NEEDS_TAILS.contains(&tail))
+    });
+    waiting || needs
+}
+
+/// Spoken answer to [`is_waiting_question`]: the sessions whose agent is
+/// waiting for input, at most three by name.
+fn waiting_reply(sessions: &[VoiceSession]) -> String {
+    let waiting: Vec<String> = sessions
+        .iter()
+        .filter(|s| s.agent_state == Some(AgentState::WaitingForInput))
+        .map(|s| spoken_title(&s.title))
+        .collect();
+    match waiting.as_slice() {
+        [] => "Nothing is waiting on you.".to_string(),
+        [one] => format!("One session is waiting on you: {one}."),
+        [first @ .., last] if waiting.len() <= 3 => format!(
+            "{} sessions are waiting on you: {} and {last}.",
+            waiting.len(),
+            first.join(", ")
+        ),
+        _ => format!(
+            "{} sessions are waiting on you, including {}.",
+            waiting.len(),
+            waiting[..3].join(", ")
+        ),
+    }
+}
+
+/// A session title as it should be read aloud: separators become spaces.
+pub(super) fn spoken_title(title: &str) -> String {
+    title
+        .split(['-', '_', '/'])
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(number: usize, title: &str, state: Option<AgentState>) -> VoiceSession {
+        VoiceSession {
+            id: SessionId::new(),
+            number: Some(number),
+            title: title.to_string(),
+            branch: format!("feature/{title}"),
+            agent_state: state,
+        }
+    }
+
+    fn catalog() -> VoiceCatalog {
+        VoiceCatalog {
+            sessions: vec![
+                session(1, "fix-auth-flow", Some(AgentState::WaitingForInput)),
+                session(2, "parser-rewrite", Some(AgentState::Working)),
+                session(4, "docs-refresh", Some(AgentState::WaitingForInput)),
+            ],
+            commands: [
+                BindableAction::ShowSettings,
+                BindableAction::RefreshPrStatus,
+                BindableAction::ShowHelp,
+            ]
+            .into_iter()
+            .map(|a| (a, a.description()))
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn attaches_by_spoken_number() {
+        let c = catalog();
+        let r = recognise("Attach to session four.", &c).unwrap();
+        assert_eq!(r.action, Some(VoiceAction::Attach(c.sessions[2].id)));
+        assert_eq!(r.reply, "Attaching to docs refresh.");
+        // Digits and "number" work too; an unused number is no match.
+        let r = recognise("go to number 2", &c).unwrap();
+        assert_eq!(r.action, Some(VoiceAction::Attach(c.sessions[1].id)));
+        assert!(recognise("attach to session nine", &c).is_none());
+    }
+
+    #[test]
+    fn opens_review_by_branch_words() {
+        let c = catalog();
+        let r = recognise("Open review for the auth branch", &c).unwrap();
+        assert_eq!(r.action, Some(VoiceAction::Review(c.sessions[0].id)));
+        assert!(r.reply.contains("fix auth flow"), "reply: {}", r.reply);
+    }
+
+    #[test]
+    fn answers_whats_waiting_without_an_action() {
+        let r = recognise("What's waiting on me?", &catalog()).unwrap();
+        assert_eq!(r.action, None);
+        assert_eq!(
+            r.reply,
+            "2 sessions are waiting on you: fix auth flow and docs refresh."
+        );
+        let idle = VoiceCatalog::default();
+        assert_eq!(
+            recognise("who needs me", &idle).unwrap().reply,
+            "Nothing is waiting on you."
+        );
+    }
+
+    #[test]
+    fn runs_palette_commands_by_label() {
+        let c = catalog();
+        let r = recognise("please refresh PR status", &c).unwrap();
+        assert_eq!(
+            r.action,
+            Some(VoiceAction::Run(BindableAction::RefreshPrStatus))
+        );
+        // "open settings" names no session, so it falls to the palette.
+        let r = recognise("open settings", &c);
+        assert!(
+            matches!(
+                r,
+                Some(Recognised {
+                    action: Some(VoiceAction::Run(BindableAction::ShowSettings)),
+                    ..
+                })
+            ),
+            "{r:?}"
+        );
+    }
+
+    #[test]
+    fn anything_else_falls_through_to_the_agent() {
+        let c = catalog();
+        for transcript in [
+            "what needs to change in the parser so the tests pass",
+            "open the parser file and explain the tokenizer",
+            "why is the auth session failing",
+            "status",
+            "",
+        ] {
+            assert_eq!(recognise(transcript, &c), None, "{transcript:?}");
+        }
+    }
+
+    #[test]
+    fn ambiguous_session_names_fall_through() {
+        let mut c = catalog();
+        c.sessions.push(session(5, "auth-tokens", None));
+        assert_eq!(recognise("attach to auth", &c), None);
+    }
+
+    #[test]
+    fn destructive_commands_are_not_voice_runnable() {
+        for action in [
+            BindableAction::DeleteSession,
+            BindableAction::Quit,
+            BindableAction::PushStack,
+            BindableAction::CascadeMergeMain,
+            BindableAction::RestartSession,
+            BindableAction::Undo,
+        ] {
+            assert!(!voice_runnable(action), "{action:?}");
+        }
+        assert!(voice_runnable(BindableAction::RefreshPrStatus));
+    }
+
+    /// With the catalog the TUI builds — every voice-runnable command — a
+    /// stray "push" or "merge main" in a transcript never runs the stack
+    /// commands that act without a confirmation.
+    #[test]
+    fn push_and_merge_main_are_not_run_by_voice() {
+        let mut c = catalog();
+        c.commands = BindableAction::ALL
+            .iter()
+            .copied()
+            .filter(|&a| voice_runnable(a))
+            .map(|a| (a, a.description()))
+            .collect();
+        for transcript in ["push", "push the stack", "merge main", "restart", "undo"] {
+            let action = recognise(transcript, &c).and_then(|r| r.action);
+            assert!(
+                !matches!(
+                    action,
+                    Some(VoiceAction::Run(
+                        BindableAction::PushStack
+                            | BindableAction::CascadeMergeMain
+                            | BindableAction::RestartSession
+                            | BindableAction::Undo
+                    ))
+                ),
+                "{transcript:?} ran {action:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,447 @@
+//! Persistent conversation history.
+//!
+//! Every turn shown in the conversation overlay — what the user typed or said,
+//! what the agent replied, when, and whether it was spoken — is appended to a
+//! JSONL file per conversation under `<data_dir>/conversation/history/`. A
+//! conversation's file also records each headless session id `claude` reports
+//! for it (a resume can fork a fresh one), so an old conversation can be picked
+//! up again with `--resume` on the latest.
+//!
+//! Reading, searching and the markdown export are pure; [`HistoryLog`] owns the
+//! writes, appending from its own task so the overlay never waits on disk.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use crate::error::TtsError;
+
+/// Longest conversation title, in characters, before it is cut short.
+const TITLE_LEN: usize = 60;
+
+/// Who authored a turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TurnRole {
+    User,
+    Assistant,
+}
+
+/// One turn of a conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryTurn {
+    pub role: TurnRole,
+    pub text: String,
+    pub at: DateTime<Utc>,
+    /// For the user, whether this was a voice transcript rather than typed; for
+    /// the assistant, whether the reply was read aloud.
+    #[serde(default)]
+    pub spoken: bool,
+}
+
+/// A line of a conversation file.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Record {
+    /// `claude` reported this session id for the conversation.
+    Session {
+        session_id: String,
+        at: DateTime<Utc>,
+    },
+    Turn(HistoryTurn),
+}
+
+/// A conversation as read back from disk.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StoredConversation {
+    pub id: String,
+    /// The latest headless session id, to `--resume`.
+    pub session_id: Option<String>,
+    pub turns: Vec<HistoryTurn>,
+}
+
+impl StoredConversation {
+    /// Parse a conversation file's contents. Lines that don't parse (a write
+    /// cut short by a crash) are skipped.
+    pub fn parse(id: &str, contents: &str) -> Self {
+        let mut conversation = Self {
+            id: id.to_string(),
+            ..Self::default()
+        };
+        for line in contents.lines().filter(|l| !l.trim().is_empty()) {
+            match serde_json::from_str(line) {
+                Ok(Record::Session { session_id, .. }) => {
+                    conversation.session_id = Some(session_id)
+                }
+                Ok(Record::Turn(turn)) => conversation.turns.push(turn),
+                Err(_) => {}
+            }
+        }
+        conversation
+    }
+
+    /// The first thing the user said, on one line and cut to length.
+    pub fn title(&self) -> String {
+        let first = self
+            .turns
+            .iter()
+            .find(|t| t.role == TurnRole::User)
+            .map(|t| t.text.split_whitespace().collect::<Vec<_>>().join(" "))
+            .unwrap_or_default();
+        if first.is_empty() {
+            return "(untitled)".to_string();
+        }
+        if first.chars().count() <= TITLE_LEN {
+            return first;
+        }
+        let cut: String = first.chars().take(TITLE_LEN - 1).collect();
+        format!("{}…", cut.trim_end())
+    }
+
+    /// When the first turn was taken.
+    pub fn started_at(&self) -> Option<DateTime<Utc>> {
+        self.turns.first().map(|t| t.at)
+    }
+
+    /// When the last turn was taken.
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.turns.last().map(|t| t.at)
+    }
+
+    /// The index of the first turn containing `query` (case-insensitive), or
+    /// `None` when no turn does. An empty query matches the first turn.
+    pub fn find(&self, query: &str) -> Option<usize> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return (!self.turns.is_empty()).then_some(0);
+        }
+        self.turns
+            .iter()
+            .position(|t| t.text.to_lowercase().contains(&query))
+    }
+
+    /// The conversation as a markdown document. `assistant` names the
+    /// assistant's turns.
+    pub fn to_markdown(&self, assistant: &str) -> String {
+        let mut out = format!("# {}\n\n", self.title());
+        if let Some(started) = self.started_at() {
+            out.push_str(&format!(
+                "_Started {} · {} turns_\n",
+                started.format("%Y-%m-%d %H:%M UTC"),
+                self.turns.len()
+            ));
+        }
+        for turn in &self.turns {
+            let who = match turn.role {
+                TurnRole::User => "You",
+                TurnRole::Assistant => assistant,
+            };
+            let how = match (turn.role, turn.spoken) {
+                (TurnRole::User, true) => ", by voice",
+                (TurnRole::Assistant, true) => ", spoken",
+                (_, false) => "",
+            };
+            out.push_str(&format!(
+                "\n**{who}** ({}{how}):\n\n{}\n",
+                turn.at.format("%H:%M"),
+                turn.text.trim()
+            ));
+        }
+        out
+    }
+}
+
+/// A fresh conversation id: sortable by start time, unique enough to never
+/// collide.
+pub fn new_conversation_id() -> String {
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    format!("{}-{}", Utc::now().format("%Y%m%d-%H%M%S"), &suffix[..8])
+}
+
+/// The conversation files under a history directory.
+#[derive(Debug, Clone)]
+pub struct ConversationHistory {
+    dir: PathBuf,
+}
+
+impl ConversationHistory {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path_for(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{id}.jsonl"))
+    }
+
+    /// Read one conversation. A conversation with no file yet is empty.
+    pub async fn load(&self, id: &str) -> Result<StoredConversation, TtsError> {
+        match tokio::fs::read_to_string(self.path_for(id)).await {
+            Ok(contents) => Ok(StoredConversation::parse(id, &contents)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(StoredConversation {
+                id: id.to_string(),
+                ..StoredConversation::default()
+            }),
+            Err(e) => Err(TtsError::History(e.to_string())),
+        }
+    }
+
+    /// Every conversation with at least one turn, most recently active first.
+    pub async fn list(&self) -> Result<Vec<StoredConversation>, TtsError> {
+        let mut entries = match tokio::fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(TtsError::History(e.to_string())),
+        };
+        let mut conversations = Vec::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| TtsError::History(e.to_string()))?
+        {
+            let path = entry.path();
+            let Some(id) = conversation_id(&path) else {
+                continue;
+            };
+            let conversation = self.load(&id).await?;
+            if !conversation.turns.is_empty() {
+                conversations.push(conversation);
+            }
+        }
+        conversations.sort_by(|a, b| b.updated_at().cmp(&a.updated_at()));
+        Ok(conversations)
+    }
+
+    /// Write `conversation` as markdown into `dir`, returning the file's path.
+    pub async fn export_markdown(
+        conversation: &StoredConversation,
+        assistant: &str,
+        dir: &Path,
+    ) -> Result<PathBuf, TtsError> {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| TtsError::History(e.to_string()))?;
+        let path = dir.join(format!("{}.md", conversation.id));
+        tokio::fs::write(&path, conversation.to_markdown(assistant))
+            .await
+            .map_err(|e| TtsError::History(e.to_string()))?;
+        Ok(path)
+    }
+
+    async fn append(&self, id: &str, record: &Record) -> Result<(), TtsError> {
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| TtsError::History(e.to_string()))?;
+        let mut line =
+            serde_json::to_string(record).map_err(|e| TtsError::History(e.to_string()))?;
+        line.push('\n');
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.path_for(id))
+            .await
+            .map_err(|e| TtsError::History(e.to_string()))?;
+        file.write_all(line.as_bytes())
+            .await
+            .map_err(|e| TtsError::History(e.to_string()))
+    }
+}
+
+/// The conversation id a history file holds, if `path` is one.
+fn conversation_id(path: &Path) -> Option<String> {
+    if path.extension()? != "jsonl" {
+        return None;
+    }
+    Some(path.file_stem()?.to_str()?.to_string())
+}
+
+/// Commands to the history writer.
+#[derive(Debug)]
+enum LogCommand {
+    Turn(HistoryTurn),
+    Session(String),
+    Switch(String),
+}
+
+/// Appends the current conversation's turns to disk from a task of its own.
+/// Cheap to clone; writes are best-effort (a failure is logged, and the
+/// conversation carries on unrecorded).
+#[derive(Debug, Clone)]
+pub struct HistoryLog {
+    tx: mpsc::UnboundedSender<LogCommand>,
+}
+
+impl HistoryLog {
+    /// Start the writer, recording into conversation `id`.
+    pub fn spawn(history: ConversationHistory, id: String) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel::<LogCommand>();
+        tokio::spawn(async move {
+            let mut id = id;
+            let mut session_id: Option<String> = None;
+            while let Some(cmd) = rx.recv().await {
+                let record = match cmd {
+                    LogCommand::Turn(turn) => Record::Turn(turn),
+                    // Only changes are worth a line.
+                    LogCommand::Session(sid) if session_id.as_ref() == Some(&sid) => continue,
+                    LogCommand::Session(sid) => {
+                        session_id = Some(sid.clone());
+                        Record::Session {
+                            session_id: sid,
+                            at: Utc::now(),
+                        }
+                    }
+                    LogCommand::Switch(next) => {
+                        id = next;
+                        session_id = None;
+                        continue;
+                    }
+                };
+                if let Err(e) = history.append(&id, &record).await {
+                    warn!(target: "conversation", "failed to record conversation history: {e}");
+                }
+            }
+        });
+        Self { tx }
+    }
+
+    /// Record a turn.
+    pub fn turn(&self, turn: HistoryTurn) {
+        let _ = self.tx.send(LogCommand::Turn(turn));
+    }
+
+    /// Record the headless session id `claude` reported.
+    pub fn session(&self, session_id: &str) {
+        let _ = self.tx.send(LogCommand::Session(session_id.to_string()));
+    }
+
+    /// Record into conversation `id` from now on.
+    pub fn switch(&self, id: &str) {
+        let _ = self.tx.send(LogCommand::Switch(id.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn(role: TurnRole, text: &str, minute: u32, spoken: bool) -> HistoryTurn {
+        use chrono::TimeZone;
+        HistoryTurn {
+            role,
+            text: text.to_string(),
+            at: Utc.with_ymd_and_hms(2026, 10, 18, 14, minute, 0).unwrap(),
+            spoken,
+        }
+    }
+
+    #[test]
+    fn parse_keeps_turns_and_the_latest_session_id() {
+        let lines = [
+            r#"{"kind":"session","session_id":"s1","at":"2026-10-18T14:00:00Z"}"#,
+            r#"{"kind":"turn","role":"user","text":"hi","at":"2026-10-18T14:00:00Z","spoken":true}"#,
+            r#"{"kind":"turn","role":"assistant","text":"hello","at":"2026-10-18T14:00:05Z"}"#,
+            r#"{"kind":"session","session_id":"s2","at":"2026-10-18T15:00:00Z"}"#,
+            // A line cut short by a crash is skipped.
+            r#"{"kind":"turn","role":"us"#,
+        ];
+        let c = StoredConversation::parse("c1", &lines.join("\n"));
+        assert_eq!(c.id, "c1");
+        assert_eq!(c.session_id.as_deref(), Some("s2"));
+        assert_eq!(c.turns.len(), 2);
+        assert!(c.turns[0].spoken);
+        assert!(!c.turns[1].spoken, "spoken defaults to false");
+    }
+
+    #[test]
+    fn title_is_the_first_user_turn_on_one_line() {
+        let mut c = StoredConversation::default();
+        assert_eq!(c.title(), "(untitled)");
+        c.turns
+            .push(turn(TurnRole::Assistant, "Welcome back", 0, true));
+        c.turns
+            .push(turn(TurnRole::User, "what's\nwaiting  on me", 1, true));
+        assert_eq!(c.title(), "what's waiting on me");
+        c.turns[1].text = "word ".repeat(30);
+        let title = c.title();
+        assert_eq!(title.chars().count(), TITLE_LEN);
+        assert!(title.ends_with('…'));
+    }
+
+    #[test]
+    fn find_matches_any_turn_case_insensitively() {
+        let c = StoredConversation {
+            turns: vec![
+                turn(TurnRole::User, "How is the Parser branch?", 0, false),
+                turn(TurnRole::Assistant, "CI failed on it", 1, true),
+            ],
+            ..StoredConversation::default()
+        };
+        assert_eq!(c.find(""), Some(0));
+        assert_eq!(c.find("parser"), Some(0));
+        assert_eq!(c.find("ci FAILED"), Some(1));
+        assert_eq!(c.find("deploy"), None);
+        assert_eq!(StoredConversation::default().find(""), None);
+    }
+
+    #[test]
+    fn markdown_lists_each_turn() {
+        let c = StoredConversation {
+            id: "c1".into(),
+            session_id: None,
+            turns: vec![
+                turn(TurnRole::User, "status?", 2, true),
+                turn(TurnRole::Assistant, "All green.", 3, true),
+                turn(TurnRole::User, "thanks", 4, false),
+            ],
+        };
+        let md = c.to_markdown("Claudette");
+        assert!(md.starts_with("# status?\n\n_Started 2026-10-18 14:02 UTC · 3 turns_\n"));
+        assert!(md.contains("\n**You** (14:02, by voice):\n\nstatus?\n"));
+        assert!(md.contains("\n**Claudette** (14:03, spoken):\n\nAll green.\n"));
+        assert!(md.contains("\n**You** (14:04):\n\nthanks\n"));
+    }
+
+    #[tokio::test]
+    async fn log_round_trips_through_the_history_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let history = ConversationHistory::new(dir.path().join("history"));
+        assert!(history.list().await.unwrap().is_empty());
+
+        let log = HistoryLog::spawn(history.clone(), "a".into());
+        log.session("s1");
+        log.session("s1"); // unchanged: not recorded again
+        log.turn(turn(TurnRole::User, "first", 0, false));
+        log.switch("b");
+        log.turn(turn(TurnRole::User, "second", 5, true));
+        // The writer drains in order; wait for the last record to land.
+        for _ in 0..100 {
+            if !history.load("b").await.unwrap().turns.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        let a = history.load("a").await.unwrap();
+        assert_eq!(a.session_id.as_deref(), Some("s1"));
+        assert_eq!(a.turns.len(), 1);
+        let all = history.list().await.unwrap();
+        let ids: Vec<&str> = all.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["b", "a"], "most recently active first");
+        let raw = std::fs::read_to_string(dir.path().join("history/a.jsonl")).unwrap();
+        assert_eq!(raw.lines().count(), 2);
+
+        let exports = dir.path().join("exports");
+        let path = ConversationHistory::export_markdown(&a, "Claudette", &exports)
+            .await
+            .unwrap();
+        assert_eq!(path, exports.join("a.md"));
+        assert!(
+            std::fs::read_to_string(path)
+                .unwrap()
+                .starts_with("# first")
+        );
+    }
+}
@@ -15,12 +15,14 @@
 //! the in-attach byte interceptor use — so an external toggle behaves
 //! identically and works even while the main loop is parked in a tmux attach.
 //! The client ([`send_command`] / [`send_default`]) backs the
-//! `claude-commander listen-toggle` subcommand.
+//! `claude-commander listen-toggle` subcommand, whose `--interrupt` barges in
+//! on a spoken reply without touching the microphone and whose `--agent` sends
+//! the recording to a named conversation agent (`toggle planner` on the wire).
 
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
 
 use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
 use tokio::net::{UnixListener, UnixStream};
@@ -39,14 +41,22 @@ pub fn default_socket_path() -> PathBuf {
     }
 }
 
-/// Map a one-line wire command to a [`ListenAction`].
-fn parse_action(line: &str) -> Option<ListenAction> {
-    match line.trim() {
-        "toggle" => Some(ListenAction::Toggle),
-        "start" => Some(ListenAction::Start),
-        "stop" => Some(ListenAction::Stop),
-        _ => None,
-    }
+/// Map a one-line wire command to a [`ListenAction`] and the conversation
+/// agent named after it, if any (`start planner`).
+fn parse_command(line: &str) -> Option<(ListenAction, Option<String>)> {
+    let line = line.trim();
+    let (word, agent) = match line.split_once(' ') {
+        Some((word, agent)) => (word, Some(agent.trim().to_string())),
+        None => (line, None),
+    };
+    let action = match word {
+        "toggle" => ListenAction::Toggle,
+        "start" => ListenAction::Start,
+        "stop" => ListenAction::Stop,
+        "interrupt" => ListenAction::Interrupt,
+        _ => return None,
+    };
+    Some((action, agent.filter(|a| !a.is_empty())))
 }
 
 /// The wire word for an action (client side).
@@ -55,21 +65,24 @@ fn action_word(action: ListenAction) -> &'static str {
         ListenAction::Toggle => "toggle",
         ListenAction::Start => "start",
         ListenAction::Stop => "stop",
+        ListenAction::Interrupt => "interrupt",
     }
 }
 
 /// Bind the socket (cleaning up a stale one from a crashed instance) and spawn
 /// the accept loop. Returns the bound path. An `AddrInUse` error with a *live*
 /// peer means another instance owns the socket — surfaced as an error so the
-/// caller can log and skip rather than stealing it.
+/// caller can log and skip rather than stealing it. A command naming an agent
+/// leaves its name in `voice_agent` for the transcript task to switch to.
 pub fn serve(
     path: PathBuf,
     listener: ListenerHandle,
     recording: Arc<AtomicBool>,
+    voice_agent: Arc<Mutex<Option<String>>>,
 ) -> std::io::Result<PathBuf> {
     let l = bind_with_cleanup(&path)?;
     info!(target: "conversation", "voice IPC listening on {}", path.display());
-    tokio::spawn(run_accept_loop(l, listener, recording));
+    tokio::spawn(run_accept_loop(l, listener, recording, voice_agent));
     Ok(path)
 }
 
@@ -100,13 +113,19 @@ fn bind_with_cleanup(path: &Path) -> std::io::Result<UnixListener> {
     }
 }
 
-async fn run_accept_loop(l: UnixListener, listener: ListenerHandle, recording: Arc<AtomicBool>) {
+async fn run_accept_loop(
+    l: UnixListener,
+    listener: ListenerHandle,
+    recording: Arc<AtomicBool>,
+    voice_agent: Arc<Mutex<Option<String>>>,
+) {
     loop {
         match l.accept().await {
             Ok((stream, _addr)) => {
                 let listener = listener.clone();
                 let recording = recording.clone();
-                tokio::spawn(handle_conn(stream, listener, recording));
+                let voice_agent = voice_agent.clone();
+                tokio::spawn(handle_conn(stream, listener, recording, voice_agent));
             }
             Err(e) => {
                 warn!(target: "conversation", "voice IPC accept failed: {e}");
@@ -117,7 +136,12 @@ async fn run_accept_loop(l: UnixListener, listener: ListenerHandle, recording: A
 }
 
 /// Read one command line, apply it, and write back the resulting state.
-async fn handle_conn(stream: UnixStream, listener: ListenerHandle, recording: Arc<AtomicBool>) {
+async fn handle_conn(
+    stream: UnixStream,
+    listener: ListenerHandle,
+    recording: Arc<AtomicBool>,
+    voice_agent: Arc<Mutex<Option<String>>>,
+) {
     let (read_half, mut write_half) = stream.into_split();
     let mut line = String::new();
     if BufReader::new(read_half)
@@ -127,8 +151,11 @@ async fn handle_conn(stream: UnixStream, listener: ListenerHandle, recording: Ar
     {
         return;
     }
-    let reply = match parse_action(&line) {
-        Some(action) => {
+    let reply = match parse_command(&line) {
+        Some((action, agent)) => {
+            if agent.is_some() {
+                *voice_agent.lock().unwrap() = agent;
+            }
             if apply_listen_action(&listener, &recording, action) {
                 "recording\n"
             } else {
@@ -141,16 +168,23 @@ async fn handle_conn(stream: UnixStream, listener: ListenerHandle, recording: Ar
 }
 
 /// Send a command to the default socket and return the one-line status reply.
-pub async fn send_default(action: ListenAction) -> std::io::Result<String> {
-    send_command(&default_socket_path(), action).await
+pub async fn send_default(action: ListenAction, agent: Option<&str>) -> std::io::Result<String> {
+    send_command(&default_socket_path(), action, agent).await
 }
 
-/// Connect to the TUI's IPC socket, send `action`, and return its status reply.
-pub async fn send_command(path: &Path, action: ListenAction) -> std::io::Result<String> {
+/// Connect to the TUI's IPC socket, send `action` (for `agent`, when named),
+/// and return its status reply.
+pub async fn send_command(
+    path: &Path,
+    action: ListenAction,
+    agent: Option<&str>,
+) -> std::io::Result<String> {
     let mut stream = UnixStream::connect(path).await?;
-    stream
-        .write_all(format!("{}\n", action_word(action)).as_bytes())
-        .await?;
+    let line = match agent {
+        Some(agent) => format!("{} {agent}\n", action_word(action)),
+        None => format!("{}\n", action_word(action)),
+    };
+    stream.write_all(line.as_bytes()).await?;
     let mut reply = String::new();
     stream.read_to_string(&mut reply).await?;
     Ok(reply.trim().to_string())
@@ -164,11 +198,31 @@ mod tests {
     use tokio::sync::mpsc::unbounded_channel;
 
     #[test]
-    fn parse_action_known_and_unknown() {
-        assert_eq!(parse_action("toggle\n"), Some(ListenAction::Toggle));
-        assert_eq!(parse_action(" start "), Some(ListenAction::Start));
-        assert_eq!(parse_action("stop"), Some(ListenAction::Stop));
-        assert_eq!(parse_action("frobnicate"), None);
+    fn parse_command_known_and_unknown() {
+        assert_eq!(
+            parse_command("toggle\n"),
+            Some((ListenAction::Toggle, None))
+        );
+        assert_eq!(parse_command(" start "), Some((ListenAction::Start, None)));
+        assert_eq!(parse_command("stop"), Some((ListenAction::Stop, None)));
+        assert_eq!(
+            parse_command("interrupt"),
+            Some((ListenAction::Interrupt, None))
+        );
+        assert_eq!(parse_command("frobnicate"), None);
+        assert_eq!(parse_command("frobnicate planner"), None);
+    }
+
+    #[test]
+    fn parse_command_takes_an_agent_name() {
+        assert_eq!(
+            parse_command("toggle planner\n"),
+            Some((ListenAction::Toggle, Some("planner".to_string())))
+        );
+        assert_eq!(
+            parse_command("start  Ops Desk "),
+            Some((ListenAction::Start, Some("Ops Desk".to_string())))
+        );
     }
 
     #[tokio::test]
@@ -180,21 +234,30 @@ mod tests {
         let recording = Arc::new(AtomicBool::new(false));
 
         // Bind happens synchronously inside serve(), so there's no accept race.
-        serve(path.clone(), ListenerHandle::from(tx), recording.clone()).expect("bind");
+        let voice_agent = Arc::new(Mutex::new(None));
+        serve(
+            path.clone(),
+            ListenerHandle::from(tx),
+            recording.clone(),
+            voice_agent.clone(),
+        )
+        .expect("bind");
 
-        let reply = send_command(&path, ListenAction::Toggle)
+        let reply = send_command(&path, ListenAction::Toggle, None)
             .await
             .expect("send");
         assert_eq!(reply, "recording");
         assert!(recording.load(Ordering::Acquire));
         assert!(matches!(rx.try_recv(), Ok(ListenerCommand::Start)));
+        assert_eq!(*voice_agent.lock().unwrap(), None);
 
-        let reply = send_command(&path, ListenAction::Toggle)
+        let reply = send_command(&path, ListenAction::Toggle, Some("planner"))
             .await
             .expect("send");
         assert_eq!(reply, "stopped");
         assert!(!recording.load(Ordering::Acquire));
         assert!(matches!(rx.try_recv(), Ok(ListenerCommand::Stop)));
+        assert_eq!(voice_agent.lock().unwrap().as_deref(), Some("planner"));
     }
 
     #[tokio::test]
@@ -207,9 +270,16 @@ mod tests {
         let (tx, _rx) = unbounded_channel();
         let recording = Arc::new(AtomicBool::new(false));
         // Should remove the stale file and bind successfully.
-        serve(path.clone(), ListenerHandle::from(tx), recording).expect("reclaim stale socket");
+        let voice_agent = Arc::new(Mutex::new(None));
+        serve(
+            path.clone(),
+            ListenerHandle::from(tx),
+            recording,
+            voice_agent,
+        )
+        .expect("reclaim stale socket");
 
-        let reply = send_command(&path, ListenAction::Start)
+        let reply = send_command(&path, ListenAction::Start, None)
             .await
             .expect("send");
         assert_eq!(reply, "recording");
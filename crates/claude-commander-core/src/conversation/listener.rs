@@ -1,24 +1,34 @@
-//! Voice-input listener: owns the mic [`Recorder`] and [`SttClient`].
+//! Voice-input listener: owns the mic [`Recorder`] and [`SttEngine`].
 //!
 //! Mirrors [`speaker::spawn_speaker`](crate::conversation::speaker::spawn_speaker),
 //! but in the opposite direction — instead of turning text into audio, it turns
 //! a finished recording into text. The app toggles capture with
 //! [`ListenerCommand`]s; when a recording stops, the captured WAV is transcribed
-//! and the resulting transcript is sent on `transcript_tx` for the app to feed
-//! to the conversation session.
+//! and the resulting transcript is sent on `events` for the app to feed to the
+//! conversation session.
+//!
+//! Starting a recording is a *barge-in*: the user is cutting in, so the spoken
+//! reply stops at once and the app is told ([`ListenerEvent::BargeIn`]) in case
+//! it should interrupt the agent's turn too. [`ListenerCommand::Interrupt`] does
+//! the same without opening the mic.
+//!
+//! With `stt.vad` on, a recording also ends by itself once the user stops
+//! talking (see [`recorder`](crate::conversation::recorder)); in `continuous`
+//! mode the listener then starts the next one straight away, so a whole
+//! conversation runs hands-free until recording is toggled off.
 
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use tokio::sync::mpsc;
 use tracing::{debug, warn};
 
 use crate::config::SttConfig;
 use crate::conversation::media::{MediaSignal, signal as media_signal};
-use crate::conversation::recorder::Recorder;
+use crate::conversation::recorder::{Recorder, Recording, VadSettings};
 use crate::conversation::speaker::{SpeakerCommand, SpeakerHandle};
-use crate::conversation::stt::SttClient;
+use crate::conversation::stt::SttEngine;
 
 /// Commands to the listener task.
 #[derive(Debug, Clone)]
@@ -27,16 +37,30 @@ pub enum ListenerCommand {
     Start,
     /// Stop recording and transcribe what was captured.
     Stop,
+    /// Barge in without recording: silence the spoken reply.
+    Interrupt,
+}
+
+/// What the listener reports back to the app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListenerEvent {
+    /// A recognized transcript, to hand to the conversation session.
+    Transcript(String),
+    /// The user cut in (started recording, or asked to interrupt). The spoken
+    /// reply has already been stopped.
+    BargeIn,
 }
 
 /// What an external trigger wants the microphone to do. `Toggle` flips the
 /// current state; `Start`/`Stop` request an absolute state (a no-op if already
-/// there).
+/// there). `Interrupt` leaves the microphone alone and only barges in on the
+/// spoken reply.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ListenAction {
     Toggle,
     Start,
     Stop,
+    Interrupt,
 }
 
 /// A respawn-stable handle to the current voice listener's command channel.
@@ -90,13 +114,18 @@ impl From<mpsc::UnboundedSender<ListenerCommand>> for ListenerHandle {
 /// toggle — so the recording state machine stays consistent no matter where the
 /// toggle originates. Returns the new recording state. Sends a
 /// [`ListenerCommand`] only when the state actually changes (so a redundant
-/// `Start`/`Stop` doesn't restart capture or transcribe silence).
+/// `Start`/`Stop` doesn't restart capture or transcribe silence). `Interrupt`
+/// always sends, and leaves the recording state as it was.
 pub fn apply_listen_action(
     listener: &ListenerHandle,
     recording: &AtomicBool,
     action: ListenAction,
 ) -> bool {
     let now = match action {
+        ListenAction::Interrupt => {
+            let _ = listener.send(ListenerCommand::Interrupt);
+            return recording.load(Ordering::Acquire);
+        }
         // Atomic flip so concurrent triggers can't both observe the old value.
         ListenAction::Toggle => !recording.fetch_xor(true, Ordering::AcqRel),
         ListenAction::Start | ListenAction::Stop => {
@@ -120,31 +149,51 @@ pub fn apply_listen_action(
 /// recorder's readiness), so a slow device never blocks the caller. Commands
 /// sent before the device finishes opening queue on the channel and run once it
 /// is ready. If no input device is available the task logs and exits, leaving
-/// the returned sender inert. Recognized transcripts are sent on
-/// `transcript_tx`; dropping the sender ends the task and releases the mic.
+/// the returned sender inert. Recognized transcripts and barge-ins are sent on
+/// `events`; dropping the sender ends the task and releases the mic.
+/// `recording` is the shared recording flag, cleared here when voice-activity
+/// detection ends a recording.
 pub fn spawn_listener(
     cfg: SttConfig,
-    transcript_tx: mpsc::UnboundedSender<String>,
+    events: mpsc::UnboundedSender<ListenerEvent>,
     gate: Option<mpsc::UnboundedSender<MediaSignal>>,
     speaker: SpeakerHandle,
+    recording: Arc<AtomicBool>,
 ) -> mpsc::UnboundedSender<ListenerCommand> {
     let (tx, mut rx) = mpsc::unbounded_channel::<ListenerCommand>();
 
     tokio::spawn(async move {
-        let (wav_tx, mut wav_rx) = mpsc::unbounded_channel::<Vec<u8>>();
+        let (wav_tx, mut wav_rx) = mpsc::unbounded_channel::<Recording>();
+        let vad = cfg.vad.then(|| VadSettings {
+            threshold: cfg.vad_threshold,
+            silence: Duration::from_millis(cfg.vad_silence_ms),
+        });
         // Open the mic off the caller's task; on failure the sender goes inert.
-        let recorder = match Recorder::new(wav_tx, cfg.input_device.clone()).await {
-            Ok(r) => r,
-            Err(e) => {
-                warn!(target: "conversation", "STT unavailable: {e}");
-                return;
-            }
-        };
-        let client = SttClient::new(&cfg);
+        let recorder =
+            match Recorder::new(wav_tx, cfg.input_device.clone(), vad, speaker.speaking()).await {
+                Ok(r) => r,
+                Err(e) => {
+                    warn!(target: "conversation", "STT unavailable: {e}");
+                    return;
+                }
+            };
+        let client = SttEngine::from_config(&cfg);
         loop {
             tokio::select! {
                 // A finished recording arrived from the recorder thread.
-                Some(wav) = wav_rx.recv() => {
+                Some(Recording { wav, auto_stopped }) = wav_rx.recv() => {
+                    if auto_stopped {
+                        if cfg.continuous && recording.load(Ordering::Acquire) {
+                            // Hands-free: listen for the next utterance right
+                            // away. No interrupt or media signals — the reply
+                            // to this one is about to be spoken, and media stays
+                            // paused while the conversation is live.
+                            recorder.start();
+                        } else {
+                            recording.store(false, Ordering::Release);
+                            media_signal(&gate, MediaSignal::RecordStopped);
+                        }
+                    }
                     // Stage 1 timing: WAV bytes in → transcript out.
                     let wav_bytes = wav.len();
                     let t0 = Instant::now();
@@ -156,7 +205,7 @@ pub fn spawn_listener(
                                 t0.elapsed().as_millis(),
                                 text.len()
                             );
-                            if transcript_tx.send(text).is_err() {
+                            if events.send(ListenerEvent::Transcript(text)).is_err() {
                                 break; // app gone
                             }
                         }
@@ -189,6 +238,7 @@ pub fn spawn_listener(
                         // current reply at once and stay muted until the new query
                         // is submitted (a no-op when nothing is speaking).
                         speaker.send(SpeakerCommand::Interrupt);
+                        let _ = events.send(ListenerEvent::BargeIn);
                         // Signal *before* opening the mic: the gate snapshots the
                         // playing players concurrently, and on Bluetooth the mic
                         // opening only pauses playback ~300ms later — so the
@@ -200,6 +250,12 @@ pub fn spawn_listener(
                         recorder.stop();
                         media_signal(&gate, MediaSignal::RecordStopped);
                     }
+                    Some(ListenerCommand::Interrupt) => {
+                        // Muted until the next message is submitted, so the rest
+                        // of the interrupted reply stays quiet as it streams.
+                        speaker.send(SpeakerCommand::Interrupt);
+                        let _ = events.send(ListenerEvent::BargeIn);
+                    }
                     None => break, // sender dropped → end the task (releases mic)
                 },
             }
@@ -290,6 +346,36 @@ mod tests {
         assert!(drain(&mut rx).is_empty());
     }
 
+    #[test]
+    fn interrupt_leaves_recording_alone() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let handle = ListenerHandle::from(tx);
+        let recording = AtomicBool::new(false);
+
+        assert!(!apply_listen_action(
+            &handle,
+            &recording,
+            ListenAction::Interrupt
+        ));
+        assert!(matches!(
+            drain(&mut rx).as_slice(),
+            [ListenerCommand::Interrupt]
+        ));
+
+        // Mid-recording it reports (and keeps) the recording state.
+        recording.store(true, Ordering::Release);
+        assert!(apply_listen_action(
+            &handle,
+            &recording,
+            ListenAction::Interrupt
+        ));
+        assert!(recording.load(Ordering::Acquire));
+        assert!(matches!(
+            drain(&mut rx).as_slice(),
+            [ListenerCommand::Interrupt]
+        ));
+    }
+
     #[test]
     fn replace_swaps_the_delivered_to_sender() {
         // The core respawn guarantee: after `replace`, triggers routed through
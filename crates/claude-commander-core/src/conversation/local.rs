@@ -0,0 +1,255 @@
+//! Offline speech engines: TTS and STT by running a local binary per request.
+//!
+//! Each engine is a shell command template (`[conversation] tts_command`,
+//! `[stt] command`) run through `sh -c` with its placeholders expanded to
+//! shell-quoted values, the same way custom commands are. Audio crosses the
+//! process boundary as WAV, through a scratch file when the template names one
+//! (`{output}` / `{input}`) and through the pipes otherwise — piper wants a
+//! file, `espeak-ng --stdout` and friends stream. Nothing here needs a server,
+//! so voice mode works on a laptop with only the binaries installed.
+
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+
+use tokio::io::AsyncWriteExt;
+
+use crate::config::SttConfig;
+use crate::config::expand_template;
+use crate::error::TtsError;
+
+/// Per-sentence timeout, matching the HTTP client's: a wedged synthesizer
+/// fails that sentence rather than the rest of the reply.
+const TTS_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Per-recording timeout, matching the HTTP client's. Covers loading the model,
+/// which whisper.cpp does on every run.
+const STT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How much of a failing command's stderr makes it into the error.
+const STDERR_MAX_CHARS: usize = 500;
+
+/// Speech synthesis by a local command (piper, espeak-ng, …).
+#[derive(Debug, Clone)]
+pub struct CommandTts {
+    template: String,
+}
+
+impl CommandTts {
+    pub fn new(template: impl Into<String>) -> Self {
+        Self {
+            template: template.into(),
+        }
+    }
+
+    /// Synthesize `text` and return the WAV bytes the command produced.
+    pub async fn synthesize(
+        &self,
+        text: &str,
+        voice: &str,
+        speed: f32,
+    ) -> Result<Vec<u8>, TtsError> {
+        let output = ScratchFile::new("wav");
+        let speed = format!("{speed:.2}");
+        let line = expand_template(
+            &self.template,
+            &[
+                ("{text}", text),
+                ("{output}", &output.path_str()),
+                ("{voice}", voice),
+                ("{speed}", &speed),
+            ],
+        );
+        // Text goes in on stdin unless it's already on the command line.
+        let stdin = (!self.template.contains("{text}")).then(|| text.as_bytes().to_vec());
+        let stdout = run(&line, stdin, TTS_TIMEOUT).await?;
+        let audio = if self.template.contains("{output}") {
+            tokio::fs::read(&output.path)
+                .await
+                .map_err(|e| TtsError::Command(format!("no audio written to {{output}}: {e}")))?
+        } else {
+            stdout
+        };
+        if audio.is_empty() {
+            return Err(TtsError::Command("the command produced no audio".into()));
+        }
+        Ok(audio)
+    }
+}
+
+/// Transcription by a local command (whisper.cpp's `whisper-cli`, …).
+#[derive(Debug, Clone)]
+pub struct CommandStt {
+    template: String,
+    language: Option<String>,
+    prompt: Option<String>,
+}
+
+impl CommandStt {
+    pub fn new(template: impl Into<String>, cfg: &SttConfig) -> Self {
+        Self {
+            template: template.into(),
+            language: cfg.language.clone(),
+            prompt: cfg.prompt.clone(),
+        }
+    }
+
+    /// Transcribe a WAV clip and return the recognized text.
+    pub async fn transcribe(&self, wav: Vec<u8>) -> Result<String, TtsError> {
+        let input = ScratchFile::new("wav");
+        let stdin = if self.template.contains("{input}") {
+            tokio::fs::write(&input.path, &wav)
+                .await
+                .map_err(|e| TtsError::Command(format!("failed to write the recording: {e}")))?;
+            None
+        } else {
+            Some(wav)
+        };
+        let line = expand_template(
+            &self.template,
+            &[
+                ("{input}", &input.path_str()),
+                // whisper.cpp's own spelling of "detect it".
+                ("{language}", self.language.as_deref().unwrap_or("auto")),
+                ("{prompt}", self.prompt.as_deref().unwrap_or_default()),
+            ],
+        );
+        let stdout = run(&line, stdin, STT_TIMEOUT).await?;
+        Ok(transcript_text(&String::from_utf8_lossy(&stdout)))
+    }
+}
+
+/// Join a transcriber's output lines into one transcript, dropping the
+/// non-speech markers whisper prints for silence and noise (`[BLANK_AUDIO]`,
+/// `(wind blowing)`).
+fn transcript_text(stdout: &str) -> String {
+    stdout
+        .lines()
+        .map(str::trim)
+        .filter(|line| {
+            let marker = (line.starts_with('[') && line.ends_with(']'))
+                || (line.starts_with('(') && line.ends_with(')'));
+            !line.is_empty() && !marker
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Run `line` through `sh -c`, feeding it `stdin`, and return its stdout. A
+/// non-zero exit is an error carrying the tail of its stderr.
+async fn run(line: &str, stdin: Option<Vec<u8>>, timeout: Duration) -> Result<Vec<u8>, TtsError> {
+    let mut child = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(line)
+        .stdin(if stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| TtsError::Command(format!("failed to start: {e}")))?;
+    if let (Some(bytes), Some(mut pipe)) = (stdin, child.stdin.take()) {
+        // Written off this task so a command that fills its stdout before
+        // reading all of stdin can't deadlock against us.
+        tokio::spawn(async move {
+            let _ = pipe.write_all(&bytes).await;
+        });
+    }
+    let output = tokio::time::timeout(timeout, child.wait_with_output())
+        .await
+        .map_err(|_| TtsError::Command(format!("timed out after {}s", timeout.as_secs())))?
+        .map_err(|e| TtsError::Command(e.to_string()))?;
+    if !output.status.success() {
+        let status = match output.status.code() {
+            Some(code) => format!("exit {code}"),
+            None => "killed by a signal".to_string(),
+        };
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stderr: String = stderr.trim().chars().take(STDERR_MAX_CHARS).collect();
+        return Err(TtsError::Command(format!("{status}: {stderr}")));
+    }
+    Ok(output.stdout)
+}
+
+/// A uniquely named file in the temp dir, removed when dropped (whether or not
+/// the command ever created it).
+struct ScratchFile {
+    path: PathBuf,
+}
+
+impl ScratchFile {
+    fn new(extension: &str) -> Self {
+        let name = format!("claude-commander-{}.{extension}", uuid::Uuid::new_v4());
+        Self {
+            path: std::env::temp_dir().join(name),
+        }
+    }
+
+    fn path_str(&self) -> String {
+        self.path.to_string_lossy().into_owned()
+    }
+}
+
+impl Drop for ScratchFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transcript_drops_whisper_markers() {
+        assert_eq!(
+            transcript_text("\n Open the review\n for the auth branch.\n[BLANK_AUDIO]\n"),
+            "Open the review for the auth branch."
+        );
+        assert_eq!(transcript_text("(wind blowing)\n"), "");
+    }
+
+    #[tokio::test]
+    async fn tts_reads_stdout_or_the_output_file() {
+        // Text on stdin, audio on stdout.
+        let tts = CommandTts::new("tr a-z A-Z");
+        assert_eq!(
+            tts.synthesize("hi there", "", 1.0).await.unwrap(),
+            b"HI THERE"
+        );
+        // Text as an argument, audio in `{output}`; values arrive quoted.
+        let tts = CommandTts::new("printf '%s@%s' {text} {speed} > {output}");
+        assert_eq!(
+            tts.synthesize("it's fine", "", 1.5).await.unwrap(),
+            b"it's fine@1.50"
+        );
+    }
+
+    #[tokio::test]
+    async fn tts_failure_reports_stderr() {
+        let tts = CommandTts::new("echo 'no such voice' >&2; exit 2");
+        let err = tts.synthesize("hello", "", 1.0).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Speech command failed: exit 2: no such voice"
+        );
+        // Succeeding without any audio is a failure too.
+        let err = CommandTts::new("true").synthesize("hello", "", 1.0).await;
+        assert!(err.is_err());
+    }
+
+    #[tokio::test]
+    async fn stt_passes_the_recording_as_a_file_or_on_stdin() {
+        let cfg = SttConfig {
+            language: Some("en".into()),
+            ..SttConfig::default()
+        };
+        let stt = CommandStt::new("printf '%s ' {language}; cat {input}", &cfg);
+        assert_eq!(stt.transcribe(b"hello".to_vec()).await.unwrap(), "en hello");
+        let stt = CommandStt::new("cat", &cfg);
+        assert_eq!(stt.transcribe(b"piped\n".to_vec()).await.unwrap(), "piped");
+    }
+}
@@ -5,9 +5,13 @@
 //! stream-json headless protocol (`claude -p --input-format stream-json
 //! --output-format stream-json --include-partial-messages`) — the interactive
 //! TUI is render-only. [`session`] drives that subprocess; [`extract`] turns the
-//! streamed text into spoken-ready sentences; [`tts`] synthesizes them and
-//! [`audio`] plays them. All the text logic is pure and unit-tested; only
-//! [`audio`] and [`session`] touch the outside world.
+//! streamed text into spoken-ready sentences; [`tts`] synthesizes them (or
+//! [`local`] does, with an offline engine) and [`audio`] plays them. In the other direction, voice transcripts pass through
+//! [`commands`], which runs simple requests in the TUI directly and hands the
+//! rest to the session. [`announce`] speaks session state changes through the
+//! same speaker, and [`history`] keeps every conversation on disk. All the text
+//! logic is pure and unit-tested; only [`audio`], [`session`] and [`local`]
+//! touch the outside world.
 //!
 //! # Why headless streaming, not hooks / MCP / transcript-tail
 //!
@@ -32,10 +36,14 @@
 //! commander's UI" and "sub-reply latency" are mutually exclusive (short of
 //! fragile pane-scraping). See [`session`] for the actual protocol handling.
 
+pub mod announce;
 pub mod audio;
+pub mod commands;
 pub mod extract;
+pub mod history;
 pub mod ipc;
 pub mod listener;
+pub mod local;
 pub mod media;
 pub mod recorder;
 pub mod session;
@@ -43,14 +51,24 @@ pub mod speaker;
 pub mod stt;
 pub mod tts;
 
+pub use announce::{Announcer, AnnouncerHandle, spawn_announcer};
+pub use commands::{Recognised, VoiceAction, VoiceCatalog, VoiceSession, recognise};
 pub use extract::{SpeakScope, split_sentences, spoken_text};
+pub use history::{
+    ConversationHistory, HistoryLog, HistoryTurn, StoredConversation, TurnRole, new_conversation_id,
+};
 pub use listener::{
-    ListenAction, ListenerCommand, ListenerHandle, apply_listen_action, spawn_listener,
+    ListenAction, ListenerCommand, ListenerEvent, ListenerHandle, apply_listen_action,
+    spawn_listener,
 };
+pub use local::{CommandStt, CommandTts};
 pub use media::{MediaSignal, signal as media_signal, spawn_media_gate};
-pub use session::{ConversationEvent, ConversationSession, parse_event, user_message_line};
+pub use session::{
+    CommentBrief, ConversationEvent, ConversationSession, PrBrief, SessionBrief, interrupt_line,
+    parse_event, user_message_line,
+};
 pub use speaker::{
     SentenceAccumulator, SpeakerCommand, SpeakerHandle, spawn_speaker, speaker_command_for,
 };
-pub use stt::SttClient;
-pub use tts::{SpeechRequest, TtsClient, build_speech_body};
+pub use stt::{SttClient, SttEngine};
+pub use tts::{SpeechRequest, TtsClient, TtsEngine, build_speech_body};
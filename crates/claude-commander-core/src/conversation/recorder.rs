@@ -10,6 +10,17 @@
 //! We record at the device's native sample rate, downmix to mono, and encode
 //! 16-bit PCM WAV — the transcription server resamples as needed, so we don't
 //! force a (possibly unsupported) capture rate on the device.
+//!
+//! With [`VadSettings`] the thread also watches the level of what it captures
+//! ([`VoiceActivity`]): once speech has been followed by enough silence it ends
+//! the recording by itself, trims the silence around the speech, and delivers
+//! it flagged [`Recording::auto_stopped`]. Anything captured while the TTS
+//! player is speaking is discarded, so a spoken reply is never taken for the
+//! user talking.
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
 
 use tokio::sync::mpsc::UnboundedSender;
 
@@ -27,12 +38,34 @@ pub struct InputDevice {
     pub label: String,
 }
 
+/// Voice-activity detection for a hands-free recording.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VadSettings {
+    /// RMS level (0.0–1.0) a 20 ms frame must reach to count as speech.
+    pub threshold: f32,
+    /// Trailing silence after speech that ends the recording.
+    pub silence: Duration,
+}
+
+/// A finished recording, as delivered by the recorder thread.
+#[derive(Debug)]
+pub struct Recording {
+    /// The capture, encoded as 16-bit PCM WAV.
+    pub wav: Vec<u8>,
+    /// Whether voice-activity detection ended it (rather than a stop command).
+    pub auto_stopped: bool,
+}
+
 #[cfg(feature = "audio")]
 use std::io::Cursor;
 #[cfg(feature = "audio")]
-use std::sync::mpsc::{Receiver, Sender, channel};
+use std::sync::Mutex;
+#[cfg(feature = "audio")]
+use std::sync::atomic::Ordering;
 #[cfg(feature = "audio")]
-use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, Sender, channel};
+#[cfg(feature = "audio")]
+use std::time::Instant;
 #[cfg(feature = "audio")]
 use tokio::sync::oneshot;
 
@@ -49,6 +82,121 @@ enum Command {
     Stop,
 }
 
+/// Length of the frames speech is detected on.
+#[cfg(feature = "audio")]
+const FRAME_MS: u32 = 20;
+
+/// Speech (in total) an utterance needs before trailing silence can end it, so
+/// a cough or a door doesn't end the recording on its own.
+#[cfg(feature = "audio")]
+const MIN_SPEECH_MS: u32 = 200;
+
+/// Silence kept either side of the speech when trimming, so word edges below
+/// the threshold aren't clipped.
+#[cfg(feature = "audio")]
+const TRIM_PAD_MS: u32 = 250;
+
+/// How often the recorder thread checks the capture for the end of speech.
+#[cfg(feature = "audio")]
+const VAD_POLL: Duration = Duration::from_millis(50);
+
+/// How long after the player falls silent capture is still discarded, for the
+/// reply's echo to die away.
+#[cfg(feature = "audio")]
+const ECHO_TAIL: Duration = Duration::from_millis(300);
+
+/// Samples per detection frame at `sample_rate`.
+#[cfg(feature = "audio")]
+fn frame_len(sample_rate: u32) -> usize {
+    (sample_rate * FRAME_MS / 1000).max(1) as usize
+}
+
+/// Root-mean-square level of `samples`.
+#[cfg(feature = "audio")]
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+}
+
+/// Frame-level voice-activity detector: tracks how much speech a recording
+/// has had and how long it has been quiet since. Pure; fed incrementally.
+#[cfg(feature = "audio")]
+#[derive(Debug)]
+struct VoiceActivity {
+    frame: usize,
+    threshold: f32,
+    min_speech_frames: usize,
+    silence_frames: usize,
+    /// Samples not yet making up a whole frame.
+    partial: Vec<f32>,
+    voiced: usize,
+    quiet_run: usize,
+}
+
+#[cfg(feature = "audio")]
+impl VoiceActivity {
+    fn new(settings: &VadSettings, sample_rate: u32) -> Self {
+        let frames = |ms: u128| (ms / u128::from(FRAME_MS)).max(1) as usize;
+        Self {
+            frame: frame_len(sample_rate),
+            threshold: settings.threshold,
+            min_speech_frames: frames(u128::from(MIN_SPEECH_MS)),
+            silence_frames: frames(settings.silence.as_millis()),
+            partial: Vec::new(),
+            voiced: 0,
+            quiet_run: 0,
+        }
+    }
+
+    /// Feed newly captured samples. Returns `true` once the utterance is over:
+    /// enough speech, then `silence` without any.
+    fn push(&mut self, samples: &[f32]) -> bool {
+        self.partial.extend_from_slice(samples);
+        let whole = self.partial.len() / self.frame * self.frame;
+        for frame in self.partial[..whole].chunks(self.frame) {
+            if rms(frame) >= self.threshold {
+                self.voiced += 1;
+                self.quiet_run = 0;
+            } else {
+                self.quiet_run += 1;
+            }
+        }
+        self.partial.drain(..whole);
+        self.voiced >= self.min_speech_frames && self.quiet_run >= self.silence_frames
+    }
+
+    /// Start over for a new recording.
+    fn reset(&mut self) {
+        self.partial.clear();
+        self.voiced = 0;
+        self.quiet_run = 0;
+    }
+}
+
+/// `samples` with the silence before the first and after the last speech
+/// frame cut off (keeping [`TRIM_PAD_MS`] either side). Empty when nothing
+/// reached `threshold`.
+#[cfg(feature = "audio")]
+fn trim_silence(samples: &[f32], sample_rate: u32, threshold: f32) -> &[f32] {
+    let frame = frame_len(sample_rate);
+    let voiced = |(_, f): &(usize, &[f32])| rms(f) >= threshold;
+    let mut frames = samples.chunks(frame).enumerate();
+    let Some((first, _)) = frames.find(voiced) else {
+        return &[];
+    };
+    let last = samples
+        .chunks(frame)
+        .enumerate()
+        .rfind(voiced)
+        .map_or(first, |(i, _)| i);
+    let pad = (sample_rate * TRIM_PAD_MS / 1000) as usize;
+    let start = (first * frame).saturating_sub(pad);
+    let end = ((last + 1) * frame + pad).min(samples.len());
+    &samples[start..end]
+}
+
 /// A `Send` handle to the recorder thread. `start` begins capture; `stop` ends
 /// it and sends the encoded WAV bytes on the channel given to [`Recorder::new`].
 #[cfg(feature = "audio")]
@@ -61,18 +209,28 @@ impl Recorder {
     /// Start the recorder thread. Returns an error if no input device is
     /// available. Awaits the thread's one-shot readiness signal rather than
     /// blocking, so opening a slow device (e.g. Bluetooth) doesn't stall the
-    /// caller's task. Finished recordings are delivered as WAV bytes on
-    /// `wav_tx`. `input_device` is the cpal device id to capture from; `None`
-    /// (or an id that isn't present) falls back to the system default.
+    /// caller's task. Finished recordings are delivered on `wav_tx`.
+    /// `input_device` is the cpal device id to capture from; `None` (or an id
+    /// that isn't present) falls back to the system default. With `vad`, a
+    /// recording also ends by itself after trailing silence, and capture is
+    /// discarded while `speaking` (the TTS player is playing) is set.
     pub async fn new(
-        wav_tx: UnboundedSender<Vec<u8>>,
+        wav_tx: UnboundedSender<Recording>,
         input_device: Option<String>,
+        vad: Option<VadSettings>,
+        speaking: Arc<AtomicBool>,
     ) -> Result<Self, TtsError> {
         let (tx, rx) = channel::<Command>();
         let (ready_tx, ready_rx) = oneshot::channel::<Result<(), String>>();
+        let thread = RecorderThread {
+            wav_tx,
+            input_device,
+            vad,
+            speaking,
+        };
         std::thread::Builder::new()
             .name("cc-stt-audio".into())
-            .spawn(move || recorder_thread(rx, wav_tx, ready_tx, input_device))
+            .spawn(move || recorder_thread(rx, thread, ready_tx))
             .map_err(|e| TtsError::Audio(e.to_string()))?;
         match ready_rx.await {
             Ok(Ok(())) => Ok(Self { tx }),
@@ -173,13 +331,27 @@ pub fn input_devices() -> Vec<InputDevice> {
     Vec::new()
 }
 
+/// What the recorder thread is started with, besides its command channel.
+#[cfg(feature = "audio")]
+struct RecorderThread {
+    wav_tx: UnboundedSender<Recording>,
+    input_device: Option<String>,
+    vad: Option<VadSettings>,
+    speaking: Arc<AtomicBool>,
+}
+
 #[cfg(feature = "audio")]
 fn recorder_thread(
     rx: Receiver<Command>,
-    wav_tx: UnboundedSender<Vec<u8>>,
+    thread: RecorderThread,
     ready: oneshot::Sender<Result<(), String>>,
-    input_device: Option<String>,
 ) {
+    let RecorderThread {
+        wav_tx,
+        input_device,
+        vad,
+        speaking,
+    } = thread;
     let host = cpal::default_host();
     // Enumerate once: derive the name list from the same devices we search, so a
     // device whose `name()` errors is simply never matched (no index skew), and
@@ -221,11 +393,54 @@ fn recorder_thread(
     // Captured mono f32 samples for the in-progress recording.
     let buffer = Arc::new(Mutex::new(Vec::<f32>::new()));
     let mut stream: Option<cpal::Stream> = None;
+    let mut detector = vad.map(|v| VoiceActivity::new(&v, sample_rate));
+    // How much of `buffer` the detector has seen, and until when capture is
+    // discarded as the player's echo.
+    let mut seen = 0;
+    let mut deaf_until: Option<Instant> = None;
+
+    // Stop capturing and deliver the recording (trimmed, with VAD on).
+    // Returns `false` once the listener is gone.
+    let finish = |stream: &mut Option<cpal::Stream>, auto_stopped: bool| {
+        // Drop the active stream to stop the capture callback before draining
+        // (`.take()` so the held guard counts as read).
+        drop(stream.take());
+        let samples = std::mem::take(&mut *buffer.lock().unwrap());
+        let speech = match &vad {
+            Some(v) => trim_silence(&samples, sample_rate, v.threshold),
+            None => &samples[..],
+        };
+        match encode_wav(speech, sample_rate) {
+            Ok(wav) => wav_tx.send(Recording { wav, auto_stopped }).is_ok(),
+            Err(e) => {
+                warn!("STT WAV encode failed: {e}");
+                true
+            }
+        }
+    };
 
-    while let Ok(cmd) = rx.recv() {
+    loop {
+        // While a hands-free recording runs, wake up regularly to look for the
+        // end of speech; otherwise just wait for the next command.
+        let cmd = if stream.is_some() && detector.is_some() {
+            match rx.recv_timeout(VAD_POLL) {
+                Ok(cmd) => Some(cmd),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        } else {
+            match rx.recv() {
+                Ok(cmd) => Some(cmd),
+                Err(_) => break,
+            }
+        };
         match cmd {
-            Command::Start => {
+            Some(Command::Start) => {
                 buffer.lock().unwrap().clear();
+                seen = 0;
+                if let Some(d) = &mut detector {
+                    d.reset();
+                }
                 match build_stream(&device, &config, sample_format, channels, buffer.clone()) {
                     Ok(s) => match s.play() {
                         Ok(()) => stream = Some(s),
@@ -234,18 +449,31 @@ fn recorder_thread(
                     Err(e) => warn!("STT mic capture failed: {e}"),
                 }
             }
-            Command::Stop => {
-                // Drop the active stream to stop the capture callback before
-                // draining (`.take()` so the held guard counts as read).
-                drop(stream.take());
-                let samples = std::mem::take(&mut *buffer.lock().unwrap());
-                match encode_wav(&samples, sample_rate) {
-                    Ok(bytes) => {
-                        if wav_tx.send(bytes).is_err() {
-                            break; // listener gone
-                        }
+            Some(Command::Stop) => {
+                if !finish(&mut stream, false) {
+                    break; // listener gone
+                }
+            }
+            None => {
+                let Some(d) = &mut detector else { continue };
+                let now = Instant::now();
+                if speaking.load(Ordering::Acquire) {
+                    deaf_until = Some(now + ECHO_TAIL);
+                }
+                let ended = {
+                    let mut buf = buffer.lock().unwrap();
+                    if deaf_until.is_some_and(|t| now < t) {
+                        // The player is talking: drop what the mic heard.
+                        buf.truncate(seen);
+                        false
+                    } else {
+                        let ended = d.push(&buf[seen..]);
+                        seen = buf.len();
+                        ended
                     }
-                    Err(e) => warn!("STT WAV encode failed: {e}"),
+                };
+                if ended && !finish(&mut stream, true) {
+                    break; // listener gone
                 }
             }
         }
@@ -327,8 +555,10 @@ pub struct Recorder;
 #[cfg(not(feature = "audio"))]
 impl Recorder {
     pub async fn new(
-        _wav_tx: UnboundedSender<Vec<u8>>,
+        _wav_tx: UnboundedSender<Recording>,
         _input_device: Option<String>,
+        _vad: Option<VadSettings>,
+        _speaking: Arc<AtomicBool>,
     ) -> Result<Self, TtsError> {
         Err(TtsError::Audio("audio support not compiled in".into()))
     }
@@ -372,6 +602,49 @@ mod tests {
         assert_eq!(reader.len(), 0);
     }
 
+    /// `ms` milliseconds at 1 kHz of a constant `level`.
+    fn tone(level: f32, ms: usize) -> Vec<f32> {
+        vec![level; ms]
+    }
+
+    #[test]
+    fn voice_activity_ends_after_speech_then_silence() {
+        let settings = VadSettings {
+            threshold: 0.1,
+            silence: Duration::from_millis(500),
+        };
+        let mut vad = VoiceActivity::new(&settings, 1_000);
+        // Silence alone never ends a recording — the user hasn't spoken yet.
+        assert!(!vad.push(&tone(0.0, 2_000)));
+        // A click is too short to count as speech.
+        assert!(!vad.push(&tone(0.5, 40)));
+        assert!(!vad.push(&tone(0.0, 1_000)));
+        // Real speech, then a pause shorter than `silence`, then enough.
+        assert!(!vad.push(&tone(0.5, 300)));
+        assert!(!vad.push(&tone(0.0, 300)));
+        assert!(!vad.push(&tone(0.5, 100)));
+        // Fed in odd-sized pieces: partial frames carry over.
+        assert!(!vad.push(&tone(0.0, 247)));
+        assert!(vad.push(&tone(0.0, 253)));
+
+        vad.reset();
+        assert!(!vad.push(&tone(0.0, 1_000)));
+    }
+
+    #[test]
+    fn trim_silence_keeps_padded_speech() {
+        let mut samples = tone(0.0, 1_000);
+        samples.extend(tone(0.5, 400));
+        samples.extend(tone(0.0, 1_000));
+        let speech = trim_silence(&samples, 1_000, 0.1);
+        // 400 ms of speech plus 250 ms either side.
+        assert_eq!(speech.len(), 900);
+        assert_eq!(speech[250], 0.5);
+        // Short leading silence is kept whole rather than padded past the start.
+        assert_eq!(trim_silence(&samples[900..], 1_000, 0.1).len(), 750);
+        assert!(trim_silence(&tone(0.01, 500), 1_000, 0.1).is_empty());
+    }
+
     #[test]
     fn resolve_input_device_id_cases() {
         let available = vec![
@@ -6,17 +6,29 @@
 //! stdout. We parse those events into [`ConversationEvent`]s — crucially the
 //! incremental `text_delta`s, which arrive as the assistant generates (the
 //! supported way to get clean token-level text without scraping a TUI).
+//!
+//! The same stdin also takes control requests: [`ConversationSession::interrupt`]
+//! asks the agent to abandon its running turn, which then ends with a `result`
+//! like any other.
+//!
+//! The agent learns about the user's other sessions by running the
+//! `claude-commander` CLI. [`SessionBrief`] is the one-call answer to "how's
+//! that session going?" — its pane tail, diffstat, pending review comments and
+//! PR/CI status — behind `claude-commander brief`.
 
 use std::path::Path;
 use std::process::Stdio;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::process::{Child, ChildStderr, ChildStdin, ChildStdout, Command};
 use tokio::sync::mpsc;
 use tracing::warn;
 
+use crate::api::SessionDetail;
+use crate::comment::{Comment, CommentStatus};
 use crate::error::TtsError;
+use crate::git::{ChecksStatus, PrState, ReviewDecision};
 
 /// Events surfaced from the session's stdout stream.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -115,11 +127,170 @@ pub fn user_message_line(text: &str) -> String {
     line
 }
 
+/// Serialize an interrupt control request (including newline): stop the turn
+/// in progress. `request_id` only has to be unique within the session.
+pub fn interrupt_line(request_id: &str) -> String {
+    let msg = serde_json::json!({
+        "type": "control_request",
+        "request_id": request_id,
+        "request": { "subtype": "interrupt" },
+    });
+    let mut line = msg.to_string();
+    line.push('\n');
+    line
+}
+
+/// Everything the conversation agent needs to say how a session is getting on,
+/// in one structured snapshot: what the agent is doing and printing, what it
+/// has changed, what review feedback is waiting to go to it, and where its PR
+/// and CI stand.
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionBrief {
+    pub id: String,
+    pub title: String,
+    pub project_name: String,
+    pub branch: String,
+    pub status: String,
+    pub agent_state: String,
+    pub diff_stat: Option<String>,
+    pub pr: Option<PrBrief>,
+    /// Review comments staged (or drifted) but not yet sent to the agent.
+    pub pending_comments: Vec<CommentBrief>,
+    /// The last lines of the session's pane; `None` when it isn't running.
+    pub pane_tail: Option<String>,
+}
+
+/// A session's pull request, as far as the last poll knows.
+#[derive(Debug, Clone, Serialize)]
+pub struct PrBrief {
+    pub number: u32,
+    pub url: Option<String>,
+    pub state: PrState,
+    pub draft: bool,
+    /// Aggregate CI status; `None` before the first poll.
+    pub checks: Option<ChecksStatus>,
+    pub review_decision: Option<ReviewDecision>,
+}
+
+/// A review comment waiting to be sent to the session's agent.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommentBrief {
+    pub file: String,
+    pub line_range: (usize, usize),
+    pub comment: String,
+    /// Its code changed since, so it no longer anchors (and blocks Apply).
+    pub drifted: bool,
+}
+
+impl SessionBrief {
+    /// Build a brief from a session's detail (fetched with the pane lines
+    /// wanted) and its comments, keeping only those not yet applied.
+    pub fn new(detail: &SessionDetail, comments: &[Comment]) -> Self {
+        let info = &detail.info;
+        let pr = info.pr_number.map(|number| PrBrief {
+            number,
+            url: info.pr_url.clone(),
+            state: info.pr_state,
+            draft: info.pr_draft,
+            checks: info.pr_checks,
+            review_decision: info.review_decision,
+        });
+        let pending_comments = comments
+            .iter()
+            .filter(|c| c.status != CommentStatus::Applied)
+            .map(|c| CommentBrief {
+                file: c.file.clone(),
+                line_range: c.line_range,
+                comment: c.comment.clone(),
+                drifted: c.status == CommentStatus::Drifted,
+            })
+            .collect();
+        // A pane is mostly blank below the prompt; those rows say nothing.
+        let pane_tail = detail
+            .pane_content
+            .as_deref()
+            .map(|pane| pane.trim_end().to_string());
+        Self {
+            id: info.id.clone(),
+            title: info.title.clone(),
+            project_name: info.project_name.clone(),
+            branch: info.branch.clone(),
+            status: info.status.to_string(),
+            agent_state: detail.agent_state.to_string(),
+            diff_stat: detail.diff_stat.clone(),
+            pr,
+            pending_comments,
+            pane_tail,
+        }
+    }
+
+    /// Plain-text rendering for the agent to read: a few labelled lines, then
+    /// the pane tail.
+    pub fn to_text(&self) -> String {
+        let mut lines = vec![
+            format!("Session: {} ({})", self.title, self.project_name),
+            format!("Branch:  {}", self.branch),
+            format!("Status:  {} | Agent: {}", self.status, self.agent_state),
+        ];
+        match self.diff_stat.as_deref().map(str::trim) {
+            Some(stat) if !stat.is_empty() => lines.push(format!("Diff:    {stat}")),
+            _ => lines.push("Diff:    no changes".to_string()),
+        }
+        match &self.pr {
+            Some(pr) => {
+                let mut line = format!(
+                    "PR:      #{} {}{}",
+                    pr.number,
+                    pr.state,
+                    if pr.draft { " (draft)" } else { "" }
+                );
+                if let Some(checks) = pr.checks {
+                    line.push_str(&format!(" | CI: {checks}"));
+                }
+                if let Some(decision) = pr.review_decision {
+                    line.push_str(&format!(" | Review: {decision}"));
+                }
+                lines.push(line);
+            }
+            None => lines.push("PR:      none".to_string()),
+        }
+        if self.pending_comments.is_empty() {
+            lines.push("Pending review comments: none".to_string());
+        } else {
+            lines.push(format!(
+                "Pending review comments ({}):",
+                self.pending_comments.len()
+            ));
+            for c in &self.pending_comments {
+                let (start, end) = c.line_range;
+                let range = if start == end {
+                    start.to_string()
+                } else {
+                    format!("{start}-{end}")
+                };
+                let drifted = if c.drifted { " [drifted]" } else { "" };
+                lines.push(format!("- {}:{range}{drifted}: {}", c.file, c.comment));
+            }
+        }
+        match &self.pane_tail {
+            Some(tail) => {
+                lines.push(String::new());
+                lines.push("Recent output:".to_string());
+                lines.push(tail.clone());
+            }
+            None => lines.push("Recent output: not running".to_string()),
+        }
+        lines.join("\n")
+    }
+}
+
 /// A running headless conversation session. Dropping it kills the child
 /// (`kill_on_drop`), so the session lives exactly as long as this handle.
 pub struct ConversationSession {
     child: Child,
     stdin: ChildStdin,
+    /// Control requests sent so far, for their ids.
+    control_requests: u64,
 }
 
 impl ConversationSession {
@@ -128,7 +299,9 @@ impl ConversationSession {
     /// `permission_mode` is passed to `--permission-mode` (e.g. `"auto"`) so the
     /// conversation agent can act without interactive approval prompts. When
     /// `resume` is `Some(id)`, `--resume <id>` continues that prior session so
-    /// the conversation keeps its history across restarts.
+    /// the conversation keeps its history across restarts. `system_prompt` is
+    /// appended to the agent's system prompt (`--append-system-prompt`), for
+    /// instructions that can't go in a `CLAUDE.md` of their own.
     ///
     /// The child's stderr is captured and logged (not discarded): when `claude`
     /// fails to start a session — a bad flag, an unknown `--resume` id, an auth
@@ -140,6 +313,7 @@ impl ConversationSession {
         permission_mode: &str,
         cwd: &Path,
         resume: Option<&str>,
+        system_prompt: Option<&str>,
         events: mpsc::UnboundedSender<ConversationEvent>,
     ) -> Result<Self, TtsError> {
         let mut cmd = Command::new(command);
@@ -153,6 +327,9 @@ impl ConversationSession {
         if let Some(id) = resume.filter(|id| !id.is_empty()) {
             cmd.args(["--resume", id]);
         }
+        if let Some(prompt) = system_prompt {
+            cmd.args(["--append-system-prompt", prompt]);
+        }
         let mut child = cmd
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
@@ -178,13 +355,31 @@ impl ConversationSession {
         }
 
         tokio::spawn(read_events(stdout, events));
-        Ok(Self { child, stdin })
+        Ok(Self {
+            child,
+            stdin,
+            control_requests: 0,
+        })
     }
 
     /// Send a user turn to the session.
     pub async fn send_user_message(&mut self, text: &str) -> Result<(), TtsError> {
+        self.write_line(&user_message_line(text)).await
+    }
+
+    /// Ask the agent to stop the turn in progress. Its reply so far stands; the
+    /// turn ends with a `result` (surfacing as [`ConversationEvent::TurnComplete`]
+    /// or [`ConversationEvent::Error`]), after which queued messages are
+    /// answered as usual.
+    pub async fn interrupt(&mut self) -> Result<(), TtsError> {
+        self.control_requests += 1;
+        let id = format!("cc_interrupt_{}", self.control_requests);
+        self.write_line(&interrupt_line(&id)).await
+    }
+
+    async fn write_line(&mut self, line: &str) -> Result<(), TtsError> {
         self.stdin
-            .write_all(user_message_line(text).as_bytes())
+            .write_all(line.as_bytes())
             .await
             .map_err(|e| TtsError::Session(format!("write to session failed: {e}")))?;
         self.stdin
@@ -311,4 +506,63 @@ mod tests {
         assert_eq!(v["message"]["role"], "user");
         assert_eq!(v["message"]["content"], "hi there");
     }
+
+    #[test]
+    fn interrupt_line_shape() {
+        let line = interrupt_line("cc_interrupt_1");
+        assert!(line.ends_with('\n'));
+        let v: serde_json::Value = serde_json::from_str(line.trim()).unwrap();
+        assert_eq!(v["type"], "control_request");
+        assert_eq!(v["request_id"], "cc_interrupt_1");
+        assert_eq!(v["request"]["subtype"], "interrupt");
+        // The control response that follows isn't surfaced as an event.
+        let ack = r#"{"type":"control_response","response":{"subtype":"success","request_id":"cc_interrupt_1"}}"#;
+        assert_eq!(parse_event(ack), None);
+    }
+
+    #[test]
+    fn brief_keeps_pending_comments_and_trims_the_pane() {
+        let mut session = crate::session::WorktreeSession::new(
+            crate::session::ProjectId::new(),
+            "migration",
+            "db-migration",
+            std::path::PathBuf::from("/tmp/wt"),
+            "claude",
+        );
+        session.pr_number = Some(42);
+        session.pr_state = Some(PrState::Open);
+        session.pr_checks = Some(ChecksStatus::Failing);
+        let detail = SessionDetail {
+            info: crate::api::session_info_from_session(&session, "api"),
+            agent_state: crate::session::AgentState::Working,
+            diff_stat: Some("3 files changed, 40 insertions(+)".into()),
+            pane_content: Some("Running migrations…\n\n\n".into()),
+            resources: None,
+        };
+        let mut applied = Comment::new(
+            "a.sql",
+            crate::comment::CommentSide::New,
+            (1, 1),
+            "",
+            "done",
+        );
+        applied.status = CommentStatus::Applied;
+        let pending = Comment::new(
+            "b.sql",
+            crate::comment::CommentSide::New,
+            (3, 5),
+            "",
+            "add an index",
+        );
+        let brief = SessionBrief::new(&detail, &[applied, pending]);
+        assert_eq!(brief.pending_comments.len(), 1);
+        assert_eq!(brief.pane_tail.as_deref(), Some("Running migrations…"));
+        let text = brief.to_text();
+        assert!(text.contains("PR:      #42 Open | CI: Failing"), "{text}");
+        assert!(text.contains("- b.sql:3-5: add an index"), "{text}");
+        assert!(
+            text.ends_with("Recent output:\nRunning migrations…"),
+            "{text}"
+        );
+    }
 }
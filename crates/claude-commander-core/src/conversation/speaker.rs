@@ -2,9 +2,12 @@
 //!
 //! Deltas arrive split mid-word, so [`SentenceAccumulator`] buffers them and
 //! emits whole sentences as they complete. The [`spawn_speaker`] task owns the
-//! audio [`Player`] and TTS client, synthesizing + queuing each sentence as it
+//! audio [`Player`] and TTS engine, synthesizing + queuing each sentence as it
 //! lands so speech starts within a sentence of the assistant beginning to type.
 
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use futures::stream::{FuturesOrdered, StreamExt};
 use tokio::sync::mpsc;
 use tracing::warn;
@@ -13,7 +16,7 @@ use crate::config::ConversationConfig;
 use crate::conversation::audio::{PlaybackEdge, Player};
 use crate::conversation::extract::{SpeakScope, first_sentence_boundary, spoken_text};
 use crate::conversation::media::{MediaSignal, signal as media_signal};
-use crate::conversation::tts::{SpeechRequest, TtsClient};
+use crate::conversation::tts::{SpeechRequest, TtsEngine};
 use crate::error::TtsError;
 
 /// Maximum concurrent synth requests. Enough to keep the audio queue fed
@@ -75,6 +78,10 @@ pub enum SpeakerCommand {
     Interrupt,
     /// Unmute: the new query has been submitted, so speak its reply.
     Resume,
+    /// A complete, standalone sentence (a spoken announcement). Queued behind
+    /// whatever is already playing or synthesizing, and spoken even while
+    /// muted — it isn't part of the interrupted reply.
+    Announce(String),
 }
 
 /// What [`SpeakerCommand`] should make the speaker task do, given the current
@@ -90,6 +97,8 @@ pub enum SpeakerEffect {
     InterruptAndMute,
     /// Clear the mute.
     Unmute,
+    /// Queue the announcement's text as its own sentence.
+    Speak,
     /// Drop the command (muted, or a no-op).
     Ignore,
 }
@@ -117,6 +126,7 @@ impl SpeakerState {
                 self.muted = false;
                 SpeakerEffect::Unmute
             }
+            SpeakerCommand::Announce(_) => SpeakerEffect::Speak,
         }
     }
 }
@@ -125,24 +135,38 @@ impl SpeakerState {
 /// recreated on each session (re)spawn, but the listener (which interrupts on
 /// record-start) and the submit path (which resumes for the new reply) are
 /// long-lived — they hold this handle and it's repointed at the live speaker.
-/// Sends are best-effort: a no-op when no speaker is up.
+/// Sends are best-effort: a no-op when no speaker is up. Also carries whether
+/// audio is coming out of the speaker right now, for the hands-free recorder
+/// to ignore what the mic hears meanwhile.
 #[derive(Clone, Default)]
-pub struct SpeakerHandle(
-    std::sync::Arc<std::sync::Mutex<Option<mpsc::UnboundedSender<SpeakerCommand>>>>,
-);
+pub struct SpeakerHandle {
+    tx: Arc<std::sync::Mutex<Option<mpsc::UnboundedSender<SpeakerCommand>>>>,
+    speaking: Arc<AtomicBool>,
+}
 
 impl SpeakerHandle {
     /// Point at the current speaker (or `None` when TTS is off / no session).
     pub fn set(&self, tx: Option<mpsc::UnboundedSender<SpeakerCommand>>) {
-        *self.0.lock().unwrap() = tx;
+        *self.tx.lock().unwrap() = tx;
+    }
+
+    /// Whether a speaker is up.
+    pub fn is_present(&self) -> bool {
+        self.tx.lock().unwrap().is_some()
     }
 
     /// Deliver a command to the current speaker, if any (best-effort no-op).
     pub fn send(&self, cmd: SpeakerCommand) {
-        if let Some(tx) = self.0.lock().unwrap().as_ref() {
+        if let Some(tx) = self.tx.lock().unwrap().as_ref() {
             let _ = tx.send(cmd);
         }
     }
+
+    /// The flag every speaker spawned for this handle keeps set while it is
+    /// playing (pass it to [`spawn_speaker`]).
+    pub fn speaking(&self) -> Arc<AtomicBool> {
+        self.speaking.clone()
+    }
 }
 
 /// Map a session event to the speaker command it drives (if any). Pure, so the
@@ -160,7 +184,7 @@ pub fn speaker_command_for(ev: &crate::conversation::ConversationEvent) -> Optio
 
 /// Start the speaker task. Returns a sender for [`SpeakerCommand`]s, or an error
 /// if no audio output device is available. Dropping the sender ends the task and
-/// stops audio.
+/// stops audio. `speaking` is kept set while audio is playing.
 ///
 /// Sentences are synthesized **concurrently** (via `FuturesOrdered`) but enqueued
 /// **in order**, so a chunk's audio is ready by the time the previous one
@@ -169,12 +193,14 @@ pub fn speaker_command_for(ev: &crate::conversation::ConversationEvent) -> Optio
 pub fn spawn_speaker(
     cfg: ConversationConfig,
     gate: Option<mpsc::UnboundedSender<MediaSignal>>,
+    speaking_flag: Arc<AtomicBool>,
 ) -> Result<mpsc::UnboundedSender<SpeakerCommand>, TtsError> {
     // Playback span boundaries from the audio thread, so we can tell the media
-    // gate when the reply actually starts and finishes being spoken.
+    // gate (and the hands-free recorder) when the reply actually starts and
+    // finishes being spoken.
     let (edge_tx, mut edge_rx) = mpsc::unbounded_channel::<PlaybackEdge>();
-    let player = Player::with_edges(cfg.volume, gate.as_ref().map(|_| edge_tx))?;
-    let client = TtsClient::new(cfg.base_url.clone());
+    let player = Player::with_edges(cfg.volume, Some(edge_tx))?;
+    let client = TtsEngine::from_config(&cfg);
     let (tx, mut rx) = mpsc::unbounded_channel::<SpeakerCommand>();
 
     tokio::spawn(async move {
@@ -215,10 +241,12 @@ pub fn spawn_speaker(
                 Some(edge) = edge_rx.recv() => match edge {
                     PlaybackEdge::Started if !speaking => {
                         speaking = true;
+                        speaking_flag.store(true, Ordering::Release);
                         media_signal(&gate, MediaSignal::SpeakingStarted);
                     }
                     PlaybackEdge::Stopped if speaking && queue.is_empty() && pending.is_empty() => {
                         speaking = false;
+                        speaking_flag.store(false, Ordering::Release);
                         media_signal(&gate, MediaSignal::SpeakingEnded);
                     }
                     // A gap mid-reply (more queued/synthesizing) or a redundant
@@ -237,6 +265,11 @@ pub fn spawn_speaker(
                                 queue.push_back(remainder);
                             }
                         }
+                        SpeakerEffect::Speak => {
+                            if let SpeakerCommand::Announce(text) = cmd {
+                                queue.push_back(text);
+                            }
+                        }
                         SpeakerEffect::InterruptAndMute => {
                             acc.clear();
                             queue.clear();
@@ -251,6 +284,7 @@ pub fn spawn_speaker(
                 },
             }
         }
+        speaking_flag.store(false, Ordering::Release);
     });
 
     Ok(tx)
@@ -260,7 +294,7 @@ pub fn spawn_speaker(
 /// speakable (e.g. a code-only fragment). Owns its inputs so it can run
 /// concurrently in a `FuturesOrdered`.
 fn synth_future(
-    client: &TtsClient,
+    client: &TtsEngine,
     cfg: &ConversationConfig,
     sentence: &str,
 ) -> Option<impl std::future::Future<Output = Result<Vec<u8>, TtsError>> + 'static> {
@@ -458,6 +492,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn announcements_speak_even_while_muted() {
+        let mut s = SpeakerState::default();
+        s.on(&SpeakerCommand::Interrupt);
+        assert_eq!(
+            s.on(&SpeakerCommand::Announce("CI failed".into())),
+            SpeakerEffect::Speak
+        );
+        // ...without lifting the mute on the interrupted reply.
+        assert_eq!(
+            s.on(&SpeakerCommand::Chunk("old".into())),
+            SpeakerEffect::Ignore
+        );
+    }
+
     #[test]
     fn accumulator_does_not_split_abbreviations() {
         let mut acc = SentenceAccumulator::new();
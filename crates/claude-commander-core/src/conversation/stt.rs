@@ -3,10 +3,12 @@
 //! Posts a `multipart/form-data` body to `{base_url}/audio/transcriptions`
 //! (OpenAI's `POST /v1/audio/transcriptions` shape), so it works against any
 //! compatible engine — we dev against a local/LAN faster-whisper server.
+//! [`SttEngine`] picks between it and a local command ([`CommandStt`]).
 
 use serde::Deserialize;
 
 use crate::config::SttConfig;
+use crate::conversation::local::CommandStt;
 use crate::error::TtsError;
 
 /// Thin client around `reqwest`. Cheap to clone (shares the connection pool).
@@ -96,6 +98,31 @@ impl SttClient {
     }
 }
 
+/// Where recordings are transcribed: the HTTP API, or a local command when
+/// `command` is set.
+#[derive(Debug, Clone)]
+pub enum SttEngine {
+    Http(SttClient),
+    Command(CommandStt),
+}
+
+impl SttEngine {
+    pub fn from_config(cfg: &SttConfig) -> Self {
+        match cfg.command.as_deref().map(str::trim) {
+            Some(template) if !template.is_empty() => Self::Command(CommandStt::new(template, cfg)),
+            _ => Self::Http(SttClient::new(cfg)),
+        }
+    }
+
+    /// Transcribe a WAV clip and return the recognized text (trimmed).
+    pub async fn transcribe(&self, wav: Vec<u8>) -> Result<String, TtsError> {
+        match self {
+            Self::Http(client) => client.transcribe(wav).await,
+            Self::Command(command) => command.transcribe(wav).await,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
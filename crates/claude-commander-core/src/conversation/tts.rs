@@ -2,10 +2,13 @@
 //!
 //! Posts to `{base_url}/audio/speech` (OpenAI's `POST /v1/audio/speech` shape),
 //! so it works against any compatible engine — we dev against the local Kokoro
-//! container on `http://127.0.0.1:8002/v1`.
+//! container on `http://127.0.0.1:8002/v1`. [`TtsEngine`] picks between it and
+//! a local command ([`CommandTts`]).
 
 use serde::Serialize;
 
+use crate::config::ConversationConfig;
+use crate::conversation::local::CommandTts;
 use crate::error::TtsError;
 
 /// A speech-synthesis request body (serializes to the OpenAI TTS JSON shape).
@@ -68,6 +71,32 @@ impl TtsClient {
     }
 }
 
+/// Where speech is synthesized: the HTTP API, or a local command when
+/// `tts_command` is set.
+#[derive(Debug, Clone)]
+pub enum TtsEngine {
+    Http(TtsClient),
+    Command(CommandTts),
+}
+
+impl TtsEngine {
+    pub fn from_config(cfg: &ConversationConfig) -> Self {
+        match cfg.tts_command.as_deref().map(str::trim) {
+            Some(template) if !template.is_empty() => Self::Command(CommandTts::new(template)),
+            _ => Self::Http(TtsClient::new(cfg.base_url.clone())),
+        }
+    }
+
+    /// Synthesize `req` and return the raw encoded audio bytes. A local
+    /// command only uses the text, voice and speed.
+    pub async fn synthesize(&self, req: &SpeechRequest<'_>) -> Result<Vec<u8>, TtsError> {
+        match self {
+            Self::Http(client) => client.synthesize(req).await,
+            Self::Command(command) => command.synthesize(req.input, req.voice, req.speed).await,
+        }
+    }
+}
+
 /// The JSON body that [`TtsClient::synthesize`] sends, exposed for testing
 /// (single source of truth via serde).
 pub fn build_speech_body(req: &SpeechRequest<'_>) -> serde_json::Value {
@@ -95,6 +124,20 @@ mod tests {
         assert_eq!(body["speed"], 1.25);
     }
 
+    #[test]
+    fn engine_follows_tts_command() {
+        let mut cfg = ConversationConfig::default();
+        assert!(matches!(TtsEngine::from_config(&cfg), TtsEngine::Http(_)));
+        cfg.tts_command = Some("espeak-ng --stdout".into());
+        assert!(matches!(
+            TtsEngine::from_config(&cfg),
+            TtsEngine::Command(_)
+        ));
+        // A blank template (a cleared setting) falls back to the API.
+        cfg.tts_command = Some("  ".into());
+        assert!(matches!(TtsEngine::from_config(&cfg), TtsEngine::Http(_)));
+    }
+
     #[test]
     fn endpoint_trims_trailing_slash() {
         assert_eq!(
@@ -6,7 +6,7 @@ use std::path::PathBuf;
 
 use thiserror::Error;
 
-use crate::session::SessionId;
+use crate::session::{HookEvent, HookOutcome, SessionId};
 
 /// Top-level error type for claude-commander
 #[derive(Error, Debug)]
@@ -48,6 +48,9 @@ pub enum SessionError {
     #[error("Session {0} is in invalid state for this operation")]
     InvalidState(SessionId),
 
+    #[error("Session {0} is archived; restore it first")]
+    Archived(SessionId),
+
     #[error("Invalid program: {0}")]
     InvalidProgram(String),
 
@@ -72,6 +75,9 @@ pub enum SessionError {
     #[error("No cascade in progress")]
     NoCascadeInProgress,
 
+    #[error("Nothing to undo")]
+    NothingToUndo,
+
     #[error(
         "Cascade resume blocked: session {0} is still in a merge state — commit the resolved merge first"
     )]
@@ -93,6 +99,22 @@ pub enum SessionError {
 
     #[error("Invalid pasted image: {0}")]
     InvalidImage(String),
+
+    #[error("No such task: {0}")]
+    TaskNotFound(String),
+
+    #[error("Invalid search pattern: {0}")]
+    InvalidSearchPattern(String),
+
+    #[error("Line is no longer in the session's scrollback: {0}")]
+    LineNotInScrollback(String),
+
+    #[error("{event} hook `{command}` failed ({outcome}); aborted by `on_failure = \"block\"`")]
+    HookFailed {
+        event: HookEvent,
+        command: String,
+        outcome: HookOutcome,
+    },
 }
 
 /// A pasted-image rejection from the shared wire contract
@@ -277,6 +299,12 @@ pub enum TtsError {
 
     #[error("Conversation session error: {0}")]
     Session(String),
+
+    #[error("Conversation history error: {0}")]
+    History(String),
+
+    #[error("Speech command failed: {0}")]
+    Command(String),
 }
 
 impl From<reqwest::Error> for TtsError {
@@ -352,12 +380,14 @@ mod tests {
                 reason: "bad".to_string(),
             },
             SessionError::InvalidState(SessionId::new()),
+            SessionError::Archived(SessionId::new()),
             SessionError::CreationFailed("fail".to_string()),
             SessionError::PersistenceFailed("fail".to_string()),
             SessionError::ProjectNotFound("proj".to_string()),
             SessionError::MaxSessionsReached(10),
             SessionError::TmuxSessionNotFound("sess".to_string()),
             SessionError::CommanderDisabled,
+            SessionError::NothingToUndo,
             SessionError::FileNotInDiff("src/main.rs".to_string()),
         ];
         for err in variants {
@@ -0,0 +1,373 @@
+//! Archive snapshots: a session's uncommitted work kept on a hidden ref
+//!
+//! Archiving removes a session's worktree but keeps its branch. Anything not
+//! yet committed — modified, deleted and untracked (non-ignored) files — is
+//! captured as a commit on top of `HEAD` under `refs/commander/archive/<id>`,
+//! written through a scratch index so the worktree's own index is untouched.
+//! Refs live in the shared repository, so the snapshot outlives the worktree;
+//! restore re-applies it to the recreated worktree as unstaged changes.
+//!
+//! The undo journal stashes a deleted session's worktree the same way, under
+//! `refs/commander/undo/<id>`.
+
+use std::path::Path;
+use std::process::Stdio;
+
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::{info, warn};
+
+use crate::error::{GitError, Result};
+use crate::session::SessionId;
+
+/// Namespace for archive refs. Outside `refs/heads`/`refs/tags`, so branch
+/// lists, pushes and fetches ignore them.
+const ARCHIVE_REF_PREFIX: &str = "refs/commander/archive/";
+
+/// Namespace for the worktree stashes behind undoable deletes.
+const UNDO_REF_PREFIX: &str = "refs/commander/undo/";
+
+/// Identity stamped on snapshot commits. They are never pushed or shown as
+/// history, and a fixed identity keeps archiving working in repos where
+/// `user.name`/`user.email` aren't configured.
+const SNAPSHOT_IDENTITY: (&str, &str) = ("claude-commander", "claude-commander@localhost");
+
+/// The hidden ref that pins `session_id`'s archive.
+pub fn archive_ref_name(session_id: &SessionId) -> String {
+    format!("{ARCHIVE_REF_PREFIX}{}", session_id.as_uuid())
+}
+
+/// The hidden ref that holds `session_id`'s worktree stash while its delete
+/// can still be undone.
+pub fn undo_ref_name(session_id: &SessionId) -> String {
+    format!("{UNDO_REF_PREFIX}{}", session_id.as_uuid())
+}
+
+/// The result of [`snapshot_worktree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeSnapshot {
+    /// The worktree's `HEAD` commit.
+    pub head: String,
+    /// Whether the ref holds a snapshot commit of uncommitted changes (on top
+    /// of `head`) rather than `head` itself.
+    pub has_snapshot: bool,
+}
+
+/// Pin `worktree`'s state to `refname`: a snapshot commit of its uncommitted
+/// changes when it is dirty, otherwise `HEAD` itself (so the commit survives
+/// even if the branch is later deleted).
+pub async fn snapshot_worktree(worktree: &Path, refname: &str) -> Result<WorktreeSnapshot> {
+    let head = git_output(worktree, &["rev-parse", "HEAD"], &[]).await?;
+    let status = git_output(worktree, &["status", "--porcelain"], &[]).await?;
+    if status.is_empty() {
+        git_output(worktree, &["update-ref", refname, &head], &[]).await?;
+        return Ok(WorktreeSnapshot {
+            head,
+            has_snapshot: false,
+        });
+    }
+
+    // A scratch index in the worktree's private git dir: seeded from HEAD so
+    // force-added files that match .gitignore stay tracked, then `add -A`
+    // records modifications, deletions and untracked files.
+    let index = git_output(
+        worktree,
+        &["rev-parse", "--git-path", "commander-archive-index"],
+        &[],
+    )
+    .await?;
+    let index = worktree.join(index);
+    let index_str = index.to_string_lossy().into_owned();
+    let env = [("GIT_INDEX_FILE", index_str.as_str())];
+    let result = async {
+        git_output(worktree, &["read-tree", "HEAD"], &env).await?;
+        git_output(worktree, &["add", "-A"], &env).await?;
+        let tree = git_output(worktree, &["write-tree"], &env).await?;
+        let (name, email) = SNAPSHOT_IDENTITY;
+        let identity = [
+            ("GIT_AUTHOR_NAME", name),
+            ("GIT_AUTHOR_EMAIL", email),
+            ("GIT_COMMITTER_NAME", name),
+            ("GIT_COMMITTER_EMAIL", email),
+        ];
+        git_output(
+            worktree,
+            &[
+                "commit-tree",
+                &tree,
+                "-p",
+                &head,
+                "-m",
+                "claude-commander archive snapshot",
+            ],
+            &identity,
+        )
+        .await
+    }
+    .await;
+    if let Err(e) = tokio::fs::remove_file(&index).await
+        && e.kind() != std::io::ErrorKind::NotFound
+    {
+        warn!("Failed to remove archive scratch index {:?}: {}", index, e);
+    }
+    let commit = result?;
+    git_output(worktree, &["update-ref", refname, &commit], &[]).await?;
+    info!(
+        "Archived uncommitted changes in {:?} to {}",
+        worktree, refname
+    );
+    Ok(WorktreeSnapshot {
+        head,
+        has_snapshot: true,
+    })
+}
+
+/// Pin `rev` (resolved in `repo_path`) to `refname`, returning the commit. The
+/// fallback for archiving a session whose worktree is already gone: there is
+/// no uncommitted work to keep, only the branch tip.
+pub async fn pin_archive_ref(repo_path: &Path, refname: &str, rev: &str) -> Result<String> {
+    let commit = git_output(repo_path, &["rev-parse", "--verify", rev], &[]).await?;
+    git_output(repo_path, &["update-ref", refname, &commit], &[]).await?;
+    Ok(commit)
+}
+
+/// Re-apply the snapshot at `refname` to `worktree` as unstaged changes (new
+/// files come back untracked). The worktree should be checked out at the
+/// snapshot's parent. Fails — leaving the worktree as it was — when the
+/// changes no longer apply.
+pub async fn restore_snapshot(worktree: &Path, refname: &str) -> Result<()> {
+    let parent = format!("{refname}^");
+    let diff = Command::new("git")
+        .current_dir(worktree)
+        .args(["diff", "--binary", "--src-prefix=a/", "--dst-prefix=b/"])
+        .args([parent.as_str(), refname])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| GitError::OperationFailed(format!("Failed to run git diff: {}", e)))?;
+    if !diff.status.success() {
+        return Err(GitError::OperationFailed(format!(
+            "git diff {refname} failed: {}",
+            String::from_utf8_lossy(&diff.stderr).trim()
+        ))
+        .into());
+    }
+    if diff.stdout.is_empty() {
+        return Ok(());
+    }
+
+    let mut apply = Command::new("git")
+        .current_dir(worktree)
+        .args(["apply", "--whitespace=nowarn", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| GitError::OperationFailed(format!("Failed to run git apply: {}", e)))?;
+    if let Some(mut stdin) = apply.stdin.take() {
+        stdin
+            .write_all(&diff.stdout)
+            .await
+            .map_err(|e| GitError::OperationFailed(format!("Failed to feed git apply: {}", e)))?;
+    }
+    let output = apply
+        .wait_with_output()
+        .await
+        .map_err(|e| GitError::OperationFailed(format!("Failed to run git apply: {}", e)))?;
+    if !output.status.success() {
+        return Err(GitError::OperationFailed(format!(
+            "saved changes no longer apply (kept at {refname}): {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Delete an archive ref. A ref that is already gone is not an error.
+pub async fn delete_archive_ref(repo_path: &Path, refname: &str) -> Result<()> {
+    if !ref_resolves(repo_path, refname).await {
+        return Ok(());
+    }
+    git_output(repo_path, &["update-ref", "-d", refname], &[]).await?;
+    Ok(())
+}
+
+/// Whether `refname` resolves to an object in the repo at `path`.
+async fn ref_resolves(path: &Path, refname: &str) -> bool {
+    Command::new("git")
+        .current_dir(path)
+        .args(["rev-parse", "--verify", "--quiet", refname])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .await
+        .map(|s| s.success())
+        .unwrap_or(false)
+}
+
+/// Run git in `dir` with extra `env`, returning trimmed stdout or the stderr
+/// as a [`GitError::OperationFailed`].
+async fn git_output(dir: &Path, args: &[&str], env: &[(&str, &str)]) -> Result<String> {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .envs(env.iter().copied())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| GitError::OperationFailed(format!("Failed to run git: {}", e)))?;
+    if !output.status.success() {
+        return Err(GitError::OperationFailed(format!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+        .into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    async fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .current_dir(dir)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+            .expect("git command runs");
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    async fn git_capture(dir: &Path, args: &[&str]) -> String {
+        git_output(dir, args, &[]).await.expect("git command runs")
+    }
+
+    async fn init_repo() -> TempDir {
+        let tmp = TempDir::new().expect("tempdir");
+        let p = tmp.path();
+        git(p, &["init", "-q"]).await;
+        git(p, &["config", "user.email", "test@example.com"]).await;
+        git(p, &["config", "user.name", "Test"]).await;
+        git(p, &["config", "commit.gpgsign", "false"]).await;
+        fs::write(p.join("kept.txt"), "v1\n").unwrap();
+        fs::write(p.join("gone.txt"), "bye\n").unwrap();
+        git(p, &["add", "."]).await;
+        git(p, &["commit", "-q", "-m", "init"]).await;
+        tmp
+    }
+
+    #[test]
+    fn archive_ref_is_namespaced_by_session() {
+        let id = SessionId::new();
+        let name = archive_ref_name(&id);
+        assert!(name.starts_with("refs/commander/archive/"));
+        assert!(name.ends_with(&id.as_uuid().to_string()));
+    }
+
+    #[tokio::test]
+    async fn clean_worktree_pins_head() {
+        let tmp = init_repo().await;
+        let p = tmp.path();
+        let head = git_capture(p, &["rev-parse", "HEAD"]).await;
+
+        let snap = snapshot_worktree(p, "refs/commander/archive/t")
+            .await
+            .unwrap();
+        assert_eq!(snap.head, head);
+        assert!(!snap.has_snapshot);
+        assert_eq!(
+            git_capture(p, &["rev-parse", "refs/commander/archive/t"]).await,
+            head
+        );
+    }
+
+    #[tokio::test]
+    async fn snapshot_round_trips_modified_deleted_and_untracked_files() {
+        let tmp = init_repo().await;
+        let p = tmp.path();
+        fs::write(p.join("kept.txt"), "v2\n").unwrap();
+        fs::remove_file(p.join("gone.txt")).unwrap();
+        fs::write(p.join("new.txt"), "fresh\n").unwrap();
+        git(p, &["add", "kept.txt"]).await;
+        let refname = "refs/commander/archive/t";
+
+        let snap = snapshot_worktree(p, refname).await.unwrap();
+        assert!(snap.has_snapshot);
+        // The worktree and its index are untouched by the snapshot.
+        assert_eq!(fs::read_to_string(p.join("kept.txt")).unwrap(), "v2\n");
+        assert_eq!(
+            git_capture(p, &["diff", "--cached", "--name-only"]).await,
+            "kept.txt"
+        );
+
+        // Throw the working state away, as removing the worktree would.
+        git(p, &["reset", "-q", "--hard", "HEAD"]).await;
+        git(p, &["clean", "-q", "-fd"]).await;
+        assert_eq!(fs::read_to_string(p.join("kept.txt")).unwrap(), "v1\n");
+
+        restore_snapshot(p, refname).await.unwrap();
+        assert_eq!(fs::read_to_string(p.join("kept.txt")).unwrap(), "v2\n");
+        assert!(!p.join("gone.txt").exists());
+        assert_eq!(fs::read_to_string(p.join("new.txt")).unwrap(), "fresh\n");
+        assert_eq!(git_capture(p, &["rev-parse", "HEAD"]).await, snap.head);
+    }
+
+    #[tokio::test]
+    async fn conflicting_restore_fails_and_keeps_the_ref() {
+        let tmp = init_repo().await;
+        let p = tmp.path();
+        let refname = "refs/commander/archive/t";
+        fs::write(p.join("kept.txt"), "archived\n").unwrap();
+        snapshot_worktree(p, refname).await.unwrap();
+        fs::write(p.join("kept.txt"), "something else\n").unwrap();
+
+        assert!(restore_snapshot(p, refname).await.is_err());
+        assert_eq!(
+            fs::read_to_string(p.join("kept.txt")).unwrap(),
+            "something else\n"
+        );
+        assert!(ref_resolves(p, refname).await);
+    }
+
+    #[tokio::test]
+    async fn pin_archive_ref_resolves_branch() {
+        let tmp = init_repo().await;
+        let p = tmp.path();
+        let head = git_capture(p, &["rev-parse", "HEAD"]).await;
+        let branch = git_capture(p, &["symbolic-ref", "HEAD"]).await;
+        let refname = "refs/commander/archive/t";
+        assert_eq!(pin_archive_ref(p, refname, &branch).await.unwrap(), head);
+        assert_eq!(git_capture(p, &["rev-parse", refname]).await, head);
+        assert!(
+            pin_archive_ref(p, refname, "refs/heads/missing")
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_archive_ref_is_idempotent() {
+        let tmp = init_repo().await;
+        let p = tmp.path();
+        let refname = "refs/commander/archive/t";
+        snapshot_worktree(p, refname).await.unwrap();
+        delete_archive_ref(p, refname).await.unwrap();
+        assert!(!ref_resolves(p, refname).await);
+        delete_archive_ref(p, refname).await.unwrap();
+    }
+}
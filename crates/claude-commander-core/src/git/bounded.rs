@@ -1,11 +1,13 @@
 //! Wall-clock-bounded subprocess runs whose timeout reaches descendants.
 //!
-//! Two call sites need the same guarantee for the same reason, so the mechanism
-//! is here rather than duplicated: [`clone`](super::clone) runs an unattended
-//! `git`/`gh repo clone`, and [`github`](super::github) runs `gh api --paginate`
-//! for the repo picker. Both spawn *trees* — `gh` shells out to `git`, which
-//! shells out to `ssh` or `git-remote-https` — and in both cases abandoning the
-//! wait without killing the tree leaves live processes behind. On the picker that
+//! Three call sites need the same guarantee for the same reason, so the
+//! mechanism is here rather than duplicated: [`clone`](super::clone) runs an
+//! unattended `git`/`gh repo clone`, [`github`](super::github) runs `gh api
+//! --paginate` for the repo picker, and the session manager's lifecycle hooks
+//! run arbitrary user `sh -c` commands. All spawn *trees* — `gh` shells out to
+//! `git`, which shells out to `ssh` or `git-remote-https`; a hook's `npm
+//! install` forks freely — and in every case abandoning the wait without
+//! killing the tree leaves live processes behind. On the picker that
 //! is a compounding leak: its retry button starts a fresh `gh` each press, and an
 //! earlier request the caller stopped waiting on keeps paginating regardless.
 //!
@@ -49,7 +51,7 @@ pub(crate) enum Bounded {
 /// not exist.
 ///
 /// The `Err` case is *only* a failure to spawn, which is why it is an
-/// [`std::io::Error`] rather than one of our own: the two callers disagree about
+/// [`std::io::Error`] rather than one of our own: the callers disagree about
 /// what an unspawnable program means (a missing `gh` is
 /// [`GhUnavailable`](crate::error::GitError::GhUnavailable), an unspawnable
 /// `git` is an operation failure), and that judgement is theirs to make.
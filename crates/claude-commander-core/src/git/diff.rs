@@ -433,6 +433,20 @@ fn format_diff_stat_summary(files: usize, added: usize, removed: usize) -> Strin
 /// diff view rendered in the TUI (see [`compute_diff_for_path`]). Returns `None` when
 /// there are no changes, or when git cannot be run.
 pub async fn diff_stat_summary(path: &Path, base: &str) -> Option<String> {
+    let stat = diff_stat_counts(path, base).await?;
+    if stat.files_changed == 0 {
+        return None;
+    }
+    Some(format_diff_stat_summary(
+        stat.files_changed,
+        stat.lines_added,
+        stat.lines_removed,
+    ))
+}
+
+/// The counts behind [`diff_stat_summary`], zeros included. Returns `None`
+/// only when git cannot be run.
+pub async fn diff_stat_counts(path: &Path, base: &str) -> Option<crate::api::DiffStat> {
     let (stat_output, untracked_output) = tokio::join!(
         Command::new("git")
             .current_dir(path)
@@ -464,11 +478,11 @@ pub async fn diff_stat_summary(path: &Path, base: &str) -> Option<String> {
             .count();
     }
 
-    if files == 0 {
-        return None;
-    }
-
-    Some(format_diff_stat_summary(files, added, removed))
+    Some(crate::api::DiffStat {
+        files_changed: files,
+        lines_added: added,
+        lines_removed: removed,
+    })
 }
 
 #[cfg(test)]
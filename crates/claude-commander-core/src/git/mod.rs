@@ -5,9 +5,10 @@
 //! - `WorktreeManager` - Worktree lifecycle management
 //! - `DiffCache` - Cached diff computation
 
+mod archive;
 mod auto_pull;
 mod backend;
-mod bounded;
+pub(crate) mod bounded;
 mod clone;
 mod clone_jobs;
 mod diff;
@@ -19,6 +20,7 @@ mod summary;
 mod worktree;
 mod worktree_include;
 
+pub use archive::*;
 pub use auto_pull::*;
 pub use backend::*;
 pub use clone::*;
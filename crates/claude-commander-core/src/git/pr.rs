@@ -10,10 +10,11 @@ use chrono::{DateTime, Utc};
 use tokio::process::Command;
 use tracing::debug;
 
-// PR state + review decision are network wire enums; they live in the shared
-// `claude-commander-protocol` crate and are re-exported here so the PR logic
-// below and `crate::git::{PrState, ReviewDecision}` paths keep working.
-pub use claude_commander_protocol::pr::{PrState, ReviewDecision};
+// PR state, review decision and checks status are network wire enums; they
+// live in the shared `claude-commander-protocol` crate and are re-exported here
+// so the PR logic below and `crate::git::{ChecksStatus, PrState,
+// ReviewDecision}` paths keep working.
+pub use claude_commander_protocol::pr::{ChecksStatus, PrState, ReviewDecision};
 
 /// PR metadata returned by `gh pr list` for the session list view.
 #[derive(Debug, Clone)]
@@ -29,6 +30,11 @@ pub struct PrInfo {
     /// Reviewer logins (users only) — union of requested reviewers and
     /// authors of any submitted review. Deduplicated, sorted.
     pub reviewers: Vec<String>,
+    /// Login of the PR's author (`None` when GitHub omits it, e.g. a deleted
+    /// account).
+    pub author: Option<String>,
+    /// Aggregate status of the PR's CI checks.
+    pub checks: ChecksStatus,
     /// Target branch the PR is opened against (e.g. `main` or another PR branch).
     /// Used to detect PR stacks — when this matches another session's branch in
     /// the same project, the sessions are stacked.
@@ -114,26 +120,6 @@ pub struct PrLabel {
     pub color: String,
 }
 
-/// Aggregate CI/checks status derived from `statusCheckRollup`.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum ChecksStatus {
-    Passing,
-    Failing,
-    Pending,
-    None,
-}
-
-impl std::fmt::Display for ChecksStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Passing => write!(f, "Passing"),
-            Self::Failing => write!(f, "Failing"),
-            Self::Pending => write!(f, "Pending"),
-            Self::None => write!(f, "None"),
-        }
-    }
-}
-
 /// Returns `true` if the `gh` CLI is installed and runnable.
 ///
 /// Called once at startup to avoid repeated fork/exec on every tick.
@@ -211,7 +197,7 @@ pub async fn check_pr_for_branch(
             "--state",
             "all",
             "--json",
-            "number,url,state,isDraft,labels,baseRefName,reviewDecision,reviewRequests,latestReviews,createdAt,closedAt,mergedAt",
+            "number,url,state,isDraft,labels,baseRefName,reviewDecision,reviewRequests,latestReviews,author,statusCheckRollup,createdAt,closedAt,mergedAt",
             "--limit",
             "5",
         ])
@@ -382,6 +368,8 @@ fn parse_pr_entry(v: &serde_json::Value) -> Option<PrInfo> {
     }
     reviewers.sort();
     reviewers.dedup();
+    let author = v["author"]["login"].as_str().map(str::to_string);
+    let checks = parse_checks_rollup(&v["statusCheckRollup"]);
     let base_ref_name = v["baseRefName"].as_str().map(str::to_string);
 
     Some(PrInfo {
@@ -392,6 +380,8 @@ fn parse_pr_entry(v: &serde_json::Value) -> Option<PrInfo> {
         labels,
         review_decision,
         reviewers,
+        author,
+        checks,
         base_ref_name,
     })
 }
@@ -840,13 +830,16 @@ mod tests {
             "latestReviews": [
                 {"author": {"login": "bob"}, "state": "COMMENTED"},
                 {"author": {"login": "alice"}, "state": "APPROVED"}
-            ]
+            ],
+            "author": {"login": "carol"}
         }]"#;
         let result = parse_pr_list_json(json, owned_since());
         let info = result.info().expect("parses");
         let mut reviewers = info.reviewers.clone();
         reviewers.sort();
         assert_eq!(reviewers, vec!["alice".to_string(), "bob".to_string()]);
+        // The PR author is its own field, never folded into the reviewers.
+        assert_eq!(info.author.as_deref(), Some("carol"));
     }
 
     #[test]
@@ -861,6 +854,28 @@ mod tests {
         let result = parse_pr_list_json(json, owned_since());
         let info = result.info().expect("parses");
         assert!(info.reviewers.is_empty());
+        assert!(info.author.is_none());
+    }
+
+    #[test]
+    fn test_parse_pr_list_reads_checks_rollup() {
+        let json = r#"[{
+            "number": 1,
+            "url": "https://x/1",
+            "state": "OPEN",
+            "isDraft": false,
+            "labels": [],
+            "statusCheckRollup": [
+                {"conclusion": "SUCCESS"},
+                {"conclusion": "FAILURE"}
+            ]
+        }]"#;
+        let result = parse_pr_list_json(json, owned_since());
+        assert_eq!(result.info().expect("parses").checks, ChecksStatus::Failing);
+
+        let json = r#"[{"number": 1, "url": "https://x/1", "state": "OPEN", "isDraft": false}]"#;
+        let result = parse_pr_list_json(json, owned_since());
+        assert_eq!(result.info().expect("parses").checks, ChecksStatus::None);
     }
 
     #[test]
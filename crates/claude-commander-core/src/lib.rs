@@ -33,6 +33,7 @@ pub mod fuzzy;
 pub mod git;
 pub mod paste_image;
 pub mod reviewed;
+pub mod scrollback;
 pub mod session;
 pub mod telemetry;
 pub mod tmux;
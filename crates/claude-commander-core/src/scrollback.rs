@@ -0,0 +1,554 @@
+//! Full-text search over every session's tmux scrollback.
+//!
+//! The index holds, per session, the rows that have scrolled into the pane's
+//! tmux history — up to [`HISTORY_LIMIT`], the same depth tmux keeps. The
+//! visible screen is never indexed: agents redraw it in place, whereas history
+//! only ever grows at the bottom and is trimmed at the top, so it can be
+//! followed incrementally. Each pass captures only the newest
+//! [`INCREMENTAL_ROWS`] of history and splices them onto the stored tail at the
+//! point where the capture lines up with it ([`SessionScrollback::splice`]).
+//!
+//! Persistence is one plain-text file per session under a directory (typically
+//! `<data_dir>/scrollback/`), appended to as rows arrive and rewritten only
+//! once it has grown well past the cap. Files outlive the tmux session — a
+//! restarted or hibernated session keeps what it printed before — and are
+//! removed with the session itself.
+//!
+//! Splicing, matching and reveal positioning are pure; the service drives the
+//! captures and the tmux side of a reveal.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use regex::{Regex, RegexBuilder};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+use crate::error::{ConfigError, Result, SessionError};
+use crate::session::SessionId;
+pub use crate::tmux::HISTORY_LIMIT;
+pub use claude_commander_protocol::scrollback::{
+    DEFAULT_SEARCH_LIMIT, ScrollbackHit, ScrollbackReveal, ScrollbackSearch,
+};
+
+/// Newest history rows captured on an incremental pass. More rows than this
+/// arriving between two passes means the stored tail isn't in the capture, and
+/// the pass falls back to the whole history.
+pub const INCREMENTAL_ROWS: usize = 2_000;
+
+/// Rows of the stored tail that must reappear, in order, in a fresh capture
+/// for it to count as a continuation: the cheap check each candidate position
+/// passes before the rest of the overlap is compared.
+const ANCHOR_ROWS: usize = 8;
+
+/// Rows left above a revealed line so it lands with some context.
+const REVEAL_CONTEXT: usize = 3;
+
+/// Rows a session's file may carry past [`HISTORY_LIMIT`] before it is
+/// rewritten, so the rewrite is occasional rather than per pass.
+const COMPACT_SLACK: usize = HISTORY_LIMIT / 4;
+
+/// Outcome of splicing a capture onto a session's stored rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Splice {
+    /// The stored tail was found (or the capture was the whole history); this
+    /// many rows were appended.
+    Appended(usize),
+    /// A partial capture that doesn't contain the stored tail: more rows
+    /// arrived than were captured. Nothing was appended; capture everything.
+    NoOverlap,
+}
+
+/// One session's indexed history rows, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionScrollback {
+    lines: Vec<String>,
+}
+
+impl SessionScrollback {
+    /// Rows held (at most [`HISTORY_LIMIT`]).
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Splice a fresh capture of the pane's newest history rows onto the
+    /// stored ones, appending whatever follows the stored tail. The tail is
+    /// placed at the earliest position where every captured row before it
+    /// matches the stored rows too, not merely its last [`ANCHOR_ROWS`]: output
+    /// that repeats a block later in the capture then can't pull the splice
+    /// point past the rows printed in between. `complete` says the capture is
+    /// the pane's whole history: then a missing tail means the history
+    /// restarted (a relaunched pane, a `clear-history`), and the capture is
+    /// appended as new.
+    pub fn splice(&mut self, history: Vec<String>, complete: bool) -> Splice {
+        let len = self.lines.len();
+        let anchor_len = ANCHOR_ROWS.min(len);
+        let start = if anchor_len == 0 {
+            Some(0)
+        } else {
+            let anchor = &self.lines[len - anchor_len..];
+            (anchor_len..=history.len()).find(|&end| {
+                let overlap = end.min(len);
+                history[end - anchor_len..end] == *anchor
+                    && history[end - overlap..end] == self.lines[len - overlap..]
+            })
+        };
+        let start = match start {
+            Some(start) => start,
+            None if complete => 0,
+            None => return Splice::NoOverlap,
+        };
+        let appended = history.len() - start;
+        self.lines.extend(history.into_iter().skip(start));
+        if self.lines.len() > HISTORY_LIMIT {
+            let excess = self.lines.len() - HISTORY_LIMIT;
+            self.lines.drain(..excess);
+        }
+        Splice::Appended(appended)
+    }
+
+    /// Every matching row, newest first, as hits for `session_id`.
+    fn search(
+        &self,
+        session_id: SessionId,
+        pattern: &ScrollbackPattern,
+    ) -> impl Iterator<Item = ScrollbackHit> + '_ {
+        let len = self.lines.len();
+        self.lines
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(move |(idx, text)| {
+                let (match_start, match_end) = pattern.find(text)?;
+                Some(ScrollbackHit {
+                    session_id,
+                    line: idx + 1,
+                    from_end: len - 1 - idx,
+                    text: text.clone(),
+                    match_start,
+                    match_end,
+                })
+            })
+    }
+}
+
+/// A compiled [`ScrollbackSearch`] pattern: literal text or a regex, smart
+/// case (case-insensitive unless the pattern contains an uppercase letter).
+#[derive(Debug, Clone)]
+pub struct ScrollbackPattern(Regex);
+
+impl ScrollbackPattern {
+    pub fn new(search: &ScrollbackSearch) -> Result<Self> {
+        if search.pattern.is_empty() {
+            return Err(SessionError::InvalidSearchPattern("empty pattern".to_string()).into());
+        }
+        let source = if search.regex {
+            search.pattern.clone()
+        } else {
+            regex::escape(&search.pattern)
+        };
+        let ignore_case = !search.pattern.chars().any(char::is_uppercase);
+        RegexBuilder::new(&source)
+            .case_insensitive(ignore_case)
+            .build()
+            .map(Self)
+            .map_err(|e| SessionError::InvalidSearchPattern(e.to_string()).into())
+    }
+
+    /// Byte range of the first match in `line`.
+    pub fn find(&self, line: &str) -> Option<(usize, usize)> {
+        self.0.find(line).map(|m| (m.start(), m.end()))
+    }
+
+    /// Byte ranges of every non-overlapping match in `line`, left to right.
+    pub fn find_all<'a>(&'a self, line: &'a str) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.0.find_iter(line).map(|m| (m.start(), m.end()))
+    }
+}
+
+/// Where `reveal`'s line sits in a fresh capture of the pane's whole history:
+/// the row with its text nearest `from_end` rows above the bottom, preferring
+/// the newer of two equally near. The history has usually grown since the hit
+/// was found, which only moves the line further up — but by a handful of rows,
+/// not onto a distant copy of the same text.
+pub fn locate(rows: &[String], reveal: &ScrollbackReveal) -> Option<usize> {
+    let expected = rows.len().saturating_sub(1 + reveal.from_end);
+    let text = reveal.text.trim_end();
+    rows.iter()
+        .enumerate()
+        .filter(|(_, row)| row.trim_end() == text)
+        .min_by_key(|(idx, _)| (idx.abs_diff(expected), std::cmp::Reverse(*idx)))
+        .map(|(idx, _)| idx)
+}
+
+/// The copy-mode scroll offset (rows up from the bottom of the history) that
+/// shows history row `row` near the top of the pane.
+pub fn reveal_offset(history_size: usize, row: usize) -> usize {
+    (history_size.saturating_sub(row) + REVEAL_CONTEXT).min(history_size)
+}
+
+/// A session's rows plus what its file holds.
+#[derive(Debug, Default)]
+struct Indexed {
+    scrollback: SessionScrollback,
+    /// Rows in the session's file.
+    on_disk: usize,
+    /// Rows at the end of `scrollback` not yet written.
+    unsaved: usize,
+}
+
+/// Every session's [`SessionScrollback`], persisted as one text file per
+/// session under a directory.
+pub struct ScrollbackIndex {
+    dir: PathBuf,
+    sessions: HashMap<SessionId, Indexed>,
+    loaded: bool,
+}
+
+impl ScrollbackIndex {
+    /// Construct an index rooted at `dir` (created lazily on first flush). Its
+    /// files are read by the first [`Self::load`].
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            sessions: HashMap::new(),
+            loaded: false,
+        }
+    }
+
+    fn path_for(&self, sid: SessionId) -> PathBuf {
+        self.dir.join(format!("{}.log", sid.as_uuid()))
+    }
+
+    /// Read every session's file, once. An absent directory is an empty index.
+    pub async fn load(&mut self) -> Result<()> {
+        if self.loaded {
+            return Ok(());
+        }
+        self.loaded = true;
+        let mut entries = match fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(ConfigError::LoadFailed(e.to_string()).into()),
+        };
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| ConfigError::LoadFailed(e.to_string()))?
+        {
+            let path = entry.path();
+            let Some(sid) = path
+                .extension()
+                .is_some_and(|ext| ext == "log")
+                .then(|| path.file_stem()?.to_str()?.parse::<uuid::Uuid>().ok())
+                .flatten()
+                .map(SessionId::from_uuid)
+            else {
+                continue;
+            };
+            let text = fs::read_to_string(&path)
+                .await
+                .map_err(|e| ConfigError::LoadFailed(e.to_string()))?;
+            let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+            let on_disk = lines.len();
+            if lines.len() > HISTORY_LIMIT {
+                lines.drain(..lines.len() - HISTORY_LIMIT);
+            }
+            self.sessions.insert(
+                sid,
+                Indexed {
+                    scrollback: SessionScrollback { lines },
+                    on_disk,
+                    unsaved: 0,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether anything is indexed for `sid` yet — a session with nothing
+    /// indexed is captured whole rather than incrementally.
+    pub fn contains(&self, sid: SessionId) -> bool {
+        self.sessions
+            .get(&sid)
+            .is_some_and(|s| !s.scrollback.is_empty())
+    }
+
+    /// Rows held for `sid`.
+    pub fn len_of(&self, sid: SessionId) -> usize {
+        self.sessions.get(&sid).map_or(0, |s| s.scrollback.len())
+    }
+
+    /// [`SessionScrollback::splice`] a capture into `sid`'s rows, in memory.
+    /// [`Self::flush`] writes it out.
+    pub fn ingest(&mut self, sid: SessionId, history: Vec<String>, complete: bool) -> Splice {
+        let indexed = self.sessions.entry(sid).or_default();
+        let outcome = indexed.scrollback.splice(history, complete);
+        if let Splice::Appended(n) = outcome {
+            indexed.unsaved += n;
+        }
+        outcome
+    }
+
+    /// Write every session's unsaved rows: appended to its file, or the file
+    /// rewritten (temp file + rename) once it would exceed the cap by more
+    /// than the slack.
+    pub async fn flush(&mut self) -> Result<()> {
+        let dirty: Vec<SessionId> = self
+            .sessions
+            .iter()
+            .filter(|(_, s)| s.unsaved > 0)
+            .map(|(sid, _)| *sid)
+            .collect();
+        if dirty.is_empty() {
+            return Ok(());
+        }
+        fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| ConfigError::SaveFailed(e.to_string()))?;
+        for sid in dirty {
+            let path = self.path_for(sid);
+            let tmp = self.dir.join(format!(".{}.tmp", sid.as_uuid()));
+            let Some(indexed) = self.sessions.get_mut(&sid) else {
+                continue;
+            };
+            let lines = &indexed.scrollback.lines;
+            let rewrite = indexed.unsaved >= lines.len()
+                || indexed.on_disk + indexed.unsaved > HISTORY_LIMIT + COMPACT_SLACK;
+            if rewrite {
+                let mut text = lines.join("\n");
+                text.push('\n');
+                fs::write(&tmp, text)
+                    .await
+                    .map_err(|e| ConfigError::SaveFailed(e.to_string()))?;
+                fs::rename(&tmp, &path)
+                    .await
+                    .map_err(|e| ConfigError::SaveFailed(e.to_string()))?;
+                indexed.on_disk = lines.len();
+            } else {
+                let mut text = lines[lines.len() - indexed.unsaved..].join("\n");
+                text.push('\n');
+                let mut file = fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&path)
+                    .await
+                    .map_err(|e| ConfigError::SaveFailed(e.to_string()))?;
+                file.write_all(text.as_bytes())
+                    .await
+                    .map_err(|e| ConfigError::SaveFailed(e.to_string()))?;
+                indexed.on_disk += indexed.unsaved;
+            }
+            indexed.unsaved = 0;
+        }
+        Ok(())
+    }
+
+    /// Drop (and delete the file of) every session `keep` rejects — sessions
+    /// that no longer exist.
+    pub async fn retain(&mut self, keep: impl Fn(&SessionId) -> bool) -> Result<()> {
+        let gone: Vec<SessionId> = self.sessions.keys().filter(|s| !keep(s)).copied().collect();
+        for sid in gone {
+            self.sessions.remove(&sid);
+            match fs::remove_file(self.path_for(sid)).await {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(ConfigError::SaveFailed(e.to_string()).into()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Up to `limit` hits for `pattern`: sessions in id order, each session's
+    /// hits newest first.
+    pub fn search(&self, pattern: &ScrollbackPattern, limit: usize) -> Vec<ScrollbackHit> {
+        let mut sids: Vec<&SessionId> = self.sessions.keys().collect();
+        sids.sort();
+        sids.into_iter()
+            .flat_map(|sid| self.sessions[sid].scrollback.search(*sid, pattern))
+            .take(limit)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn rows(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
+    fn numbered(range: std::ops::Range<usize>) -> Vec<String> {
+        range.map(|i| format!("line {i}")).collect()
+    }
+
+    fn pattern(text: &str) -> ScrollbackPattern {
+        ScrollbackPattern::new(&ScrollbackSearch::literal(text)).unwrap()
+    }
+
+    #[test]
+    fn splice_appends_only_rows_after_the_stored_tail() {
+        let mut sb = SessionScrollback::default();
+        assert_eq!(sb.splice(numbered(0..20), true), Splice::Appended(20));
+        // The next capture overlaps the last 15 stored rows.
+        assert_eq!(sb.splice(numbered(5..30), false), Splice::Appended(10));
+        assert_eq!(sb.lines, numbered(0..30));
+        // Nothing new: the tail is the end of the capture.
+        assert_eq!(sb.splice(numbered(10..30), false), Splice::Appended(0));
+        assert_eq!(sb.len(), 30);
+    }
+
+    #[test]
+    fn splice_reports_a_partial_capture_that_missed_the_tail() {
+        let mut sb = SessionScrollback::default();
+        sb.splice(numbered(0..20), true);
+        assert_eq!(sb.splice(numbered(100..120), false), Splice::NoOverlap);
+        assert_eq!(sb.len(), 20);
+        // The same rows as a whole-history capture: a restarted pane.
+        assert_eq!(sb.splice(numbered(100..120), true), Splice::Appended(20));
+        assert_eq!(sb.len(), 40);
+    }
+
+    #[test]
+    fn splice_anchors_on_the_whole_tail_not_its_last_row() {
+        let mut sb = SessionScrollback::default();
+        sb.splice(rows(&["x", "ok"]), true);
+        // A lone "ok" is not the tail; "x", "ok" is, and only "z" follows it.
+        assert_eq!(
+            sb.splice(rows(&["y", "ok", "x", "ok", "z"]), false),
+            Splice::Appended(1)
+        );
+        assert_eq!(sb.lines, rows(&["x", "ok", "z"]));
+    }
+
+    #[test]
+    fn splice_keeps_rows_between_repeats_of_the_tail_block() {
+        let block: Vec<String> = (0..ANCHOR_ROWS).map(|i| format!("test {i} ok")).collect();
+        let mut sb = SessionScrollback::default();
+        let mut stored = numbered(0..10);
+        stored.extend(block.clone());
+        sb.splice(stored.clone(), true);
+
+        // The same block is printed again after some new rows.
+        let mut capture = numbered(5..10);
+        capture.extend(block.clone());
+        let mut new_rows = rows(&["new 0", "new 1", "new 2"]);
+        new_rows.extend(block.clone());
+        new_rows.push("new 3".to_string());
+        capture.extend(new_rows.clone());
+
+        assert_eq!(sb.splice(capture, false), Splice::Appended(new_rows.len()));
+        stored.extend(new_rows);
+        assert_eq!(sb.lines, stored);
+    }
+
+    #[test]
+    fn splice_keeps_at_most_the_history_limit() {
+        let mut sb = SessionScrollback::default();
+        sb.splice(numbered(0..HISTORY_LIMIT), true);
+        sb.splice(numbered(HISTORY_LIMIT - 10..HISTORY_LIMIT + 5), false);
+        assert_eq!(sb.len(), HISTORY_LIMIT);
+        assert_eq!(sb.lines.first().map(String::as_str), Some("line 5"));
+    }
+
+    #[test]
+    fn pattern_is_smart_case_and_literal_unless_regex() {
+        assert_eq!(pattern("error").find("ERROR: x"), Some((0, 5)));
+        assert_eq!(pattern("Error").find("ERROR: x"), None);
+        assert_eq!(pattern("a.c").find("abc"), None);
+        let re = ScrollbackPattern::new(&ScrollbackSearch {
+            pattern: "a.c".to_string(),
+            regex: true,
+            limit: None,
+        })
+        .unwrap();
+        assert_eq!(re.find("xabc"), Some((1, 4)));
+    }
+
+    #[test]
+    fn pattern_rejects_empty_and_invalid_regex() {
+        assert!(ScrollbackPattern::new(&ScrollbackSearch::literal("")).is_err());
+        let bad = ScrollbackSearch {
+            pattern: "(".to_string(),
+            regex: true,
+            limit: None,
+        };
+        assert!(ScrollbackPattern::new(&bad).is_err());
+        // The same text is fine as a literal.
+        assert!(ScrollbackPattern::new(&ScrollbackSearch::literal("(")).is_ok());
+    }
+
+    #[test]
+    fn locate_prefers_the_copy_nearest_the_expected_row() {
+        let rows = rows(&["cargo test", "ok", "cargo test", "fail", "x", "y"]);
+        let reveal = |from_end| ScrollbackReveal {
+            text: "cargo test".to_string(),
+            from_end,
+        };
+        assert_eq!(locate(&rows, &reveal(3)), Some(2));
+        assert_eq!(locate(&rows, &reveal(5)), Some(0));
+        // Equally near both: the newer one.
+        assert_eq!(locate(&rows, &reveal(4)), Some(2));
+        let gone = ScrollbackReveal {
+            text: "nope".to_string(),
+            from_end: 0,
+        };
+        assert_eq!(locate(&rows, &gone), None);
+    }
+
+    #[test]
+    fn reveal_offset_leaves_context_and_clamps() {
+        assert_eq!(reveal_offset(100, 40), 63);
+        assert_eq!(reveal_offset(100, 0), 100);
+        assert_eq!(reveal_offset(100, 99), 4);
+    }
+
+    #[tokio::test]
+    async fn index_persists_appends_and_reloads() {
+        let tmp = TempDir::new().unwrap();
+        let sid = SessionId::new();
+        let mut index = ScrollbackIndex::new(tmp.path().join("scrollback"));
+        index.load().await.unwrap();
+        index.ingest(sid, numbered(0..10), true);
+        index.flush().await.unwrap();
+        index.ingest(sid, numbered(5..15), false);
+        index.flush().await.unwrap();
+
+        let mut reloaded = ScrollbackIndex::new(tmp.path().join("scrollback"));
+        reloaded.load().await.unwrap();
+        assert!(reloaded.contains(sid));
+        assert_eq!(reloaded.len_of(sid), 15);
+
+        let hits = reloaded.search(&pattern("line 1"), 10);
+        let lines: Vec<usize> = hits.iter().map(|h| h.line).collect();
+        // "line 1" and "line 10".."line 14", newest first.
+        assert_eq!(lines, vec![15, 14, 13, 12, 11, 2]);
+        assert_eq!(hits[0].from_end, 0);
+        assert_eq!(hits[0].text, "line 14");
+        assert_eq!((hits[0].match_start, hits[0].match_end), (0, 6));
+    }
+
+    #[tokio::test]
+    async fn index_search_honours_the_limit_and_retain_deletes_files() {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path().join("scrollback");
+        let (a, b) = (SessionId::new(), SessionId::new());
+        let mut index = ScrollbackIndex::new(dir.clone());
+        index.ingest(a, rows(&["panic: a1", "panic: a2"]), true);
+        index.ingest(b, rows(&["panic: b1"]), true);
+        index.flush().await.unwrap();
+        assert_eq!(index.search(&pattern("panic"), 2).len(), 2);
+        assert_eq!(index.search(&pattern("panic"), 10).len(), 3);
+
+        index.retain(|sid| *sid == a).await.unwrap();
+        assert!(!index.contains(b));
+        assert!(!dir.join(format!("{}.log", b.as_uuid())).exists());
+        assert!(dir.join(format!("{}.log", a.as_uuid())).exists());
+    }
+}
@@ -9,14 +9,23 @@
 //! unstacked session as a one-row card. Sections span backends: cards from
 //! every server land in the same shared columns.
 //!
+//! Optionally the board is also split into horizontal *swimlanes* (per
+//! project, server, program or PR author — see [`Swimlanes`]). Lanes don't
+//! move cards out of their columns: each column's cards are ordered lane-major
+//! and every [`BoardLane`] records its slice of each column, so positions and
+//! navigation keep working on the flat columns. The exception is a collapsed
+//! lane, whose cards move into the lane itself; numbering walks both, so
+//! folding a lane never renumbers (or drops) a session.
+//!
 //! This lives in the library (not `tui/`) so the stack-grouping, section
 //! resolution and flattening logic is unit-testable without a terminal, and it
 //! consumes only wire DTOs so local and remote backends drive it identically.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::ops::Range;
 
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 use crate::api::{SessionInfo, WorkspaceSnapshot};
 use crate::backend::{BackendId, ConnectionState};
@@ -99,9 +108,98 @@ pub struct BoardColumn {
     /// Advisory WIP limit resolved from config (`in_progress_limit` for the
     /// catch-all, `SectionConfig::max_sessions` otherwise). `None` = no limit.
     pub max_sessions: Option<u32>,
+    /// Advisory WIP limit for each lane's slice of the column
+    /// (`in_progress_lane_limit` / `SectionConfig::lane_max_sessions`). Always
+    /// `None` on a board without lanes.
+    pub lane_max_sessions: Option<u32>,
     pub cards: Vec<BoardCard>,
 }
 
+/// How the board is split into horizontal swimlanes. Persisted in `tui.json`;
+/// absent means no lanes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Swimlanes {
+    /// One lane per project, in sidebar order.
+    Project,
+    /// One lane per backend, in backend order (local first).
+    Server,
+    /// One lane per program (the session's launch command), name-sorted.
+    Program,
+    /// One lane per PR author, name-sorted, with sessions that have no PR in
+    /// a trailing "No PR" lane.
+    PrAuthor,
+}
+
+impl Swimlanes {
+    /// The lane groupings in cycling order.
+    pub const ALL: [Swimlanes; 4] = [
+        Swimlanes::Project,
+        Swimlanes::Server,
+        Swimlanes::Program,
+        Swimlanes::PrAuthor,
+    ];
+
+    /// Human-readable name, for the board's top bar and toasts.
+    pub fn label(self) -> &'static str {
+        match self {
+            Swimlanes::Project => "project",
+            Swimlanes::Server => "server",
+            Swimlanes::Program => "program",
+            Swimlanes::PrAuthor => "PR author",
+        }
+    }
+
+    /// The grouping after `current` in the cycle off → project → server →
+    /// program → PR author → off.
+    pub fn cycle(current: Option<Swimlanes>) -> Option<Swimlanes> {
+        match current {
+            None => Some(Self::ALL[0]),
+            Some(lanes) => {
+                let idx = Self::ALL.iter().position(|l| *l == lanes).unwrap_or(0);
+                Self::ALL.get(idx + 1).copied()
+            }
+        }
+    }
+}
+
+/// Swimlane options for [`build_board`].
+#[derive(Debug, Clone, Copy)]
+pub struct LaneOptions<'a> {
+    pub grouping: Swimlanes,
+    /// [`BoardLane::key`]s of the lanes folded down to their heading.
+    pub collapsed: &'a BTreeSet<String>,
+    /// Per-lane WIP limit for the "In Progress" catch-all.
+    pub in_progress_limit: Option<u32>,
+}
+
+/// One horizontal swimlane across every column.
+///
+/// `rows[i]` is the range of this lane's cards in `columns[i].cards` and
+/// `counts[i]` how many sessions the lane has in that column. A collapsed lane
+/// contributes no cards (its ranges are empty, so nothing in it is drawn or
+/// selectable) but keeps its counts for the heading and its cards in `folded`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardLane {
+    /// Stable identity for the collapsed set, e.g. `project:<id>` or
+    /// `program:codex`.
+    pub key: String,
+    pub name: String,
+    pub collapsed: bool,
+    pub rows: Vec<Range<usize>>,
+    pub counts: Vec<usize>,
+    /// `folded[i]` is a collapsed lane's cards in column `i`, in the order
+    /// they'd show in; empty for an expanded lane.
+    pub folded: Vec<Vec<BoardCard>>,
+}
+
+impl BoardLane {
+    /// Sessions in the lane across every column.
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+}
+
 /// The full board: the (server-grouped) project sidebar plus the section
 /// columns shared by every backend.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -110,8 +208,11 @@ pub struct Board {
     pub servers: Vec<BoardServer>,
     /// Sidebar entries, name-sorted within each server's range.
     pub projects: Vec<BoardProjectEntry>,
-    /// Section columns. `columns[0]` is always the "In Progress" catch-all.
+    /// Section columns. `columns[0]` is always the "In Progress" catch-all
+    /// (unless empty columns are hidden).
     pub columns: Vec<BoardColumn>,
+    /// Swimlanes, top to bottom. Empty when the board isn't split into lanes.
+    pub lanes: Vec<BoardLane>,
 }
 
 /// A position on the board.
@@ -128,47 +229,80 @@ pub struct BoardPos {
 }
 
 impl Board {
-    /// The single column-major traversal of the board's selectable worktree
-    /// rows, yielding each row's [`BoardPos`] paired with its session id in
-    /// session-number order. Every position/numbering accessor
+    /// The cards of the column at index `idx` in display order, folded ones
+    /// included: lane by lane, each card paired with its row in
+    /// `columns[idx].cards`, or `None` when its lane is collapsed.
+    fn column_cards(&self, idx: usize) -> impl Iterator<Item = (Option<usize>, &BoardCard)> + '_ {
+        let column = &self.columns[idx];
+        // Without lanes the column's cards are the whole story; with them,
+        // every card sits in exactly one lane's range or folded list.
+        let unlaned = self.lanes.is_empty().then(|| {
+            column
+                .cards
+                .iter()
+                .enumerate()
+                .map(|(row, card)| (Some(row), card))
+        });
+        let laned = self.lanes.iter().flat_map(move |lane| {
+            lane.rows[idx]
+                .clone()
+                .map(move |row| (Some(row), &column.cards[row]))
+                .chain(lane.folded[idx].iter().map(|card| (None, card)))
+        });
+        unlaned.into_iter().flatten().chain(laned)
+    }
+
+    /// The single column-major traversal of the board's worktree rows, yielding
+    /// each row's [`BoardPos`] paired with its session id in session-number
+    /// order. Every position/numbering accessor
     /// ([`position_of`](Self::position_of),
     /// [`pos_of_session_number`](Self::pos_of_session_number),
-    /// [`session_numbers`](Self::session_numbers),
-    /// [`worktree_count`](Self::worktree_count)) is defined in terms of this so
-    /// the traversal exists in exactly one place. `row` is the flattened
-    /// Worktree-row index within the column (cards concatenated in order).
-    fn worktree_rows(&self) -> impl Iterator<Item = (BoardPos, SessionId)> + '_ {
-        self.columns
-            .iter()
-            .enumerate()
-            .flat_map(|(col_idx, column)| {
-                column.cards.iter().enumerate().map(move |(row, card)| {
-                    let SessionListItem::Worktree { id, .. } = &card.row else {
-                        unreachable!("board rows are always Worktree")
-                    };
-                    (
-                        BoardPos {
-                            col: col_idx + 1,
-                            row,
-                        },
-                        *id,
-                    )
-                })
+    /// [`session_numbers`](Self::session_numbers)) is defined in terms of this
+    /// so the traversal exists in exactly one place. `row` is the flattened
+    /// Worktree-row index within the column (cards concatenated in order); a
+    /// session folded away in a collapsed lane keeps its place in the numbering
+    /// but has no position.
+    fn worktree_rows(&self) -> impl Iterator<Item = (Option<BoardPos>, SessionId)> + '_ {
+        (0..self.columns.len()).flat_map(move |col_idx| {
+            self.column_cards(col_idx).map(move |(row, card)| {
+                let SessionListItem::Worktree { id, .. } = &card.row else {
+                    unreachable!("board rows are always Worktree")
+                };
+                let pos = row.map(|row| BoardPos {
+                    col: col_idx + 1,
+                    row,
+                });
+                (pos, *id)
             })
+        })
     }
 
-    /// Every card across all columns, column-major then in-column order. Cards
-    /// carry their project id/name, so palette/quick-switch consumers can build
-    /// a session→project map straight from the board without a separate
-    /// [`AppState`] lookup.
+    /// Every card on the board, column-major then in-column order, including
+    /// those folded away in collapsed lanes. Cards carry their project id/name,
+    /// so palette/quick-switch consumers can build a session→project map
+    /// straight from the board without a separate [`AppState`] lookup.
     pub fn cards(&self) -> impl Iterator<Item = &BoardCard> {
-        self.columns.iter().flat_map(|column| column.cards.iter())
+        (0..self.columns.len()).flat_map(move |idx| self.column_cards(idx).map(|(_, card)| card))
+    }
+
+    /// Every card on the board, folded ones included, in no particular order —
+    /// for stamping UI-only state onto rows after a build.
+    pub fn cards_mut(&mut self) -> impl Iterator<Item = &mut BoardCard> {
+        let folded = self
+            .lanes
+            .iter_mut()
+            .flat_map(|lane| lane.folded.iter_mut().flatten());
+        self.columns
+            .iter_mut()
+            .flat_map(|column| column.cards.iter_mut())
+            .chain(folded)
     }
 
     /// Total number of selectable worktree rows across all columns. One card is
-    /// one session row now, so this is simply the card count.
+    /// one session row, so this is the card count, less the cards folded away
+    /// in collapsed lanes.
     pub fn worktree_count(&self) -> usize {
-        self.cards().count()
+        self.columns.iter().map(|column| column.cards.len()).sum()
     }
 
     /// The `Worktree` row for `id`, if the session is on the board. Consumers
@@ -182,7 +316,9 @@ impl Board {
 
     /// Column-major session numbering: session id → its 1-based number, matching
     /// the on-screen row number and
-    /// [`pos_of_session_number`](Self::pos_of_session_number).
+    /// [`pos_of_session_number`](Self::pos_of_session_number). Sessions in
+    /// collapsed lanes are numbered too, so folding a lane leaves every number
+    /// where it was.
     pub fn session_numbers(&self) -> HashMap<SessionId, usize> {
         self.worktree_rows()
             .enumerate()
@@ -202,11 +338,11 @@ impl Board {
 
     /// Locate a session on the board. Returns the 1-based column and the
     /// flattened Worktree-row index within that column, or `None` if the
-    /// session isn't on the board.
+    /// session isn't on the board or is folded away in a collapsed lane.
     pub fn position_of(&self, id: SessionId) -> Option<BoardPos> {
         self.worktree_rows()
             .find(|(_, sid)| *sid == id)
-            .map(|(pos, _)| pos)
+            .and_then(|(pos, _)| pos)
     }
 
     /// The sidebar row index for `project_id` (column 0), or `None` if the
@@ -219,13 +355,14 @@ impl Board {
     }
 
     /// The board position of the session with the given 1-based number (the Nth
-    /// worktree row in column-major order), or `None` if out of range. Replaces
-    /// the deleted free `session_number_to_list_index` + flat-list lookup.
+    /// worktree row in column-major order), or `None` if out of range or the
+    /// session is folded away in a collapsed lane. Replaces the deleted free
+    /// `session_number_to_list_index` + flat-list lookup.
     pub fn pos_of_session_number(&self, number: usize) -> Option<BoardPos> {
         number
             .checked_sub(1)
             .and_then(|i| self.worktree_rows().nth(i))
-            .map(|(pos, _)| pos)
+            .and_then(|(pos, _)| pos)
     }
 
     /// Resolve the session and/or project addressed by a board position.
@@ -253,6 +390,35 @@ impl Board {
             None => (None, None),
         }
     }
+
+    /// The lane holding the card at `pos`, or `None` for the sidebar, an empty
+    /// column, or a board without lanes.
+    pub fn lane_at(&self, pos: BoardPos) -> Option<usize> {
+        let col = pos.col.checked_sub(1)?;
+        self.lanes
+            .iter()
+            .position(|lane| lane.rows.get(col).is_some_and(|r| r.contains(&pos.row)))
+    }
+
+    /// Each lane's card ranges per section column (`out[lane][column]`), for
+    /// [`BoardState::sync_lanes`](crate::tui::widgets::board::BoardState::sync_lanes).
+    /// Empty on a board without lanes.
+    pub fn lane_rows(&self) -> Vec<Vec<Range<usize>>> {
+        self.lanes.iter().map(|lane| lane.rows.clone()).collect()
+    }
+
+    /// Sessions in the column at index `idx`, including those folded away in
+    /// collapsed lanes (which contribute no cards to the column).
+    pub fn column_total(&self, idx: usize) -> usize {
+        let cards = self.columns.get(idx).map_or(0, |c| c.cards.len());
+        let folded: usize = self
+            .lanes
+            .iter()
+            .filter(|lane| lane.collapsed)
+            .filter_map(|lane| lane.counts.get(idx))
+            .sum();
+        cards + folded
+    }
 }
 
 /// Build the board model from every backend's cached snapshot and the
@@ -276,12 +442,17 @@ impl Board {
 /// "In Progress" catch-all) is dropped from the columns entirely — so a board
 /// with many configured sections, or a filter that empties most of them, shows
 /// only the columns that have work.
+///
+/// With `lanes`, every stack is also assigned a swimlane (by its leaf, like its
+/// section) and each column's cards are regrouped lane-major, keeping the
+/// attention order within a lane. Only lanes that hold a session are built.
 pub fn build_board(
     inputs: &[BoardBackendInput<'_>],
     sections: &[SectionConfig],
     in_progress_limit: Option<u32>,
     filter: Option<ProjectId>,
     hide_empty: bool,
+    lanes: Option<LaneOptions<'_>>,
 ) -> Board {
     let valid_section = |name: &str| name == IN_PROGRESS || sections.iter().any(|s| s.name == name);
 
@@ -293,7 +464,7 @@ pub fn build_board(
     // rough order of how likely each session is to need the user.
     let mut pending_by_section: HashMap<String, Vec<PendingCard>> = HashMap::new();
 
-    for input in inputs {
+    for (input_idx, input) in inputs.iter().enumerate() {
         let snapshot = input.snapshot;
         let by_id: HashMap<SessionId, &SessionInfo> = snapshot
             .sessions
@@ -322,7 +493,7 @@ pub fn build_board(
             projects: group_start..projects.len(),
         });
 
-        for project in &sorted_projects {
+        for (project_idx, project) in sorted_projects.iter().enumerate() {
             // An active project filter hides every other project's cards; the
             // sidebar entry above still lists the project so it stays reachable.
             if filter.is_some_and(|f| f != project.id) {
@@ -427,8 +598,47 @@ pub fn build_board(
                     .max()
                     .unwrap_or_else(|| leaf.node_entered_section_at());
 
+                let lane = lanes.map(|opts| match opts.grouping {
+                    Swimlanes::Project => PendingLane {
+                        order: (false, input_idx, project_idx, String::new()),
+                        key: format!("project:{}", project.id),
+                        name: project.name.clone(),
+                    },
+                    Swimlanes::Server => PendingLane {
+                        order: (false, input_idx, 0, String::new()),
+                        key: format!("server:{}", input.name),
+                        name: input.name.clone(),
+                    },
+                    Swimlanes::Program => PendingLane {
+                        order: (false, 0, 0, leaf.program.clone()),
+                        key: format!("program:{}", leaf.program),
+                        name: leaf.program.clone(),
+                    },
+                    // The leaf's PR decides, like the section; a leaf without
+                    // one falls back to the nearest member that has an author.
+                    Swimlanes::PrAuthor => {
+                        let author = leaf
+                            .pr_author
+                            .as_ref()
+                            .or_else(|| members.iter().find_map(|m| m.pr_author.as_ref()));
+                        match author {
+                            Some(login) => PendingLane {
+                                order: (false, 0, 0, login.to_lowercase()),
+                                key: format!("author:{}", login.to_lowercase()),
+                                name: login.clone(),
+                            },
+                            None => PendingLane {
+                                order: (true, 0, 0, String::new()),
+                                key: "author:".to_string(),
+                                name: "No PR".to_string(),
+                            },
+                        }
+                    }
+                });
+
                 pending.push(PendingCard {
                     section: section_name,
+                    lane,
                     tier,
                     recency,
                     leaf_id,
@@ -450,32 +660,72 @@ pub fn build_board(
         }
     }
 
-    let columns: Vec<BoardColumn> = std::iter::once(IN_PROGRESS.to_string())
-        .chain(sections.iter().map(|s| s.name.clone()))
-        .filter_map(|name| {
-            let mut groups = pending_by_section.remove(&name).unwrap_or_default();
-            // With `hide_empty`, drop sections that produced no cards (including
-            // the "In Progress" catch-all) so the board shows only columns with
-            // work.
-            if hide_empty && groups.is_empty() {
-                return None;
-            }
-            let max_sessions = resolve_section_limit(&name, sections, in_progress_limit);
-            // Order the column: attention tier ascending (needs-you at the top),
-            // then newest-first within a tier, then leaf id as a stable
-            // tiebreaker so equal-recency cards never jitter.
-            groups.sort_by(|a, b| {
-                a.tier
-                    .cmp(&b.tier)
-                    .then_with(|| b.recency.cmp(&a.recency))
-                    .then_with(|| a.leaf_id.cmp(&b.leaf_id))
-            });
-            // Expand each stack group into its per-session cards, contiguous and
-            // in stack order (base first, children indented).
-            let cards: Vec<BoardCard> = groups
-                .into_iter()
-                .flat_map(|pc| {
-                    pc.rows.into_iter().map(move |item| {
+    // Every lane that holds a stack, in display order. Each lane's order key is
+    // unique to it, so the map dedups lanes as well as sorting them.
+    let lane_order: BTreeMap<(bool, usize, usize, String), (String, String)> = pending_by_section
+        .values()
+        .flatten()
+        .filter_map(|pc| pc.lane.as_ref())
+        .map(|lane| (lane.order.clone(), (lane.key.clone(), lane.name.clone())))
+        .collect();
+    let lane_index: HashMap<&str, usize> = lane_order
+        .values()
+        .enumerate()
+        .map(|(i, (key, _))| (key.as_str(), i))
+        .collect();
+    let is_collapsed: Vec<bool> = lane_order
+        .values()
+        .map(|(key, _)| lanes.is_some_and(|opts| opts.collapsed.contains(key)))
+        .collect();
+
+    // Each column alongside its lanes' (card range, session count, folded cards).
+    let columns: Vec<(BoardColumn, Vec<LaneColumnSlice>)> =
+        std::iter::once(IN_PROGRESS.to_string())
+            .chain(sections.iter().map(|s| s.name.clone()))
+            .filter_map(|name| {
+                let mut groups = pending_by_section.remove(&name).unwrap_or_default();
+                // With `hide_empty`, drop sections that produced no cards
+                // (including the "In Progress" catch-all) so the board shows
+                // only columns with work.
+                if hide_empty && groups.is_empty() {
+                    return None;
+                }
+                let max_sessions = resolve_section_limit(&name, sections, in_progress_limit);
+                let lane_max_sessions = lanes.and_then(|opts| {
+                    if name == IN_PROGRESS {
+                        opts.in_progress_limit
+                    } else {
+                        sections
+                            .iter()
+                            .find(|s| s.name == name)
+                            .and_then(|s| s.lane_max_sessions)
+                    }
+                });
+                // Order the column: attention tier ascending (needs-you at the
+                // top), then newest-first within a tier, then leaf id as a
+                // stable tiebreaker so equal-recency cards never jitter. With
+                // lanes, a stable re-sort by lane keeps that order within each.
+                groups.sort_by(|a, b| {
+                    a.tier
+                        .cmp(&b.tier)
+                        .then_with(|| b.recency.cmp(&a.recency))
+                        .then_with(|| a.leaf_id.cmp(&b.leaf_id))
+                });
+                let lane_of = |pc: &PendingCard| {
+                    pc.lane
+                        .as_ref()
+                        .and_then(|lane| lane_index.get(lane.key.as_str()).copied())
+                };
+                groups.sort_by_key(lane_of);
+
+                // Expand each stack group into its per-session cards, contiguous
+                // and in stack order (base first, children indented). A
+                // collapsed lane's cards go to its folded list instead.
+                let mut cards: Vec<BoardCard> = Vec::new();
+                let mut lane_slices = vec![(0..0, 0, Vec::new()); lane_order.len()];
+                for pc in groups {
+                    let lane = lane_of(&pc);
+                    let group_cards = pc.rows.into_iter().map(|item| {
                         let SessionListItem::Worktree { stacked_child, .. } = &item else {
                             unreachable!("board rows are always Worktree")
                         };
@@ -485,29 +735,97 @@ pub fn build_board(
                             indent: *stacked_child,
                             row: item,
                         }
-                    })
-                })
-                .collect();
-            Some(BoardColumn {
-                name,
-                max_sessions,
-                cards,
+                    });
+                    let Some(lane) = lane else {
+                        cards.extend(group_cards);
+                        continue;
+                    };
+                    let (range, count, folded) = &mut lane_slices[lane];
+                    if is_collapsed[lane] {
+                        folded.extend(group_cards);
+                        *count = folded.len();
+                        continue;
+                    }
+                    if range.is_empty() {
+                        *range = cards.len()..cards.len();
+                    }
+                    cards.extend(group_cards);
+                    range.end = cards.len();
+                    *count = range.len();
+                }
+                // An empty lane slice sits where the lane would start, so
+                // range-based lookups stay ordered.
+                let mut next_start = cards.len();
+                for (range, _, _) in lane_slices.iter_mut().rev() {
+                    if range.is_empty() {
+                        *range = next_start..next_start;
+                    } else {
+                        next_start = range.start;
+                    }
+                }
+                Some((
+                    BoardColumn {
+                        name,
+                        max_sessions,
+                        lane_max_sessions,
+                        cards,
+                    },
+                    lane_slices,
+                ))
             })
+            .collect();
+
+    let lanes: Vec<BoardLane> = lane_order
+        .into_values()
+        .enumerate()
+        .map(|(i, (key, name))| {
+            let mut rows = Vec::with_capacity(columns.len());
+            let mut counts = Vec::with_capacity(columns.len());
+            let mut folded = Vec::with_capacity(columns.len());
+            for (_, slices) in &columns {
+                let (range, count, cards) = slices[i].clone();
+                rows.push(range);
+                counts.push(count);
+                folded.push(cards);
+            }
+            BoardLane {
+                key,
+                name,
+                collapsed: is_collapsed[i],
+                rows,
+                counts,
+                folded,
+            }
         })
         .collect();
 
     Board {
         servers,
         projects,
-        columns,
+        columns: columns.into_iter().map(|(column, _)| column).collect(),
+        lanes,
     }
 }
 
-/// A stack group awaiting placement: its resolved section plus the keys the
-/// column sort needs (attention tier, recency, and a stable leaf-id tiebreak)
+/// One lane's share of one column while the board is built: its range of the
+/// column's cards, its session count, and (when collapsed) its folded cards.
+type LaneColumnSlice = (Range<usize>, usize, Vec<BoardCard>);
+
+/// The swimlane a stack group belongs to. `order` sorts lanes top to bottom:
+/// a trailing-lane flag (the PR-author grouping's "No PR"), then the backend
+/// and sidebar indices (project/server lanes), then the lowercased name.
+struct PendingLane {
+    order: (bool, usize, usize, String),
+    key: String,
+    name: String,
+}
+
+/// A stack group awaiting placement: its resolved section (and lane, when the
+/// board has lanes) plus the keys the column sort needs (attention tier, recency, and a stable leaf-id tiebreak)
 /// and the per-session rows to expand into cards.
 struct PendingCard {
     section: String,
+    lane: Option<PendingLane>,
     tier: u8,
     recency: DateTime<Utc>,
     leaf_id: SessionId,
@@ -633,6 +951,8 @@ pub(crate) fn worktree_item(
         // Set by refresh_list_items from UiState::lfs_pull_in_flight after the
         // items are built.
         lfs_pulling: false,
+        task_status: crate::session::TaskStatus::summarize(&session.task_runs),
+        tags: session.tags.clone(),
         stacked_child,
     }
 }
@@ -789,6 +1109,7 @@ mod tests {
             in_progress_limit,
             filter,
             hide_empty,
+            None,
         )
     }
 
@@ -1533,4 +1854,175 @@ mod tests {
         let board = board_from(&state, &[], None, &BTreeMap::new());
         assert_eq!(board.position_of(SessionId::new()), None);
     }
+
+    // --- swimlanes -------------------------------------------------------
+
+    /// As [`board_from`], split into `grouping` lanes with `collapsed` folded.
+    fn board_with_lanes(
+        snapshot: &WorkspaceSnapshot,
+        sections: &[SectionConfig],
+        grouping: Swimlanes,
+        collapsed: &BTreeSet<String>,
+    ) -> Board {
+        build_board(
+            &[BoardBackendInput {
+                backend: LOCAL_BACKEND_ID,
+                name: "local".to_string(),
+                connection: ConnectionState::Connected,
+                version_warning: None,
+                snapshot,
+                agent_states: &BTreeMap::new(),
+            }],
+            sections,
+            None,
+            None,
+            false,
+            Some(LaneOptions {
+                grouping,
+                collapsed,
+                in_progress_limit: Some(1),
+            }),
+        )
+    }
+
+    #[test]
+    fn project_lanes_follow_sidebar_order_and_regroup_each_column() {
+        let (pa, pb) = (ProjectId::new(), ProjectId::new());
+        let mut sessions = Vec::new();
+        // Interleave creation times so attention order alone would mix the
+        // projects within the column.
+        for (i, pid) in [pa, pb, pa, pb].into_iter().enumerate() {
+            let mut s = make_session(&format!("s{i}"), &format!("b{i}"), i as i64 * 10);
+            s.project_id = pid;
+            sessions.push(s);
+        }
+        let state = snapshot_from(sessions);
+        let board = board_with_lanes(&state, &[], Swimlanes::Project, &BTreeSet::new());
+
+        let lane_names: Vec<&str> = board.lanes.iter().map(|l| l.name.as_str()).collect();
+        let sidebar_names: Vec<&str> = board.projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(lane_names, sidebar_names);
+        assert_eq!(board.lanes[0].rows, vec![0..2]);
+        assert_eq!(board.lanes[1].rows, vec![2..4]);
+        assert_eq!(board.lanes[1].counts, vec![2]);
+
+        // Cards are lane-major, and every card sits in its project's lane.
+        let first_lane_project = board.projects[0].project_id;
+        for (row, card) in board.columns[0].cards.iter().enumerate() {
+            let lane = board.lane_at(BoardPos { col: 1, row }).unwrap();
+            assert_eq!(lane, usize::from(card.project_id != first_lane_project));
+        }
+        assert_eq!(board.lane_at(BoardPos { col: 0, row: 0 }), None);
+        assert_eq!(board.columns[0].lane_max_sessions, Some(1));
+    }
+
+    #[test]
+    fn collapsed_lane_keeps_its_counts_but_contributes_no_cards() {
+        let mut claude = make_session("a", "a", 0);
+        claude.program = "claude".to_string();
+        let mut codex = make_session("b", "b", 10);
+        codex.program = "codex".to_string();
+        let codex_id = codex.id;
+        let state = snapshot_from(vec![claude, codex]);
+        let collapsed = BTreeSet::from(["program:claude".to_string()]);
+        let board = board_with_lanes(&state, &[], Swimlanes::Program, &collapsed);
+
+        assert_eq!(board.lanes.len(), 2);
+        assert!(board.lanes[0].collapsed);
+        assert_eq!(board.lanes[0].counts, vec![1]);
+        assert!(board.lanes[0].rows[0].is_empty());
+        assert_eq!(board.lanes[1].rows, vec![0..1]);
+        assert_eq!(column_session_ids(&board, IN_PROGRESS), vec![codex_id]);
+        // The column still counts the folded session, and the lane keeps it.
+        assert_eq!(board.column_total(0), 2);
+        assert_eq!(board.worktree_count(), 1);
+        assert_eq!(board.lanes[0].folded[0].len(), 1);
+        assert_eq!(board.cards().count(), 2);
+    }
+
+    #[test]
+    fn collapsing_a_lane_keeps_grid_tiles_and_session_numbers() {
+        use crate::tui::widgets::grid::{GridFilter, grid_tiles};
+
+        let mut sessions = Vec::new();
+        for (i, program) in ["claude", "codex", "claude", "codex"]
+            .into_iter()
+            .enumerate()
+        {
+            let mut s = make_session(&format!("s{i}"), &format!("b{i}"), i as i64 * 10);
+            s.program = program.to_string();
+            sessions.push(s);
+        }
+        let claude_id = sessions[0].id;
+        let codex_id = sessions[1].id;
+        let state = snapshot_from(sessions);
+        let open = board_with_lanes(&state, &[], Swimlanes::Program, &BTreeSet::new());
+        let collapsed = BTreeSet::from(["program:claude".to_string()]);
+        let folded = board_with_lanes(&state, &[], Swimlanes::Program, &collapsed);
+        assert_eq!(folded.worktree_count(), 2);
+
+        let tiles = grid_tiles(&open, GridFilter::All);
+        assert_eq!(tiles.len(), 4);
+        assert_eq!(grid_tiles(&folded, GridFilter::All), tiles);
+        let numbers = open.session_numbers();
+        assert_eq!(folded.session_numbers(), numbers);
+
+        // A folded session keeps its number but has no position to jump to;
+        // the visible ones still resolve by number.
+        assert_eq!(folded.position_of(claude_id), None);
+        assert_eq!(folded.pos_of_session_number(numbers[&claude_id]), None);
+        assert_eq!(
+            folded.pos_of_session_number(numbers[&codex_id]),
+            folded.position_of(codex_id)
+        );
+        assert!(folded.position_of(codex_id).is_some());
+    }
+
+    #[test]
+    fn pr_author_lanes_are_name_sorted_with_no_pr_last() {
+        let mut none = make_session("none", "none", 0);
+        none.pr_author = None;
+        let mut zed = make_session("zed", "zed", 10);
+        zed.pr_author = Some("zed".to_string());
+        let mut amy = make_session("amy", "amy", 20);
+        amy.pr_author = Some("Amy".to_string());
+        let state = snapshot_from(vec![none, zed, amy]);
+        let board = board_with_lanes(&state, &[], Swimlanes::PrAuthor, &BTreeSet::new());
+
+        let lane_names: Vec<&str> = board.lanes.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(lane_names, vec!["Amy", "zed", "No PR"]);
+        assert_eq!(board.lanes[0].key, "author:amy");
+    }
+
+    #[test]
+    fn lane_limits_resolve_per_section() {
+        let s = make_session_in_section("a", "a", 0, "Review");
+        let state = snapshot_from(vec![s]);
+        let sections = vec![SectionConfig {
+            name: "Review".to_string(),
+            lane_max_sessions: Some(2),
+            ..Default::default()
+        }];
+        let board = board_with_lanes(&state, &sections, Swimlanes::Server, &BTreeSet::new());
+        assert_eq!(board.lanes.len(), 1);
+        assert_eq!(board.lanes[0].name, "local");
+        assert_eq!(board.lanes[0].counts, vec![0, 1]);
+        assert_eq!(board.columns[1].lane_max_sessions, Some(2));
+
+        // Without lanes no lane limit is carried.
+        let plain = board_from(&state, &sections, None, &BTreeMap::new());
+        assert!(plain.lanes.is_empty());
+        assert_eq!(plain.columns[1].lane_max_sessions, None);
+    }
+
+    #[test]
+    fn swimlanes_cycle_through_every_grouping_then_off() {
+        let mut seen = Vec::new();
+        let mut current = Swimlanes::cycle(None);
+        while let Some(lanes) = current {
+            seen.push(lanes);
+            current = Swimlanes::cycle(current);
+        }
+        assert_eq!(seen, Swimlanes::ALL);
+    }
 }
@@ -0,0 +1,182 @@
+//! Archiving sessions
+//!
+//! Archiving takes a finished-but-unmerged session out of the way without
+//! losing it: tmux is stopped and the worktree removed, but the branch, the
+//! session's metadata and its agent resume info stay, and any uncommitted work
+//! is kept on a hidden ref (see [`crate::git::snapshot_worktree`]). Restoring
+//! recreates the worktree at the same path — agents key `--resume`/`--continue`
+//! on the working directory — re-applies the snapshot and relaunches resumed.
+
+use super::*;
+use crate::session::ArchiveInfo;
+
+impl SessionManager {
+    /// Archive a session: stop tmux, pin its uncommitted work to
+    /// `refs/commander/archive/<id>`, and remove the worktree, keeping the
+    /// branch and the session row (marked [`WorktreeSession::archived`]).
+    ///
+    /// Refused while the session is mid-operation (creating, merging, pushing,
+    /// or paused in a cascade). If the snapshot fails the session is left
+    /// stopped with its worktree intact.
+    #[instrument(skip(self))]
+    pub async fn archive_session(&self, session_id: &SessionId) -> Result<()> {
+        let (session, repo_path) = {
+            let state = self.store.read().await;
+            let session = state
+                .get_session(session_id)
+                .ok_or(SessionError::NotFound(*session_id))?;
+            if session.archived.is_some() {
+                return Err(SessionError::Archived(*session_id).into());
+            }
+            if !matches!(
+                session.status,
+                SessionStatus::Running | SessionStatus::Stopped
+            ) {
+                return Err(SessionError::InvalidState(*session_id).into());
+            }
+            let repo_path = state
+                .get_project(&session.project_id)
+                .map(|p| p.repo_path.clone())
+                .ok_or_else(|| SessionError::ProjectNotFound(session.project_id.to_string()))?;
+            (session.clone(), repo_path)
+        };
+
+        // Stop first so the agent can't write while the snapshot is taken. This
+        // is the non-destructive kill, so a failure below leaves an ordinary
+        // stopped session that resumes on attach.
+        self.kill_session(session_id, false).await?;
+
+        let archive_ref = crate::git::archive_ref_name(session_id);
+        let (head, has_snapshot) = if session.worktree_path.exists() {
+            let snapshot =
+                crate::git::snapshot_worktree(&session.worktree_path, &archive_ref).await?;
+            (snapshot.head, snapshot.has_snapshot)
+        } else {
+            // Worktree already gone (removed by hand): only the branch is left.
+            let branch_ref = format!("refs/heads/{}", session.branch);
+            let head = crate::git::pin_archive_ref(&repo_path, &archive_ref, &branch_ref).await?;
+            (head, false)
+        };
+
+        self.remove_session_worktree(Some(&repo_path), &session.worktree_path)
+            .await?;
+
+        let sid = *session_id;
+        let archived = ArchiveInfo {
+            archived_at: chrono::Utc::now(),
+            head,
+            archive_ref,
+            has_snapshot,
+        };
+        self.store
+            .mutate(move |state| {
+                if let Some(session) = state.get_session_mut(&sid) {
+                    session.set_status(SessionStatus::Stopped);
+                    session.hibernated = true;
+                    // No worktree to serve from; a restore allocates afresh.
+                    session.ports = None;
+                    session.archived = Some(archived);
+                }
+            })
+            .await?;
+
+        info!("Archived session {}", session_id);
+        Ok(())
+    }
+
+    /// Restore an archived session: recreate its worktree at the original path
+    /// (recreating the branch at the archived commit if it has since been
+    /// deleted), re-apply the uncommitted-work snapshot, and relaunch the agent
+    /// with `--resume`.
+    ///
+    /// When the snapshot no longer applies (the branch moved on since), the
+    /// recreated worktree is removed again and the session stays archived; the
+    /// error names the ref holding the changes.
+    #[instrument(skip(self))]
+    pub async fn restore_session(&self, session_id: &SessionId) -> Result<()> {
+        let (session, archive, repo_path) = {
+            let state = self.store.read().await;
+            let session = state
+                .get_session(session_id)
+                .ok_or(SessionError::NotFound(*session_id))?;
+            let Some(archive) = session.archived.clone() else {
+                return Err(SessionError::InvalidState(*session_id).into());
+            };
+            let repo_path = state
+                .get_project(&session.project_id)
+                .map(|p| p.repo_path.clone())
+                .ok_or_else(|| SessionError::ProjectNotFound(session.project_id.to_string()))?;
+            (session.clone(), archive, repo_path)
+        };
+
+        self.recreate_worktree_from_ref(
+            &session,
+            &repo_path,
+            &archive.head,
+            archive.has_snapshot.then_some(archive.archive_ref.as_str()),
+        )
+        .await?;
+        if let Err(e) = crate::git::delete_archive_ref(&repo_path, &archive.archive_ref).await {
+            warn!("Failed to delete archive ref after restore: {}", e);
+        }
+
+        // Clear the marker and flag the session hibernated, so the relaunch
+        // resumes the agent conversation regardless of `resume_session`.
+        let sid = *session_id;
+        self.store
+            .mutate(move |state| {
+                if let Some(session) = state.get_session_mut(&sid) {
+                    session.archived = None;
+                    session.hibernated = true;
+                }
+            })
+            .await?;
+
+        self.restart_session(session_id).await?;
+        info!("Restored session {}", session_id);
+        Ok(())
+    }
+
+    /// Recreate `session`'s worktree at its original path — recreating the
+    /// branch at `head` if it has been deleted since — and re-apply the
+    /// uncommitted-work snapshot at `snapshot_ref`, if any. Shared by restore
+    /// and undo. When the snapshot no longer applies the worktree is removed
+    /// again, so a failed attempt can simply be retried.
+    pub(super) async fn recreate_worktree_from_ref(
+        &self,
+        session: &WorktreeSession,
+        repo_path: &Path,
+        head: &str,
+        snapshot_ref: Option<&str>,
+    ) -> Result<()> {
+        let worktree_path = &session.worktree_path;
+        if !worktree_path.exists() {
+            let branch_exists =
+                crate::git::ref_exists_cli(repo_path, &format!("refs/heads/{}", session.branch))
+                    .await;
+            let worktrees_dir = match worktree_path.parent() {
+                Some(dir) => dir.to_path_buf(),
+                None => self.config_store.read().worktrees_dir()?,
+            };
+            WorktreeManager::run_create_worktree(
+                worktrees_dir,
+                repo_path.to_path_buf(),
+                worktree_path.clone(),
+                session.branch.clone(),
+                branch_exists,
+                (!branch_exists).then(|| head.to_string()),
+                false,
+            )
+            .await?;
+        }
+
+        if let Some(snapshot_ref) = snapshot_ref
+            && let Err(e) = crate::git::restore_snapshot(worktree_path, snapshot_ref).await
+        {
+            self.remove_session_worktree(Some(repo_path), worktree_path)
+                .await?;
+            return Err(e);
+        }
+        Ok(())
+    }
+}
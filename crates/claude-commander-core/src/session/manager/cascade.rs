@@ -14,7 +14,7 @@ use tracing::{info, instrument, warn};
 
 use super::*;
 use crate::error::SessionError;
-use crate::session::{AgentState, stack_chain_from_base};
+use crate::session::{AgentState, HookEvent, stack_chain_from_base};
 
 /// Result of running `git merge` on a worktree.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -140,8 +140,11 @@ impl SessionManager {
             self.set_status(sid, SessionStatus::Merging).await;
             let outcome = run_git_merge(wt_path, &upstream).await;
             match outcome {
-                Ok(MergeOutcome::Clean) | Ok(MergeOutcome::AlreadyUpToDate) => {
+                Ok(merged @ (MergeOutcome::Clean | MergeOutcome::AlreadyUpToDate)) => {
                     self.set_status(sid, SessionStatus::Running).await;
+                    if merged == MergeOutcome::Clean {
+                        self.spawn_session_hooks(HookEvent::PostMerge, *sid, None);
+                    }
                     sessions_merged += 1;
                     info!(
                         "cascade: merged {} into session '{}' ({}/{})",
@@ -283,8 +286,11 @@ impl SessionManager {
 
             self.set_status(sid, SessionStatus::Merging).await;
             match run_git_merge(wt_path, &upstream).await {
-                Ok(MergeOutcome::Clean) | Ok(MergeOutcome::AlreadyUpToDate) => {
+                Ok(merged @ (MergeOutcome::Clean | MergeOutcome::AlreadyUpToDate)) => {
                     self.set_status(sid, SessionStatus::Running).await;
+                    if merged == MergeOutcome::Clean {
+                        self.spawn_session_hooks(HookEvent::PostMerge, *sid, None);
+                    }
                     sessions_merged += 1;
                     info!(
                         "cascade resume: merged {} into '{}' ({}/{})",
@@ -328,6 +334,10 @@ impl SessionManager {
         };
         self.set_status(&paused_at, SessionStatus::Running).await;
         self.clear_cascade_paused().await;
+        self.record_undo(crate::config::UndoAction::CascadeAbandon {
+            session_id: paused_at,
+        })
+        .await;
         info!("cascade: abandoned pause at {}", paused_at);
         Ok(())
     }
@@ -35,6 +35,10 @@ impl SessionManager {
             if !session.status.can_attach() {
                 return Err(SessionError::InvalidState(*session_id).into());
             }
+            // No worktree to launch into; restoring recreates it.
+            if session.archived.is_some() {
+                return Err(SessionError::Archived(*session_id).into());
+            }
 
             (
                 session.tmux_session_name.clone(),
@@ -63,6 +67,7 @@ impl SessionManager {
             if pane_dead {
                 info!("Pane is dead, killing tmux session for recreation");
                 let _ = self.tmux.kill_session(&tmux_name).await;
+                self.stop_resource_scopes(&tmux_name).await;
                 true
             } else {
                 false
@@ -79,6 +84,12 @@ impl SessionManager {
             let resume_program =
                 super::lifecycle::program_with_session_name(&resume_program, &title);
             let resume_program = self.maybe_wrap_nix_develop(&resume_program, &worktree_path);
+            let resume_program = self
+                .maybe_wrap_resource_scope(&resume_program, &tmux_name, &tmux_name)
+                .await;
+            let resume_program = self
+                .maybe_with_session_env(&resume_program, session_id, &worktree_path)
+                .await;
             info!("Recreating tmux session with: {}", resume_program);
             self.tmux
                 .create_session(&tmux_name, &worktree_path, Some(&resume_program))
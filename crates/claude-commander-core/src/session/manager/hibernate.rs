@@ -77,6 +77,38 @@ pub(crate) fn attached_including_shell(main_attached: bool, shell_attached: Opti
     main_attached || shell_attached.unwrap_or(false)
 }
 
+/// Whether available memory is below `threshold_percent` of total. A zero
+/// threshold (or an unreadable total) is never pressure. Pure.
+pub(crate) fn under_memory_pressure(total: u64, available: u64, threshold_percent: u8) -> bool {
+    threshold_percent > 0
+        && total > 0
+        && u128::from(available) * 100 < u128::from(total) * u128::from(threshold_percent)
+}
+
+/// Pick which idle sessions to hibernate to relieve memory pressure: largest
+/// RSS first, stopping once the freed estimate brings available memory back
+/// to the threshold. Sessions are `(id, rss_bytes)` and must already be idle
+/// (the caller applies the same activity rules as the timed pass). Pure.
+pub(crate) fn pressure_victims(
+    mut idle: Vec<(SessionId, u64)>,
+    total: u64,
+    available: u64,
+    threshold_percent: u8,
+) -> Vec<SessionId> {
+    idle.sort_by(|a, b| b.1.cmp(&a.1));
+    let target = u128::from(total) * u128::from(threshold_percent) / 100;
+    let mut projected = u128::from(available);
+    let mut victims = Vec::new();
+    for (id, rss) in idle {
+        if projected >= target {
+            break;
+        }
+        victims.push(id);
+        projected += u128::from(rss);
+    }
+    victims
+}
+
 impl SessionManager {
     /// Attachment check spanning the main pane and its paired shell. Each probe
     /// is conservative — a failed `is_session_attached` counts as attached — so
@@ -186,6 +218,8 @@ impl SessionManager {
             idle_since.retain(|id, _| candidate_ids.contains(id));
 
             let now = Instant::now();
+            // Idle-but-not-yet-expired sessions, for the memory-pressure pass.
+            let mut idle_now: Vec<(SessionId, String)> = Vec::new();
             for (id, tmux_name, shell_tmux_name, program, last_attached_at) in candidates {
                 let state = detector
                     .detect(AgentKind::from_program(&program), &tmux_name)
@@ -214,6 +248,9 @@ impl SessionManager {
                         idle_since.remove(&id);
                     }
                 }
+                if !is_active && !hibernate {
+                    idle_now.push((id, tmux_name.clone()));
+                }
                 if hibernate {
                     // Record telemetry only for a real hibernation: the pre-kill
                     // guards in hibernate_session may skip (attached, restarted,
@@ -230,6 +267,60 @@ impl SessionManager {
                     }
                 }
             }
+
+            self.relieve_memory_pressure(idle_now, &telemetry).await;
+        }
+    }
+
+    /// Memory-pressure pass: when host available memory is under
+    /// `resource_limits.memory_pressure_percent`, hibernate the largest idle
+    /// sessions (see [`pressure_victims`]) without waiting out their idle
+    /// timeout. `idle` holds the sessions this tick found idle.
+    async fn relieve_memory_pressure(&self, idle: Vec<(SessionId, String)>, telemetry: &Telemetry) {
+        let threshold = self
+            .config_store
+            .read()
+            .resource_limits
+            .memory_pressure_percent;
+        if idle.is_empty() {
+            return;
+        }
+        let Some((total, available)) = resources::host_memory() else {
+            return;
+        };
+        if !under_memory_pressure(total, available, threshold) {
+            return;
+        }
+
+        let mut sized = Vec::with_capacity(idle.len());
+        for (id, tmux_name) in idle {
+            if let Some(rss_bytes) = self.session_rss_bytes(&tmux_name).await {
+                sized.push((id, rss_bytes));
+            }
+        }
+        let victims = pressure_victims(sized, total, available, threshold);
+        if !victims.is_empty() {
+            warn!(
+                "Memory pressure ({} MiB of {} MiB available); hibernating {} idle session(s)",
+                available / (1024 * 1024),
+                total / (1024 * 1024),
+                victims.len()
+            );
+        }
+        for id in victims {
+            match self.hibernate_session(&id).await {
+                Ok(true) => {
+                    info!("Hibernated idle session {} under memory pressure", id);
+                    telemetry.feature("hibernate_pressure");
+                }
+                Ok(false) => {
+                    debug!(
+                        "Skipped pressure-hibernating {} (guard tripped at kill time)",
+                        id
+                    );
+                }
+                Err(e) => warn!("Failed to hibernate session {}: {}", id, e),
+            }
         }
     }
 }
@@ -317,6 +408,31 @@ mod tests {
         assert!(!attached_including_shell(false, None));
     }
 
+    #[test]
+    fn memory_pressure_threshold() {
+        assert!(under_memory_pressure(1000, 50, 10));
+        assert!(!under_memory_pressure(1000, 100, 10));
+        // Zero threshold disables; an unreadable total is never pressure.
+        assert!(!under_memory_pressure(1000, 0, 0));
+        assert!(!under_memory_pressure(0, 0, 10));
+    }
+
+    #[test]
+    fn pressure_victims_largest_first_until_recovered() {
+        let (small, mid, big) = (SessionId::new(), SessionId::new(), SessionId::new());
+        let idle = vec![(small, 10), (big, 300), (mid, 100)];
+        // Target 200 of 1000; 50 available. Freeing `big` alone gets to 350.
+        assert_eq!(pressure_victims(idle.clone(), 1000, 50, 20), vec![big]);
+        // Target 500: needs `big` then `mid` (50 + 300 + 100 = 450 < 500), then `small`.
+        assert_eq!(pressure_victims(idle, 1000, 50, 50), vec![big, mid, small]);
+    }
+
+    #[test]
+    fn pressure_victims_none_when_not_short() {
+        let idle = vec![(SessionId::new(), 500)];
+        assert!(pressure_victims(idle, 1000, 300, 10).is_empty());
+    }
+
     #[test]
     fn working_or_attached_or_recent_counts_as_active() {
         assert!(is_session_active(AgentState::Working, false, false));
@@ -0,0 +1,414 @@
+//! Lifecycle hooks
+//!
+//! Runs the shell commands configured for a session lifecycle event — the
+//! global `[hooks]` table plus the project's `.commander.toml` — in the
+//! session's worktree, each bounded by `timeout_secs` (an overrun kills the
+//! hook's whole process tree, see [`crate::git::bounded`]). Every run is
+//! recorded on the session (`WorktreeSession::hook_runs`) for the Info view.
+//!
+//! `post_create` and `pre_delete` run inline and can abort the operation
+//! under `on_failure = "block"`; the after-the-fact events (`post_merge`,
+//! agent- and PR-state changes) are fired detached and only ever warn.
+
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use tokio::process::Command;
+
+use super::*;
+use crate::config::{HookFailurePolicy, HooksConfig, ProjectFile};
+use crate::git::bounded::{Bounded, run_bounded};
+use crate::session::{HookEvent, HookOutcome, HookRun, PortBlock};
+
+/// Lines of combined output kept per run.
+const OUTPUT_TAIL_LINES: usize = 20;
+/// Byte cap on the kept tail, so one chatty hook can't bloat `state.json`.
+const OUTPUT_TAIL_BYTES: usize = 4096;
+
+/// What a hook needs to know about the session it runs for. Built from state
+/// for existing sessions; `finalize_session` builds it by hand because the
+/// worktree path isn't persisted yet when `post_create` runs.
+#[derive(Debug, Clone)]
+pub(crate) struct HookContext {
+    pub session_id: SessionId,
+    pub title: String,
+    pub branch: String,
+    pub worktree_path: PathBuf,
+    pub project_name: String,
+    pub repo_path: PathBuf,
+    /// The session's port block, exported like it is to the panes.
+    pub ports: Option<PortBlock>,
+}
+
+impl HookContext {
+    fn from_state(state: &AppState, session_id: &SessionId) -> Option<Self> {
+        let session = state.get_session(session_id)?;
+        let project = state.get_project(&session.project_id)?;
+        Some(Self {
+            session_id: session.id,
+            title: session.title.clone(),
+            branch: session.branch.clone(),
+            worktree_path: session.worktree_path.clone(),
+            project_name: project.name.clone(),
+            repo_path: project.repo_path.clone(),
+            ports: session.ports,
+        })
+    }
+}
+
+/// A `from → to` state change, exported as `COMMANDER_FROM` / `COMMANDER_TO`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Transition {
+    pub from: String,
+    pub to: String,
+}
+
+/// Whether a failure of `event`'s hooks may abort anything. Only the events
+/// that run *before* an operation can; the rest report on something that has
+/// already happened.
+fn can_block(event: HookEvent) -> bool {
+    matches!(event, HookEvent::PostCreate | HookEvent::PreDelete)
+}
+
+/// Whether a run of `event`'s hooks belongs in the session's run history.
+/// `agent_state_change` fires on every working/idle flip, so its successes
+/// would push everything else out of the ring and rewrite state on each
+/// flip; only its failures are kept.
+fn should_record(event: HookEvent, outcome: &HookOutcome) -> bool {
+    event != HookEvent::AgentStateChange || !outcome.is_success()
+}
+
+/// Environment handed to every hook command.
+fn hook_env(
+    event: HookEvent,
+    ctx: &HookContext,
+    transition: Option<&Transition>,
+) -> Vec<(String, String)> {
+    let mut env: Vec<(String, String)> = [
+        ("COMMANDER_EVENT", event.to_string()),
+        ("COMMANDER_SESSION_ID", ctx.session_id.as_uuid().to_string()),
+        ("COMMANDER_SESSION_TITLE", ctx.title.clone()),
+        ("COMMANDER_BRANCH", ctx.branch.clone()),
+        (
+            "COMMANDER_WORKTREE",
+            ctx.worktree_path.to_string_lossy().into_owned(),
+        ),
+        ("COMMANDER_PROJECT", ctx.project_name.clone()),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v))
+    .collect();
+    if let Some(block) = &ctx.ports {
+        env.extend(super::ports::port_vars(block));
+    }
+    if let Some(t) = transition {
+        env.push(("COMMANDER_FROM".to_string(), t.from.clone()));
+        env.push(("COMMANDER_TO".to_string(), t.to.clone()));
+    }
+    env
+}
+
+/// The last [`OUTPUT_TAIL_LINES`] lines of stdout followed by stderr, capped
+/// at [`OUTPUT_TAIL_BYTES`] (cut on a char boundary, keeping the end).
+fn output_tail(stdout: &[u8], stderr: &[u8]) -> String {
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(stdout),
+        String::from_utf8_lossy(stderr)
+    );
+    text_tail(&combined, OUTPUT_TAIL_LINES, OUTPUT_TAIL_BYTES)
+}
+
+/// The last `max_lines` lines of `text`, capped at `max_bytes` (cut on a char
+/// boundary, keeping the end). Shared with the task runner.
+pub(super) fn text_tail(text: &str, max_lines: usize, max_bytes: usize) -> String {
+    let lines: Vec<&str> = text.trim_end().lines().collect();
+    let mut tail = lines[lines.len().saturating_sub(max_lines)..].join("\n");
+    if tail.len() > max_bytes {
+        let mut cut = tail.len() - max_bytes;
+        while !tail.is_char_boundary(cut) {
+            cut += 1;
+        }
+        tail.drain(..cut);
+    }
+    tail
+}
+
+/// Run one hook command to completion and describe how it went.
+async fn run_hook_command(
+    command: &str,
+    event: HookEvent,
+    ctx: &HookContext,
+    transition: Option<&Transition>,
+    timeout: Duration,
+) -> HookRun {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c")
+        .arg(command)
+        .current_dir(&ctx.worktree_path)
+        .envs(hook_env(event, ctx, transition));
+
+    let started = Instant::now();
+    let (outcome, output_tail) = match run_bounded(cmd, "hook", timeout).await {
+        Ok(Bounded::Finished {
+            status,
+            stdout,
+            stderr,
+        }) => {
+            let outcome = if status.success() {
+                HookOutcome::Succeeded
+            } else {
+                HookOutcome::Failed {
+                    code: status.code(),
+                }
+            };
+            (outcome, output_tail(&stdout, &stderr))
+        }
+        Ok(Bounded::TimedOut) => (HookOutcome::TimedOut, String::new()),
+        Err(e) => (HookOutcome::SpawnFailed, e.to_string()),
+    };
+
+    HookRun {
+        event,
+        command: command.to_string(),
+        outcome,
+        finished_at: Utc::now(),
+        duration_ms: started.elapsed().as_millis() as u64,
+        output_tail,
+    }
+}
+
+impl SessionManager {
+    /// The effective hooks for a session: global config with the project
+    /// file layered on top.
+    fn resolved_hooks(&self, ctx: &HookContext) -> HooksConfig {
+        let (global, trusted) = {
+            let config = self.config_store.read();
+            (config.hooks.clone(), config.trusted_project_files.clone())
+        };
+        let project = ProjectFile::load_for(&ctx.worktree_path, &ctx.repo_path, &trusted);
+        global.with_project(&project.hooks)
+    }
+
+    /// Run `event`'s hooks for the session described by `ctx`, in order,
+    /// recording each run on the session (see [`should_record`]).
+    ///
+    /// Returns [`SessionError::HookFailed`] for the first failing command when
+    /// the event can block and the policy is `block`; the remaining commands
+    /// are then skipped. Otherwise failures are logged and every command runs.
+    pub(crate) async fn run_hooks(
+        &self,
+        event: HookEvent,
+        ctx: &HookContext,
+        transition: Option<&Transition>,
+    ) -> Result<()> {
+        let hooks = self.resolved_hooks(ctx);
+        let commands = hooks.commands(event);
+        if commands.is_empty() {
+            return Ok(());
+        }
+        if !ctx.worktree_path.is_dir() {
+            // Nothing to run in — a session whose worktree is already gone must
+            // still be deletable, so this is never a blocking failure.
+            debug!(
+                "Skipping {event} hooks for {}: worktree missing",
+                ctx.session_id
+            );
+            return Ok(());
+        }
+
+        let timeout = Duration::from_secs(hooks.timeout_secs.max(1));
+        let block = can_block(event) && hooks.on_failure == HookFailurePolicy::Block;
+        for command in commands {
+            let run = run_hook_command(command, event, ctx, transition, timeout).await;
+            let outcome = run.outcome;
+            if outcome.is_success() {
+                debug!("{event} hook `{command}` succeeded for {}", ctx.session_id);
+            } else {
+                warn!("{event} hook `{command}` for {}: {outcome}", ctx.session_id);
+            }
+
+            if should_record(event, &outcome) {
+                let id = ctx.session_id;
+                if let Err(e) = self
+                    .store
+                    .mutate(move |state| {
+                        if let Some(session) = state.get_session_mut(&id) {
+                            session.record_hook_run(run);
+                        }
+                    })
+                    .await
+                {
+                    warn!("Failed to record hook run for {id}: {e}");
+                }
+            }
+
+            if block && !outcome.is_success() {
+                return Err(SessionError::HookFailed {
+                    event,
+                    command: command.clone(),
+                    outcome,
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// [`Self::run_hooks`] for a session already in state. A session that
+    /// has vanished has nothing to run for.
+    pub(crate) async fn run_session_hooks(
+        &self,
+        event: HookEvent,
+        session_id: SessionId,
+        transition: Option<&Transition>,
+    ) -> Result<()> {
+        let ctx = {
+            let state = self.store.read().await;
+            HookContext::from_state(&state, &session_id)
+        };
+        match ctx {
+            Some(ctx) => self.run_hooks(event, &ctx, transition).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Fire `event`'s hooks in the background, for events that report on
+    /// something that already happened and so never block.
+    pub(crate) fn spawn_session_hooks(
+        &self,
+        event: HookEvent,
+        session_id: SessionId,
+        transition: Option<Transition>,
+    ) {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = manager
+                .run_session_hooks(event, session_id, transition.as_ref())
+                .await
+            {
+                warn!("{event} hooks for {session_id} failed: {e}");
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod hooks_tests {
+    use super::*;
+
+    fn ctx() -> HookContext {
+        HookContext {
+            session_id: SessionId::new(),
+            title: "Fix login".to_string(),
+            branch: "fix-login".to_string(),
+            worktree_path: PathBuf::from("/tmp/wt"),
+            project_name: "api".to_string(),
+            repo_path: PathBuf::from("/tmp/repo"),
+            ports: None,
+        }
+    }
+
+    fn has(env: &[(String, String)], key: &str, value: &str) -> bool {
+        env.iter().any(|(k, v)| k == key && v == value)
+    }
+
+    #[test]
+    fn env_carries_transition_only_for_state_changes() {
+        let ctx = ctx();
+        let env = hook_env(HookEvent::PostCreate, &ctx, None);
+        assert!(has(&env, "COMMANDER_EVENT", "post_create"));
+        assert!(has(&env, "COMMANDER_BRANCH", "fix-login"));
+        assert!(!env.iter().any(|(k, _)| k == "COMMANDER_FROM"));
+
+        let t = Transition {
+            from: "open".to_string(),
+            to: "merged".to_string(),
+        };
+        let env = hook_env(HookEvent::PrStateChange, &ctx, Some(&t));
+        assert!(has(&env, "COMMANDER_FROM", "open"));
+        assert!(has(&env, "COMMANDER_TO", "merged"));
+    }
+
+    #[test]
+    fn env_carries_the_session_ports() {
+        let mut ctx = ctx();
+        ctx.ports = Some(PortBlock {
+            start: 20010,
+            count: 2,
+        });
+        let env = hook_env(HookEvent::PostCreate, &ctx, None);
+        assert!(has(&env, "PORT", "20010"));
+        assert!(has(&env, "COMMANDER_PORT_1", "20011"));
+    }
+
+    #[test]
+    fn only_pre_operation_events_block() {
+        assert!(can_block(HookEvent::PostCreate));
+        assert!(can_block(HookEvent::PreDelete));
+        assert!(!can_block(HookEvent::PostMerge));
+        assert!(!can_block(HookEvent::AgentStateChange));
+        assert!(!can_block(HookEvent::PrStateChange));
+    }
+
+    #[test]
+    fn agent_state_change_records_only_failures() {
+        let ok = HookOutcome::Succeeded;
+        let failed = HookOutcome::Failed { code: Some(1) };
+        assert!(!should_record(HookEvent::AgentStateChange, &ok));
+        assert!(should_record(HookEvent::AgentStateChange, &failed));
+        assert!(should_record(HookEvent::PrStateChange, &ok));
+        assert!(should_record(HookEvent::PostCreate, &ok));
+    }
+
+    #[test]
+    fn output_tail_keeps_last_lines_of_both_streams() {
+        let stdout: String = (0..30).map(|i| format!("out {i}\n")).collect();
+        let tail = output_tail(stdout.as_bytes(), b"err\n");
+        let lines: Vec<&str> = tail.lines().collect();
+        assert_eq!(lines.len(), OUTPUT_TAIL_LINES);
+        assert_eq!(lines.last(), Some(&"err"));
+        assert_eq!(lines.first(), Some(&"out 11"));
+    }
+
+    #[test]
+    fn output_tail_is_byte_capped_on_a_char_boundary() {
+        let long = "é".repeat(OUTPUT_TAIL_BYTES);
+        let tail = output_tail(long.as_bytes(), b"");
+        assert!(tail.len() <= OUTPUT_TAIL_BYTES);
+        assert!(tail.chars().all(|c| c == 'é'));
+    }
+
+    #[tokio::test]
+    async fn failing_command_reports_exit_code_and_output() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut ctx = ctx();
+        ctx.worktree_path = dir.path().to_path_buf();
+        let run = run_hook_command(
+            "echo \"$COMMANDER_EVENT\"; exit 3",
+            HookEvent::PreDelete,
+            &ctx,
+            None,
+            Duration::from_secs(10),
+        )
+        .await;
+        assert_eq!(run.outcome, HookOutcome::Failed { code: Some(3) });
+        assert_eq!(run.output_tail, "pre_delete");
+    }
+
+    #[tokio::test]
+    async fn overrunning_command_times_out() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut ctx = ctx();
+        ctx.worktree_path = dir.path().to_path_buf();
+        let run = run_hook_command(
+            "sleep 30",
+            HookEvent::PostCreate,
+            &ctx,
+            None,
+            Duration::from_millis(200),
+        )
+        .await;
+        assert_eq!(run.outcome, HookOutcome::TimedOut);
+    }
+}
@@ -1,7 +1,10 @@
 //! Session lifecycle: create, restart, kill, and delete sessions.
 
+use super::hooks::HookContext;
 use super::*;
 use crate::agent::AgentKind;
+use crate::config::shell_quote;
+use crate::session::HookEvent;
 
 impl SessionManager {
     /// Prepare a placeholder session in `Creating` state.
@@ -125,12 +128,16 @@ impl SessionManager {
             )
         };
 
-        let (repo_path, main_branch) = {
+        let (repo_path, main_branch, project_name) = {
             let state = self.store.read().await;
             let project = state
                 .get_project(&project_id)
                 .ok_or_else(|| SessionError::ProjectNotFound(project_id.to_string()))?;
-            (project.repo_path.clone(), project.main_branch.clone())
+            (
+                project.repo_path.clone(),
+                project.main_branch.clone(),
+                project.name.clone(),
+            )
         };
 
         info!(
@@ -275,6 +282,22 @@ impl SessionManager {
                 session.tmux_session_name.clone()
             };
 
+            // `post_create` hooks run before the agent starts, so it launches
+            // into a prepared worktree (deps installed, `.env` copied). The
+            // worktree path isn't persisted yet, hence the explicit context. A
+            // blocking failure lands in the cleanup below like any other.
+            let hook_ctx = HookContext {
+                session_id: *session_id,
+                title: title.clone(),
+                branch: branch_name.clone(),
+                worktree_path: worktree_info.path.clone(),
+                project_name: project_name.clone(),
+                repo_path: repo_path.clone(),
+                ports: self.ensure_port_block(session_id).await,
+            };
+            self.run_hooks(HookEvent::PostCreate, &hook_ctx, None)
+                .await?;
+
             // Build a single positional prompt arg combining stack context (for
             // stacked sessions) and any user-provided initial prompt. Harnesses that
             // accept a positional prompt (Claude, Codex) take exactly one, so both
@@ -300,12 +323,17 @@ impl SessionManager {
                     program.clone()
                 } else {
                     let combined = prompt_parts.join("\n\n");
-                    let escaped = shell_escape_single_quote(&combined);
-                    format!("{program} '{escaped}'")
+                    format!("{program} {}", shell_quote(&combined))
                 }
             };
             let launch_cmd = program_with_session_name(&launch_cmd, &title);
             let launch_cmd = self.maybe_wrap_nix_develop(&launch_cmd, &worktree_info.path);
+            let launch_cmd = self
+                .maybe_wrap_resource_scope(&launch_cmd, &tmux_session_name, &tmux_session_name)
+                .await;
+            let launch_cmd = self
+                .maybe_with_session_env(&launch_cmd, session_id, &worktree_info.path)
+                .await;
 
             // Create tmux session in the worktree directory
             let tmux_start = std::time::Instant::now();
@@ -404,7 +432,8 @@ impl SessionManager {
         Ok(())
     }
 
-    /// Kill tmux sessions (main + shell) for a worktree session.
+    /// Kill tmux sessions (main + shell + task windows) for a worktree
+    /// session, plus the resource scopes they ran in.
     pub(super) async fn kill_tmux_sessions(&self, tmux_name: &str, shell_tmux_name: Option<&str>) {
         if let Err(e) = self.tmux.kill_session(tmux_name).await {
             warn!("Failed to kill tmux session: {}", e);
@@ -412,6 +441,12 @@ impl SessionManager {
         if let Some(shell_name) = shell_tmux_name {
             let _ = self.tmux.kill_session(shell_name).await;
         }
+        // Usually absent (no task has run); the error is expected then.
+        let _ = self
+            .tmux
+            .kill_session(&tasks::task_tmux_name(tmux_name))
+            .await;
+        self.stop_session_resource_scopes(tmux_name).await;
     }
 
     /// Restart a session (kill tmux and recreate, optionally with --resume)
@@ -430,6 +465,9 @@ impl SessionManager {
             let session = state
                 .get_session(session_id)
                 .ok_or(SessionError::NotFound(*session_id))?;
+            if session.archived.is_some() {
+                return Err(SessionError::Archived(*session_id).into());
+            }
             (
                 session.tmux_session_name.clone(),
                 session.shell_tmux_session_name.clone(),
@@ -467,6 +505,12 @@ impl SessionManager {
         let resume_program = resume_program_for(&program, force_resume);
         let resume_program = program_with_session_name(&resume_program, &title);
         let resume_program = self.maybe_wrap_nix_develop(&resume_program, &worktree_path);
+        let resume_program = self
+            .maybe_wrap_resource_scope(&resume_program, &tmux_session_name, &tmux_session_name)
+            .await;
+        let resume_program = self
+            .maybe_with_session_env(&resume_program, session_id, &worktree_path)
+            .await;
         let create_result = self
             .tmux
             .create_session(&tmux_session_name, &worktree_path, Some(&resume_program))
@@ -536,6 +580,9 @@ impl SessionManager {
                 .values()
                 .find(|s| s.tmux_session_name == tmux_name)
                 .ok_or_else(|| SessionError::TmuxSessionNotFound(tmux_name.to_string()))?;
+            if session.archived.is_some() {
+                return Err(SessionError::Archived(session.id).into());
+            }
             (
                 session.id,
                 session.worktree_path.clone(),
@@ -563,9 +610,16 @@ impl SessionManager {
             .await?;
 
         let _ = self.tmux.kill_session(tmux_name).await;
+        self.stop_resource_scopes(tmux_name).await;
 
         let launch_cmd = program_with_session_name(&program, &title);
         let launch_cmd = self.maybe_wrap_nix_develop(&launch_cmd, &worktree_path);
+        let launch_cmd = self
+            .maybe_wrap_resource_scope(&launch_cmd, tmux_name, tmux_name)
+            .await;
+        let launch_cmd = self
+            .maybe_with_session_env(&launch_cmd, &session_id, &worktree_path)
+            .await;
         let create_result = self
             .tmux
             .create_session(tmux_name, &worktree_path, Some(&launch_cmd))
@@ -649,6 +703,10 @@ impl SessionManager {
                 if let Some(session) = state.get_session_mut(&sid) {
                     session.set_status(SessionStatus::Stopped);
                     session.hibernated = !remove_worktree;
+                    if remove_worktree {
+                        // No worktree left to serve from; free the port block.
+                        session.ports = None;
+                    }
                 }
             })
             .await?;
@@ -838,6 +896,11 @@ impl SessionManager {
                 .map(|p| p.repo_path.clone())
         };
 
+        // `pre_delete` hooks run while the worktree and row still exist; under
+        // `on_failure = "block"` a failing hook leaves the session untouched.
+        self.run_session_hooks(HookEvent::PreDelete, *session_id, None)
+            .await?;
+
         // Remove from state FIRST so the tree updates immediately: this single
         // mutate bumps the change feed, and the row disappears without waiting on
         // the slow tmux-kill + `git worktree remove` below (which the kill-first
@@ -867,12 +930,21 @@ impl SessionManager {
             session.shell_tmux_session_name.as_deref(),
         )
         .await;
+        // Stash the worktree for undo while it is still on disk (tmux is already
+        // down, so nothing writes to it mid-snapshot).
+        let stash = match repo_path.as_deref() {
+            Some(repo_path) => self.stash_for_undo(&session, repo_path).await,
+            None => None,
+        };
         // Swallow (don't `?`) any teardown error: the session is already gone, so
         // propagating would report a failed delete and skip the child-PR retarget
-        // below, leaving children retargeted locally but not on GitHub.
-        if let Err(e) = self
-            .remove_session_worktree(repo_path.as_deref(), &session.worktree_path)
-            .await
+        // below, leaving children retargeted locally but not on GitHub. An
+        // archived session has no worktree; its archive ref stays until the undo
+        // entry expires.
+        if session.archived.is_none()
+            && let Err(e) = self
+                .remove_session_worktree(repo_path.as_deref(), &session.worktree_path)
+                .await
         {
             warn!("Failed to remove worktree while deleting session: {}", e);
         }
@@ -880,6 +952,18 @@ impl SessionManager {
         // Durably retarget child PRs on GitHub (best-effort, non-fatal).
         Self::retarget_child_prs(pr_retargets).await;
 
+        // Without a repo there is nothing to recreate the worktree from.
+        if let Some(repo_path) = repo_path {
+            self.record_undo(crate::config::UndoAction::DeleteSession(
+                crate::config::RemovedSession {
+                    session,
+                    repo_path,
+                    stash,
+                },
+            ))
+            .await;
+        }
+
         info!("Deleted session {}", session_id);
         Ok(())
     }
@@ -949,19 +1033,15 @@ pub(super) fn program_with_session_name(program: &str, session_title: &str) -> S
     if !AgentKind::from_program(program).is_claude() || session_title.is_empty() {
         return program.to_string();
     }
-    let escaped = shell_escape_single_quote(session_title);
+    let quoted = shell_quote(session_title);
     let mut parts = program.splitn(2, char::is_whitespace);
     let cmd = parts.next().unwrap();
     match parts.next() {
-        Some(rest) => format!("{cmd} -n '{escaped}' {rest}"),
-        None => format!("{cmd} -n '{escaped}'"),
+        Some(rest) => format!("{cmd} -n {quoted} {rest}"),
+        None => format!("{cmd} -n {quoted}"),
     }
 }
 
-pub(super) fn shell_escape_single_quote(s: &str) -> String {
-    s.replace('\'', "'\\''")
-}
-
 /// Choose the launch command when recreating a session's tmux pane: the
 /// harness's resume command when `force_resume` is set, otherwise the program
 /// launched fresh. Resume syntax is harness-specific; an unrecognised program
@@ -23,17 +23,24 @@ pub struct ScanResult {
     pub skipped: usize,
 }
 
+mod archive;
 mod cascade;
 mod content;
 mod hibernate;
+mod hooks;
 mod lifecycle;
 mod nix;
+mod ports;
 mod project_shell;
 mod projects;
+mod resources;
 mod shell;
+mod tasks;
+mod undo;
 mod worktree_sync;
 
 pub use cascade::{CascadeOutcome, PushStackOutcome};
+pub(crate) use hooks::Transition as HookTransition;
 pub use lifecycle::program_with_agent_flags;
 pub(crate) use projects::repo_identity;
 
@@ -56,6 +63,10 @@ pub struct SessionManager {
     project_diff_cache: DiffCache<ProjectId>,
     /// Tmux status-style string derived from theme
     tmux_status_style: String,
+    /// Latest bulk agent-state snapshot. The service's poll loop maintains it
+    /// (it is the same `Arc` as `CommanderService`'s cache); the manager reads
+    /// it so section reassignment it triggers sees the polled agent states.
+    pub(crate) agent_states: Arc<tokio::sync::RwLock<crate::api::AgentStatesSnapshot>>,
 }
 
 impl Clone for SessionManager {
@@ -68,6 +79,7 @@ impl Clone for SessionManager {
             diff_cache: self.diff_cache.clone(),
             project_diff_cache: self.project_diff_cache.clone(),
             tmux_status_style: self.tmux_status_style.clone(),
+            agent_states: self.agent_states.clone(),
         }
     }
 }
@@ -105,6 +117,11 @@ impl SessionManager {
             diff_cache,
             project_diff_cache,
             tmux_status_style: tmux_status_style.into(),
+            agent_states: Arc::new(tokio::sync::RwLock::new(crate::api::AgentStatesSnapshot {
+                states: Default::default(),
+                commander_running: false,
+                viewers: Default::default(),
+            })),
         }
     }
 
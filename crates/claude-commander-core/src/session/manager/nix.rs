@@ -6,6 +6,7 @@ use std::ffi::OsStr;
 use std::sync::{Mutex, OnceLock};
 
 use super::*;
+use crate::config::shell_quote;
 
 /// Wrap a shell command string so it runs inside the Nix dev shell of the
 /// pane's working directory (`nix develop`'s flake ref defaults to `.`).
@@ -14,8 +15,8 @@ use super::*;
 /// an argv list, so it must go through `sh -c` as a single argument. `exec`
 /// replaces that `sh` with the program, avoiding an extra process layer.
 pub(super) fn wrap_in_nix_develop(cmd: &str) -> String {
-    let escaped = lifecycle::shell_escape_single_quote(&format!("exec {cmd}"));
-    format!("nix develop --command sh -c '{escaped}'")
+    let quoted = shell_quote(&format!("exec {cmd}"));
+    format!("nix develop --command sh -c {quoted}")
 }
 
 /// Whether a `nix` executable exists in any directory of the given PATH value.
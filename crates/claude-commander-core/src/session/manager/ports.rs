@@ -0,0 +1,483 @@
+//! Per-session port blocks
+//!
+//! With `[ports] enabled`, every session holds a block of TCP ports no other
+//! session holds, so several checkouts of the same web app can each run a dev
+//! server. The block is persisted on the session (`WorktreeSession::ports`)
+//! and exported to its panes as `PORT` / `COMMANDER_PORT_<n>` — as env
+//! assignments prefixed to the launch command — and written to a dotenv
+//! overlay in the worktree for tools that read one. Deleting the session
+//! releases the block: allocation only avoids blocks held by sessions still in
+//! state.
+
+use std::collections::BTreeMap;
+use std::net::{Ipv4Addr, TcpListener};
+
+use tokio::process::Command;
+
+use super::*;
+use crate::config::shell_quote;
+use crate::session::PortBlock;
+
+/// The `PORT` / `COMMANDER_PORT_<n>` variables for a block.
+pub(super) fn port_vars(block: &PortBlock) -> Vec<(String, String)> {
+    let mut vars = vec![("PORT".to_string(), block.start.to_string())];
+    vars.extend(
+        block
+            .ports()
+            .enumerate()
+            .map(|(i, port)| (format!("COMMANDER_PORT_{i}"), port.to_string())),
+    );
+    vars
+}
+
+/// Expand `{port}`, `{port_<n>}`, `{session_id}` and `{branch}` in a
+/// configured `[ports.env]` value. Placeholders are matched as whole
+/// `{...}` tokens in a single pass, so `{port_1}` never matches inside
+/// `{port_10}` and a substituted value (a branch named `{port}`, say) is not
+/// expanded again. Unknown placeholders, including a `{port_<n>}` past the end
+/// of the block, are kept verbatim.
+fn expand_env_template(
+    template: &str,
+    block: &PortBlock,
+    session_id: &SessionId,
+    branch: &str,
+) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open..];
+        // A token ends at the first `}`; another `{` first means this one
+        // isn't a placeholder, so it is copied and scanning resumes after it.
+        let close = match after[1..].find(['{', '}']) {
+            Some(i) if after.as_bytes()[i + 1] == b'}' => i + 1,
+            _ => {
+                out.push('{');
+                rest = &after[1..];
+                continue;
+            }
+        };
+        let value = match &after[1..close] {
+            "port" => Some(block.start.to_string()),
+            "session_id" => Some(session_id.as_uuid().to_string()),
+            "branch" => Some(branch.to_string()),
+            name => name
+                .strip_prefix("port_")
+                .filter(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|n| n.parse::<usize>().ok())
+                .and_then(|n| block.ports().nth(n))
+                .map(|port| port.to_string()),
+        };
+        out.push_str(value.as_deref().unwrap_or(&after[..=close]));
+        rest = &after[close + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Keys become unquoted `KEY=` assignments on the launch line, so only a
+/// portable shell variable name is accepted: a leading letter or `_`, then
+/// letters, digits and `_`.
+fn is_valid_env_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Every variable a session's panes get: the port variables, then the
+/// configured extras (which may override them, e.g. a different `PORT`).
+/// Extras whose key isn't a valid variable name are dropped with a warning.
+fn session_env(
+    block: &PortBlock,
+    extra: &BTreeMap<String, String>,
+    session_id: &SessionId,
+    branch: &str,
+) -> Vec<(String, String)> {
+    let mut vars = port_vars(block);
+    for (key, template) in extra {
+        if !is_valid_env_key(key) {
+            warn!("Ignoring [ports.env] key `{key}`: names must be letters, digits or `_`");
+            continue;
+        }
+        let value = expand_env_template(template, block, session_id, branch);
+        match vars.iter_mut().find(|(k, _)| k == key) {
+            Some(existing) => existing.1 = value,
+            None => vars.push((key.clone(), value)),
+        }
+    }
+    vars
+}
+
+/// Prefix `cmd` with `KEY='value'` assignments so the launched program (and
+/// everything under it — `nix develop`, a systemd scope and the agent all pass
+/// their environment down) sees them.
+fn with_env_prefix(cmd: &str, vars: &[(String, String)]) -> String {
+    if vars.is_empty() {
+        return cmd.to_string();
+    }
+    let assignments: Vec<String> = vars
+        .iter()
+        .map(|(k, v)| format!("{k}={}", shell_quote(v)))
+        .collect();
+    format!("{} {cmd}", assignments.join(" "))
+}
+
+/// Dotenv rendering of the variables, quoted so values with spaces survive.
+fn overlay_contents(vars: &[(String, String)]) -> String {
+    let mut out = String::from(
+        "# Written by claude-commander for this session. Regenerated on every launch.\n",
+    );
+    for (k, v) in vars {
+        let escaped = v.replace('\\', "\\\\").replace('"', "\\\"");
+        out.push_str(&format!("{k}=\"{escaped}\"\n"));
+    }
+    out
+}
+
+/// The lowest `size`-port block in `range_start..=range_end` that overlaps no
+/// block in `taken` and whose every port `is_free` accepts. Blocks are aligned
+/// to `size` from `range_start`, so a session's ports are predictable from its
+/// slot.
+fn next_port_block(
+    taken: &[PortBlock],
+    range_start: u16,
+    range_end: u16,
+    size: u16,
+    is_free: impl Fn(u16) -> bool,
+) -> Option<PortBlock> {
+    if size == 0 || range_end < range_start {
+        return None;
+    }
+    let mut start = u32::from(range_start);
+    while start + u32::from(size) - 1 <= u32::from(range_end) {
+        let candidate = PortBlock {
+            start: start as u16,
+            count: size,
+        };
+        if !taken.iter().any(|t| t.overlaps(&candidate)) && candidate.ports().all(&is_free) {
+            return Some(candidate);
+        }
+        start += u32::from(size);
+    }
+    None
+}
+
+/// Times [`SessionManager::ensure_port_block`] probes again after another
+/// session claimed its candidate block first.
+const CLAIM_ATTEMPTS: usize = 3;
+
+/// Whether nothing is listening on `port` on the loopback interface. A quick
+/// bind probe: it can race a process that binds a moment later, which is why
+/// the block is persisted rather than re-probed on every launch.
+fn port_is_free(port: u16) -> bool {
+    TcpListener::bind((Ipv4Addr::LOCALHOST, port)).is_ok()
+}
+
+impl SessionManager {
+    /// The session's port block, allocating one if ports are enabled and it
+    /// has none yet (a new session, or one that predates the setting).
+    /// `None` when ports are disabled or the range is exhausted.
+    pub(super) async fn ensure_port_block(&self, session_id: &SessionId) -> Option<PortBlock> {
+        let config = self.config_store.read().ports.clone();
+        if !config.enabled {
+            return None;
+        }
+        for _ in 0..CLAIM_ATTEMPTS {
+            let taken: Vec<PortBlock> = {
+                let state = self.store.read().await;
+                // Common case: already allocated. Skip the probe and the write.
+                if let Some(block) = state.get_session(session_id)?.ports {
+                    return Some(block);
+                }
+                state.sessions.values().filter_map(|s| s.ports).collect()
+            };
+            // Probe outside the store lock: bind attempts are blocking I/O and
+            // would stall every other state write while a range is scanned.
+            let (range_start, range_end, size) =
+                (config.range_start, config.range_end, config.block_size);
+            let candidate = tokio::task::spawn_blocking(move || {
+                next_port_block(&taken, range_start, range_end, size, port_is_free)
+            })
+            .await
+            .ok()
+            .flatten();
+            let Some(candidate) = candidate else {
+                warn!("No free port block left for session {session_id}");
+                return None;
+            };
+            let sid = *session_id;
+            // Re-check inside the mutate so two concurrent creates can't both
+            // claim the same free block; the loser probes again.
+            let result = self
+                .store
+                .mutate(move |state| {
+                    let current = state.get_session(&sid)?.ports;
+                    if current.is_some() {
+                        return current;
+                    }
+                    if state
+                        .sessions
+                        .values()
+                        .filter_map(|s| s.ports)
+                        .any(|t| t.overlaps(&candidate))
+                    {
+                        return None;
+                    }
+                    state.get_session_mut(&sid)?.ports = Some(candidate);
+                    Some(candidate)
+                })
+                .await;
+            match result {
+                Ok(Some(block)) => return Some(block),
+                // Claimed by another session meanwhile (or this one is gone,
+                // which the next read notices).
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("Failed to record port block for {session_id}: {e}");
+                    return None;
+                }
+            }
+        }
+        warn!("Port block for session {session_id} kept being claimed by other sessions");
+        None
+    }
+
+    /// Prefix `cmd` with the session's port (and `[ports.env]`) variables and
+    /// refresh its dotenv overlay. Applied as the outermost layer of a pane's
+    /// launch command; returns `cmd` unchanged when ports are disabled.
+    /// The worktree is passed in because `finalize_session` launches before it
+    /// persists the session's worktree path.
+    pub(super) async fn maybe_with_session_env(
+        &self,
+        cmd: &str,
+        session_id: &SessionId,
+        worktree: &Path,
+    ) -> String {
+        let Some(block) = self.ensure_port_block(session_id).await else {
+            return cmd.to_string();
+        };
+        let branch = {
+            let state = self.store.read().await;
+            match state.get_session(session_id) {
+                Some(s) => s.branch.clone(),
+                None => return cmd.to_string(),
+            }
+        };
+        let config = self.config_store.read().ports.clone();
+        let vars = session_env(&block, &config.env, session_id, &branch);
+        if !config.env_file.is_empty() {
+            write_env_overlay(worktree, &config.env_file, &vars).await;
+        }
+        with_env_prefix(cmd, &vars)
+    }
+}
+
+/// Write the dotenv overlay into the worktree and make sure git ignores it.
+/// Failures are logged: the env prefix already carries the same variables.
+async fn write_env_overlay(worktree: &Path, file_name: &str, vars: &[(String, String)]) {
+    if !worktree.is_dir() {
+        return;
+    }
+    if let Err(e) = tokio::fs::write(worktree.join(file_name), overlay_contents(vars)).await {
+        warn!("Failed to write {file_name} in {}: {e}", worktree.display());
+        return;
+    }
+    exclude_from_git(worktree, file_name).await;
+}
+
+/// Add `/<file_name>` to the repository's `info/exclude` (shared by all its
+/// worktrees) unless it is already listed, so the overlay never shows up as
+/// an untracked file for the agent to commit.
+async fn exclude_from_git(worktree: &Path, file_name: &str) {
+    let output = Command::new("git")
+        .args(["rev-parse", "--git-path", "info/exclude"])
+        .current_dir(worktree)
+        .output()
+        .await;
+    let Ok(output) = output else { return };
+    if !output.status.success() {
+        return;
+    }
+    let rel = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let path = worktree.join(rel);
+    let pattern = format!("/{file_name}");
+    let existing = tokio::fs::read_to_string(&path).await.unwrap_or_default();
+    if existing.lines().any(|l| l.trim() == pattern) {
+        return;
+    }
+    let mut contents = existing;
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    contents.push_str(&pattern);
+    contents.push('\n');
+    if let Some(parent) = path.parent() {
+        let _ = tokio::fs::create_dir_all(parent).await;
+    }
+    if let Err(e) = tokio::fs::write(&path, contents).await {
+        debug!("Failed to update {}: {e}", path.display());
+    }
+}
+
+#[cfg(test)]
+mod ports_tests {
+    use super::*;
+
+    fn block(start: u16, count: u16) -> PortBlock {
+        PortBlock { start, count }
+    }
+
+    #[test]
+    fn next_block_skips_taken_and_busy_ports() {
+        // First slot held by another session.
+        let taken = [block(20000, 5)];
+        assert_eq!(
+            next_port_block(&taken, 20000, 20099, 5, |_| true),
+            Some(block(20005, 5))
+        );
+        // Second slot free of sessions but a port in it is bound on the host.
+        assert_eq!(
+            next_port_block(&taken, 20000, 20099, 5, |p| p != 20007),
+            Some(block(20010, 5))
+        );
+    }
+
+    #[test]
+    fn next_block_respects_range_end_and_degenerate_config() {
+        assert_eq!(next_port_block(&[], 20000, 20003, 5, |_| true), None);
+        assert_eq!(
+            next_port_block(&[], 20000, 20004, 5, |_| true),
+            Some(block(20000, 5))
+        );
+        assert_eq!(next_port_block(&[], 20000, 20099, 0, |_| true), None);
+        assert_eq!(
+            next_port_block(&[], 65534, 65535, 2, |_| true),
+            Some(block(65534, 2))
+        );
+    }
+
+    #[tokio::test]
+    async fn concurrent_claims_get_disjoint_blocks() {
+        use crate::config::{AppState, Config, ConfigStore, StateStore};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.projects_dir = Some(dir.path().join("projects"));
+        config.ports.enabled = true;
+        config.ports.range_start = 47000;
+        config.ports.range_end = 47099;
+        config.ports.block_size = 5;
+        let config_store = Arc::new(ConfigStore::with_path(
+            config,
+            dir.path().join("config.toml"),
+        ));
+        let store = Arc::new(StateStore::with_path(
+            AppState::new(),
+            dir.path().join("state.json"),
+        ));
+        let manager = SessionManager::new(config_store, store.clone(), "");
+
+        let mut ids = Vec::new();
+        for name in ["a", "b"] {
+            let session = WorktreeSession::new(
+                ProjectId::new(),
+                name,
+                name,
+                dir.path().join(name),
+                "claude",
+            );
+            ids.push(session.id);
+            store
+                .mutate(move |state| {
+                    state.add_session(session);
+                })
+                .await
+                .unwrap();
+        }
+
+        let (a, b) = tokio::join!(
+            manager.ensure_port_block(&ids[0]),
+            manager.ensure_port_block(&ids[1])
+        );
+        let (a, b) = (a.unwrap(), b.unwrap());
+        assert!(!a.overlaps(&b), "{a:?} and {b:?} overlap");
+        // Once held, the block is returned as is.
+        assert_eq!(manager.ensure_port_block(&ids[0]).await, Some(a));
+    }
+
+    #[test]
+    fn session_env_expands_templates_and_lets_extras_override() {
+        let sid = SessionId::new();
+        let extra = BTreeMap::from([
+            (
+                "API_URL".to_string(),
+                "http://localhost:{port_1}".to_string(),
+            ),
+            ("PORT".to_string(), "{port_2}".to_string()),
+        ]);
+        let vars = session_env(&block(3000, 3), &extra, &sid, "feat");
+        assert!(vars.contains(&("PORT".to_string(), "3002".to_string())));
+        assert!(vars.contains(&("COMMANDER_PORT_0".to_string(), "3000".to_string())));
+        assert!(vars.contains(&("API_URL".to_string(), "http://localhost:3001".to_string())));
+        assert_eq!(vars.iter().filter(|(k, _)| k == "PORT").count(), 1);
+    }
+
+    #[test]
+    fn env_keys_must_be_shell_variable_names() {
+        assert!(is_valid_env_key("DATABASE_URL"));
+        assert!(is_valid_env_key("_x1"));
+        assert!(!is_valid_env_key(""));
+        assert!(!is_valid_env_key("1X"));
+        assert!(!is_valid_env_key("A-B"));
+    }
+
+    #[test]
+    fn hostile_env_key_never_reaches_the_launch_line() {
+        let extra = BTreeMap::from([
+            ("X=1; touch /tmp/pwned;".to_string(), "v".to_string()),
+            ("OK".to_string(), "v".to_string()),
+        ]);
+        let vars = session_env(&block(3000, 1), &extra, &SessionId::new(), "feat");
+        assert!(vars.iter().all(|(k, _)| is_valid_env_key(k)));
+        assert!(vars.contains(&("OK".to_string(), "v".to_string())));
+        let line = with_env_prefix("claude", &vars);
+        assert!(!line.contains("pwned"), "{line}");
+    }
+
+    #[test]
+    fn port_10_template_is_not_clobbered_by_port_1() {
+        let out = expand_env_template(
+            "{port_1}/{port_10}/{port_11}/{port_0}",
+            &block(100, 11),
+            &SessionId::new(),
+            "",
+        );
+        assert_eq!(out, "101/110/{port_11}/100");
+    }
+
+    #[test]
+    fn substituted_values_are_not_expanded_again() {
+        let out = expand_env_template(
+            "{branch}:{{port}:{port",
+            &block(100, 2),
+            &SessionId::new(),
+            "{port}",
+        );
+        assert_eq!(out, "{port}:{100:{port");
+    }
+
+    #[test]
+    fn env_prefix_quotes_values() {
+        let vars = vec![("A".to_string(), "it's".to_string())];
+        assert_eq!(with_env_prefix("claude", &vars), "A='it'\\''s' claude");
+        assert_eq!(with_env_prefix("claude", &[]), "claude");
+    }
+
+    #[test]
+    fn overlay_is_dotenv_quoted() {
+        let vars = vec![("X".to_string(), "a \"b\"".to_string())];
+        assert!(overlay_contents(&vars).ends_with("X=\"a \\\"b\\\"\"\n"));
+    }
+}
@@ -1,6 +1,7 @@
 //! Project lifecycle: add and remove git repositories.
 
 use super::*;
+use crate::session::HookEvent;
 
 /// Canonicalize `path`, keeping it unchanged when it can't be resolved (it may
 /// not exist yet, or sit behind a permission the caller will fail on anyway).
@@ -182,16 +183,44 @@ impl SessionManager {
             let _ = self.tmux.kill_session(shell_name).await;
         }
 
-        // Tear down each session: kill its tmux (a no-op when stopped) AND
-        // remove its git worktree. `kill_session(_, true)` does both uniformly
-        // regardless of status — removing only tmux here leaked the worktrees.
+        // Tear down each session: kill its tmux (a no-op when stopped), stash
+        // its worktree for undo, then remove the worktree — removing only tmux
+        // here leaked the worktrees.
+        let mut removed = Vec::new();
         for session_id in &project.worktrees {
-            if let Err(e) = self.kill_session(session_id, true).await {
+            // A project removal can't be refused one session at a time, so a
+            // blocking `pre_delete` hook only warns here.
+            if let Err(e) = self
+                .run_session_hooks(HookEvent::PreDelete, *session_id, None)
+                .await
+            {
+                warn!("{e}");
+            }
+            if let Err(e) = self.kill_session(session_id, false).await {
                 warn!(
                     "Failed to tear down session {} while removing project: {}",
                     session_id, e
                 );
             }
+            let Some(session) = self.store.read().await.get_session(session_id).cloned() else {
+                continue;
+            };
+            let stash = self.stash_for_undo(&session, &project.repo_path).await;
+            if session.archived.is_none()
+                && let Err(e) = self
+                    .remove_session_worktree(Some(&project.repo_path), &session.worktree_path)
+                    .await
+            {
+                warn!(
+                    "Failed to remove worktree of session {} while removing project: {}",
+                    session_id, e
+                );
+            }
+            removed.push(crate::config::RemovedSession {
+                session,
+                repo_path: project.repo_path.clone(),
+                stash,
+            });
         }
 
         // Remove project from state (also removes sessions)
@@ -202,6 +231,12 @@ impl SessionManager {
             })
             .await?;
 
+        self.record_undo(crate::config::UndoAction::RemoveProject {
+            project,
+            sessions: removed,
+        })
+        .await;
+
         info!("Removed project {}", project_id);
         Ok(())
     }
@@ -0,0 +1,682 @@
+//! Per-session resource limits and usage.
+//!
+//! With `resource_limits.enabled`, every pane a session launches — the agent,
+//! its shell and each task window — runs through `systemd-run --user --scope`,
+//! so the command and everything it spawns share a cgroup. Each launch gets its
+//! own scope, and all of a session's scopes sit in one per-session slice that
+//! carries the configured CPU / memory / task ceilings, so the limits cap the
+//! session as a whole rather than each pane. Usage (RSS and CPU time) is
+//! sampled from those cgroups where a pane runs in one, and otherwise summed
+//! over the pane's process tree from `/proc`, then totalled over the session's
+//! panes — so the Info view, `status --json` and the memory-pressure
+//! hibernation pass get numbers whether or not limits are on.
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use super::*;
+use crate::api::ResourceUsage;
+use crate::config::ResourceLimitsConfig;
+use crate::config::shell_quote;
+
+/// Prefix of every scope and slice unit we create. Also how a pane's cgroup is
+/// recognised as one of ours when sampling usage.
+const SCOPE_PREFIX: &str = "claude-commander-";
+
+/// Length of the random suffix [`scope_unit_name`] is given, so a stop pattern
+/// can match one launch name's scopes exactly (see [`scope_stop_pattern`]).
+const SCOPE_SUFFIX_LEN: usize = 8;
+
+/// Clock ticks per second for `/proc/<pid>/stat` CPU fields. The kernel
+/// reports these in `USER_HZ`, which is 100 on every architecture Linux ships.
+const USER_HZ: u64 = 100;
+
+/// Wall time between the two readings [`SessionManager::session_resource_usage`]
+/// takes when it has no recent sample to measure CPU against. Long enough
+/// that the 10ms tick granularity of `USER_HZ` stays within a couple of
+/// percent.
+const CPU_SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Oldest previous sample a CPU figure is still measured against. Past this
+/// the delta would be an average over minutes rather than current usage.
+const CPU_SAMPLE_MAX_AGE: Duration = Duration::from_secs(30);
+
+/// Build a scope unit name for a launch of `tmux_name`. The random suffix
+/// keeps a restart from colliding with a previous scope that is still being
+/// torn down (a straggler that ignored SIGHUP keeps it alive). Characters
+/// systemd rejects in unit names are replaced with `_`.
+pub(super) fn scope_unit_name(tmux_name: &str, suffix: &str) -> String {
+    let sanitized: String = tmux_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{SCOPE_PREFIX}{sanitized}-{suffix}")
+}
+
+/// The slice every scope of the session `tmux_name` is launched into, which
+/// carries the session's limits. A `-` in a slice name starts a nested slice,
+/// so the tmux name is escaped the way `systemd-escape` does it; otherwise
+/// `cc-x-y`'s slice would sit inside `cc-x`'s, sharing its ceilings and being
+/// stopped along with it.
+pub(super) fn slice_unit_name(tmux_name: &str) -> String {
+    let mut escaped = String::new();
+    for (i, b) in tmux_name.bytes().enumerate() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'_' | b':') || (b == b'.' && i > 0) {
+            escaped.push(b as char);
+        } else {
+            escaped.push_str(&format!("\\x{b:02x}"));
+        }
+    }
+    format!("{SCOPE_PREFIX}{escaped}.slice")
+}
+
+/// `systemctl stop` glob for every scope [`scope_unit_name`] made for
+/// `launch_name`. The suffix is matched as exactly [`SCOPE_SUFFIX_LEN`]
+/// characters, so stopping the agent's scopes (`cc-x-????????`) leaves the
+/// shell's (`cc-x-sh-…`) and the task windows' (`cc-x-tasks-…`) alone.
+pub(super) fn scope_stop_pattern(launch_name: &str) -> String {
+    format!(
+        "{}{}.scope",
+        scope_unit_name(launch_name, ""),
+        "?".repeat(SCOPE_SUFFIX_LEN)
+    )
+}
+
+/// Launch name of a task window, for its scope: the task tmux session plus the
+/// window (task) name.
+pub(super) fn task_scope_name(tmux_name: &str, task: &str) -> String {
+    format!("{}-{task}", tasks::task_tmux_name(tmux_name))
+}
+
+/// `set-property` assignments putting the configured limits on a session's
+/// slice, in a stable order. Every limit is assigned, an unset one to "no
+/// limit", so a ceiling dropped from the config is lifted at the session's
+/// next launch instead of lingering on the slice.
+pub(super) fn slice_properties(limits: &ResourceLimitsConfig) -> Vec<String> {
+    let cpu = limits
+        .cpu_quota_percent
+        .map(|cpu| format!("{cpu}%"))
+        .unwrap_or_default();
+    let mem = limits.memory_max.as_deref().unwrap_or("infinity");
+    let pids = limits
+        .pids_max
+        .map_or_else(|| "infinity".to_string(), |pids| pids.to_string());
+    vec![
+        format!("CPUQuota={cpu}"),
+        format!("MemoryMax={mem}"),
+        format!("TasksMax={pids}"),
+    ]
+}
+
+/// Wrap a shell command string so it runs inside a transient systemd scope
+/// named `unit`, placed in `slice`. The scope itself carries no limits; the
+/// slice's apply. Like `wrap_in_nix_develop`, the command is a full shell
+/// string, so it goes through `sh -c` with `exec`.
+pub(super) fn wrap_in_resource_scope(cmd: &str, unit: &str, slice: &str) -> String {
+    let quoted = shell_quote(&format!("exec {cmd}"));
+    format!(
+        "systemd-run --user --scope --quiet --collect --unit={unit} --slice='{slice}' \
+         sh -c {quoted}"
+    )
+}
+
+/// Whether `systemd-run` is on the given PATH and a systemd user manager is
+/// reachable through the given `XDG_RUNTIME_DIR`.
+fn systemd_user_scope_possible(path_var: Option<&OsStr>, runtime_dir: Option<&OsStr>) -> bool {
+    let has_binary = path_var
+        .is_some_and(|paths| std::env::split_paths(paths).any(|d| d.join("systemd-run").is_file()));
+    let has_manager =
+        runtime_dir.is_some_and(|dir| Path::new(dir).join("systemd").join("private").exists());
+    has_binary && has_manager
+}
+
+/// Cached [`systemd_user_scope_possible`] for the current process environment.
+fn systemd_scopes_available() -> bool {
+    static AVAILABLE: OnceLock<bool> = OnceLock::new();
+    *AVAILABLE.get_or_init(|| {
+        systemd_user_scope_possible(
+            std::env::var_os("PATH").as_deref(),
+            std::env::var_os("XDG_RUNTIME_DIR").as_deref(),
+        )
+    })
+}
+
+/// The cgroup v2 path from `/proc/<pid>/cgroup` (the `0::` line).
+fn parse_cgroup_v2_path(contents: &str) -> Option<&str> {
+    contents.lines().find_map(|l| l.strip_prefix("0::"))
+}
+
+/// Whether a cgroup path is one of our per-session scopes.
+fn is_commander_scope(cgroup_path: &str) -> bool {
+    cgroup_path
+        .rsplit('/')
+        .next()
+        .is_some_and(|leaf| leaf.starts_with(SCOPE_PREFIX) && leaf.ends_with(".scope"))
+}
+
+/// `usage_usec` from a cgroup's `cpu.stat`.
+fn parse_cpu_stat_usage_usec(contents: &str) -> Option<u64> {
+    contents
+        .lines()
+        .find_map(|l| l.strip_prefix("usage_usec "))
+        .and_then(|v| v.trim().parse().ok())
+}
+
+/// `VmRSS` in bytes from `/proc/<pid>/status`. Kernel threads have none.
+fn parse_status_rss_bytes(contents: &str) -> Option<u64> {
+    let kb: u64 = contents
+        .lines()
+        .find_map(|l| l.strip_prefix("VmRSS:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kb * 1024)
+}
+
+/// `(ppid, utime + stime ticks)` from `/proc/<pid>/stat`. The command name is
+/// parenthesised and may itself contain spaces or parens, so fields are
+/// counted from the *last* `)`.
+fn parse_stat_ppid_and_ticks(contents: &str) -> Option<(u32, u64)> {
+    let rest = &contents[contents.rfind(')')? + 1..];
+    let fields: Vec<&str> = rest.split_whitespace().collect();
+    // After the comm: state(0) ppid(1) ... utime(11) stime(12).
+    let ppid = fields.get(1)?.parse().ok()?;
+    let utime: u64 = fields.get(11)?.parse().ok()?;
+    let stime: u64 = fields.get(12)?.parse().ok()?;
+    Some((ppid, utime + stime))
+}
+
+/// `root` plus every transitive child of it, given a pid → ppid map.
+fn process_tree(root: u32, parents: &HashMap<u32, u32>) -> Vec<u32> {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (&pid, &ppid) in parents {
+        children.entry(ppid).or_default().push(pid);
+    }
+    let mut tree = vec![root];
+    let mut i = 0;
+    while i < tree.len() {
+        if let Some(kids) = children.get(&tree[i]) {
+            tree.extend(kids);
+        }
+        i += 1;
+    }
+    tree
+}
+
+/// CPU utilisation (percent of one core) between two cumulative samples.
+/// `None` without a previous sample or when no time has passed.
+fn cpu_percent(prev: Option<(Instant, u64)>, now: Instant, cpu_time_ms: u64) -> Option<f32> {
+    let (then, prev_ms) = prev?;
+    let wall_ms = now.duration_since(then).as_millis() as f64;
+    if wall_ms <= 0.0 {
+        return None;
+    }
+    let used = cpu_time_ms.saturating_sub(prev_ms) as f64;
+    Some((used / wall_ms * 100.0) as f32)
+}
+
+/// `prev` if it is recent enough to measure current CPU usage against; see
+/// [`CPU_SAMPLE_MAX_AGE`].
+fn recent_sample(prev: Option<(Instant, u64)>, now: Instant) -> Option<(Instant, u64)> {
+    prev.filter(|(then, _)| now.duration_since(*then) <= CPU_SAMPLE_MAX_AGE)
+}
+
+/// `(MemTotal, MemAvailable)` in bytes from `/proc/meminfo`.
+fn parse_meminfo(contents: &str) -> Option<(u64, u64)> {
+    let field = |name: &str| -> Option<u64> {
+        let kb: u64 = contents
+            .lines()
+            .find_map(|l| l.strip_prefix(name))?
+            .trim()
+            .trim_end_matches("kB")
+            .trim()
+            .parse()
+            .ok()?;
+        Some(kb * 1024)
+    };
+    Some((field("MemTotal:")?, field("MemAvailable:")?))
+}
+
+/// Usage from the pane process's own cgroup, if it is one of our scopes.
+fn read_scope_usage(pid: u32) -> Option<(u64, u64)> {
+    let cgroup = std::fs::read_to_string(format!("/proc/{pid}/cgroup")).ok()?;
+    let path = parse_cgroup_v2_path(&cgroup)?;
+    if !is_commander_scope(path) {
+        return None;
+    }
+    let dir = Path::new("/sys/fs/cgroup").join(path.trim_start_matches('/'));
+    let rss = std::fs::read_to_string(dir.join("memory.current"))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    let usec = parse_cpu_stat_usage_usec(&std::fs::read_to_string(dir.join("cpu.stat")).ok()?)?;
+    Some((rss, usec / 1000))
+}
+
+/// Usage summed over the process trees of `roots`, from one `/proc` scan.
+/// `None` when none of the roots is alive. Processes can exit between the
+/// directory scan and the reads; those are simply skipped.
+fn read_tree_usage(roots: &[u32]) -> Option<(u64, u64)> {
+    let mut parents = HashMap::new();
+    let mut ticks = HashMap::new();
+    for entry in std::fs::read_dir("/proc").ok()?.flatten() {
+        let Some(pid) = entry
+            .file_name()
+            .to_str()
+            .and_then(|n| n.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        if let Some((ppid, t)) = std::fs::read_to_string(entry.path().join("stat"))
+            .ok()
+            .and_then(|s| parse_stat_ppid_and_ticks(&s))
+        {
+            parents.insert(pid, ppid);
+            ticks.insert(pid, t);
+        }
+    }
+    let live: Vec<u32> = roots
+        .iter()
+        .copied()
+        .filter(|root| parents.contains_key(root))
+        .collect();
+    if live.is_empty() {
+        return None;
+    }
+    let mut pids: Vec<u32> = live
+        .into_iter()
+        .flat_map(|root| process_tree(root, &parents))
+        .collect();
+    pids.sort_unstable();
+    pids.dedup();
+    let mut rss = 0;
+    let mut total_ticks = 0;
+    for pid in pids {
+        total_ticks += ticks.get(&pid).copied().unwrap_or(0);
+        rss += std::fs::read_to_string(format!("/proc/{pid}/status"))
+            .ok()
+            .and_then(|s| parse_status_rss_bytes(&s))
+            .unwrap_or(0);
+    }
+    Some((rss, total_ticks * 1000 / USER_HZ))
+}
+
+/// Usage of a session's panes, given their pids: each pane in one of our
+/// scopes reads its cgroup, the rest are walked as process trees. Returns
+/// `(rss_bytes, cpu_time_ms, scoped)`, `scoped` only when every pane was.
+fn read_panes_usage(pids: &[u32]) -> Option<(u64, u64, bool)> {
+    let mut rss = 0;
+    let mut cpu_ms = 0;
+    let mut unscoped = Vec::new();
+    let mut any = false;
+    for &pid in pids {
+        match read_scope_usage(pid) {
+            Some((r, c)) => {
+                rss += r;
+                cpu_ms += c;
+                any = true;
+            }
+            None => unscoped.push(pid),
+        }
+    }
+    if !unscoped.is_empty()
+        && let Some((r, c)) = read_tree_usage(&unscoped)
+    {
+        rss += r;
+        cpu_ms += c;
+        any = true;
+    }
+    any.then_some((rss, cpu_ms, unscoped.is_empty()))
+}
+
+/// Previous cumulative CPU sample per tmux session, for the utilisation delta
+/// of [`SessionManager::session_resource_usage`]. Only that reporting path
+/// records samples; the memory-pressure pass reads RSS alone, so it never
+/// shortens the window the next report's CPU figure is measured over.
+fn cpu_samples() -> &'static Mutex<HashMap<String, (Instant, u64)>> {
+    static SAMPLES: OnceLock<Mutex<HashMap<String, (Instant, u64)>>> = OnceLock::new();
+    SAMPLES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Host `(MemTotal, MemAvailable)` in bytes, or `None` off Linux.
+pub(super) fn host_memory() -> Option<(u64, u64)> {
+    parse_meminfo(&std::fs::read_to_string("/proc/meminfo").ok()?)
+}
+
+impl SessionManager {
+    /// Wrap `cmd` in a systemd scope inside the session's slice when
+    /// `resource_limits.enabled` is set and a systemd user manager is
+    /// available, first (re)applying the configured limits to the slice;
+    /// otherwise return it unchanged. `tmux_name` is the session's agent tmux
+    /// session, naming the slice; `launch_name` names the scope: the pane's
+    /// tmux session, or [`task_scope_name`] for a task window. Applied outside
+    /// `maybe_wrap_nix_develop`, so the dev-shell evaluation is limited too.
+    pub(super) async fn maybe_wrap_resource_scope(
+        &self,
+        cmd: &str,
+        tmux_name: &str,
+        launch_name: &str,
+    ) -> String {
+        let limits = self.config_store.read().resource_limits.clone();
+        if !limits.enabled {
+            return cmd.to_string();
+        }
+        if !systemd_scopes_available() {
+            debug!("resource_limits enabled but no systemd user manager; launching unscoped");
+            return cmd.to_string();
+        }
+        let slice = slice_unit_name(tmux_name);
+        let mut args = vec!["set-property", "--runtime", slice.as_str()];
+        let props = slice_properties(&limits);
+        args.extend(props.iter().map(String::as_str));
+        self.systemctl(&args).await;
+
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+        let unit = scope_unit_name(launch_name, &suffix[..SCOPE_SUFFIX_LEN]);
+        info!(
+            "Launching {} inside scope {} of {}",
+            launch_name, unit, slice
+        );
+        wrap_in_resource_scope(cmd, &unit, &slice)
+    }
+
+    /// Stop any scope left behind by a previous launch of `launch_name`,
+    /// taking down stragglers (backgrounded dev servers, watchers) that
+    /// survived the tmux kill. Best-effort and a no-op when limits are off.
+    pub(super) async fn stop_resource_scopes(&self, launch_name: &str) {
+        self.stop_scope_units(&scope_stop_pattern(launch_name))
+            .await;
+    }
+
+    /// Stop every scope of a session — the agent pane's, the shell's and all
+    /// task windows' — by stopping the slice they share, then drop the limits
+    /// set on it. Used when the session's tmux sessions are killed.
+    pub(super) async fn stop_session_resource_scopes(&self, tmux_name: &str) {
+        let slice = slice_unit_name(tmux_name);
+        self.stop_scope_units(&slice).await;
+        if self.config_store.read().resource_limits.enabled && systemd_scopes_available() {
+            self.systemctl(&["revert", &slice]).await;
+        }
+    }
+
+    async fn stop_scope_units(&self, pattern: &str) {
+        if !self.config_store.read().resource_limits.enabled || !systemd_scopes_available() {
+            return;
+        }
+        self.systemctl(&["stop", pattern]).await;
+    }
+
+    /// Run `systemctl --user` with `args`. Best-effort: a failure is logged at
+    /// debug level and otherwise ignored.
+    async fn systemctl(&self, args: &[&str]) {
+        let result = tokio::process::Command::new("systemctl")
+            .arg("--user")
+            .args(args)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::piped())
+            .output()
+            .await;
+        if let Ok(output) = result
+            && !output.status.success()
+        {
+            debug!(
+                "systemctl --user {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+    }
+
+    /// Sample the resource usage of a session: its agent pane plus, when they
+    /// are running, its shell pane and task windows. `None` when the agent
+    /// pane is gone or `/proc` isn't available (non-Linux hosts).
+    ///
+    /// CPU utilisation is measured against the previous call's sample. When
+    /// there is none recent — always the case for a one-shot CLI process —
+    /// a second reading is taken [`CPU_SAMPLE_INTERVAL`] after the first.
+    pub async fn session_resource_usage(&self, tmux_name: &str) -> Option<ResourceUsage> {
+        let (mut rss_bytes, mut cpu_time_ms, mut scoped) =
+            self.read_session_usage(tmux_name).await?;
+        let mut now = Instant::now();
+        let prev = cpu_samples().lock().unwrap().get(tmux_name).copied();
+        let mut prev = recent_sample(prev, now);
+        if prev.is_none() {
+            prev = Some((now, cpu_time_ms));
+            tokio::time::sleep(CPU_SAMPLE_INTERVAL).await;
+            (rss_bytes, cpu_time_ms, scoped) = self.read_session_usage(tmux_name).await?;
+            now = Instant::now();
+        }
+
+        cpu_samples()
+            .lock()
+            .unwrap()
+            .insert(tmux_name.to_string(), (now, cpu_time_ms));
+        Some(ResourceUsage {
+            rss_bytes,
+            cpu_time_ms,
+            cpu_percent: cpu_percent(prev, now, cpu_time_ms),
+            scoped,
+        })
+    }
+
+    /// Resident memory of a session's panes, as [`Self::session_resource_usage`]
+    /// totals it, without recording a CPU sample. For the memory-pressure pass.
+    pub(super) async fn session_rss_bytes(&self, tmux_name: &str) -> Option<u64> {
+        self.read_session_usage(tmux_name)
+            .await
+            .map(|(rss_bytes, _, _)| rss_bytes)
+    }
+
+    /// `(rss_bytes, cpu_time_ms, scoped)` over a session's panes; see
+    /// [`read_panes_usage`].
+    async fn read_session_usage(&self, tmux_name: &str) -> Option<(u64, u64, bool)> {
+        let mut pids = vec![self.tmux.pane_pid(tmux_name).await.ok().flatten()?];
+        for extra in [format!("{tmux_name}-sh"), tasks::task_tmux_name(tmux_name)] {
+            if let Ok(more) = self.tmux.session_pane_pids(&extra).await {
+                pids.extend(more);
+            }
+        }
+        tokio::task::spawn_blocking(move || read_panes_usage(&pids))
+            .await
+            .ok()
+            .flatten()
+    }
+}
+
+#[cfg(test)]
+mod resources_tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn unit_name_sanitizes_and_appends_suffix() {
+        assert_eq!(
+            scope_unit_name("cc-my session/1", "ab12cd34"),
+            "claude-commander-cc-my_session_1-ab12cd34"
+        );
+    }
+
+    #[test]
+    fn stop_pattern_matches_only_its_own_launch() {
+        let agent = scope_stop_pattern("cc-x");
+        assert_eq!(agent, "claude-commander-cc-x-????????.scope");
+        let glob = |pattern: &str, unit: &str| {
+            // `?` is the only wildcard the pattern uses.
+            pattern.len() == unit.len()
+                && pattern
+                    .chars()
+                    .zip(unit.chars())
+                    .all(|(p, u)| p == '?' || p == u)
+        };
+        let suffix = "ab12cd34";
+        assert!(glob(
+            &agent,
+            &format!("{}.scope", scope_unit_name("cc-x", suffix))
+        ));
+        assert!(!glob(
+            &agent,
+            &format!("{}.scope", scope_unit_name("cc-x-sh", suffix))
+        ));
+        let task = task_scope_name("cc-x", "test");
+        assert!(!glob(
+            &agent,
+            &format!("{}.scope", scope_unit_name(&task, suffix))
+        ));
+        assert!(glob(
+            &scope_stop_pattern(&task),
+            &format!("{}.scope", scope_unit_name(&task, suffix))
+        ));
+        assert!(!glob(
+            &scope_stop_pattern(&task),
+            &format!(
+                "{}.scope",
+                scope_unit_name(&task_scope_name("cc-x", "test-all"), suffix)
+            )
+        ));
+    }
+
+    #[test]
+    fn slice_name_escapes_hierarchy_separators() {
+        assert_eq!(slice_unit_name("cc-x"), "claude-commander-cc\\x2dx.slice");
+        assert_eq!(
+            slice_unit_name("cc-my session/1"),
+            "claude-commander-cc\\x2dmy\\x20session\\x2f1.slice"
+        );
+        // No `-` after our prefix, so `cc-x-y`'s slice isn't nested in `cc-x`'s.
+        assert!(!slice_unit_name("cc-x-y")[SCOPE_PREFIX.len()..].contains('-'));
+    }
+
+    #[test]
+    fn slice_properties_assign_every_limit() {
+        let mut limits = ResourceLimitsConfig::default();
+        assert_eq!(
+            slice_properties(&limits),
+            vec!["CPUQuota=", "MemoryMax=infinity", "TasksMax=infinity"]
+        );
+
+        limits.cpu_quota_percent = Some(150);
+        limits.memory_max = Some("4G".to_string());
+        limits.pids_max = Some(256);
+        assert_eq!(
+            slice_properties(&limits),
+            vec!["CPUQuota=150%", "MemoryMax=4G", "TasksMax=256"]
+        );
+    }
+
+    #[test]
+    fn wrap_builds_unlimited_scope_in_session_slice() {
+        assert_eq!(
+            wrap_in_resource_scope("claude", "claude-commander-x-1", "claude-commander-x.slice"),
+            "systemd-run --user --scope --quiet --collect --unit=claude-commander-x-1 \
+             --slice='claude-commander-x.slice' sh -c 'exec claude'"
+        );
+    }
+
+    #[test]
+    fn wrap_escapes_single_quotes_in_command() {
+        assert_eq!(
+            wrap_in_resource_scope("claude -n 'a b'", "u", "s.slice"),
+            "systemd-run --user --scope --quiet --collect --unit=u --slice='s.slice' \
+             sh -c 'exec claude -n '\\''a b'\\'''"
+        );
+    }
+
+    #[test]
+    fn scope_possible_needs_binary_and_manager() {
+        let bin = tempfile::TempDir::new().unwrap();
+        let run = tempfile::TempDir::new().unwrap();
+        let path_var = std::env::join_paths([bin.path()]).unwrap();
+        let runtime = run.path().as_os_str();
+
+        assert!(!systemd_user_scope_possible(Some(&path_var), Some(runtime)));
+        std::fs::write(bin.path().join("systemd-run"), "").unwrap();
+        assert!(!systemd_user_scope_possible(Some(&path_var), Some(runtime)));
+        std::fs::create_dir_all(run.path().join("systemd")).unwrap();
+        std::fs::write(run.path().join("systemd/private"), "").unwrap();
+        assert!(systemd_user_scope_possible(Some(&path_var), Some(runtime)));
+        assert!(!systemd_user_scope_possible(None, Some(runtime)));
+        assert!(!systemd_user_scope_possible(Some(&path_var), None));
+    }
+
+    #[test]
+    fn cgroup_path_and_scope_detection() {
+        let contents = "0::/user.slice/user-1000.slice/user@1000.service/app.slice/claude-commander-cc-x-ab12cd34.scope\n";
+        let path = parse_cgroup_v2_path(contents).unwrap();
+        assert!(is_commander_scope(path));
+        // The tmux server's own scope (e.g. under GNOME) is not ours.
+        assert!(!is_commander_scope(
+            "/user.slice/user-1000.slice/user@1000.service/app.slice/tmux-spawn-1.scope"
+        ));
+        assert!(parse_cgroup_v2_path("1:name=systemd:/x\n").is_none());
+    }
+
+    #[test]
+    fn parses_cpu_stat_and_status() {
+        assert_eq!(
+            parse_cpu_stat_usage_usec("usage_usec 123456\nuser_usec 100000\n"),
+            Some(123_456)
+        );
+        assert_eq!(
+            parse_status_rss_bytes("Name:\tclaude\nVmRSS:\t  2048 kB\n"),
+            Some(2048 * 1024)
+        );
+        assert_eq!(parse_status_rss_bytes("Name:\tkthreadd\n"), None);
+    }
+
+    #[test]
+    fn parses_stat_with_awkward_comm() {
+        // comm containing spaces and a ')' must not shift the fields.
+        let stat = "4242 (tmux: a) b) S 4200 4242 4242 0 -1 4194560 100 0 0 0 30 12 0 0 20 0 1 0";
+        assert_eq!(parse_stat_ppid_and_ticks(stat), Some((4200, 42)));
+    }
+
+    #[test]
+    fn process_tree_collects_descendants_only() {
+        let parents = HashMap::from([(10, 1), (11, 10), (12, 11), (20, 1), (13, 10)]);
+        let mut tree = process_tree(10, &parents);
+        tree.sort();
+        assert_eq!(tree, vec![10, 11, 12, 13]);
+    }
+
+    #[test]
+    fn cpu_percent_from_delta() {
+        let now = Instant::now();
+        assert_eq!(cpu_percent(None, now, 500), None);
+        let prev = Some((now - Duration::from_secs(2), 1000));
+        // 1000ms of CPU over 2s of wall time = half a core.
+        let pct = cpu_percent(prev, now, 2000).unwrap();
+        assert!((pct - 50.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn stale_cpu_sample_is_not_reused() {
+        let now = Instant::now();
+        let fresh = Some((now - Duration::from_secs(2), 1000));
+        assert_eq!(recent_sample(fresh, now), fresh);
+        let stale = Some((now - CPU_SAMPLE_MAX_AGE - Duration::from_secs(1), 1000));
+        assert_eq!(recent_sample(stale, now), None);
+        assert_eq!(recent_sample(None, now), None);
+    }
+
+    #[test]
+    fn parses_meminfo() {
+        let info = "MemTotal:       16000000 kB\nMemFree:  100 kB\nMemAvailable:    4000000 kB\n";
+        assert_eq!(
+            parse_meminfo(info),
+            Some((16_000_000 * 1024, 4_000_000 * 1024))
+        );
+        assert_eq!(parse_meminfo("MemTotal: 1 kB\n"), None);
+    }
+}
@@ -43,6 +43,7 @@ impl SessionManager {
                     shell_name
                 );
                 let _ = self.tmux.kill_session(&shell_name).await;
+                self.stop_resource_scopes(&shell_name).await;
             } else {
                 info!("Reusing existing shell session {}", shell_name);
                 self.tmux
@@ -63,6 +64,12 @@ impl SessionManager {
 
         let shell_program = self.config_store.read().shell_program.clone();
         let shell_program = self.maybe_wrap_nix_develop(&shell_program, &worktree_path);
+        let shell_program = self
+            .maybe_wrap_resource_scope(&shell_program, &tmux_name, &shell_name)
+            .await;
+        let shell_program = self
+            .maybe_with_session_env(&shell_program, session_id, &worktree_path)
+            .await;
         self.tmux
             .create_session(&shell_name, &worktree_path, Some(&shell_program))
             .await?;
@@ -112,6 +119,7 @@ impl SessionManager {
         let pane_dead = self.tmux.is_pane_dead(&shell_name).await.unwrap_or(false);
         if pane_dead {
             let _ = self.tmux.kill_session(&shell_name).await;
+            self.stop_resource_scopes(&shell_name).await;
             let sid = *session_id;
             let _ = self
                 .store
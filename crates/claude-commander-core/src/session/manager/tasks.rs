@@ -0,0 +1,489 @@
+//! Project tasks
+//!
+//! Named commands (`test = "cargo test"`) from the global `[tasks]` table and
+//! the project's `.commander.toml`, run on demand in the session's task tmux
+//! session — one window per task, left open on exit so its output can be read
+//! or attached to. A watcher polls the window until the command exits and
+//! records the run on the session (`WorktreeSession::task_runs`): status, exit
+//! code and the tail of its output. Those drive the card badge, the
+//! `task_status` section predicate, and sending a failure to the agent.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use super::hooks::text_tail;
+use super::*;
+use crate::config::ProjectFile;
+use crate::config::shell_quote;
+use crate::session::{TaskRun, TaskStart, TaskStatus};
+
+/// Lines of output kept on a finished run.
+const OUTPUT_TAIL_LINES: usize = 40;
+/// Byte cap on the kept tail, so a noisy test suite can't bloat `state.json`.
+const OUTPUT_TAIL_BYTES: usize = 8192;
+/// Lines of scrollback handed to the agent for a failed task — more than the
+/// stored tail, since a compiler error often sits well above the summary.
+const AGENT_OUTPUT_LINES: usize = 400;
+/// How often a watcher checks whether its task window has exited.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Consecutive tmux failures after which a watcher assumes the window is gone
+/// (killed with its session, or tmux restarted) and records the run failed.
+const MAX_POLL_ERRORS: u32 = 5;
+
+/// Name of the tmux session holding a session's task windows.
+pub(super) fn task_tmux_name(tmux_name: &str) -> String {
+    format!("{tmux_name}-tasks")
+}
+
+/// Task names double as tmux window names, so they are kept to what a target
+/// can address unambiguously: a leading letter, then letters, digits, `-` and
+/// `_`.
+fn is_valid_task_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// The global tasks with the project's merged over them by name. Invalid
+/// names and blank commands are dropped with a warning.
+fn resolve_tasks(
+    global: &BTreeMap<String, String>,
+    project: &BTreeMap<String, String>,
+) -> BTreeMap<String, String> {
+    let mut tasks = global.clone();
+    tasks.extend(project.iter().map(|(k, v)| (k.clone(), v.clone())));
+    tasks.retain(|name, command| {
+        if !is_valid_task_name(name) {
+            warn!("Ignoring task `{name}`: names must be letters, digits, `-` or `_`");
+            return false;
+        }
+        !command.trim().is_empty()
+    });
+    tasks
+}
+
+/// The line run in a task's window: the command under `sh -c`, so the
+/// session env prefix reaches every part of a compound command.
+fn task_shell_command(command: &str) -> String {
+    format!("sh -c {}", shell_quote(command))
+}
+
+/// A running `run` completed with `exit` (`None` = killed or vanished).
+fn finished_run(mut run: TaskRun, exit: Option<i32>, output: &str) -> TaskRun {
+    run.status = if exit == Some(0) {
+        TaskStatus::Passed
+    } else {
+        TaskStatus::Failed
+    };
+    run.exit_code = exit;
+    run.finished_at = Some(Utc::now());
+    run.output_tail = text_tail(output, OUTPUT_TAIL_LINES, OUTPUT_TAIL_BYTES);
+    run
+}
+
+/// The failed run to report: the named task's, or the most recently finished
+/// failure when no name is given.
+fn pick_failed_run<'a>(runs: &'a [TaskRun], name: Option<&str>) -> Option<&'a TaskRun> {
+    let mut failed = runs.iter().filter(|r| r.status == TaskStatus::Failed);
+    match name {
+        Some(name) => failed.find(|r| r.name == name),
+        None => failed.max_by_key(|r| r.finished_at),
+    }
+}
+
+impl SessionManager {
+    /// The tasks available to a session: the global `[tasks]` table with the
+    /// project file's (worktree copy first, then the main checkout) merged
+    /// over it.
+    pub async fn session_tasks(&self, session_id: &SessionId) -> Result<BTreeMap<String, String>> {
+        let (worktree_path, repo_path) = {
+            let state = self.store.read().await;
+            let session = state
+                .get_session(session_id)
+                .ok_or(SessionError::NotFound(*session_id))?;
+            let repo_path = state
+                .get_project(&session.project_id)
+                .map(|p| p.repo_path.clone())
+                .unwrap_or_default();
+            (session.worktree_path.clone(), repo_path)
+        };
+        let (global, trusted) = {
+            let config = self.config_store.read();
+            (config.tasks.clone(), config.trusted_project_files.clone())
+        };
+        let project = ProjectFile::load_for(&worktree_path, &repo_path, &trusted);
+        Ok(resolve_tasks(&global, &project.tasks))
+    }
+
+    /// Start (or restart) task `name` in its window of the session's task tmux
+    /// session, record it as running, and watch it to completion.
+    pub async fn run_task(&self, session_id: &SessionId, name: &str) -> Result<()> {
+        let command = self
+            .session_tasks(session_id)
+            .await?
+            .remove(name)
+            .ok_or_else(|| SessionError::TaskNotFound(name.to_string()))?;
+        let (tmux_name, worktree_path) = {
+            let state = self.store.read().await;
+            let session = state
+                .get_session(session_id)
+                .ok_or(SessionError::NotFound(*session_id))?;
+            (
+                session.tmux_session_name.clone(),
+                session.worktree_path.clone(),
+            )
+        };
+        if !worktree_path.is_dir() {
+            return Err(SessionError::InvalidState(*session_id).into());
+        }
+
+        // A rerun replaces the window's process; stop the previous run's scope
+        // too, so its stragglers don't outlive it.
+        let scope_name = resources::task_scope_name(&tmux_name, name);
+        self.stop_resource_scopes(&scope_name).await;
+        let shell_command = task_shell_command(&command);
+        let shell_command = self.maybe_wrap_nix_develop(&shell_command, &worktree_path);
+        let shell_command = self
+            .maybe_wrap_resource_scope(&shell_command, &tmux_name, &scope_name)
+            .await;
+        let shell_command = self
+            .maybe_with_session_env(&shell_command, session_id, &worktree_path)
+            .await;
+        self.tmux
+            .run_in_window(
+                &task_tmux_name(&tmux_name),
+                name,
+                &worktree_path,
+                &shell_command,
+            )
+            .await?;
+
+        let run = TaskRun {
+            name: name.to_string(),
+            command,
+            status: TaskStatus::Running,
+            exit_code: None,
+            started_at: Utc::now(),
+            finished_at: None,
+            output_tail: String::new(),
+        };
+        self.store_task_run(*session_id, run.clone(), None).await?;
+        info!("Started task {name} for session {session_id}");
+        self.spawn_task_watcher(*session_id, run);
+        Ok(())
+    }
+
+    /// Start every task available to the session, one result per task (empty
+    /// when none are configured). A task that fails to start is reported and
+    /// logged; the rest still run.
+    pub async fn run_all_tasks(&self, session_id: &SessionId) -> Result<Vec<TaskStart>> {
+        let names: Vec<String> = self.session_tasks(session_id).await?.into_keys().collect();
+        let mut results = Vec::with_capacity(names.len());
+        for name in names {
+            let error = match self.run_task(session_id, &name).await {
+                Ok(()) => None,
+                Err(e) => {
+                    warn!("Failed to start task `{name}` for {session_id}: {e}");
+                    Some(e.to_string())
+                }
+            };
+            results.push(TaskStart { name, error });
+        }
+        Ok(results)
+    }
+
+    /// A failed task run and the output to show the agent for it: the task
+    /// window's recent scrollback while the window is still around, otherwise
+    /// the tail kept on the run. `name` picks the task; `None` takes the most
+    /// recent failure.
+    pub async fn failed_task_output(
+        &self,
+        session_id: &SessionId,
+        name: Option<&str>,
+    ) -> Result<(TaskRun, String)> {
+        let (run, tmux_name) = {
+            let state = self.store.read().await;
+            let session = state
+                .get_session(session_id)
+                .ok_or(SessionError::NotFound(*session_id))?;
+            let run = pick_failed_run(&session.task_runs, name)
+                .cloned()
+                .ok_or_else(|| {
+                    SessionError::TaskNotFound(match name {
+                        Some(name) => format!("no failed run of `{name}`"),
+                        None => "no failed task run".to_string(),
+                    })
+                })?;
+            (run, session.tmux_session_name.clone())
+        };
+        let target = format!("{}:{}", task_tmux_name(&tmux_name), run.name);
+        let output = match self.tmux.capture_history(&target).await {
+            Ok(history) if !history.trim().is_empty() => {
+                text_tail(&history, AGENT_OUTPUT_LINES, usize::MAX)
+            }
+            _ => run.output_tail.clone(),
+        };
+        Ok((run, output))
+    }
+
+    /// Re-attach watchers to runs a previous process left `Running`. A run
+    /// whose window no longer exists is recorded failed by its watcher.
+    pub async fn resume_task_watchers(&self) {
+        let running: Vec<(SessionId, TaskRun)> = {
+            let state = self.store.read().await;
+            state
+                .sessions
+                .values()
+                .flat_map(|s| {
+                    s.task_runs
+                        .iter()
+                        .filter(|r| r.status == TaskStatus::Running)
+                        .map(|r| (s.id, r.clone()))
+                })
+                .collect()
+        };
+        for (session_id, run) in running {
+            self.spawn_task_watcher(session_id, run);
+        }
+    }
+
+    /// Record `run` on the session and re-run its section assignment, since
+    /// a `task_status` predicate may now match. With `if_started_at`, only
+    /// replaces a run of the same task started at that instant — a watcher
+    /// must not overwrite a rerun that superseded its run.
+    async fn store_task_run(
+        &self,
+        session_id: SessionId,
+        run: TaskRun,
+        if_started_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let sections =
+            crate::session::effective_sections(&self.config_store.read().sections).into_owned();
+        let signals = crate::session::SectionSignals::for_session(
+            &self.agent_states.read().await.states,
+            &session_id,
+            Utc::now(),
+        );
+        self.store
+            .mutate(move |state| {
+                let Some(session) = state.get_session_mut(&session_id) else {
+                    return;
+                };
+                if let Some(started_at) = if_started_at
+                    && !session
+                        .task_runs
+                        .iter()
+                        .any(|r| r.name == run.name && r.started_at == started_at)
+                {
+                    return;
+                }
+                session.record_task_run(run);
+                crate::session::apply_assignment(session, &sections, signals);
+            })
+            .await?;
+        Ok(())
+    }
+
+    fn spawn_task_watcher(&self, session_id: SessionId, run: TaskRun) {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            manager.watch_task(session_id, run).await;
+        });
+    }
+
+    /// Poll a running task's window until its command exits, then record the
+    /// outcome. Gives up quietly when the run is superseded by a rerun or the
+    /// session is deleted.
+    async fn watch_task(&self, session_id: SessionId, run: TaskRun) {
+        let mut errors = 0;
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+            let tmux_name = {
+                let state = self.store.read().await;
+                let Some(session) = state.get_session(&session_id) else {
+                    return;
+                };
+                let current = session.task_runs.iter().any(|r| {
+                    r.name == run.name
+                        && r.started_at == run.started_at
+                        && r.status == TaskStatus::Running
+                });
+                if !current {
+                    return;
+                }
+                session.tmux_session_name.clone()
+            };
+            let target = format!("{}:{}", task_tmux_name(&tmux_name), run.name);
+
+            let finished = match self.tmux.pane_exit_status(&target).await {
+                Ok(None) => {
+                    errors = 0;
+                    continue;
+                }
+                Ok(Some(exit)) => {
+                    let output = self.tmux.capture_history(&target).await.unwrap_or_default();
+                    finished_run(run.clone(), exit, &output)
+                }
+                Err(e) => {
+                    errors += 1;
+                    if errors < MAX_POLL_ERRORS {
+                        continue;
+                    }
+                    debug!("Task window {target} is gone: {e}");
+                    finished_run(run.clone(), None, &run.output_tail)
+                }
+            };
+
+            let status = finished.status;
+            if let Err(e) = self
+                .store_task_run(session_id, finished, Some(run.started_at))
+                .await
+            {
+                warn!("Failed to record task {} for {session_id}: {e}", run.name);
+            }
+            info!("Task {} for session {session_id} {status}", run.name);
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tasks_tests {
+    use super::*;
+
+    fn run(name: &str, status: TaskStatus) -> TaskRun {
+        TaskRun {
+            name: name.to_string(),
+            command: format!("run {name}"),
+            status,
+            exit_code: None,
+            started_at: Utc::now(),
+            finished_at: None,
+            output_tail: String::new(),
+        }
+    }
+
+    #[test]
+    fn project_tasks_override_global_by_name() {
+        let global = BTreeMap::from([
+            ("test".to_string(), "cargo test".to_string()),
+            ("lint".to_string(), "cargo clippy".to_string()),
+        ]);
+        let project = BTreeMap::from([
+            ("test".to_string(), "cargo nextest run".to_string()),
+            ("bad name".to_string(), "true".to_string()),
+            ("empty".to_string(), " ".to_string()),
+        ]);
+        let tasks = resolve_tasks(&global, &project);
+        assert_eq!(
+            tasks.get("test").map(String::as_str),
+            Some("cargo nextest run")
+        );
+        assert_eq!(tasks.get("lint").map(String::as_str), Some("cargo clippy"));
+        assert_eq!(tasks.len(), 2);
+    }
+
+    #[test]
+    fn task_names_must_be_window_safe() {
+        assert!(is_valid_task_name("test"));
+        assert!(is_valid_task_name("e2e-web_1"));
+        assert!(!is_valid_task_name(""));
+        assert!(!is_valid_task_name("1st"));
+        assert!(!is_valid_task_name("a.b"));
+        assert!(!is_valid_task_name("a:b"));
+    }
+
+    #[test]
+    fn shell_command_quotes_the_task() {
+        assert_eq!(
+            task_shell_command("echo 'hi' && make"),
+            "sh -c 'echo '\\''hi'\\'' && make'"
+        );
+    }
+
+    #[test]
+    fn finished_run_maps_exit_codes() {
+        let passed = finished_run(run("test", TaskStatus::Running), Some(0), "ok\n");
+        assert_eq!(passed.status, TaskStatus::Passed);
+        assert_eq!(passed.output_tail, "ok");
+        assert!(passed.finished_at.is_some());
+
+        let failed = finished_run(run("test", TaskStatus::Running), Some(101), "");
+        assert_eq!(failed.status, TaskStatus::Failed);
+        assert_eq!(failed.exit_code, Some(101));
+
+        let killed = finished_run(run("test", TaskStatus::Running), None, "");
+        assert_eq!(killed.status, TaskStatus::Failed);
+        assert_eq!(killed.exit_code, None);
+    }
+
+    #[test]
+    fn picks_named_or_latest_failure() {
+        let mut older = run("lint", TaskStatus::Failed);
+        older.finished_at = Some(Utc::now() - chrono::Duration::minutes(5));
+        let mut newer = run("test", TaskStatus::Failed);
+        newer.finished_at = Some(Utc::now());
+        let runs = [older, newer, run("build", TaskStatus::Passed)];
+
+        assert_eq!(pick_failed_run(&runs, None).unwrap().name, "test");
+        assert_eq!(pick_failed_run(&runs, Some("lint")).unwrap().name, "lint");
+        assert!(pick_failed_run(&runs, Some("build")).is_none());
+    }
+
+    /// Recording a run re-runs section assignment, which must use the polled
+    /// agent state rather than none — or every finished task would drop a
+    /// waiting agent out of its "Waiting" section until the next poll.
+    #[tokio::test]
+    async fn recording_a_run_keeps_the_polled_agent_state_section() {
+        use crate::config::{AppState, Config, ConfigStore, StateStore};
+        use crate::session::AgentState;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.projects_dir = Some(dir.path().join("projects"));
+        config.sections =
+            vec![toml::from_str("name = \"Waiting\"\nagent_state = \"waiting\"\n").unwrap()];
+        let config_store = Arc::new(ConfigStore::with_path(
+            config,
+            dir.path().join("config.toml"),
+        ));
+        let store = Arc::new(StateStore::with_path(
+            AppState::new(),
+            dir.path().join("state.json"),
+        ));
+        let manager = SessionManager::new(config_store, store.clone(), "");
+
+        let session = WorktreeSession::new(
+            ProjectId::new(),
+            "task",
+            "branch-task",
+            dir.path().join("wt"),
+            "claude",
+        );
+        let id = session.id;
+        store
+            .mutate(move |state| {
+                state.add_session(session);
+            })
+            .await
+            .unwrap();
+        manager
+            .agent_states
+            .write()
+            .await
+            .states
+            .insert(id, AgentState::WaitingForInput);
+
+        manager
+            .store_task_run(id, run("test", TaskStatus::Passed), None)
+            .await
+            .unwrap();
+        let state = store.read().await;
+        assert_eq!(
+            state.get_session(&id).unwrap().current_section.as_deref(),
+            Some("Waiting")
+        );
+    }
+}
@@ -339,9 +339,9 @@ async fn delete_session_mutates_state_once_removal_first() {
     // Regression: delete must remove the session from state *before* the slow
     // tmux/worktree teardown, so the tree row disappears immediately rather than
     // lingering until `git worktree remove` finishes. Observable via the store
-    // generation counter: the fix mutates state exactly once (the removal),
-    // whereas the old kill-first path mutated twice (a `Stopped` transition
-    // inside `kill_session`, then the removal).
+    // generation counter: the fix mutates state for the removal and then only
+    // to journal the delete for undo once teardown is done, whereas the old
+    // kill-first path opened with a `Stopped` transition inside `kill_session`.
     let mut config = Config::default();
     config.telemetry.enabled = false;
     // Isolate tmux onto a throwaway socket dir so the teardown never touches the
@@ -382,10 +382,19 @@ async fn delete_session_mutates_state_once_removal_first() {
 
     assert_eq!(
         gen_after - gen_before,
-        1,
-        "delete must mutate state once (removal), not transition through Stopped first"
+        2,
+        "delete must mutate state for the removal and the undo entry only, not transition \
+         through Stopped first"
     );
-    assert!(store.read().await.get_session(&sid).is_none());
+    let state = store.read().await;
+    assert!(state.get_session(&sid).is_none());
+    assert!(matches!(
+        state.undo_journal.as_slice(),
+        [crate::config::UndoEntry {
+            action: crate::config::UndoAction::DeleteSession(_),
+            ..
+        }]
+    ));
 }
 
 // -- `Project.origin_url` capture and backfill --
@@ -657,3 +666,183 @@ async fn backfill_refills_origin_url_after_an_older_binary_drops_the_field() {
         first
     );
 }
+
+// -- archive / restore --
+
+#[tokio::test]
+async fn archive_keeps_branch_and_uncommitted_work_and_restore_brings_them_back() {
+    let (tmp, _remote, local) = repo_with_remote();
+    let mut config = Config::default();
+    config.telemetry.enabled = false;
+    config.worktrees_dir = Some(tmp.path().join("worktrees"));
+    // Isolate tmux onto a throwaway socket dir (see the test-isolation rules in
+    // CLAUDE.md); the restore's relaunch lands there.
+    let tmux_tmpdir = TempDir::new().unwrap();
+    config.tmux_tmpdir = Some(tmux_tmpdir.path().to_path_buf());
+    let (_cdir, config_store) = test_config_store(config);
+    let (_sdir, store) = test_store();
+    let manager = SessionManager::new(config_store, store.clone(), "");
+    let pid = manager.add_project(local.clone()).await.unwrap();
+
+    let wt = tmp.path().join("worktrees").join("feat");
+    git(
+        &local,
+        &["worktree", "add", "-b", "feat", wt.to_str().unwrap()],
+    );
+    std::fs::write(wt.join("README"), "edited\n").unwrap();
+    std::fs::write(wt.join("scratch.txt"), "untracked\n").unwrap();
+    let mut session = WorktreeSession::new(pid, "feat", "feat", wt.clone(), "claude");
+    session.status = SessionStatus::Stopped;
+    let sid = session.id;
+    store
+        .mutate(move |state| state.add_session(session))
+        .await
+        .unwrap();
+
+    manager.archive_session(&sid).await.unwrap();
+    assert!(!wt.exists(), "worktree directory removed");
+    git(&local, &["show-ref", "--verify", "refs/heads/feat"]);
+    {
+        let state = store.read().await;
+        let s = state.get_session(&sid).unwrap();
+        let archive = s.archived.as_ref().expect("marked archived");
+        assert!(archive.has_snapshot);
+        assert_eq!(s.status, SessionStatus::Stopped);
+        assert!(s.hibernated);
+    }
+    assert!(matches!(
+        manager.restart_session(&sid).await,
+        Err(crate::Error::Session(SessionError::Archived(id))) if id == sid
+    ));
+    assert!(matches!(
+        manager.archive_session(&sid).await,
+        Err(crate::Error::Session(SessionError::Archived(_)))
+    ));
+
+    // The relaunch needs tmux, which the sandbox may lack; the worktree and
+    // its changes are back either way.
+    let _ = manager.restore_session(&sid).await;
+    assert_eq!(
+        std::fs::read_to_string(wt.join("README")).unwrap(),
+        "edited\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(wt.join("scratch.txt")).unwrap(),
+        "untracked\n"
+    );
+    assert!(
+        store
+            .read()
+            .await
+            .get_session(&sid)
+            .unwrap()
+            .archived
+            .is_none()
+    );
+    let _ = manager.kill_session(&sid, false).await;
+}
+
+// -- undo --
+
+#[tokio::test]
+async fn undo_brings_back_a_deleted_session_with_its_uncommitted_work() {
+    let (tmp, _remote, local) = repo_with_remote();
+    let mut config = Config::default();
+    config.telemetry.enabled = false;
+    config.worktrees_dir = Some(tmp.path().join("worktrees"));
+    let tmux_tmpdir = TempDir::new().unwrap();
+    config.tmux_tmpdir = Some(tmux_tmpdir.path().to_path_buf());
+    let (_cdir, config_store) = test_config_store(config);
+    let (_sdir, store) = test_store();
+    let manager = SessionManager::new(config_store, store.clone(), "");
+    let pid = manager.add_project(local.clone()).await.unwrap();
+
+    let wt = tmp.path().join("worktrees").join("oops");
+    git(
+        &local,
+        &["worktree", "add", "-b", "oops", wt.to_str().unwrap()],
+    );
+    std::fs::write(wt.join("README"), "half done\n").unwrap();
+    let mut session = WorktreeSession::new(pid, "oops", "oops", wt.clone(), "claude");
+    session.status = SessionStatus::Stopped;
+    let sid = session.id;
+    store
+        .mutate(move |state| state.add_session(session))
+        .await
+        .unwrap();
+
+    manager.delete_session(&sid).await.unwrap();
+    assert!(!wt.exists());
+    assert!(store.read().await.get_session(&sid).is_none());
+
+    // As with restore, the relaunch may fail without tmux; undo still reports
+    // success once the record and worktree are back.
+    let undone = manager.undo_last().await.unwrap();
+    assert!(undone.contains("oops"), "description: {undone}");
+    assert_eq!(
+        std::fs::read_to_string(wt.join("README")).unwrap(),
+        "half done\n"
+    );
+    assert!(store.read().await.get_session(&sid).is_some());
+    assert!(store.read().await.undo_journal.is_empty());
+    assert!(matches!(
+        manager.undo_last().await,
+        Err(crate::Error::Session(SessionError::NothingToUndo))
+    ));
+    let _ = manager.kill_session(&sid, false).await;
+}
+
+#[tokio::test]
+async fn expired_undo_entry_is_pruned_and_its_ref_deleted() {
+    let (tmp, _remote, local) = repo_with_remote();
+    let mut config = Config::default();
+    config.telemetry.enabled = false;
+    config.worktrees_dir = Some(tmp.path().join("worktrees"));
+    let tmux_tmpdir = TempDir::new().unwrap();
+    config.tmux_tmpdir = Some(tmux_tmpdir.path().to_path_buf());
+    let (_cdir, config_store) = test_config_store(config);
+    let (_sdir, store) = test_store();
+    let manager = SessionManager::new(config_store, store.clone(), "");
+    let pid = manager.add_project(local.clone()).await.unwrap();
+
+    let wt = tmp.path().join("worktrees").join("gone");
+    git(
+        &local,
+        &["worktree", "add", "-b", "gone", wt.to_str().unwrap()],
+    );
+    let mut session = WorktreeSession::new(pid, "gone", "gone", wt, "claude");
+    session.status = SessionStatus::Stopped;
+    let sid = session.id;
+    store
+        .mutate(move |state| state.add_session(session))
+        .await
+        .unwrap();
+    manager.delete_session(&sid).await.unwrap();
+
+    let undo_ref = crate::git::undo_ref_name(&sid);
+    let ref_exists = || {
+        std::process::Command::new("git")
+            .current_dir(&local)
+            .args(["rev-parse", "--verify", "--quiet", &undo_ref])
+            .status()
+            .unwrap()
+            .success()
+    };
+    assert!(ref_exists());
+
+    // Still inside the window: nothing to do.
+    manager.expire_undo_entries().await;
+    assert_eq!(store.read().await.undo_journal.len(), 1);
+
+    store
+        .mutate(|state| {
+            for entry in &mut state.undo_journal {
+                entry.recorded_at -= crate::config::UNDO_TTL + chrono::Duration::minutes(1);
+            }
+        })
+        .await
+        .unwrap();
+    manager.expire_undo_entries().await;
+    assert!(store.read().await.undo_journal.is_empty());
+    assert!(!ref_exists(), "{undo_ref} should be deleted once expired");
+}
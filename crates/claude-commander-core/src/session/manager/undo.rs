@@ -0,0 +1,263 @@
+//! Undo for destructive actions
+//!
+//! Deleting a session, removing a project and abandoning a paused cascade each
+//! journal what they took away in [`AppState::undo_journal`]. For sessions
+//! that is the record itself plus a stash of the worktree on a hidden ref
+//! (`refs/commander/undo/<id>`), taken just before the worktree is removed.
+//! Undo pops the newest entry, puts the records back, recreates the worktrees
+//! from branch + stash and relaunches the agents resumed.
+
+use super::*;
+use crate::config::{RemovedSession, UndoAction, UndoEntry, WorktreeStash};
+
+impl SessionManager {
+    /// Pin `session`'s worktree (or, when it is already gone, its branch tip)
+    /// to its undo ref ahead of removal. `None` for an archived session, whose
+    /// archive ref already holds its work, and when nothing could be pinned —
+    /// a failed stash must never block the delete itself.
+    pub(super) async fn stash_for_undo(
+        &self,
+        session: &WorktreeSession,
+        repo_path: &Path,
+    ) -> Option<WorktreeStash> {
+        if session.archived.is_some() {
+            return None;
+        }
+        let stash_ref = crate::git::undo_ref_name(&session.id);
+        let pinned = if session.worktree_path.exists() {
+            crate::git::snapshot_worktree(&session.worktree_path, &stash_ref)
+                .await
+                .map(|s| (s.head, s.has_snapshot))
+        } else {
+            let branch_ref = format!("refs/heads/{}", session.branch);
+            crate::git::pin_archive_ref(repo_path, &stash_ref, &branch_ref)
+                .await
+                .map(|head| (head, false))
+        };
+        match pinned {
+            Ok((head, has_snapshot)) => Some(WorktreeStash {
+                head,
+                stash_ref,
+                has_snapshot,
+            }),
+            Err(e) => {
+                warn!(
+                    "Failed to stash worktree of session {} for undo: {}",
+                    session.id, e
+                );
+                None
+            }
+        }
+    }
+
+    /// Journal `action` as undoable, deleting the refs of any entries it
+    /// pushes out.
+    pub(super) async fn record_undo(&self, action: UndoAction) {
+        let now = chrono::Utc::now();
+        match self
+            .store
+            .mutate(move |state| state.record_undo(action, now))
+            .await
+        {
+            Ok(dropped) => Self::discard_undo_entries(dropped).await,
+            Err(e) => warn!("Failed to record undo entry: {}", e),
+        }
+    }
+
+    /// Drop journal entries past [`UNDO_TTL`](crate::config::UNDO_TTL) and
+    /// delete the refs they kept alive. Run from the background tick (first at
+    /// startup), so a deleted session's stash and archive refs go once its
+    /// undo window closes rather than at the next undoable action. Skips the
+    /// state write when nothing has expired.
+    pub async fn expire_undo_entries(&self) {
+        let now = chrono::Utc::now();
+        let any_expired = self
+            .store
+            .read()
+            .await
+            .undo_journal
+            .iter()
+            .any(|e| now - e.recorded_at > crate::config::UNDO_TTL);
+        if !any_expired {
+            return;
+        }
+        match self
+            .store
+            .mutate(move |state| state.prune_undo_journal(now))
+            .await
+        {
+            Ok(dropped) => Self::discard_undo_entries(dropped).await,
+            Err(e) => warn!("Failed to prune undo journal: {}", e),
+        }
+    }
+
+    /// Delete the hidden refs kept alive by entries that can no longer be
+    /// undone. Best-effort: a leftover ref only pins a commit.
+    async fn discard_undo_entries(entries: Vec<UndoEntry>) {
+        for (repo_path, refname) in entries.iter().flat_map(|e| e.action.refs()) {
+            if let Err(e) = crate::git::delete_archive_ref(&repo_path, &refname).await {
+                warn!("Failed to delete {} after undo expiry: {}", refname, e);
+            }
+        }
+    }
+
+    /// Undo the most recent destructive action still in the journal, returning
+    /// its description (e.g. `deleted session "x"`).
+    ///
+    /// If the undo fails part-way the entry goes back on the journal, so it can
+    /// be retried once the cause (say, a path now in the way) is dealt with.
+    #[instrument(skip(self))]
+    pub async fn undo_last(&self) -> Result<String> {
+        let now = chrono::Utc::now();
+        let (entry, dropped) = self.store.mutate(move |state| state.take_undo(now)).await?;
+        Self::discard_undo_entries(dropped).await;
+        let entry = entry.ok_or(SessionError::NothingToUndo)?;
+        let description = entry.action.describe();
+
+        let result = match entry.action.clone() {
+            UndoAction::DeleteSession(removed) => self.bring_back_session(removed).await,
+            UndoAction::RemoveProject { project, sessions } => {
+                self.bring_back_project(project, sessions).await
+            }
+            UndoAction::CascadeAbandon { session_id } => self.repause_cascade(session_id).await,
+        };
+        if let Err(e) = result {
+            let _ = self
+                .store
+                .mutate(move |state| state.undo_journal.push(entry))
+                .await;
+            return Err(e);
+        }
+
+        info!("Undid {}", description);
+        Ok(description)
+    }
+
+    /// Put a deleted session back: recreate its worktree from the stash, re-add
+    /// the record, and relaunch it resumed. An archived session comes back
+    /// archived — its worktree stays gone until it is restored.
+    async fn bring_back_session(&self, removed: RemovedSession) -> Result<()> {
+        let RemovedSession {
+            session,
+            repo_path,
+            stash,
+        } = removed;
+        let session_id = session.id;
+        {
+            let state = self.store.read().await;
+            if state.get_session(&session_id).is_some() {
+                return Err(SessionError::AlreadyExists(session.title.clone()).into());
+            }
+            if state.get_project(&session.project_id).is_none() {
+                return Err(SessionError::ProjectNotFound(session.project_id.to_string()).into());
+            }
+        }
+
+        let archived = session.archived.is_some();
+        if !archived {
+            // Without a stash (it failed at delete time) the branch tip is all
+            // there is to come back to.
+            let (head, snapshot_ref) = match &stash {
+                Some(stash) => (
+                    stash.head.as_str(),
+                    stash.has_snapshot.then_some(stash.stash_ref.as_str()),
+                ),
+                None => (session.branch.as_str(), None),
+            };
+            self.recreate_worktree_from_ref(&session, &repo_path, head, snapshot_ref)
+                .await?;
+            if let Some(stash) = &stash
+                && let Err(e) = crate::git::delete_archive_ref(&repo_path, &stash.stash_ref).await
+            {
+                warn!("Failed to delete undo ref after undo: {}", e);
+            }
+        }
+
+        // Re-add stopped and flagged hibernated, so the relaunch below resumes
+        // the agent conversation regardless of `resume_session`.
+        self.store
+            .mutate(move |state| {
+                let mut session = session;
+                session.set_status(SessionStatus::Stopped);
+                session.hibernated = true;
+                session.ports = None;
+                state.add_session(session);
+            })
+            .await?;
+
+        // The record and worktree are back either way; a failed relaunch
+        // leaves an ordinary stopped session that resumes on attach.
+        if !archived && let Err(e) = self.restart_session(&session_id).await {
+            warn!(
+                "Undo brought back session {} but relaunch failed: {}",
+                session_id, e
+            );
+        }
+        Ok(())
+    }
+
+    /// Put a removed project back along with the sessions it took down. A
+    /// session that fails to come back doesn't fail the project: it is logged
+    /// and journaled again as its own undoable delete, to retry on its own.
+    async fn bring_back_project(
+        &self,
+        project: Project,
+        sessions: Vec<RemovedSession>,
+    ) -> Result<()> {
+        {
+            let state = self.store.read().await;
+            if state.get_project(&project.id).is_some()
+                || state
+                    .projects
+                    .values()
+                    .any(|p| p.repo_path == project.repo_path)
+            {
+                return Err(SessionError::AlreadyExists(project.name.clone()).into());
+            }
+        }
+        self.store
+            .mutate(move |state| {
+                let mut project = project;
+                // Sessions re-link themselves as they come back; the tmux
+                // shell is recreated on demand.
+                project.worktrees.clear();
+                project.shell_tmux_session_name = None;
+                state.add_project(project);
+            })
+            .await?;
+
+        for removed in sessions {
+            let id = removed.session.id;
+            if let Err(e) = self.bring_back_session(removed.clone()).await {
+                warn!(
+                    "Failed to bring back session {} with its project: {}",
+                    id, e
+                );
+                self.record_undo(UndoAction::DeleteSession(removed)).await;
+            }
+        }
+        Ok(())
+    }
+
+    /// Re-pause the cascade at `session_id`, as it was before the abandon.
+    async fn repause_cascade(&self, session_id: SessionId) -> Result<()> {
+        {
+            let state = self.store.read().await;
+            if state.get_session(&session_id).is_none() {
+                return Err(SessionError::NotFound(session_id).into());
+            }
+            if state.cascade_paused_at.is_some() {
+                return Err(SessionError::InvalidState(session_id).into());
+            }
+        }
+        self.store
+            .mutate(move |state| {
+                if let Some(session) = state.get_session_mut(&session_id) {
+                    session.set_status(SessionStatus::CascadePaused);
+                }
+                state.cascade_paused_at = Some(session_id);
+            })
+            .await?;
+        Ok(())
+    }
+}
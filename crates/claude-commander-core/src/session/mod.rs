@@ -8,18 +8,21 @@
 pub mod board;
 mod branch_reconcile;
 mod manager;
+pub mod query;
 pub mod section;
 mod types;
 
 pub use board::{
-    Board, BoardBackendInput, BoardCard, BoardColumn, BoardPos, BoardProjectEntry, BoardServer,
-    build_board,
+    Board, BoardBackendInput, BoardCard, BoardColumn, BoardLane, BoardPos, BoardProjectEntry,
+    BoardServer, LaneOptions, Swimlanes, build_board,
 };
 pub use branch_reconcile::decide_branch_reconcile;
 pub use manager::*;
+pub use query::SessionQuery;
 pub use section::{
-    IN_PROGRESS, RenderedSection, SectionAssignment, SectionConfig, apply_assignment,
-    assign_section, build_sections, clear_override_and_reassign, default_board_sections,
-    effective_sections, place_created_session, rename_section, section_name_available,
+    IN_PROGRESS, RenderedSection, SectionAssignment, SectionConfig, SectionMatch, SectionSignals,
+    apply_assignment, assign_section, assignment_would_change, build_sections,
+    clear_override_and_reassign, default_board_sections, effective_sections, place_created_session,
+    rename_section, section_name_available,
 };
 pub use types::*;
@@ -0,0 +1,625 @@
+//! Session filter queries for the board and list views.
+//!
+//! A query is a whitespace-separated list of terms, all of which must match
+//! (AND). Most terms are `key:value`; a comma-separated value matches any of
+//! its parts, a leading `-` negates the term, and double quotes keep spaces
+//! inside a value (`label:"needs review"`). A bare word is matched against
+//! the session's title and branch, except `unread`, which is a flag:
+//!
+//! ```text
+//! state:waiting project:api pr:open label:needs-review age:>3d program:codex unread
+//! ```
+//!
+//! The fields are the ones [`SectionConfig`](crate::session::SectionConfig)
+//! predicates read — PR state, draft, labels, review decision, reviewers,
+//! task status and tags — plus the live agent state, the session status, its
+//! notes and its timestamps. Like section assignment this is a pure function
+//! over the wire DTOs, so local and remote sessions filter identically.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::api::{SessionInfo, WorkspaceSnapshot};
+use crate::git::{PrState, ReviewDecision};
+use crate::session::section::{is_copilot_login, parse_age};
+use crate::session::{AgentState, SessionId, SessionStatus, TaskStatus};
+
+/// A parsed filter query. Parse with [`str::parse`]; an empty string parses
+/// to the query that matches everything.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionQuery {
+    source: String,
+    terms: Vec<Term>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Term {
+    negated: bool,
+    test: Test,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Test {
+    State(Vec<AgentState>),
+    Status(Vec<SessionStatus>),
+    Project(Vec<String>),
+    Program(Vec<String>),
+    Branch(Vec<String>),
+    Section(Vec<String>),
+    Pr(Vec<PrFilter>),
+    Label(Vec<String>),
+    Tag(Vec<String>),
+    Note(Vec<String>),
+    Review(Vec<Option<ReviewDecision>>),
+    Reviewer(Vec<String>),
+    Task(Vec<Option<TaskStatus>>),
+    Age(Since),
+    Attached(Since),
+    Entered(Since),
+    Unread,
+    Text(String),
+}
+
+/// `pr:` values. `open` includes drafts; `ready` is an open non-draft PR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PrFilter {
+    Any,
+    None,
+    Open,
+    Ready,
+    Draft,
+    Merged,
+    Closed,
+}
+
+/// A timestamp comparison: `>3d` is "more than three days ago", `<2h` "within
+/// the last two hours". `never` matches a timestamp that isn't set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Since {
+    OlderThan(Duration),
+    NewerThan(Duration),
+    Never,
+}
+
+impl Since {
+    fn matches(self, at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+        match (self, at) {
+            (Self::Never, at) => at.is_none(),
+            (_, None) => false,
+            (Self::OlderThan(d), Some(at)) => now - at > d,
+            (Self::NewerThan(d), Some(at)) => now - at < d,
+        }
+    }
+}
+
+/// Keys accepted before the `:`, for the parse error on an unknown one.
+const KEYS: &str = "state, status, project, program, branch, section, pr, label, tag, note, \
+                    review, reviewer, task, age, attached, entered";
+
+impl SessionQuery {
+    /// The query text as typed.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether the query has no terms (and so matches every session).
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Whether `session`, whose agent is in `agent_state`, satisfies every term.
+    pub fn matches(
+        &self,
+        session: &SessionInfo,
+        agent_state: Option<AgentState>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        self.terms
+            .iter()
+            .all(|t| t.test.matches(session, agent_state, now) != t.negated)
+    }
+
+    /// `snapshot` cut down to the matching sessions. Projects keep only their
+    /// matching session ids, and a project left with none is dropped, so the
+    /// list and board builders see exactly what the query lets through.
+    pub fn filter_snapshot(
+        &self,
+        snapshot: &WorkspaceSnapshot,
+        agent_states: &BTreeMap<SessionId, AgentState>,
+        now: DateTime<Utc>,
+    ) -> WorkspaceSnapshot {
+        let mut filtered = snapshot.clone();
+        filtered
+            .sessions
+            .retain(|s| self.matches(s, agent_states.get(&s.session_id).copied(), now));
+        let kept: std::collections::HashSet<SessionId> =
+            filtered.sessions.iter().map(|s| s.session_id).collect();
+        for project in &mut filtered.projects {
+            project.session_ids.retain(|id| kept.contains(id));
+        }
+        filtered.projects.retain(|p| !p.session_ids.is_empty());
+        filtered
+    }
+}
+
+impl fmt::Display for SessionQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl FromStr for SessionQuery {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let terms = tokenize(s)?
+            .into_iter()
+            .map(|word| parse_term(&word))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            source: s.trim().to_string(),
+            terms,
+        })
+    }
+}
+
+impl Test {
+    fn matches(
+        &self,
+        s: &SessionInfo,
+        agent_state: Option<AgentState>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        match self {
+            Self::State(states) => agent_state.is_some_and(|a| states.contains(&a)),
+            Self::Status(statuses) => statuses.contains(&s.status),
+            Self::Project(needles) => contains_any(&s.project_name, needles),
+            Self::Program(needles) => contains_any(&s.program, needles),
+            Self::Branch(needles) => contains_any(&s.branch, needles),
+            Self::Section(needles) => {
+                let section = s
+                    .current_section
+                    .as_deref()
+                    .unwrap_or(crate::session::IN_PROGRESS);
+                contains_any(section, needles)
+            }
+            Self::Pr(filters) => filters.iter().any(|f| f.matches(s)),
+            Self::Label(labels) => labels
+                .iter()
+                .any(|l| s.pr_labels.iter().any(|have| have.eq_ignore_ascii_case(l))),
+            // Tags are stored normalised, so compare the needle the same way.
+            Self::Tag(tags) => tags
+                .iter()
+                .any(|t| s.tags.contains(&crate::session::normalize_tag(t))),
+            Self::Note(needles) => contains_any(&s.notes, needles),
+            Self::Review(decisions) => {
+                s.pr_number.is_some() && decisions.contains(&s.review_decision)
+            }
+            Self::Reviewer(logins) => logins.iter().any(|l| match l.as_str() {
+                // Same Copilot exclusion as `has_reviewer = true/false`.
+                "any" => s.pr_reviewers.iter().any(|r| !is_copilot_login(r)),
+                "none" => !s.pr_reviewers.iter().any(|r| !is_copilot_login(r)),
+                login => s.pr_reviewers.iter().any(|r| r.eq_ignore_ascii_case(login)),
+            }),
+            Self::Task(statuses) => statuses.contains(&TaskStatus::summarize(&s.task_runs)),
+            Self::Age(since) => since.matches(Some(s.created_at), now),
+            Self::Attached(since) => since.matches(s.last_attached_at, now),
+            Self::Entered(since) => since.matches(s.entered_section_at, now),
+            Self::Unread => s.unread,
+            Self::Text(needle) => {
+                s.title.to_lowercase().contains(needle) || s.branch.to_lowercase().contains(needle)
+            }
+        }
+    }
+}
+
+impl PrFilter {
+    fn matches(self, s: &SessionInfo) -> bool {
+        let has_pr = s.pr_number.is_some();
+        match self {
+            Self::Any => has_pr,
+            Self::None => !has_pr,
+            Self::Open => has_pr && s.pr_state == PrState::Open,
+            Self::Ready => has_pr && s.pr_state == PrState::Open && !s.pr_draft,
+            Self::Draft => has_pr && s.pr_state == PrState::Open && s.pr_draft,
+            Self::Merged => has_pr && s.pr_state == PrState::Merged,
+            Self::Closed => has_pr && s.pr_state == PrState::Closed,
+        }
+    }
+}
+
+/// Case-insensitive substring match against any needle (already lowercased).
+fn contains_any(haystack: &str, needles: &[String]) -> bool {
+    let haystack = haystack.to_lowercase();
+    needles.iter().any(|n| haystack.contains(n.as_str()))
+}
+
+/// Split on whitespace outside double quotes, dropping the quotes.
+fn tokenize(s: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    for c in s.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("unclosed quote".to_string());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    Ok(words)
+}
+
+fn parse_term(word: &str) -> Result<Term, String> {
+    let (negated, body) = match word.strip_prefix('-') {
+        Some(rest) if !rest.is_empty() => (true, rest),
+        _ => (false, word),
+    };
+    let test = match body.split_once(':') {
+        None if body.eq_ignore_ascii_case("unread") => Test::Unread,
+        None => Test::Text(body.to_lowercase()),
+        Some((key, value)) => parse_keyed(&key.to_lowercase(), value)?,
+    };
+    Ok(Term { negated, test })
+}
+
+fn parse_keyed(key: &str, value: &str) -> Result<Test, String> {
+    if value.is_empty() {
+        return Err(format!("`{key}:` needs a value"));
+    }
+    let parts: Vec<String> = value
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    Ok(match key {
+        "state" => Test::State(each(
+            key,
+            &parts,
+            parse_agent_state,
+            "working, idle, waiting, unknown",
+        )?),
+        "status" => Test::Status(each(
+            key,
+            &parts,
+            parse_status,
+            "creating, running, stopped, merging, cascade_paused, pushing",
+        )?),
+        "project" => Test::Project(parts),
+        "program" => Test::Program(parts),
+        "branch" => Test::Branch(parts),
+        "section" => Test::Section(parts),
+        "pr" => Test::Pr(each(
+            key,
+            &parts,
+            parse_pr,
+            "open, ready, draft, merged, closed, any, none",
+        )?),
+        "label" => Test::Label(parts),
+        "tag" => Test::Tag(parts),
+        "note" => Test::Note(parts),
+        "review" => Test::Review(each(
+            key,
+            &parts,
+            parse_review,
+            "approved, changes, required, none",
+        )?),
+        "reviewer" => Test::Reviewer(parts),
+        "task" => Test::Task(each(
+            key,
+            &parts,
+            parse_task,
+            "passed, failed, running, none",
+        )?),
+        "age" => Test::Age(parse_since(key, value)?),
+        "attached" => Test::Attached(parse_since(key, value)?),
+        "entered" => Test::Entered(parse_since(key, value)?),
+        _ => return Err(format!("unknown key `{key}:` (expected one of {KEYS})")),
+    })
+}
+
+/// Parse every comma-separated part of an enumerated value.
+fn each<T>(
+    key: &str,
+    parts: &[String],
+    parse: fn(&str) -> Option<T>,
+    expected: &str,
+) -> Result<Vec<T>, String> {
+    parts
+        .iter()
+        .map(|p| parse(p).ok_or_else(|| format!("unknown {key} `{p}` (expected {expected})")))
+        .collect()
+}
+
+fn parse_agent_state(s: &str) -> Option<AgentState> {
+    Some(match s {
+        "working" => AgentState::Working,
+        "idle" => AgentState::Idle,
+        "waiting" => AgentState::WaitingForInput,
+        "unknown" => AgentState::Unknown,
+        _ => return None,
+    })
+}
+
+fn parse_status(s: &str) -> Option<SessionStatus> {
+    Some(match s {
+        "creating" => SessionStatus::Creating,
+        "running" => SessionStatus::Running,
+        "stopped" => SessionStatus::Stopped,
+        "merging" => SessionStatus::Merging,
+        "cascade_paused" | "paused" => SessionStatus::CascadePaused,
+        "pushing" => SessionStatus::Pushing,
+        _ => return None,
+    })
+}
+
+fn parse_pr(s: &str) -> Option<PrFilter> {
+    Some(match s {
+        "any" | "yes" => PrFilter::Any,
+        "none" | "no" => PrFilter::None,
+        "open" => PrFilter::Open,
+        "ready" => PrFilter::Ready,
+        "draft" => PrFilter::Draft,
+        "merged" => PrFilter::Merged,
+        "closed" => PrFilter::Closed,
+        _ => return None,
+    })
+}
+
+fn parse_review(s: &str) -> Option<Option<ReviewDecision>> {
+    Some(match s {
+        "approved" => Some(ReviewDecision::Approved),
+        "changes" | "changes_requested" => Some(ReviewDecision::ChangesRequested),
+        "required" | "review_required" => Some(ReviewDecision::ReviewRequired),
+        "none" => None,
+        _ => return None,
+    })
+}
+
+fn parse_task(s: &str) -> Option<Option<TaskStatus>> {
+    Some(match s {
+        "passed" | "pass" => Some(TaskStatus::Passed),
+        "failed" | "fail" => Some(TaskStatus::Failed),
+        "running" => Some(TaskStatus::Running),
+        "none" => None,
+        _ => return None,
+    })
+}
+
+/// `>3d`, `<90m`, or `never`. Units are `m`, `h`, `d` and `w`.
+fn parse_since(key: &str, value: &str) -> Result<Since, String> {
+    if value.eq_ignore_ascii_case("never") {
+        return Ok(Since::Never);
+    }
+    let (older, duration) = parse_age(value).ok_or_else(|| {
+        if is_age_shaped(value) {
+            format!("`{key}:{value}` is too large an age")
+        } else {
+            format!("`{key}:{value}` needs a comparison like >3d, <2h or never")
+        }
+    })?;
+    Ok(match older {
+        true => Since::OlderThan(duration),
+        false => Since::NewerThan(duration),
+    })
+}
+
+/// Whether `value` is written like an age (`>`/`<`, digits, a unit) — so one
+/// [`parse_age`] still refused is out of range rather than malformed.
+fn is_age_shaped(value: &str) -> bool {
+    let Some(rest) = value.strip_prefix(['>', '<']) else {
+        return false;
+    };
+    let Some(digits) = rest.strip_suffix(['m', 'h', 'd', 'w', 'M', 'H', 'D', 'W']) else {
+        return false;
+    };
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::{ProjectId, TaskRun, WorktreeSession};
+    use std::path::PathBuf;
+
+    fn info(title: &str) -> SessionInfo {
+        let s = WorktreeSession::new(
+            ProjectId::new(),
+            title,
+            title,
+            PathBuf::from("/tmp/wt"),
+            "claude",
+        );
+        crate::api::session_info_from_session(&s, "api-server")
+    }
+
+    fn q(s: &str) -> SessionQuery {
+        s.parse().unwrap()
+    }
+
+    fn with_pr(mut s: SessionInfo, state: PrState, draft: bool) -> SessionInfo {
+        s.pr_number = Some(7);
+        s.pr_state = state;
+        s.pr_draft = draft;
+        s
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        let query = q("   ");
+        assert!(query.is_empty());
+        assert!(query.matches(&info("a"), None, Utc::now()));
+    }
+
+    #[test]
+    fn agent_state_needs_a_live_state() {
+        let s = info("a");
+        let now = Utc::now();
+        assert!(q("state:waiting").matches(&s, Some(AgentState::WaitingForInput), now));
+        assert!(q("state:idle,waiting").matches(&s, Some(AgentState::Idle), now));
+        assert!(!q("state:waiting").matches(&s, Some(AgentState::Working), now));
+        assert!(!q("state:waiting").matches(&s, None, now));
+        assert!(q("-state:working").matches(&s, None, now));
+    }
+
+    #[test]
+    fn pr_values_follow_section_semantics() {
+        let now = Utc::now();
+        let none = info("a");
+        let draft = with_pr(info("b"), PrState::Open, true);
+        let ready = with_pr(info("c"), PrState::Open, false);
+        let merged = with_pr(info("d"), PrState::Merged, false);
+
+        // The DTO's pr_state is Open even without a PR; `pr:open` must not
+        // match that.
+        assert!(!q("pr:open").matches(&none, None, now));
+        assert!(q("pr:none").matches(&none, None, now));
+        assert!(q("pr:open").matches(&draft, None, now));
+        assert!(q("pr:open").matches(&ready, None, now));
+        assert!(!q("pr:ready").matches(&draft, None, now));
+        assert!(q("pr:draft").matches(&draft, None, now));
+        assert!(q("pr:merged,closed").matches(&merged, None, now));
+        assert!(q("pr:any").matches(&merged, None, now));
+    }
+
+    #[test]
+    fn labels_reviewers_and_review_decision() {
+        let now = Utc::now();
+        let mut s = with_pr(info("a"), PrState::Open, false);
+        s.pr_labels = vec!["Needs-Review".to_string()];
+        s.pr_reviewers = vec!["copilot-pull-request-reviewer[bot]".to_string()];
+        s.review_decision = Some(ReviewDecision::ReviewRequired);
+
+        assert!(q("label:needs-review").matches(&s, None, now));
+        assert!(q(r#"label:"wip,needs-review""#).matches(&s, None, now));
+        assert!(!q("label:wip").matches(&s, None, now));
+        assert!(q("reviewer:none").matches(&s, None, now), "Copilot alone");
+        s.pr_reviewers.push("alice".to_string());
+        assert!(q("reviewer:any reviewer:Alice").matches(&s, None, now));
+        assert!(q("review:required").matches(&s, None, now));
+        assert!(!q("review:approved").matches(&s, None, now));
+    }
+
+    #[test]
+    fn tags_match_exactly_and_notes_by_substring() {
+        let now = Utc::now();
+        let mut s = info("a");
+        s.tags = vec!["blocked".to_string(), "needs-review".to_string()];
+        s.notes = "Waiting on the API team".to_string();
+
+        assert!(q("tag:#Blocked").matches(&s, None, now));
+        assert!(q(r#"tag:"needs review""#).matches(&s, None, now));
+        assert!(
+            !q("tag:block").matches(&s, None, now),
+            "tags aren't substrings"
+        );
+        assert!(q("-tag:wip note:api").matches(&s, None, now));
+        assert!(!q("note:deploy").matches(&s, None, now));
+    }
+
+    #[test]
+    fn substring_keys_are_case_insensitive() {
+        let now = Utc::now();
+        let mut s = info("Fix login");
+        s.program = "codex --full-auto".to_string();
+        assert!(q("project:API program:codex").matches(&s, None, now));
+        assert!(q("section:progress").matches(&s, None, now), "catch-all");
+        assert!(
+            q("LOGIN").matches(&s, None, now),
+            "bare words search the title"
+        );
+        assert!(!q("program:claude").matches(&s, None, now));
+    }
+
+    #[test]
+    fn timestamps_compare_against_now() {
+        let now = Utc::now();
+        let mut s = info("a");
+        s.created_at = now - Duration::days(4);
+        assert!(q("age:>3d").matches(&s, None, now));
+        assert!(!q("age:<3d").matches(&s, None, now));
+        assert!(q("attached:never").matches(&s, None, now));
+        assert!(!q("attached:<1h").matches(&s, None, now));
+        s.last_attached_at = Some(now - Duration::minutes(10));
+        assert!(q("attached:<1h").matches(&s, None, now));
+        assert!(!q("attached:never").matches(&s, None, now));
+    }
+
+    #[test]
+    fn unread_and_task_status() {
+        let now = Utc::now();
+        let mut s = info("a");
+        assert!(q("-unread task:none").matches(&s, None, now));
+        s.unread = true;
+        s.task_runs = vec![TaskRun {
+            name: "test".to_string(),
+            command: "cargo test".to_string(),
+            status: TaskStatus::Failed,
+            exit_code: Some(1),
+            started_at: now,
+            finished_at: Some(now),
+            output_tail: String::new(),
+        }];
+        assert!(q("unread task:failed").matches(&s, None, now));
+        assert!(!q("task:passed").matches(&s, None, now));
+    }
+
+    #[test]
+    fn parse_errors_name_the_problem() {
+        let err = |s: &str| s.parse::<SessionQuery>().unwrap_err();
+        assert!(err("colour:red").contains("unknown key `colour:`"));
+        assert!(err("state:sleeping").contains("unknown state `sleeping`"));
+        assert!(err("age:3d").contains("needs a comparison"));
+        assert!(err("age:>3y").contains("needs a comparison"));
+        assert!(err("age:>99999999999999d").contains("too large an age"));
+        assert!(err("attached:<99999999999999999999w").contains("too large an age"));
+        assert!(err("label:").contains("needs a value"));
+        assert!(err(r#"label:"wip"#).contains("unclosed quote"));
+    }
+
+    #[test]
+    fn filter_snapshot_prunes_projects() {
+        use crate::api::{ProjectInfo, ServerStatus};
+        let keep = info("keep");
+        let mut drop = info("drop");
+        drop.project_id = ProjectId::new();
+        let project = |s: &SessionInfo| ProjectInfo {
+            id: s.project_id,
+            name: s.project_name.clone(),
+            repo_path: PathBuf::from("/tmp"),
+            main_branch: "main".to_string(),
+            session_ids: vec![s.session_id],
+            origin_url: None,
+        };
+        let snapshot = WorkspaceSnapshot {
+            projects: vec![project(&keep), project(&drop)],
+            sessions: vec![keep.clone(), drop],
+            cascade_paused: None,
+            pending_comment_sessions: Vec::new(),
+            project_pull: Default::default(),
+            operations: Vec::new(),
+            server: ServerStatus {
+                gh_available: false,
+                tmux_ok: true,
+                version: "test".to_string(),
+            },
+        };
+        let filtered = q("keep").filter_snapshot(&snapshot, &BTreeMap::new(), Utc::now());
+        assert_eq!(filtered.sessions.len(), 1);
+        assert_eq!(filtered.projects.len(), 1);
+        assert_eq!(filtered.projects[0].id, keep.project_id);
+    }
+}
@@ -1,40 +1,332 @@
 //! Section assignment for worktree sessions.
 //!
 //! Sessions are grouped under configurable section headers in the TUI list.
-//! Assignment is a pure function of the session's PR-derived state and the
-//! user's section configuration.
+//! Assignment is a pure function of the session's stored state (PR fields,
+//! timestamps, program, tags, diffstat, CI checks), the live
+//! [`SectionSignals`] the caller supplies (the agent's state and the evaluation
+//! time), and the user's section configuration.
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::git::{PrState, ReviewDecision};
-use crate::session::{SessionId, SessionNode, WorktreeSession};
+use crate::agent::AgentKind;
+use crate::git::{ChecksStatus, PrState, ReviewDecision};
+use crate::session::{
+    AgentState, SessionId, SessionNode, TaskStatus, WorktreeSession, normalize_tag,
+};
 
-/// Declarative predicate matching a session to a section.
-/// All declared fields must match (AND); undeclared fields are ignored.
+/// A board section: a name, the predicates that match a session to it, and
+/// its advisory WIP limits. The predicates are written inline in the
+/// section's table (see [`SectionMatch`]).
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SectionConfig {
     pub name: String,
+    #[serde(flatten)]
+    pub matcher: SectionMatch,
+    /// Advisory WIP limit. When `Some(n)`, the section header shows
+    /// `count/n`, rendering in the warning colour when `count == n` and the
+    /// error colour when `count > n`. Purely informational — never blocks
+    /// creation or section transitions.
     #[serde(default)]
-    pub pr_state: Option<StatePredicate>,
+    pub max_sessions: Option<u32>,
+    /// Advisory WIP limit for each board swimlane's slice of this section,
+    /// coloured the same way as `max_sessions` in the lane's count. Only
+    /// shown while the board is split into swimlanes.
     #[serde(default)]
+    pub lane_max_sessions: Option<u32>,
+}
+
+/// Declarative predicate matching a session: a section's own predicates, and
+/// each group in its `any` (OR) or `not` list. All declared fields must match
+/// (AND); undeclared fields are ignored. Groups nest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SectionMatch {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr_state: Option<StatePredicate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_draft: Option<bool>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_label: Option<LabelPredicate>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_pr: Option<bool>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub review_decision: Option<DecisionPredicate>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_reviewer: Option<ReviewerPredicate>,
-    /// Advisory WIP limit. When `Some(n)`, the section header shows
-    /// `count/n`, rendering in the warning colour when `count == n` and the
-    /// error colour when `count > n`. Purely informational — never blocks
-    /// creation or section transitions.
-    #[serde(default)]
-    pub max_sessions: Option<u32>,
+    /// Summary status of the session's project tasks (see
+    /// [`TaskStatus::summarize`]); a session with no task runs never matches.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_status: Option<TaskPredicate>,
+    /// Live agent state; never matches a session with no detected state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_state: Option<AgentStatePredicate>,
+    /// Whether the agent finished a turn the user hasn't looked at yet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unread: Option<bool>,
+    /// Time since the session was last active (`last_active_at`), e.g. `">3d"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inactive_for: Option<AgePredicate>,
+    /// Time since the session was created, e.g. `"<2h"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age: Option<AgePredicate>,
+    /// Glob over the session's launch command, e.g. `"codex*"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub program: Option<GlobPredicate>,
+    /// Agent harness derived from the program (see [`AgentKind`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<AgentKindPredicate>,
+    /// Glob over the branch name, e.g. `"spike/*"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<GlobPredicate>,
+    /// Glob over the session title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<GlobPredicate>,
+    /// User tag on the session (any-of for a list), compared after the same
+    /// normalisation tags get when set, so `"#WIP"` matches the tag `wip`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_tag: Option<LabelPredicate>,
+    /// Lines changed (insertions + deletions) against the session's base,
+    /// e.g. `">500"`. Never matches before the first diffstat refresh.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff_lines: Option<CountPredicate>,
+    /// Files changed against the session's base, e.g. `">20"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff_files: Option<CountPredicate>,
+    /// Aggregate CI status of the PR's checks; a session without a PR never
+    /// matches.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checks: Option<ChecksPredicate>,
+    /// OR groups: when any are given, at least one must match as well.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub any: Vec<SectionMatch>,
+    /// Negated group: the matcher matches only when this group doesn't.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not: Option<Box<SectionMatch>>,
+}
+
+/// Live inputs to section assignment that aren't stored on the session.
+#[derive(Debug, Clone, Copy)]
+pub struct SectionSignals {
+    /// The agent's state from the agent-state poll; `None` when it hasn't
+    /// been detected (stopped session, or no poll has run).
+    pub agent_state: Option<AgentState>,
+    /// Evaluation time for the age predicates.
+    pub now: DateTime<Utc>,
+}
+
+impl SectionSignals {
+    /// Signals at `now` with no agent state known.
+    pub fn at(now: DateTime<Utc>) -> Self {
+        Self {
+            agent_state: None,
+            now,
+        }
+    }
+
+    /// Signals for session `id` at `now`, taking its agent state from a poll
+    /// snapshot.
+    pub fn for_session(
+        states: &BTreeMap<SessionId, AgentState>,
+        id: &SessionId,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            agent_state: states.get(id).copied(),
+            now,
+        }
+    }
+}
+
+impl SectionConfig {
+    /// Whether any predicate (nested groups included) reads the live agent
+    /// state or the unread flag, so assignment must be re-run when those
+    /// change.
+    pub fn reads_live_signals(&self) -> bool {
+        self.matcher
+            .any_clause(&|m: &SectionMatch| m.agent_state.is_some() || m.unread.is_some())
+    }
+
+    /// Whether any predicate (nested groups included) compares a timestamp
+    /// against the evaluation time, so assignment must be re-run as time
+    /// passes.
+    pub fn reads_age(&self) -> bool {
+        self.matcher
+            .any_clause(&|m: &SectionMatch| m.inactive_for.is_some() || m.age.is_some())
+    }
+
+    /// Whether any predicate (nested groups included) reads the diffstat, so
+    /// the PR poll must keep it fresh.
+    pub fn reads_diff_stat(&self) -> bool {
+        self.matcher
+            .any_clause(&|m: &SectionMatch| m.diff_lines.is_some() || m.diff_files.is_some())
+    }
+
+    /// Whether any predicate reads an input that can flip back (agent state,
+    /// unread, inactivity, age, diffstat, CI checks, user tags). A session in
+    /// such a section is released for a full rescan once the section stops
+    /// matching, instead of staying put under the forward-only rule.
+    fn is_volatile(&self) -> bool {
+        self.matcher.any_clause(&|m: &SectionMatch| {
+            m.agent_state.is_some()
+                || m.unread.is_some()
+                || m.inactive_for.is_some()
+                || m.age.is_some()
+                || m.diff_lines.is_some()
+                || m.diff_files.is_some()
+                || m.checks.is_some()
+                || m.has_tag.is_some()
+        })
+    }
+}
+
+impl SectionMatch {
+    /// True when no field is declared (the group matches every session).
+    fn is_empty(&self) -> bool {
+        self.pr_state.is_none()
+            && self.is_draft.is_none()
+            && self.has_label.is_none()
+            && self.has_pr.is_none()
+            && self.review_decision.is_none()
+            && self.has_reviewer.is_none()
+            && self.task_status.is_none()
+            && self.agent_state.is_none()
+            && self.unread.is_none()
+            && self.inactive_for.is_none()
+            && self.age.is_none()
+            && self.program.is_none()
+            && self.agent.is_none()
+            && self.branch.is_none()
+            && self.title.is_none()
+            && self.has_tag.is_none()
+            && self.diff_lines.is_none()
+            && self.diff_files.is_none()
+            && self.checks.is_none()
+            && self.any.is_empty()
+            && self.not.is_none()
+    }
+
+    /// Whether `test` holds for this group or any nested one.
+    fn any_clause(&self, test: &dyn Fn(&SectionMatch) -> bool) -> bool {
+        test(self)
+            || self.any.iter().any(|g| g.any_clause(test))
+            || self.not.as_ref().is_some_and(|g| g.any_clause(test))
+    }
+
+    fn matches(&self, session: &WorktreeSession, signals: SectionSignals) -> bool {
+        if let Some(state_pred) = &self.pr_state
+            && !state_pred.matches(session.pr_state)
+        {
+            return false;
+        }
+        if let Some(required) = self.is_draft
+            && session.pr_draft != required
+        {
+            return false;
+        }
+        if let Some(label_pred) = &self.has_label
+            && !label_pred.matches(&session.pr_labels)
+        {
+            return false;
+        }
+        if let Some(required) = self.has_pr
+            && session.pr_number.is_some() != required
+        {
+            return false;
+        }
+        if let Some(decision_pred) = &self.review_decision
+            && !decision_pred.matches(session.review_decision)
+        {
+            return false;
+        }
+        if let Some(reviewer_pred) = &self.has_reviewer
+            && !reviewer_pred.matches(&session.pr_reviewers)
+        {
+            return false;
+        }
+        if let Some(task_pred) = &self.task_status
+            && !task_pred.matches(session.task_status())
+        {
+            return false;
+        }
+        if let Some(state_pred) = &self.agent_state
+            && !state_pred.matches(signals.agent_state)
+        {
+            return false;
+        }
+        if let Some(required) = self.unread
+            && session.unread != required
+        {
+            return false;
+        }
+        if let Some(age) = self.inactive_for
+            && !age.matches(session.last_active_at, signals.now)
+        {
+            return false;
+        }
+        if let Some(age) = self.age
+            && !age.matches(session.created_at, signals.now)
+        {
+            return false;
+        }
+        if let Some(glob) = &self.program
+            && !glob.matches(&session.program)
+        {
+            return false;
+        }
+        if let Some(kind_pred) = &self.agent
+            && !kind_pred.matches(Some(AgentKind::from_program(&session.program)))
+        {
+            return false;
+        }
+        if let Some(glob) = &self.branch
+            && !glob.matches(&session.branch)
+        {
+            return false;
+        }
+        if let Some(glob) = &self.title
+            && !glob.matches(&session.title)
+        {
+            return false;
+        }
+        if let Some(tag_pred) = &self.has_tag
+            && !tag_pred.matches_normalized(&session.tags)
+        {
+            return false;
+        }
+        if let Some(count) = self.diff_lines
+            && !count.matches(
+                session
+                    .diff_stat
+                    .map(|d| (d.lines_added + d.lines_removed) as u64),
+            )
+        {
+            return false;
+        }
+        if let Some(count) = self.diff_files
+            && !count.matches(session.diff_stat.map(|d| d.files_changed as u64))
+        {
+            return false;
+        }
+        if let Some(checks_pred) = &self.checks
+            && !checks_pred.matches(session.pr_checks)
+        {
+            return false;
+        }
+        if !self.any.is_empty() && !self.any.iter().any(|g| g.matches(session, signals)) {
+            return false;
+        }
+        if let Some(group) = &self.not
+            && group.matches(session, signals)
+        {
+            return false;
+        }
+        true
+    }
 }
 
 /// Reviewer predicate.
@@ -67,7 +359,7 @@ impl ReviewerPredicate {
     }
 }
 
-fn is_copilot_login(login: &str) -> bool {
+pub(crate) fn is_copilot_login(login: &str) -> bool {
     login.to_lowercase().contains("copilot")
 }
 
@@ -93,6 +385,197 @@ impl<T: PartialEq + Copy> OneOrMany<T> {
 
 pub type StatePredicate = OneOrMany<PrState>;
 pub type DecisionPredicate = OneOrMany<ReviewDecision>;
+pub type TaskPredicate = OneOrMany<TaskStatus>;
+pub type AgentStatePredicate = OneOrMany<AgentState>;
+pub type AgentKindPredicate = OneOrMany<AgentKind>;
+pub type ChecksPredicate = OneOrMany<ChecksStatus>;
+
+/// Glob predicate over a string field: a single pattern or a list (any-of).
+/// `*` matches any run of characters and `?` any one; matching is
+/// case-insensitive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GlobPredicate {
+    One(String),
+    Any(Vec<String>),
+}
+
+impl GlobPredicate {
+    fn matches(&self, value: &str) -> bool {
+        match self {
+            Self::One(pattern) => glob_matches(pattern, value),
+            Self::Any(patterns) => patterns.iter().any(|p| glob_matches(p, value)),
+        }
+    }
+}
+
+/// Case-insensitive glob match of the whole of `text` against `pattern`.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    // Classic backtracking over the most recent `*`.
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((sp, st)) => {
+                    p = sp + 1;
+                    t = st + 1;
+                    star = Some((sp, st + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Age predicate: `">3d"` is "more than three days ago", `"<2h"` "within the
+/// last two hours". Units are `m`, `h`, `d` and `w`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AgePredicate {
+    pub older: bool,
+    pub duration: Duration,
+}
+
+impl AgePredicate {
+    fn matches(self, at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        if self.older {
+            now - at > self.duration
+        } else {
+            now - at < self.duration
+        }
+    }
+}
+
+/// Parse `>3d` / `<90m` into (older-than, duration). Units are `m`, `h`,
+/// `d` and `w`. `None` for anything malformed, and for an amount too large
+/// to be a [`Duration`].
+pub(crate) fn parse_age(value: &str) -> Option<(bool, Duration)> {
+    let value = value.trim();
+    let (older, rest) = match value.as_bytes().first() {
+        Some(b'>') => (true, &value[1..]),
+        Some(b'<') => (false, &value[1..]),
+        _ => return None,
+    };
+    let split = rest.find(|c: char| !c.is_ascii_digit())?;
+    let (digits, unit) = rest.split_at(split);
+    let n: i64 = digits.parse().ok()?;
+    let duration = match unit.to_lowercase().as_str() {
+        "m" => Duration::try_minutes(n),
+        "h" => Duration::try_hours(n),
+        "d" => Duration::try_days(n),
+        "w" => Duration::try_weeks(n),
+        _ => return None,
+    }?;
+    Some((older, duration))
+}
+
+impl FromStr for AgePredicate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_age(s)
+            .map(|(older, duration)| Self { older, duration })
+            .ok_or_else(|| format!("`{s}` is not an age like >3d or <2h"))
+    }
+}
+
+impl TryFrom<String> for AgePredicate {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for AgePredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = if self.older { '>' } else { '<' };
+        let minutes = self.duration.num_minutes();
+        let (n, unit) = if minutes % (7 * 24 * 60) == 0 && minutes != 0 {
+            (minutes / (7 * 24 * 60), 'w')
+        } else if minutes % (24 * 60) == 0 && minutes != 0 {
+            (minutes / (24 * 60), 'd')
+        } else if minutes % 60 == 0 && minutes != 0 {
+            (minutes / 60, 'h')
+        } else {
+            (minutes, 'm')
+        };
+        write!(f, "{op}{n}{unit}")
+    }
+}
+
+impl From<AgePredicate> for String {
+    fn from(age: AgePredicate) -> Self {
+        age.to_string()
+    }
+}
+
+/// Count threshold: `">500"` or `"<10"`. A session whose count is unknown
+/// never matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CountPredicate {
+    pub greater: bool,
+    pub value: u64,
+}
+
+impl CountPredicate {
+    fn matches(self, count: Option<u64>) -> bool {
+        match count {
+            Some(n) if self.greater => n > self.value,
+            Some(n) => n < self.value,
+            None => false,
+        }
+    }
+}
+
+impl FromStr for CountPredicate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let bad = || format!("`{s}` is not a threshold like >500 or <10");
+        let (greater, rest) = match s.as_bytes().first() {
+            Some(b'>') => (true, &s[1..]),
+            Some(b'<') => (false, &s[1..]),
+            _ => return Err(bad()),
+        };
+        let value = rest.trim().parse().map_err(|_| bad())?;
+        Ok(Self { greater, value })
+    }
+}
+
+impl TryFrom<String> for CountPredicate {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for CountPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", if self.greater { '>' } else { '<' }, self.value)
+    }
+}
+
+impl From<CountPredicate> for String {
+    fn from(count: CountPredicate) -> Self {
+        count.to_string()
+    }
+}
 
 /// Label predicate: accepts either a single label (string in TOML) or a list
 /// (array of strings, any-of semantics).
@@ -110,6 +593,15 @@ impl LabelPredicate {
             Self::Any(needles) => needles.iter().any(|n| labels.iter().any(|l| l == n)),
         }
     }
+
+    /// Like [`Self::matches`], but normalises the needles the way session
+    /// tags are stored (see [`normalize_tag`]).
+    fn matches_normalized(&self, tags: &[String]) -> bool {
+        match self {
+            Self::One(needle) => tags.contains(&normalize_tag(needle)),
+            Self::Any(needles) => needles.iter().any(|n| tags.contains(&normalize_tag(n))),
+        }
+    }
 }
 
 /// Result of assigning a session to a section.
@@ -137,10 +629,18 @@ pub enum SectionAssignment {
 /// 3. Otherwise scan `sections[start..]` and return the first predicate match,
 ///    where `start` is the current section's config index (or 0 if the
 ///    session has no current section, its current section is predicate-less,
-///    or its current section no longer exists).
+///    or its current section no longer exists). A volatile current section
+///    (one reading agent state, unread, inactivity, age, diffstat, CI checks
+///    or tags) that no longer matches releases the session: the scan restarts
+///    at 0.
 /// 4. If nothing matches in that range, stay where we were. If `current_section`
-///    doesn't exist in the config, fall to [`SectionAssignment::InProgress`].
-pub fn assign_section(session: &WorktreeSession, sections: &[SectionConfig]) -> SectionAssignment {
+///    doesn't exist in the config, or released the session, fall to
+///    [`SectionAssignment::InProgress`].
+pub fn assign_section(
+    session: &WorktreeSession,
+    sections: &[SectionConfig],
+    signals: SectionSignals,
+) -> SectionAssignment {
     if let Some(name) = &session.section_override {
         if name == IN_PROGRESS {
             return SectionAssignment::InProgress;
@@ -150,21 +650,26 @@ pub fn assign_section(session: &WorktreeSession, sections: &[SectionConfig]) ->
         }
     }
 
-    let start = session
+    let current = session
         .current_section
         .as_deref()
-        .and_then(|n| sections.iter().position(|s| s.name == n))
-        .filter(|&i| has_predicates(&sections[i]))
+        .and_then(|n| sections.iter().position(|s| s.name == n));
+    let released = current.is_some_and(|i| {
+        let section = &sections[i];
+        section.is_volatile() && !section.matcher.matches(session, signals)
+    });
+    let start = current
+        .filter(|&i| has_predicates(&sections[i]) && !released)
         .unwrap_or(0);
 
     for section in &sections[start..] {
-        if has_predicates(section) && section_matches(session, section) {
+        if has_predicates(section) && section.matcher.matches(session, signals) {
             return SectionAssignment::Matched(section.name.clone());
         }
     }
 
     match &session.current_section {
-        Some(name) if sections.iter().any(|s| &s.name == name) => {
+        Some(name) if !released && sections.iter().any(|s| &s.name == name) => {
             SectionAssignment::Matched(name.clone())
         }
         _ => SectionAssignment::InProgress,
@@ -174,12 +679,7 @@ pub fn assign_section(session: &WorktreeSession, sections: &[SectionConfig]) ->
 /// True when a section declares at least one predicate field; otherwise the
 /// section is a manual-only waypoint (reachable only via override).
 fn has_predicates(section: &SectionConfig) -> bool {
-    section.pr_state.is_some()
-        || section.is_draft.is_some()
-        || section.has_label.is_some()
-        || section.has_pr.is_some()
-        || section.review_decision.is_some()
-        || section.has_reviewer.is_some()
+    !section.matcher.is_empty()
 }
 
 /// Reserved name of the implicit catch-all section, always at process
@@ -197,12 +697,18 @@ pub fn default_board_sections() -> Vec<SectionConfig> {
     vec![
         SectionConfig {
             name: "In Review".to_string(),
-            pr_state: Some(StatePredicate::One(PrState::Open)),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Open)),
+                ..Default::default()
+            },
             ..Default::default()
         },
         SectionConfig {
             name: "Merged".to_string(),
-            pr_state: Some(StatePredicate::One(PrState::Merged)),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Merged)),
+                ..Default::default()
+            },
             ..Default::default()
         },
     ]
@@ -295,20 +801,39 @@ pub fn effective_sections(configured: &[SectionConfig]) -> Cow<'_, [SectionConfi
 pub fn apply_assignment(
     session: &mut WorktreeSession,
     sections: &[SectionConfig],
-    now: DateTime<Utc>,
+    signals: SectionSignals,
 ) -> bool {
-    let new_name: Option<String> = match assign_section(session, sections) {
-        SectionAssignment::Matched(name) => Some(name),
-        SectionAssignment::InProgress => None,
-    };
+    let new_name = assigned_name(session, sections, signals);
     if session.current_section == new_name {
         return false;
     }
     session.current_section = new_name;
-    session.entered_section_at = now;
+    session.entered_section_at = signals.now;
     true
 }
 
+/// Whether [`apply_assignment`] would move the session, without mutating it.
+/// Lets a caller holding only a read lock skip a store write when nothing
+/// would change.
+pub fn assignment_would_change(
+    session: &WorktreeSession,
+    sections: &[SectionConfig],
+    signals: SectionSignals,
+) -> bool {
+    session.current_section != assigned_name(session, sections, signals)
+}
+
+fn assigned_name(
+    session: &WorktreeSession,
+    sections: &[SectionConfig],
+    signals: SectionSignals,
+) -> Option<String> {
+    match assign_section(session, sections, signals) {
+        SectionAssignment::Matched(name) => Some(name),
+        SectionAssignment::InProgress => None,
+    }
+}
+
 /// User-initiated "Auto" / clear-override action from the section picker.
 ///
 /// Unlike [`apply_assignment`] — which is forward-only because the background
@@ -324,13 +849,14 @@ pub fn apply_assignment(
 pub fn clear_override_and_reassign(
     session: &mut WorktreeSession,
     sections: &[SectionConfig],
-    now: DateTime<Utc>,
+    signals: SectionSignals,
 ) -> bool {
+    let now = signals.now;
     let had_override = session.section_override.is_some();
     let prior_section = session.current_section.clone();
     session.section_override = None;
     session.current_section = None;
-    apply_assignment(session, sections, now);
+    apply_assignment(session, sections, signals);
     let changed = had_override || session.current_section != prior_section;
     if changed {
         // `apply_assignment` only stamps on section-name change. When the
@@ -420,40 +946,6 @@ pub fn rename_section(session: &mut WorktreeSession, old: &str, new: &str) -> bo
     touched
 }
 
-fn section_matches(session: &WorktreeSession, section: &SectionConfig) -> bool {
-    if let Some(state_pred) = &section.pr_state
-        && !state_pred.matches(session.pr_state)
-    {
-        return false;
-    }
-    if let Some(required) = section.is_draft
-        && session.pr_draft != required
-    {
-        return false;
-    }
-    if let Some(label_pred) = &section.has_label
-        && !label_pred.matches(&session.pr_labels)
-    {
-        return false;
-    }
-    if let Some(required) = section.has_pr
-        && session.pr_number.is_some() != required
-    {
-        return false;
-    }
-    if let Some(decision_pred) = &section.review_decision
-        && !decision_pred.matches(session.review_decision)
-    {
-        return false;
-    }
-    if let Some(reviewer_pred) = &section.has_reviewer
-        && !reviewer_pred.matches(&session.pr_reviewers)
-    {
-        return false;
-    }
-    true
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -461,6 +953,10 @@ mod tests {
     use chrono::{Duration, Utc};
     use std::path::PathBuf;
 
+    fn signals() -> SectionSignals {
+        SectionSignals::at(Utc::now())
+    }
+
     fn make_session() -> WorktreeSession {
         WorktreeSession::new(
             ProjectId::new(),
@@ -478,15 +974,18 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "In Review".into(),
-            review_decision: Some(DecisionPredicate::Any(vec![
-                ReviewDecision::ChangesRequested,
-                ReviewDecision::ReviewRequired,
-            ])),
+            matcher: SectionMatch {
+                review_decision: Some(DecisionPredicate::Any(vec![
+                    ReviewDecision::ChangesRequested,
+                    ReviewDecision::ReviewRequired,
+                ])),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("In Review".into())
         );
     }
@@ -497,12 +996,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Approved".into(),
-            review_decision: Some(DecisionPredicate::One(ReviewDecision::Approved)),
+            matcher: SectionMatch {
+                review_decision: Some(DecisionPredicate::One(ReviewDecision::Approved)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::InProgress
         );
     }
@@ -515,12 +1017,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Ready to Merge".into(),
-            review_decision: Some(DecisionPredicate::One(ReviewDecision::Approved)),
+            matcher: SectionMatch {
+                review_decision: Some(DecisionPredicate::One(ReviewDecision::Approved)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("Ready to Merge".into())
         );
     }
@@ -530,7 +1035,7 @@ mod tests {
         let session = make_session();
         let sections: Vec<SectionConfig> = vec![];
 
-        let result = assign_section(&session, &sections);
+        let result = assign_section(&session, &sections, signals());
 
         assert_eq!(result, SectionAssignment::InProgress);
     }
@@ -542,12 +1047,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Merged".into(),
-            pr_state: Some(StatePredicate::One(PrState::Merged)),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Merged)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::InProgress
         );
     }
@@ -560,12 +1068,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Drafts".into(),
-            is_draft: Some(true),
+            matcher: SectionMatch {
+                is_draft: Some(true),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("Drafts".into())
         );
     }
@@ -578,13 +1089,16 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Open drafts".into(),
-            pr_state: Some(StatePredicate::One(PrState::Open)),
-            is_draft: Some(true),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Open)),
+                is_draft: Some(true),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::InProgress
         );
     }
@@ -596,12 +1110,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Needs review".into(),
-            has_label: Some(LabelPredicate::One("ready-for-review".into())),
+            matcher: SectionMatch {
+                has_label: Some(LabelPredicate::One("ready-for-review".into())),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("Needs review".into())
         );
     }
@@ -613,12 +1130,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Needs review".into(),
-            has_label: Some(LabelPredicate::One("ready-for-review".into())),
+            matcher: SectionMatch {
+                has_label: Some(LabelPredicate::One("ready-for-review".into())),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::InProgress
         );
     }
@@ -630,15 +1150,18 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Blocked".into(),
-            has_label: Some(LabelPredicate::Any(vec![
-                "blocked".into(),
-                "waiting-on-author".into(),
-            ])),
+            matcher: SectionMatch {
+                has_label: Some(LabelPredicate::Any(vec![
+                    "blocked".into(),
+                    "waiting-on-author".into(),
+                ])),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("Blocked".into())
         );
     }
@@ -650,7 +1173,10 @@ mod tests {
         // predicate is a valid forward move.
         let sections = vec![SectionConfig {
             name: "Open".into(),
-            pr_state: Some(StatePredicate::One(PrState::Open)),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Open)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
         let mut session = make_session();
@@ -658,7 +1184,7 @@ mod tests {
         session.current_section = Some("Removed Section".into());
 
         let now = session.entered_section_at + Duration::minutes(1);
-        let changed = apply_assignment(&mut session, &sections, now);
+        let changed = apply_assignment(&mut session, &sections, SectionSignals::at(now));
 
         assert!(changed);
         assert_eq!(session.current_section.as_deref(), Some("Open"));
@@ -674,12 +1200,18 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Open".into(),
-                pr_state: Some(StatePredicate::One(PrState::Open)),
+                matcher: SectionMatch {
+                    pr_state: Some(StatePredicate::One(PrState::Open)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
                 name: "In Review".into(),
-                review_decision: Some(DecisionPredicate::One(ReviewDecision::ChangesRequested)),
+                matcher: SectionMatch {
+                    review_decision: Some(DecisionPredicate::One(ReviewDecision::ChangesRequested)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
         ];
@@ -689,7 +1221,7 @@ mod tests {
         session.current_section = Some("In Review".into());
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("In Review".into())
         );
     }
@@ -706,14 +1238,17 @@ mod tests {
             },
             SectionConfig {
                 name: "Needs Review".into(),
-                has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                matcher: SectionMatch {
+                    has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
         ];
         let session = make_session(); // no PR data
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::InProgress
         );
     }
@@ -728,7 +1263,7 @@ mod tests {
         session.section_override = Some("Stale".into());
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("Stale".into())
         );
     }
@@ -742,7 +1277,10 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Needs Review".into(),
-                has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                matcher: SectionMatch {
+                    has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
@@ -758,7 +1296,7 @@ mod tests {
         let later = original + Duration::hours(1);
 
         session.section_override = None;
-        let changed = apply_assignment(&mut session, &sections, later);
+        let changed = apply_assignment(&mut session, &sections, SectionSignals::at(later));
 
         assert!(changed);
         assert_eq!(session.current_section.as_deref(), Some("Needs Review"));
@@ -771,7 +1309,10 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Open".into(),
-                pr_state: Some(StatePredicate::One(PrState::Open)),
+                matcher: SectionMatch {
+                    pr_state: Some(StatePredicate::One(PrState::Open)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
@@ -787,7 +1328,7 @@ mod tests {
 
         // User pins backward to "Open" (backward in process order).
         session.section_override = Some("Open".into());
-        let changed = apply_assignment(&mut session, &sections, later);
+        let changed = apply_assignment(&mut session, &sections, SectionSignals::at(later));
 
         assert!(changed);
         assert_eq!(session.current_section.as_deref(), Some("Open"));
@@ -799,12 +1340,18 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Needs Review".into(),
-                has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                matcher: SectionMatch {
+                    has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
                 name: "In Review".into(),
-                review_decision: Some(DecisionPredicate::One(ReviewDecision::ChangesRequested)),
+                matcher: SectionMatch {
+                    review_decision: Some(DecisionPredicate::One(ReviewDecision::ChangesRequested)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
         ];
@@ -819,7 +1366,7 @@ mod tests {
         session.review_decision = Some(ReviewDecision::ChangesRequested);
 
         let later = original + Duration::hours(1);
-        let changed = apply_assignment(&mut session, &sections, later);
+        let changed = apply_assignment(&mut session, &sections, SectionSignals::at(later));
 
         assert!(!changed, "auto must not advance past an override");
         assert_eq!(session.current_section.as_deref(), Some("Needs Review"));
@@ -832,12 +1379,18 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Needs Review".into(),
-                has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                matcher: SectionMatch {
+                    has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
                 name: "In Review".into(),
-                review_decision: Some(DecisionPredicate::One(ReviewDecision::ChangesRequested)),
+                matcher: SectionMatch {
+                    review_decision: Some(DecisionPredicate::One(ReviewDecision::ChangesRequested)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
         ];
@@ -854,7 +1407,7 @@ mod tests {
         session.pr_labels.clear();
 
         let later = original_stamp + Duration::hours(1);
-        let changed = apply_assignment(&mut session, &sections, later);
+        let changed = apply_assignment(&mut session, &sections, SectionSignals::at(later));
 
         assert!(!changed, "auto move backward should be refused");
         assert_eq!(
@@ -870,12 +1423,18 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Needs Review".into(),
-                has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                matcher: SectionMatch {
+                    has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
                 name: "In Review".into(),
-                review_decision: Some(DecisionPredicate::One(ReviewDecision::ChangesRequested)),
+                matcher: SectionMatch {
+                    review_decision: Some(DecisionPredicate::One(ReviewDecision::ChangesRequested)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
         ];
@@ -891,7 +1450,7 @@ mod tests {
         session.review_decision = Some(ReviewDecision::ChangesRequested);
 
         let later = original_stamp + Duration::hours(1);
-        let changed = apply_assignment(&mut session, &sections, later);
+        let changed = apply_assignment(&mut session, &sections, SectionSignals::at(later));
 
         assert!(changed);
         assert_eq!(session.current_section.as_deref(), Some("In Review"));
@@ -905,12 +1464,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Has PR".into(),
-            has_pr: Some(true),
+            matcher: SectionMatch {
+                has_pr: Some(true),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("Has PR".into())
         );
     }
@@ -921,12 +1483,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "No PR".into(),
-            has_pr: Some(false),
+            matcher: SectionMatch {
+                has_pr: Some(false),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("No PR".into())
         );
     }
@@ -940,18 +1505,24 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Needs review".into(),
-                has_label: Some(LabelPredicate::One("ready-for-review".into())),
+                matcher: SectionMatch {
+                    has_label: Some(LabelPredicate::One("ready-for-review".into())),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
                 name: "Open".into(),
-                pr_state: Some(StatePredicate::One(PrState::Open)),
+                matcher: SectionMatch {
+                    pr_state: Some(StatePredicate::One(PrState::Open)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
         ];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("Needs review".into())
         );
     }
@@ -965,7 +1536,10 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Open".into(),
-                pr_state: Some(StatePredicate::One(PrState::Open)),
+                matcher: SectionMatch {
+                    pr_state: Some(StatePredicate::One(PrState::Open)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
@@ -975,7 +1549,7 @@ mod tests {
         ];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("In progress".into())
         );
     }
@@ -991,12 +1565,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Open".into(),
-            pr_state: Some(StatePredicate::One(PrState::Open)),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Open)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::InProgress
         );
     }
@@ -1009,12 +1586,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Open".into(),
-            pr_state: Some(StatePredicate::One(PrState::Open)),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Open)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("Open".into())
         );
     }
@@ -1031,18 +1611,27 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Needs Review".into(),
-                has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                matcher: SectionMatch {
+                    has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
                 name: "In Review".into(),
-                pr_state: Some(StatePredicate::One(PrState::Open)),
-                has_reviewer: Some(ReviewerPredicate::Bool(true)),
+                matcher: SectionMatch {
+                    pr_state: Some(StatePredicate::One(PrState::Open)),
+                    has_reviewer: Some(ReviewerPredicate::Bool(true)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
                 name: "Drafts".into(),
-                is_draft: Some(true),
+                matcher: SectionMatch {
+                    is_draft: Some(true),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
         ];
@@ -1055,7 +1644,7 @@ mod tests {
         let original_stamp = session.entered_section_at;
         let now = original_stamp + Duration::minutes(1);
 
-        let changed = clear_override_and_reassign(&mut session, &sections, now);
+        let changed = clear_override_and_reassign(&mut session, &sections, SectionSignals::at(now));
 
         assert!(changed, "session should leave Drafts after Auto");
         assert!(
@@ -1076,12 +1665,18 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Open".into(),
-                pr_state: Some(StatePredicate::One(PrState::Open)),
+                matcher: SectionMatch {
+                    pr_state: Some(StatePredicate::One(PrState::Open)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
                 name: "Drafts".into(),
-                is_draft: Some(true),
+                matcher: SectionMatch {
+                    is_draft: Some(true),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
         ];
@@ -1092,7 +1687,7 @@ mod tests {
         session.current_section = Some("Drafts".into());
         let now = session.entered_section_at + Duration::minutes(1);
 
-        let changed = clear_override_and_reassign(&mut session, &sections, now);
+        let changed = clear_override_and_reassign(&mut session, &sections, SectionSignals::at(now));
 
         assert!(changed);
         assert_eq!(session.section_override, None);
@@ -1108,11 +1703,14 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Open".into(),
-            pr_state: Some(StatePredicate::One(PrState::Open)),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Open)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
-        let changed = apply_assignment(&mut session, &sections, now);
+        let changed = apply_assignment(&mut session, &sections, SectionSignals::at(now));
 
         assert!(changed);
         assert_eq!(session.current_section.as_deref(), Some("Open"));
@@ -1128,11 +1726,18 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Open".into(),
-            pr_state: Some(StatePredicate::One(PrState::Open)),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Open)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
-        let changed = apply_assignment(&mut session, &sections, Utc::now() + Duration::hours(1));
+        let changed = apply_assignment(
+            &mut session,
+            &sections,
+            SectionSignals::at(Utc::now() + Duration::hours(1)),
+        );
 
         assert!(!changed);
         assert_eq!(session.entered_section_at, original);
@@ -1157,7 +1762,10 @@ mod tests {
         let sessions = vec![newer.clone(), older.clone()];
         let sections = vec![SectionConfig {
             name: "Open".into(),
-            pr_state: Some(StatePredicate::One(PrState::Open)),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Open)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
@@ -1187,12 +1795,18 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Drafts".into(),
-                is_draft: Some(true),
+                matcher: SectionMatch {
+                    is_draft: Some(true),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
                 name: "Open".into(),
-                pr_state: Some(StatePredicate::One(PrState::Open)),
+                matcher: SectionMatch {
+                    pr_state: Some(StatePredicate::One(PrState::Open)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
         ];
@@ -1216,7 +1830,10 @@ mod tests {
         // the session there too, not re-evaluate predicates.
         let sections = vec![SectionConfig {
             name: "Needs Review".into(),
-            has_label: Some(LabelPredicate::One("dev-review-required".into())),
+            matcher: SectionMatch {
+                has_label: Some(LabelPredicate::One("dev-review-required".into())),
+                ..Default::default()
+            },
             ..Default::default()
         }];
         let mut session = make_session();
@@ -1236,7 +1853,10 @@ mod tests {
     fn in_progress_catchall_is_first() {
         let sections = vec![SectionConfig {
             name: "Open".into(),
-            pr_state: Some(StatePredicate::One(PrState::Open)),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Open)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
@@ -1256,7 +1876,10 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Open".into(),
-                pr_state: Some(StatePredicate::One(PrState::Open)),
+                matcher: SectionMatch {
+                    pr_state: Some(StatePredicate::One(PrState::Open)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
@@ -1267,7 +1890,7 @@ mod tests {
 
         // User pins to "In progress".
         session.section_override = Some("In progress".into());
-        let changed = apply_assignment(&mut session, &sections, now);
+        let changed = apply_assignment(&mut session, &sections, SectionSignals::at(now));
 
         assert!(changed);
         assert_eq!(session.current_section.as_deref(), Some("In progress"));
@@ -1285,22 +1908,63 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Done".into(),
-            pr_state: Some(StatePredicate::Any(vec![PrState::Merged, PrState::Closed])),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::Any(vec![PrState::Merged, PrState::Closed])),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&merged_session, &sections),
+            assign_section(&merged_session, &sections, signals()),
             SectionAssignment::Matched("Done".into())
         );
         assert_eq!(
-            assign_section(&closed_session, &sections),
+            assign_section(&closed_session, &sections, signals()),
             SectionAssignment::Matched("Done".into())
         );
         assert_eq!(
-            assign_section(&open_session, &sections),
+            assign_section(&open_session, &sections, signals()),
+            SectionAssignment::InProgress
+        );
+    }
+
+    #[test]
+    fn task_status_predicate_matches_the_task_summary() {
+        let failing = vec![SectionConfig {
+            name: "Broken".into(),
+            matcher: SectionMatch {
+                task_status: Some(TaskPredicate::One(TaskStatus::Failed)),
+                ..Default::default()
+            },
+            ..Default::default()
+        }];
+        let mut session = make_session();
+        // No task has run: nothing to match.
+        assert_eq!(
+            assign_section(&session, &failing, signals()),
+            SectionAssignment::InProgress
+        );
+
+        let run = |name: &str, status| crate::session::TaskRun {
+            name: name.into(),
+            command: "true".into(),
+            status,
+            exit_code: None,
+            started_at: Utc::now(),
+            finished_at: None,
+            output_tail: String::new(),
+        };
+        session.record_task_run(run("lint", TaskStatus::Passed));
+        assert_eq!(
+            assign_section(&session, &failing, signals()),
             SectionAssignment::InProgress
         );
+        session.record_task_run(run("test", TaskStatus::Failed));
+        assert_eq!(
+            assign_section(&session, &failing, signals()),
+            SectionAssignment::Matched("Broken".into())
+        );
     }
 
     #[test]
@@ -1310,12 +1974,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "In Review".into(),
-            has_reviewer: Some(ReviewerPredicate::Bool(true)),
+            matcher: SectionMatch {
+                has_reviewer: Some(ReviewerPredicate::Bool(true)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("In Review".into())
         );
     }
@@ -1330,12 +1997,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "In Review".into(),
-            has_reviewer: Some(ReviewerPredicate::Bool(true)),
+            matcher: SectionMatch {
+                has_reviewer: Some(ReviewerPredicate::Bool(true)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::InProgress
         );
     }
@@ -1347,12 +2017,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Alice's".into(),
-            has_reviewer: Some(ReviewerPredicate::One("alice".into())),
+            matcher: SectionMatch {
+                has_reviewer: Some(ReviewerPredicate::One("alice".into())),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("Alice's".into())
         );
     }
@@ -1364,12 +2037,15 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Team".into(),
-            has_reviewer: Some(ReviewerPredicate::Any(vec!["alice".into(), "bob".into()])),
+            matcher: SectionMatch {
+                has_reviewer: Some(ReviewerPredicate::Any(vec!["alice".into(), "bob".into()])),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("Team".into())
         );
     }
@@ -1402,12 +2078,18 @@ mod tests {
         let sections = vec![
             SectionConfig {
                 name: "Drafts".into(),
-                is_draft: Some(true),
+                matcher: SectionMatch {
+                    is_draft: Some(true),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
                 name: "In Review".into(),
-                has_reviewer: Some(ReviewerPredicate::Bool(true)),
+                matcher: SectionMatch {
+                    has_reviewer: Some(ReviewerPredicate::Bool(true)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
         ];
@@ -1423,12 +2105,20 @@ mod tests {
 
         // Forward-only scan keeps it in Drafts while nothing later matches...
         let later = now + Duration::minutes(1);
-        assert!(!apply_assignment(&mut session, &sections, later));
+        assert!(!apply_assignment(
+            &mut session,
+            &sections,
+            SectionSignals::at(later)
+        ));
         assert_eq!(session.current_section.as_deref(), Some("Drafts"));
 
         // ...but it still advances once a later section's predicate matches.
         session.pr_reviewers = vec!["alice".into()];
-        assert!(apply_assignment(&mut session, &sections, later));
+        assert!(apply_assignment(
+            &mut session,
+            &sections,
+            SectionSignals::at(later)
+        ));
         assert_eq!(session.current_section.as_deref(), Some("In Review"));
     }
 
@@ -1439,14 +2129,21 @@ mod tests {
         // bounce it out.
         let sections = vec![SectionConfig {
             name: "Drafts".into(),
-            is_draft: Some(true),
+            matcher: SectionMatch {
+                is_draft: Some(true),
+                ..Default::default()
+            },
             ..Default::default()
         }];
         let mut session = make_session(); // no PR at all
         let now = session.entered_section_at + Duration::minutes(1);
 
         place_created_session(&mut session, "Drafts", &sections, now);
-        let changed = apply_assignment(&mut session, &sections, now + Duration::minutes(1));
+        let changed = apply_assignment(
+            &mut session,
+            &sections,
+            SectionSignals::at(now + Duration::minutes(1)),
+        );
 
         assert!(!changed);
         assert_eq!(session.current_section.as_deref(), Some("Drafts"));
@@ -1456,7 +2153,10 @@ mod tests {
     fn place_created_session_ignores_in_progress_and_unknown_names() {
         let sections = vec![SectionConfig {
             name: "Open".into(),
-            pr_state: Some(StatePredicate::One(PrState::Open)),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Open)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
         let mut session = make_session();
@@ -1508,7 +2208,7 @@ mod tests {
         let sections = default_board_sections();
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("In Review".into())
         );
     }
@@ -1532,7 +2232,11 @@ mod tests {
         rename_section(&mut session, "Self Review", "Reviewing");
 
         // The TUI reconciles every session against the new config.
-        apply_assignment(&mut session, &sections, placed_at + Duration::minutes(1));
+        apply_assignment(
+            &mut session,
+            &sections,
+            SectionSignals::at(placed_at + Duration::minutes(1)),
+        );
 
         let groups = build_sections(&[session.clone()], &sections);
         let reviewing = groups
@@ -1556,12 +2260,18 @@ mod tests {
         let mut sections = vec![
             SectionConfig {
                 name: "Open".into(),
-                pr_state: Some(StatePredicate::One(PrState::Open)),
+                matcher: SectionMatch {
+                    pr_state: Some(StatePredicate::One(PrState::Open)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
             SectionConfig {
                 name: "In Review".into(),
-                review_decision: Some(DecisionPredicate::One(ReviewDecision::ChangesRequested)),
+                matcher: SectionMatch {
+                    review_decision: Some(DecisionPredicate::One(ReviewDecision::ChangesRequested)),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
         ];
@@ -1574,7 +2284,7 @@ mod tests {
         rename_section(&mut session, "In Review", "Reviewing");
 
         let later = session.entered_section_at + Duration::hours(1);
-        apply_assignment(&mut session, &sections, later);
+        apply_assignment(&mut session, &sections, SectionSignals::at(later));
 
         assert_eq!(
             session.current_section.as_deref(),
@@ -1591,7 +2301,7 @@ mod tests {
         let sections = default_board_sections();
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::Matched("Merged".into())
         );
     }
@@ -1638,7 +2348,7 @@ mod tests {
         let sections = default_board_sections();
 
         assert_eq!(
-            assign_section(&session, &sections),
+            assign_section(&session, &sections, signals()),
             SectionAssignment::InProgress
         );
     }
@@ -1661,12 +2371,438 @@ mod tests {
 
         let sections = vec![SectionConfig {
             name: "Open PRs".into(),
-            pr_state: Some(StatePredicate::One(PrState::Open)),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Open)),
+                ..Default::default()
+            },
             ..Default::default()
         }];
 
-        let result = assign_section(&session, &sections);
+        let result = assign_section(&session, &sections, signals());
 
         assert_eq!(result, SectionAssignment::Matched("Open PRs".into()));
     }
+
+    fn needs_me() -> SectionConfig {
+        SectionConfig {
+            name: "Needs me".into(),
+            matcher: SectionMatch {
+                any: vec![
+                    SectionMatch {
+                        agent_state: Some(OneOrMany::One(AgentState::WaitingForInput)),
+                        ..Default::default()
+                    },
+                    SectionMatch {
+                        unread: Some(true),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn with_state(state: AgentState) -> SectionSignals {
+        SectionSignals {
+            agent_state: Some(state),
+            now: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn any_group_matches_waiting_or_unread() {
+        let sections = vec![needs_me()];
+        let mut session = make_session();
+
+        assert_eq!(
+            assign_section(&session, &sections, with_state(AgentState::WaitingForInput)),
+            SectionAssignment::Matched("Needs me".into())
+        );
+        assert_eq!(
+            assign_section(&session, &sections, with_state(AgentState::Working)),
+            SectionAssignment::InProgress
+        );
+
+        session.unread = true;
+        assert_eq!(
+            assign_section(&session, &sections, with_state(AgentState::Idle)),
+            SectionAssignment::Matched("Needs me".into())
+        );
+    }
+
+    #[test]
+    fn unknown_agent_state_never_matches_a_state_predicate() {
+        let sections = vec![needs_me()];
+        let session = make_session();
+
+        assert_eq!(
+            assign_section(&session, &sections, signals()),
+            SectionAssignment::InProgress
+        );
+    }
+
+    #[test]
+    fn volatile_section_releases_the_session_once_it_stops_matching() {
+        // Forward-only would pin a session in "Needs me" forever; a section
+        // reading live signals lets go once its predicate fails.
+        let sections = vec![needs_me()];
+        let mut session = make_session();
+        assert!(apply_assignment(
+            &mut session,
+            &sections,
+            with_state(AgentState::WaitingForInput)
+        ));
+        assert_eq!(session.current_section.as_deref(), Some("Needs me"));
+
+        assert!(apply_assignment(
+            &mut session,
+            &sections,
+            with_state(AgentState::Working)
+        ));
+        assert_eq!(session.current_section, None);
+    }
+
+    #[test]
+    fn released_session_rescans_earlier_sections() {
+        let sections = vec![
+            SectionConfig {
+                name: "Open".into(),
+                matcher: SectionMatch {
+                    pr_state: Some(StatePredicate::One(PrState::Open)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            SectionConfig {
+                name: "Failing CI".into(),
+                matcher: SectionMatch {
+                    checks: Some(OneOrMany::One(ChecksStatus::Failing)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        ];
+        let mut session = make_session();
+        session.pr_state = Some(PrState::Open);
+        session.current_section = Some("Failing CI".into());
+        session.pr_checks = Some(ChecksStatus::Passing);
+
+        assert!(apply_assignment(&mut session, &sections, signals()));
+        assert_eq!(session.current_section.as_deref(), Some("Open"));
+    }
+
+    #[test]
+    fn pr_sections_stay_forward_only() {
+        // A PR-state section is not volatile: the session stays put even
+        // though it no longer matches.
+        let sections = vec![SectionConfig {
+            name: "Open".into(),
+            matcher: SectionMatch {
+                pr_state: Some(StatePredicate::One(PrState::Open)),
+                ..Default::default()
+            },
+            ..Default::default()
+        }];
+        let mut session = make_session();
+        session.current_section = Some("Open".into());
+        session.pr_state = Some(PrState::Closed);
+
+        assert!(!apply_assignment(&mut session, &sections, signals()));
+        assert_eq!(session.current_section.as_deref(), Some("Open"));
+    }
+
+    #[test]
+    fn age_predicates_compare_against_the_evaluation_time() {
+        let now = Utc::now();
+        let sections = vec![
+            SectionConfig {
+                name: "Stale".into(),
+                matcher: SectionMatch {
+                    inactive_for: Some(">3d".parse().unwrap()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            SectionConfig {
+                name: "Fresh".into(),
+                matcher: SectionMatch {
+                    age: Some("<2h".parse().unwrap()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        ];
+        let mut session = make_session();
+        session.created_at = now - Duration::days(10);
+        session.last_active_at = now - Duration::days(4);
+        assert_eq!(
+            assign_section(&session, &sections, SectionSignals::at(now)),
+            SectionAssignment::Matched("Stale".into())
+        );
+
+        session.last_active_at = now - Duration::hours(1);
+        assert_eq!(
+            assign_section(&session, &sections, SectionSignals::at(now)),
+            SectionAssignment::InProgress
+        );
+
+        session.created_at = now - Duration::minutes(30);
+        assert_eq!(
+            assign_section(&session, &sections, SectionSignals::at(now)),
+            SectionAssignment::Matched("Fresh".into())
+        );
+    }
+
+    #[test]
+    fn oversized_age_is_rejected_rather_than_panicking() {
+        assert!(">9999999999999999d".parse::<AgePredicate>().is_err());
+        assert!("<99999999999999999m".parse::<AgePredicate>().is_err());
+        assert!(
+            toml::from_str::<SectionConfig>("name = \"x\"\nage = \">9999999999999999d\"\n")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn session_ages_out_of_an_age_section() {
+        let sections = vec![SectionConfig {
+            name: "Fresh".into(),
+            matcher: SectionMatch {
+                age: Some("<2h".parse().unwrap()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }];
+        let now = Utc::now();
+        let mut session = make_session();
+        session.created_at = now - Duration::minutes(30);
+        assert!(apply_assignment(
+            &mut session,
+            &sections,
+            SectionSignals::at(now)
+        ));
+        assert_eq!(session.current_section.as_deref(), Some("Fresh"));
+
+        assert!(apply_assignment(
+            &mut session,
+            &sections,
+            SectionSignals::at(now + Duration::hours(3))
+        ));
+        assert_eq!(session.current_section, None);
+    }
+
+    #[test]
+    fn program_agent_branch_and_title_globs() {
+        let mut session = make_session();
+        session.program = "codex --model o3".into();
+        session.branch = "exp/parser".into();
+        session.title = "Parser spike".into();
+
+        let section = |f: fn(&mut SectionConfig)| {
+            let mut s = SectionConfig {
+                name: "S".into(),
+                ..Default::default()
+            };
+            f(&mut s);
+            vec![s]
+        };
+        let matched = SectionAssignment::Matched("S".into());
+
+        let by_agent = section(|s| s.matcher.agent = Some(OneOrMany::One(AgentKind::Codex)));
+        assert_eq!(assign_section(&session, &by_agent, signals()), matched);
+        let by_program = section(|s| s.matcher.program = Some(GlobPredicate::One("codex*".into())));
+        assert_eq!(assign_section(&session, &by_program, signals()), matched);
+        let by_branch = section(|s| {
+            s.matcher.branch = Some(GlobPredicate::Any(vec!["spike/*".into(), "exp/*".into()]))
+        });
+        assert_eq!(assign_section(&session, &by_branch, signals()), matched);
+        let by_title = section(|s| s.matcher.title = Some(GlobPredicate::One("*SPIKE".into())));
+        assert_eq!(assign_section(&session, &by_title, signals()), matched);
+        let wrong_agent = section(|s| s.matcher.agent = Some(OneOrMany::One(AgentKind::Claude)));
+        assert_eq!(
+            assign_section(&session, &wrong_agent, signals()),
+            SectionAssignment::InProgress
+        );
+    }
+
+    #[test]
+    fn has_tag_matches_normalised_tags_and_releases_when_removed() {
+        let sections = vec![SectionConfig {
+            name: "Blocked".into(),
+            matcher: SectionMatch {
+                has_tag: Some(LabelPredicate::Any(vec![
+                    "#Blocked".into(),
+                    "waiting on".into(),
+                ])),
+                ..Default::default()
+            },
+            ..Default::default()
+        }];
+        let mut session = make_session();
+        assert_eq!(
+            assign_section(&session, &sections, signals()),
+            SectionAssignment::InProgress
+        );
+
+        session.tags = vec!["waiting-on".into()];
+        assert_eq!(
+            assign_section(&session, &sections, signals()),
+            SectionAssignment::Matched("Blocked".into())
+        );
+
+        // Removing the tag releases the session instead of leaving it parked.
+        session.current_section = Some("Blocked".into());
+        session.tags.clear();
+        assert_eq!(
+            assign_section(&session, &sections, signals()),
+            SectionAssignment::InProgress
+        );
+    }
+
+    #[test]
+    fn glob_matching_rules() {
+        assert!(glob_matches("exp/*", "exp/a/b"));
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("fix-?", "FIX-1"));
+        assert!(!glob_matches("fix-?", "fix-12"));
+        assert!(!glob_matches("exp/*", "main"));
+        assert!(glob_matches("*a*b*", "xxaYYbzz"));
+    }
+
+    #[test]
+    fn diff_thresholds_need_a_known_diffstat() {
+        let sections = vec![SectionConfig {
+            name: "Big diffs".into(),
+            matcher: SectionMatch {
+                diff_lines: Some(">500".parse().unwrap()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }];
+        let mut session = make_session();
+        assert_eq!(
+            assign_section(&session, &sections, signals()),
+            SectionAssignment::InProgress,
+            "an unknown diffstat never matches"
+        );
+
+        session.diff_stat = Some(crate::api::DiffStat {
+            files_changed: 2,
+            lines_added: 400,
+            lines_removed: 101,
+        });
+        assert_eq!(
+            assign_section(&session, &sections, signals()),
+            SectionAssignment::Matched("Big diffs".into())
+        );
+
+        let few_files = vec![SectionConfig {
+            name: "Small".into(),
+            matcher: SectionMatch {
+                diff_files: Some("<2".parse().unwrap()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }];
+        assert_eq!(
+            assign_section(&session, &few_files, signals()),
+            SectionAssignment::InProgress
+        );
+    }
+
+    #[test]
+    fn not_group_negates_its_clauses() {
+        let sections = vec![SectionConfig {
+            name: "Not Claude".into(),
+            matcher: SectionMatch {
+                has_pr: Some(true),
+                not: Some(Box::new(SectionMatch {
+                    agent: Some(OneOrMany::One(AgentKind::Claude)),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            },
+            ..Default::default()
+        }];
+        let mut session = make_session();
+        session.pr_number = Some(1);
+        assert_eq!(
+            assign_section(&session, &sections, signals()),
+            SectionAssignment::InProgress
+        );
+
+        session.program = "opencode".into();
+        assert_eq!(
+            assign_section(&session, &sections, signals()),
+            SectionAssignment::Matched("Not Claude".into())
+        );
+    }
+
+    #[test]
+    fn new_predicates_parse_and_round_trip_through_toml() {
+        #[derive(Serialize, Deserialize)]
+        struct Doc {
+            sections: Vec<SectionConfig>,
+        }
+        let doc: Doc = toml::from_str(
+            r#"
+            [[sections]]
+            name = "Failing CI"
+            checks = ["failing", "pending"]
+            diff_lines = ">500"
+            inactive_for = ">3d"
+            agent = "codex"
+            branch = ["exp/*", "spike/*"]
+
+            [[sections.any]]
+            agent_state = "waiting"
+
+            [[sections.any]]
+            unread = true
+
+            [sections.not]
+            title = "*wip*"
+            "#,
+        )
+        .unwrap();
+        let section = &doc.sections[0];
+        assert_eq!(section.matcher.diff_lines.unwrap().to_string(), ">500");
+        assert_eq!(section.matcher.inactive_for.unwrap().to_string(), ">3d");
+        assert_eq!(section.matcher.any.len(), 2);
+        assert!(section.reads_live_signals());
+        assert!(section.reads_diff_stat());
+        assert!(section.reads_age());
+
+        let again: Doc = toml::from_str(&toml::to_string(&doc).unwrap()).unwrap();
+        let section = &again.sections[0];
+        assert_eq!(section.matcher.diff_lines.unwrap().to_string(), ">500");
+        assert_eq!(section.matcher.any.len(), 2);
+        assert!(section.matcher.not.is_some());
+        assert!(
+            toml::from_str::<Doc>("[[sections]]\nname = \"x\"\ndiff_lines = \"500\"\n").is_err()
+        );
+    }
+
+    #[test]
+    fn signal_readers_look_inside_nested_groups() {
+        let nested = SectionConfig {
+            name: "Nested".into(),
+            matcher: SectionMatch {
+                not: Some(Box::new(SectionMatch {
+                    any: vec![SectionMatch {
+                        diff_files: Some(">10".parse().unwrap()),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                })),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(nested.reads_diff_stat());
+        assert!(!nested.reads_live_signals());
+        assert!(needs_me().reads_live_signals());
+        assert!(!needs_me().reads_diff_stat());
+    }
 }
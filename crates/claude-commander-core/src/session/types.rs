@@ -13,7 +13,11 @@ use serde::{Deserialize, Serialize};
 // `claude-commander-protocol` crate (`Serialize + Deserialize`, mobile-safe).
 // Re-exported here so `crate::session::{SessionId, SessionStatus, ...}` paths
 // and the `WorktreeSession`/`Project` model below keep working unchanged.
-pub use claude_commander_protocol::session::{AgentState, ProjectId, SessionId, SessionStatus};
+pub use claude_commander_protocol::hook::{HookEvent, HookOutcome, HookRun};
+pub use claude_commander_protocol::session::{
+    AgentState, ArchiveInfo, PortBlock, ProjectId, SessionId, SessionStatus,
+};
+pub use claude_commander_protocol::task::{TaskRun, TaskStart, TaskStatus};
 
 /// Project represents a git repository (parent session)
 ///
@@ -155,6 +159,14 @@ pub struct WorktreeSession {
     /// submitted review authors. Empty when there's no PR or no reviewers.
     #[serde(default)]
     pub pr_reviewers: Vec<String>,
+    /// Login of the PR's author. `None` when there's no PR (or GitHub didn't
+    /// report one); drives the board's PR-author swimlanes.
+    #[serde(default)]
+    pub pr_author: Option<String>,
+    /// Aggregate CI status of the PR's checks. `None` when there's no PR.
+    /// Read by the `checks` section predicate.
+    #[serde(default)]
+    pub pr_checks: Option<crate::git::ChecksStatus>,
     /// Branch the PR targets, as reported by GitHub (e.g. `main` or another
     /// session's branch). Populated from `gh pr` JSON's `baseRefName`; used
     /// as the source of truth for PR-stack detection.
@@ -207,8 +219,73 @@ pub struct WorktreeSession {
     /// recreated.
     #[serde(default)]
     pub hibernated: bool,
+    /// The most recent lifecycle-hook runs, oldest first, capped at
+    /// [`MAX_HOOK_RUNS`]. Shown in the Info view; see
+    /// [`Self::record_hook_run`].
+    #[serde(default)]
+    pub hook_runs: Vec<HookRun>,
+    /// Ports reserved for this session while `[ports]` is enabled. Allocated
+    /// on first launch and kept across restarts so a dev server comes back on
+    /// the same port; freed with the session.
+    #[serde(default)]
+    pub ports: Option<PortBlock>,
+    /// The latest run of each project task, by name, in first-run order.
+    /// Drives the card badge and the `task_status` section predicate; see
+    /// [`Self::record_task_run`].
+    #[serde(default)]
+    pub task_runs: Vec<TaskRun>,
+    /// Size of the session's change against its base, refreshed by the PR
+    /// poll while a section predicate reads it (`diff_lines`/`diff_files`).
+    /// `None` until the first refresh.
+    #[serde(default)]
+    pub diff_stat: Option<crate::api::DiffStat>,
+    /// User-chosen tags (`spike`, `blocked`, …), normalised by
+    /// [`normalize_tags`]. Read by the `has_tag` section predicate and the
+    /// `tag:` filter.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Free-form notes the user keeps on the session. Empty when unset.
+    #[serde(default)]
+    pub notes: String,
+    /// Set while the session is archived: tmux stopped and the worktree
+    /// removed, with the branch and any uncommitted work kept. Archived
+    /// sessions are hidden from the list and board until restored.
+    #[serde(default)]
+    pub archived: Option<ArchiveInfo>,
+}
+
+/// Normalise user-entered tags: trimmed, lowercased, a leading `#` dropped and
+/// inner whitespace turned into `-`. Blank tags and repeats are removed; first
+/// occurrence order is kept.
+pub fn normalize_tags<I, S>(tags: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut out: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = normalize_tag(tag.as_ref());
+        if !tag.is_empty() && !out.contains(&tag) {
+            out.push(tag);
+        }
+    }
+    out
+}
+
+/// Normalise one tag the way [`normalize_tags`] does, for comparisons.
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim()
+        .trim_start_matches('#')
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase()
 }
 
+/// How many hook runs a session keeps. Enough to see every event of a
+/// create → merge → delete cycle without the state file growing unbounded.
+pub const MAX_HOOK_RUNS: usize = 10;
+
 impl WorktreeSession {
     /// Create a new worktree session
     pub fn new(
@@ -247,6 +324,8 @@ impl WorktreeSession {
             pr_labels: Vec::new(),
             review_decision: None,
             pr_reviewers: Vec::new(),
+            pr_author: None,
+            pr_checks: None,
             pr_base_branch: None,
             stack_parent_session_id: None,
             unread: false,
@@ -257,6 +336,14 @@ impl WorktreeSession {
             keep_alive: false,
             branch_adopted_at: None,
             hibernated: false,
+            hook_runs: Vec::new(),
+            ports: None,
+            task_runs: Vec::new(),
+            diff_stat: None,
+            tags: Vec::new(),
+            notes: String::new(),
+            archived: None,
+            pr_checks: None,
         }
     }
 
@@ -297,6 +384,8 @@ impl WorktreeSession {
             pr_labels: Vec::new(),
             review_decision: None,
             pr_reviewers: Vec::new(),
+            pr_author: None,
+            pr_checks: None,
             pr_base_branch: None,
             stack_parent_session_id: None,
             unread: false,
@@ -307,6 +396,13 @@ impl WorktreeSession {
             keep_alive: false,
             branch_adopted_at: None,
             hibernated: false,
+            hook_runs: Vec::new(),
+            ports: None,
+            task_runs: Vec::new(),
+            diff_stat: None,
+            tags: Vec::new(),
+            notes: String::new(),
+            archived: None,
         }
     }
 
@@ -323,6 +419,28 @@ impl WorktreeSession {
         self.last_active_at = Utc::now();
     }
 
+    /// Append a hook run, dropping the oldest once more than
+    /// [`MAX_HOOK_RUNS`] are kept.
+    pub fn record_hook_run(&mut self, run: HookRun) {
+        self.hook_runs.push(run);
+        let excess = self.hook_runs.len().saturating_sub(MAX_HOOK_RUNS);
+        self.hook_runs.drain(..excess);
+    }
+
+    /// Record a task run, replacing the previous run of the same task.
+    pub fn record_task_run(&mut self, run: TaskRun) {
+        match self.task_runs.iter_mut().find(|r| r.name == run.name) {
+            Some(existing) => *existing = run,
+            None => self.task_runs.push(run),
+        }
+    }
+
+    /// The card-badge summary of [`Self::task_runs`] (see
+    /// [`TaskStatus::summarize`]).
+    pub fn task_status(&self) -> Option<TaskStatus> {
+        TaskStatus::summarize(&self.task_runs)
+    }
+
     /// Record an attach event. Used by the in-tmux switcher to order
     /// sessions Alt+Tab-style by most-recently viewed.
     pub fn mark_attached(&mut self) {
@@ -340,15 +458,17 @@ impl WorktreeSession {
         self.fuzzy_score(query).is_some()
     }
 
-    /// Best fuzzy score across title, branch, and program — or `None` if
-    /// no field matches. Used by the palette to rank results.
+    /// Best fuzzy score across title, branch, program, tags and notes — or
+    /// `None` if no field matches. Used by the palette to rank results.
     pub fn fuzzy_score(&self, query: &str) -> Option<i64> {
         [
             self.title.as_str(),
             self.branch.as_str(),
             self.program.as_str(),
+            self.notes.as_str(),
         ]
-        .iter()
+        .into_iter()
+        .chain(self.tags.iter().map(String::as_str))
         .filter_map(|s| crate::fuzzy::fuzzy_score(s, query))
         .max()
     }
@@ -607,6 +727,12 @@ pub enum SessionListItem {
         /// skipped). Drives the `⇣ LFS` row marker. Sourced from
         /// `UiState::lfs_pull_in_flight`, not persisted.
         lfs_pulling: bool,
+        /// Summary of the session's project-task runs (see
+        /// [`TaskStatus::summarize`]). Drives the card's task badge; `None`
+        /// when no task has run.
+        task_status: Option<TaskStatus>,
+        /// The session's user tags, drawn as `#tag` chips on its board card.
+        tags: Vec<String>,
         /// True when this row is a stacked child of the row directly above it,
         /// meaning it sits one indent deeper than a normal session row. Stack
         /// bases and unstacked sessions keep the normal indent and have this
@@ -768,6 +894,60 @@ mod tests {
         assert_eq!(project.origin_url, None);
     }
 
+    #[test]
+    fn record_hook_run_keeps_only_the_newest() {
+        let mut session = WorktreeSession::new(
+            ProjectId::new(),
+            "t",
+            "b",
+            PathBuf::from("/tmp/wt"),
+            "claude",
+        );
+        for i in 0..MAX_HOOK_RUNS + 3 {
+            session.record_hook_run(HookRun {
+                event: HookEvent::PostCreate,
+                command: format!("cmd {i}"),
+                outcome: HookOutcome::Succeeded,
+                finished_at: Utc::now(),
+                duration_ms: 0,
+                output_tail: String::new(),
+            });
+        }
+        assert_eq!(session.hook_runs.len(), MAX_HOOK_RUNS);
+        assert_eq!(session.hook_runs[0].command, "cmd 3");
+        assert_eq!(
+            session.hook_runs.last().unwrap().command,
+            format!("cmd {}", MAX_HOOK_RUNS + 2)
+        );
+    }
+
+    #[test]
+    fn record_task_run_replaces_the_same_task() {
+        let mut session = WorktreeSession::new(
+            ProjectId::new(),
+            "t",
+            "b",
+            PathBuf::from("/tmp/wt"),
+            "claude",
+        );
+        let run = |name: &str, status| TaskRun {
+            name: name.to_string(),
+            command: format!("run {name}"),
+            status,
+            exit_code: None,
+            started_at: Utc::now(),
+            finished_at: None,
+            output_tail: String::new(),
+        };
+        session.record_task_run(run("test", TaskStatus::Failed));
+        session.record_task_run(run("lint", TaskStatus::Passed));
+        assert_eq!(session.task_status(), Some(TaskStatus::Failed));
+        session.record_task_run(run("test", TaskStatus::Running));
+        let names: Vec<&str> = session.task_runs.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["test", "lint"]);
+        assert_eq!(session.task_status(), Some(TaskStatus::Running));
+    }
+
     #[test]
     fn test_worktree_session_creation() {
         let project_id = ProjectId::new();
@@ -915,6 +1095,30 @@ mod tests {
         assert_eq!(combined, title_only);
     }
 
+    #[test]
+    fn test_fuzzy_score_reaches_tags_and_notes() {
+        let mut session = WorktreeSession::new(
+            ProjectId::new(),
+            "payments",
+            "fix",
+            PathBuf::from("/tmp"),
+            "claude",
+        );
+        assert!(session.fuzzy_score("customer").is_none());
+        session.tags = vec!["customer-x".to_string()];
+        assert!(session.fuzzy_score("customer").is_some());
+        session.notes = "waiting on API team".to_string();
+        assert!(session.fuzzy_score("api team").is_some());
+    }
+
+    #[test]
+    fn test_normalize_tags_trims_lowercases_and_dedupes() {
+        assert_eq!(
+            normalize_tags(["  Spike ", "#blocked", "customer x", "spike", "", "#"]),
+            vec!["spike", "blocked", "customer-x"]
+        );
+    }
+
     #[test]
     fn test_is_active() {
         assert!(SessionStatus::Creating.is_active());
@@ -1383,6 +1587,7 @@ mod session_info_stack_node_tests {
             pr_labels: Vec::new(),
             review_decision: None,
             pr_reviewers: Vec::new(),
+            pr_author: None,
             created_at: Utc::now() + ChronoDuration::seconds(created_offset_secs),
             unread: false,
             stack_parent_session_id: None,
@@ -1395,6 +1600,12 @@ mod session_info_stack_node_tests {
             keep_alive: false,
             worktree_path: String::new(),
             tmux_session_name: String::new(),
+            hook_runs: Vec::new(),
+            ports: None,
+            task_runs: Vec::new(),
+            tags: Vec::new(),
+            notes: String::new(),
+            archived: None,
         }
     }
 
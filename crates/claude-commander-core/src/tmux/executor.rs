@@ -5,6 +5,7 @@
 //! - Timeout handling
 //! - Structured output parsing
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::process::Stdio;
 use std::sync::Arc;
@@ -24,6 +25,10 @@ pub const DEFAULT_MAX_CONCURRENT: usize = 16;
 /// Default command timeout
 pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
 
+/// Scrollback kept per session (`history-limit`), and so the most the
+/// scrollback index holds for one.
+pub const HISTORY_LIMIT: usize = 50_000;
+
 /// Async tmux command executor
 ///
 /// Uses a semaphore to limit concurrent tmux commands, preventing
@@ -230,8 +235,15 @@ impl TmuxExecutor {
             .await?;
 
         // Increase scrollback buffer for long Claude sessions
-        self.execute(&["set-option", "-t", session_name, "history-limit", "50000"])
-            .await?;
+        let history_limit = HISTORY_LIMIT.to_string();
+        self.execute(&[
+            "set-option",
+            "-t",
+            session_name,
+            "history-limit",
+            &history_limit,
+        ])
+        .await?;
 
         Ok(())
     }
@@ -261,6 +273,27 @@ impl TmuxExecutor {
         Ok(output.trim() == "1")
     }
 
+    /// PID of the process running in the session's (first) pane — the launch
+    /// command's top process. `None` when tmux reports nothing parseable.
+    pub async fn pane_pid(&self, session_name: &str) -> Result<Option<u32>> {
+        let output = self
+            .execute(&["list-panes", "-t", session_name, "-F", "#{pane_pid}"])
+            .await?;
+        Ok(output.lines().next().and_then(|l| l.trim().parse().ok()))
+    }
+
+    /// Pids of every pane in every window of a session, for summing a
+    /// session's resource usage across its task windows.
+    pub async fn session_pane_pids(&self, session_name: &str) -> Result<Vec<u32>> {
+        let output = self
+            .execute(&["list-panes", "-s", "-t", session_name, "-F", "#{pane_pid}"])
+            .await?;
+        Ok(output
+            .lines()
+            .filter_map(|l| l.trim().parse().ok())
+            .collect())
+    }
+
     /// Whether any client is currently attached to the session. Used by the
     /// hibernation policy to avoid stopping a session the user is viewing.
     /// `#{session_attached}` is the count of attached clients, so any non-zero
@@ -279,6 +312,35 @@ impl TmuxExecutor {
         Ok(parse_session_attached(&output))
     }
 
+    /// The terminal size (`cols`, `rows`) a client needs to show the session's
+    /// current window exactly: the window's size plus its status lines. A
+    /// client attached at this size leaves the window as it is even when it's
+    /// the only one attached — the case `attach -r`'s `ignore-size` doesn't
+    /// cover, since tmux sizes to flagged clients when there are no others.
+    pub async fn window_client_size(&self, session_name: &str) -> Result<(u16, u16)> {
+        let output = self
+            .execute(&[
+                "display-message",
+                "-p",
+                "-t",
+                session_name,
+                "#{window_width} #{window_height} #{status}",
+            ])
+            .await?;
+        parse_window_client_size(&output)
+            .ok_or_else(|| TmuxError::ParseError("window size".to_string()))
+    }
+
+    /// How many clients are attached to each session, keyed by session name,
+    /// from a single `list-sessions` call. Sessions with no client are left out.
+    /// Feeds the viewer counts in the agent-state snapshot.
+    pub async fn attached_client_counts(&self) -> Result<HashMap<String, u32>> {
+        let output = self
+            .execute(&["list-sessions", "-F", "#{session_attached} #{session_name}"])
+            .await?;
+        Ok(parse_attached_counts(&output))
+    }
+
     /// Send keys to a tmux session
     pub async fn send_keys(&self, session_name: &str, keys: &str) -> Result<()> {
         self.execute(&["send-keys", "-t", session_name, keys])
@@ -355,6 +417,151 @@ impl TmuxExecutor {
 
         self.execute(&args).await
     }
+
+    /// Plain-text capture of a pane's whole scrollback (no escape sequences,
+    /// wrapped lines joined). `target` may name a window (`session:window`).
+    pub async fn capture_history(&self, target: &str) -> Result<String> {
+        self.execute(&["capture-pane", "-t", target, "-p", "-J", "-S", "-"])
+            .await
+    }
+
+    /// The rows that have scrolled off the top of a pane into its history —
+    /// the newest `lines` of them, or all when `None` — as plain text, one
+    /// tmux row per line (wrapped lines are *not* joined, so a row's index is
+    /// its copy-mode position). Returns the pane's total history size too,
+    /// read in the same tmux invocation so the two agree.
+    ///
+    /// The visible screen is excluded: agents redraw it in place, so only
+    /// history is append-only.
+    pub async fn capture_history_rows(
+        &self,
+        session_name: &str,
+        lines: Option<usize>,
+    ) -> Result<(usize, Vec<String>)> {
+        let start = lines.map_or_else(|| "-".to_string(), |n| format!("-{}", n.max(1)));
+        let output = self
+            .execute(&[
+                "display-message",
+                "-p",
+                "-t",
+                session_name,
+                "#{history_size}",
+                ";",
+                "capture-pane",
+                "-t",
+                session_name,
+                "-p",
+                "-S",
+                &start,
+                "-E",
+                "-1",
+            ])
+            .await?;
+        let mut rows = output.lines();
+        let history_size: usize = rows
+            .next()
+            .and_then(|l| l.trim().parse().ok())
+            .ok_or_else(|| TmuxError::ParseError("history_size".to_string()))?;
+        // With no history, `-E -1` clamps onto the first visible row; that
+        // row is not history, so drop it.
+        if history_size == 0 {
+            return Ok((0, Vec::new()));
+        }
+        Ok((
+            history_size,
+            rows.map(|r| r.trim_end().to_string()).collect(),
+        ))
+    }
+
+    /// Put a pane in copy-mode scrolled `offset` rows up from the bottom of
+    /// its history (tmux's `goto-line`), clamped by tmux to the history size.
+    pub async fn copy_mode_goto_line(&self, session_name: &str, offset: usize) -> Result<()> {
+        let offset = offset.to_string();
+        self.execute(&[
+            "copy-mode",
+            "-t",
+            session_name,
+            ";",
+            "send-keys",
+            "-t",
+            session_name,
+            "-X",
+            "goto-line",
+            &offset,
+        ])
+        .await?;
+        Ok(())
+    }
+
+    /// Run `command` in the window named `window` of `session_name`,
+    /// replacing whatever that window ran before. Creates the session (with
+    /// this as its first window) or the window as needed. The window stays
+    /// open when the command exits (`remain-on-exit`, see
+    /// [`Self::create_session`]) so its output and exit status can be read.
+    pub async fn run_in_window(
+        &self,
+        session_name: &str,
+        window: &str,
+        working_dir: &std::path::Path,
+        command: &str,
+    ) -> Result<()> {
+        if !self.session_exists(session_name).await? {
+            self.create_session(session_name, working_dir, Some(command))
+                .await?;
+            self.execute(&["rename-window", "-t", session_name, window])
+                .await?;
+            return Ok(());
+        }
+
+        let working_dir_str = working_dir.to_str().unwrap_or(".");
+        let windows = self
+            .execute(&["list-windows", "-t", session_name, "-F", "#{window_name}"])
+            .await?;
+        if windows.lines().any(|name| name == window) {
+            let target = format!("{session_name}:{window}");
+            self.execute(&[
+                "respawn-window",
+                "-k",
+                "-t",
+                &target,
+                "-c",
+                working_dir_str,
+                command,
+            ])
+            .await?;
+        } else {
+            let target = format!("{session_name}:");
+            self.execute(&[
+                "new-window",
+                "-d",
+                "-t",
+                &target,
+                "-n",
+                window,
+                "-c",
+                working_dir_str,
+                command,
+            ])
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// How a target's pane ended: `None` while its program is still running,
+    /// `Some(code)` once it has exited (`code` is `None` when a signal killed
+    /// it). Relies on `remain-on-exit` keeping the dead pane around.
+    pub async fn pane_exit_status(&self, target: &str) -> Result<Option<Option<i32>>> {
+        let output = self
+            .execute(&[
+                "list-panes",
+                "-t",
+                target,
+                "-F",
+                "#{pane_dead} #{pane_dead_status}",
+            ])
+            .await?;
+        Ok(parse_pane_exit_status(&output))
+    }
 }
 
 /// Info used to render a per-session tmux status bar.
@@ -452,10 +659,66 @@ fn parse_session_attached(output: &str) -> bool {
     output.trim().parse::<u32>().map(|n| n > 0).unwrap_or(true)
 }
 
+/// Parse `#{session_attached} #{session_name}` lines for
+/// [`TmuxExecutor::attached_client_counts`]. The count comes first so a session
+/// name containing a space still splits correctly.
+fn parse_attached_counts(output: &str) -> HashMap<String, u32> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (count, name) = line.split_once(' ')?;
+            let count = count.trim().parse::<u32>().ok()?;
+            (count > 0).then(|| (name.to_string(), count))
+        })
+        .collect()
+}
+
+/// Parse `#{window_width} #{window_height} #{status}` for
+/// [`TmuxExecutor::window_client_size`]. The `status` option is `off`, `on`
+/// (one line) or a line count from 2 to 5.
+fn parse_window_client_size(output: &str) -> Option<(u16, u16)> {
+    let mut fields = output.split_whitespace();
+    let cols = fields.next()?.parse().ok()?;
+    let rows: u16 = fields.next()?.parse().ok()?;
+    let status_lines = match fields.next()? {
+        "off" => 0,
+        "on" => 1,
+        lines => lines.parse().ok()?,
+    };
+    Some((cols, rows.saturating_add(status_lines)))
+}
+
+/// Parse `#{pane_dead} #{pane_dead_status}` for
+/// [`TmuxExecutor::pane_exit_status`]. tmux leaves the status empty for a
+/// pane killed by a signal.
+fn parse_pane_exit_status(output: &str) -> Option<Option<i32>> {
+    let line = output.lines().next()?.trim();
+    let (dead, status) = line.split_once(' ').unwrap_or((line, ""));
+    (dead == "1").then(|| status.trim().parse().ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_pane_exit_status_reads_dead_panes_only() {
+        assert_eq!(parse_pane_exit_status("0 \n"), None);
+        assert_eq!(parse_pane_exit_status("1 0\n"), Some(Some(0)));
+        assert_eq!(parse_pane_exit_status("1 101\n"), Some(Some(101)));
+        assert_eq!(parse_pane_exit_status("1 \n"), Some(None));
+        assert_eq!(parse_pane_exit_status(""), None);
+    }
+
+    #[test]
+    fn parse_window_client_size_adds_status_lines() {
+        assert_eq!(parse_window_client_size("120 39 on\n"), Some((120, 40)));
+        assert_eq!(parse_window_client_size("120 39 off"), Some((120, 39)));
+        assert_eq!(parse_window_client_size("80 20 3"), Some((80, 23)));
+        assert_eq!(parse_window_client_size("80 20"), None);
+        assert_eq!(parse_window_client_size(""), None);
+    }
+
     #[test]
     fn parse_session_attached_counts() {
         assert!(parse_session_attached("1"));
@@ -465,6 +728,14 @@ mod tests {
         assert!(parse_session_attached(" 1 "));
     }
 
+    #[test]
+    fn parse_attached_counts_skips_unattached_sessions() {
+        let counts = parse_attached_counts("0 cc-idle\n2 cc-demo\n1 cc with space\ngarbage\n");
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["cc-demo"], 2);
+        assert_eq!(counts["cc with space"], 1);
+    }
+
     #[test]
     fn parse_session_attached_unparsable_is_conservatively_attached() {
         // Empty / non-numeric output must NOT read as unattached, or a
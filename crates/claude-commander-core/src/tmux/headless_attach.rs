@@ -101,12 +101,40 @@ impl HeadlessAttach {
         cols: u16,
         rows: u16,
         tmux_tmpdir: Option<&Path>,
+    ) -> Result<Self> {
+        Self::spawn_client(session_name, cols, rows, tmux_tmpdir, false)
+    }
+
+    /// Like [`Self::spawn`], but attaches with `attach-session -r`: tmux marks
+    /// the client read-only, so keystrokes reaching it are ignored. Used for
+    /// spectators — the server also drops their input before it gets here, so
+    /// this is the second of two locks, not the only one.
+    pub fn spawn_read_only(
+        session_name: &str,
+        cols: u16,
+        rows: u16,
+        tmux_tmpdir: Option<&Path>,
+    ) -> Result<Self> {
+        Self::spawn_client(session_name, cols, rows, tmux_tmpdir, true)
+    }
+
+    fn spawn_client(
+        session_name: &str,
+        cols: u16,
+        rows: u16,
+        tmux_tmpdir: Option<&Path>,
+        read_only: bool,
     ) -> Result<Self> {
         let (pty, pts) = pty_process::open()?;
         pty.resize(pty_process::Size::new(rows, cols))?;
 
+        let mut args = vec!["attach-session"];
+        if read_only {
+            args.push("-r");
+        }
+        args.extend(["-t", session_name]);
         let mut cmd = pty_process::Command::new("tmux")
-            .args(["attach-session", "-t", session_name])
+            .args(args)
             .with_tmux_tmpdir(tmux_tmpdir);
         // `tmux attach` refuses to start (or degrades to no IO) when the
         // inherited TERM is missing or "dumb" — the norm for headless hosts
@@ -123,8 +151,8 @@ impl HeadlessAttach {
         let child = cmd.spawn(pts)?;
 
         info!(
-            "Spawned tmux attach-session for {} (client tty {:?})",
-            session_name, client_tty
+            "Spawned tmux attach-session for {} (client tty {:?}, read-only {})",
+            session_name, client_tty, read_only
         );
 
         Ok(Self {
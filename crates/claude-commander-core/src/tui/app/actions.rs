@@ -176,6 +176,50 @@ pub(super) fn reset_confirm_message(title: Option<&str>) -> String {
     )
 }
 
+/// Body of the archive confirmation for a session titled `title`: what goes
+/// (tmux and the worktree directory), what stays (branch, uncommitted work,
+/// the agent conversation), and where to find it again.
+pub(super) fn archive_confirm_message(title: Option<&str>) -> String {
+    let subject = match title {
+        Some(title) => format!("\"{title}\""),
+        None => "this session".to_string(),
+    };
+    format!(
+        "Archive session {subject}?\nThis stops its tmux session and removes the worktree \
+         directory. The branch is kept, uncommitted changes are saved to a hidden ref, and \
+         the agent conversation resumes on restore.\nRestore it from \"Archived sessions…\" \
+         in the palette or with `claude-commander restore`."
+    )
+}
+
+/// Status line for a run-all-tasks request: the tasks now running, then any
+/// that couldn't start with the first error.
+pub(super) fn run_tasks_message(results: &[crate::session::TaskStart]) -> String {
+    let started: Vec<&str> = results
+        .iter()
+        .filter(|r| r.started())
+        .map(|r| r.name.as_str())
+        .collect();
+    let failed: Vec<&crate::session::TaskStart> = results.iter().filter(|r| !r.started()).collect();
+    let mut msg = if started.is_empty() {
+        String::new()
+    } else {
+        format!("Running tasks: {}", started.join(", "))
+    };
+    if let Some(first) = failed.first() {
+        let names: Vec<&str> = failed.iter().map(|r| r.name.as_str()).collect();
+        if !msg.is_empty() {
+            msg.push_str(" — ");
+        }
+        msg.push_str(&format!(
+            "failed to start {}: {}",
+            names.join(", "),
+            first.error.as_deref().unwrap_or_default()
+        ));
+    }
+    msg
+}
+
 impl App {
     /// Open `Modal::PathInput` at the current working directory with its
     /// subdirectory list already populated.
@@ -533,6 +577,72 @@ impl App {
         }
     }
 
+    /// Build the initial-prompt editor for a new-session dialog, seeded with the
+    /// recent prompts from `tui.json` for Ctrl-p/Ctrl-n recall.
+    pub(super) fn new_prompt_editor(&self) -> super::PromptEditor {
+        super::PromptEditor::new(self.tui_prefs.prefs().prompt_history)
+    }
+
+    /// Ctrl-o in the New Session prompt field: write the prompt to a temp file
+    /// and suspend the TUI on `$EDITOR` for it, via the same foreground path as
+    /// open-in-editor. The dialog stays open underneath, and
+    /// [`Self::load_prompt_from_editor`] reads the file back when the editor
+    /// exits.
+    pub(super) fn open_prompt_in_editor(&mut self) {
+        let Modal::Input {
+            prompt_editor: Some(editor),
+            ..
+        } = &self.ui_state.modal
+        else {
+            return;
+        };
+        let Some(command) = self.config.resolve_editor() else {
+            self.ui_state.status_message = Some((
+                "No editor configured. Set 'editor' in config.toml or $VISUAL / $EDITOR."
+                    .to_string(),
+                Instant::now() + Duration::from_secs(3),
+            ));
+            return;
+        };
+        let path = std::env::temp_dir().join(format!("cc-prompt-{}.md", uuid::Uuid::new_v4()));
+        if let Err(e) = std::fs::write(&path, editor.text()) {
+            self.ui_state.status_message = Some((
+                format!("Failed to write prompt file: {e}"),
+                Instant::now() + Duration::from_secs(3),
+            ));
+            return;
+        }
+        // Always run in the foreground, even for a GUI editor: the dialog
+        // needs the edited text back, so it has to wait for the editor to exit.
+        self.ui_state.prompt_edit_file = Some(path.clone());
+        self.ui_state.editor_command = Some((command, path));
+        self.ui_state.should_quit = true;
+    }
+
+    /// Replace the open New Session dialog's prompt with what `$EDITOR` saved
+    /// to `file`, then remove the file. A dialog closed meanwhile just drops
+    /// the text.
+    pub(super) fn load_prompt_from_editor(&mut self, file: &std::path::Path) {
+        let text = std::fs::read_to_string(file);
+        let _ = std::fs::remove_file(file);
+        match (text, &mut self.ui_state.modal) {
+            (
+                Ok(text),
+                Modal::Input {
+                    prompt_editor: Some(editor),
+                    ..
+                },
+            ) => editor.set_text(&text),
+            (Ok(_), _) => {}
+            (Err(e), _) => {
+                self.ui_state.status_message = Some((
+                    format!("Failed to read the edited prompt: {e}"),
+                    Instant::now() + Duration::from_secs(3),
+                ));
+            }
+        }
+    }
+
     /// Build the server picker for a new-session dialog, or `None` when only one
     /// backend is configured (the field is hidden then, matching the suppressed
     /// server headers in the list). Highlights `current`.
@@ -745,6 +855,7 @@ impl App {
                 program_picker: Some(self.new_program_picker()),
                 server_picker,
                 section_picker,
+                prompt_editor: Some(self.new_prompt_editor()),
                 focus: super::InputFocus::Name,
                 expanded: false,
                 mask: false,
@@ -823,6 +934,7 @@ impl App {
             program_picker: Some(self.new_program_picker()),
             server_picker: None,
             section_picker: None,
+            prompt_editor: Some(self.new_prompt_editor()),
             focus: super::InputFocus::Name,
             expanded: false,
             mask: false,
@@ -975,7 +1087,8 @@ impl App {
             Ok(()) => {
                 // Refresh off the event loop; BackendChanged re-renders the tree.
                 self.spawn_backend_view_refresh(backend_id);
-                ("Cascade pause cleared".to_string(), 3)
+                self.ui_state.undo_backend = Some(backend_id);
+                (format!("Cascade pause cleared — {}", self.undo_hint()), 8)
             }
             Err(e) => (format!("Cascade abandon failed: {e}"), 5),
         };
@@ -1244,14 +1357,16 @@ impl App {
                 if session.status == SessionStatus::Creating {
                     continue;
                 }
-                // Best fuzzy score across title/branch/program — mirrors
-                // `WorktreeSession::fuzzy_score` over the DTO fields.
+                // Best fuzzy score across title/branch/program/notes/tags —
+                // mirrors `WorktreeSession::fuzzy_score` over the DTO fields.
                 let Some(score) = [
                     session.title.as_str(),
                     session.branch.as_str(),
                     session.program.as_str(),
+                    session.notes.as_str(),
                 ]
-                .iter()
+                .into_iter()
+                .chain(session.tags.iter().map(String::as_str))
                 .filter_map(|s| crate::fuzzy::fuzzy_score(s, query))
                 .max() else {
                     continue;
@@ -1336,6 +1451,12 @@ impl App {
         if eff_mode == PaletteMode::GithubRepoPicker {
             return self.gather_github_repo_picker_items(eff_query);
         }
+        if eff_mode == PaletteMode::ScrollbackSearch {
+            return self.gather_scrollback_hit_items();
+        }
+        if eff_mode == PaletteMode::ArchivedPicker {
+            return self.gather_archived_picker_items(eff_query);
+        }
         if eff_mode == PaletteMode::Unified {
             for m in self.gather_quick_switch_matches(eff_query).await {
                 out.push(QuickSwitchItem::Session(m));
@@ -1344,6 +1465,8 @@ impl App {
         for c in self.gather_command_entries(eff_query) {
             out.push(QuickSwitchItem::Command(c));
         }
+        out.extend(self.gather_saved_filter_items(eff_query));
+        out.extend(self.gather_custom_command_items(eff_query));
         out
     }
 
@@ -1398,6 +1521,9 @@ impl App {
 
         let eff_mode = Self::effective_palette_mode(mode, &query);
         let eff_query = Self::palette_filter_query(eff_mode, &query);
+        if eff_mode == PaletteMode::ScrollbackSearch {
+            self.schedule_scrollback_search(eff_query);
+        }
 
         // The dedicated picker modes each *replace* the whole row set with their
         // own filtered list; falling through would append command entries to it.
@@ -1413,6 +1539,8 @@ impl App {
                 Some(self.gather_remote_server_picker_items(eff_query))
             }
             PaletteMode::GithubRepoPicker => Some(self.gather_github_repo_picker_items(eff_query)),
+            PaletteMode::ScrollbackSearch => Some(self.gather_scrollback_hit_items()),
+            PaletteMode::ArchivedPicker => Some(self.gather_archived_picker_items(eff_query)),
             PaletteMode::Unified | PaletteMode::CommandOnly => None,
         };
         if let Some(rows) = picker_rows {
@@ -1444,6 +1572,8 @@ impl App {
 
         let mut rows = session_items;
         rows.extend(command_items);
+        rows.extend(self.gather_saved_filter_items(eff_query));
+        rows.extend(self.gather_custom_command_items(eff_query));
         self.replace_palette_rows(rows);
     }
 
@@ -1514,6 +1644,21 @@ impl App {
         }
     }
 
+    /// Handle "Archive session" — confirm, then stop the session and remove its
+    /// worktree while keeping the branch (see [`ConfirmAction::ArchiveSession`]).
+    pub(super) fn handle_archive_session(&mut self) {
+        if let Some(sref) = self.ui_state.selected_session_id {
+            let title = self.session(sref).map(|s| s.title.clone());
+            self.ui_state.modal = Modal::Confirm {
+                title: "Archive Session".to_string(),
+                message: archive_confirm_message(title.as_deref()),
+                on_confirm: ConfirmAction::ArchiveSession {
+                    session_id: sref.id,
+                },
+            };
+        }
+    }
+
     /// Toggle keep-alive on the selected session (opt out of / back into
     /// auto-hibernation). Non-destructive, so it applies immediately and
     /// reports via a transient status message.
@@ -1545,6 +1690,45 @@ impl App {
         }
     }
 
+    /// Start every project task for the selected session in its task window.
+    /// Progress shows as the card's task badge; this only reports what started
+    /// and what couldn't.
+    pub(super) async fn handle_run_tasks(&mut self) {
+        let Some(session_id) = self.ui_state.selected_session_id else {
+            return;
+        };
+        let msg = match self
+            .backend_arc(session_id.backend)
+            .run_tasks(session_id.id, None)
+            .await
+        {
+            Ok(results) if results.is_empty() => {
+                "No tasks configured — add a [tasks] table to the project".to_string()
+            }
+            Ok(results) => run_tasks_message(&results),
+            Err(e) => format!("Failed to run tasks: {e}"),
+        };
+        self.ui_state.status_message = Some((msg, Instant::now() + Duration::from_secs(3)));
+        self.refresh_list_items().await;
+    }
+
+    /// Hand the selected session's most recent failed task output to its
+    /// agent as a prompt.
+    pub(super) async fn handle_send_task_failure(&mut self) {
+        let Some(session_id) = self.ui_state.selected_session_id else {
+            return;
+        };
+        let msg = match self
+            .backend_arc(session_id.backend)
+            .send_task_failure(session_id.id, None)
+            .await
+        {
+            Ok(task) => format!("Sent `{task}` failure to the agent"),
+            Err(e) => format!("Failed to send task failure: {e}"),
+        };
+        self.ui_state.status_message = Some((msg, Instant::now() + Duration::from_secs(3)));
+    }
+
     /// Handle delete session - show confirmation
     pub(super) async fn handle_delete_session(&mut self) {
         if self.selected_session_is_creating() {
@@ -1659,19 +1843,69 @@ impl App {
         if self.ui_state.selected_session_id.map(|r| r.id) == Some(session_id) {
             self.ui_state.selected_session_id = None;
         }
-        let backend = self.backend_arc(self.backend_of_session(session_id));
+        let backend_id = self.backend_of_session(session_id);
+        let backend = self.backend_arc(backend_id);
         let tx = self.event_loop.sender();
         tokio::spawn(async move {
-            if let Err(e) = backend.delete_session(session_id).await {
-                let _ = tx
-                    .send(AppEvent::StateUpdate(StateUpdate::Error {
-                        message: format!("Failed to delete session: {e}"),
-                    }))
-                    .await;
-            }
+            let update = match backend.delete_session(session_id).await {
+                Ok(()) => StateUpdate::UndoableActionFinished {
+                    backend_id: backend_id.0,
+                    message: "Session deleted".to_string(),
+                },
+                Err(e) => StateUpdate::Error {
+                    message: format!("Failed to delete session: {e}"),
+                },
+            };
+            let _ = tx.send(AppEvent::StateUpdate(update)).await;
+        });
+    }
+
+    /// The "how to undo" half of a toast after an undoable action: the Undo
+    /// key when one is bound, otherwise the palette.
+    pub(super) fn undo_hint(&self) -> String {
+        match self.config.keybindings.keys_display(BindableAction::Undo) {
+            keys if keys.is_empty() => "undo from the palette".to_string(),
+            keys => format!("press {keys} to undo"),
+        }
+    }
+
+    /// Handle "Undo" — reverse the most recent undoable action on the backend
+    /// that took it (local when none has this run). Spawned: bringing a session
+    /// back recreates its worktree and relaunches the agent.
+    pub(super) fn handle_undo(&mut self) {
+        let backend_id = self.ui_state.undo_backend.unwrap_or(LOCAL_BACKEND_ID);
+        self.ui_state.status_message = Some((
+            "Undoing…".to_string(),
+            Instant::now() + Duration::from_secs(30),
+        ));
+        let backend = self.backend_arc(backend_id);
+        let tx = self.event_loop.sender();
+        tokio::spawn(async move {
+            let result = backend.undo().await.map_err(|e| e.to_string());
+            let _ = tx
+                .send(AppEvent::StateUpdate(StateUpdate::UndoFinished {
+                    backend_id: backend_id.0,
+                    result,
+                }))
+                .await;
         });
     }
 
+    pub(super) fn handle_undo_finished(
+        &mut self,
+        backend_id: BackendId,
+        result: std::result::Result<String, String>,
+    ) {
+        let (msg, secs) = match result {
+            Ok(summary) => {
+                self.spawn_backend_view_refresh(backend_id);
+                (format!("Undone: {summary}"), 5)
+            }
+            Err(e) => (format!("Undo failed: {e}"), 5),
+        };
+        self.ui_state.status_message = Some((msg, Instant::now() + Duration::from_secs(secs)));
+    }
+
     /// Open the "Move to section" palette for the selected session.
     /// The palette lists "Auto" plus one entry per effective section (the
     /// configured `[[sections]]`, or the baked-in defaults when none are
@@ -1692,9 +1926,9 @@ impl App {
     }
 
     /// Cycle the session-list view (`v`): project → sections → stacks → board →
-    /// (repeat). Section-grouped modes are skipped when no `[[sections]]` are
+    /// grid → (repeat). Section-grouped modes are skipped when no `[[sections]]` are
     /// configured (they would render identically to the project view), so with
-    /// no sections `v` simply toggles between the project list and the board.
+    /// no sections `v` cycles the project list, the board and the grid.
     /// The chosen view is persisted to `tui.json` so it survives restarts, and
     /// the current session/project selection is carried across the rebuild.
     pub(super) async fn handle_toggle_view_mode(&mut self) {
@@ -1716,7 +1950,11 @@ impl App {
 
         // Carry the previous selection across the rebuild so the same session
         // (or project) stays focused after switching view.
-        if new_view.is_board() {
+        if new_view.is_grid() {
+            if let Some(sid) = selected_session {
+                self.select_grid_session(sid);
+            }
+        } else if new_view.is_board() {
             if let Some(sid) = selected_session
                 && let Some(pos) = self.ui_state.board.position_of(sid)
             {
@@ -1754,10 +1992,10 @@ impl App {
     }
 
     /// Collapse or expand the section containing the selected row (`ToggleSection`).
-    /// Section-list-only; a no-op on the board (which has no `list_state` cursor),
-    /// with no sections configured, or with no selection.
+    /// Section-list-only; a no-op on the board and grid (which have no
+    /// `list_state` cursor), with no sections configured, or with no selection.
     pub(super) async fn handle_toggle_section(&mut self) {
-        if self.ui_state.view_mode.is_board() || self.config.sections.is_empty() {
+        if self.ui_state.view_mode.is_full_screen() || self.config.sections.is_empty() {
             return;
         }
         let Some(idx) = self.ui_state.list_state.selected() else {
@@ -1785,6 +2023,79 @@ impl App {
         self.update_selection();
     }
 
+    /// Cycle the board's swimlane grouping (`CycleSwimlanes`): project →
+    /// server → program → PR author → off. Persisted to `tui.json`.
+    pub(super) async fn handle_cycle_swimlanes(&mut self) {
+        if !self.ui_state.view_mode.is_board() {
+            return;
+        }
+        let lanes = Swimlanes::cycle(self.ui_state.board_lanes);
+        self.ui_state.board_lanes = lanes;
+        self.tui_prefs.set_swimlanes(lanes).await;
+        self.refresh_list_items().await;
+        self.update_selection();
+        self.ui_state.status_message = Some((
+            format!("Swimlanes: {}", lanes.map_or("off", |l| l.label())),
+            Instant::now() + Duration::from_secs(3),
+        ));
+    }
+
+    /// Fold or unfold the selected card's swimlane (`ToggleLane`). With the
+    /// cursor outside every lane (the sidebar or an empty column), unfold all
+    /// folded lanes instead — the only way back to a lane whose cards are all
+    /// hidden without the mouse.
+    pub(super) async fn handle_toggle_lane(&mut self) {
+        if !self.ui_state.view_mode.is_board() || self.ui_state.board.lanes.is_empty() {
+            return;
+        }
+        let lane = self
+            .ui_state
+            .board_state
+            .selected()
+            .and_then(|pos| self.ui_state.board.lane_at(pos));
+        if let Some(idx) = lane {
+            self.toggle_lane(idx).await;
+            return;
+        }
+        let folded: Vec<String> = self
+            .ui_state
+            .board
+            .lanes
+            .iter()
+            .filter(|l| l.collapsed)
+            .map(|l| l.key.clone())
+            .collect();
+        if folded.is_empty() {
+            return;
+        }
+        for key in &folded {
+            self.ui_state.collapsed_lanes.remove(key);
+        }
+        self.apply_collapsed_lanes().await;
+    }
+
+    /// Fold or unfold the board's lane `idx` (an index into `board.lanes`).
+    pub(super) async fn toggle_lane(&mut self, idx: usize) {
+        let Some(lane) = self.ui_state.board.lanes.get(idx) else {
+            return;
+        };
+        let key = lane.key.clone();
+        if !self.ui_state.collapsed_lanes.remove(&key) {
+            self.ui_state.collapsed_lanes.insert(key);
+        }
+        self.apply_collapsed_lanes().await;
+    }
+
+    /// Persist the folded-lane set and rebuild the board around it. Folding
+    /// the lane under the cursor leaves the cursor clamped to a visible card.
+    async fn apply_collapsed_lanes(&mut self) {
+        self.tui_prefs
+            .set_collapsed_lanes(self.ui_state.collapsed_lanes.iter().cloned().collect())
+            .await;
+        self.refresh_list_items().await;
+        self.update_selection();
+    }
+
     /// Open the "Change program" palette for the selected session. The palette
     /// lists the owning backend's configured programs; selecting one confirms,
     /// then changes the session's program and relaunches it fresh.
@@ -2012,6 +2323,7 @@ impl App {
             program_picker: None,
             server_picker: None,
             section_picker: None,
+            prompt_editor: None,
             focus: super::InputFocus::Name,
             expanded: false,
             mask: false,
@@ -2225,6 +2537,7 @@ impl App {
             program_picker: None,
             server_picker: None,
             section_picker: None,
+            prompt_editor: None,
             focus: super::InputFocus::Name,
             expanded: false,
             mask: false,
@@ -2252,6 +2565,95 @@ impl App {
         };
     }
 
+    /// Handle "Archived sessions…" — open the palette listing every backend's
+    /// archived sessions. Archived rows are kept out of the tree, so this is
+    /// where they are found again; choosing one restores it.
+    pub(super) fn handle_show_archived(&mut self) {
+        if self.backends.iter().all(|h| h.view.archived.is_empty()) {
+            self.ui_state.status_message = Some((
+                "No archived sessions".to_string(),
+                Instant::now() + Duration::from_secs(3),
+            ));
+            return;
+        }
+        let matches = self.gather_archived_picker_items("");
+        self.ui_state.modal = Modal::QuickSwitch {
+            mode: PaletteMode::ArchivedPicker,
+            query: super::Input::default(),
+            matches,
+            selected_idx: 0,
+            scroll: 0,
+        };
+    }
+
+    /// Build the archived-session picker rows, newest archive first, filtered
+    /// by a title/branch/project substring.
+    pub(super) fn gather_archived_picker_items(&self, filter_query: &str) -> Vec<QuickSwitchItem> {
+        let q = filter_query.to_lowercase();
+        let mut rows: Vec<(chrono::DateTime<chrono::Utc>, QuickSwitchItem)> = Vec::new();
+        for handle in &self.backends {
+            for session in &handle.view.archived {
+                let Some(archive) = &session.archived else {
+                    continue;
+                };
+                if !q.is_empty()
+                    && ![&session.title, &session.branch, &session.project_name]
+                        .iter()
+                        .any(|s| s.to_lowercase().contains(&q))
+                {
+                    continue;
+                }
+                let label = format!(
+                    "{} [{}] · {} · archived {}",
+                    session.title,
+                    session.branch,
+                    session.project_name,
+                    archive.archived_at.format("%Y-%m-%d %H:%M UTC")
+                );
+                rows.push((
+                    archive.archived_at,
+                    QuickSwitchItem::ArchivedSession {
+                        backend: handle.id,
+                        session_id: session.session_id,
+                        label,
+                    },
+                ));
+            }
+        }
+        rows.sort_by(|a, b| b.0.cmp(&a.0));
+        rows.into_iter().map(|(_, row)| row).collect()
+    }
+
+    /// Restore an archived session picked from the archived palette. Spawned
+    /// like a restart: recreating the worktree and relaunching the agent can
+    /// take a while, and `RestartFinished` refreshes the view and toasts.
+    pub(super) fn restore_archived_session(
+        &mut self,
+        backend_id: BackendId,
+        session_id: SessionId,
+    ) {
+        self.ui_state.modal = Modal::None;
+        self.ui_state.status_message = Some((
+            "Restoring session…".to_string(),
+            Instant::now() + Duration::from_secs(30),
+        ));
+        let backend = self.backend_arc(backend_id);
+        let tx = self.event_loop.sender();
+        tokio::spawn(async move {
+            let result = backend
+                .restore_session(session_id)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx
+                .send(AppEvent::StateUpdate(StateUpdate::RestartFinished {
+                    backend_id: backend_id.0,
+                    kind: RestartKind::Restore,
+                    result,
+                }))
+                .await;
+        });
+    }
+
     /// Build the remove-server picker rows: one per configured server,
     /// filtered by name/url substring.
     pub(super) fn gather_remote_server_picker_items(
@@ -2377,16 +2779,69 @@ impl App {
         let Some(current_title) = self.session(sref).map(|s| s.title.clone()) else {
             return;
         };
+        self.open_session_text_modal(
+            "Rename Session",
+            "Enter new session name:",
+            current_title,
+            InputAction::RenameSession { session_id },
+        );
+    }
+
+    /// Open the tag editor for the selected session, prefilled with its
+    /// current tags as a comma-separated list.
+    pub(super) async fn handle_edit_tags(&mut self) {
+        let Some(sref) = self.ui_state.selected_session_id else {
+            return;
+        };
+        let session_id = sref.id;
+        let Some(current) = self.session(sref).map(|s| s.tags.join(", ")) else {
+            return;
+        };
+        self.open_session_text_modal(
+            "Edit Tags",
+            "Tags (comma-separated):",
+            current,
+            InputAction::EditTags { session_id },
+        );
+    }
+
+    /// Open the notes editor for the selected session, prefilled with its
+    /// current notes.
+    pub(super) async fn handle_edit_notes(&mut self) {
+        let Some(sref) = self.ui_state.selected_session_id else {
+            return;
+        };
+        let session_id = sref.id;
+        let Some(current) = self.session(sref).map(|s| s.notes.clone()) else {
+            return;
+        };
+        self.open_session_text_modal(
+            "Edit Notes",
+            "Notes (empty clears them):",
+            current,
+            InputAction::EditNotes { session_id },
+        );
+    }
+
+    /// A plain single-field input modal editing one session attribute.
+    fn open_session_text_modal(
+        &mut self,
+        title: &str,
+        prompt: &str,
+        value: String,
+        on_submit: InputAction,
+    ) {
         self.ui_state.modal = Modal::Input {
-            title: "Rename Session".to_string(),
-            prompt: "Enter new session name:".to_string(),
-            value: current_title.into(),
-            on_submit: InputAction::RenameSession { session_id },
+            title: title.to_string(),
+            prompt: prompt.to_string(),
+            value: value.into(),
+            on_submit,
             existing_branches: None,
             project_picker: None,
             program_picker: None,
             server_picker: None,
             section_picker: None,
+            prompt_editor: None,
             focus: super::InputFocus::Name,
             expanded: false,
             mask: false,
@@ -2422,13 +2877,15 @@ impl App {
     /// Handle input modal submission. `program` is the command chosen in the
     /// new-session program picker, or `None` for flows without a picker (which
     /// then fall back to the first configured program inside `prepare_session`,
-    /// on whichever backend owns the target project).
+    /// on whichever backend owns the target project). `initial_prompt` is the
+    /// prompt editor's non-blank text, sent to the agent when it launches.
     pub(super) async fn handle_input_submit(
         &mut self,
         action: InputAction,
         value: String,
         program: Option<String>,
         backend: Option<BackendId>,
+        initial_prompt: Option<String>,
     ) {
         match action {
             InputAction::CreateSession {
@@ -2459,13 +2916,16 @@ impl App {
                     };
                     return;
                 };
+                if let Some(prompt) = &initial_prompt {
+                    self.tui_prefs.record_prompt(prompt).await;
+                }
                 self.spawn_create_session(
                     backend_id,
                     crate::api::CreateSessionOpts {
                         project_path,
                         title: value,
                         program,
-                        initial_prompt: None,
+                        initial_prompt,
                         effort: None,
                         mode: None,
                         model: None,
@@ -2501,13 +2961,16 @@ impl App {
                     };
                     return;
                 };
+                if let Some(prompt) = &initial_prompt {
+                    self.tui_prefs.record_prompt(prompt).await;
+                }
                 self.spawn_create_session(
                     backend_id,
                     crate::api::CreateSessionOpts {
                         project_path,
                         title: value,
                         program,
-                        initial_prompt: None,
+                        initial_prompt,
                         effort: None,
                         mode: None,
                         model: None,
@@ -2575,6 +3038,37 @@ impl App {
                         .await;
                 });
             }
+            InputAction::EditTags { session_id } => {
+                let tags: Vec<String> = value.split(',').map(str::to_string).collect();
+                // The owning host normalises (and drops empty) tags.
+                let backend_id = self.backend_of_session(session_id);
+                let backend = self.backend_arc(backend_id);
+                let tx = self.event_loop.sender();
+                tokio::spawn(async move {
+                    let _ = backend.set_tags(session_id, tags).await;
+                    let _ = tx
+                        .send(AppEvent::StateUpdate(StateUpdate::SessionMutationApplied {
+                            backend_id: backend_id.0,
+                            session_id,
+                        }))
+                        .await;
+                });
+            }
+            InputAction::EditNotes { session_id } => {
+                let notes = value;
+                let backend_id = self.backend_of_session(session_id);
+                let backend = self.backend_arc(backend_id);
+                let tx = self.event_loop.sender();
+                tokio::spawn(async move {
+                    let _ = backend.set_notes(session_id, notes).await;
+                    let _ = tx
+                        .send(AppEvent::StateUpdate(StateUpdate::SessionMutationApplied {
+                            backend_id: backend_id.0,
+                            session_id,
+                        }))
+                        .await;
+                });
+            }
             InputAction::ScanDirectory => {
                 let expanded = crate::tui::path_completer::expand_tilde(value.trim());
                 let path = PathBuf::from(expanded);
@@ -2679,6 +3173,7 @@ impl App {
                     program_picker: None,
                     server_picker: None,
                     section_picker: None,
+                    prompt_editor: None,
                     focus: super::InputFocus::Name,
                     expanded: false,
                     mask: false,
@@ -2710,6 +3205,7 @@ impl App {
                         program_picker: None,
                         server_picker: None,
                         section_picker: None,
+                        prompt_editor: None,
                         focus: super::InputFocus::Name,
                         expanded: false,
                         mask: false,
@@ -2726,6 +3222,7 @@ impl App {
                     program_picker: None,
                     server_picker: None,
                     section_picker: None,
+                    prompt_editor: None,
                     focus: super::InputFocus::Name,
                     expanded: false,
                     mask: true,
@@ -2747,6 +3244,12 @@ impl App {
                 let dest_name = (!dest_name.is_empty()).then(|| dest_name.to_string());
                 self.spawn_clone(backend, source, dest_name);
             }
+            InputAction::FilterSessions { previous } => {
+                self.submit_filter_query(value, previous).await;
+            }
+            InputAction::SaveFilter { query } => {
+                self.submit_save_filter(value, query).await;
+            }
         }
     }
 
@@ -2777,6 +3280,10 @@ impl App {
                     .iter()
                     .map(|sid| (self.backend_arc(self.backend_of_session(*sid)), *sid))
                     .collect();
+                // Undo walks back from the last delete of the batch.
+                if let Some(last) = session_ids.last() {
+                    self.ui_state.undo_backend = Some(self.backend_of_session(*last));
+                }
                 let tx = self.event_loop.sender();
                 tokio::spawn(delete_sessions_in_sequence(deletes, tx));
                 self.ui_state.status_message = Some((
@@ -2832,6 +3339,34 @@ impl App {
                         .await;
                 });
             }
+            ConfirmAction::ArchiveSession { session_id } => {
+                // Stopping tmux and removing the worktree can be slow; spawn it
+                // like a restart. The session drops out of the tree once the
+                // refresh lands.
+                let backend_id = self.backend_of_session(session_id);
+                if self.ui_state.selected_session_id.map(|r| r.id) == Some(session_id) {
+                    self.ui_state.selected_session_id = None;
+                }
+                self.ui_state.status_message = Some((
+                    "Archiving session…".to_string(),
+                    Instant::now() + Duration::from_secs(30),
+                ));
+                let backend = self.backend_arc(backend_id);
+                let tx = self.event_loop.sender();
+                tokio::spawn(async move {
+                    let result = backend
+                        .archive_session(session_id)
+                        .await
+                        .map_err(|e| e.to_string());
+                    let _ = tx
+                        .send(AppEvent::StateUpdate(StateUpdate::RestartFinished {
+                            backend_id: backend_id.0,
+                            kind: RestartKind::Archive,
+                            result,
+                        }))
+                        .await;
+                });
+            }
             ConfirmAction::ChangeProgram {
                 session_id,
                 program,
@@ -2874,13 +3409,16 @@ impl App {
                 let backend = self.backend_arc(backend_id);
                 let tx = self.event_loop.sender();
                 tokio::spawn(async move {
-                    if let Err(e) = backend.remove_project(project_id).await {
-                        let _ = tx
-                            .send(AppEvent::StateUpdate(StateUpdate::Error {
-                                message: format!("Failed to remove project: {e}"),
-                            }))
-                            .await;
-                    }
+                    let update = match backend.remove_project(project_id).await {
+                        Ok(()) => StateUpdate::UndoableActionFinished {
+                            backend_id: backend_id.0,
+                            message: "Project removed".to_string(),
+                        },
+                        Err(e) => StateUpdate::Error {
+                            message: format!("Failed to remove project: {e}"),
+                        },
+                    };
+                    let _ = tx.send(AppEvent::StateUpdate(update)).await;
                 });
             }
             ConfirmAction::AddRemoteServerAnyway { server } => {
@@ -12,6 +12,10 @@
 
 use super::*;
 
+/// How often an open Info surface re-samples the selected session's resource
+/// usage.
+const RESOURCE_SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+
 impl App {
     /// Whether an Info surface is currently showing: the `i` modal, or the list
     /// views' right-pane Info tab. The enriched-PR and AI-summary fetches feed
@@ -27,10 +31,11 @@ impl App {
     }
 
     /// Whether anything on screen currently consumes preview data: the list
-    /// views' right pane, or the Info modal's diffstat. False in board view with
-    /// no Info modal, so no per-tick tmux/git traffic runs there.
+    /// views' right pane, or the Info modal's diffstat. False in the board and
+    /// grid views with no Info modal, so no per-tick tmux/git traffic runs
+    /// there (the grid takes its own, lighter captures).
     fn preview_data_wanted(&self) -> bool {
-        !self.ui_state.view_mode.is_board() || self.is_info_open()
+        !self.ui_state.view_mode.is_full_screen() || self.is_info_open()
     }
 
     /// Spawn a background fetch of the selected session's (or project's) pane
@@ -182,6 +187,8 @@ impl App {
         };
         let session_id = sref.id;
 
+        self.spawn_resource_fetch(sref);
+
         // Read the session's PR number from its backend snapshot (always
         // populated), not the board — the board is only built in board view.
         let pr_number = match self.session(sref) {
@@ -251,6 +258,61 @@ impl App {
         }
     }
 
+    /// Sample the selected session's resource usage for the Info surface.
+    ///
+    /// Goes through `session_detail`, so a remote session reports the server
+    /// host's numbers. Throttled to one sample per `RESOURCE_SAMPLE_INTERVAL`
+    /// (also the window the CPU percentage is averaged over), and skipped for
+    /// sessions that aren't running.
+    fn spawn_resource_fetch(&mut self, sref: SessionRef) {
+        let session_id = sref.id;
+        // Selection moved: drop the old sample and the throttle so the new
+        // session's line fills in right away.
+        if self
+            .ui_state
+            .resource_usage
+            .as_ref()
+            .is_some_and(|(sid, _)| *sid != session_id)
+        {
+            self.ui_state.resource_usage = None;
+            self.ui_state.resource_fetch_spawned_at = None;
+        }
+        let running = self.session(sref).is_some_and(|s| s.status.is_active());
+        if !running {
+            self.ui_state.resource_usage = None;
+            return;
+        }
+        if self
+            .ui_state
+            .resource_fetch_spawned_at
+            .is_some_and(|at| at.elapsed() < RESOURCE_SAMPLE_INTERVAL)
+        {
+            return;
+        }
+
+        let backend = self.backend_arc(sref.backend);
+        let tx = self.event_loop.sender();
+        let spawned_at = Instant::now();
+        self.ui_state.resource_fetch_spawned_at = Some(spawned_at);
+        tokio::spawn(async move {
+            let query = session_id.as_uuid().to_string();
+            let usage = match backend.session_detail(&query, None).await {
+                Ok(detail) => detail.and_then(|d| d.resources),
+                Err(e) => {
+                    debug!("Resource usage fetch failed: {e}");
+                    None
+                }
+            };
+            let _ = tx
+                .send(AppEvent::StateUpdate(StateUpdate::ResourceUsageReady {
+                    spawned_at,
+                    session_id,
+                    usage,
+                }))
+                .await;
+        });
+    }
+
     /// Kick off a background `git lfs pull` for a session created with the
     /// LFS smudge skipped, so large files hydrate without blocking creation.
     /// Local sessions only: the worktree path in a remote session's snapshot
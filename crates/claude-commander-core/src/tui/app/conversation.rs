@@ -7,6 +7,16 @@
 //! depends on the UI event loop. The overlay is a *pure view*: it locks the
 //! model and renders it. This satisfies the "runs fully headless; the window is
 //! just a log + input" requirement.
+//!
+//! Every turn the view finalizes is also appended to the on-disk
+//! [`history`](crate::conversation::history), so a conversation survives
+//! restarts; Ctrl-r in the overlay browses, searches, resumes and exports past
+//! ones ([`HistoryBrowser`]).
+//!
+//! Besides the default agent, `[[conversation.profiles]]` name other agents,
+//! each with its own working directory, instructions and history. One is
+//! active at a time; Ctrl-a in the overlay (or `listen-toggle --agent`)
+//! switches, stopping the old agent's session and resuming the new one's.
 
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -14,10 +24,13 @@ use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use super::*;
+use crate::conversation::commands::voice_runnable;
 use crate::conversation::{
-    ConversationEvent, ConversationSession, ListenAction, ListenerHandle, MediaSignal,
-    SpeakerCommand, SpeakerHandle, apply_listen_action, media_signal, spawn_listener,
-    spawn_media_gate, spawn_speaker,
+    AnnouncerHandle, ConversationEvent, ConversationHistory, ConversationSession, HistoryLog,
+    HistoryTurn, ListenAction, ListenerEvent, ListenerHandle, MediaSignal, SpeakerCommand,
+    SpeakerHandle, StoredConversation, TurnRole, VoiceAction, VoiceCatalog, VoiceSession,
+    apply_listen_action, media_signal, new_conversation_id, recognise, spawn_announcer,
+    spawn_listener, spawn_media_gate, spawn_speaker,
 };
 
 /// Canonical project spinner frames (advanced every 3 render ticks).
@@ -50,11 +63,16 @@ You are *speaking*, not writing. Talk like a person would out loud.
 Don't guess about the user's sessions or projects — inspect the live state with
 the CLI (it needs no approval). Good first commands:
 - `claude-commander list` — all current sessions, their projects and status.
-- `claude-commander status <name>` — detail on one session.
-- `claude-commander log <name>` — recent output from a session.
+- `claude-commander brief <name>` — how one session is getting on: what its
+  agent is doing and printing, its diffstat, review comments waiting to be
+  sent, and its PR and CI status. Use it for \"how's the migration going?\".
+- `claude-commander log <name>` — more of a session's recent output.
 Run `claude-commander list` early when the user asks anything about what's
 going on. You can read anything on the filesystem the user can.
+";
 
+/// Added to the instructions of an agent running in its own scratch dir.
+const SCRATCH_DIR_NOTE: &str = "\
 ## Working directory
 This directory is your own scratch space; nothing else in it matters.
 ";
@@ -63,23 +81,67 @@ This directory is your own scratch space; nothing else in it matters.
 /// so the next launch resumes the same conversation via `--resume`.
 const SESSION_ID_FILE: &str = "session-id";
 
-/// Read the persisted session id to resume, if any. A missing/blank file means
-/// "start fresh" (e.g. the very first launch). Async (`tokio::fs`) so it never
-/// blocks the runtime from the spawn path.
-async fn read_resume_id(dir: &Path) -> Option<String> {
-    let id = tokio::fs::read_to_string(dir.join(SESSION_ID_FILE))
-        .await
-        .ok()?;
+/// File (in the conversation scratch dir) naming the conversation the history
+/// is being recorded into.
+const CONVERSATION_ID_FILE: &str = "conversation-id";
+
+/// Directory (in the conversation scratch dir) of recorded conversations.
+const HISTORY_DIR: &str = "history";
+
+/// Directory (in the conversation scratch dir) conversations are exported to.
+const EXPORTS_DIR: &str = "exports";
+
+/// Directory (in the default agent's scratch dir) holding each profile's own.
+const AGENTS_DIR: &str = "agents";
+
+/// An agent's state dir: its history, its resume ids and, when it has no `cwd`
+/// of its own, its working directory. `None` is the default agent.
+fn agent_dir(profile: Option<&str>) -> crate::error::Result<std::path::PathBuf> {
+    let dir = Config::data_dir()?.join("conversation");
+    Ok(match profile {
+        Some(name) => dir.join(AGENTS_DIR).join(agent_slug(name)),
+        None => dir,
+    })
+}
+
+/// A profile name made safe to use as a directory name.
+fn agent_slug(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// The agent `name` selects: `Some(None)` for the default agent, `Some(profile)`
+/// for a profile (its name as configured), `None` when no agent goes by it.
+fn agent_named(conv: &crate::config::ConversationConfig, name: &str) -> Option<Option<String>> {
+    if name.eq_ignore_ascii_case(&conv.name) {
+        return Some(None);
+    }
+    conv.profile(name).map(|p| Some(p.name.clone()))
+}
+
+/// Read a persisted id (`file` is [`SESSION_ID_FILE`] or
+/// [`CONVERSATION_ID_FILE`]), if any. A missing/blank file means "start fresh"
+/// (e.g. the very first launch). Async (`tokio::fs`) so it never blocks the
+/// runtime from the spawn path.
+async fn read_id(dir: &Path, file: &str) -> Option<String> {
+    let id = tokio::fs::read_to_string(dir.join(file)).await.ok()?;
     let id = id.trim();
     (!id.is_empty()).then(|| id.to_string())
 }
 
-/// Persist the current session id so the next launch can resume it. Best-effort:
-/// a write failure just means the next launch starts a fresh conversation.
-/// Async (`tokio::fs`) so it never blocks the bridge task.
-async fn write_resume_id(dir: &Path, id: &str) {
-    if let Err(e) = tokio::fs::write(dir.join(SESSION_ID_FILE), id).await {
-        warn!(target: "conversation", "failed to persist session id: {e}");
+/// Persist an id so the next launch picks it up. Best-effort: a write failure
+/// just means the next launch starts a fresh conversation. Async (`tokio::fs`)
+/// so it never blocks the bridge task.
+async fn write_id(dir: &Path, file: &str, id: &str) {
+    if let Err(e) = tokio::fs::write(dir.join(file), id).await {
+        warn!(target: "conversation", "failed to persist {file}: {e}");
     }
 }
 
@@ -95,6 +157,46 @@ pub enum ConvRole {
 pub struct ConvMessage {
     pub role: ConvRole,
     pub text: String,
+    pub at: chrono::DateTime<chrono::Utc>,
+    /// A voice transcript (user) or a reply read aloud (assistant).
+    pub spoken: bool,
+}
+
+impl ConvMessage {
+    fn new(role: ConvRole, text: String, spoken: bool) -> Self {
+        Self {
+            role,
+            text,
+            at: chrono::Utc::now(),
+            spoken,
+        }
+    }
+
+    fn from_turn(turn: &HistoryTurn) -> Self {
+        let role = match turn.role {
+            TurnRole::User => ConvRole::User,
+            TurnRole::Assistant => ConvRole::Assistant,
+        };
+        Self {
+            role,
+            text: turn.text.clone(),
+            at: turn.at,
+            spoken: turn.spoken,
+        }
+    }
+
+    fn to_turn(&self) -> HistoryTurn {
+        let role = match self.role {
+            ConvRole::User => TurnRole::User,
+            ConvRole::Assistant => TurnRole::Assistant,
+        };
+        HistoryTurn {
+            role,
+            text: self.text.clone(),
+            at: self.at,
+            spoken: self.spoken,
+        }
+    }
 }
 
 /// Lifecycle status of the conversation, shown in the overlay.
@@ -122,13 +224,26 @@ pub struct ConversationView {
     /// User messages sent while a turn was still in progress. The session queues
     /// them and answers them in order; we defer *displaying* each until the
     /// preceding reply completes, so history stays correctly ordered.
-    pub pending_user: std::collections::VecDeque<String>,
+    pub pending_user: std::collections::VecDeque<ConvMessage>,
     /// When the current agent turn began (user message handed to the session).
     /// Drives the stage-2 latency traces; `None` between turns.
     turn_started_at: Option<Instant>,
     /// Whether time-to-first-token has already been logged for this turn (so we
     /// emit it once, at the first delta).
     first_token_logged: bool,
+    /// The running turn was interrupted (barge-in); whatever result closes it,
+    /// error or not, just ends it.
+    interrupted: bool,
+    /// Where finalized turns are recorded. `None` until the session first
+    /// comes up.
+    log: Option<HistoryLog>,
+    /// Whether replies are being read aloud (a TTS speaker is up).
+    speaks_replies: bool,
+    /// The user cut in on the current reply, so it isn't being spoken.
+    reply_silenced: bool,
+    /// Bumped when the view switches conversation, so the previous session's
+    /// bridge stands down instead of applying its last events here.
+    generation: u64,
 }
 
 impl ConversationView {
@@ -138,6 +253,9 @@ impl ConversationView {
         match ev {
             ConversationEvent::Started { session_id } => {
                 self.session_id = Some(session_id.clone());
+                if let Some(log) = &self.log {
+                    log.session(session_id);
+                }
                 if self.status != ConvStatus::Thinking {
                     self.status = ConvStatus::Idle;
                 }
@@ -165,7 +283,12 @@ impl ConversationView {
                     self.streaming.push_str("\n\n");
                 }
             }
+            // An interrupted turn ends with an error result; that's expected.
+            ConversationEvent::Error(_) if self.interrupted => {
+                self.apply(&ConversationEvent::TurnComplete);
+            }
             ConversationEvent::TurnComplete => {
+                self.interrupted = false;
                 // Stage 2b: total agent turn — submission to last token.
                 if let Some(t0) = self.turn_started_at.take() {
                     tracing::debug!(
@@ -178,10 +301,8 @@ impl ConversationView {
                 // If the user queued a message while this reply streamed, show it
                 // now (after the reply) and stay Thinking — it's answered next.
                 if let Some(next) = self.pending_user.pop_front() {
-                    self.messages.push(ConvMessage {
-                        role: ConvRole::User,
-                        text: next,
-                    });
+                    self.record(next);
+                    self.reply_silenced = false;
                     self.status = ConvStatus::Thinking;
                     // The queued turn starts being answered now — restart the clock.
                     self.turn_started_at = Some(Instant::now());
@@ -192,11 +313,13 @@ impl ConversationView {
             }
             ConversationEvent::Error(e) => {
                 self.turn_started_at = None;
+                self.interrupted = false;
                 self.finalize_streaming();
                 self.status = ConvStatus::Error(e.clone());
             }
             ConversationEvent::Exited => {
                 self.turn_started_at = None;
+                self.interrupted = false;
                 self.finalize_streaming();
                 self.status = ConvStatus::Error("session ended".to_string());
             }
@@ -207,24 +330,34 @@ impl ConversationView {
         let text = std::mem::take(&mut self.streaming);
         let text = text.trim();
         if !text.is_empty() {
-            self.messages.push(ConvMessage {
-                role: ConvRole::Assistant,
-                text: text.to_string(),
-            });
+            let spoken = self.speaks_replies && !self.reply_silenced;
+            self.record(ConvMessage::new(
+                ConvRole::Assistant,
+                text.to_string(),
+                spoken,
+            ));
         }
     }
 
-    /// Record a user turn. Shown immediately when idle; when a reply is still in
-    /// progress it's queued for display until that reply completes (the session
-    /// has already received it and will answer it next).
-    fn push_user(&mut self, text: String) {
+    /// Show a finalized turn and append it to the history.
+    fn record(&mut self, message: ConvMessage) {
+        if let Some(log) = &self.log {
+            log.turn(message.to_turn());
+        }
+        self.messages.push(message);
+    }
+
+    /// Record a user turn (`spoken` when it's a voice transcript). Shown
+    /// immediately when idle; when a reply is still in progress it's queued for
+    /// display until that reply completes (the session has already received it
+    /// and will answer it next).
+    fn push_user(&mut self, text: String, spoken: bool) {
+        let message = ConvMessage::new(ConvRole::User, text, spoken);
         if self.status == ConvStatus::Thinking {
-            self.pending_user.push_back(text);
+            self.pending_user.push_back(message);
         } else {
-            self.messages.push(ConvMessage {
-                role: ConvRole::User,
-                text,
-            });
+            self.record(message);
+            self.reply_silenced = false;
             self.status = ConvStatus::Thinking;
             self.last_delta_at = None;
             // Start the stage-2 clock: this turn is now in the agent's hands.
@@ -233,6 +366,54 @@ impl ConversationView {
         }
     }
 
+    /// Note that the running turn is being interrupted. `false` (and nothing
+    /// noted) when no turn is running, so there's nothing to interrupt.
+    fn begin_interrupt(&mut self) -> bool {
+        if self.status != ConvStatus::Thinking {
+            return false;
+        }
+        self.interrupted = true;
+        true
+    }
+
+    /// The user cut in: the current reply is no longer being spoken.
+    fn silence_reply(&mut self) {
+        self.reply_silenced = true;
+    }
+
+    /// Switch to `conversation`: show its turns and forget everything about the
+    /// one before, whose session's last events are ignored from here on.
+    fn load(&mut self, conversation: &StoredConversation) {
+        let generation = self.generation + 1;
+        let log = self.log.take();
+        let speaks_replies = self.speaks_replies;
+        *self = Self {
+            messages: conversation
+                .turns
+                .iter()
+                .map(ConvMessage::from_turn)
+                .collect(),
+            session_id: conversation.session_id.clone(),
+            log,
+            speaks_replies,
+            generation,
+            ..Self::default()
+        };
+        if let Some(log) = &self.log {
+            log.switch(&conversation.id);
+        }
+    }
+
+    /// Forget the conversation, ahead of switching agent; the next session
+    /// start loads the new agent's history. The old session's last events are
+    /// ignored from here on.
+    fn reset(&mut self) {
+        *self = Self {
+            generation: self.generation + 1,
+            ..Self::default()
+        };
+    }
+
     fn set_error(&mut self, msg: impl Into<String>) {
         self.status = ConvStatus::Error(msg.into());
     }
@@ -262,11 +443,29 @@ pub struct ConversationRuntime {
     /// Media-gate signal channel: pauses other players for the voice turn and
     /// resumes them after the reply. `None` when the feature is disabled.
     pub gate: Option<tokio::sync::mpsc::UnboundedSender<MediaSignal>>,
+    /// The agent being talked to: a profile's name, or `None` for the default
+    /// agent. Shared with the off-loop transcript task, which switches it when
+    /// `listen-toggle --agent` asks for another.
+    pub agent: Arc<Mutex<Option<String>>>,
+    /// The agent `listen-toggle --agent` asked for, set by the IPC task and
+    /// taken by the next transcript.
+    pub voice_agent: Arc<Mutex<Option<String>>>,
     /// Respawn-stable handle to the current session's TTS speaker. Held here so
     /// the long-lived listener (interrupt on record-start) and the submit path
     /// (resume for the new reply) reach the speaker even as it's recreated on
     /// each session respawn.
     pub speaker: SpeakerHandle,
+    /// What voice commands are matched against — the board's sessions and the
+    /// palette commands — rebuilt on every list refresh and read by the
+    /// off-loop transcript task. `None` while voice commands are off, so every
+    /// transcript goes to the agent.
+    pub voice_catalog: Arc<Mutex<Option<VoiceCatalog>>>,
+    /// Spoken announcements of session state changes. Fed by every backend's
+    /// change feed; spoken through [`speaker`](Self::speaker).
+    pub announcer: AnnouncerHandle,
+    /// Whether the announcer task is running (started the first time
+    /// announcements are enabled).
+    announcer_started: bool,
 }
 
 impl ConversationRuntime {
@@ -285,15 +484,60 @@ impl ConversationRuntime {
     }
 }
 
-/// Write one user turn to the session and record it in the shared view. Returns
-/// `false` if there's no live session (caller may respawn and retry). Lives free
-/// of `&App` so it can run off the UI loop — from the typed-input path *and* from
-/// the off-loop voice-submit task (which keeps working during a tmux attach).
+/// The overlay's browser of past conversations (Ctrl-r): every recorded
+/// conversation, most recently active first, filtered as the query is typed.
+#[derive(Debug, Clone, Default)]
+pub struct HistoryBrowser {
+    pub query: Input,
+    /// Every recorded conversation, loaded when the browser opens.
+    pub conversations: Vec<StoredConversation>,
+    /// The conversations matching `query`: an index into `conversations` and
+    /// the first matching turn.
+    pub matches: Vec<(usize, usize)>,
+    pub selected: usize,
+    /// The outcome of the last export, shown under the list.
+    pub notice: Option<String>,
+}
+
+impl HistoryBrowser {
+    fn new(conversations: Vec<StoredConversation>) -> Self {
+        let mut browser = Self {
+            conversations,
+            ..Self::default()
+        };
+        browser.refilter();
+        browser
+    }
+
+    /// Recompute `matches` for the current query, keeping the selection in range.
+    fn refilter(&mut self) {
+        let query = self.query.value();
+        self.matches = self
+            .conversations
+            .iter()
+            .enumerate()
+            .filter_map(|(i, c)| c.find(query).map(|turn| (i, turn)))
+            .collect();
+        self.selected = self.selected.min(self.matches.len().saturating_sub(1));
+    }
+
+    fn selected_conversation(&self) -> Option<&StoredConversation> {
+        let (i, _) = self.matches.get(self.selected)?;
+        self.conversations.get(*i)
+    }
+}
+
+/// Write one user turn to the session and record it in the shared view
+/// (`spoken` for a voice transcript). Returns `false` if there's no live session
+/// (caller may respawn and retry). Lives free of `&App` so it can run off the UI
+/// loop — from the typed-input path *and* from the off-loop voice-submit task
+/// (which keeps working during a tmux attach).
 async fn submit_to_session(
     session: &Arc<tokio::sync::Mutex<Option<ConversationSession>>>,
     view: &Arc<Mutex<ConversationView>>,
     speaker: &SpeakerHandle,
     text: String,
+    spoken: bool,
 ) -> bool {
     let mut guard = session.lock().await;
     let Some(s) = guard.as_mut() else {
@@ -302,7 +546,7 @@ async fn submit_to_session(
     match s.send_user_message(&text).await {
         Ok(()) => {
             drop(guard);
-            view.lock().unwrap().push_user(text);
+            view.lock().unwrap().push_user(text, spoken);
             // The new query is in — lift any mute set when the user started
             // recording, so its reply (not the interrupted one) is spoken.
             speaker.send(SpeakerCommand::Resume);
@@ -315,13 +559,93 @@ async fn submit_to_session(
     }
 }
 
-/// Spawn the headless `claude` session, the TTS speaker, and the off-loop bridge
-/// that feeds the speaker + updates the shared model. Idempotent (a no-op if a
-/// session is already running). Free of `&App` so it can run off the UI loop —
-/// the listener's submit task calls it to lazily heal the session when a voice
-/// transcript arrives before the conversation was ever opened.
+/// Interrupt the agent's running turn, if there is one — the second half of a
+/// barge-in when `conversation.interrupt_turn` is on. Its reply so far stays in
+/// the history. Free of `&App` for the same reason as [`submit_to_session`].
+async fn interrupt_turn(
+    session: &Arc<tokio::sync::Mutex<Option<ConversationSession>>>,
+    view: &Arc<Mutex<ConversationView>>,
+) {
+    if !view.lock().unwrap().begin_interrupt() {
+        return;
+    }
+    let mut guard = session.lock().await;
+    let sent = match guard.as_mut() {
+        Some(s) => s.interrupt().await,
+        None => return,
+    };
+    if let Err(e) = sent {
+        tracing::warn!(target: "conversation", "interrupt failed: {e}");
+        view.lock().unwrap().interrupted = false;
+    }
+}
+
+/// Start recording into the current conversation's history (a new one on the
+/// very first launch) and, on a fresh view, show where it left off. A no-op
+/// once recording has started.
+async fn start_history_log(dir: &Path, view: &Arc<Mutex<ConversationView>>) {
+    if view.lock().unwrap().log.is_some() {
+        return;
+    }
+    let history = ConversationHistory::new(dir.join(HISTORY_DIR));
+    let id = match read_id(dir, CONVERSATION_ID_FILE).await {
+        Some(id) => id,
+        None => {
+            let id = new_conversation_id();
+            write_id(dir, CONVERSATION_ID_FILE, &id).await;
+            id
+        }
+    };
+    let stored = history.load(&id).await.unwrap_or_else(|e| {
+        warn!(target: "conversation", "failed to read conversation history: {e}");
+        StoredConversation::default()
+    });
+    let mut v = view.lock().unwrap();
+    if v.log.is_some() {
+        return;
+    }
+    if v.messages.is_empty() {
+        v.messages = stored.turns.iter().map(ConvMessage::from_turn).collect();
+    }
+    v.log = Some(HistoryLog::spawn(history, id));
+}
+
+/// Stop the running session and clear the view, making `profile` the active
+/// agent (`None` is the default one). Returns `false`, doing nothing, when it
+/// already is. The caller starts the new agent's session.
+async fn switch_agent(
+    profile: Option<String>,
+    agent: &Arc<Mutex<Option<String>>>,
+    session: &Arc<tokio::sync::Mutex<Option<ConversationSession>>>,
+    view: &Arc<Mutex<ConversationView>>,
+    speaker: &SpeakerHandle,
+) -> bool {
+    {
+        let mut active = agent.lock().unwrap();
+        if *active == profile {
+            return false;
+        }
+        *active = profile;
+    }
+    speaker.send(SpeakerCommand::Interrupt);
+    // Clear the view first, so the old session's bridge stands down instead
+    // of reporting its exit to the new agent's conversation.
+    view.lock().unwrap().reset();
+    if let Some(mut s) = session.lock().await.take() {
+        s.shutdown().await;
+    }
+    true
+}
+
+/// Spawn the headless `claude` session for the active agent, the TTS speaker,
+/// and the off-loop bridge that feeds the speaker + updates the shared model.
+/// Idempotent (a no-op if a session is already running). Free of `&App` so it
+/// can run off the UI loop — the listener's submit task calls it to lazily heal
+/// the session when a voice transcript arrives before the conversation was ever
+/// opened.
 async fn spawn_session_runtime(
-    conv: &crate::config::ConversationConfig,
+    base: &crate::config::ConversationConfig,
+    agent: &Arc<Mutex<Option<String>>>,
     gate: Option<tokio::sync::mpsc::UnboundedSender<MediaSignal>>,
     session: &Arc<tokio::sync::Mutex<Option<ConversationSession>>>,
     view: &Arc<Mutex<ConversationView>>,
@@ -331,8 +655,10 @@ async fn spawn_session_runtime(
     if session.lock().await.is_some() {
         return;
     }
-    let dir = match Config::data_dir() {
-        Ok(d) => d.join("conversation"),
+    let profile = agent.lock().unwrap().clone();
+    let conv = &base.agent(profile.as_deref());
+    let dir = match agent_dir(profile.as_deref()) {
+        Ok(dir) => dir,
         Err(e) => {
             view.lock().unwrap().set_error(format!("no data dir: {e}"));
             return;
@@ -342,20 +668,45 @@ async fn spawn_session_runtime(
         view.lock().unwrap().set_error(format!("mkdir failed: {e}"));
         return;
     }
+    start_history_log(&dir, view).await;
 
-    // Seed CLAUDE.md so the agent knows it's a (spoken) Claude Commander
-    // assistant and how to inspect live session/project state. Rewritten on
-    // each (re)spawn so the embedded CLI reference stays current.
-    let prime = CONVERSATION_PRIME.replace("{name}", &conv.name);
-    let claude_md = format!("{}\n{}", prime.trim_end(), cli_reference);
-    if let Err(e) = tokio::fs::write(dir.join("CLAUDE.md"), claude_md).await {
-        warn!(target: "conversation", "failed to write CLAUDE.md: {e}");
-    }
+    // Tell the agent it's a (spoken) Claude Commander assistant and how to
+    // inspect live session/project state. Rebuilt on each (re)spawn so the
+    // embedded CLI reference stays current.
+    let prime = match &conv.system_prompt_file {
+        Some(path) => tokio::fs::read_to_string(path).await.unwrap_or_else(|e| {
+            warn!(target: "conversation", "failed to read {}: {e}", path.display());
+            CONVERSATION_PRIME.to_string()
+        }),
+        None => CONVERSATION_PRIME.to_string(),
+    };
+    let prime = prime.replace("{name}", &conv.name);
+    // In its scratch dir the instructions are its CLAUDE.md; in a directory of
+    // the user's (a repo, say) they're appended to the system prompt instead,
+    // leaving that directory's own CLAUDE.md alone.
+    let (cwd, system_prompt) = match &conv.cwd {
+        Some(cwd) => {
+            let prompt = format!("{}\n\n{}", prime.trim_end(), cli_reference);
+            (cwd.clone(), Some(prompt))
+        }
+        None => {
+            let claude_md = format!(
+                "{}\n\n{}{}",
+                prime.trim_end(),
+                SCRATCH_DIR_NOTE,
+                cli_reference
+            );
+            if let Err(e) = tokio::fs::write(dir.join("CLAUDE.md"), claude_md).await {
+                warn!(target: "conversation", "failed to write CLAUDE.md: {e}");
+            }
+            (dir.clone(), None)
+        }
+    };
 
     // Streaming-TTS speaker (fed directly by the bridge, off the UI loop).
     // Failure (e.g. no audio device) is non-fatal: chat still works, silent.
     let speaker = if conv.enabled {
-        match spawn_speaker(conv.clone(), gate.clone()) {
+        match spawn_speaker(conv.clone(), gate.clone(), speaker_handle.speaking()) {
             Ok(tx) => Some(tx),
             Err(e) => {
                 warn!(target: "conversation", "TTS unavailable: {e}");
@@ -368,16 +719,18 @@ async fn spawn_session_runtime(
     // Publish the (possibly None) speaker so the long-lived listener/submit paths
     // reach this session's speaker; overwrites any prior speaker on respawn.
     speaker_handle.set(speaker.clone());
+    view.lock().unwrap().speaks_replies = speaker.is_some();
 
     let (ev_tx, mut ev_rx) = tokio::sync::mpsc::unbounded_channel::<ConversationEvent>();
     // Resume the previous conversation if we have a stored session id, so the
     // agent keeps its history (and memory of the user) across restarts.
-    let resume = read_resume_id(&dir).await;
+    let resume = read_id(&dir, SESSION_ID_FILE).await;
     match ConversationSession::spawn(
         &conv.command,
         &conv.permission_mode,
-        &dir,
+        &cwd,
         resume.as_deref(),
+        system_prompt.as_deref(),
         ev_tx,
     ) {
         Ok(s) => *session.lock().await = Some(s),
@@ -394,11 +747,20 @@ async fn spawn_session_runtime(
     let bridge_view = view.clone();
     let bridge_dir = dir.clone();
     let bridge_gate = gate.clone();
+    let generation = view.lock().unwrap().generation;
     tokio::spawn(async move {
         while let Some(ev) = ev_rx.recv().await {
+            // The view moved on to another conversation; this session is over.
+            if bridge_view.lock().unwrap().generation != generation {
+                break;
+            }
             // Let the media gate know the text turn finished, so it can resume
-            // media once the spoken reply (if any) has drained.
-            if matches!(ev, ConversationEvent::TurnComplete) {
+            // media once the spoken reply (if any) has drained. An interrupted
+            // turn finishes with an error result.
+            let interrupted = bridge_view.lock().unwrap().interrupted;
+            if matches!(ev, ConversationEvent::TurnComplete)
+                || (interrupted && matches!(ev, ConversationEvent::Error(_)))
+            {
                 media_signal(&bridge_gate, MediaSignal::TurnComplete);
             }
             if matches!(ev, ConversationEvent::Delta(_)) {
@@ -410,7 +772,7 @@ async fn spawn_session_runtime(
             // conversation. Claude may fork a fresh id on resume, so record
             // whatever the latest init reports.
             if let ConversationEvent::Started { session_id } = &ev {
-                write_resume_id(&bridge_dir, session_id).await;
+                write_id(&bridge_dir, SESSION_ID_FILE, session_id).await;
             }
             if let Some(sp) = &speaker
                 && let Some(cmd) = crate::conversation::speaker_command_for(&ev)
@@ -445,6 +807,8 @@ impl App {
         self.ui_state.modal = Modal::Conversation {
             input: Input::default(),
             scroll: 0,
+            history: None,
+            agents: None,
         };
     }
 
@@ -457,6 +821,7 @@ impl App {
         let gate = self.conversation.gate.clone();
         spawn_session_runtime(
             &conv,
+            &self.conversation.agent,
             gate,
             &self.conversation.session,
             &self.conversation.view,
@@ -517,33 +882,104 @@ impl App {
     fn build_and_store_listener(&mut self) {
         let gate = self.conversation.gate.clone();
         let speaker = self.conversation.speaker.clone();
-        let (tx_text, mut rx_text) = tokio::sync::mpsc::unbounded_channel::<String>();
+        let (tx_events, mut rx_events) = tokio::sync::mpsc::unbounded_channel::<ListenerEvent>();
         let tx = spawn_listener(
             self.config.stt.clone(),
-            tx_text,
+            tx_events,
             gate.clone(),
             speaker.clone(),
+            self.conversation.recording.clone(),
         );
         self.conversation.listener.replace(tx);
         let session = self.conversation.session.clone();
         let view = self.conversation.view.clone();
+        let agent = self.conversation.agent.clone();
+        let voice_agent = self.conversation.voice_agent.clone();
         let conv = self.config.conversation.clone();
         // The lazy-heal path below writes the conversation's CLAUDE.md, so this
         // off-loop task needs its own copy of the injected CLI reference.
         let cli_reference = self.cli_reference.clone();
+        let catalog = self.conversation.voice_catalog.clone();
+        let events = self.event_loop.sender();
         tokio::spawn(async move {
-            while let Some(text) = rx_text.recv().await {
-                let text = text.trim().to_string();
+            while let Some(event) = rx_events.recv().await {
+                let text = match event {
+                    ListenerEvent::Transcript(text) => text.trim().to_string(),
+                    // The listener has already silenced the reply.
+                    ListenerEvent::BargeIn => {
+                        view.lock().unwrap().silence_reply();
+                        if conv.interrupt_turn {
+                            interrupt_turn(&session, &view).await;
+                        }
+                        continue;
+                    }
+                };
+                // `listen-toggle --agent` asked for this to go to another agent.
+                let requested = voice_agent.lock().unwrap().take();
+                if let Some(name) = requested {
+                    match agent_named(&conv, &name) {
+                        Some(profile) => {
+                            switch_agent(profile, &agent, &session, &view, &speaker).await;
+                        }
+                        None => {
+                            warn!(target: "conversation", "no conversation agent named {name:?}")
+                        }
+                    }
+                }
                 if text.is_empty() {
                     continue;
                 }
+                // A simple request the TUI can run itself skips the agent: speak
+                // the confirmation now and queue the action for the loop (which
+                // runs it once any tmux attach returns).
+                let recognised = catalog
+                    .lock()
+                    .unwrap()
+                    .as_ref()
+                    .and_then(|c| recognise(&text, c));
+                if let Some(recognised) = recognised {
+                    debug!(
+                        target: "conversation",
+                        "voice command: {text:?} → {:?}",
+                        recognised.action
+                    );
+                    // The speaker lives with the session; bring it up if this is
+                    // the first thing said.
+                    spawn_session_runtime(
+                        &conv,
+                        &agent,
+                        gate.clone(),
+                        &session,
+                        &view,
+                        &speaker,
+                        &cli_reference,
+                    )
+                    .await;
+                    // Unmute (recording muted the speaker) and say it; the gate
+                    // resumes paused media once the confirmation has played.
+                    speaker.send(SpeakerCommand::Resume);
+                    speaker.send(SpeakerCommand::Chunk(recognised.reply));
+                    speaker.send(SpeakerCommand::Flush);
+                    media_signal(&gate, MediaSignal::TurnComplete);
+                    if let Some(action) = recognised.action {
+                        let update = StateUpdate::VoiceCommand {
+                            transcript: text,
+                            action,
+                        };
+                        if events.send(AppEvent::StateUpdate(update)).await.is_err() {
+                            break; // app gone
+                        }
+                    }
+                    continue;
+                }
                 // Submit directly (off the UI loop) so voice input works even
                 // while the main loop is parked in a tmux attach. If no session
                 // is up yet (transcript arrived before the overlay was opened),
                 // spawn one and retry once.
-                if !submit_to_session(&session, &view, &speaker, text.clone()).await {
+                if !submit_to_session(&session, &view, &speaker, text.clone(), true).await {
                     spawn_session_runtime(
                         &conv,
+                        &agent,
                         gate.clone(),
                         &session,
                         &view,
@@ -551,7 +987,7 @@ impl App {
                         &cli_reference,
                     )
                     .await;
-                    submit_to_session(&session, &view, &speaker, text).await;
+                    submit_to_session(&session, &view, &speaker, text, true).await;
                 }
             }
         });
@@ -573,8 +1009,9 @@ impl App {
         // desktop hotkey driving the *current* listener.
         let listener = self.conversation.listener.clone();
         let recording = self.conversation.recording.clone();
+        let voice_agent = self.conversation.voice_agent.clone();
         let path = crate::conversation::ipc::default_socket_path();
-        if let Err(e) = crate::conversation::ipc::serve(path, listener, recording) {
+        if let Err(e) = crate::conversation::ipc::serve(path, listener, recording, voice_agent) {
             warn!(target: "conversation", "voice IPC socket unavailable: {e}");
         }
     }
@@ -589,6 +1026,7 @@ impl App {
             &self.conversation.view,
             &self.conversation.speaker,
             text.clone(),
+            false,
         )
         .await
         {
@@ -602,6 +1040,7 @@ impl App {
             &self.conversation.view,
             &self.conversation.speaker,
             text,
+            false,
         )
         .await
         {
@@ -613,6 +1052,113 @@ impl App {
         }
     }
 
+    /// Barge in from the overlay: stop the spoken reply at once (it stays
+    /// muted until the next message) and, with `interrupt_turn`, the agent's
+    /// turn too. Alt-V and `listen-toggle` barge in through the listener.
+    pub(super) async fn barge_in(&mut self) {
+        self.conversation.speaker.send(SpeakerCommand::Interrupt);
+        self.conversation.view.lock().unwrap().silence_reply();
+        if self.config.conversation.interrupt_turn {
+            interrupt_turn(&self.conversation.session, &self.conversation.view).await;
+        }
+    }
+
+    /// The active agent's profile name (`None` for the default agent).
+    fn active_agent(&self) -> Option<String> {
+        self.conversation.agent.lock().unwrap().clone()
+    }
+
+    /// What the active agent is called.
+    fn active_agent_name(&self) -> String {
+        let active = self.conversation.agent.lock().unwrap();
+        match active
+            .as_deref()
+            .and_then(|n| self.config.conversation.profile(n))
+        {
+            Some(profile) => profile.name.clone(),
+            None => self.config.conversation.name.clone(),
+        }
+    }
+
+    /// Switch the conversation to another agent (`None` is the default one),
+    /// stopping the current agent's session and starting the new one's, which
+    /// picks up its own last conversation.
+    pub(super) async fn select_conversation_agent(&mut self, profile: Option<String>) {
+        if switch_agent(
+            profile,
+            &self.conversation.agent,
+            &self.conversation.session,
+            &self.conversation.view,
+            &self.conversation.speaker,
+        )
+        .await
+        {
+            self.ensure_conversation_started().await;
+        }
+    }
+
+    /// Ctrl-r: browse past conversations. Reads every recorded conversation,
+    /// so the list is current each time it opens.
+    pub(super) async fn open_conversation_history(&mut self) {
+        let listed = match agent_dir(self.active_agent().as_deref()) {
+            Ok(dir) => ConversationHistory::new(dir.join(HISTORY_DIR))
+                .list()
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        let browser = match listed {
+            Ok(conversations) => HistoryBrowser::new(conversations),
+            Err(e) => HistoryBrowser {
+                notice: Some(format!("Couldn't read past conversations: {e}")),
+                ..HistoryBrowser::default()
+            },
+        };
+        if let Modal::Conversation { history, .. } = &mut self.ui_state.modal {
+            *history = Some(Box::new(browser));
+        }
+    }
+
+    /// Switch to `conversation`: stop the current session and relaunch
+    /// `claude` resuming the conversation's latest session (a fresh one when it
+    /// has none), recording into its history from here on.
+    pub(super) async fn switch_conversation(&mut self, conversation: StoredConversation) {
+        let dir = match agent_dir(self.active_agent().as_deref()) {
+            Ok(dir) => dir,
+            Err(e) => {
+                self.set_status_message(format!("No data dir: {e}"), 4);
+                return;
+            }
+        };
+        self.conversation.speaker.send(SpeakerCommand::Interrupt);
+        // Move the view on first, so the old session's bridge stands down
+        // instead of reporting its exit in the new conversation.
+        self.conversation.view.lock().unwrap().load(&conversation);
+        if let Some(mut session) = self.conversation.session.lock().await.take() {
+            session.shutdown().await;
+        }
+        if let Err(e) = tokio::fs::create_dir_all(&dir).await {
+            warn!(target: "conversation", "failed to create {}: {e}", dir.display());
+        }
+        write_id(&dir, CONVERSATION_ID_FILE, &conversation.id).await;
+        let resume = conversation.session_id.as_deref().unwrap_or_default();
+        write_id(&dir, SESSION_ID_FILE, resume).await;
+        self.ensure_conversation_started().await;
+    }
+
+    /// Write `conversation` out as markdown, returning a line saying where.
+    async fn export_conversation(&self, conversation: &StoredConversation) -> String {
+        let dir = match agent_dir(self.active_agent().as_deref()) {
+            Ok(dir) => dir.join(EXPORTS_DIR),
+            Err(e) => return format!("Export failed: {e}"),
+        };
+        let name = self.active_agent_name();
+        match ConversationHistory::export_markdown(conversation, &name, &dir).await {
+            Ok(path) => format!("Exported to {}", path.display()),
+            Err(e) => format!("Export failed: {e}"),
+        }
+    }
+
     /// Alt-V: toggle voice input. First press starts recording the microphone;
     /// the next press stops it and submits the transcript to the conversation
     /// agent. Works whether the overlay is open or not, mirroring spoken replies.
@@ -633,6 +1179,143 @@ impl App {
         }
     }
 
+    /// Rebuild the voice-command catalog from every backend's snapshot (sessions
+    /// with their board numbers and agent states) and the palette's commands.
+    /// Called on each list refresh; clears it while voice commands are off.
+    pub(super) fn refresh_voice_catalog(&self) {
+        let stt = &self.config.stt;
+        let catalog = (stt.enabled && stt.voice_commands).then(|| {
+            let numbers = self.voice_session_numbers();
+            let sessions = self
+                .backends
+                .iter()
+                .flat_map(|h| {
+                    let numbers = &numbers;
+                    h.view.snapshot.sessions.iter().map(move |s| VoiceSession {
+                        id: s.session_id,
+                        number: numbers.get(&s.session_id).copied(),
+                        title: s.title.clone(),
+                        branch: s.branch.clone(),
+                        agent_state: h.view.agent_states.states.get(&s.session_id).copied(),
+                    })
+                })
+                .collect();
+            // Every palette command, available or not: availability follows
+            // the selection, so it is checked when the command runs.
+            let commands = BindableAction::ALL
+                .iter()
+                .copied()
+                .filter(|&a| is_palette_command(a) && voice_runnable(a))
+                .map(|a| (a, a.description()))
+                .collect();
+            VoiceCatalog { sessions, commands }
+        });
+        *self.conversation.voice_catalog.lock().unwrap() = catalog;
+    }
+
+    /// Apply the announcement settings and, the first time they are on, start
+    /// the task that speaks them. Called at startup and whenever they change.
+    pub(super) fn ensure_announcer_started(&mut self) {
+        self.conversation.announcer.configure(
+            self.config.announcements.clone(),
+            self.config.conversation.clone(),
+        );
+        if self.conversation.announcer_started || !self.config.announcements.enabled {
+            return;
+        }
+        self.conversation.announcer_started = true;
+        self.refresh_announcer_numbers();
+        // Never over the conversation: not while recording, nor while a reply
+        // is streaming (one that has finished streaming is already queued in
+        // the speaker ahead of anything announced).
+        let view = self.conversation.view.clone();
+        let recording = self.conversation.recording.clone();
+        spawn_announcer(
+            self.conversation.announcer.clone(),
+            self.conversation.speaker.clone(),
+            self.conversation.gate.clone(),
+            move || {
+                if recording.load(Ordering::Acquire) {
+                    return true;
+                }
+                let view = view.lock().unwrap();
+                view.status == ConvStatus::Thinking || !view.streaming.is_empty()
+            },
+        );
+    }
+
+    /// Hand the announcer the session numbers the view shows, so it says
+    /// "Session 3" for the session labelled 3. Called on each list refresh.
+    pub(super) fn refresh_announcer_numbers(&self) {
+        if self.conversation.announcer.is_enabled() {
+            self.conversation
+                .announcer
+                .set_numbers(self.voice_session_numbers());
+        }
+    }
+
+    /// Session numbers as the current view shows them: the board's (also used
+    /// by the grid), or the order of worktree rows in a list view.
+    fn voice_session_numbers(&self) -> HashMap<SessionId, usize> {
+        if self.ui_state.view_mode.is_full_screen() {
+            return self.ui_state.session_numbers.clone();
+        }
+        self.ui_state
+            .list_items
+            .iter()
+            .filter_map(|item| match item {
+                SessionListItem::Worktree { id, .. } => Some(*id),
+                _ => None,
+            })
+            .zip(1..)
+            .collect()
+    }
+
+    /// Run a recognised voice command. Its spoken confirmation has already
+    /// played; the toast echoes what was heard. Commands act on the main view,
+    /// so the conversation overlay steps aside — any other dialog is left alone
+    /// rather than discarding what's in it.
+    pub(super) async fn handle_voice_command(&mut self, transcript: String, action: VoiceAction) {
+        match self.ui_state.modal {
+            Modal::None => {}
+            Modal::Conversation { .. } => self.ui_state.modal = Modal::None,
+            _ => {
+                self.set_status_message(
+                    format!("🎙 \"{transcript}\" — close the open dialog first"),
+                    4,
+                );
+                return;
+            }
+        }
+        self.set_status_message(format!("🎙 \"{transcript}\""), 4);
+        match action {
+            VoiceAction::Attach(id) | VoiceAction::Review(id) | VoiceAction::Select(id) => {
+                self.unhide_session(id).await;
+                if !self.select_session_in_tree(id) {
+                    self.set_status_message("🎙 That session is no longer on the board", 4);
+                    return;
+                }
+                match action {
+                    VoiceAction::Attach(_) => self.handle_select().await,
+                    VoiceAction::Review(_) => {
+                        self.handle_command(UserCommand::OpenReviewDiff).await
+                    }
+                    _ => {}
+                }
+            }
+            VoiceAction::Run(command) => {
+                if self.ui_state.is_command_available(command) {
+                    self.handle_command(command.into()).await;
+                } else {
+                    self.set_status_message(
+                        format!("🎙 \"{}\" isn't available here", command.description()),
+                        4,
+                    );
+                }
+            }
+        }
+    }
+
     /// Show a transient status-bar message for `secs` seconds.
     fn set_status_message(&mut self, msg: impl Into<String>, secs: u64) {
         self.ui_state.status_message = Some((
@@ -648,10 +1331,18 @@ impl App {
         area: Rect,
         input: &Input,
         scroll: u16,
+        history: Option<&HistoryBrowser>,
+        agents: Option<usize>,
     ) {
         frame.render_widget(Clear, area);
+        // Name the agent once there's more than one to talk to.
+        let title = if self.config.conversation.profiles.is_empty() {
+            " Conversation ".to_string()
+        } else {
+            format!(" Conversation · {} ", self.active_agent_name())
+        };
         let block = Block::default()
-            .title(" Conversation ")
+            .title(title)
             .borders(Borders::ALL)
             .border_type(self.border_type())
             .border_style(Style::default().fg(self.theme.modal_info));
@@ -669,17 +1360,33 @@ impl App {
             .constraints([Constraint::Min(1), Constraint::Length(3)])
             .split(inner);
 
+        if let Some(browser) = history {
+            self.render_history_browser(frame, chunks[0], chunks[1], browser);
+            return;
+        }
+        if let Some(selected) = agents {
+            self.render_agent_picker(frame, chunks[0], selected);
+            return;
+        }
+
         // Build the history lines from the shared model.
         let width = chunks[0].width.max(1) as usize;
+        let assistant = self.active_agent_name();
         let mut lines: Vec<Line> = Vec::new();
         {
             let view = self.conversation.view.lock().unwrap();
             for msg in &view.messages {
-                self.push_message_lines(&mut lines, msg.role, &msg.text, width);
+                self.push_message_lines(&mut lines, msg.role, &msg.text, &assistant, width);
             }
             if !view.streaming.is_empty() {
                 // The reply is arriving — the text itself is the progress indicator.
-                self.push_message_lines(&mut lines, ConvRole::Assistant, &view.streaming, width);
+                self.push_message_lines(
+                    &mut lines,
+                    ConvRole::Assistant,
+                    &view.streaming,
+                    &assistant,
+                    width,
+                );
             }
             // Progress / error indicator below the (partial) reply.
             match &view.status {
@@ -715,7 +1422,7 @@ impl App {
         }
         if lines.is_empty() {
             lines.push(Line::from(Span::styled(
-                "Type a message and press Enter. Replies stream in and are spoken aloud.",
+                "Type a message and press Enter. Replies stream in and are spoken aloud (Ctrl-x cuts in, Ctrl-r opens past conversations, Ctrl-a switches agent).",
                 Style::default().fg(self.theme.text_secondary),
             )));
         }
@@ -784,20 +1491,180 @@ impl App {
         ));
     }
 
-    /// Append a message's wrapped lines (role header + body) to `lines`.
+    /// Render the past-conversations browser in place of the live log: the
+    /// matching conversations in `list_area`, the search query in `query_area`.
+    fn render_history_browser(
+        &self,
+        frame: &mut Frame,
+        list_area: Rect,
+        query_area: Rect,
+        browser: &HistoryBrowser,
+    ) {
+        let query = browser.query.value();
+        let width = list_area.width.max(1) as usize;
+        // Two rows per conversation while searching (title + matching turn).
+        let per_row = if query.is_empty() { 1 } else { 2 };
+        let mut lines: Vec<Line> = Vec::new();
+        for (row, &(i, turn)) in browser.matches.iter().enumerate() {
+            let conv = &browser.conversations[i];
+            let when = conv
+                .updated_at()
+                .map(|t| {
+                    t.with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string()
+                })
+                .unwrap_or_default();
+            let text = format!("{when}  {}  ({} turns)", conv.title(), conv.turns.len());
+            let style = if row == browser.selected {
+                Style::default()
+                    .fg(self.theme.conversation_accent)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default().fg(self.theme.text_primary)
+            };
+            lines.push(Line::from(Span::styled(
+                super::settings::truncate_str(&text, width),
+                style,
+            )));
+            if per_row == 2 {
+                let snippet = conv.turns[turn].text.split_whitespace().collect::<Vec<_>>();
+                lines.push(Line::from(Span::styled(
+                    super::settings::truncate_str(&format!("    {}", snippet.join(" ")), width),
+                    Style::default().fg(self.theme.text_secondary),
+                )));
+            }
+        }
+        if lines.is_empty() {
+            let empty = if browser.conversations.is_empty() {
+                "No past conversations yet."
+            } else {
+                "No conversations match."
+            };
+            lines.push(Line::from(Span::styled(
+                empty,
+                Style::default().fg(self.theme.text_secondary),
+            )));
+        }
+        // The last row is the hint (or the last export's outcome).
+        let hint = browser.notice.clone().unwrap_or_else(|| {
+            "Enter resume · Ctrl-e export · Ctrl-n new conversation · Esc back".to_string()
+        });
+        let view_h = (list_area.height as usize).saturating_sub(2).max(1);
+        // Keep the selection on screen.
+        let sel_end = (browser.selected + 1) * per_row;
+        let start = sel_end.saturating_sub(view_h);
+        let end = (start + view_h).min(lines.len());
+        let mut visible: Vec<Line> = lines[start..end].to_vec();
+        visible.push(Line::from(String::new()));
+        visible.push(Line::from(Span::styled(
+            super::settings::truncate_str(&hint, width),
+            Style::default().fg(self.theme.text_secondary),
+        )));
+        frame.render_widget(Paragraph::new(visible), list_area);
+
+        let input_block = Block::default()
+            .borders(Borders::TOP | Borders::BOTTOM)
+            .border_type(self.border_type())
+            .border_style(Style::default().fg(self.theme.conversation_accent));
+        let input_inner = input_block.inner(query_area);
+        frame.render_widget(input_block, query_area);
+
+        const PROMPT: &str = "search › ";
+        let prompt_w = PROMPT.chars().count() as u16;
+        frame.render_widget(
+            Paragraph::new(PROMPT).style(Style::default().fg(self.theme.text_secondary)),
+            Rect {
+                width: prompt_w.min(input_inner.width),
+                ..input_inner
+            },
+        );
+        let text_area = Rect {
+            x: input_inner.x + prompt_w,
+            width: input_inner.width.saturating_sub(prompt_w),
+            ..input_inner
+        };
+        let text_width = text_area.width.max(1);
+        let view_scroll = browser.query.visual_scroll(text_width as usize);
+        frame.render_widget(
+            Paragraph::new(query)
+                .scroll((0, view_scroll as u16))
+                .style(Style::default().fg(self.theme.text_primary)),
+            text_area,
+        );
+        let col = (browser.query.visual_cursor().saturating_sub(view_scroll)) as u16;
+        frame.set_cursor_position((
+            text_area.x + col.min(text_width.saturating_sub(1)),
+            text_area.y,
+        ));
+    }
+
+    /// The Ctrl-a agent picker: the default agent, then each profile, with
+    /// where it runs and how much it may do.
+    fn render_agent_picker(&self, frame: &mut Frame, area: Rect, selected: usize) {
+        let conv = &self.config.conversation;
+        let active = self.active_agent();
+        let width = area.width.max(1) as usize;
+        let rows = std::iter::once((None, conv.name.as_str())).chain(
+            conv.profiles
+                .iter()
+                .map(|p| (Some(&p.name), p.name.as_str())),
+        );
+        let mut lines: Vec<Line> = Vec::new();
+        for (row, (profile, name)) in rows.enumerate() {
+            let agent = conv.agent(profile.map(String::as_str));
+            let cwd = agent
+                .cwd
+                .as_ref()
+                .map_or_else(|| "scratch dir".to_string(), |p| p.display().to_string());
+            let marker = if profile == active.as_ref() {
+                "●"
+            } else {
+                " "
+            };
+            let text = format!("{marker} {name}  —  {cwd} · {}", agent.permission_mode);
+            let style = if row == selected {
+                Style::default()
+                    .fg(self.theme.conversation_accent)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default().fg(self.theme.text_primary)
+            };
+            lines.push(Line::from(Span::styled(
+                super::settings::truncate_str(&text, width),
+                style,
+            )));
+        }
+        lines.push(Line::from(String::new()));
+        if conv.profiles.is_empty() {
+            lines.push(Line::from(Span::styled(
+                super::settings::truncate_str(
+                    "Add agents as [[conversation.profiles]] in the config file.",
+                    width,
+                ),
+                Style::default().fg(self.theme.text_secondary),
+            )));
+        }
+        lines.push(Line::from(Span::styled(
+            super::settings::truncate_str("Enter switch · Esc back", width),
+            Style::default().fg(self.theme.text_secondary),
+        )));
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+
+    /// Append a message's wrapped lines (role header + body) to `lines`, the
+    /// agent's replies headed `assistant`.
     fn push_message_lines(
         &self,
         lines: &mut Vec<Line<'static>>,
         role: ConvRole,
         text: &str,
+        assistant: &str,
         width: usize,
     ) {
         let (label, color) = match role {
             ConvRole::User => ("You", self.theme.text_accent),
-            ConvRole::Assistant => (
-                self.config.conversation.name.as_str(),
-                self.theme.conversation_accent,
-            ),
+            ConvRole::Assistant => (assistant, self.theme.conversation_accent),
         };
         lines.push(Line::from(Span::styled(
             format!("{label}:"),
@@ -822,9 +1689,80 @@ impl App {
     ) -> bool {
         use crossterm::event::{KeyCode, KeyModifiers};
 
-        let Modal::Conversation { input, scroll } = &mut self.ui_state.modal else {
+        let Modal::Conversation {
+            input,
+            scroll,
+            history,
+            agents,
+        } = &mut self.ui_state.modal
+        else {
             return false;
         };
+        if let Some(selected) = agents {
+            let count = self.config.conversation.profiles.len() + 1;
+            match key.code {
+                KeyCode::Esc => *agents = None,
+                KeyCode::Up => *selected = selected.saturating_sub(1),
+                KeyCode::Down => *selected = (*selected + 1).min(count - 1),
+                KeyCode::Enter => {
+                    // Row 0 is the default agent, then the profiles in order.
+                    let profile = match *selected {
+                        0 => None,
+                        i => Some(self.config.conversation.profiles[i - 1].name.clone()),
+                    };
+                    *agents = None;
+                    *scroll = 0;
+                    self.select_conversation_agent(profile).await;
+                }
+                _ => {}
+            }
+            return true;
+        }
+        if let Some(browser) = history {
+            match key.code {
+                KeyCode::Esc => *history = None,
+                KeyCode::Up => browser.selected = browser.selected.saturating_sub(1),
+                KeyCode::Down => {
+                    if browser.selected + 1 < browser.matches.len() {
+                        browser.selected += 1;
+                    }
+                }
+                KeyCode::Enter => {
+                    if let Some(conv) = browser.selected_conversation().cloned() {
+                        *history = None;
+                        *scroll = 0;
+                        self.switch_conversation(conv).await;
+                    }
+                }
+                KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    if let Some(conv) = browser.selected_conversation().cloned() {
+                        let notice = self.export_conversation(&conv).await;
+                        if let Modal::Conversation {
+                            history: Some(browser),
+                            ..
+                        } = &mut self.ui_state.modal
+                        {
+                            browser.notice = Some(notice);
+                        }
+                    }
+                }
+                KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    *history = None;
+                    *scroll = 0;
+                    let fresh = StoredConversation {
+                        id: new_conversation_id(),
+                        ..StoredConversation::default()
+                    };
+                    self.switch_conversation(fresh).await;
+                }
+                _ => {
+                    if super::edit_text_input(&mut browser.query, key) {
+                        browser.refilter();
+                    }
+                }
+            }
+            return true;
+        }
         match key.code {
             // Esc, Alt-c again, or Ctrl-q close the overlay (session keeps running).
             KeyCode::Esc => {
@@ -836,6 +1774,27 @@ impl App {
             KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 self.ui_state.modal = Modal::None;
             }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_conversation_history().await;
+            }
+            // Ctrl-a picks the agent to talk to, starting on the active one.
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                let active = self.active_agent();
+                let row = self
+                    .config
+                    .conversation
+                    .profiles
+                    .iter()
+                    .position(|p| Some(&p.name) == active.as_ref())
+                    .map_or(0, |i| i + 1);
+                if let Modal::Conversation { agents, .. } = &mut self.ui_state.modal {
+                    *agents = Some(row);
+                }
+            }
+            // Ctrl-x cuts in on the reply without leaving the overlay.
+            KeyCode::Char('x') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.barge_in().await;
+            }
             KeyCode::Enter => {
                 let text = input.value().trim().to_string();
                 *input = Input::default();
@@ -912,16 +1871,16 @@ mod tests {
     async fn resume_id_round_trips() {
         let dir = tempfile::tempdir().unwrap();
         // No file yet → start fresh.
-        assert_eq!(read_resume_id(dir.path()).await, None);
+        assert_eq!(read_id(dir.path(), SESSION_ID_FILE).await, None);
         // After persisting, the same id comes back for the next launch.
-        write_resume_id(dir.path(), "abc-123").await;
+        write_id(dir.path(), SESSION_ID_FILE, "abc-123").await;
         assert_eq!(
-            read_resume_id(dir.path()).await,
+            read_id(dir.path(), SESSION_ID_FILE).await,
             Some("abc-123".to_string())
         );
         // A blank/whitespace file is treated as "no id" (start fresh).
-        write_resume_id(dir.path(), "  \n").await;
-        assert_eq!(read_resume_id(dir.path()).await, None);
+        write_id(dir.path(), SESSION_ID_FILE, "  \n").await;
+        assert_eq!(read_id(dir.path(), SESSION_ID_FILE).await, None);
     }
 
     #[test]
@@ -937,7 +1896,7 @@ mod tests {
     #[test]
     fn view_streams_and_finalizes_a_turn() {
         let mut v = ConversationView::default();
-        v.push_user("hi".into());
+        v.push_user("hi".into(), false);
         assert_eq!(v.status, ConvStatus::Thinking);
         assert_eq!(v.messages.len(), 1); // the user message
         v.apply(&ConversationEvent::Started {
@@ -960,7 +1919,7 @@ mod tests {
         assert!(v.turn_started_at.is_none());
 
         // Submitting a turn starts the stage-2 clock.
-        v.push_user("hi".into());
+        v.push_user("hi".into(), false);
         assert!(v.turn_started_at.is_some());
         assert!(!v.first_token_logged);
 
@@ -976,9 +1935,9 @@ mod tests {
         assert!(v.turn_started_at.is_none());
 
         // A queued message promoted at TurnComplete restarts the clock.
-        v.push_user("one".into());
+        v.push_user("one".into(), false);
         v.apply(&ConversationEvent::Delta("a".into()));
-        v.push_user("two".into()); // queued while Thinking
+        v.push_user("two".into(), false); // queued while Thinking
         v.apply(&ConversationEvent::TurnComplete);
         assert!(v.turn_started_at.is_some()); // re-armed for the queued turn
         assert!(!v.first_token_logged);
@@ -987,7 +1946,7 @@ mod tests {
     #[test]
     fn agent_turn_timer_cleared_on_error() {
         let mut v = ConversationView::default();
-        v.push_user("hi".into());
+        v.push_user("hi".into(), false);
         v.apply(&ConversationEvent::Error("boom".into()));
         assert!(v.turn_started_at.is_none());
     }
@@ -995,10 +1954,10 @@ mod tests {
     #[test]
     fn view_queues_a_message_sent_mid_reply() {
         let mut v = ConversationView::default();
-        v.push_user("first".into()); // Thinking
+        v.push_user("first".into(), false); // Thinking
         v.apply(&ConversationEvent::Delta("answering".into()));
         // A second message while Thinking is queued, not shown.
-        v.push_user("second".into());
+        v.push_user("second".into(), false);
         assert_eq!(v.pending_user.len(), 1);
         let user_msgs = v
             .messages
@@ -1013,6 +1972,115 @@ mod tests {
         assert_eq!(v.messages.last().unwrap().text, "second");
     }
 
+    #[test]
+    fn view_interrupted_turn_ends_without_an_error() {
+        let mut v = ConversationView::default();
+        // Nothing to interrupt while idle.
+        assert!(!v.begin_interrupt());
+        v.push_user("tell me everything".into(), false);
+        v.apply(&ConversationEvent::Delta("Well, it all began".into()));
+        assert!(v.begin_interrupt());
+        // The aborted turn's error result just ends it; the partial reply stays.
+        v.apply(&ConversationEvent::Error("turn ended with an error".into()));
+        assert_eq!(v.status, ConvStatus::Idle);
+        assert_eq!(v.messages.last().unwrap().text, "Well, it all began");
+        // Later errors are reported again.
+        v.push_user("again".into(), false);
+        v.apply(&ConversationEvent::Error("boom".into()));
+        assert_eq!(v.status, ConvStatus::Error("boom".into()));
+    }
+
+    fn stored(id: &str, texts: &[&str]) -> StoredConversation {
+        StoredConversation {
+            id: id.into(),
+            session_id: Some(format!("sess-{id}")),
+            turns: texts
+                .iter()
+                .enumerate()
+                .map(|(i, text)| HistoryTurn {
+                    role: if i % 2 == 0 {
+                        TurnRole::User
+                    } else {
+                        TurnRole::Assistant
+                    },
+                    text: (*text).into(),
+                    at: chrono::Utc::now(),
+                    spoken: false,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn history_browser_filters_on_any_turn() {
+        let mut b = HistoryBrowser::new(vec![
+            stored("a", &["What's failing in CI?", "The lint job."]),
+            stored("b", &["Merge the auth PR", "Done, it's merged."]),
+        ]);
+        // An empty query lists everything.
+        assert_eq!(b.matches, vec![(0, 0), (1, 0)]);
+        b.selected = 1;
+        // Matching is case-insensitive, over replies too, and the selection
+        // is pulled back into range as the list shrinks.
+        b.query = Input::from("LINT");
+        b.refilter();
+        assert_eq!(b.matches, vec![(0, 1)]);
+        assert_eq!(b.selected, 0);
+        assert_eq!(b.selected_conversation().unwrap().id, "a");
+        b.query = Input::from("nothing like this");
+        b.refilter();
+        assert!(b.selected_conversation().is_none());
+    }
+
+    #[test]
+    fn view_load_replaces_the_conversation() {
+        let mut v = ConversationView::default();
+        v.push_user("old question".into(), false);
+        v.apply(&ConversationEvent::Delta("half a repl".into()));
+        v.load(&stored("b", &["Merge the auth PR", "Done, it's merged."]));
+        assert_eq!(v.messages.len(), 2);
+        assert_eq!(v.messages[1].role, ConvRole::Assistant);
+        assert!(v.streaming.is_empty());
+        assert_eq!(v.session_id.as_deref(), Some("sess-b"));
+        // The old session's bridge sees the generation move and stands down.
+        assert_eq!(v.generation, 1);
+    }
+
+    #[test]
+    fn view_reset_forgets_the_conversation() {
+        let mut v = ConversationView::default();
+        v.load(&stored("a", &["Status?", "All green."]));
+        v.apply(&ConversationEvent::Delta("More to".into()));
+        v.reset();
+        assert!(v.messages.is_empty());
+        assert!(v.streaming.is_empty());
+        assert!(v.session_id.is_none());
+        assert_eq!(v.generation, 2);
+    }
+
+    #[test]
+    fn agent_slug_is_a_safe_dir_name() {
+        assert_eq!(agent_slug("Ops Desk"), "ops-desk");
+        assert_eq!(agent_slug("../planner"), "---planner");
+    }
+
+    #[test]
+    fn agent_named_matches_default_and_profiles() {
+        let conv = crate::config::ConversationConfig {
+            profiles: vec![crate::config::ConversationProfile {
+                name: "Planner".into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert_eq!(agent_named(&conv, &conv.name.to_uppercase()), Some(None));
+        assert_eq!(
+            agent_named(&conv, "planner"),
+            Some(Some("Planner".to_string()))
+        );
+        assert_eq!(agent_named(&conv, "ops"), None);
+    }
+
     #[test]
     fn view_break_inserts_separator() {
         let mut v = ConversationView::default();
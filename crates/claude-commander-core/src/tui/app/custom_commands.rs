@@ -0,0 +1,245 @@
+//! User-defined `[[custom_commands]]`: palette rows and the three run modes.
+//!
+//! A custom command is a shell template expanded against the selected session
+//! ([`CustomCommand::expand`]) and run on this machine. `detached` spawns it
+//! and forgets it, `modal` waits and shows its output, and `shell` types it
+//! into the session's shell pane — the one mode that needs the session to be
+//! local, since the pane lives on the session's host.
+
+use std::process::Stdio;
+
+use super::*;
+use crate::config::{CommandVars, CustomCommand, CustomCommandRun};
+
+/// How long a `modal` command may run before it is killed. The modal shows a
+/// spinner meanwhile, so this bounds how long the TUI is blocked on it.
+const MODAL_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Output kept for the modal: the tail, so a chatty command still shows its
+/// final lines (usually the verdict).
+const MODAL_OUTPUT_MAX_BYTES: usize = 64 * 1024;
+
+impl App {
+    /// Palette rows for the custom commands matching `filter_query`. Offered
+    /// only with a session selected on a live backend (every template is
+    /// about a session); `shell` entries also need the session's shell pane
+    /// to be reachable from here.
+    pub(super) fn gather_custom_command_items(&self, filter_query: &str) -> Vec<QuickSwitchItem> {
+        if self.ui_state.selected_session_id.is_none() || !self.ui_state.selected_backend_connected
+        {
+            return Vec::new();
+        }
+        let shell_ok = self.ui_state.selected_backend_capabilities.shell_toggle;
+        let mut scored: Vec<(i64, QuickSwitchItem)> = self
+            .config
+            .custom_commands
+            .iter()
+            .filter(|c| shell_ok || c.run != CustomCommandRun::Shell)
+            .filter_map(|c| {
+                let score = crate::fuzzy::fuzzy_score(&c.name, filter_query)?;
+                Some((
+                    score,
+                    QuickSwitchItem::CustomCommand {
+                        name: c.name.clone(),
+                        keys: self.config.keybindings.custom_keys_display(&c.name),
+                    },
+                ))
+            })
+            .collect();
+        if !filter_query.is_empty() {
+            scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        }
+        scored.into_iter().map(|(_, item)| item).collect()
+    }
+
+    /// Run the custom command `name` against the selected session.
+    pub(super) async fn handle_custom_command(&mut self, name: &str) {
+        let Some(sref) = self.ui_state.selected_session_id else {
+            return;
+        };
+        let Some(command) = self
+            .config
+            .custom_commands
+            .iter()
+            .find(|c| c.name == name)
+            .cloned()
+        else {
+            self.toast(format!("No custom command named \"{name}\""));
+            return;
+        };
+        let Some(vars) = self.command_vars(sref) else {
+            return;
+        };
+        let line = command.expand(&vars);
+        // Run in the worktree when it is on this machine; a remote session's
+        // path means nothing here, so fall back to the TUI's own directory.
+        let cwd = Some(std::path::PathBuf::from(&vars.worktree)).filter(|p| p.is_dir());
+
+        match command.run {
+            CustomCommandRun::Detached => match spawn_detached(&line, cwd.as_deref()) {
+                Ok(()) => self.toast(format!("Started {}", command.name)),
+                Err(e) => self.toast(format!("Failed to start {}: {e}", command.name)),
+            },
+            CustomCommandRun::Shell => self.run_in_shell_pane(sref, &command, &line).await,
+            CustomCommandRun::Modal => {
+                self.ui_state.modal = Modal::Loading {
+                    title: command.name.clone(),
+                    message: "Running…".to_string(),
+                    hint: None,
+                };
+                let tx = self.event_loop.sender();
+                tokio::spawn(async move {
+                    let result = run_captured(&line, cwd.as_deref()).await;
+                    let _ = tx
+                        .send(AppEvent::StateUpdate(StateUpdate::CustomCommandFinished {
+                            name: command.name,
+                            result,
+                        }))
+                        .await;
+                });
+            }
+        }
+    }
+
+    /// Show a finished `modal` command's output, unless the user has moved on
+    /// (the spinner is gone).
+    pub(super) fn show_custom_command_output(
+        &mut self,
+        name: String,
+        result: Result<String, String>,
+    ) {
+        if !matches!(self.ui_state.modal, Modal::Loading { .. }) {
+            debug!("Dropping output of custom command {name}: its modal was closed");
+            return;
+        }
+        self.ui_state.modal = match result {
+            Ok(output) => Modal::CommandOutput {
+                title: name,
+                output,
+                scroll: 0,
+            },
+            Err(message) => Modal::Error {
+                message: format!("{name}: {message}"),
+            },
+        };
+    }
+
+    async fn run_in_shell_pane(&mut self, sref: SessionRef, command: &CustomCommand, line: &str) {
+        let Some(local) = self
+            .local_backend()
+            .filter(|_| sref.backend == LOCAL_BACKEND_ID)
+        else {
+            self.toast(format!(
+                "{} runs in the shell pane, which is only reachable for local sessions",
+                command.name
+            ));
+            return;
+        };
+        let msg = match local.run_in_shell_pane(sref.id, line).await {
+            Ok(()) => format!("Sent {} to the shell pane", command.name),
+            Err(e) => format!("Failed to run {}: {e}", command.name),
+        };
+        self.toast(msg);
+    }
+
+    fn toast(&mut self, msg: String) {
+        self.ui_state.status_message = Some((msg, Instant::now() + Duration::from_secs(3)));
+    }
+
+    /// The template values for a session, from its backend's snapshot.
+    fn command_vars(&self, sref: SessionRef) -> Option<CommandVars> {
+        let session = self.session(sref)?;
+        Some(CommandVars {
+            worktree: session.worktree_path.clone(),
+            branch: session.branch.clone(),
+            title: session.title.clone(),
+            session_id: session.session_id.to_string(),
+            project: session.project_name.clone(),
+            pr_url: session.pr_url.clone().unwrap_or_default(),
+            pr_number: session.pr_number.map(|n| n.to_string()).unwrap_or_default(),
+        })
+    }
+}
+
+fn shell_command(line: &str, cwd: Option<&std::path::Path>) -> tokio::process::Command {
+    let mut cmd = tokio::process::Command::new("sh");
+    cmd.arg("-c").arg(line).stdin(Stdio::null());
+    if let Some(dir) = cwd {
+        cmd.current_dir(dir);
+    }
+    cmd
+}
+
+/// Start `line` with its output discarded. The child is awaited on a task of
+/// its own so it never lingers as a zombie.
+fn spawn_detached(line: &str, cwd: Option<&std::path::Path>) -> std::io::Result<()> {
+    let mut child = shell_command(line, cwd)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    tokio::spawn(async move {
+        let _ = child.wait().await;
+    });
+    Ok(())
+}
+
+/// Run `line` to completion (bounded by [`MODAL_TIMEOUT`]) and return its
+/// stdout followed by its stderr, plus a note when it exited non-zero.
+async fn run_captured(line: &str, cwd: Option<&std::path::Path>) -> Result<String, String> {
+    let child = shell_command(line, cwd)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("failed to start: {e}"))?;
+    let output = tokio::time::timeout(MODAL_TIMEOUT, child.wait_with_output())
+        .await
+        .map_err(|_| format!("timed out after {}s", MODAL_TIMEOUT.as_secs()))?
+        .map_err(|e| e.to_string())?;
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    if !output.status.success() {
+        let status = match output.status.code() {
+            Some(code) => format!("exit {code}"),
+            None => "killed by a signal".to_string(),
+        };
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text.push_str(&format!("[{status}]"));
+    }
+    Ok(tail(text, MODAL_OUTPUT_MAX_BYTES))
+}
+
+/// The last `max_bytes` of `text`, cut at a line start.
+fn tail(text: String, max_bytes: usize) -> String {
+    if text.len() <= max_bytes {
+        return text;
+    }
+    let mut start = text.len() - max_bytes;
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    let cut = text[start..].find('\n').map_or(start, |i| start + i + 1);
+    text[cut..].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tail_keeps_whole_trailing_lines() {
+        assert_eq!(tail("short".to_string(), 10), "short");
+        assert_eq!(tail("aaaa\nbbbb\ncccc\n".to_string(), 8), "cccc\n");
+    }
+
+    #[tokio::test]
+    async fn run_captured_reports_output_and_failure() {
+        let out = run_captured("echo out; echo err >&2; exit 3", None)
+            .await
+            .unwrap();
+        assert_eq!(out, "out\nerr\n[exit 3]");
+        assert_eq!(run_captured("printf ok", None).await.unwrap(), "ok");
+    }
+}
@@ -63,6 +63,9 @@ impl App {
                 // PR-status and project-pull polling live in
                 // CommanderService::spawn_background_tasks, not the UI tick.
                 self.spawn_preview_update();
+                // The watch grid's tiles; self-gates to the grid view and
+                // throttles itself to one round a second.
+                self.spawn_grid_captures();
             }
 
             if self.ui_state.should_quit {
@@ -132,6 +135,7 @@ impl App {
                 let old_servers = self.config.remote_servers.clone();
                 self.config = self.service.read_config();
                 self.reload_theme();
+                self.ensure_announcer_started();
 
                 // Reconcile the live backends against the new remote-server list
                 // (add/remove/rebuild handles) when it changed.
@@ -0,0 +1,244 @@
+//! The session filter: the query bar, saved filters, and applying the active
+//! query to the views.
+//!
+//! The query ([`SessionQuery`]) is applied to each backend's snapshot before
+//! the board, grid and list builders see it, so every view narrows the same
+//! way and the builders stay filter-unaware. The bar filters live as it's
+//! typed; a query that doesn't parse leaves the last one that did in place and
+//! shows the error under the input. Named filters live in `tui.json` and are
+//! offered as palette rows.
+
+use std::borrow::Cow;
+
+use super::*;
+use crate::api::WorkspaceSnapshot;
+
+impl App {
+    /// Handle "Filter sessions" — open the query bar on the active query.
+    pub(super) fn handle_filter_sessions(&mut self) {
+        let previous = self.ui_state.session_query.clone();
+        let value = previous
+            .as_ref()
+            .map(|q| q.source().to_string())
+            .unwrap_or_default();
+        self.ui_state.modal = filter_modal(value, previous);
+    }
+
+    /// Handle "Save the current filter as…" — ask for a name. With no filter
+    /// active the prompt deletes a saved filter instead.
+    pub(super) fn handle_save_filter(&mut self) {
+        let query = self
+            .ui_state
+            .session_query
+            .as_ref()
+            .map(|q| q.source().to_string());
+        let (title, prompt) = match &query {
+            Some(q) => (
+                "Save Filter".to_string(),
+                format!("Name for `{q}` (an existing name is replaced):"),
+            ),
+            None => (
+                "Delete Saved Filter".to_string(),
+                "No filter is active. Name of the saved filter to delete:".to_string(),
+            ),
+        };
+        self.ui_state.modal = Modal::Input {
+            title,
+            prompt,
+            value: super::Input::default(),
+            on_submit: InputAction::SaveFilter { query },
+            existing_branches: None,
+            project_picker: None,
+            program_picker: None,
+            server_picker: None,
+            section_picker: None,
+            prompt_editor: None,
+            focus: super::InputFocus::Name,
+            expanded: false,
+            mask: false,
+        };
+    }
+
+    /// Re-apply the query bar's text after an edit, when it parses. A query
+    /// that doesn't parse keeps the previous filter; the bar shows the error.
+    pub(super) async fn preview_filter_query(&mut self) {
+        let Modal::Input {
+            value,
+            on_submit: InputAction::FilterSessions { .. },
+            ..
+        } = &self.ui_state.modal
+        else {
+            return;
+        };
+        let Ok(query) = value.value().parse::<SessionQuery>() else {
+            return;
+        };
+        self.set_session_query(Some(query)).await;
+    }
+
+    /// Submit of the query bar: keep the filter, or reopen the bar on a query
+    /// that doesn't parse so it can be fixed.
+    pub(super) async fn submit_filter_query(
+        &mut self,
+        value: String,
+        previous: Option<SessionQuery>,
+    ) {
+        match value.parse::<SessionQuery>() {
+            Ok(query) => {
+                self.set_session_query(Some(query)).await;
+                if let Some(query) = &self.ui_state.session_query {
+                    self.ui_state.status_message = Some((
+                        format!("Filtering by `{query}` (Esc to clear)"),
+                        Instant::now() + Duration::from_secs(3),
+                    ));
+                }
+            }
+            Err(_) => self.ui_state.modal = filter_modal(value, previous),
+        }
+    }
+
+    /// Cancel of the query bar: put back the filter it was opened on.
+    pub(super) async fn cancel_filter_query(&mut self, previous: Option<SessionQuery>) {
+        self.set_session_query(previous).await;
+    }
+
+    /// Save (or, with no query, delete) the filter `name`.
+    pub(super) async fn submit_save_filter(&mut self, name: String, query: Option<String>) {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            self.ui_state.status_message = Some((
+                "Filter name cannot be empty".to_string(),
+                Instant::now() + Duration::from_secs(3),
+            ));
+            return;
+        }
+        let message = match query {
+            Some(query) => {
+                self.tui_prefs.save_filter(&name, &query).await;
+                format!("Saved filter \"{name}\"")
+            }
+            None if self.tui_prefs.delete_filter(&name).await => {
+                format!("Deleted saved filter \"{name}\"")
+            }
+            None => format!("No saved filter named \"{name}\""),
+        };
+        self.ui_state.status_message = Some((message, Instant::now() + Duration::from_secs(3)));
+    }
+
+    /// Apply a saved filter picked in the palette.
+    pub(super) async fn apply_saved_filter(&mut self, name: &str, query: &str) {
+        match query.parse::<SessionQuery>() {
+            Ok(parsed) => {
+                self.record_feature("filter.saved");
+                self.set_session_query(Some(parsed)).await;
+                self.ui_state.status_message = Some((
+                    format!("Filtering by \"{name}\" (Esc to clear)"),
+                    Instant::now() + Duration::from_secs(3),
+                ));
+            }
+            // Hand-edited tui.json, or a key a later version dropped.
+            Err(e) => {
+                self.ui_state.modal = Modal::Error {
+                    message: format!("Saved filter \"{name}\" is invalid: {e}"),
+                };
+            }
+        }
+    }
+
+    /// Palette rows for the saved filters matching `filter_query`.
+    pub(super) fn gather_saved_filter_items(&self, filter_query: &str) -> Vec<QuickSwitchItem> {
+        let mut scored: Vec<(i64, QuickSwitchItem)> = self
+            .tui_prefs
+            .prefs()
+            .saved_filters
+            .into_iter()
+            .filter_map(|f| {
+                let score = crate::fuzzy::fuzzy_score(&f.name, filter_query)?;
+                Some((
+                    score,
+                    QuickSwitchItem::SavedFilter {
+                        name: f.name,
+                        query: f.query,
+                    },
+                ))
+            })
+            .collect();
+        if !filter_query.is_empty() {
+            scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        }
+        scored.into_iter().map(|(_, item)| item).collect()
+    }
+
+    /// Install `query` (an empty one clears the filter) and rebuild the views.
+    pub(super) async fn set_session_query(&mut self, query: Option<SessionQuery>) {
+        let query = query.filter(|q| !q.is_empty());
+        if self.ui_state.session_query == query {
+            return;
+        }
+        self.ui_state.session_query = query;
+        self.refresh_list_items().await;
+    }
+
+    /// Each backend's snapshot as the views should see it: cut down to the
+    /// active query's matches, or borrowed as is when no query is active.
+    pub(super) fn filtered_snapshots(&self) -> Vec<Cow<'_, WorkspaceSnapshot>> {
+        let now = chrono::Utc::now();
+        self.backends
+            .iter()
+            .map(|h| match &self.ui_state.session_query {
+                Some(query) => Cow::Owned(query.filter_snapshot(
+                    &h.view.snapshot,
+                    &h.view.agent_states.states,
+                    now,
+                )),
+                None => Cow::Borrowed(&h.view.snapshot),
+            })
+            .collect()
+    }
+
+    /// Clear whichever filters hide `session_id` and rebuild, so a jump to it
+    /// from the palette always lands (the palette lists every session).
+    pub(super) async fn unhide_session(&mut self, session_id: SessionId) {
+        if self.ui_state.board_filter.is_none() && self.ui_state.session_query.is_none() {
+            return;
+        }
+        let shown = if self.ui_state.view_mode.is_full_screen() {
+            self.ui_state.board.position_of(session_id).is_some()
+        } else {
+            super::selection::worktree_list_index(&self.ui_state.list_items, session_id).is_some()
+        };
+        if !shown {
+            self.ui_state.board_filter = None;
+            self.ui_state.session_query = None;
+            self.refresh_list_items().await;
+        }
+    }
+}
+
+/// The query bar, opened on `value`.
+fn filter_modal(value: String, previous: Option<SessionQuery>) -> Modal {
+    Modal::Input {
+        title: "Filter Sessions".to_string(),
+        prompt: "Query, e.g. state:waiting pr:open label:needs-review age:>3d unread".to_string(),
+        value: value.into(),
+        on_submit: InputAction::FilterSessions { previous },
+        existing_branches: None,
+        project_picker: None,
+        program_picker: None,
+        server_picker: None,
+        section_picker: None,
+        prompt_editor: None,
+        focus: super::InputFocus::Name,
+        expanded: false,
+        mask: false,
+    }
+}
+
+/// The line under the query bar: why the query doesn't parse, or how to
+/// leave the bar.
+pub(super) fn filter_bar_hint(value: &str) -> String {
+    match value.parse::<SessionQuery>() {
+        Ok(_) => "↳ filtering as you type — Enter keeps it, Esc restores the previous".to_string(),
+        Err(e) => format!("⚠ {e}"),
+    }
+}
@@ -0,0 +1,190 @@
+//! Watch grid: tile rebuild, the capture round, filter/zoom and mouse picks.
+//!
+//! Tiles are derived from the board model (see `rebuild_board_view`), so the
+//! grid shares the board's order, numbering and project filter. Captures are
+//! taken in rounds: one round in flight at a time, at most once per
+//! [`GRID_CAPTURE_INTERVAL`], covering only the tiles drawn on the last frame.
+
+use futures::StreamExt;
+
+use super::*;
+use crate::tui::widgets::grid::grid_tiles;
+
+/// Minimum gap between capture rounds. The grid is a glanceable overview, not
+/// an attached terminal; a second of lag keeps tmux traffic proportionate.
+const GRID_CAPTURE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A round older than this is presumed lost and no longer blocks the next one.
+const GRID_CAPTURE_STALE: Duration = Duration::from_secs(5);
+
+impl App {
+    /// Rebuild the tiles from the freshly built board and re-anchor the cursor
+    /// on the tracked session so a reorder doesn't move the highlight.
+    pub(super) fn rebuild_grid_view(&mut self) {
+        let tiles = grid_tiles(&self.ui_state.board, self.ui_state.grid_state.filter);
+        self.ui_state
+            .grid_captures
+            .retain(|id, _| tiles.iter().any(|t| t.session_id == *id));
+        self.ui_state.grid_state.sync(tiles.len());
+        if let Some(sid) = self.ui_state.selected_session_id.map(|r| r.id)
+            && let Some(idx) = tiles.iter().position(|t| t.session_id == sid)
+        {
+            self.ui_state.grid_state.select(idx);
+        }
+        self.ui_state.grid_tiles = tiles;
+    }
+
+    /// Select the tile watching `session_id`. Returns `false` when it has none
+    /// (stopped, or hidden by the filter).
+    pub(super) fn select_grid_session(&mut self, session_id: SessionId) -> bool {
+        match self
+            .ui_state
+            .grid_tiles
+            .iter()
+            .position(|t| t.session_id == session_id)
+        {
+            Some(idx) => {
+                self.ui_state.grid_state.select(idx);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Spawn a capture round for the tiles on screen.
+    ///
+    /// Local captures run through the `TmuxExecutor`, whose semaphore is sized
+    /// by `max_concurrent_tmux`; the round itself keeps at most half that many
+    /// captures in flight, so the agent-state poll and the preview never queue
+    /// behind a full grid. Remote tiles are fetched from their server the same
+    /// way.
+    pub(super) fn spawn_grid_captures(&mut self) {
+        if !self.ui_state.view_mode.is_grid() || self.ui_state.grid_visible.is_empty() {
+            return;
+        }
+        if let Some(spawned_at) = self.ui_state.grid_capture_spawned_at {
+            if spawned_at.elapsed() < GRID_CAPTURE_STALE {
+                return;
+            }
+            debug!("Grid capture round stale (>5s), spawning a new one");
+        }
+        if self
+            .ui_state
+            .grid_captured_at
+            .is_some_and(|at| at.elapsed() < GRID_CAPTURE_INTERVAL)
+        {
+            return;
+        }
+
+        let lines = usize::from(self.ui_state.grid_capture_lines.max(1));
+        let targets: Vec<(Arc<dyn CommanderBackend>, SessionId)> = self
+            .ui_state
+            .grid_visible
+            .iter()
+            .map(|&id| (self.backend_arc(self.backend_of_session(id)), id))
+            .collect();
+        let in_flight = (self.config.max_concurrent_tmux / 2).max(1);
+        let tx = self.event_loop.sender();
+        let spawned_at = Instant::now();
+        self.ui_state.grid_capture_spawned_at = Some(spawned_at);
+
+        tokio::spawn(async move {
+            let captures: Vec<(SessionId, String)> = futures::stream::iter(targets)
+                .map(|(backend, id)| async move {
+                    match backend.pane_content(id, lines).await {
+                        Ok(content) => content.map(|c| (id, c)),
+                        Err(e) => {
+                            debug!("Grid capture of {id} failed: {e}");
+                            None
+                        }
+                    }
+                })
+                .buffer_unordered(in_flight)
+                .filter_map(std::future::ready)
+                .collect()
+                .await;
+            let _ = tx
+                .send(AppEvent::StateUpdate(StateUpdate::GridCapturesReady {
+                    spawned_at,
+                    captures,
+                }))
+                .await;
+        });
+    }
+
+    /// Fold a finished capture round into the tiles. A round that no longer
+    /// owns the guard (superseded after going stale) is still applied — its
+    /// captures are no older than what the tiles show.
+    pub(super) fn apply_grid_captures(
+        &mut self,
+        spawned_at: Instant,
+        captures: Vec<(SessionId, String)>,
+    ) {
+        if self.ui_state.grid_capture_spawned_at == Some(spawned_at) {
+            self.ui_state.grid_capture_spawned_at = None;
+            self.ui_state.grid_captured_at = Some(Instant::now());
+        }
+        for (id, content) in captures {
+            if self.ui_state.grid_tiles.iter().any(|t| t.session_id == id) {
+                self.ui_state.grid_captures.insert(id, content);
+            }
+        }
+    }
+
+    /// Cycle the grid filter (`f`): all → working → needs input.
+    pub(super) async fn handle_cycle_grid_filter(&mut self) {
+        if !self.ui_state.view_mode.is_grid() {
+            return;
+        }
+        let filter = self.ui_state.grid_state.filter.next();
+        self.ui_state.grid_state.filter = filter;
+        self.refresh_list_items().await;
+        // New tiles may be on screen; capture them now rather than next second.
+        self.ui_state.grid_captured_at = None;
+        self.ui_state.status_message = Some((
+            format!("Grid: showing {}", filter.label()),
+            Instant::now() + Duration::from_secs(3),
+        ));
+    }
+
+    /// Zoom the selected tile to the whole grid area, or back (`z`).
+    pub(super) fn handle_toggle_grid_zoom(&mut self) {
+        if !self.ui_state.view_mode.is_grid() || self.ui_state.grid_state.selected().is_none() {
+            return;
+        }
+        self.ui_state.grid_state.zoomed = !self.ui_state.grid_state.zoomed;
+        self.ui_state.grid_captured_at = None;
+    }
+
+    /// Left-click on the grid: select the tile; a double-click attaches, like
+    /// Enter.
+    pub(super) async fn handle_grid_left_click(&mut self, col: u16, row: u16) {
+        use crate::tui::list_nav::DOUBLE_CLICK_WINDOW;
+
+        let Some(idx) = self
+            .ui_state
+            .grid_hit_regions
+            .iter()
+            .find(|(rect, _)| rect.contains(ratatui::layout::Position { x: col, y: row }))
+            .map(|(_, idx)| *idx)
+        else {
+            self.ui_state.grid_last_click = None;
+            return;
+        };
+        let now = Instant::now();
+        let is_double_click = matches!(
+            self.ui_state.grid_last_click,
+            Some((prev, at)) if prev == idx && now.duration_since(at) <= DOUBLE_CLICK_WINDOW
+        );
+        if self.ui_state.grid_state.selected() != Some(idx) {
+            self.ui_state.grid_state.select(idx);
+            self.update_selection();
+        }
+        if is_double_click {
+            self.ui_state.grid_last_click = None;
+            self.handle_command(UserCommand::Select).await;
+        } else {
+            self.ui_state.grid_last_click = Some((idx, now));
+        }
+    }
+}
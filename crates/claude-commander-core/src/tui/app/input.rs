@@ -173,6 +173,15 @@ enum PasteRefilter {
 fn apply_paste_to_modal(modal: &mut Modal, text: &str) -> Option<PasteRefilter> {
     let clean = text.replace(['\n', '\r'], "");
     match modal {
+        // The prompt editor is multi-line, so it keeps the raw text's newlines.
+        Modal::Input {
+            focus: super::InputFocus::Prompt,
+            prompt_editor: Some(editor),
+            ..
+        } => {
+            editor.insert_str(text);
+            None
+        }
         Modal::Input { value, .. } => {
             super::insert_into_input(value, &clean);
             None
@@ -258,6 +267,30 @@ enum InputKeyOutcome {
     /// The server picker confirmed a *different* backend; the caller must rebuild
     /// the project/program/section pickers for it (an async, `App`-level step).
     ServerChanged,
+    /// Ctrl-o in the prompt field: hand the prompt to `$EDITOR` (the caller
+    /// suspends the TUI for it).
+    OpenPromptEditor,
+}
+
+/// Submit the New Session dialog, unless its project picker has no current
+/// match and so nothing to create under. Rather than silently ignore the
+/// submit then, reopen the Project dropdown so the `(no matching projects)` row
+/// is visible and the user can see why — and keep the gate here (pure +
+/// testable) rather than in the `App` caller.
+fn submit_input_modal(
+    project_picker: &Option<super::ProjectPicker>,
+    focus: &mut super::InputFocus,
+    expanded: &mut bool,
+) -> InputKeyOutcome {
+    if project_picker
+        .as_ref()
+        .is_some_and(|p| p.selected_id().is_none())
+    {
+        *focus = super::InputFocus::Project;
+        *expanded = true;
+        return InputKeyOutcome::Handled;
+    }
+    InputKeyOutcome::Submit
 }
 
 /// Pure key routing for the New Session dialog, mirroring `apply_paste_to_modal`
@@ -273,7 +306,7 @@ enum InputKeyOutcome {
 /// (Project only) characters filter.
 fn handle_input_modal_key(modal: &mut Modal, key: crossterm::event::KeyEvent) -> InputKeyOutcome {
     use super::InputFocus;
-    use crossterm::event::KeyCode;
+    use crossterm::event::{KeyCode, KeyModifiers};
     let Modal::Input {
         value,
         existing_branches,
@@ -281,6 +314,7 @@ fn handle_input_modal_key(modal: &mut Modal, key: crossterm::event::KeyEvent) ->
         program_picker,
         server_picker,
         section_picker,
+        prompt_editor,
         focus,
         expanded,
         ..
@@ -379,8 +413,8 @@ fn handle_input_modal_key(modal: &mut Modal, key: crossterm::event::KeyEvent) ->
                     _ => {}
                 }
             }
-            // The name field never expands; treat as a stray state and reset.
-            InputFocus::Name => *expanded = false,
+            // The text fields never expand; treat as a stray state and reset.
+            InputFocus::Name | InputFocus::Prompt => *expanded = false,
         }
         return InputKeyOutcome::Handled;
     }
@@ -396,24 +430,44 @@ fn handle_input_modal_key(modal: &mut Modal, key: crossterm::event::KeyEvent) ->
         project: has_project,
         program: has_program,
         section: has_section,
+        prompt: prompt_editor.is_some(),
     };
-    match key.code {
-        KeyCode::Enter => {
-            // A project picker with no current match has nothing to create
-            // under. Rather than silently ignore Enter, reopen the Project
-            // dropdown so the `(no matching projects)` row is visible and the
-            // user can see why — and keep the gate here (pure + testable)
-            // rather than in the `App` caller.
-            if project_picker
-                .as_ref()
-                .is_some_and(|p| p.selected_id().is_none())
-            {
-                *focus = InputFocus::Project;
-                *expanded = true;
-                return InputKeyOutcome::Handled;
+    // The prompt editor is multi-line, so Enter is a newline there and the
+    // dialog submits with Ctrl-s / Alt-Enter instead. ↑/↓ move between lines
+    // and only leave the field from its first/last line.
+    if *focus == InputFocus::Prompt
+        && let Some(editor) = prompt_editor.as_mut()
+    {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('s') if ctrl => {
+                return submit_input_modal(project_picker, focus, expanded);
+            }
+            KeyCode::Enter if key.modifiers.contains(KeyModifiers::ALT) => {
+                return submit_input_modal(project_picker, focus, expanded);
+            }
+            KeyCode::Esc => return InputKeyOutcome::Cancel,
+            KeyCode::Char('o') if ctrl => return InputKeyOutcome::OpenPromptEditor,
+            KeyCode::Char('p') if ctrl => {
+                editor.history_prev();
+            }
+            KeyCode::Char('n') if ctrl => {
+                editor.history_next();
+            }
+            KeyCode::Tab => *focus = focus.next(fields),
+            KeyCode::BackTab => *focus = focus.prev(fields),
+            KeyCode::Up if !editor.move_up() => *focus = focus.prev(fields),
+            KeyCode::Down if !editor.move_down() => *focus = focus.next(fields),
+            KeyCode::Up | KeyCode::Down => {}
+            _ => {
+                editor.handle_key(key);
             }
-            return InputKeyOutcome::Submit;
         }
+        return InputKeyOutcome::Handled;
+    }
+
+    match key.code {
+        KeyCode::Enter => return submit_input_modal(project_picker, focus, expanded),
         KeyCode::Esc => return InputKeyOutcome::Cancel,
         KeyCode::Tab | KeyCode::Down => *focus = focus.next(fields),
         KeyCode::BackTab | KeyCode::Up => *focus = focus.prev(fields),
@@ -451,6 +505,8 @@ fn handle_input_modal_key(modal: &mut Modal, key: crossterm::event::KeyEvent) ->
                     *expanded = true;
                 }
             }
+            // Handled above whenever the editor is present.
+            InputFocus::Prompt => {}
         },
     }
     InputKeyOutcome::Handled
@@ -491,6 +547,18 @@ impl App {
                     return;
                 }
 
+                // An open pane inspection takes every key until it's closed,
+                // so nothing typed while reading can act on the session. It
+                // only exists where the right pane does.
+                if self.ui_state.pane_inspect.is_some() {
+                    if self.ui_state.view_mode.is_full_screen() {
+                        self.ui_state.pane_inspect = None;
+                    } else {
+                        self.handle_inspect_key(key);
+                        return;
+                    }
+                }
+
                 // Check for configurable leader key (quick-switch).
                 // Shift+<leader> opens directly in command-only mode
                 // (VSCode-style command palette). We check the Shift-variant
@@ -519,12 +587,22 @@ impl App {
                     return;
                 }
 
+                // Esc leaves a zoomed grid tile first.
+                if key.code == crossterm::event::KeyCode::Esc
+                    && key.modifiers.is_empty()
+                    && self.ui_state.view_mode.is_grid()
+                    && self.ui_state.grid_state.zoomed
+                {
+                    self.handle_toggle_grid_zoom();
+                    return;
+                }
+
                 // Esc clears an active project filter (set by selecting a
-                // project in the board sidebar). Board-only — the filter has no
-                // effect or indicator in the list views.
+                // project in the board sidebar). Board and grid only — the
+                // filter has no effect or indicator in the list views.
                 if key.code == crossterm::event::KeyCode::Esc
                     && key.modifiers.is_empty()
-                    && self.ui_state.view_mode.is_board()
+                    && self.ui_state.view_mode.is_full_screen()
                     && self.ui_state.board_filter.is_some()
                 {
                     self.ui_state.board_filter = None;
@@ -532,6 +610,15 @@ impl App {
                     return;
                 }
 
+                // Esc then clears an active filter query, in every view.
+                if key.code == crossterm::event::KeyCode::Esc
+                    && key.modifiers.is_empty()
+                    && self.ui_state.session_query.is_some()
+                {
+                    self.set_session_query(None).await;
+                    return;
+                }
+
                 // Number-jump: intercept digit keys to select by session number.
                 if let crossterm::event::KeyCode::Char(c @ '0'..='9') = key.code
                     && key.modifiers.is_empty()
@@ -714,7 +801,7 @@ impl App {
                 match apply_paste_to_modal(&mut self.ui_state.modal, &text) {
                     Some(PasteRefilter::CheckoutBranch) => self.refilter_checkout_branches(),
                     Some(PasteRefilter::QuickSwitch) => self.refilter_quick_switch(),
-                    None => {}
+                    None => self.preview_filter_query().await,
                 }
             }
         }
@@ -742,11 +829,21 @@ impl App {
                 // editing) lives in the pure `handle_input_modal_key` helper so
                 // it is unit-testable without an `App`.
                 match handle_input_modal_key(&mut self.ui_state.modal, key) {
-                    InputKeyOutcome::Handled => {}
-                    InputKeyOutcome::Cancel => self.ui_state.modal = Modal::None,
+                    InputKeyOutcome::Handled => self.preview_filter_query().await,
+                    InputKeyOutcome::Cancel => {
+                        let modal = std::mem::replace(&mut self.ui_state.modal, Modal::None);
+                        if let Modal::Input {
+                            on_submit: InputAction::FilterSessions { previous },
+                            ..
+                        } = modal
+                        {
+                            self.cancel_filter_query(previous).await;
+                        }
+                    }
                     InputKeyOutcome::ServerChanged => {
                         self.on_new_session_server_changed().await;
                     }
+                    InputKeyOutcome::OpenPromptEditor => self.open_prompt_in_editor(),
                     InputKeyOutcome::Submit => {
                         // `handle_input_modal_key` only returns `Submit` once a
                         // project (if any) is selectable, so no re-gating here.
@@ -757,6 +854,7 @@ impl App {
                             program_picker,
                             server_picker,
                             section_picker,
+                            prompt_editor,
                             ..
                         } = &self.ui_state.modal
                         else {
@@ -786,8 +884,12 @@ impl App {
                         let backend = server_picker.as_ref().and_then(|p| p.selected_backend());
                         let value = value.value().to_string();
                         let program = program_picker.as_ref().and_then(|p| p.selected_command());
+                        let initial_prompt = prompt_editor
+                            .as_ref()
+                            .filter(|e| !e.is_blank())
+                            .map(|e| e.text());
                         self.ui_state.modal = Modal::None;
-                        self.handle_input_submit(action, value, program, backend)
+                        self.handle_input_submit(action, value, program, backend, initial_prompt)
                             .await;
                     }
                 }
@@ -886,6 +988,14 @@ impl App {
                 }
             }
 
+            Modal::CommandOutput { scroll, .. } => {
+                if let Some(action) = classify_help_key(&key, &self.config.keybindings)
+                    && apply_scroll_action(scroll, action)
+                {
+                    self.ui_state.modal = Modal::None;
+                }
+            }
+
             Modal::Info { scroll } => match classify_info_key(&key) {
                 InfoKey::Scroll(action) => {
                     if apply_scroll_action(scroll, action) {
@@ -1100,6 +1210,11 @@ impl App {
             return;
         }
 
+        if self.ui_state.view_mode.is_grid() {
+            self.handle_grid_left_click(col, row).await;
+            return;
+        }
+
         // List views have no board regions/buttons — route to the list handler.
         if !self.ui_state.view_mode.is_board() {
             self.handle_list_left_click(col, row).await;
@@ -1122,6 +1237,12 @@ impl App {
             self.open_settings_on_programs(backend);
             return;
         }
+        // A click on a swimlane heading folds or unfolds that lane.
+        if let Some(lane) = self.board_lane_at(col, row) {
+            self.ui_state.last_left_click = None;
+            self.toggle_lane(lane).await;
+            return;
+        }
         if let Some((pos, button)) = self.board_button_at(col, row) {
             use crate::tui::widgets::board::CardButton;
             self.ui_state.last_left_click = None;
@@ -1338,16 +1459,11 @@ impl App {
             Some(QuickSwitchItem::Session(m)) => {
                 let session_id = m.session_id;
                 self.ui_state.modal = Modal::None;
-                // The target may be hidden by an active project filter (the
-                // palette lists every session regardless of the filter). Clear
-                // it and rebuild so the jump always lands — quick-switch is the
-                // primary jump path and must never silently no-op.
-                if self.ui_state.board_filter.is_some()
-                    && self.ui_state.board.position_of(session_id).is_none()
-                {
-                    self.ui_state.board_filter = None;
-                    self.refresh_list_items().await;
-                }
+                // The target may be hidden by an active filter (the palette
+                // lists every session regardless of it). Clear it and rebuild
+                // so the jump always lands — quick-switch is the primary jump
+                // path and must never silently no-op.
+                self.unhide_session(session_id).await;
                 self.select_session_in_tree(session_id);
                 self.handle_select().await;
             }
@@ -1355,6 +1471,15 @@ impl App {
                 self.ui_state.modal = Modal::None;
                 self.handle_command(entry.action.into()).await;
             }
+            Some(QuickSwitchItem::SavedFilter { name, query }) => {
+                self.ui_state.modal = Modal::None;
+                self.apply_saved_filter(&name, &query).await;
+            }
+            Some(QuickSwitchItem::CustomCommand { name, .. }) => {
+                self.ui_state.modal = Modal::None;
+                self.handle_command(UserCommand::RunCustomCommand(name))
+                    .await;
+            }
             Some(QuickSwitchItem::SectionMove {
                 session_id, target, ..
             }) => {
@@ -1385,6 +1510,16 @@ impl App {
                     &dir_name,
                 );
             }
+            Some(QuickSwitchItem::ScrollbackHit { backend, hit, .. }) => {
+                self.open_scrollback_hit(backend, hit).await;
+            }
+            Some(QuickSwitchItem::ArchivedSession {
+                backend,
+                session_id,
+                ..
+            }) => {
+                self.restore_archived_session(backend, session_id);
+            }
             Some(QuickSwitchItem::ProgramChange {
                 session_id,
                 program,
@@ -1468,7 +1603,7 @@ impl App {
         self.ui_state.modal = Modal::None;
         // Path-input flows (AddProject…) never create a session, so no backend
         // override applies.
-        self.handle_input_submit(action, submit_value, None, None)
+        self.handle_input_submit(action, submit_value, None, None, None)
             .await;
     }
 
@@ -1519,7 +1654,9 @@ impl App {
                     *scroll = adjust_list_scroll(new_idx, *scroll, LIST_MAX_VISIBLE);
                 }
             }
-            Modal::Help { scroll } | Modal::Info { scroll } => {
+            Modal::Help { scroll }
+            | Modal::Info { scroll }
+            | Modal::CommandOutput { scroll, .. } => {
                 *scroll = scroll.saturating_add_signed(if down { 1 } else { -1 });
             }
             _ => {}
@@ -1618,6 +1755,21 @@ impl App {
             UserCommand::RenameSession => {
                 self.handle_rename_session().await;
             }
+            UserCommand::EditTags => {
+                self.handle_edit_tags().await;
+            }
+            UserCommand::EditNotes => {
+                self.handle_edit_notes().await;
+            }
+            UserCommand::ArchiveSession => {
+                self.handle_archive_session();
+            }
+            UserCommand::ShowArchived => {
+                self.handle_show_archived();
+            }
+            UserCommand::Undo => {
+                self.handle_undo();
+            }
             UserCommand::MoveToSection => {
                 self.handle_move_to_section().await;
             }
@@ -1627,6 +1779,18 @@ impl App {
             UserCommand::ToggleSection => {
                 self.handle_toggle_section().await;
             }
+            UserCommand::CycleSwimlanes => {
+                self.handle_cycle_swimlanes().await;
+            }
+            UserCommand::ToggleLane => {
+                self.handle_toggle_lane().await;
+            }
+            UserCommand::CycleGridFilter => {
+                self.handle_cycle_grid_filter().await;
+            }
+            UserCommand::ToggleGridZoom => {
+                self.handle_toggle_grid_zoom();
+            }
             UserCommand::RestartSession => {
                 self.handle_restart_session();
             }
@@ -1639,6 +1803,24 @@ impl App {
             UserCommand::ToggleKeepAlive => {
                 self.handle_toggle_keep_alive().await;
             }
+            UserCommand::RunTasks => {
+                self.handle_run_tasks().await;
+            }
+            UserCommand::SearchScrollback => {
+                self.handle_search_scrollback();
+            }
+            UserCommand::FilterSessions => {
+                self.handle_filter_sessions();
+            }
+            UserCommand::SaveFilter => {
+                self.handle_save_filter();
+            }
+            UserCommand::RunCustomCommand(name) => {
+                self.handle_custom_command(&name).await;
+            }
+            UserCommand::SendTaskFailure => {
+                self.handle_send_task_failure().await;
+            }
             UserCommand::RemoveProject => {
                 self.handle_remove_project();
             }
@@ -1731,10 +1913,10 @@ impl App {
                     self.spawn_ai_summary_if_needed(session_id);
                 }
             }
-            // Right-pane commands are list-view only — the board is full-screen
-            // and draws no right pane, so they would have nothing to act on.
+            // Right-pane commands are list-view only — the board and grid are
+            // full-screen and draw no right pane, so they have nothing to act on.
             UserCommand::TogglePane | UserCommand::TogglePaneReverse
-                if !self.ui_state.view_mode.is_board() =>
+                if !self.ui_state.view_mode.is_full_screen() =>
             {
                 let forward = matches!(cmd, UserCommand::TogglePane);
                 self.ui_state.right_pane_view = self
@@ -1749,6 +1931,9 @@ impl App {
                 self.spawn_info_fetch();
                 self.spawn_preview_update();
             }
+            UserCommand::InspectPane if !self.ui_state.view_mode.is_full_screen() => {
+                self.handle_inspect_pane();
+            }
             UserCommand::ShrinkLeftPane => self.resize_left_pane(-2).await,
             UserCommand::GrowLeftPane => self.resize_left_pane(2).await,
             _ => {}
@@ -2144,6 +2329,7 @@ mod tests {
             program_picker: None,
             server_picker: None,
             section_picker: None,
+            prompt_editor: None,
             focus: crate::tui::app::InputFocus::Name,
             expanded: false,
             mask: false,
@@ -2342,6 +2528,7 @@ diff --git a/a.rs b/a.rs
             program_picker: program,
             server_picker: None,
             section_picker: None,
+            prompt_editor: None,
             focus: InputFocus::Name,
             expanded: false,
             mask: false,
@@ -2374,6 +2561,7 @@ diff --git a/a.rs b/a.rs
                 vec!["Open PRs".to_string(), "Merged".to_string()],
                 None,
             )),
+            prompt_editor: None,
             focus: InputFocus::Name,
             expanded: false,
             mask: false,
@@ -2660,4 +2848,68 @@ diff --git a/a.rs b/a.rs
             _ => panic!("not an Input modal with a server picker"),
         }
     }
+
+    fn prompt_modal() -> Modal {
+        let mut m = session_modal(None, Some(program_fixture(&["claude"], 0)));
+        if let Modal::Input {
+            prompt_editor,
+            focus,
+            ..
+        } = &mut m
+        {
+            *prompt_editor = Some(crate::tui::prompt_editor::PromptEditor::new(vec![
+                "last time".to_string(),
+            ]));
+            *focus = InputFocus::Prompt;
+        }
+        m
+    }
+
+    fn prompt_text(m: &Modal) -> String {
+        match m {
+            Modal::Input {
+                prompt_editor: Some(e),
+                ..
+            } => e.text(),
+            _ => panic!("not an Input modal with a prompt editor"),
+        }
+    }
+
+    #[test]
+    fn prompt_field_enter_is_a_newline_and_ctrl_s_submits() {
+        let mut m = prompt_modal();
+        for c in "fix it".chars() {
+            handle_input_modal_key(&mut m, key(KeyCode::Char(c)));
+        }
+        assert_eq!(
+            handle_input_modal_key(&mut m, key(KeyCode::Enter)),
+            InputKeyOutcome::Handled
+        );
+        handle_input_modal_key(&mut m, key(KeyCode::Char('!')));
+        assert_eq!(prompt_text(&m), "fix it\n!");
+        // ↑ moves within the text until the first line, then leaves the field.
+        handle_input_modal_key(&mut m, key(KeyCode::Up));
+        assert_eq!(focus_of(&m), InputFocus::Prompt);
+        assert_eq!(
+            handle_input_modal_key(&mut m, ctrl(KeyCode::Char('s'))),
+            InputKeyOutcome::Submit
+        );
+        assert_eq!(
+            handle_input_modal_key(&mut m, ctrl(KeyCode::Char('o'))),
+            InputKeyOutcome::OpenPromptEditor
+        );
+        handle_input_modal_key(&mut m, key(KeyCode::Up));
+        assert_eq!(focus_of(&m), InputFocus::Program);
+    }
+
+    #[test]
+    fn prompt_field_recalls_history_and_keeps_pasted_newlines() {
+        let mut m = prompt_modal();
+        handle_input_modal_key(&mut m, ctrl(KeyCode::Char('p')));
+        assert_eq!(prompt_text(&m), "last time");
+        handle_input_modal_key(&mut m, ctrl(KeyCode::Char('n')));
+        assert_eq!(prompt_text(&m), "");
+        apply_paste_to_modal(&mut m, "step one\r\nstep two");
+        assert_eq!(prompt_text(&m), "step one\nstep two");
+    }
 }
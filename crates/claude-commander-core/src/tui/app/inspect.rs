@@ -0,0 +1,197 @@
+//! The right pane's read-only inspection mode: scroll, search, select and
+//! copy a session's captured output without attaching to it.
+//!
+//! Opening it freezes the tab's current capture into a [`PreviewInspector`].
+//! On the Preview tab a deeper capture of the agent pane's scrollback follows
+//! through `backend.preview` — a remote session's history arrives as
+//! `PreviewData` exactly like a local one's. Until it's closed every key
+//! drives the inspector, so nothing typed here can reach the agent.
+
+use super::*;
+use crate::api::{PreviewData, PreviewTarget};
+use crate::tui::osc52;
+
+/// Lines of agent-pane history fetched when inspection opens on the Preview
+/// tab.
+const INSPECT_HISTORY_LINES: usize = 5000;
+
+/// Lines a wheel notch moves the inspection cursor, matching the live pane.
+const INSPECT_WHEEL_LINES: isize = 3;
+
+impl App {
+    /// Handle "Inspect pane" — freeze the right pane's capture and open it for
+    /// reading, starting at the search prompt (the default key is `/`).
+    pub(super) fn handle_inspect_pane(&mut self) {
+        let view = self
+            .ui_state
+            .right_pane_view
+            .effective(self.is_project_selected());
+        let content = match view {
+            RightPaneView::Preview => &self.ui_state.preview_content,
+            RightPaneView::Shell => &self.ui_state.shell_content,
+            RightPaneView::Info => {
+                self.ui_state.status_message = Some((
+                    "Nothing to inspect on the Info tab — switch to Preview or Shell".to_string(),
+                    Instant::now() + Duration::from_secs(3),
+                ));
+                return;
+            }
+        };
+        let mut inspector = PreviewInspector::new(content);
+        inspector.begin_search();
+        let session = self.ui_state.selected_session_id;
+        self.ui_state.pane_inspect = Some(PaneInspect {
+            view,
+            session,
+            project: self.ui_state.selected_project_id,
+            inspector,
+        });
+
+        // The live capture is only the visible screen; fetch the history
+        // behind it. The shell has no history endpoint, so it stays as is.
+        if view == RightPaneView::Preview
+            && let Some(sref) = session
+        {
+            let backend = self.backend_for(sref);
+            let tx = self.event_loop.sender();
+            tokio::spawn(async move {
+                let target = PreviewTarget::Session {
+                    id: sref.id,
+                    lines: Some(INSPECT_HISTORY_LINES),
+                };
+                match backend.preview(target).await {
+                    Ok(PreviewData {
+                        pane: Some(content),
+                        ..
+                    }) => {
+                        let _ = tx
+                            .send(AppEvent::StateUpdate(StateUpdate::InspectHistoryLoaded {
+                                session_id: sref.id,
+                                content,
+                            }))
+                            .await;
+                    }
+                    Ok(_) => {}
+                    Err(e) => debug!("Inspect history for {} failed: {e}", sref.id),
+                }
+            });
+        }
+    }
+
+    /// Swap the deeper history into the open inspection, if it is still on
+    /// that session's Preview tab.
+    pub(super) fn apply_inspect_history(&mut self, session_id: SessionId, content: String) {
+        match &mut self.ui_state.pane_inspect {
+            Some(inspect)
+                if inspect.view == RightPaneView::Preview
+                    && inspect.session.map(|r| r.id) == Some(session_id) =>
+            {
+                inspect.inspector.replace_content(&content);
+            }
+            _ => debug!("Discarding inspect history for {session_id} (inspection moved on)"),
+        }
+    }
+
+    /// Close the inspection when the selection it was taken from has moved on.
+    pub(super) fn close_stale_inspect(&mut self) {
+        if self.ui_state.pane_inspect.as_ref().is_some_and(|i| {
+            i.session != self.ui_state.selected_session_id
+                || i.project != self.ui_state.selected_project_id
+        }) {
+            self.ui_state.pane_inspect = None;
+        }
+    }
+
+    /// A wheel notch over the right pane while inspecting moves the cursor.
+    pub(super) fn wheel_inspect(&mut self, down: bool) {
+        if let Some(inspect) = &mut self.ui_state.pane_inspect {
+            inspect.inspector.move_cursor(if down {
+                INSPECT_WHEEL_LINES
+            } else {
+                -INSPECT_WHEEL_LINES
+            });
+        }
+    }
+
+    /// Keys while inspecting. The search prompt takes text while it's open;
+    /// otherwise vi-style keys move, `v` selects, `y`/Enter copies, and Esc
+    /// backs out one step at a time — selection, then search, then inspection.
+    pub(super) fn handle_inspect_key(&mut self, key: crossterm::event::KeyEvent) {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let Some(inspect) = &mut self.ui_state.pane_inspect else {
+            return;
+        };
+        let ins = &mut inspect.inspector;
+        if ins.is_editing_search() {
+            match key.code {
+                KeyCode::Esc => ins.cancel_search(),
+                KeyCode::Enter => ins.commit_search(),
+                KeyCode::Backspace => ins.search_pop(),
+                KeyCode::Char(c)
+                    if !key
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+                {
+                    ins.search_push(c)
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('k') | KeyCode::Up => ins.move_cursor(-1),
+            KeyCode::Char('j') | KeyCode::Down => ins.move_cursor(1),
+            KeyCode::Char('u') if ctrl => ins.page(false),
+            KeyCode::Char('d') if ctrl => ins.page(true),
+            KeyCode::PageUp => ins.page(false),
+            KeyCode::PageDown => ins.page(true),
+            KeyCode::Char('g') | KeyCode::Home => ins.to_top(),
+            KeyCode::Char('G') | KeyCode::End => ins.to_bottom(),
+            KeyCode::Char('/') => ins.begin_search(),
+            KeyCode::Char('n') => {
+                ins.search_next(false);
+            }
+            KeyCode::Char('N') => {
+                ins.search_next(true);
+            }
+            KeyCode::Char('v' | 'V' | ' ') => ins.toggle_selection(),
+            KeyCode::Char('y') | KeyCode::Enter => self.copy_inspect_selection(),
+            KeyCode::Esc => {
+                if !ins.clear_selection() {
+                    if ins.search().is_some() {
+                        ins.cancel_search();
+                    } else {
+                        self.ui_state.pane_inspect = None;
+                    }
+                }
+            }
+            KeyCode::Char('q') => self.ui_state.pane_inspect = None,
+            _ => {}
+        }
+    }
+
+    /// Copy the selection (or the cursor line) to the system clipboard over
+    /// OSC 52, which reaches the terminal the TUI runs in — local or over ssh.
+    fn copy_inspect_selection(&mut self) {
+        let Some(inspect) = &mut self.ui_state.pane_inspect else {
+            return;
+        };
+        let text = inspect.inspector.copy_text();
+        let lines = inspect.inspector.copy_line_count();
+        inspect.inspector.clear_selection();
+        let message = match osc52::copy(&text) {
+            Ok(()) => {
+                self.record_feature("inspect.copy");
+                format!(
+                    "Copied {lines} line{} to the clipboard",
+                    if lines == 1 { "" } else { "s" }
+                )
+            }
+            Err(e) => format!("Copy failed: {e}"),
+        };
+        self.ui_state.status_message = Some((message, Instant::now() + Duration::from_secs(3)));
+    }
+}
@@ -38,15 +38,17 @@ use tui_input::Input;
 
 use super::event::{AppEvent, EventLoop, InputEvent, RestartKind, StateUpdate, UserCommand};
 use super::path_completer::PathCompleter;
+use super::prompt_editor::PromptEditor;
 use super::theme::Theme;
 use super::widgets::board::{
     BoardButtonRegion, BoardHitRegion, BoardRects, BoardState, BoardWidget,
 };
+use super::widgets::grid::{GridFilter, GridState, GridTile, GridWidget};
 use super::widgets::{
-    InfoContent, InfoProjectData, InfoSessionData, InfoView, Preview, PreviewState, TreeList,
-    TreeListState, status_glyph,
+    InfoContent, InfoProjectData, InfoSessionData, InfoView, InspectView, Preview,
+    PreviewInspector, PreviewState, TreeList, TreeListState, status_glyph,
 };
-use crate::api::{CommanderService, DiffSide};
+use crate::api::{CommanderService, DiffSide, ResourceUsage};
 use crate::backend::{
     AttachConnection, AttachKind, BResult, BackendCapabilities, BackendError, BackendHandle,
     BackendId, BackendView, CommanderBackend, ConnectionState, LOCAL_BACKEND_ID, LocalBackend,
@@ -59,18 +61,25 @@ use crate::git::{
     AiSummary, BlockReason, DiffInfo, EnrichedPrInfo, diff_hash, fetch_branch_summary,
     fetch_enriched_pr, is_gh_available,
 };
+use crate::scrollback::{ScrollbackHit, ScrollbackReveal};
 use crate::session::{
-    AgentState, Board, BoardPos, ProjectId, SessionId, SessionListItem, SessionStatus,
+    AgentState, Board, BoardPos, ProjectId, SessionId, SessionListItem, SessionQuery,
+    SessionStatus, Swimlanes,
 };
 
 mod actions;
 mod background;
 mod conversation;
+mod custom_commands;
 mod event_loop;
+mod filter;
+mod grid;
 mod input;
+mod inspect;
 mod modals;
 mod render;
 mod review;
+mod scrollback;
 mod selection;
 mod settings;
 mod state;
@@ -128,12 +137,15 @@ fn should_auto_restart_ended(session_name: &str, consecutive_ends: u8) -> bool {
 /// ([`App::spawn_backend_view_refresh`]) so both deliver identical updates off
 /// the event loop. A failed snapshot fetch is skipped (the cached view keeps its
 /// last-good contents) without killing a long-lived feed loop; a failed
-/// agent-states fetch falls back to empty. Returns `false` only when the event
-/// channel has closed, so a feed loop knows to stop.
+/// agent-states fetch falls back to empty. Each fetch is also shown to the
+/// `announcer` here, off the loop, so announcements keep up while the loop is
+/// parked in a tmux attach. Returns `false` only when the event channel has
+/// closed, so a feed loop knows to stop.
 async fn fetch_and_send_backend_change(
     backend_id: usize,
     backend: Arc<dyn CommanderBackend>,
     tx: tokio::sync::mpsc::Sender<AppEvent>,
+    announcer: crate::conversation::AnnouncerHandle,
 ) -> bool {
     let snapshot = match backend.workspace_snapshot().await {
         Ok(s) => s,
@@ -149,7 +161,9 @@ async fn fetch_and_send_backend_change(
             .unwrap_or_else(|_| crate::api::AgentStatesSnapshot {
                 states: Default::default(),
                 commander_running: false,
+                viewers: Default::default(),
             });
+    announcer.observe(backend_id, &snapshot.sessions, &states.states);
     tx.send(AppEvent::StateUpdate(StateUpdate::BackendChanged {
         backend_id,
         snapshot: Box::new(snapshot),
@@ -251,8 +265,8 @@ pub(crate) const DEFAULT_LEFT_PANE_PCT: u16 = 30;
 
 /// Which view the right-hand pane shows in the *list* view modes.
 ///
-/// The board is a full-screen takeover with no right pane, so this is only
-/// consulted when [`ViewMode::is_board`] is false. `Info` renders the same
+/// The board and the grid are full-screen takeovers with no right pane, so
+/// this is only consulted when [`ViewMode::is_full_screen`] is false. `Info` renders the same
 /// content as the `i` Info modal — which remains the only way to reach it from
 /// the board.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -411,6 +425,11 @@ pub enum Modal {
         /// renders a section picker; the highlighted row becomes the new
         /// session's section (catch-all row = no override / auto-assign).
         section_picker: Option<SectionPicker>,
+        /// When `Some`, the dialog renders a multi-line initial-prompt editor
+        /// below the pickers; non-blank text is sent to the agent on launch.
+        /// Only the session-creating flows (New Session, New Stacked Session)
+        /// populate it.
+        prompt_editor: Option<PromptEditor>,
         /// Which field currently has focus. Tab cycles through the fields that
         /// are present (see `InputFocus::next`).
         focus: InputFocus,
@@ -498,8 +517,22 @@ pub enum Modal {
     ReviewDiff(Box<DiffReviewState>),
     /// Full-screen conversation overlay (view onto the headless `claude`
     /// session). View-only state; the session itself lives on `App`, so closing
-    /// this leaves the conversation running.
-    Conversation { input: Input, scroll: u16 },
+    /// this leaves the conversation running. `history` is set while browsing
+    /// past conversations (Ctrl-r); `agents` is the highlighted row while
+    /// picking an agent (Ctrl-a).
+    Conversation {
+        input: Input,
+        scroll: u16,
+        history: Option<Box<conversation::HistoryBrowser>>,
+        agents: Option<usize>,
+    },
+    /// Output of a `modal`-mode custom command. `scroll` is the first visible
+    /// line, clamped against the output height each frame (like `Help`).
+    CommandOutput {
+        title: String,
+        output: String,
+        scroll: u16,
+    },
     /// Session Info overlay (metadata, diffstat, PR details, AI summary) for the
     /// selected session. `scroll` is the first visible line of the composed
     /// `InfoView` content, clamped against its height each frame (like `Help`).
@@ -557,6 +590,14 @@ pub enum PaletteMode {
     ProgramPicker {
         session_id: SessionId,
     },
+    /// Full-text search over every backend's scrollback index. Rows are the
+    /// hits in [`AppUiState::scrollback_search`], fetched (debounced) as the
+    /// query changes rather than filtered locally; selecting one attaches to
+    /// the session with its pane scrolled to the line.
+    ScrollbackSearch,
+    /// Archived-session picker: one row per archived session across every
+    /// backend (they are hidden from the tree); selecting one restores it.
+    ArchivedPicker,
 }
 
 /// A row in the quick-switch palette — either an open session, a
@@ -565,6 +606,18 @@ pub enum PaletteMode {
 pub enum QuickSwitchItem {
     Session(QuickSwitchMatch),
     Command(CommandEntry),
+    /// A filter saved from the query bar; selecting it applies `query`.
+    SavedFilter {
+        name: String,
+        query: String,
+    },
+    /// A `[[custom_commands]]` entry, run against the selected session.
+    CustomCommand {
+        /// The entry's `name` — its label, and how it is looked up on run.
+        name: String,
+        /// Pre-formatted `[keybindings.custom]` binding; empty when unbound.
+        keys: String,
+    },
     /// Selecting this row pins `session_id` to `target` (Some = section name,
     /// None = "Auto" / clear override).
     SectionMove {
@@ -600,6 +653,22 @@ pub enum QuickSwitchItem {
         /// Pre-formatted display label.
         label: String,
     },
+    /// Selecting this row attaches to the hit's session, scrolled to the hit's
+    /// line in copy-mode (scrollback-search palette mode).
+    ScrollbackHit {
+        backend: BackendId,
+        hit: ScrollbackHit,
+        /// The session's title, looked up when the row was built.
+        title: String,
+    },
+    /// Selecting this row restores the archived session (archived-picker
+    /// palette mode).
+    ArchivedSession {
+        backend: BackendId,
+        session_id: SessionId,
+        /// Pre-formatted display label (title, branch, project, archive date).
+        label: String,
+    },
 }
 
 /// A command row in the quick-switch palette.
@@ -666,6 +735,37 @@ impl Default for RepoPicker {
     }
 }
 
+/// Debounce between the last keystroke and the scrollback search it triggers,
+/// so typing a word issues one search per backend rather than one per letter.
+pub(crate) const SCROLLBACK_SEARCH_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// State behind [`PaletteMode::ScrollbackSearch`].
+///
+/// On `AppUiState` for the same reason as [`RepoPicker`]: hits arrive
+/// asynchronously and the palette rows are rebuilt from them.
+#[derive(Debug, Clone, Default)]
+pub struct ScrollbackSearchState {
+    /// The query the current `hits` (or the in-flight search) answer.
+    pub query: String,
+    /// Hits from every backend, each tagged with the backend that owns it.
+    pub hits: Vec<(BackendId, ScrollbackHit)>,
+    /// A search is scheduled or in flight.
+    pub searching: bool,
+    /// Bumped on every query change; the pending search checks it after the
+    /// debounce and its results are dropped on arrival if it moved on.
+    pub generation: Arc<std::sync::atomic::AtomicU64>,
+}
+
+/// An open inspection of the right pane: the frozen capture being read, and
+/// the tab and selection it was taken from — it closes when either moves on.
+#[derive(Debug, Clone)]
+pub struct PaneInspect {
+    pub view: RightPaneView,
+    pub session: Option<SessionRef>,
+    pub project: Option<(BackendId, ProjectId)>,
+    pub inspector: PreviewInspector,
+}
+
 /// A single branch entry in the checkout modal list
 #[derive(Debug, Clone)]
 pub struct BranchEntry {
@@ -1020,6 +1120,8 @@ pub enum InputFocus {
     Program,
     /// The section picker (only present when the backend has configured sections).
     Section,
+    /// The multi-line initial-prompt editor (session-creating flows only).
+    Prompt,
 }
 
 /// Which of the New Session dialog's picker fields are present, so the focus
@@ -1030,12 +1132,13 @@ pub struct FieldsPresent {
     pub project: bool,
     pub program: bool,
     pub section: bool,
+    pub prompt: bool,
 }
 
 impl InputFocus {
     /// Ordered ring of the fields that exist; Name is always present. The order
     /// mirrors the dialog's top-to-bottom layout: Name → Server → Project →
-    /// Program → Section.
+    /// Program → Section → Prompt.
     fn ring(fields: FieldsPresent) -> Vec<InputFocus> {
         let mut ring = vec![InputFocus::Name];
         if fields.server {
@@ -1050,6 +1153,9 @@ impl InputFocus {
         if fields.section {
             ring.push(InputFocus::Section);
         }
+        if fields.prompt {
+            ring.push(InputFocus::Prompt);
+        }
         ring
     }
 
@@ -1340,6 +1446,14 @@ pub enum InputAction {
     RenameSession {
         session_id: SessionId,
     },
+    /// Replace the session's tags with the comma-separated input.
+    EditTags {
+        session_id: SessionId,
+    },
+    /// Replace the session's notes with the input (empty clears them).
+    EditNotes {
+        session_id: SessionId,
+    },
     /// Step 1 of the add-remote-server flow: the server's display name.
     AddRemoteServerName,
     /// Step 2: the server's base URL.
@@ -1362,6 +1476,16 @@ pub enum InputAction {
         backend: BackendId,
         source: claude_commander_protocol::github::CloneSource,
     },
+    /// The query bar. Applied live as it's edited; submitting keeps the
+    /// filter, cancelling restores `previous`.
+    FilterSessions {
+        previous: Option<SessionQuery>,
+    },
+    /// Name for the active filter query, saved to `tui.json`. Submitting a
+    /// name with no filter active deletes the saved filter of that name.
+    SaveFilter {
+        query: Option<String>,
+    },
 }
 
 /// Action to perform when confirm modal is confirmed
@@ -1381,6 +1505,10 @@ pub enum ConfirmAction {
     ResetSession {
         session_id: SessionId,
     },
+    /// Archive a session: stop it and remove its worktree, keeping the branch.
+    ArchiveSession {
+        session_id: SessionId,
+    },
     /// Change a session's program (agent) to `program` and relaunch it fresh.
     ChangeProgram {
         session_id: SessionId,
@@ -1410,8 +1538,8 @@ pub enum ConfirmAction {
 /// Application UI state
 pub struct AppUiState {
     /// Active view. List modes (`ProjectGrouped`/`SectionGrouped`/
-    /// `SectionStacks`) render the tree-list; `Board` renders the kanban board.
-    /// Cycled with `v` (`ToggleViewMode`).
+    /// `SectionStacks`) render the tree-list; `Board` renders the kanban board
+    /// and `Grid` the watch grid. Cycled with `v` (`ToggleViewMode`).
     pub view_mode: ViewMode,
     /// Flat tree-list rows for the active list view. Rebuilt only in a list
     /// view; retains the last list-view rows while the board is shown (consumers
@@ -1426,6 +1554,11 @@ pub struct AppUiState {
     pub board: Board,
     /// Board navigation state (cursor position + per-column scroll).
     pub board_state: BoardState,
+    /// Board swimlane grouping (`L` cycles it); `None` shows plain columns.
+    pub board_lanes: Option<Swimlanes>,
+    /// Keys of the folded swimlanes. Kept across groupings, so switching away
+    /// and back restores the same folds.
+    pub collapsed_lanes: std::collections::BTreeSet<String>,
     /// Clickable board regions from the last render frame, mapping screen rects
     /// to board positions for mouse clicks. Rebuilt every frame.
     pub board_hit_regions: Vec<BoardHitRegion>,
@@ -1437,6 +1570,9 @@ pub struct AppUiState {
     /// Sidebar server-heading hit regions from the last render frame; a click
     /// opens that server's Settings → Programs tab.
     pub board_heading_regions: Vec<(Rect, crate::backend::BackendId)>,
+    /// Swimlane heading hit regions from the last render frame, with the
+    /// lane's index in `board.lanes`; a click folds or unfolds that lane.
+    pub board_lane_regions: Vec<(Rect, usize)>,
     /// Column/sidebar rectangles from the last render frame, for wheel-scroll
     /// targeting. `None` before the first board render.
     pub board_column_rects: Option<BoardRects>,
@@ -1445,6 +1581,33 @@ pub struct AppUiState {
     /// sidebar cursor (see `refresh_list_items`), cleared by Esc in the main
     /// view. `ProjectId`s are globally unique, so this needs no backend qualifier.
     pub board_filter: Option<ProjectId>,
+    /// Active session filter query (the query bar, `F` by default): when
+    /// `Some`, the board, grid and list views show only the sessions it
+    /// matches. Applied in `refresh_list_items`, cleared by Esc.
+    pub session_query: Option<SessionQuery>,
+    /// Watch-grid tiles, derived from `board` under the grid's filter. Rebuilt
+    /// in `refresh_list_items` while the grid is showing.
+    pub grid_tiles: Vec<GridTile>,
+    /// Watch-grid cursor, zoom and filter.
+    pub grid_state: GridState,
+    /// Latest pane capture per tiled session. Entries for sessions that left
+    /// the grid are dropped on rebuild.
+    pub grid_captures: HashMap<SessionId, String>,
+    /// Tile rects → tile index from the last grid frame, for mouse clicks.
+    pub grid_hit_regions: Vec<(Rect, usize)>,
+    /// Sessions whose tiles were drawn on the last grid frame; only these are
+    /// captured.
+    pub grid_visible: Vec<SessionId>,
+    /// Lines each capture needs to fill the tallest drawn tile.
+    pub grid_capture_lines: u16,
+    /// When the in-flight capture round was spawned (`None` = idle). Doubles
+    /// as the round's generation token in `GridCapturesReady`.
+    pub grid_capture_spawned_at: Option<Instant>,
+    /// When the last capture round finished, for the re-capture interval.
+    pub grid_captured_at: Option<Instant>,
+    /// Last left-mouse click on a grid tile: (tile index, timestamp), for
+    /// double-click-to-attach.
+    pub grid_last_click: Option<(usize, Instant)>,
     /// Enriched PR info for the currently selected session
     pub enriched_pr: Option<(SessionId, EnrichedPrInfo)>,
     /// Session whose enriched-PR fetch came back empty (no PR data, or `gh`
@@ -1452,6 +1615,9 @@ pub struct AppUiState {
     /// surface left open would respawn `gh` every few seconds forever. Cleared
     /// by an explicit PR-status refresh, which is the retry path.
     pub enriched_pr_unavailable: Option<SessionId>,
+    /// Latest resource-usage sample for the selected session, shown as the
+    /// Info surface's Usage line.
+    pub resource_usage: Option<(SessionId, ResourceUsage)>,
     /// Cached AI summaries keyed by session ID
     pub ai_summaries: std::collections::HashMap<SessionId, AiSummary>,
     /// Current modal
@@ -1546,6 +1712,9 @@ pub struct AppUiState {
     /// Drives the `*` marker in the session list; refreshed on startup and
     /// whenever the review view closes.
     pub sessions_with_comments: HashSet<SessionId>,
+    /// How many clients are attached to each session's agent pane, unioned
+    /// over every backend's agent-state snapshot. Drives the `◉N` marker.
+    pub session_viewers: HashMap<SessionId, u32>,
     /// Currently selected session (for preview/diff), qualified by the backend
     /// that owns it so actions route to the right machine.
     pub selected_session_id: Option<SessionRef>,
@@ -1576,6 +1745,10 @@ pub struct AppUiState {
     pub pending_switcher_target: Option<AttachTarget>,
     /// Editor command + path to open after exiting TUI
     pub editor_command: Option<(String, PathBuf)>,
+    /// Set alongside `editor_command` when the file being edited is the New
+    /// Session dialog's prompt: once the editor exits, its contents replace the
+    /// prompt editor's text and the file is removed.
+    pub prompt_edit_file: Option<PathBuf>,
     /// When attached via shell toggle (Ctrl+\), stores the session name to switch back to.
     /// Contains (current_session_name, paired_session_name) so we can toggle between them.
     pub shell_toggle_pair: Option<(String, String)>,
@@ -1594,6 +1767,11 @@ pub struct AppUiState {
     /// Info modal would re-spawn a duplicate `gh` fetch each tick until the
     /// first resolves. Mirrors `preview_update_spawned_at`'s 5s safety window.
     pub enriched_pr_fetch_spawned_at: Option<Instant>,
+    /// When the last resource-usage sample was spawned. Doubles as the
+    /// re-sample throttle: a new sample is taken once this is older than
+    /// `RESOURCE_SAMPLE_INTERVAL`, and it is the generation token for
+    /// `ResourceUsageReady`.
+    pub resource_fetch_spawned_at: Option<Instant>,
     /// Whether a review-diff refresh re-compose is currently in flight, so the
     /// idle trigger and a manual refresh don't double-spawn.
     pub review_refresh_in_flight: bool,
@@ -1608,6 +1786,10 @@ pub struct AppUiState {
     /// palette entries without an async read on every keystroke. Refreshed in
     /// `refresh_list_items`.
     pub cascade_paused: bool,
+    /// Backend of the most recent undoable action taken from this TUI; the
+    /// Undo command goes there. `None` (nothing undoable yet this run) means
+    /// the local backend, whose journal also covers CLI deletes.
+    pub undo_backend: Option<BackendId>,
     /// Last left-mouse click on a board row: (board position, timestamp).
     /// Used to detect double-click on the same row within `DOUBLE_CLICK_WINDOW`.
     pub last_left_click: Option<(BoardPos, Instant)>,
@@ -1658,6 +1840,15 @@ pub struct AppUiState {
     /// ([`PaletteMode::GithubRepoPicker`]). Reset each time the picker opens;
     /// only read while it is open, so a stale value between opens is inert.
     pub repo_picker: RepoPicker,
+    /// Query + hits behind the scrollback-search palette
+    /// ([`PaletteMode::ScrollbackSearch`]). Reset each time it opens.
+    pub scrollback_search: ScrollbackSearchState,
+    /// A scrollback-search hit to scroll to once the attach it started is
+    /// connected — consumed by the attach loop for the matching session.
+    pub pending_scrollback_reveal: Option<(SessionRef, ScrollbackReveal)>,
+    /// The right pane's read-only inspection, while open. Keys drive it
+    /// instead of the session list until it is closed.
+    pub pane_inspect: Option<PaneInspect>,
 }
 
 impl Default for AppUiState {
@@ -1672,10 +1863,24 @@ impl Default for AppUiState {
             board_hit_regions: Vec::new(),
             board_button_regions: Vec::new(),
             board_heading_regions: Vec::new(),
+            board_lane_regions: Vec::new(),
+            board_lanes: None,
+            collapsed_lanes: std::collections::BTreeSet::new(),
             board_column_rects: None,
             board_filter: None,
+            session_query: None,
+            grid_tiles: Vec::new(),
+            grid_state: GridState::default(),
+            grid_captures: HashMap::new(),
+            grid_hit_regions: Vec::new(),
+            grid_visible: Vec::new(),
+            grid_capture_lines: 0,
+            grid_capture_spawned_at: None,
+            grid_captured_at: None,
+            grid_last_click: None,
             enriched_pr: None,
             enriched_pr_unavailable: None,
+            resource_usage: None,
             ai_summaries: std::collections::HashMap::new(),
             modal: Modal::None,
             session_numbers: HashMap::new(),
@@ -1703,6 +1908,7 @@ impl Default for AppUiState {
             action_buttons: Vec::new(),
             review_buttons: Vec::new(),
             sessions_with_comments: HashSet::new(),
+            session_viewers: HashMap::new(),
 
             should_quit: false,
             selected_session_id: None,
@@ -1714,17 +1920,20 @@ impl Default for AppUiState {
             pending_open_review: None,
             pending_switcher_target: None,
             editor_command: None,
+            prompt_edit_file: None,
             shell_toggle_pair: None,
             force_clear: false,
             prev_fullscreen: false,
             gh_available: false,
             enriched_pr_fetch_spawned_at: None,
+            resource_fetch_spawned_at: None,
             review_refresh_in_flight: false,
             terminal_size: Rect::default(),
             tick_count: 0,
             throbber_state: throbber_widgets_tui::ThrobberState::default(),
             agent_states: BTreeMap::new(),
             cascade_paused: false,
+            undo_backend: None,
             last_left_click: None,
             last_list_click: None,
             list_rect: None,
@@ -1737,6 +1946,9 @@ impl Default for AppUiState {
             program_picker_choices: Vec::new(),
             program_picker_current: String::new(),
             repo_picker: RepoPicker::default(),
+            scrollback_search: ScrollbackSearchState::default(),
+            pending_scrollback_reveal: None,
+            pane_inspect: None,
         }
     }
 }
@@ -1749,9 +1961,9 @@ impl AppUiState {
     }
 
     /// Whether the right pane is currently showing its Info tab. False on the
-    /// board, which has no right pane.
+    /// board and the grid, which have no right pane.
     pub fn is_info_tab(&self) -> bool {
-        !self.view_mode.is_board()
+        !self.view_mode.is_full_screen()
             && self.right_pane_view.effective(self.is_project_selected()) == RightPaneView::Info
     }
 
@@ -1776,10 +1988,15 @@ impl AppUiState {
             | BindableAction::SelectShell
             | BindableAction::DeleteSession
             | BindableAction::RenameSession
+            | BindableAction::EditTags
+            | BindableAction::EditNotes
+            | BindableAction::ArchiveSession
             | BindableAction::RestartSession
             | BindableAction::ResetSession
             | BindableAction::ChangeProgram
             | BindableAction::ToggleKeepAlive
+            | BindableAction::RunTasks
+            | BindableAction::SendTaskFailure
             | BindableAction::OpenPullRequest
             | BindableAction::OpenReviewDiff
             | BindableAction::OpenInfo
@@ -1814,12 +2031,17 @@ impl AppUiState {
             BindableAction::NewSession
             | BindableAction::CheckoutBranch
             | BindableAction::CloneRepository => connected,
-            // The right pane only exists in the list views; the board is a
-            // full-screen takeover, so these have nothing to act on there.
+            // The right pane only exists in the list views; the board and the
+            // grid are full-screen takeovers, so these have nothing to act on.
             BindableAction::TogglePane
             | BindableAction::TogglePaneReverse
             | BindableAction::ShrinkLeftPane
-            | BindableAction::GrowLeftPane => !self.view_mode.is_board(),
+            | BindableAction::GrowLeftPane
+            | BindableAction::InspectPane => !self.view_mode.is_full_screen(),
+            BindableAction::CycleSwimlanes => self.view_mode.is_board(),
+            BindableAction::ToggleLane => self.view_mode.is_board() && !self.board.lanes.is_empty(),
+            BindableAction::CycleGridFilter => self.view_mode.is_grid(),
+            BindableAction::ToggleGridZoom => self.view_mode.is_grid() && has_session,
             // All other actions are always available
             _ => true,
         }
@@ -1839,17 +2061,7 @@ impl AppUiState {
     ) -> Vec<CommandEntry> {
         let mut scored: Vec<(i64, CommandEntry)> = Vec::new();
         for &action in BindableAction::ALL {
-            if matches!(
-                action,
-                BindableAction::NavigateUp
-                    | BindableAction::NavigateDown
-                    | BindableAction::NextGroup
-                    | BindableAction::PreviousGroup
-                    | BindableAction::NavigateFirst
-                    | BindableAction::NavigateLast
-                    | BindableAction::NavigateLeft
-                    | BindableAction::NavigateRight
-            ) {
+            if !is_palette_command(action) {
                 continue;
             }
             if !self.is_command_available(action) {
@@ -1876,6 +2088,22 @@ impl AppUiState {
     }
 }
 
+/// Whether `action` is listed in the command palette — everything but the
+/// cursor movement keys. Voice commands draw on the same list.
+pub(super) fn is_palette_command(action: BindableAction) -> bool {
+    !matches!(
+        action,
+        BindableAction::NavigateUp
+            | BindableAction::NavigateDown
+            | BindableAction::NextGroup
+            | BindableAction::PreviousGroup
+            | BindableAction::NavigateFirst
+            | BindableAction::NavigateLast
+            | BindableAction::NavigateLeft
+            | BindableAction::NavigateRight
+    )
+}
+
 /// Main TUI application
 /// Serializes program-list writes to each backend. A remote save is a full-list
 /// PUT; committing per edit means holding a reorder key fires several in quick
@@ -2283,7 +2511,7 @@ impl App {
         let states = backend.agent_states(false).await;
         if let Some(handle) = self.backends.iter_mut().find(|h| h.id == id) {
             if let Ok(snapshot) = snapshot {
-                handle.view.snapshot = snapshot;
+                handle.view.set_snapshot(snapshot);
                 // Local: derive connection from the snapshot's tmux health;
                 // remote: leave it to the connection-watch task (returns None).
                 if let Some(conn) =
@@ -2309,7 +2537,7 @@ impl App {
         // seeded) rather than the store directly, so this stays clear of the
         // Phase-C store-access gate.
         if let Ok(snapshot) = self.local_arc().workspace_snapshot().await {
-            self.backends[0].view.snapshot = snapshot;
+            self.backends[0].view.set_snapshot(snapshot);
             self.backends[0].view.connection = crate::backend::ConnectionState::Connected;
         }
         // Mirror any test-injected agent states (set on `ui_state.agent_states`
@@ -2500,7 +2728,7 @@ impl App {
             let states = handle.backend.agent_states(false).await;
             match (snapshot, states) {
                 (Ok(snapshot), Ok(states)) => {
-                    handle.view.snapshot = snapshot;
+                    handle.view.set_snapshot(snapshot);
                     handle.view.agent_states = states;
                     // Only the local backend reaches this arm (remotes `continue`
                     // above). Its connection derives from the snapshot's tmux
@@ -2571,9 +2799,16 @@ impl App {
             let backend = handle.backend.clone();
             let mut feed = backend.change_feed();
             let tx = self.event_loop.sender();
+            let announcer = self.conversation.announcer.clone();
             tasks.push(tokio::spawn(async move {
                 while feed.changed().await {
-                    if !fetch_and_send_backend_change(backend_id, backend.clone(), tx.clone()).await
+                    if !fetch_and_send_backend_change(
+                        backend_id,
+                        backend.clone(),
+                        tx.clone(),
+                        announcer.clone(),
+                    )
+                    .await
                     {
                         break;
                     }
@@ -2593,7 +2828,8 @@ impl App {
     pub(super) fn spawn_backend_view_refresh(&self, id: BackendId) {
         let backend = self.backend_arc(id);
         let tx = self.event_loop.sender();
-        tokio::spawn(fetch_and_send_backend_change(id.0, backend, tx));
+        let announcer = self.conversation.announcer.clone();
+        tokio::spawn(fetch_and_send_backend_change(id.0, backend, tx, announcer));
     }
 
     /// Run the application
@@ -2712,6 +2948,11 @@ impl App {
             self.ui_state.left_pane_pct = pct.clamp(MIN_LEFT_PANE_PCT, MAX_LEFT_PANE_PCT);
         }
 
+        // Restore the board's swimlane grouping and folds.
+        let prefs = self.tui_prefs.prefs();
+        self.ui_state.board_lanes = prefs.swimlanes;
+        self.ui_state.collapsed_lanes = prefs.collapsed_lanes.into_iter().collect();
+
         // Restore last selection from persisted state
         self.refresh_list_items().await;
         self.restore_selection().await;
@@ -2727,6 +2968,8 @@ impl App {
             self.ensure_listener_started().await;
             self.spawn_listen_ipc();
         }
+        // Announcements share the listener's media gate, so come up after it.
+        self.ensure_announcer_started();
 
         loop {
             // Setup terminal for TUI
@@ -2764,10 +3007,20 @@ impl App {
                 info!("Launching editor: {} {}", editor, path.display());
                 let status = std::process::Command::new(&editor).arg(&path).status();
 
-                if let Err(e) = status {
-                    self.ui_state.modal = Modal::Error {
-                        message: format!("Failed to launch '{}': {}", editor, e),
-                    };
+                match status {
+                    Ok(_) => {
+                        if let Some(file) = self.ui_state.prompt_edit_file.take() {
+                            self.load_prompt_from_editor(&file);
+                        }
+                    }
+                    Err(e) => {
+                        if let Some(file) = self.ui_state.prompt_edit_file.take() {
+                            let _ = std::fs::remove_file(file);
+                        }
+                        self.ui_state.modal = Modal::Error {
+                            message: format!("Failed to launch '{}': {}", editor, e),
+                        };
+                    }
                 }
 
                 self.event_loop.restart_input();
@@ -2843,6 +3096,16 @@ impl App {
                             };
                             let streams = conn.split();
 
+                            // A scrollback-search jump scrolls the pane to its
+                            // hit now that the attach (and its resize) is live.
+                            match &current {
+                                AttachTarget::Session {
+                                    session,
+                                    kind: AttachKind::Agent,
+                                } => self.spawn_pending_scrollback_reveal(*session),
+                                _ => self.ui_state.pending_scrollback_reveal = None,
+                            }
+
                             // Only intercept Ctrl+Z / Alt-r / Alt-V for Claude
                             // (non-shell) panes: SIGTSTP would freeze a shell-
                             // less pane, and a shell's Ctrl-r must not be
@@ -28,6 +28,9 @@ pub(super) enum ActiveCursor {
     Name { row: u16, base_col: u16 },
     /// The project filter (a plain `String`); the cursor sits at absolute `col`.
     Filter { row: u16, col: u16 },
+    /// The initial-prompt editor; `row`/`col` are already scrolled into the
+    /// visible box, relative to the inner area.
+    Prompt { row: u16, col: u16 },
 }
 
 /// Label column contents for the collapsed grid; the widest determines the
@@ -37,6 +40,14 @@ const SERVER_LABEL: &str = "Server";
 const PROJECT_LABEL: &str = "Project";
 const PROGRAM_LABEL: &str = "Program";
 const SECTION_LABEL: &str = "Section";
+const PROMPT_LABEL: &str = "Prompt";
+
+/// Text rows in the initial-prompt box. Fixed, so typing a long prompt scrolls
+/// inside the box rather than growing the dialog.
+const PROMPT_ROWS: usize = 5;
+
+/// Left margin of each prompt-box row (`  │ `).
+const PROMPT_GUTTER: u16 = 4;
 
 /// Build the body lines for the New Session (`Modal::Input`) dialog.
 ///
@@ -51,6 +62,8 @@ const SECTION_LABEL: &str = "Section";
 /// original prompt + single input.
 /// `width` is the inner content width, used to pad the selection bar. Returns
 /// the lines plus where the active text cursor should be placed, if any.
+/// The initial-prompt editor, when present, is always drawn as a fixed-height
+/// box below the pickers so focusing it doesn't resize the dialog.
 #[allow(clippy::too_many_arguments)]
 pub(super) fn build_input_modal_lines(
     prompt: &str,
@@ -60,6 +73,7 @@ pub(super) fn build_input_modal_lines(
     program_picker: Option<&ProgramPicker>,
     server_picker: Option<&ServerPicker>,
     section_picker: Option<&SectionPicker>,
+    prompt_editor: Option<&PromptEditor>,
     focus: InputFocus,
     expanded: bool,
     max_rows: usize,
@@ -253,15 +267,59 @@ pub(super) fn build_input_modal_lines(
         }
     }
 
+    // Initial-prompt box: a label row, then PROMPT_ROWS text rows scrolled to
+    // keep the cursor visible (vertically and, for a long line, horizontally).
+    if let Some(editor) = prompt_editor {
+        let focused = focus == InputFocus::Prompt;
+        let label_style = if focused { header_style } else { dim_style };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{PROMPT_LABEL:<label_w$}"), label_style),
+            Span::raw("    "),
+            Span::styled("optional, sent to the agent on launch", dim_style),
+        ]));
+
+        let (cur_row, cur_col) = editor.cursor();
+        let text_w = (width.saturating_sub(PROMPT_GUTTER) as usize).max(1);
+        let top = cur_row.saturating_sub(PROMPT_ROWS - 1);
+        let left = cur_col.saturating_sub(text_w - 1);
+        let first_row = lines.len();
+        for i in 0..PROMPT_ROWS {
+            let text: String = editor
+                .lines()
+                .get(top + i)
+                .map(|l| l.chars().skip(left).take(text_w).collect())
+                .unwrap_or_default();
+            lines.push(Line::from(vec![
+                Span::styled("  │ ", label_style),
+                Span::raw(text),
+            ]));
+        }
+        if editor.is_blank() && !focused {
+            lines[first_row] = Line::from(vec![
+                Span::styled("  │ ", dim_style),
+                Span::styled("Tab here to write one…", dim_style),
+            ]);
+        }
+        if focused {
+            cursor = Some(ActiveCursor::Prompt {
+                row: (first_row + cur_row - top) as u16,
+                col: PROMPT_GUTTER + (cur_col - left) as u16,
+            });
+        }
+    }
+
     if let Some(h) = hint {
         lines.push(Line::from(Span::styled(h.to_string(), italic_info)));
     }
 
     // Footer hint (context-sensitive), separated by a blank line. On the name
     // row Space types a literal space, so only advertise "Space choose" when a
-    // picker row is focused.
+    // picker row is focused. In the prompt Enter is a newline, so it advertises
+    // the submit chord and its editor/history keys instead.
     let footer = if expanded {
         "↑↓ choose · Enter select · Esc close"
+    } else if focus == InputFocus::Prompt {
+        "Ctrl-s create · Ctrl-o $EDITOR · Ctrl-p/n history · Esc cancel"
     } else if focus == InputFocus::Name {
         "↑↓ move · Enter create · Esc cancel"
     } else {
@@ -311,10 +369,11 @@ impl App {
                 program_picker,
                 server_picker,
                 section_picker,
+                prompt_editor,
                 focus,
                 expanded,
                 mask,
-                ..
+                on_submit,
             } => {
                 // Cap the visible project rows so a long list can't grow the
                 // modal off-screen; the list scrolls (via `picker.scroll`) to
@@ -330,6 +389,13 @@ impl App {
                     )
                     .map(|b| format!("↳ existing branch: {} — will check out", b))
                 });
+                // The query bar's hint is the parse error, if any.
+                let hint = match on_submit {
+                    InputAction::FilterSessions { .. } => {
+                        Some(super::filter::filter_bar_hint(value.value()))
+                    }
+                    _ => hint,
+                };
 
                 // Secret entry (e.g. a bearer token) renders as bullets; the
                 // underlying Input still holds the real value, and bullets are
@@ -354,6 +420,7 @@ impl App {
                     program_picker.as_ref(),
                     server_picker.as_ref(),
                     section_picker.as_ref(),
+                    prompt_editor.as_ref(),
                     *focus,
                     *expanded,
                     max_rows,
@@ -380,7 +447,8 @@ impl App {
                 frame.render_widget(Paragraph::new(lines), inner);
 
                 // Place the real cursor at whichever text field the layout
-                // reported as active (the name input or the project filter).
+                // reported as active (the name input, the project filter, or
+                // the prompt editor).
                 match cursor {
                     Some(ActiveCursor::Name { row, base_col }) => place_input_cursor(
                         frame,
@@ -389,7 +457,7 @@ impl App {
                         inner.y + row,
                         inner.width.saturating_sub(base_col),
                     ),
-                    Some(ActiveCursor::Filter { row, col }) => {
+                    Some(ActiveCursor::Filter { row, col } | ActiveCursor::Prompt { row, col }) => {
                         frame.set_cursor_position((
                             (inner.x + col).min(inner.x + inner.width.saturating_sub(1)),
                             inner.y + row,
@@ -590,6 +658,18 @@ impl App {
                 }
             }
 
+            Modal::CommandOutput {
+                title,
+                output,
+                scroll,
+            } => {
+                let mut offset = *scroll;
+                self.render_command_output_modal(frame, area, title, output, &mut offset);
+                if let Modal::CommandOutput { scroll, .. } = &mut self.ui_state.modal {
+                    *scroll = offset;
+                }
+            }
+
             Modal::Info { scroll } => {
                 let mut offset = *scroll;
                 self.render_info_modal(frame, area, &mut offset);
@@ -869,6 +949,11 @@ impl App {
             "",
             width = key_col_width,
         )));
+        lines.push(Line::from(format!(
+            "  {:<width$}(`--interrupt` cuts a spoken reply short)",
+            "",
+            width = key_col_width,
+        )));
 
         // Mouse (the status/review bars surface primary actions as buttons).
         lines.push(Line::from(""));
@@ -1012,6 +1097,62 @@ impl App {
         }
     }
 
+    /// Render a custom command's output (`Modal::CommandOutput`). Mirrors
+    /// [`render_help_modal`]: clamps `scroll` against the output height.
+    fn render_command_output_modal(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        title: &str,
+        output: &str,
+        scroll: &mut u16,
+    ) {
+        let modal_area = centered_rect(80, 80, area);
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(format!(" {title} "))
+            .borders(Borders::ALL)
+            .border_type(self.border_type())
+            .border_style(Style::default().fg(self.theme.modal_info));
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let content_area = inner.inner(Margin {
+            horizontal: 1,
+            vertical: 0,
+        });
+        let lines: Vec<Line> = if output.is_empty() {
+            vec![Line::from(Span::styled(
+                "(no output)",
+                Style::default().fg(self.theme.text_secondary),
+            ))]
+        } else {
+            output.lines().map(|l| Line::from(l.to_string())).collect()
+        };
+        let total_lines = lines.len().min(u16::MAX as usize) as u16;
+        let visible = content_area.height;
+        let max_scroll = total_lines.saturating_sub(visible);
+        if *scroll > max_scroll {
+            *scroll = max_scroll;
+        }
+        let offset = *scroll;
+        frame.render_widget(Paragraph::new(lines).scroll((offset, 0)), content_area);
+
+        if max_scroll > 0 {
+            // See `render_help_modal` for why the length is the number of
+            // scroll positions rather than the line count.
+            let mut sb_state = ScrollbarState::new(max_scroll as usize + 1)
+                .position(offset as usize)
+                .viewport_content_length(visible as usize);
+            let scrollbar = Scrollbar::default()
+                .orientation(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None);
+            frame.render_stateful_widget(scrollbar, content_area, &mut sb_state);
+        }
+    }
+
     /// Render the session Info modal (`Modal::Info`). Mirrors
     /// [`render_help_modal`]: the caller passes the current scroll, this clamps
     /// it against the composed content height and writes back the clamp result.
@@ -1197,6 +1338,7 @@ impl App {
             PaletteMode::SectionPicker { .. } => " Move to Section ",
             PaletteMode::RemoteServerPicker => " Remove Remote Server ",
             PaletteMode::ProgramPicker { .. } => " Change Program ",
+            PaletteMode::ArchivedPicker => " Archived Sessions — Enter restores ",
             // The fetch state lives in the title (as the Checkout modal
             // does with "fetching origin…") so a slow or failed `gh`
             // listing is visible rather than reading as an empty account.
@@ -1206,6 +1348,18 @@ impl App {
                 RepoFetch::Ready => " Clone Repository — Enter a repo, or type a URL ",
                 RepoFetch::Failed(_) => " Clone Repository — no repo list; type a URL ",
             },
+            PaletteMode::ScrollbackSearch => {
+                let search = &self.ui_state.scrollback_search;
+                if search.query.trim().is_empty() {
+                    " Search Scrollback — type to search every session "
+                } else if search.searching {
+                    " Search Scrollback — searching… "
+                } else if search.hits.is_empty() {
+                    " Search Scrollback — no matches "
+                } else {
+                    " Search Scrollback "
+                }
+            }
         };
         let block = Block::default()
             .title(title)
@@ -1293,54 +1447,29 @@ impl App {
                     frame.render_widget(Paragraph::new(Line::from(spans)), line_area);
                 }
                 QuickSwitchItem::Command(entry) => {
-                    // Full-row background distinguishes commands from
-                    // sessions at a glance. Selection highlight takes
-                    // precedence over the command background.
-                    let row_style = if is_selected {
-                        self.theme.selection()
-                    } else {
-                        Style::default()
-                            .bg(self.theme.palette_command_bg)
-                            .fg(self.theme.palette_command_fg)
-                    };
-
-                    // Reserve trailing space for the right-aligned
-                    // key hint; keep one space margin on each side.
-                    let available = line_area.width as usize;
-                    let glyph = " ❯ ";
-                    let keys = &entry.keys;
-                    let keys_width = keys.chars().count();
-                    let label = entry.label;
-                    let label_width = label.chars().count();
-                    let glyph_width = glyph.chars().count();
-                    let padding = available
-                        .saturating_sub(glyph_width)
-                        .saturating_sub(label_width)
-                        .saturating_sub(keys_width)
-                        // Leave a 1-char gutter before the key hint
-                        // when it's non-empty.
-                        .saturating_sub(if keys.is_empty() { 0 } else { 1 });
-
-                    let gutter = if keys.is_empty() {
-                        String::new()
-                    } else {
-                        " ".to_string()
-                    };
-                    let content = format!(
-                        "{glyph}{label}{pad}{gutter}{keys}",
-                        glyph = glyph,
-                        label = label,
-                        pad = " ".repeat(padding),
-                        gutter = gutter,
-                        keys = keys,
+                    self.render_command_row(
+                        frame,
+                        line_area,
+                        is_selected,
+                        entry.label,
+                        &entry.keys,
                     );
-                    let line = Line::from(Span::styled(content, row_style));
-                    frame.render_widget(Paragraph::new(line).style(row_style), line_area);
+                }
+                QuickSwitchItem::CustomCommand { name, keys } => {
+                    self.render_command_row(frame, line_area, is_selected, name, keys);
+                }
+                QuickSwitchItem::SavedFilter { name, query } => {
+                    let label = format!("Filter: {name}");
+                    self.render_command_row(frame, line_area, is_selected, &label, query);
+                }
+                QuickSwitchItem::ScrollbackHit { hit, title, .. } => {
+                    self.render_scrollback_hit_row(frame, line_area, is_selected, hit, title);
                 }
                 QuickSwitchItem::SectionMove { label, .. }
                 | QuickSwitchItem::RemoteServerRemove { label, .. }
                 | QuickSwitchItem::GithubRepo { label, .. }
-                | QuickSwitchItem::ProgramChange { label, .. } => {
+                | QuickSwitchItem::ProgramChange { label, .. }
+                | QuickSwitchItem::ArchivedSession { label, .. } => {
                     let style = if is_selected {
                         self.theme.selection()
                     } else {
@@ -1352,6 +1481,96 @@ impl App {
             }
         }
     }
+
+    /// Draw one scrollback-search hit: `title:line` dimmed, then the line's
+    /// text with the match emphasised.
+    fn render_scrollback_hit_row(
+        &self,
+        frame: &mut Frame,
+        line_area: Rect,
+        is_selected: bool,
+        hit: &ScrollbackHit,
+        title: &str,
+    ) {
+        let row_style = if is_selected {
+            self.theme.selection()
+        } else {
+            Style::default()
+        };
+        let text = hit.text.trim_start();
+        let trimmed = hit.text.len() - text.len();
+        // The range is in bytes of the untrimmed text; an out-of-range or
+        // non-boundary range (it can't come from this index, but the hit may
+        // be a remote's) degrades to no emphasis.
+        let (before, matched, after) = hit
+            .match_start
+            .checked_sub(trimmed)
+            .zip(hit.match_end.checked_sub(trimmed))
+            .and_then(|(start, end)| {
+                Some((text.get(..start)?, text.get(start..end)?, text.get(end..)?))
+            })
+            .unwrap_or((text, "", ""));
+        let line = Line::from(vec![
+            Span::styled(
+                format!(" {title}:{} ", hit.line),
+                Style::default().fg(self.theme.text_secondary),
+            ),
+            Span::raw(before.to_string()),
+            Span::styled(
+                matched.to_string(),
+                Style::default()
+                    .fg(self.theme.text_accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(after.to_string()),
+        ]);
+        frame.render_widget(Paragraph::new(line).style(row_style), line_area);
+    }
+
+    /// Draw one command row of the palette — a built-in command or a custom
+    /// one: glyph and label on the left, key hint right-aligned.
+    fn render_command_row(
+        &self,
+        frame: &mut Frame,
+        line_area: Rect,
+        is_selected: bool,
+        label: &str,
+        keys: &str,
+    ) {
+        // Full-row background distinguishes commands from
+        // sessions at a glance. Selection highlight takes
+        // precedence over the command background.
+        let row_style = if is_selected {
+            self.theme.selection()
+        } else {
+            Style::default()
+                .bg(self.theme.palette_command_bg)
+                .fg(self.theme.palette_command_fg)
+        };
+
+        // Reserve trailing space for the right-aligned
+        // key hint; keep one space margin on each side.
+        let available = line_area.width as usize;
+        let glyph = " ❯ ";
+        let keys_width = keys.chars().count();
+        let label_width = label.chars().count();
+        let glyph_width = glyph.chars().count();
+        let padding = available
+            .saturating_sub(glyph_width)
+            .saturating_sub(label_width)
+            .saturating_sub(keys_width)
+            // Leave a 1-char gutter before the key hint
+            // when it's non-empty.
+            .saturating_sub(if keys.is_empty() { 0 } else { 1 });
+
+        let gutter = if keys.is_empty() { "" } else { " " };
+        let content = format!(
+            "{glyph}{label}{pad}{gutter}{keys}",
+            pad = " ".repeat(padding),
+        );
+        let line = Line::from(Span::styled(content, row_style));
+        frame.render_widget(Paragraph::new(line).style(row_style), line_area);
+    }
 }
 
 // List-modal geometry, shared between the render arms and the mouse handler
@@ -1535,6 +1754,7 @@ mod tests {
             program,
             server,
             section,
+            None,
             focus,
             expanded,
             MAX_ROWS,
@@ -1554,6 +1774,46 @@ mod tests {
         assert!(!has_line(&lines, "Session name"));
     }
 
+    #[test]
+    fn prompt_box_scrolls_to_keep_the_cursor_visible() {
+        let prog = ProgramPicker {
+            choices: vec![crate::config::ProgramEntry {
+                label: "claude".to_string(),
+                command: "claude".to_string(),
+            }],
+            selected: 0,
+        };
+        let mut editor = PromptEditor::default();
+        editor.insert_str("one\ntwo\nthree\nfour\nfive\nsix");
+        let (lines, cursor) = build_input_modal_lines(
+            "Enter session name:",
+            "x",
+            None,
+            None,
+            Some(&prog),
+            None,
+            None,
+            Some(&editor),
+            InputFocus::Prompt,
+            false,
+            MAX_ROWS,
+            WIDTH,
+            &Theme::basic(),
+        );
+        // Six lines in a five-row box: the first scrolls off, the cursor
+        // stays on the last visible row after "six".
+        assert!(!lines.iter().any(|l| line_text(l) == "  │ one"));
+        let six = lines
+            .iter()
+            .position(|l| line_text(l) == "  │ six")
+            .expect("cursor line shown");
+        assert!(matches!(
+            cursor,
+            Some(ActiveCursor::Prompt { row, col: 7 }) if row as usize == six
+        ));
+        assert!(has_line(&lines, "Ctrl-s create"));
+    }
+
     #[test]
     fn collapsed_grid_shows_three_field_rows_with_values() {
         let choices = project_choices(&["alpha", "beta", "gamma"]);
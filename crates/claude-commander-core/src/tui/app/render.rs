@@ -137,8 +137,21 @@ impl App {
         }
 
         // The conversation overlay is also a full-screen takeover.
-        if let Modal::Conversation { input, scroll } = &self.ui_state.modal {
-            self.render_conversation_modal(frame, size, input, *scroll);
+        if let Modal::Conversation {
+            input,
+            scroll,
+            history,
+            agents,
+        } = &self.ui_state.modal
+        {
+            self.render_conversation_modal(
+                frame,
+                size,
+                input,
+                *scroll,
+                history.as_deref(),
+                *agents,
+            );
             return;
         }
 
@@ -150,8 +163,8 @@ impl App {
             height: size.height.saturating_sub(1),
         };
 
-        if self.ui_state.view_mode.is_board() {
-            // Board: 1-line top bar, then the board.
+        if self.ui_state.view_mode.is_full_screen() {
+            // Board / grid: 1-line top bar, then the board or the tiles.
             let top_bar = Rect {
                 height: 1,
                 ..content
@@ -165,7 +178,11 @@ impl App {
             // rect so a stale wheel event can't scroll an invisible pane.
             self.ui_state.right_pane_rect = None;
             self.render_top_bar(frame, top_bar);
-            self.render_board(frame, board_area);
+            if self.ui_state.view_mode.is_grid() {
+                self.render_grid(frame, board_area);
+            } else {
+                self.render_board(frame, board_area);
+            }
             self.render_modal(frame, board_area);
         } else {
             // List view: session list on the left, live pane on the right.
@@ -182,29 +199,60 @@ impl App {
     }
 
     /// Render the 1-line top bar: app title on the left, session/project counts
-    /// on the right, styled like the status bar.
+    /// on the right, styled like the status bar. On the grid the title also
+    /// names the active filter and the count is of tiles; on the board it
+    /// names the swimlane grouping when lanes are on.
     fn render_top_bar(&self, frame: &mut Frame, area: Rect) {
         if area.height == 0 {
             return;
         }
         let style = self.theme.status_bar();
 
-        let sessions = self.ui_state.board.worktree_count();
+        let grid = self.ui_state.view_mode.is_grid();
+        let sessions = if grid {
+            self.ui_state.grid_tiles.len()
+        } else {
+            // Sessions folded away in a collapsed swimlane still count.
+            let board = &self.ui_state.board;
+            (0..board.columns.len())
+                .map(|i| board.column_total(i))
+                .sum()
+        };
         let projects = self.ui_state.board.projects.len();
 
-        let title_text = " Claude Commander";
-        // When a project filter is active, name it and how to clear it; the
-        // session count then reflects the filtered card count.
-        let counts_text = match self.ui_state.board_filter.and_then(|pid| {
+        let title_text = if grid {
+            format!(
+                " Claude Commander \u{00b7} Grid [{}]",
+                self.ui_state.grid_state.filter.label()
+            )
+        } else if let Some(lanes) = self
+            .ui_state
+            .board_lanes
+            .filter(|_| self.ui_state.view_mode.is_board())
+        {
+            format!(" Claude Commander \u{00b7} lanes: {}", lanes.label())
+        } else {
+            " Claude Commander".to_string()
+        };
+        // When a project filter or a query is active, name it and how to clear
+        // it; the session count then reflects the filtered card count.
+        let project_filter = self.ui_state.board_filter.and_then(|pid| {
             self.ui_state
                 .board
                 .projects
                 .iter()
                 .find(|p| p.project_id == pid)
                 .map(|p| p.name.clone())
-        }) {
-            Some(name) => format!(
-                "filtered: {name} (Esc to clear) \u{00b7} {sessions} session{} ",
+        });
+        let filter = match (project_filter, &self.ui_state.session_query) {
+            (Some(name), Some(query)) => Some(format!("{name}, {query}")),
+            (Some(name), None) => Some(name),
+            (None, Some(query)) => Some(query.to_string()),
+            (None, None) => None,
+        };
+        let counts_text = match filter {
+            Some(filter) => format!(
+                "filtered: {filter} (Esc to clear) \u{00b7} {sessions} session{} ",
                 if sessions == 1 { "" } else { "s" },
             ),
             None => format!(
@@ -246,11 +294,16 @@ impl App {
             .split(area);
 
         let heading_style = self.theme.status_bar();
-        let heading = Paragraph::new(Line::styled(
-            self.ui_state.view_mode.heading_label(),
-            heading_style,
-        ))
-        .style(heading_style);
+        // An active filter query is named after the view, as on the board.
+        let heading_text = match &self.ui_state.session_query {
+            Some(query) => format!(
+                "{} filter `{query}` (Esc to clear)",
+                self.ui_state.view_mode.heading_label()
+            ),
+            None => self.ui_state.view_mode.heading_label().to_string(),
+        };
+        let heading =
+            Paragraph::new(Line::styled(heading_text, heading_style)).style(heading_style);
         frame.render_widget(heading, chunks[0]);
 
         let body = chunks[1];
@@ -297,6 +350,7 @@ impl App {
                 .invert_pr_label_color(self.config.invert_pr_label_color)
                 .show_program_override(show_program)
                 .comment_sessions(self.ui_state.sessions_with_comments.clone())
+                .session_viewers(self.ui_state.session_viewers.clone())
                 .recent_display_info(display_info);
             // Record the recents-panel rect for mouse hit-testing.
             self.ui_state.recents_rect = Some(sub[0]);
@@ -338,7 +392,8 @@ impl App {
             .invert_pr_label_color(self.config.invert_pr_label_color)
             .show_session_program(self.config.show_session_program)
             .pull_blocked_projects(blocked)
-            .comment_sessions(self.ui_state.sessions_with_comments.clone());
+            .comment_sessions(self.ui_state.sessions_with_comments.clone())
+            .session_viewers(self.ui_state.session_viewers.clone());
 
         let mut main_state = ratatui::widgets::ListState::default();
         *main_state.offset_mut() = self.ui_state.main_list_offset;
@@ -397,6 +452,8 @@ impl App {
             self.ui_state.board_hit_regions.clear();
             self.ui_state.board_button_regions.clear();
             self.ui_state.board_heading_regions.clear();
+            self.ui_state.board_lane_regions.clear();
+            self.ui_state.board_lane_regions.clear();
             self.ui_state.board_column_rects = None;
             return;
         }
@@ -410,6 +467,7 @@ impl App {
             .show_session_program(self.config.show_session_program)
             .mixed_programs(self.ui_state.has_mixed_programs)
             .comment_sessions(&self.ui_state.sessions_with_comments)
+            .session_viewers(&self.ui_state.session_viewers)
             .pull_blocked_projects(&self.ui_state.project_pull_blocked)
             .project_colors(&self.ui_state.project_colors)
             .session_numbers(&self.ui_state.session_numbers)
@@ -420,15 +478,79 @@ impl App {
         self.ui_state.board_hit_regions = out.hit_regions;
         self.ui_state.board_button_regions = out.button_regions;
         self.ui_state.board_heading_regions = out.heading_regions;
+        self.ui_state.board_lane_regions = out.lane_regions;
         self.ui_state.board_column_rects = Some(out.rects);
     }
 
+    /// Render the watch grid: one tile per session with a live pane, each
+    /// showing the tail of its latest capture. Records the tile rects for mouse
+    /// hits and which sessions are on screen, so the next capture round covers
+    /// exactly those.
+    fn render_grid(&mut self, frame: &mut Frame, area: Rect) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        self.ui_state.list_rect = None;
+        self.ui_state.recents_rect = None;
+        // Clear the board's hit regions too, so a click can't land on a card
+        // that isn't drawn.
+        self.ui_state.board_hit_regions.clear();
+        self.ui_state.board_button_regions.clear();
+        self.ui_state.board_heading_regions.clear();
+        self.ui_state.board_lane_regions.clear();
+        self.ui_state.board_column_rects = None;
+
+        if self.ui_state.grid_tiles.is_empty() {
+            let key = self
+                .config
+                .keybindings
+                .keys_for(BindableAction::CycleGridFilter)
+                .first()
+                .map(|k| k.to_string());
+            let hint = match (self.ui_state.grid_state.filter, key) {
+                (GridFilter::All, _) => "No running sessions to watch".to_string(),
+                (filter, Some(k)) => format!(
+                    "No sessions match \"{}\" \u{2014} press {k} to change the filter",
+                    filter.label()
+                ),
+                (filter, None) => format!("No sessions match \"{}\"", filter.label()),
+            };
+            let para = Paragraph::new(Line::from(Span::styled(
+                hint,
+                Style::default().fg(self.theme.text_secondary),
+            )))
+            .alignment(Alignment::Center);
+            let mid = Rect {
+                y: area.y + area.height / 2,
+                height: 1,
+                ..area
+            };
+            frame.render_widget(para, mid);
+            self.ui_state.grid_hit_regions.clear();
+            self.ui_state.grid_visible.clear();
+            return;
+        }
+
+        let out = GridWidget::new(
+            &self.ui_state.grid_tiles,
+            &self.ui_state.grid_captures,
+            &self.ui_state.session_numbers,
+            &self.theme,
+        )
+        .tick(self.ui_state.tick_count)
+        .rounded(self.config.rounded_borders)
+        .render(area, frame.buffer_mut(), &mut self.ui_state.grid_state);
+        self.ui_state.grid_hit_regions = out.hit_regions;
+        self.ui_state.grid_visible = out.visible;
+        self.ui_state.grid_capture_lines = out.capture_lines;
+    }
+
     /// Render the list views' right-hand pane: a live capture of the selected
     /// session's agent pane or shell, or its Info view, with a tab header.
     ///
-    /// The pane is passive — keys always drive the session list — so the live
-    /// captures render dimmed when `dim_unfocused_preview` is set, keeping the
-    /// list visually dominant. Info is exempt: it is static, already styled for
+    /// The pane is passive — keys drive the session list unless an inspection
+    /// is open (see `inspect.rs`) — so the live captures render dimmed when
+    /// `dim_unfocused_preview` is set, keeping the list visually dominant. Info is exempt: it is static, already styled for
     /// legibility, and shares its lines with the modal, so dimming it would only
     /// make the same text harder to read. Capture content arrives from
     /// `spawn_preview_update`; its scroll follows the tail until the user wheels
@@ -451,6 +573,18 @@ impl App {
             frame.render_widget(Clear, area);
         }
 
+        // A tab swap underneath an open inspection (a selection moving onto a
+        // project row trades Preview for Shell) closes it, rather than leaving
+        // keys captured by a view that is no longer drawn.
+        if self
+            .ui_state
+            .pane_inspect
+            .as_ref()
+            .is_some_and(|i| i.view != view)
+        {
+            self.ui_state.pane_inspect = None;
+        }
+
         let block = Block::default()
             .title(self.build_pane_tabs(tabs, active))
             .borders(Borders::ALL)
@@ -477,6 +611,19 @@ impl App {
             return;
         }
 
+        // An open inspection replaces the live capture with its frozen copy,
+        // drawn with a focused border since keys now drive the pane.
+        if let Some(inspect) = &mut self.ui_state.pane_inspect {
+            inspect.inspector.set_height(area.height.saturating_sub(2));
+            frame.render_widget(Clear, area);
+            frame.render_widget(
+                InspectView::new(&inspect.inspector, &self.theme)
+                    .block(block.border_style(self.theme.border_focused())),
+                area,
+            );
+            return;
+        }
+
         let dim_opacity = self
             .config
             .dim_unfocused_preview
@@ -595,6 +742,15 @@ impl App {
             ai_summary,
             summary_key_hint,
             stack_chain: &self.ui_state.stack_chain,
+            resources: self
+                .ui_state
+                .resource_usage
+                .and_then(|(sid, usage)| (sid == session_id).then_some(usage)),
+            hook_runs: &session.hook_runs,
+            ports: session.ports,
+            task_runs: &session.task_runs,
+            tags: &session.tags,
+            notes: &session.notes,
         })
     }
 
@@ -746,10 +902,13 @@ impl App {
     }
 
     /// The ordered, context-aware set of actions surfaced as buttons in the
-    /// status bar, filtered to those currently invokable.
+    /// status bar, filtered to those currently invokable (the grid controls
+    /// only show on the grid).
     fn status_bar_actions(&self) -> Vec<BindableAction> {
         use BindableAction::*;
         const ACTIONS: &[BindableAction] = &[
+            CycleGridFilter,
+            ToggleGridZoom,
             NewSession,
             NewStackedSession,
             DeleteSession,
@@ -0,0 +1,189 @@
+//! Scrollback search: the palette mode, its debounced fan-out across backends,
+//! and the reveal that scrolls an attached pane to the chosen hit.
+//!
+//! Each backend searches its own index (tmux history lives where the session
+//! runs), so a query goes to every connected backend and the hits are merged.
+//! Unlike the other palette modes the rows aren't filtered locally — every
+//! keystroke schedules a fresh search, debounced by
+//! [`SCROLLBACK_SEARCH_DEBOUNCE`] and superseded by generation.
+
+use std::sync::atomic::Ordering;
+
+use super::*;
+use crate::scrollback::ScrollbackSearch;
+
+/// Hits requested from each backend per search. The palette shows a window of
+/// them; more than this and the query wants narrowing rather than scrolling.
+const PALETTE_SEARCH_LIMIT: usize = 100;
+
+/// Delay between an attach connecting and the reveal it carries, so tmux has
+/// applied the attaching client's size before copy-mode positions the view —
+/// a resize reflows the history and would move the line out from under it.
+const REVEAL_AFTER_ATTACH: Duration = Duration::from_millis(200);
+
+impl App {
+    /// Handle "Search scrollback" — open the palette in scrollback-search mode.
+    pub(super) fn handle_search_scrollback(&mut self) {
+        self.ui_state.scrollback_search = ScrollbackSearchState::default();
+        self.ui_state.modal = Modal::QuickSwitch {
+            mode: PaletteMode::ScrollbackSearch,
+            query: super::Input::default(),
+            matches: Vec::new(),
+            selected_idx: 0,
+            scroll: 0,
+        };
+    }
+
+    /// Schedule a search for `query` unless it's the one already answered or
+    /// in flight. An empty query clears the hits instead.
+    pub(super) fn schedule_scrollback_search(&mut self, query: &str) {
+        let state = &mut self.ui_state.scrollback_search;
+        if state.query == query {
+            return;
+        }
+        state.query = query.to_string();
+        let generation = state.generation.fetch_add(1, Ordering::Relaxed) + 1;
+        if query.trim().is_empty() {
+            state.hits.clear();
+            state.searching = false;
+            return;
+        }
+        state.searching = true;
+
+        let latest = state.generation.clone();
+        let search = ScrollbackSearch {
+            limit: Some(PALETTE_SEARCH_LIMIT),
+            ..ScrollbackSearch::literal(query)
+        };
+        // Same reachability rule as the PR refresh fan-out: a degraded remote
+        // would only error, so it is skipped rather than reported every keystroke.
+        let backends: Vec<(BackendId, String, Arc<dyn CommanderBackend>)> = self
+            .backends
+            .iter()
+            .filter(|h| {
+                h.id == LOCAL_BACKEND_ID || matches!(h.view.connection, ConnectionState::Connected)
+            })
+            .map(|h| (h.id, h.backend.descriptor().name, h.backend.clone()))
+            .collect();
+        let tx = self.event_loop.sender();
+        tokio::spawn(async move {
+            tokio::time::sleep(SCROLLBACK_SEARCH_DEBOUNCE).await;
+            if latest.load(Ordering::Relaxed) != generation {
+                return;
+            }
+            let results =
+                futures::future::join_all(backends.into_iter().map(|(id, name, backend)| {
+                    let search = search.clone();
+                    async move { (id, name, backend.search_scrollback(search).await) }
+                }))
+                .await;
+            let mut hits = Vec::new();
+            let mut errors = Vec::new();
+            for (id, name, result) in results {
+                match result {
+                    Ok(found) => hits.extend(found.into_iter().map(|hit| (id.0, hit))),
+                    Err(e) => errors.push(format!("{name}: {e}")),
+                }
+            }
+            let _ = tx
+                .send(AppEvent::StateUpdate(StateUpdate::ScrollbackHitsLoaded {
+                    generation,
+                    hits,
+                    errors,
+                }))
+                .await;
+        });
+    }
+
+    /// Apply a finished search, unless the query has moved on since. Backends
+    /// whose search failed are named in the status bar; the others' hits
+    /// still show.
+    pub(super) fn apply_scrollback_hits(
+        &mut self,
+        generation: u64,
+        hits: Vec<(BackendId, ScrollbackHit)>,
+        errors: Vec<String>,
+    ) {
+        let state = &mut self.ui_state.scrollback_search;
+        if generation != state.generation.load(Ordering::Relaxed) {
+            debug!("Discarding stale scrollback hits (gen {generation})");
+            return;
+        }
+        state.hits = hits;
+        state.searching = false;
+        if !errors.is_empty() {
+            self.ui_state.status_message = Some((
+                format!("Scrollback search failed on {}", errors.join("; ")),
+                Instant::now() + Duration::from_secs(5),
+            ));
+        }
+        self.refilter_quick_switch();
+    }
+
+    /// The palette rows for the current hits, in the order the backends
+    /// returned them (newest first per session).
+    pub(super) fn gather_scrollback_hit_items(&self) -> Vec<QuickSwitchItem> {
+        self.ui_state
+            .scrollback_search
+            .hits
+            .iter()
+            .map(|(backend, hit)| {
+                let title = self
+                    .view_for(*backend)
+                    .snapshot
+                    .sessions
+                    .iter()
+                    .find(|s| s.session_id == hit.session_id)
+                    .map(|s| s.title.clone())
+                    .unwrap_or_else(|| hit.session_id.to_string());
+                QuickSwitchItem::ScrollbackHit {
+                    backend: *backend,
+                    hit: hit.clone(),
+                    title,
+                }
+            })
+            .collect()
+    }
+
+    /// Enter on a hit: attach to its session and queue the reveal for once the
+    /// attach is connected (the attach loop consumes it).
+    pub(super) async fn open_scrollback_hit(&mut self, backend: BackendId, hit: ScrollbackHit) {
+        self.ui_state.modal = Modal::None;
+        self.unhide_session(hit.session_id).await;
+        if !self.select_session_in_tree(hit.session_id) {
+            self.ui_state.status_message = Some((
+                "That session is no longer running".to_string(),
+                Instant::now() + Duration::from_secs(3),
+            ));
+            return;
+        }
+        self.ui_state.pending_scrollback_reveal =
+            Some((SessionRef::new(backend, hit.session_id), hit.reveal()));
+        self.handle_select().await;
+        // An attach that didn't start (a stopped session being restarted, a
+        // refused remote) leaves nothing to reveal into.
+        if self.ui_state.attach_request.is_none() {
+            self.ui_state.pending_scrollback_reveal = None;
+        }
+    }
+
+    /// Fire the pending reveal if it belongs to the attach that just connected.
+    /// Failures are logged only: the attach itself is what the user asked for,
+    /// and a line trimmed from the history since the search just means the
+    /// pane opens at the bottom as usual.
+    pub(super) fn spawn_pending_scrollback_reveal(&mut self, attached: SessionRef) {
+        let Some((session, reveal)) = self.ui_state.pending_scrollback_reveal.take() else {
+            return;
+        };
+        if session != attached {
+            return;
+        }
+        let backend = self.backend_for(session);
+        tokio::spawn(async move {
+            tokio::time::sleep(REVEAL_AFTER_ATTACH).await;
+            if let Err(e) = backend.reveal_scrollback_line(session.id, reveal).await {
+                debug!("Scrollback reveal in {} failed: {e}", session.id);
+            }
+        });
+    }
+}
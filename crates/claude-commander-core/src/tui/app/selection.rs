@@ -5,11 +5,19 @@ use super::*;
 impl App {
     /// The backend that owns session `id`, by scanning cached snapshots. Session
     /// ids are globally unique (UUIDs), so at most one backend matches; defaults
-    /// to the local backend when none does (e.g. a stale ref).
+    /// to the local backend when none does (e.g. a stale ref). Archived sessions
+    /// are held apart from the snapshot, so they are scanned too.
     pub(super) fn backend_of_session(&self, id: SessionId) -> BackendId {
         self.backends
             .iter()
-            .find(|h| h.view.snapshot.sessions.iter().any(|s| s.session_id == id))
+            .find(|h| {
+                h.view
+                    .snapshot
+                    .sessions
+                    .iter()
+                    .chain(&h.view.archived)
+                    .any(|s| s.session_id == id)
+            })
             .map(|h| h.id)
             .unwrap_or(LOCAL_BACKEND_ID)
     }
@@ -45,9 +53,12 @@ impl App {
             .unwrap_or(crate::backend::BackendCapabilities::LOCAL)
     }
 
-    /// Move the selection down one row (board row / list item).
+    /// Move the selection down one row (board row / grid row of tiles / list
+    /// item).
     pub(super) fn nav_down(&mut self) {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            self.ui_state.grid_state.down();
+        } else if self.ui_state.view_mode.is_board() {
             self.ui_state.board_state.next_row();
         } else {
             self.ui_state.list_state.next();
@@ -56,39 +67,53 @@ impl App {
 
     /// Move the selection up one row.
     pub(super) fn nav_up(&mut self) {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            self.ui_state.grid_state.up();
+        } else if self.ui_state.view_mode.is_board() {
             self.ui_state.board_state.previous_row();
         } else {
             self.ui_state.list_state.previous();
         }
     }
 
-    /// Move right: board switches column; a list view is one-dimensional (no-op).
+    /// Move right: board switches column, grid moves to the next tile; a list
+    /// view is one-dimensional (no-op).
     pub(super) fn nav_right(&mut self) {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            self.ui_state.grid_state.next();
+        } else if self.ui_state.view_mode.is_board() {
             self.ui_state.board_state.next_column();
         }
     }
 
-    /// Move left: board switches column; a list view is one-dimensional (no-op).
+    /// Move left: board switches column, grid moves to the previous tile; a
+    /// list view is one-dimensional (no-op).
     pub(super) fn nav_left(&mut self) {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            self.ui_state.grid_state.previous();
+        } else if self.ui_state.view_mode.is_board() {
             self.ui_state.board_state.previous_column();
         }
     }
 
-    /// Next group: board switches column; list jumps to the next group header.
+    /// Next group: board switches column, grid turns the page; list jumps to
+    /// the next group header.
     pub(super) fn nav_next_group(&mut self) {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            self.ui_state.grid_state.page(true);
+        } else if self.ui_state.view_mode.is_board() {
             self.ui_state.board_state.next_column();
         } else {
             self.ui_state.list_state.next_group();
         }
     }
 
-    /// Previous group: board switches column; list jumps to the previous header.
+    /// Previous group: board switches column, grid turns the page back; list
+    /// jumps to the previous header.
     pub(super) fn nav_previous_group(&mut self) {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            self.ui_state.grid_state.page(false);
+        } else if self.ui_state.view_mode.is_board() {
             self.ui_state.board_state.previous_column();
         } else {
             self.ui_state.list_state.previous_group();
@@ -97,9 +122,12 @@ impl App {
 
     /// Move a screenful up or down: the board steps within the current column
     /// by the number of cards that column had visible on the last frame; a list
-    /// view steps by its visible row count. Both keep one row of overlap.
+    /// view steps by its visible row count. Both keep one row of overlap. The
+    /// grid turns a whole page of tiles.
     pub(super) fn nav_page(&mut self, down: bool) {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            self.ui_state.grid_state.page(down);
+        } else if self.ui_state.view_mode.is_board() {
             let Some(col) = self.ui_state.board_state.selected_column() else {
                 return;
             };
@@ -118,18 +146,22 @@ impl App {
         }
     }
 
-    /// Jump to the first row (board column top / list top).
+    /// Jump to the first row (board column top / first tile / list top).
     pub(super) fn nav_first(&mut self) {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            self.ui_state.grid_state.select_first();
+        } else if self.ui_state.view_mode.is_board() {
             self.ui_state.board_state.select_first();
         } else {
             self.ui_state.list_state.select_first();
         }
     }
 
-    /// Jump to the last row (board column bottom / list bottom).
+    /// Jump to the last row (board column bottom / last tile / list bottom).
     pub(super) fn nav_last(&mut self) {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            self.ui_state.grid_state.select_last();
+        } else if self.ui_state.view_mode.is_board() {
             self.ui_state.board_state.select_last();
         } else {
             self.ui_state.list_state.select_last();
@@ -142,10 +174,16 @@ impl App {
     /// session and project; a landed-but-empty column (or no selection) clears
     /// both. This preserves every action gate — `RemoveProject` (project, no
     /// session) and project-shell attach both fall out of the sidebar case
-    /// unchanged.
+    /// unchanged. A grid tile yields its session and project.
     pub(super) fn update_selection(&mut self) {
         // Read the raw (session, project) ids from whichever view is active.
-        let (session, project) = if self.ui_state.view_mode.is_board() {
+        let (session, project) = if self.ui_state.view_mode.is_grid() {
+            self.ui_state
+                .grid_state
+                .selected()
+                .and_then(|idx| self.ui_state.grid_tiles.get(idx))
+                .map_or((None, None), |t| (Some(t.session_id), Some(t.project_id)))
+        } else if self.ui_state.view_mode.is_board() {
             match self.ui_state.board_state.selected() {
                 Some(pos) => self.ui_state.board.ids_at(pos),
                 None => (None, None),
@@ -199,6 +237,8 @@ impl App {
             }
         }
 
+        self.close_stale_inspect();
+
         // Fetch info-modal data if applicable (gated on the Info modal being
         // open — `spawn_info_fetch` is a no-op otherwise).
         self.spawn_info_fetch();
@@ -220,6 +260,21 @@ impl App {
             .map(|(_, backend)| *backend)
     }
 
+    /// Map a mouse `(col, row)` to the swimlane whose heading is under it,
+    /// returning the lane's index in `board.lanes`.
+    pub(super) fn board_lane_at(&self, col: u16, row: u16) -> Option<usize> {
+        self.ui_state
+            .board_lane_regions
+            .iter()
+            .find(|(rect, _)| {
+                col >= rect.x
+                    && col < rect.x + rect.width
+                    && row >= rect.y
+                    && row < rect.y + rect.height
+            })
+            .map(|(_, lane)| *lane)
+    }
+
     /// Map a mouse `(col, row)` in absolute terminal coordinates to the board
     /// position of the row under it, using the hit regions recorded on the last
     /// render frame. Returns `None` when the click is not on any row.
@@ -278,10 +333,10 @@ impl App {
 
     /// Move the list/pane divider by `delta` percentage points, clamped to
     /// [`MIN_LEFT_PANE_PCT`]..=[`MAX_LEFT_PANE_PCT`], and persist the result.
-    /// A no-op in board view (no divider) and when already at the clamp, so a
-    /// held key can't spam `tui.json` writes.
+    /// A no-op in the full-screen views (no divider) and when already at the
+    /// clamp, so a held key can't spam `tui.json` writes.
     pub(super) async fn resize_left_pane(&mut self, delta: i16) {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_full_screen() {
             return;
         }
         let next = (self.ui_state.left_pane_pct as i16 + delta)
@@ -308,10 +363,20 @@ impl App {
     /// right pane it scrolls that pane's content (three lines a notch, which also
     /// breaks follow-the-tail until the user wheels back to the bottom). On the
     /// board it moves the selection within the hovered column — one notch, one
-    /// row.
+    /// row. On the grid a notch steps one tile.
     pub(super) fn scroll_pane_at(&mut self, x: u16, direction: ScrollDirection) {
+        if self.ui_state.view_mode.is_grid() {
+            match direction {
+                ScrollDirection::Up => self.ui_state.grid_state.previous(),
+                ScrollDirection::Down => self.ui_state.grid_state.next(),
+            }
+            self.update_selection();
+            return;
+        }
         if !self.ui_state.view_mode.is_board() {
-            if self.x_in_right_pane(x) {
+            if self.x_in_right_pane(x) && self.ui_state.pane_inspect.is_some() {
+                self.wheel_inspect(matches!(direction, ScrollDirection::Down));
+            } else if self.x_in_right_pane(x) {
                 const LINES_PER_TICK: u16 = 3;
                 match direction {
                     ScrollDirection::Up => self.active_pane_state().scroll_up(LINES_PER_TICK),
@@ -355,7 +420,19 @@ impl App {
     /// Numbering is column-major — the Nth `Worktree` row (see
     /// [`Board::pos_of_session_number`]).
     pub(super) fn jump_to_session_number(&mut self, number: usize) {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            // Tiles carry the board's numbers; a number whose session has no
+            // tile (stopped, or filtered out) selects nothing.
+            if let Some((&id, _)) = self
+                .ui_state
+                .session_numbers
+                .iter()
+                .find(|(_, n)| **n == number)
+                && self.select_grid_session(id)
+            {
+                self.update_selection();
+            }
+        } else if self.ui_state.view_mode.is_board() {
             if let Some(pos) = self.ui_state.board.pos_of_session_number(number) {
                 self.ui_state.board_state.select(Some(pos));
                 self.update_selection();
@@ -376,9 +453,11 @@ impl App {
     /// In the section list views it is the section header at or above the
     /// cursor (other list views render no headers, so `None`). The implicit
     /// "In Progress" catch-all stamps no override — sessions land there by
-    /// default anyway.
+    /// default anyway. The grid has no sections, so `None`.
     pub(super) fn target_section(&self) -> Option<String> {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            None
+        } else if self.ui_state.view_mode.is_board() {
             let pos = self.ui_state.board_state.selected()?;
             if pos.col == 0 {
                 return None;
@@ -395,7 +474,13 @@ impl App {
     /// selection state. No-op (returns `false`) if the session has no row on the
     /// board — e.g. it was deleted.
     pub(super) fn select_session_in_tree(&mut self, session_id: SessionId) -> bool {
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            let found = self.select_grid_session(session_id);
+            if found {
+                self.update_selection();
+            }
+            found
+        } else if self.ui_state.view_mode.is_board() {
             match self.ui_state.board.position_of(session_id) {
                 Some(pos) => {
                     self.ui_state.board_state.select(Some(pos));
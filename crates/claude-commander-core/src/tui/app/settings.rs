@@ -107,6 +107,13 @@ impl App {
                             .unwrap_or_else(|| "(unlimited)".into()),
                         "in_progress_limit",
                     ),
+                    SettingsRow::text(
+                        "In Progress Lane WIP Limit",
+                        c.in_progress_lane_limit
+                            .map(|n| n.to_string())
+                            .unwrap_or_else(|| "(unlimited)".into()),
+                        "in_progress_lane_limit",
+                    ),
                     SettingsRow::header("Editor"),
                     SettingsRow::text(
                         "Editor",
@@ -242,6 +249,11 @@ impl App {
                     ),
                     SettingsRow::text("Assistant Name", c.name.clone(), "conversation_name"),
                     SettingsRow::text("TTS Base URL", c.base_url.clone(), "conversation_base_url"),
+                    SettingsRow::text(
+                        "TTS Command",
+                        c.tts_command.clone().unwrap_or_else(|| "(none)".into()),
+                        "conversation_tts_command",
+                    ),
                     SettingsRow::text("Model", c.model.clone(), "conversation_model"),
                     SettingsRow::text(
                         "Voice",
@@ -260,9 +272,19 @@ impl App {
                         c.speak_scope.label().to_string(),
                         "conversation_speak_scope",
                     ),
+                    SettingsRow::toggle(
+                        "Interrupt Agent on Barge-in",
+                        c.interrupt_turn,
+                        "conversation_interrupt_turn",
+                    ),
                     // Speech-to-text (voice input, Alt-V).
                     SettingsRow::toggle("Enable Voice Input (STT)", s.enabled, "stt_enabled"),
                     SettingsRow::text("STT Base URL", s.base_url.clone(), "stt_base_url"),
+                    SettingsRow::text(
+                        "STT Command",
+                        s.command.clone().unwrap_or_else(|| "(none)".into()),
+                        "stt_command",
+                    ),
                     SettingsRow::text("STT Model", s.model.clone(), "stt_model"),
                     SettingsRow::text(
                         "STT Language",
@@ -284,6 +306,19 @@ impl App {
                         s.pause_media,
                         "stt_pause_media",
                     ),
+                    SettingsRow::toggle("Voice Commands", s.voice_commands, "stt_voice_commands"),
+                    SettingsRow::toggle("Hands-free Recording (VAD)", s.vad, "stt_vad"),
+                    SettingsRow::text(
+                        "VAD Silence (ms)",
+                        s.vad_silence_ms.to_string(),
+                        "stt_vad_silence_ms",
+                    ),
+                    SettingsRow::toggle("Continuous Listening", s.continuous, "stt_continuous"),
+                    SettingsRow::toggle(
+                        "Spoken Announcements",
+                        self.config.announcements.enabled,
+                        "announcements_enabled",
+                    ),
                 ]
             }
             SettingsTab::Sections => {
@@ -1142,6 +1177,14 @@ impl App {
                         value.parse::<u32>().ok().filter(|&n| n > 0)
                     };
                 }
+                "in_progress_lane_limit" => {
+                    self.config.in_progress_lane_limit =
+                        if value.is_empty() || value == "(unlimited)" {
+                            None
+                        } else {
+                            value.parse::<u32>().ok().filter(|&n| n > 0)
+                        };
+                }
                 _ => {}
             },
             SettingsTab::Conversation => match field_key {
@@ -1154,6 +1197,14 @@ impl App {
                     };
                 }
                 "conversation_base_url" => self.config.conversation.base_url = value.to_string(),
+                "conversation_tts_command" => {
+                    let v = value.trim();
+                    self.config.conversation.tts_command = if v.is_empty() || v == "(none)" {
+                        None
+                    } else {
+                        Some(v.to_string())
+                    };
+                }
                 "conversation_model" => self.config.conversation.model = value.to_string(),
                 "conversation_voice" => {
                     self.config.conversation.voice = if value.is_empty() || value == "(default)" {
@@ -1184,6 +1235,15 @@ impl App {
                     }
                 }
                 "stt_base_url" => self.config.stt.base_url = value.to_string(),
+                "stt_command" => {
+                    let v = value.trim();
+                    self.config.stt.command = if v.is_empty() || v == "(none)" {
+                        None
+                    } else {
+                        Some(v.to_string())
+                    };
+                    self.respawn_listener();
+                }
                 "stt_model" => self.config.stt.model = value.to_string(),
                 "stt_language" => {
                     self.config.stt.language = if value.is_empty() || value == "(auto)" {
@@ -1208,6 +1268,12 @@ impl App {
                             Some(v.to_string())
                         };
                 }
+                "stt_vad_silence_ms" => {
+                    if let Ok(v) = value.trim().parse::<u64>() {
+                        self.config.stt.vad_silence_ms = v.clamp(200, 10_000);
+                        self.respawn_listener();
+                    }
+                }
                 _ => {}
             },
             SettingsTab::Theme => {
@@ -1374,6 +1440,24 @@ impl App {
             "conversation_enabled" => self.config.conversation.enabled = value,
             "stt_enabled" => self.config.stt.enabled = value,
             "stt_pause_media" => self.config.stt.pause_media = value,
+            "stt_voice_commands" => self.config.stt.voice_commands = value,
+            // The listener reads these when it's built, so rebuild it.
+            "stt_vad" => {
+                self.config.stt.vad = value;
+                self.respawn_listener();
+            }
+            "stt_continuous" => {
+                self.config.stt.continuous = value;
+                self.respawn_listener();
+            }
+            "conversation_interrupt_turn" => {
+                self.config.conversation.interrupt_turn = value;
+                self.respawn_listener();
+            }
+            "announcements_enabled" => {
+                self.config.announcements.enabled = value;
+                self.ensure_announcer_started();
+            }
             "telemetry_enabled" => self.config.telemetry.enabled = value,
             _ => {
                 warn!("Unknown boolean setting: {}", field_key);
@@ -2769,8 +2853,10 @@ fn unique_program_label(programs: &[crate::config::ProgramEntry]) -> String {
 /// Build displayable rows for a section's predicates.
 fn predicate_rows(section: &crate::session::SectionConfig) -> Vec<(String, String)> {
     use crate::session::section::{
-        DecisionPredicate, LabelPredicate, ReviewerPredicate, StatePredicate,
+        DecisionPredicate, GlobPredicate, LabelPredicate, OneOrMany, ReviewerPredicate,
+        StatePredicate, TaskPredicate,
     };
+    let matcher = &section.matcher;
 
     let fmt_state = |p: &StatePredicate| match p {
         crate::session::section::OneOrMany::One(v) => format!("{v:?}").to_lowercase(),
@@ -2790,6 +2876,15 @@ fn predicate_rows(section: &crate::session::SectionConfig) -> Vec<(String, Strin
             .join(", "),
     };
 
+    let fmt_task = |p: &TaskPredicate| match p {
+        crate::session::section::OneOrMany::One(v) => v.to_string(),
+        crate::session::section::OneOrMany::Any(vs) => vs
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+
     let fmt_label = |p: &LabelPredicate| match p {
         LabelPredicate::One(s) => s.clone(),
         LabelPredicate::Any(vs) => vs.join(", "),
@@ -2801,73 +2896,213 @@ fn predicate_rows(section: &crate::session::SectionConfig) -> Vec<(String, Strin
         ReviewerPredicate::Any(vs) => vs.join(", "),
     };
 
+    fn fmt_many<T>(p: &OneOrMany<T>, name: impl Fn(&T) -> String) -> String {
+        match p {
+            OneOrMany::One(v) => name(v),
+            OneOrMany::Any(vs) => vs.iter().map(name).collect::<Vec<_>>().join(", "),
+        }
+    }
+
+    let fmt_glob = |p: &GlobPredicate| match p {
+        GlobPredicate::One(s) => s.clone(),
+        GlobPredicate::Any(vs) => vs.join(", "),
+    };
+
     let not_set = "(not set)".to_string();
     vec![
         (
             "pr_state".into(),
-            section
+            matcher
                 .pr_state
                 .as_ref()
                 .map_or_else(|| not_set.clone(), fmt_state),
         ),
         (
             "is_draft".into(),
-            section
+            matcher
                 .is_draft
                 .map_or_else(|| not_set.clone(), |b| b.to_string()),
         ),
         (
             "has_label".into(),
-            section
+            matcher
                 .has_label
                 .as_ref()
                 .map_or_else(|| not_set.clone(), fmt_label),
         ),
         (
             "has_pr".into(),
-            section
+            matcher
                 .has_pr
                 .map_or_else(|| not_set.clone(), |b| b.to_string()),
         ),
         (
             "review_decision".into(),
-            section
+            matcher
                 .review_decision
                 .as_ref()
                 .map_or_else(|| not_set.clone(), fmt_decision),
         ),
         (
             "has_reviewer".into(),
-            section
+            matcher
                 .has_reviewer
                 .as_ref()
                 .map_or_else(|| not_set.clone(), fmt_reviewer),
         ),
+        (
+            "task_status".into(),
+            matcher
+                .task_status
+                .as_ref()
+                .map_or_else(|| not_set.clone(), fmt_task),
+        ),
+        (
+            "agent_state".into(),
+            matcher
+                .agent_state
+                .as_ref()
+                .map_or_else(|| not_set.clone(), |p| fmt_many(p, |v| v.to_string())),
+        ),
+        (
+            "unread".into(),
+            matcher
+                .unread
+                .map_or_else(|| not_set.clone(), |b| b.to_string()),
+        ),
+        (
+            "inactive_for".into(),
+            matcher
+                .inactive_for
+                .map_or_else(|| not_set.clone(), |a| a.to_string()),
+        ),
+        (
+            "age".into(),
+            matcher
+                .age
+                .map_or_else(|| not_set.clone(), |a| a.to_string()),
+        ),
+        (
+            "program".into(),
+            matcher
+                .program
+                .as_ref()
+                .map_or_else(|| not_set.clone(), fmt_glob),
+        ),
+        (
+            "agent".into(),
+            matcher.agent.as_ref().map_or_else(
+                || not_set.clone(),
+                |p| fmt_many(p, |v| format!("{v:?}").to_lowercase()),
+            ),
+        ),
+        (
+            "branch".into(),
+            matcher
+                .branch
+                .as_ref()
+                .map_or_else(|| not_set.clone(), fmt_glob),
+        ),
+        (
+            "title".into(),
+            matcher
+                .title
+                .as_ref()
+                .map_or_else(|| not_set.clone(), fmt_glob),
+        ),
+        (
+            "diff_lines".into(),
+            matcher
+                .diff_lines
+                .map_or_else(|| not_set.clone(), |c| c.to_string()),
+        ),
+        (
+            "diff_files".into(),
+            matcher
+                .diff_files
+                .map_or_else(|| not_set.clone(), |c| c.to_string()),
+        ),
+        (
+            "checks".into(),
+            matcher.checks.as_ref().map_or_else(
+                || not_set.clone(),
+                |p| fmt_many(p, |v| v.to_string().to_lowercase()),
+            ),
+        ),
+        (
+            "has_tag".into(),
+            matcher
+                .has_tag
+                .as_ref()
+                .map_or_else(|| not_set.clone(), fmt_label),
+        ),
         (
             "max_sessions".into(),
             section
                 .max_sessions
                 .map_or_else(|| not_set.clone(), |n| n.to_string()),
         ),
+        (
+            "lane_max_sessions".into(),
+            section
+                .lane_max_sessions
+                .map_or_else(|| not_set.clone(), |n| n.to_string()),
+        ),
     ]
 }
 
 /// Apply a user-edited predicate value string to a SectionConfig field.
 fn apply_predicate_edit(section: &mut crate::session::SectionConfig, pred_idx: usize, value: &str) {
     use crate::git::{PrState, ReviewDecision};
-    use crate::session::section::{LabelPredicate, OneOrMany, ReviewerPredicate};
+    use crate::session::TaskStatus;
+    use crate::session::section::{GlobPredicate, LabelPredicate, OneOrMany, ReviewerPredicate};
 
+    let matcher = &mut section.matcher;
     let trimmed = value.trim();
 
+    fn many<T>(mut parsed: Vec<T>) -> Option<OneOrMany<T>> {
+        match parsed.len() {
+            0 => None,
+            1 => parsed.pop().map(OneOrMany::One),
+            _ => Some(OneOrMany::Any(parsed)),
+        }
+    }
+
+    let glob = |value: &str| {
+        let mut patterns: Vec<String> = value
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        match patterns.len() {
+            0 => None,
+            1 => patterns.pop().map(GlobPredicate::One),
+            _ => Some(GlobPredicate::Any(patterns)),
+        }
+    };
+
+    let label_list = |value: &str| {
+        let mut labels: Vec<String> = value
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        match labels.len() {
+            0 => None,
+            1 => labels.pop().map(LabelPredicate::One),
+            _ => Some(LabelPredicate::Any(labels)),
+        }
+    };
+
     match pred_idx {
         // pr_state
         0 => {
             if trimmed.is_empty() {
-                section.pr_state = None;
+                matcher.pr_state = None;
             } else {
                 let parts: Vec<&str> = trimmed.split(',').map(str::trim).collect();
                 let parsed: Vec<PrState> = parts.iter().filter_map(|s| parse_pr_state(s)).collect();
-                section.pr_state = match parsed.len() {
+                matcher.pr_state = match parsed.len() {
                     0 => None,
                     1 => Some(OneOrMany::One(parsed[0])),
                     _ => Some(OneOrMany::Any(parsed)),
@@ -2876,32 +3111,17 @@ fn apply_predicate_edit(section: &mut crate::session::SectionConfig, pred_idx: u
         }
         // is_draft
         1 => {
-            section.is_draft = match trimmed {
+            matcher.is_draft = match trimmed {
                 "true" => Some(true),
                 "false" => Some(false),
                 _ => None,
             };
         }
         // has_label
-        2 => {
-            if trimmed.is_empty() {
-                section.has_label = None;
-            } else {
-                let labels: Vec<String> = trimmed
-                    .split(',')
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect();
-                section.has_label = match labels.len() {
-                    0 => None,
-                    1 => Some(LabelPredicate::One(labels.into_iter().next().unwrap())),
-                    _ => Some(LabelPredicate::Any(labels)),
-                };
-            }
-        }
+        2 => matcher.has_label = label_list(trimmed),
         // has_pr
         3 => {
-            section.has_pr = match trimmed {
+            matcher.has_pr = match trimmed {
                 "true" => Some(true),
                 "false" => Some(false),
                 _ => None,
@@ -2910,14 +3130,14 @@ fn apply_predicate_edit(section: &mut crate::session::SectionConfig, pred_idx: u
         // review_decision
         4 => {
             if trimmed.is_empty() {
-                section.review_decision = None;
+                matcher.review_decision = None;
             } else {
                 let parts: Vec<&str> = trimmed.split(',').map(str::trim).collect();
                 let parsed: Vec<ReviewDecision> = parts
                     .iter()
                     .filter_map(|s| parse_review_decision(s))
                     .collect();
-                section.review_decision = match parsed.len() {
+                matcher.review_decision = match parsed.len() {
                     0 => None,
                     1 => Some(OneOrMany::One(parsed[0])),
                     _ => Some(OneOrMany::Any(parsed)),
@@ -2927,18 +3147,18 @@ fn apply_predicate_edit(section: &mut crate::session::SectionConfig, pred_idx: u
         // has_reviewer
         5 => {
             if trimmed.is_empty() {
-                section.has_reviewer = None;
+                matcher.has_reviewer = None;
             } else {
                 match trimmed {
-                    "true" => section.has_reviewer = Some(ReviewerPredicate::Bool(true)),
-                    "false" => section.has_reviewer = Some(ReviewerPredicate::Bool(false)),
+                    "true" => matcher.has_reviewer = Some(ReviewerPredicate::Bool(true)),
+                    "false" => matcher.has_reviewer = Some(ReviewerPredicate::Bool(false)),
                     _ => {
                         let logins: Vec<String> = trimmed
                             .split(',')
                             .map(|s| s.trim().to_string())
                             .filter(|s| !s.is_empty())
                             .collect();
-                        section.has_reviewer = match logins.len() {
+                        matcher.has_reviewer = match logins.len() {
                             0 => None,
                             1 => Some(ReviewerPredicate::One(logins.into_iter().next().unwrap())),
                             _ => Some(ReviewerPredicate::Any(logins)),
@@ -2947,14 +3167,85 @@ fn apply_predicate_edit(section: &mut crate::session::SectionConfig, pred_idx: u
                 }
             }
         }
-        // max_sessions
+        // task_status
         6 => {
+            let parsed: Vec<TaskStatus> = trimmed
+                .split(',')
+                .filter_map(|s| parse_task_status(s.trim()))
+                .collect();
+            matcher.task_status = match parsed.len() {
+                0 => None,
+                1 => Some(OneOrMany::One(parsed[0])),
+                _ => Some(OneOrMany::Any(parsed)),
+            };
+        }
+        // agent_state
+        7 => {
+            matcher.agent_state = many(
+                trimmed
+                    .split(',')
+                    .filter_map(|s| parse_agent_state(s.trim()))
+                    .collect(),
+            );
+        }
+        // unread
+        8 => {
+            matcher.unread = match trimmed {
+                "true" => Some(true),
+                "false" => Some(false),
+                _ => None,
+            };
+        }
+        // inactive_for
+        9 => matcher.inactive_for = trimmed.parse().ok(),
+        // age
+        10 => matcher.age = trimmed.parse().ok(),
+        // program
+        11 => matcher.program = glob(trimmed),
+        // agent
+        12 => {
+            matcher.agent = many(
+                trimmed
+                    .split(',')
+                    .filter_map(|s| parse_agent_kind(s.trim()))
+                    .collect(),
+            );
+        }
+        // branch
+        13 => matcher.branch = glob(trimmed),
+        // title
+        14 => matcher.title = glob(trimmed),
+        // diff_lines
+        15 => matcher.diff_lines = trimmed.parse().ok(),
+        // diff_files
+        16 => matcher.diff_files = trimmed.parse().ok(),
+        // checks
+        17 => {
+            matcher.checks = many(
+                trimmed
+                    .split(',')
+                    .filter_map(|s| parse_checks(s.trim()))
+                    .collect(),
+            );
+        }
+        // has_tag
+        18 => matcher.has_tag = label_list(trimmed),
+        // max_sessions
+        19 => {
             section.max_sessions = if trimmed.is_empty() {
                 None
             } else {
                 trimmed.parse::<u32>().ok().filter(|&n| n > 0)
             };
         }
+        // lane_max_sessions
+        20 => {
+            section.lane_max_sessions = if trimmed.is_empty() {
+                None
+            } else {
+                trimmed.parse::<u32>().ok().filter(|&n| n > 0)
+            };
+        }
         _ => {}
     }
 }
@@ -2968,6 +3259,49 @@ fn parse_pr_state(s: &str) -> Option<crate::git::PrState> {
     }
 }
 
+fn parse_task_status(s: &str) -> Option<crate::session::TaskStatus> {
+    match s.to_lowercase().as_str() {
+        "running" => Some(crate::session::TaskStatus::Running),
+        "passed" => Some(crate::session::TaskStatus::Passed),
+        "failed" => Some(crate::session::TaskStatus::Failed),
+        _ => None,
+    }
+}
+
+fn parse_agent_state(s: &str) -> Option<crate::session::AgentState> {
+    use crate::session::AgentState;
+    match s.to_lowercase().replace(['-', '_'], "").as_str() {
+        "working" => Some(AgentState::Working),
+        "idle" => Some(AgentState::Idle),
+        "waiting" | "waitingforinput" => Some(AgentState::WaitingForInput),
+        "unknown" => Some(AgentState::Unknown),
+        _ => None,
+    }
+}
+
+fn parse_agent_kind(s: &str) -> Option<crate::agent::AgentKind> {
+    use crate::agent::AgentKind;
+    match s.to_lowercase().as_str() {
+        "claude" => Some(AgentKind::Claude),
+        "codex" => Some(AgentKind::Codex),
+        "opencode" => Some(AgentKind::OpenCode),
+        "omp" => Some(AgentKind::Omp),
+        "unknown" => Some(AgentKind::Unknown),
+        _ => None,
+    }
+}
+
+fn parse_checks(s: &str) -> Option<crate::git::ChecksStatus> {
+    use crate::git::ChecksStatus;
+    match s.to_lowercase().as_str() {
+        "passing" => Some(ChecksStatus::Passing),
+        "failing" => Some(ChecksStatus::Failing),
+        "pending" => Some(ChecksStatus::Pending),
+        "none" => Some(ChecksStatus::None),
+        _ => None,
+    }
+}
+
 fn parse_review_decision(s: &str) -> Option<crate::git::ReviewDecision> {
     match s.to_lowercase().replace(['-', '_'], "").as_str() {
         "approved" => Some(crate::git::ReviewDecision::Approved),
@@ -3337,4 +3671,40 @@ mod tests {
         assert_eq!(SettingsTab::Conversation.label(), "Conversation");
         assert_eq!(SettingsTab::Programs.label(), "Programs");
     }
+
+    #[test]
+    fn predicate_edits_land_on_the_row_they_were_typed_into() {
+        let inputs = [
+            ("agent_state", "waiting, idle"),
+            ("unread", "true"),
+            ("inactive_for", ">3d"),
+            ("age", "<2h"),
+            ("program", "codex*"),
+            ("agent", "codex, opencode"),
+            ("branch", "exp/*"),
+            ("title", "*spike*"),
+            ("diff_lines", ">500"),
+            ("diff_files", "<3"),
+            ("checks", "failing"),
+            ("has_tag", "blocked, wip"),
+            ("max_sessions", "4"),
+            ("lane_max_sessions", "2"),
+        ];
+        let mut section = crate::session::SectionConfig {
+            name: "S".to_string(),
+            ..Default::default()
+        };
+        for (label, value) in inputs {
+            let idx = predicate_rows(&section)
+                .iter()
+                .position(|(l, _)| l == label)
+                .expect("row exists");
+            apply_predicate_edit(&mut section, idx, value);
+        }
+        let rows = predicate_rows(&section);
+        for (label, value) in inputs {
+            let (_, shown) = rows.iter().find(|(l, _)| l == label).unwrap();
+            assert_eq!(shown, value, "{label} should round-trip");
+        }
+    }
 }
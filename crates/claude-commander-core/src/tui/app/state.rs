@@ -34,7 +34,7 @@ impl App {
                 };
 
                 if let Some(handle) = self.backends.iter_mut().find(|h| h.id.0 == backend_id) {
-                    handle.view.snapshot = *snapshot;
+                    handle.view.set_snapshot(*snapshot);
                     handle.view.agent_states = states.clone();
                     // The local backend's connection derives from the snapshot's
                     // tmux health; a remote backend's is owned by its
@@ -67,6 +67,7 @@ impl App {
                 // this a remote's pending markers would never render and a
                 // cross-frontend marker change would never propagate.
                 self.refresh_comment_indicators();
+                self.refresh_viewer_counts();
                 self.refresh_list_items().await;
             }
             StateUpdate::BackendConnection { backend_id, state } => {
@@ -92,6 +93,10 @@ impl App {
                 debug!("Session removed: {}", session_id);
                 self.refresh_list_items().await;
             }
+            StateUpdate::GridCapturesReady {
+                spawned_at,
+                captures,
+            } => self.apply_grid_captures(spawned_at, captures),
             StateUpdate::PreviewReady {
                 spawned_at,
                 session_id,
@@ -143,6 +148,22 @@ impl App {
                     debug!("Discarding stale EnrichedPrReady for {}", session_id);
                 }
             }
+            StateUpdate::ResourceUsageReady {
+                spawned_at,
+                session_id,
+                usage,
+            } => {
+                // The spawn stamp stays as the re-sample throttle; only a
+                // result from the latest spawn for the still-selected session
+                // is applied.
+                if self.ui_state.resource_fetch_spawned_at == Some(spawned_at)
+                    && self.ui_state.selected_session_id.map(|r| r.id) == Some(session_id)
+                {
+                    self.ui_state.resource_usage = usage.map(|u| (session_id, u));
+                } else {
+                    debug!("Discarding stale ResourceUsageReady for {}", session_id);
+                }
+            }
             StateUpdate::AiSummaryReady {
                 session_id,
                 result,
@@ -329,6 +350,21 @@ impl App {
                 // Rebuild the rows from the new listing if the picker is still up.
                 self.refilter_quick_switch();
             }
+            StateUpdate::ScrollbackHitsLoaded {
+                generation,
+                hits,
+                errors,
+            } => {
+                let hits = hits
+                    .into_iter()
+                    .map(|(backend_id, hit)| (BackendId(backend_id), hit))
+                    .collect();
+                self.apply_scrollback_hits(generation, hits, errors);
+            }
+            StateUpdate::InspectHistoryLoaded {
+                session_id,
+                content,
+            } => self.apply_inspect_history(session_id, content),
             StateUpdate::CloneJobUpdated {
                 backend_id,
                 source,
@@ -652,16 +688,46 @@ impl App {
             StateUpdate::CascadeAbandonFinished { backend_id, result } => {
                 self.handle_cascade_abandon_finished(BackendId(backend_id), result);
             }
+            StateUpdate::UndoableActionFinished {
+                backend_id,
+                message,
+            } => {
+                self.ui_state.undo_backend = Some(BackendId(backend_id));
+                self.ui_state.status_message = Some((
+                    format!("{message} — {}", self.undo_hint()),
+                    Instant::now() + Duration::from_secs(8),
+                ));
+            }
+            StateUpdate::UndoFinished { backend_id, result } => {
+                self.handle_undo_finished(BackendId(backend_id), result);
+            }
+            StateUpdate::VoiceCommand { transcript, action } => {
+                self.handle_voice_command(transcript, action).await;
+            }
             StateUpdate::LfsPullFinished { session_id }
                 if self.ui_state.lfs_pull_in_flight.remove(&session_id) =>
             {
                 debug!("lfs pull finished for {}", session_id);
                 self.refresh_list_items().await;
             }
+            StateUpdate::CustomCommandFinished { name, result } => {
+                self.show_custom_command_output(name, result);
+            }
             _ => {}
         }
     }
 
+    /// Re-derive the session-list viewer (`◉N`) markers from every backend's
+    /// cached agent-state snapshot, like the pending-comment markers.
+    fn refresh_viewer_counts(&mut self) {
+        self.ui_state.session_viewers = self
+            .backends
+            .iter()
+            .flat_map(|h| h.view.agent_states.viewers.iter())
+            .map(|(id, n)| (*id, *n))
+            .collect();
+    }
+
     /// Fold the workspace snapshot's per-project pull status into the render-side
     /// `project_pull_blocked` badge map. The background pull loop maintains the
     /// status server-side; only [`PullStatus::Blocked`] surfaces a badge (an
@@ -777,8 +843,12 @@ impl App {
             .iter()
             .any(|h| h.view.snapshot.cascade_paused.is_some());
 
-        if self.ui_state.view_mode.is_board() {
+        // The grid tiles the board's model, so it rebuilds the board first.
+        if self.ui_state.view_mode.is_full_screen() {
             self.rebuild_board_view();
+            if self.ui_state.view_mode.is_grid() {
+                self.rebuild_grid_view();
+            }
         } else {
             self.rebuild_list_view();
         }
@@ -807,16 +877,20 @@ impl App {
 
         self.update_selection();
         self.recompute_stack_chain();
+        self.refresh_voice_catalog();
+        self.refresh_announcer_numbers();
     }
 
     /// Rebuild the kanban board model from every backend's cached snapshot and
     /// re-anchor the board cursor to the tracked selection.
     fn rebuild_board_view(&mut self) {
         let sections = self.config.effective_sections();
+        let snapshots = self.filtered_snapshots();
         let inputs: Vec<crate::session::BoardBackendInput> = self
             .backends
             .iter()
-            .map(|h| {
+            .zip(&snapshots)
+            .map(|(h, snapshot)| {
                 let version_warning = if h.id == crate::backend::LOCAL_BACKEND_ID {
                     None
                 } else {
@@ -830,7 +904,7 @@ impl App {
                     name: h.backend.descriptor().name,
                     connection: h.view.connection.clone(),
                     version_warning,
-                    snapshot: &h.view.snapshot,
+                    snapshot,
                     agent_states: &h.view.agent_states.states,
                 }
             })
@@ -841,26 +915,34 @@ impl App {
             self.config.in_progress_limit,
             self.ui_state.board_filter,
             self.config.hide_empty_sections,
+            self.ui_state
+                .board_lanes
+                .map(|grouping| crate::session::LaneOptions {
+                    grouping,
+                    collapsed: &self.ui_state.collapsed_lanes,
+                    in_progress_limit: self.config.in_progress_lane_limit,
+                }),
         );
 
         // Mark rows whose LFS content is still being pulled (UI-only state).
         if !self.ui_state.lfs_pull_in_flight.is_empty() {
-            for column in &mut board.columns {
-                for card in &mut column.cards {
-                    let SessionListItem::Worktree {
-                        id, lfs_pulling, ..
-                    } = &mut card.row
-                    else {
-                        unreachable!("board rows are always Worktree")
-                    };
-                    *lfs_pulling = self.ui_state.lfs_pull_in_flight.contains(id);
-                }
+            for card in board.cards_mut() {
+                let SessionListItem::Worktree {
+                    id, lfs_pulling, ..
+                } = &mut card.row
+                else {
+                    unreachable!("board rows are always Worktree")
+                };
+                *lfs_pulling = self.ui_state.lfs_pull_in_flight.contains(id);
             }
         }
 
         let counts = board.selectable_row_counts();
         self.ui_state.board = board;
         self.ui_state.board_state.sync(counts);
+        self.ui_state
+            .board_state
+            .sync_lanes(self.ui_state.board.lane_rows());
 
         // Per-frame render inputs cached here (recomputed on rebuild only).
         self.ui_state.session_numbers = self.ui_state.board.session_numbers();
@@ -896,6 +978,7 @@ impl App {
     /// backend) followed by that backend's items.
     fn rebuild_list_view(&mut self) {
         let single_backend = self.backends.len() == 1;
+        let snapshots = self.filtered_snapshots();
         let mut items: Vec<SessionListItem> = Vec::new();
 
         // Recent-sessions block, prepended above the per-backend tree and
@@ -907,9 +990,9 @@ impl App {
         let recent_limit = self.config.recent_sessions_limit as usize;
         if recent_limit > 0 {
             let mut candidates: Vec<(chrono::DateTime<chrono::Utc>, SessionListItem)> = Vec::new();
-            for handle in &self.backends {
+            for (handle, snapshot) in self.backends.iter().zip(&snapshots) {
                 let agent_states = &handle.view.agent_states.states;
-                for s in &handle.view.snapshot.sessions {
+                for s in &snapshot.sessions {
                     if let Some(at) = s.last_attached_at {
                         candidates.push((
                             at,
@@ -950,8 +1033,8 @@ impl App {
         // divider); the per-backend tree appended below is the scrolling list.
         let recents_len = items.len();
 
-        for handle in &self.backends {
-            let snapshot = &handle.view.snapshot;
+        for (handle, snapshot) in self.backends.iter().zip(&snapshots) {
+            let snapshot: &crate::api::WorkspaceSnapshot = snapshot;
             let agent_states = &handle.view.agent_states.states;
             if !single_backend {
                 let version_warning = if handle.id == crate::backend::LOCAL_BACKEND_ID {
@@ -1092,7 +1175,11 @@ impl App {
         let (last_session, last_project) =
             (prefs.last_selected_session, prefs.last_selected_project);
 
-        if self.ui_state.view_mode.is_board() {
+        if self.ui_state.view_mode.is_grid() {
+            if let Some(sid) = last_session {
+                self.select_grid_session(sid);
+            }
+        } else if self.ui_state.view_mode.is_board() {
             if let Some(sid) = last_session
                 && let Some(pos) = self.ui_state.board.position_of(sid)
             {
@@ -180,6 +180,8 @@ fn make_worktree_with_id(id: SessionId) -> SessionListItem {
         unread: false,
         keep_alive: false,
         lfs_pulling: false,
+        task_status: None,
+        tags: Vec::new(),
         stacked_child: false,
     }
 }
@@ -549,6 +551,12 @@ fn test_info_view_renders_stack_chain() {
         ai_summary: None,
         summary_key_hint: None,
         stack_chain: &chain,
+        resources: None,
+        hook_runs: &[],
+        ports: None,
+        task_runs: &[],
+        tags: &[],
+        notes: "",
     };
     let view = InfoView::new(InfoContent::Session(data), &theme);
     let lines = view.build_lines();
@@ -602,6 +610,12 @@ fn test_info_view_stack_section_renders_above_pr_section() {
         ai_summary: None,
         summary_key_hint: None,
         stack_chain: &chain,
+        resources: None,
+        hook_runs: &[],
+        ports: None,
+        task_runs: &[],
+        tags: &[],
+        notes: "",
     };
     let view = InfoView::new(InfoContent::Session(data), &theme);
     let lines = view.build_lines();
@@ -642,6 +656,12 @@ fn test_info_view_no_stack_section_for_unstacked() {
         ai_summary: None,
         summary_key_hint: None,
         stack_chain: &[],
+        resources: None,
+        hook_runs: &[],
+        ports: None,
+        task_runs: &[],
+        tags: &[],
+        notes: "",
     };
     let view = InfoView::new(InfoContent::Session(data), &theme);
     let lines = view.build_lines();
@@ -984,8 +1004,45 @@ fn test_stt_rows_present_with_defaults() {
         kind_of("stt_prompt"),
         SettingsRowKind::Text("(none)".to_string())
     );
-    // Media pausing is on by default.
+    // Speech goes through the HTTP APIs until a local command is set.
+    assert_eq!(
+        kind_of("stt_command"),
+        SettingsRowKind::Text("(none)".to_string())
+    );
+    assert_eq!(
+        kind_of("conversation_tts_command"),
+        SettingsRowKind::Text("(none)".to_string())
+    );
+    // Media pausing and voice commands are on by default.
     assert_eq!(kind_of("stt_pause_media"), SettingsRowKind::Toggle(true));
+    assert_eq!(kind_of("stt_voice_commands"), SettingsRowKind::Toggle(true));
+    // Barge-in only silences the reply unless asked to stop the turn too.
+    assert_eq!(
+        kind_of("conversation_interrupt_turn"),
+        SettingsRowKind::Toggle(false)
+    );
+    // Hands-free recording is opt-in.
+    assert_eq!(kind_of("stt_vad"), SettingsRowKind::Toggle(false));
+    assert_eq!(kind_of("stt_continuous"), SettingsRowKind::Toggle(false));
+    assert_eq!(
+        kind_of("stt_vad_silence_ms"),
+        SettingsRowKind::Text("1200".to_string())
+    );
+    assert_eq!(
+        kind_of("announcements_enabled"),
+        SettingsRowKind::Toggle(false)
+    );
+}
+
+#[tokio::test]
+async fn test_toggle_announcements_configures_the_announcer() {
+    let mut app = make_test_app();
+    assert!(!app.conversation.announcer.is_enabled());
+    app.apply_bool_setting("announcements_enabled", true);
+    assert!(app.config.announcements.enabled);
+    assert!(app.conversation.announcer.is_enabled());
+    app.apply_bool_setting("announcements_enabled", false);
+    assert!(!app.conversation.announcer.is_enabled());
 }
 
 #[test]
@@ -1009,12 +1066,26 @@ fn test_apply_stt_text_fields() {
     assert_eq!(app.config.stt.base_url, "http://192.168.1.10:8080/v1");
     assert_eq!(app.config.stt.model, "large-v3-turbo");
     assert_eq!(app.config.stt.language.as_deref(), Some("en"));
+    app.apply_settings_edit(SettingsTab::Conversation, "stt_vad_silence_ms", "50");
+    assert_eq!(app.config.stt.vad_silence_ms, 200, "clamped to the minimum");
 
     // Sentinel / empty clears the optional fields back to None.
     app.apply_settings_edit(SettingsTab::Conversation, "stt_language", "(auto)");
     assert_eq!(app.config.stt.language, None);
     app.apply_settings_edit(SettingsTab::Conversation, "stt_prompt", "");
     assert_eq!(app.config.stt.prompt, None);
+
+    app.apply_settings_edit(
+        SettingsTab::Conversation,
+        "stt_command",
+        " whisper-cli -m base.bin -f {input} -nt ",
+    );
+    assert_eq!(
+        app.config.stt.command.as_deref(),
+        Some("whisper-cli -m base.bin -f {input} -nt")
+    );
+    app.apply_settings_edit(SettingsTab::Conversation, "stt_command", "(none)");
+    assert_eq!(app.config.stt.command, None);
 }
 
 #[test]
@@ -2871,7 +2942,7 @@ fn section_picker_default_prefers_a_configured_section_over_the_catch_all() {
 
 // --- InputFocus (Tab cycling in the input modal) ---
 
-/// All four optional fields present (the new-session dialog with >1 backend and
+/// All optional fields present (the new-session dialog with >1 backend and
 /// configured sections).
 fn all_fields() -> crate::tui::app::FieldsPresent {
     crate::tui::app::FieldsPresent {
@@ -2879,6 +2950,7 @@ fn all_fields() -> crate::tui::app::FieldsPresent {
         project: true,
         program: true,
         section: true,
+        prompt: true,
     }
 }
 
@@ -2890,18 +2962,21 @@ fn project_program_only() -> crate::tui::app::FieldsPresent {
         project: true,
         program: true,
         section: false,
+        prompt: false,
     }
 }
 
 #[test]
 fn input_focus_cycles_all_present_fields() {
-    // Name → Server → Project → Program → Section → Name with every field present.
+    // Name → Server → Project → Program → Section → Prompt → Name with every
+    // field present.
     let f = all_fields();
     assert_eq!(InputFocus::Name.next(f), InputFocus::Server);
     assert_eq!(InputFocus::Server.next(f), InputFocus::Project);
     assert_eq!(InputFocus::Project.next(f), InputFocus::Program);
     assert_eq!(InputFocus::Program.next(f), InputFocus::Section);
-    assert_eq!(InputFocus::Section.next(f), InputFocus::Name);
+    assert_eq!(InputFocus::Section.next(f), InputFocus::Prompt);
+    assert_eq!(InputFocus::Prompt.next(f), InputFocus::Name);
 }
 
 #[test]
@@ -2917,6 +2992,7 @@ fn input_focus_skips_absent_fields() {
         project: false,
         program: true,
         section: false,
+        prompt: false,
     };
     assert_eq!(InputFocus::Name.next(no_project), InputFocus::Program);
     assert_eq!(InputFocus::Program.next(no_project), InputFocus::Name);
@@ -2926,6 +3002,7 @@ fn input_focus_skips_absent_fields() {
         project: false,
         program: false,
         section: false,
+        prompt: false,
     };
     assert_eq!(InputFocus::Name.next(none), InputFocus::Name);
 }
@@ -2934,7 +3011,8 @@ fn input_focus_skips_absent_fields() {
 fn input_focus_prev_cycles_backward() {
     // Shift+Tab reverses the full ring.
     let f = all_fields();
-    assert_eq!(InputFocus::Name.prev(f), InputFocus::Section);
+    assert_eq!(InputFocus::Name.prev(f), InputFocus::Prompt);
+    assert_eq!(InputFocus::Prompt.prev(f), InputFocus::Section);
     assert_eq!(InputFocus::Section.prev(f), InputFocus::Program);
     assert_eq!(InputFocus::Program.prev(f), InputFocus::Project);
     assert_eq!(InputFocus::Project.prev(f), InputFocus::Server);
@@ -3304,6 +3382,7 @@ fn agent_states_box() -> Box<crate::api::AgentStatesSnapshot> {
     Box::new(crate::api::AgentStatesSnapshot {
         states: Default::default(),
         commander_running: false,
+        viewers: Default::default(),
     })
 }
 
@@ -3594,6 +3673,7 @@ async fn pull_blocked_badges_union_remote_backend_snapshot() {
         states: Box::new(crate::api::AgentStatesSnapshot {
             states: Default::default(),
             commander_running: false,
+            viewers: Default::default(),
         }),
     })
     .await;
@@ -3621,6 +3701,7 @@ async fn local_connection_degrades_from_tmux_ok_false_and_stays_degraded() {
         Box::new(crate::api::AgentStatesSnapshot {
             states: Default::default(),
             commander_running: false,
+            viewers: Default::default(),
         })
     };
 
@@ -3683,6 +3764,7 @@ async fn remote_connection_stays_watch_owned_across_snapshot_fold() {
         states: Box::new(crate::api::AgentStatesSnapshot {
             states: Default::default(),
             commander_running: false,
+            viewers: Default::default(),
         }),
     })
     .await;
@@ -3839,6 +3921,7 @@ async fn add_remote_server_flow_chains_name_url_token() {
         "buildbox".into(),
         None,
         None,
+        None,
     )
     .await;
     assert!(matches!(
@@ -3858,6 +3941,7 @@ async fn add_remote_server_flow_chains_name_url_token() {
         "not a url".into(),
         None,
         None,
+        None,
     )
     .await;
     match &app.ui_state.modal {
@@ -3881,6 +3965,7 @@ async fn add_remote_server_flow_chains_name_url_token() {
         "http://buildbox:7878".into(),
         None,
         None,
+        None,
     )
     .await;
     assert!(matches!(
@@ -3901,6 +3986,7 @@ async fn add_remote_server_flow_chains_name_url_token() {
         "sekrit-token".into(),
         None,
         None,
+        None,
     )
     .await;
     assert!(matches!(&app.ui_state.modal, Modal::Loading { .. }));
@@ -3915,6 +4001,7 @@ async fn add_remote_server_duplicate_name_reprompts() {
         "buildbox".into(),
         None,
         None,
+        None,
     )
     .await;
     assert!(matches!(
@@ -4073,6 +4160,7 @@ fn masked_input_modal_renders_bullets_not_the_token() {
         program_picker: None,
         server_picker: None,
         section_picker: None,
+        prompt_editor: None,
         focus: InputFocus::Name,
         expanded: false,
         mask: true,
@@ -4768,6 +4856,7 @@ fn open_create_session_modal(project_id: ProjectId, section: Option<String>) ->
         }),
         server_picker: None,
         section_picker: Some(SectionPicker::new(Vec::new(), None)),
+        prompt_editor: None,
         focus: InputFocus::Name,
         expanded: false,
         mask: false,
@@ -5398,6 +5487,7 @@ async fn fold_backend_states(
         states: Box::new(crate::api::AgentStatesSnapshot {
             states: new_states,
             commander_running: false,
+            viewers: Default::default(),
         }),
     })
     .await;
@@ -5539,6 +5629,168 @@ async fn reset_confirm_takes_the_no_resume_path_and_toasts_reset() {
     assert!(msg.contains("reset"), "toast: {msg}");
 }
 
+#[tokio::test]
+async fn archive_confirm_routes_to_owning_backend_and_toasts_archived() {
+    let (mut app, remote_sid) = app_with_remote_session().await;
+
+    app.handle_confirm(super::ConfirmAction::ArchiveSession {
+        session_id: remote_sid,
+    })
+    .await;
+
+    loop {
+        match app.event_loop.next().await.expect("an archive event") {
+            AppEvent::StateUpdate(su @ StateUpdate::RestartFinished { .. }) => {
+                app.handle_state_update(su).await;
+                break;
+            }
+            _ => continue,
+        }
+    }
+
+    assert_eq!(
+        remote_mock(&app, BackendId(1)).archived_sessions(),
+        vec![remote_sid]
+    );
+    let (msg, _) = app
+        .ui_state
+        .status_message
+        .clone()
+        .expect("a status toast after archive");
+    assert!(msg.contains("archived"), "toast: {msg}");
+}
+
+#[tokio::test]
+async fn archived_sessions_leave_the_tree_and_restore_from_the_picker() {
+    // An archived session is split out of the snapshot into `view.archived`,
+    // listed only by the archived picker, and restored on its owning backend.
+    let (mut snap, sid, _pid) = snapshot_with_one_session();
+    snap.sessions[0].archived = Some(crate::session::ArchiveInfo {
+        archived_at: chrono::Utc::now(),
+        head: "abc123".to_string(),
+        archive_ref: "refs/commander/archive/x".to_string(),
+        has_snapshot: false,
+    });
+    let mut app = build_app_with_mock_remotes(vec![("buildbox", snap)]);
+    app.bootstrap_backend_views().await;
+    app.refresh_backend_view(BackendId(1)).await;
+
+    let view = &app.backend(BackendId(1)).unwrap().view;
+    assert!(view.snapshot.sessions.is_empty());
+    assert_eq!(view.archived.len(), 1);
+
+    app.handle_show_archived();
+    match &app.ui_state.modal {
+        Modal::QuickSwitch { mode, matches, .. } => {
+            assert_eq!(*mode, PaletteMode::ArchivedPicker);
+            assert!(matches!(
+                matches.as_slice(),
+                [QuickSwitchItem::ArchivedSession { label, .. }] if label.contains("remote-sess")
+            ));
+        }
+        other => panic!("expected the archived picker, got {other:?}"),
+    }
+
+    app.activate_quick_switch_selection().await;
+    loop {
+        match app.event_loop.next().await.expect("a restore event") {
+            AppEvent::StateUpdate(su @ StateUpdate::RestartFinished { .. }) => {
+                app.handle_state_update(su).await;
+                break;
+            }
+            _ => continue,
+        }
+    }
+    assert_eq!(
+        remote_mock(&app, BackendId(1)).restored_sessions(),
+        vec![sid]
+    );
+}
+
+#[tokio::test]
+async fn delete_toasts_an_undo_hint_and_undo_routes_to_the_same_backend() {
+    let (mut app, remote_sid) = app_with_remote_session().await;
+
+    app.handle_confirm(super::ConfirmAction::DeleteSession {
+        session_id: remote_sid,
+    })
+    .await;
+    loop {
+        match app.event_loop.next().await.expect("a delete event") {
+            AppEvent::StateUpdate(su @ StateUpdate::UndoableActionFinished { .. }) => {
+                app.handle_state_update(su).await;
+                break;
+            }
+            _ => continue,
+        }
+    }
+    assert_eq!(app.ui_state.undo_backend, Some(BackendId(1)));
+    let (msg, _) = app.ui_state.status_message.clone().expect("a delete toast");
+    assert!(msg.contains("u to undo"), "toast: {msg}");
+
+    app.handle_command(UserCommand::Undo).await;
+    loop {
+        match app.event_loop.next().await.expect("an undo event") {
+            AppEvent::StateUpdate(su @ StateUpdate::UndoFinished { .. }) => {
+                app.handle_state_update(su).await;
+                break;
+            }
+            _ => continue,
+        }
+    }
+    assert_eq!(remote_mock(&app, BackendId(1)).undo_calls(), 1);
+    let (msg, _) = app.ui_state.status_message.clone().expect("an undo toast");
+    assert!(msg.starts_with("Undone: "), "toast: {msg}");
+}
+
+#[tokio::test]
+async fn voice_catalog_lists_sessions_and_voice_commands_select_them() {
+    use crate::conversation::VoiceAction;
+
+    let (mut app, remote_sid) = app_with_remote_session().await;
+    app.refresh_voice_catalog();
+    assert!(
+        app.conversation.voice_catalog.lock().unwrap().is_none(),
+        "no catalog while voice input is off"
+    );
+
+    app.config.stt.enabled = true;
+    app.refresh_voice_catalog();
+    {
+        let catalog = app.conversation.voice_catalog.lock().unwrap();
+        let catalog = catalog.as_ref().expect("a catalog with voice input on");
+        assert!(catalog.sessions.iter().any(|s| s.id == remote_sid));
+        assert!(
+            catalog
+                .commands
+                .iter()
+                .all(|(a, _)| *a != BindableAction::DeleteSession)
+        );
+    }
+
+    app.handle_state_update(StateUpdate::VoiceCommand {
+        transcript: "select remote sess".to_string(),
+        action: VoiceAction::Select(remote_sid),
+    })
+    .await;
+    assert_eq!(
+        app.ui_state.selected_session_id.map(|r| r.id),
+        Some(remote_sid)
+    );
+    let (msg, _) = app.ui_state.status_message.clone().expect("a voice toast");
+    assert!(msg.contains("select remote sess"), "toast: {msg}");
+}
+
+#[test]
+fn archive_confirm_message_says_the_branch_is_kept() {
+    use super::actions::archive_confirm_message;
+
+    let msg = archive_confirm_message(Some("fix-parser"));
+    assert!(msg.contains("\"fix-parser\""), "message: {msg}");
+    assert!(msg.contains("branch is kept"), "message: {msg}");
+    assert!(archive_confirm_message(None).contains("this session"));
+}
+
 #[test]
 fn reset_confirm_message_names_session_and_promises_no_resume() {
     use super::actions::reset_confirm_message;
@@ -5913,6 +6165,7 @@ async fn pending_comment_markers_union_every_backend_view() {
         states: Box::new(crate::api::AgentStatesSnapshot {
             states: Default::default(),
             commander_running: false,
+            viewers: Default::default(),
         }),
     })
     .await;
@@ -5952,17 +6205,81 @@ fn board_with_one_session(pid: ProjectId, session_id: SessionId) -> crate::sessi
             BoardColumn {
                 name: crate::session::IN_PROGRESS.to_string(),
                 max_sessions: None,
+                lane_max_sessions: None,
                 cards: vec![card],
             },
             BoardColumn {
                 name: "Review".to_string(),
                 max_sessions: None,
+                lane_max_sessions: None,
                 cards: vec![],
             },
         ],
+        lanes: vec![],
     }
 }
 
+#[tokio::test]
+async fn grid_tiles_board_sessions_and_draws_their_captures() {
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    let mut app = make_test_app();
+    let pid = ProjectId::new();
+    let sid = SessionId::new();
+    app.ui_state.view_mode = ViewMode::Grid;
+    app.ui_state.board = board_with_one_session(pid, sid);
+    app.rebuild_grid_view();
+    app.update_selection();
+    assert_eq!(app.ui_state.selected_session_id.map(|r| r.id), Some(sid));
+    assert_eq!(app.ui_state.selected_project_id.map(|(_, p)| p), Some(pid));
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    terminal.draw(|f| app.render(f)).unwrap();
+    // The drawn tile is what the next capture round asks for.
+    assert_eq!(app.ui_state.grid_visible, vec![sid]);
+    assert!(app.ui_state.grid_capture_lines > 0);
+
+    app.apply_grid_captures(Instant::now(), vec![(sid, "agent-output-line".to_string())]);
+    terminal.draw(|f| app.render(f)).unwrap();
+    let text = buffer_text(&terminal);
+    assert!(
+        text.contains("agent-output-line"),
+        "capture must reach its tile"
+    );
+    assert!(text.contains("Grid [all]"), "top bar names the grid filter");
+
+    // The session isn't working, so the working filter leaves no tiles.
+    app.ui_state.grid_state.filter = crate::tui::widgets::grid::GridFilter::Working;
+    app.rebuild_grid_view();
+    app.update_selection();
+    assert!(app.ui_state.grid_tiles.is_empty());
+    assert_eq!(app.ui_state.selected_session_id, None);
+    assert!(
+        app.ui_state.grid_captures.is_empty(),
+        "captures of untiled sessions are dropped"
+    );
+}
+
+#[tokio::test]
+async fn grid_zoom_toggles_and_esc_unzooms() {
+    let mut app = make_test_app();
+    app.ui_state.view_mode = ViewMode::Grid;
+    app.ui_state.board = board_with_one_session(ProjectId::new(), SessionId::new());
+    app.rebuild_grid_view();
+
+    app.handle_command(UserCommand::ToggleGridZoom).await;
+    assert!(app.ui_state.grid_state.zoomed);
+    app.handle_input(InputEvent::Key(key(crossterm::event::KeyCode::Esc)))
+        .await;
+    assert!(!app.ui_state.grid_state.zoomed);
+
+    // Zoom is a grid control; elsewhere it does nothing.
+    app.ui_state.view_mode = ViewMode::Board;
+    app.handle_command(UserCommand::ToggleGridZoom).await;
+    assert!(!app.ui_state.grid_state.zoomed);
+}
+
 #[tokio::test]
 async fn update_selection_maps_sidebar_to_project_and_card_to_session() {
     let mut app = make_test_app();
@@ -6111,6 +6428,38 @@ async fn app_with_two_sessions() -> (App, ProjectId, SessionId, SessionId) {
     (app, project_id, s1_id, s2_id)
 }
 
+#[tokio::test]
+async fn swimlanes_cycle_fold_and_persist_on_the_board() {
+    let (mut app, project_id, s1_id, _s2_id) = app_with_two_sessions().await;
+    app.ui_state.view_mode = ViewMode::Board;
+    app.refresh_list_items().await;
+    assert!(app.ui_state.board.lanes.is_empty());
+
+    app.handle_command(UserCommand::CycleSwimlanes).await;
+    assert_eq!(app.ui_state.board_lanes, Some(Swimlanes::Project));
+    assert_eq!(app.tui_prefs.prefs().swimlanes, Some(Swimlanes::Project));
+    let lanes = &app.ui_state.board.lanes;
+    assert_eq!(lanes.len(), 1);
+    assert_eq!(lanes[0].key, format!("project:{project_id}"));
+    assert_eq!(lanes[0].total(), 2);
+
+    // Folding the selected card's lane hides its cards but keeps the counts.
+    let pos = app.ui_state.board.position_of(s1_id).unwrap();
+    app.ui_state.board_state.select(Some(pos));
+    app.update_selection();
+    app.handle_command(UserCommand::ToggleLane).await;
+    assert!(app.ui_state.board.lanes[0].collapsed);
+    assert_eq!(app.ui_state.board.worktree_count(), 0);
+    assert_eq!(app.ui_state.board.column_total(pos.col - 1), 2);
+    assert_eq!(app.tui_prefs.prefs().collapsed_lanes.len(), 1);
+
+    // With the cursor outside every lane, the key unfolds them all.
+    app.handle_command(UserCommand::ToggleLane).await;
+    assert!(!app.ui_state.board.lanes[0].collapsed);
+    assert_eq!(app.ui_state.board.worktree_count(), 2);
+    assert!(app.tui_prefs.prefs().collapsed_lanes.is_empty());
+}
+
 #[tokio::test]
 async fn refresh_reanchors_cursor_to_selected_session_after_column_move() {
     let mut app = make_test_app();
@@ -6164,7 +6513,11 @@ async fn refresh_reanchors_cursor_to_selected_session_after_column_move() {
         .mutate(move |state| {
             if let Some(s) = state.get_session_mut(&s2_id) {
                 s.section_override = Some("Beta".to_string());
-                crate::session::apply_assignment(s, &sections, now);
+                crate::session::apply_assignment(
+                    s,
+                    &sections,
+                    crate::session::SectionSignals::at(now),
+                );
             }
         })
         .await
@@ -6610,14 +6963,17 @@ async fn wheel_over_hovered_column_moves_selection_within_that_column() {
             BoardColumn {
                 name: crate::session::IN_PROGRESS.to_string(),
                 max_sessions: None,
+                lane_max_sessions: None,
                 cards: vec![mk_card(mk(a_id))],
             },
             BoardColumn {
                 name: "Review".to_string(),
                 max_sessions: None,
+                lane_max_sessions: None,
                 cards: vec![mk_card(mk(b1_id)), mk_card(mk(b2_id))],
             },
         ],
+        lanes: vec![],
     };
     app.ui_state.board_state.sync(vec![1, 1, 2]);
     // Start selection in the In Progress column on session A.
@@ -6672,6 +7028,9 @@ fn test_is_command_available_session_scoped_hidden_without_session() {
         BindableAction::SelectShell,
         BindableAction::DeleteSession,
         BindableAction::RenameSession,
+        BindableAction::EditTags,
+        BindableAction::EditNotes,
+        BindableAction::ArchiveSession,
         BindableAction::RestartSession,
         BindableAction::ToggleKeepAlive,
         BindableAction::OpenInEditor,
@@ -6693,6 +7052,9 @@ fn test_is_command_available_session_scoped_shown_with_session() {
         BindableAction::SelectShell,
         BindableAction::DeleteSession,
         BindableAction::RenameSession,
+        BindableAction::EditTags,
+        BindableAction::EditNotes,
+        BindableAction::ArchiveSession,
         BindableAction::RestartSession,
         BindableAction::ToggleKeepAlive,
         BindableAction::OpenInEditor,
@@ -7104,6 +7466,107 @@ async fn palette_jump_to_a_filtered_out_session_clears_the_filter_and_selects()
     assert!(app.ui_state.board.position_of(sb_id).is_some());
 }
 
+#[tokio::test]
+async fn query_bar_filters_live_and_cancel_restores_the_previous_query() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let (mut app, _pa_id, sa_id, _pb_id, sb_id) = app_with_two_projects().await;
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    app.handle_command(UserCommand::FilterSessions).await;
+    for c in "project:bbb".chars() {
+        app.handle_input(key(KeyCode::Char(c))).await;
+    }
+    // Applied while typing, before Enter.
+    assert_eq!(app.ui_state.board.worktree_count(), 1);
+    assert!(app.ui_state.board.position_of(sb_id).is_some());
+
+    app.handle_input(key(KeyCode::Enter)).await;
+    assert!(matches!(app.ui_state.modal, Modal::None));
+    assert_eq!(
+        app.ui_state.session_query.as_ref().map(|q| q.source()),
+        Some("project:bbb")
+    );
+
+    // Reopen, retype to the other project, then cancel: the kept query is back.
+    app.handle_command(UserCommand::FilterSessions).await;
+    for _ in 0..3 {
+        app.handle_input(key(KeyCode::Backspace)).await;
+    }
+    for c in "aaa".chars() {
+        app.handle_input(key(KeyCode::Char(c))).await;
+    }
+    assert!(app.ui_state.board.position_of(sa_id).is_some());
+    app.handle_input(key(KeyCode::Esc)).await;
+    assert!(app.ui_state.board.position_of(sa_id).is_none());
+    assert!(app.ui_state.board.position_of(sb_id).is_some());
+
+    // Esc in the main view clears it.
+    app.handle_input(key(KeyCode::Esc)).await;
+    assert!(app.ui_state.session_query.is_none());
+    assert_eq!(app.ui_state.board.worktree_count(), 2);
+}
+
+#[tokio::test]
+async fn query_bar_keeps_the_last_valid_query_while_the_text_does_not_parse() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let (mut app, ..) = app_with_two_projects().await;
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    app.handle_command(UserCommand::FilterSessions).await;
+    for c in "project:aaa age:".chars() {
+        app.handle_input(key(KeyCode::Char(c))).await;
+    }
+    assert_eq!(
+        app.ui_state.session_query.as_ref().map(|q| q.source()),
+        Some("project:aaa"),
+        "the half-typed `age:` term must not drop the filter"
+    );
+
+    // Enter on a query that doesn't parse keeps the bar open to fix it.
+    app.handle_input(key(KeyCode::Enter)).await;
+    assert!(matches!(
+        app.ui_state.modal,
+        Modal::Input {
+            on_submit: InputAction::FilterSessions { .. },
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn saved_filter_palette_row_applies_its_query() {
+    let (mut app, _pa_id, sa_id, _pb_id, sb_id) = app_with_two_projects().await;
+    app.tui_prefs.save_filter("only b", "project:bbb").await;
+
+    app.open_quick_switch_with_mode(PaletteMode::Unified).await;
+    if let Modal::QuickSwitch {
+        matches,
+        selected_idx,
+        ..
+    } = &mut app.ui_state.modal
+    {
+        *selected_idx = matches
+            .iter()
+            .position(
+                |m| matches!(m, QuickSwitchItem::SavedFilter { name, .. } if name == "only b"),
+            )
+            .expect("palette lists the saved filter");
+    } else {
+        panic!("expected quick-switch modal");
+    }
+    app.activate_quick_switch_selection().await;
+
+    assert!(app.ui_state.board.position_of(sb_id).is_some());
+    assert!(app.ui_state.board.position_of(sa_id).is_none());
+
+    // A palette jump to the hidden session clears the query so it lands.
+    app.unhide_session(sa_id).await;
+    assert!(app.ui_state.session_query.is_none());
+    assert!(app.ui_state.board.position_of(sa_id).is_some());
+}
+
 // ===========================================================================
 // View cycling (`v` / ToggleViewMode) across list and board views
 // ===========================================================================
@@ -7118,7 +7581,7 @@ fn one_section() -> Vec<crate::session::SectionConfig> {
 }
 
 #[tokio::test]
-async fn toggle_view_mode_cycles_all_four_views_with_sections() {
+async fn toggle_view_mode_cycles_all_five_views_with_sections() {
     let mut app = make_test_app();
     app.config.sections = one_section();
     app.ui_state.view_mode = ViewMode::ProjectGrouped;
@@ -7130,6 +7593,8 @@ async fn toggle_view_mode_cycles_all_four_views_with_sections() {
     app.handle_toggle_view_mode().await;
     assert_eq!(app.ui_state.view_mode, ViewMode::Board);
     app.handle_toggle_view_mode().await;
+    assert_eq!(app.ui_state.view_mode, ViewMode::Grid);
+    app.handle_toggle_view_mode().await;
     assert_eq!(app.ui_state.view_mode, ViewMode::ProjectGrouped);
 }
 
@@ -7140,10 +7605,12 @@ async fn toggle_view_mode_skips_section_views_without_sections() {
     app.ui_state.view_mode = ViewMode::ProjectGrouped;
 
     // With no sections the two section-grouped views would render identically
-    // to the project view, so `v` skips straight to the board and back.
+    // to the project view, so `v` skips straight to the board, then the grid.
     app.handle_toggle_view_mode().await;
     assert_eq!(app.ui_state.view_mode, ViewMode::Board);
     app.handle_toggle_view_mode().await;
+    assert_eq!(app.ui_state.view_mode, ViewMode::Grid);
+    app.handle_toggle_view_mode().await;
     assert_eq!(app.ui_state.view_mode, ViewMode::ProjectGrouped);
 }
 
@@ -7597,6 +8064,120 @@ async fn wheel_scrolls_the_pane_over_it_and_moves_the_selection_over_the_list()
     assert_eq!(app.ui_state.preview_state.scroll_offset, pane_offset);
 }
 
+#[tokio::test]
+async fn slash_opens_pane_inspection_which_takes_every_key_until_closed() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    let mut app = app_with_rendered_list(5);
+    app.ui_state.preview_content = "first\nneedle here\nlast\n".to_string();
+    let press = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    app.handle_input(press(KeyCode::Char('/'))).await;
+    let inspect = app
+        .ui_state
+        .pane_inspect
+        .as_ref()
+        .expect("`/` opens inspection");
+    assert!(
+        inspect.inspector.is_editing_search(),
+        "inspection opens at the search prompt"
+    );
+    for c in "needle".chars() {
+        app.handle_input(press(KeyCode::Char(c))).await;
+    }
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|f| app.render(f)).unwrap();
+    assert!(
+        buffer_text(&terminal).contains("/needle  1 match"),
+        "the search prompt must render along the pane's bottom border"
+    );
+    app.handle_input(press(KeyCode::Enter)).await;
+    let cursor = |app: &App| {
+        app.ui_state
+            .pane_inspect
+            .as_ref()
+            .unwrap()
+            .inspector
+            .cursor()
+    };
+    assert_eq!(cursor(&app), 1, "the search jumps to the match");
+
+    // `j` moves the inspection cursor, never the list selection.
+    let selected = app.ui_state.list_state.selected();
+    app.handle_input(press(KeyCode::Char('j'))).await;
+    assert_eq!(cursor(&app), 2);
+    assert_eq!(app.ui_state.list_state.selected(), selected);
+
+    // Esc backs out a step at a time: the search first, then the inspection.
+    app.handle_input(press(KeyCode::Esc)).await;
+    let inspect = app
+        .ui_state
+        .pane_inspect
+        .as_ref()
+        .expect("still inspecting");
+    assert!(inspect.inspector.search().is_none());
+    app.handle_input(press(KeyCode::Esc)).await;
+    assert!(app.ui_state.pane_inspect.is_none());
+}
+
+#[tokio::test]
+async fn inspect_pane_is_refused_on_the_info_tab() {
+    let mut app = app_with_rendered_list(3);
+    app.ui_state.right_pane_view = RightPaneView::Info;
+    app.handle_command(UserCommand::InspectPane).await;
+    assert!(app.ui_state.pane_inspect.is_none());
+    assert!(app.ui_state.status_message.is_some());
+
+    app.ui_state.view_mode = ViewMode::Board;
+    assert!(
+        !app.ui_state
+            .is_command_available(BindableAction::InspectPane),
+        "the board has no right pane to inspect"
+    );
+}
+
+#[tokio::test]
+async fn inspect_history_applies_only_to_the_inspected_session() {
+    let mut app = app_with_rendered_list(3);
+    let sid = SessionId::new();
+    let sref = SessionRef::new(crate::backend::LOCAL_BACKEND_ID, sid);
+    app.ui_state.preview_content = "screen".to_string();
+    app.ui_state.selected_session_id = Some(sref);
+    app.handle_command(UserCommand::InspectPane).await;
+
+    app.handle_state_update(StateUpdate::InspectHistoryLoaded {
+        session_id: SessionId::new(),
+        content: "other\nsession".to_string(),
+    })
+    .await;
+    let inspect = app.ui_state.pane_inspect.as_ref().unwrap();
+    assert_eq!(
+        inspect.inspector.line_count(),
+        1,
+        "another session's history is dropped"
+    );
+
+    app.handle_state_update(StateUpdate::InspectHistoryLoaded {
+        session_id: sid,
+        content: "older\nolder still\nscreen".to_string(),
+    })
+    .await;
+    let inspect = app.ui_state.pane_inspect.as_ref().unwrap();
+    assert_eq!(inspect.inspector.line_count(), 3);
+    assert_eq!(
+        inspect.inspector.cursor(),
+        2,
+        "the cursor stays on the same text"
+    );
+
+    // Moving the selection elsewhere closes the inspection.
+    app.ui_state.list_state.select(Some(1));
+    app.update_selection();
+    assert!(app.ui_state.pane_inspect.is_none());
+}
+
 #[tokio::test]
 async fn info_tab_renders_session_detail_in_the_right_pane() {
     use ratatui::Terminal;
@@ -8251,6 +8832,7 @@ async fn submitting_a_destination_name_starts_the_clone_on_the_pickers_backend()
         "my-diffgrid".to_string(),
         None,
         None,
+        None,
     )
     .await;
 
@@ -8282,6 +8864,7 @@ async fn an_empty_destination_name_means_derive_it_from_the_source() {
         "   ".to_string(),
         None,
         None,
+        None,
     )
     .await;
     let requests = eventually(|| remote_mock(&app, id).clone_requests()).await;
@@ -8751,3 +9334,191 @@ async fn an_unread_palette_row_is_bold_like_its_tree_row() {
         );
     }
 }
+
+// ---------------------------------------------------------------------------
+// Scrollback search palette
+// ---------------------------------------------------------------------------
+
+fn scrollback_hit(session_id: SessionId, text: &str) -> crate::scrollback::ScrollbackHit {
+    crate::scrollback::ScrollbackHit {
+        session_id,
+        line: 42,
+        from_end: 7,
+        text: text.to_string(),
+        match_start: 0,
+        match_end: 5,
+    }
+}
+
+/// Open the scrollback palette on `app` and type `query` into it.
+async fn search_scrollback_for(app: &mut App, query: &str) {
+    app.handle_command(UserCommand::SearchScrollback).await;
+    for c in query.chars() {
+        app.handle_modal_key(key(crossterm::event::KeyCode::Char(c)))
+            .await;
+    }
+}
+
+fn current_scrollback_generation(app: &App) -> u64 {
+    app.ui_state
+        .scrollback_search
+        .generation
+        .load(std::sync::atomic::Ordering::Relaxed)
+}
+
+#[tokio::test]
+async fn scrollback_hits_from_a_remote_become_rows_titled_by_their_session() {
+    let (mut app, sid) = app_with_remote_session().await;
+    search_scrollback_for(&mut app, "error").await;
+    assert!(app.ui_state.scrollback_search.searching);
+    assert_eq!(app.ui_state.scrollback_search.query, "error");
+
+    let generation = current_scrollback_generation(&app);
+    app.handle_state_update(StateUpdate::ScrollbackHitsLoaded {
+        generation,
+        hits: vec![(1, scrollback_hit(sid, "error: boom"))],
+        errors: Vec::new(),
+    })
+    .await;
+
+    assert!(!app.ui_state.scrollback_search.searching);
+    let Modal::QuickSwitch { matches, .. } = &app.ui_state.modal else {
+        panic!("the palette stays open");
+    };
+    match matches.as_slice() {
+        [
+            QuickSwitchItem::ScrollbackHit {
+                backend,
+                hit,
+                title,
+            },
+        ] => {
+            assert_eq!(*backend, BackendId(1));
+            assert_eq!(hit.session_id, sid);
+            assert_eq!(title, "remote-sess");
+        }
+        other => panic!("expected one hit row, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn a_superseded_scrollback_search_is_dropped() {
+    let (mut app, sid) = app_with_remote_session().await;
+    search_scrollback_for(&mut app, "err").await;
+    let stale = current_scrollback_generation(&app);
+    app.handle_modal_key(key(crossterm::event::KeyCode::Char('o')))
+        .await;
+    assert_ne!(current_scrollback_generation(&app), stale);
+
+    app.handle_state_update(StateUpdate::ScrollbackHitsLoaded {
+        generation: stale,
+        hits: vec![(1, scrollback_hit(sid, "error: boom"))],
+        errors: Vec::new(),
+    })
+    .await;
+
+    assert!(app.ui_state.scrollback_search.hits.is_empty());
+    assert!(
+        app.ui_state.scrollback_search.searching,
+        "the newer search is still outstanding"
+    );
+}
+
+#[tokio::test]
+async fn a_failed_backend_search_is_reported_without_hiding_other_hits() {
+    let (mut app, sid) = app_with_remote_session().await;
+    search_scrollback_for(&mut app, "error").await;
+    let generation = current_scrollback_generation(&app);
+    app.handle_state_update(StateUpdate::ScrollbackHitsLoaded {
+        generation,
+        hits: vec![(1, scrollback_hit(sid, "error: boom"))],
+        errors: vec!["local: Invalid request: bad pattern".to_string()],
+    })
+    .await;
+
+    assert_eq!(app.ui_state.scrollback_search.hits.len(), 1);
+    let (message, _) = app.ui_state.status_message.as_ref().unwrap();
+    assert!(message.contains("local"), "{message}");
+}
+
+#[tokio::test]
+async fn selecting_a_scrollback_hit_attaches_and_reveals_on_the_owning_backend() {
+    let (mut app, sid) = app_with_remote_session().await;
+    app.refresh_list_items().await;
+    search_scrollback_for(&mut app, "error").await;
+    let hit = scrollback_hit(sid, "error: boom");
+    let generation = current_scrollback_generation(&app);
+    app.handle_state_update(StateUpdate::ScrollbackHitsLoaded {
+        generation,
+        hits: vec![(1, hit.clone())],
+        errors: Vec::new(),
+    })
+    .await;
+
+    app.activate_quick_switch_selection().await;
+
+    let session = SessionRef::new(BackendId(1), sid);
+    assert!(matches!(
+        app.ui_state.attach_request,
+        Some(AttachTarget::Session {
+            session: s,
+            kind: AttachKind::Agent,
+        }) if s == session
+    ));
+    assert_eq!(
+        app.ui_state.pending_scrollback_reveal,
+        Some((session, hit.reveal()))
+    );
+
+    // The attach loop fires the reveal once connected; it goes to the remote.
+    app.spawn_pending_scrollback_reveal(session);
+    assert!(app.ui_state.pending_scrollback_reveal.is_none());
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    assert_eq!(
+        remote_mock(&app, BackendId(1)).scrollback_reveals(),
+        vec![(sid, hit.reveal())]
+    );
+}
+
+#[tokio::test]
+async fn a_reveal_for_another_session_is_discarded() {
+    let (mut app, sid) = app_with_remote_session().await;
+    let pending = SessionRef::new(BackendId(1), sid);
+    app.ui_state.pending_scrollback_reveal = Some((pending, scrollback_hit(sid, "error").reveal()));
+
+    app.spawn_pending_scrollback_reveal(SessionRef::new(BackendId(1), SessionId::new()));
+
+    assert!(app.ui_state.pending_scrollback_reveal.is_none());
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    assert!(
+        remote_mock(&app, BackendId(1))
+            .scrollback_reveals()
+            .is_empty()
+    );
+}
+
+#[test]
+fn run_tasks_message_reports_tasks_that_failed_to_start() {
+    use super::actions::run_tasks_message;
+    use crate::session::TaskStart;
+    let ok = |name: &str| TaskStart {
+        name: name.to_string(),
+        error: None,
+    };
+    let failed = TaskStart {
+        name: "lint".to_string(),
+        error: Some("tmux: no server".to_string()),
+    };
+    assert_eq!(
+        run_tasks_message(&[ok("build"), ok("test")]),
+        "Running tasks: build, test"
+    );
+    assert_eq!(
+        run_tasks_message(&[ok("build"), failed.clone()]),
+        "Running tasks: build — failed to start lint: tmux: no server"
+    );
+    assert_eq!(
+        run_tasks_message(&[failed]),
+        "failed to start lint: tmux: no server"
+    );
+}
@@ -9,6 +9,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
+use crate::api::ResourceUsage;
 use crate::config::keybindings::{BindableAction, KeyBindings};
 use crate::git::{DiffInfo, EnrichedPrInfo};
 use crate::session::{ProjectId, SessionId};
@@ -49,11 +50,15 @@ pub enum InputEvent {
 /// Which flavour of pane relaunch a [`StateUpdate::RestartFinished`] is
 /// reporting, so the toast and the error prefix match what the operator asked
 /// for. `Restart` covers both the plain restart and a program change (which
-/// relaunches the pane); `Reset` is the no-resume relaunch.
+/// relaunches the pane); `Reset` is the no-resume relaunch. `Archive` and
+/// `Restore` ride the same completion path since they also tear down or
+/// relaunch the pane in the background.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RestartKind {
     Restart,
     Reset,
+    Archive,
+    Restore,
 }
 
 impl RestartKind {
@@ -62,6 +67,8 @@ impl RestartKind {
         match self {
             Self::Restart => "Session restarted",
             Self::Reset => "Session reset — fresh conversation",
+            Self::Archive => "Session archived",
+            Self::Restore => "Session restored — resuming",
         }
     }
 
@@ -70,6 +77,8 @@ impl RestartKind {
         match self {
             Self::Restart => "Failed to restart",
             Self::Reset => "Failed to reset",
+            Self::Archive => "Failed to archive",
+            Self::Restore => "Failed to restore",
         }
     }
 }
@@ -122,6 +131,12 @@ pub enum StateUpdate {
         server: crate::config::RemoteServerConfig,
         result: Result<bool, String>,
     },
+    /// A `modal`-mode custom command finished: its combined output, or why it
+    /// couldn't run.
+    CustomCommandFinished {
+        name: String,
+        result: Result<String, String>,
+    },
     /// Enriched PR info ready from background fetch
     EnrichedPrReady {
         /// Generation token for the in-flight guard — see
@@ -130,6 +145,14 @@ pub enum StateUpdate {
         session_id: SessionId,
         info: Option<EnrichedPrInfo>,
     },
+    /// Resource-usage sample for the Info surface's Usage line
+    ResourceUsageReady {
+        /// Generation token for the in-flight guard — see
+        /// [`StateUpdate::PreviewReady::spawned_at`].
+        spawned_at: Instant,
+        session_id: SessionId,
+        usage: Option<ResourceUsage>,
+    },
     /// AI-generated branch summary ready
     AiSummaryReady {
         session_id: SessionId,
@@ -225,6 +248,13 @@ pub enum StateUpdate {
         backend: crate::backend::BackendId,
         message: String,
     },
+    /// A watch-grid capture round finished: the latest pane tail of each tile
+    /// captured. Sessions whose capture failed or have no pane are absent.
+    GridCapturesReady {
+        /// Generation token, as for [`StateUpdate::PreviewReady`].
+        spawned_at: Instant,
+        captures: Vec<(SessionId, String)>,
+    },
     /// Preview / shell / diff data ready from a background fetch, applied only
     /// if the same thing is still selected. One fetch feeds every consumer: the
     /// list views' right pane (`preview_content` / `shell_content`) and the Info
@@ -272,6 +302,21 @@ pub enum StateUpdate {
         backend_id: usize,
         result: std::result::Result<(), String>,
     },
+    /// An undoable delete or project removal finished on `backend_id`. The
+    /// handler points the Undo command at that backend and toasts `message`
+    /// with the undo hint.
+    UndoableActionFinished { backend_id: usize, message: String },
+    /// An undo finished (or failed): `Ok` carries what was undone.
+    UndoFinished {
+        backend_id: usize,
+        result: std::result::Result<String, String>,
+    },
+    /// A voice transcript was recognised as a TUI command (the spoken
+    /// confirmation is already on its way); run it on the loop.
+    VoiceCommand {
+        transcript: String,
+        action: crate::conversation::VoiceAction,
+    },
     /// The GitHub repo listing for the open clone picker finished (or failed).
     /// Spawned off the event loop because `gh api --paginate` has no server-side
     /// timeout and a large account takes many seconds — blocking here would
@@ -287,6 +332,21 @@ pub enum StateUpdate {
         /// unauthenticated one, still leaves the picker's URL path usable.
         result: std::result::Result<Vec<claude_commander_protocol::github::GithubRepo>, String>,
     },
+    /// A debounced scrollback search finished on every connected backend.
+    ScrollbackHitsLoaded {
+        /// The search generation; stale when the query has changed since.
+        generation: u64,
+        /// Hits tagged with their backend id (indexes `Vec<BackendHandle>`).
+        hits: Vec<(usize, claude_commander_protocol::scrollback::ScrollbackHit)>,
+        /// `"<backend name>: <reason>"` per backend whose search failed.
+        errors: Vec<String>,
+    },
+    /// The deeper agent-pane capture for an open pane inspection arrived.
+    /// Applied only if inspection is still open on that session's Preview tab.
+    InspectHistoryLoaded {
+        session_id: SessionId,
+        content: String,
+    },
     /// A poll of an in-flight clone job came back. Emitted roughly once a second
     /// by the poll task until the job reaches a terminal status; there is no
     /// cancellation — jobs are bounded server-side by `clone_timeout_secs`.
@@ -405,6 +465,17 @@ pub enum UserCommand {
     DeleteMergedPrSessions,
     /// Rename the currently selected session (UI title only)
     RenameSession,
+    /// Edit the selected session's tags (comma-separated, palette-only)
+    EditTags,
+    /// Edit the selected session's free-form notes (palette-only)
+    EditNotes,
+    /// Archive the selected session: stop it and remove its worktree, keeping
+    /// the branch and any uncommitted work (palette-only)
+    ArchiveSession,
+    /// Open the picker of archived sessions; choosing one restores it
+    ShowArchived,
+    /// Undo the most recent session delete, project removal or cascade abandon
+    Undo,
     /// Restart current session (kill tmux and recreate)
     RestartSession,
     /// Reset current session: restart it *without* resuming, discarding the
@@ -414,6 +485,17 @@ pub enum UserCommand {
     ChangeProgram,
     /// Toggle keep-alive on the selected session (opt out of auto-hibernation)
     ToggleKeepAlive,
+    /// Run the selected session's project tasks
+    RunTasks,
+    /// Open the palette in scrollback-search mode: full-text search over every
+    /// session's indexed tmux history
+    SearchScrollback,
+    /// Open the query bar that filters the board and list rows
+    FilterSessions,
+    /// Save the active filter query under a name, for the palette
+    SaveFilter,
+    /// Send the selected session's latest failed task output to its agent
+    SendTaskFailure,
     /// Remove an entire project
     RemoveProject,
     /// Open worktree in editor/IDE
@@ -486,8 +568,22 @@ pub enum UserCommand {
     ShrinkLeftPane,
     /// Widen the session list (move the pane divider right).
     GrowLeftPane,
+    /// Open read-only inspection of the right pane's capture: scroll, search,
+    /// select and copy without attaching.
+    InspectPane,
     /// Collapse or expand the section containing the selected item.
     ToggleSection,
+    /// Cycle the board's swimlane grouping.
+    CycleSwimlanes,
+    /// Fold or unfold the selected card's swimlane.
+    ToggleLane,
+    /// Cycle the watch grid's filter: all / working / needs input.
+    CycleGridFilter,
+    /// Zoom the selected watch-grid tile to the whole grid area, or back.
+    ToggleGridZoom,
+    /// Run the `[[custom_commands]]` entry with this name against the
+    /// selected session (from its `[keybindings.custom]` binding).
+    RunCustomCommand(String),
 }
 
 impl UserCommand {
@@ -503,7 +599,11 @@ impl UserCommand {
             return None;
         }
 
-        // Try the configurable bindings first
+        // Try the configurable bindings first. Custom-command bindings win over
+        // built-in actions: binding a key to one is an explicit choice.
+        if let Some(name) = bindings.resolve_custom(&key) {
+            return Some(UserCommand::RunCustomCommand(name.to_string()));
+        }
         if let Some(action) = bindings.resolve(&key) {
             return Some(action.into());
         }
@@ -539,10 +639,15 @@ impl UserCommand {
             | UserCommand::ResetSession
             | UserCommand::ChangeProgram
             | UserCommand::ToggleKeepAlive
+            | UserCommand::RunTasks
+            | UserCommand::SendTaskFailure
             | UserCommand::NewProject
             | UserCommand::ScanDirectory
             | UserCommand::OpenReviewDiff
             | UserCommand::RenameSession
+            | UserCommand::EditTags
+            | UserCommand::EditNotes
+            | UserCommand::ArchiveSession
             | UserCommand::RemoveProject
             | UserCommand::CascadeResume
             | UserCommand::PushStack => None,
@@ -591,6 +696,14 @@ impl UserCommand {
             UserCommand::ShowSettings => Some("ui.settings"),
             UserCommand::EditServerPrograms => Some("ui.edit_server_programs"),
             UserCommand::QuickSwitch => Some("ui.quick_switch"),
+            UserCommand::SearchScrollback => Some("ui.search_scrollback"),
+            UserCommand::FilterSessions => Some("ui.filter_sessions"),
+            UserCommand::SaveFilter => Some("ui.save_filter"),
+            // Opening the picker; the restore itself is `session.restore`.
+            UserCommand::ShowArchived => Some("ui.show_archived"),
+            // Distinct from the service's `undo`: this counts the TUI entry
+            // point (key or palette), not successful undos.
+            UserCommand::Undo => Some("ui.undo"),
             // The *domain* feature (`clone_project`) is recorded inside
             // `CommanderService::start_clone`, which covers every frontend.
             // This names the distinct UI event of opening the repo picker —
@@ -599,7 +712,13 @@ impl UserCommand {
             UserCommand::MoveToSection => Some("ui.move_to_section"),
             UserCommand::ToggleViewMode => Some("ui.toggle_view_mode"),
             UserCommand::ToggleSection => Some("ui.toggle_section"),
+            UserCommand::CycleSwimlanes => Some("ui.cycle_swimlanes"),
+            UserCommand::ToggleLane => Some("ui.toggle_lane"),
+            UserCommand::CycleGridFilter => Some("ui.grid_filter"),
+            UserCommand::ToggleGridZoom => Some("ui.grid_zoom"),
             UserCommand::TogglePane | UserCommand::TogglePaneReverse => Some("ui.toggle_pane"),
+            UserCommand::InspectPane => Some("ui.inspect_pane"),
+            UserCommand::RunCustomCommand(_) => Some("custom_command.run"),
         }
     }
 }
@@ -631,13 +750,23 @@ impl From<BindableAction> for UserCommand {
             BindableAction::DeleteSession => Self::DeleteSession,
             BindableAction::DeleteMergedPrSessions => Self::DeleteMergedPrSessions,
             BindableAction::RenameSession => Self::RenameSession,
+            BindableAction::EditTags => Self::EditTags,
+            BindableAction::EditNotes => Self::EditNotes,
+            BindableAction::ArchiveSession => Self::ArchiveSession,
+            BindableAction::ShowArchived => Self::ShowArchived,
+            BindableAction::Undo => Self::Undo,
             BindableAction::RestartSession => Self::RestartSession,
             BindableAction::ResetSession => Self::ResetSession,
             BindableAction::ChangeProgram => Self::ChangeProgram,
             BindableAction::ToggleKeepAlive => Self::ToggleKeepAlive,
+            BindableAction::RunTasks => Self::RunTasks,
+            BindableAction::SendTaskFailure => Self::SendTaskFailure,
             BindableAction::RemoveProject => Self::RemoveProject,
             BindableAction::OpenInEditor => Self::OpenInEditor,
             BindableAction::OpenInfo => Self::OpenInfo,
+            BindableAction::SearchScrollback => Self::SearchScrollback,
+            BindableAction::FilterSessions => Self::FilterSessions,
+            BindableAction::SaveFilter => Self::SaveFilter,
             BindableAction::OpenPullRequest => Self::OpenPullRequest,
             BindableAction::RefreshPrStatus => Self::RefreshPrStatus,
             BindableAction::OpenCommander => Self::OpenCommander,
@@ -657,10 +786,15 @@ impl From<BindableAction> for UserCommand {
             BindableAction::MoveToSection => Self::MoveToSection,
             BindableAction::ToggleViewMode => Self::ToggleViewMode,
             BindableAction::ToggleSection => Self::ToggleSection,
+            BindableAction::CycleSwimlanes => Self::CycleSwimlanes,
+            BindableAction::ToggleLane => Self::ToggleLane,
+            BindableAction::CycleGridFilter => Self::CycleGridFilter,
+            BindableAction::ToggleGridZoom => Self::ToggleGridZoom,
             BindableAction::TogglePane => Self::TogglePane,
             BindableAction::TogglePaneReverse => Self::TogglePaneReverse,
             BindableAction::ShrinkLeftPane => Self::ShrinkLeftPane,
             BindableAction::GrowLeftPane => Self::GrowLeftPane,
+            BindableAction::InspectPane => Self::InspectPane,
             BindableAction::AddRemoteServer => Self::AddRemoteServer,
             BindableAction::RemoveRemoteServer => Self::RemoveRemoteServer,
         }
@@ -855,6 +989,9 @@ mod tests {
             // feature name, so the TUI chokepoint must stay silent to avoid ~2x
             // inflation of these counts relative to other frontends.
             UserCommand::RenameSession,
+            UserCommand::EditTags,
+            UserCommand::EditNotes,
+            UserCommand::ArchiveSession,
             UserCommand::RemoveProject,
             UserCommand::CascadeResume,
             UserCommand::PushStack,
@@ -941,6 +1078,17 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn custom_command_binding_shadows_built_in_action() {
+        // `q` is Quit by default; binding it to a custom command takes it over.
+        let b: KeyBindings = toml::from_str("[custom]\ndeploy = \"q\"").unwrap();
+        let key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert!(matches!(
+            UserCommand::from_key(key, &b),
+            Some(UserCommand::RunCustomCommand(name)) if name == "deploy"
+        ));
+    }
+
     #[test]
     fn test_pane_resize_keys() {
         let b = kb();
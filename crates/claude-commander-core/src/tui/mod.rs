@@ -11,8 +11,10 @@ mod digit_accumulator;
 mod event;
 pub mod hotkey;
 pub(crate) mod list_nav;
+mod osc52;
 mod path_completer;
 mod prefs;
+mod prompt_editor;
 mod syntax_highlight;
 pub mod theme;
 mod widgets;
@@ -0,0 +1,69 @@
+//! System clipboard writes over OSC 52.
+//!
+//! The escape sequence asks the terminal itself to set its clipboard, so a
+//! copy works wherever the TUI is displayed — including over ssh, where a
+//! clipboard library would only reach the remote host's (usually absent)
+//! display. Inside tmux the sequence is wrapped for passthrough, which needs
+//! `set -g allow-passthrough on`.
+
+use std::io::{self, Write};
+
+use base64::Engine;
+
+/// Largest encoded payload sent. Many terminals silently drop an OSC 52
+/// sequence beyond about 100 kB, so a longer copy is refused up front rather
+/// than reported as done and lost.
+pub const MAX_ENCODED_BYTES: usize = 100_000;
+
+/// The sequence that puts `text` on the clipboard, or `None` when it's over
+/// [`MAX_ENCODED_BYTES`]. With `in_tmux` it is wrapped in a DCS passthrough
+/// (escapes doubled) so tmux forwards it to the outer terminal.
+pub fn sequence(text: &str, in_tmux: bool) -> Option<String> {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    if encoded.len() > MAX_ENCODED_BYTES {
+        return None;
+    }
+    let osc = format!("\x1b]52;c;{encoded}\x07");
+    Some(match in_tmux {
+        true => format!("\x1bPtmux;{}\x1b\\", osc.replace('\x1b', "\x1b\x1b")),
+        false => osc,
+    })
+}
+
+/// Put `text` on the clipboard of the terminal on stdout.
+pub fn copy(text: &str) -> io::Result<()> {
+    let in_tmux = std::env::var_os("TMUX").is_some();
+    let seq = sequence(text, in_tmux)
+        .ok_or_else(|| io::Error::other("selection too large for the terminal clipboard"))?;
+    let mut out = io::stdout().lock();
+    out.write_all(seq.as_bytes())?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequence_base64_encodes_into_osc_52() {
+        assert_eq!(
+            sequence("hi\n", false).as_deref(),
+            Some("\x1b]52;c;aGkK\x07")
+        );
+    }
+
+    #[test]
+    fn sequence_wraps_for_tmux_passthrough() {
+        assert_eq!(
+            sequence("hi", true).as_deref(),
+            Some("\x1bPtmux;\x1b\x1b]52;c;aGk=\x07\x1b\\")
+        );
+    }
+
+    #[test]
+    fn oversized_text_is_refused() {
+        let big = "x".repeat(MAX_ENCODED_BYTES);
+        assert!(sequence(&big, false).is_none());
+        assert!(sequence(&"x".repeat(MAX_ENCODED_BYTES / 2), false).is_some());
+    }
+}
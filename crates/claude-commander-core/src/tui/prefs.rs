@@ -22,7 +22,7 @@ use tracing::warn;
 use crate::config::ViewMode;
 use crate::config::store::{atomic_write, open_lock_file};
 use crate::error::{ConfigError, Result};
-use crate::session::{ProjectId, SessionId};
+use crate::session::{ProjectId, SessionId, Swimlanes};
 
 /// Current `tui.json` schema version. Bump this and extend [`migrate_prefs_schema`]
 /// whenever an upgrade must rewrite existing prefs exactly once.
@@ -60,8 +60,34 @@ pub struct TuiPrefs {
     /// [`DEFAULT_LEFT_PANE_PCT`](crate::tui::app::DEFAULT_LEFT_PANE_PCT).
     #[serde(default)]
     pub left_pane_pct: Option<u16>,
+    /// Initial prompts from the New Session dialog, newest first and capped at
+    /// [`PROMPT_HISTORY_LEN`], recalled with Ctrl-p/Ctrl-n in the prompt field.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prompt_history: Vec<String>,
+    /// Named session filter queries, offered in the palette. Kept in the
+    /// order they were first saved.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub saved_filters: Vec<SavedFilter>,
+    /// Board swimlane grouping. `None`/absent means lanes are off.
+    #[serde(default)]
+    pub swimlanes: Option<Swimlanes>,
+    /// Keys of the swimlanes the user has folded (see
+    /// [`BoardLane::key`](crate::session::BoardLane::key)).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub collapsed_lanes: Vec<String>,
+}
+
+/// A session filter query saved under a name (see
+/// [`SessionQuery`](crate::session::SessionQuery)).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedFilter {
+    pub name: String,
+    pub query: String,
 }
 
+/// How many recent initial prompts `tui.json` keeps.
+pub const PROMPT_HISTORY_LEN: usize = 50;
+
 /// The UI-pref fields as they lived in `state.json` before `tui.json` existed,
 /// read directly (not through [`StateStore`](crate::config::StateStore)) for the
 /// one-time migration. Deserialised leniently: any other `state.json` fields are
@@ -90,6 +116,10 @@ impl From<LegacyStatePrefs> for TuiPrefs {
             // Legacy state.json predates multi-backend; its selection is local.
             last_selected_backend: None,
             left_pane_pct: l.left_pane_pct,
+            prompt_history: Vec::new(),
+            saved_filters: Vec::new(),
+            swimlanes: None,
+            collapsed_lanes: Vec::new(),
         }
     }
 }
@@ -187,6 +217,68 @@ impl TuiPrefsStore {
     pub async fn set_left_pane_pct(&self, pct: u16) {
         self.update(|p| p.left_pane_pct = Some(pct)).await;
     }
+
+    /// Record a submitted initial prompt as the newest history entry.
+    pub async fn record_prompt(&self, prompt: &str) {
+        self.update(|p| push_recent_prompt(&mut p.prompt_history, prompt))
+            .await;
+    }
+
+    /// Persist the board's swimlane grouping (`None` turns lanes off).
+    pub async fn set_swimlanes(&self, lanes: Option<Swimlanes>) {
+        self.update(|p| p.swimlanes = lanes).await;
+    }
+
+    /// Persist the folded swimlane keys.
+    pub async fn set_collapsed_lanes(&self, keys: Vec<String>) {
+        self.update(|p| p.collapsed_lanes = keys).await;
+    }
+
+    /// Save `query` as the named filter, replacing one of the same name.
+    pub async fn save_filter(&self, name: &str, query: &str) {
+        self.update(|p| upsert_saved_filter(&mut p.saved_filters, name, query))
+            .await;
+    }
+
+    /// Delete the named filter. Returns whether one existed.
+    pub async fn delete_filter(&self, name: &str) -> bool {
+        let mut removed = false;
+        self.update(|p| {
+            let before = p.saved_filters.len();
+            p.saved_filters
+                .retain(|f| !f.name.eq_ignore_ascii_case(name));
+            removed = p.saved_filters.len() != before;
+        })
+        .await;
+        removed
+    }
+}
+
+/// Save `query` as the filter `name`, replacing an existing filter of that
+/// name (compared case-insensitively) in place.
+fn upsert_saved_filter(filters: &mut Vec<SavedFilter>, name: &str, query: &str) {
+    let filter = SavedFilter {
+        name: name.to_string(),
+        query: query.to_string(),
+    };
+    match filters
+        .iter_mut()
+        .find(|f| f.name.eq_ignore_ascii_case(name))
+    {
+        Some(existing) => *existing = filter,
+        None => filters.push(filter),
+    }
+}
+
+/// Put `prompt` at the front of `history`, dropping an earlier copy of it and
+/// anything past [`PROMPT_HISTORY_LEN`]. Blank prompts aren't recorded.
+fn push_recent_prompt(history: &mut Vec<String>, prompt: &str) {
+    if prompt.trim().is_empty() {
+        return;
+    }
+    history.retain(|p| p != prompt);
+    history.insert(0, prompt.to_string());
+    history.truncate(PROMPT_HISTORY_LEN);
 }
 
 /// Read `tui.json`, falling back to defaults (with a warning) on any read/parse
@@ -389,4 +481,56 @@ mod tests {
         let prefs = TuiPrefsStore::load(dir.path()).prefs();
         assert_eq!(prefs.left_pane_pct, Some(42));
     }
+
+    #[tokio::test]
+    async fn prompt_history_is_newest_first_deduplicated_and_persisted() {
+        let dir = TempDir::new().unwrap();
+        {
+            let store = TuiPrefsStore::load(dir.path());
+            store.record_prompt("fix the flaky test").await;
+            store.record_prompt("add a changelog entry").await;
+            store.record_prompt("   ").await;
+            store.record_prompt("fix the flaky test").await;
+        }
+        assert_eq!(
+            TuiPrefsStore::load(dir.path()).prefs().prompt_history,
+            vec!["fix the flaky test", "add a changelog entry"]
+        );
+
+        let mut history: Vec<String> = (0..PROMPT_HISTORY_LEN).map(|i| i.to_string()).collect();
+        push_recent_prompt(&mut history, "new");
+        assert_eq!(history.len(), PROMPT_HISTORY_LEN);
+        assert_eq!(history[0], "new");
+    }
+
+    #[tokio::test]
+    async fn saved_filters_replace_by_name_and_persist() {
+        let dir = TempDir::new().unwrap();
+        {
+            let store = TuiPrefsStore::load(dir.path());
+            store.save_filter("Waiting", "state:waiting").await;
+            store.save_filter("stale", "age:>7d").await;
+            store.save_filter("waiting", "state:waiting unread").await;
+        }
+        let store = TuiPrefsStore::load(dir.path());
+        assert_eq!(
+            store.prefs().saved_filters,
+            vec![
+                SavedFilter {
+                    name: "waiting".to_string(),
+                    query: "state:waiting unread".to_string(),
+                },
+                SavedFilter {
+                    name: "stale".to_string(),
+                    query: "age:>7d".to_string(),
+                },
+            ]
+        );
+        assert!(store.delete_filter("STALE").await);
+        assert!(!store.delete_filter("stale").await);
+        assert_eq!(
+            TuiPrefsStore::load(dir.path()).prefs().saved_filters.len(),
+            1
+        );
+    }
 }
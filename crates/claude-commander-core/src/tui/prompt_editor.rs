@@ -0,0 +1,323 @@
+//! Multi-line text editor backing the New Session dialog's initial-prompt
+//! field.
+//!
+//! `tui-input` is single-line (its cursor math and word motions assume one
+//! row), so the prompt keeps its own line buffer: a `Vec<String>` of logical
+//! lines plus a `(row, col)` cursor counted in chars. Rendering wraps nothing —
+//! the dialog scrolls the rows vertically and clips long lines — which keeps
+//! cursor placement a plain row/column lookup.
+//!
+//! Recent prompts are browsed with [`PromptEditor::history_prev`] /
+//! [`PromptEditor::history_next`]; the text being typed is parked while
+//! browsing and comes back when the user steps past the newest entry.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Multi-line prompt buffer with cursor and recent-prompt history.
+///
+/// Invariant: `lines` is never empty, `row < lines.len()`, and
+/// `col <= lines[row].chars().count()`.
+#[derive(Debug, Clone)]
+pub struct PromptEditor {
+    lines: Vec<String>,
+    row: usize,
+    col: usize,
+    /// Recent prompts, newest first (a snapshot taken when the dialog opened).
+    history: Vec<String>,
+    /// Index into `history` of the entry shown, or `None` while editing the
+    /// user's own text.
+    history_idx: Option<usize>,
+    /// The user's own text, parked while a history entry is shown.
+    parked: Option<String>,
+}
+
+impl Default for PromptEditor {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl PromptEditor {
+    /// An empty editor that can recall `history` (newest first).
+    pub fn new(history: Vec<String>) -> Self {
+        Self {
+            lines: vec![String::new()],
+            row: 0,
+            col: 0,
+            history,
+            history_idx: None,
+            parked: None,
+        }
+    }
+
+    /// The full text, lines joined with `\n`.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Whether the prompt holds nothing but whitespace.
+    pub fn is_blank(&self) -> bool {
+        self.lines.iter().all(|l| l.trim().is_empty())
+    }
+
+    /// The logical lines, for rendering.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// The cursor as `(row, col)`, with `col` counted in chars.
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    /// Replace the whole buffer (e.g. with the file `$EDITOR` wrote), leaving
+    /// the cursor at the end. A single trailing newline — which most editors
+    /// add on save — is dropped.
+    pub fn set_text(&mut self, text: &str) {
+        let text = text.replace('\r', "");
+        let text = text.strip_suffix('\n').unwrap_or(&text);
+        self.lines = text.split('\n').map(str::to_string).collect();
+        self.row = self.lines.len() - 1;
+        self.col = self.lines[self.row].chars().count();
+    }
+
+    /// Insert `text` at the cursor, splitting lines on `\n`. Used for typed
+    /// characters and bracketed paste alike; CRs from CRLF clipboards are
+    /// dropped.
+    pub fn insert_str(&mut self, text: &str) {
+        for c in text.chars().filter(|&c| c != '\r') {
+            if c == '\n' {
+                self.newline();
+            } else {
+                self.insert_char(c);
+            }
+        }
+    }
+
+    fn insert_char(&mut self, c: char) {
+        let at = self.byte_offset();
+        self.lines[self.row].insert(at, c);
+        self.col += 1;
+    }
+
+    fn newline(&mut self) {
+        let at = self.byte_offset();
+        let rest = self.lines[self.row].split_off(at);
+        self.lines.insert(self.row + 1, rest);
+        self.row += 1;
+        self.col = 0;
+    }
+
+    fn backspace(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+            let at = self.byte_offset();
+            self.lines[self.row].remove(at);
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.col = self.line_len();
+            self.lines[self.row].push_str(&line);
+        }
+    }
+
+    fn delete(&mut self) {
+        if self.col < self.line_len() {
+            let at = self.byte_offset();
+            self.lines[self.row].remove(at);
+        } else if self.row + 1 < self.lines.len() {
+            let next = self.lines.remove(self.row + 1);
+            self.lines[self.row].push_str(&next);
+        }
+    }
+
+    /// Move the cursor up a line. Returns `false` when already on the first
+    /// line, so the caller can move focus out of the field instead.
+    pub fn move_up(&mut self) -> bool {
+        if self.row == 0 {
+            return false;
+        }
+        self.row -= 1;
+        self.col = self.col.min(self.line_len());
+        true
+    }
+
+    /// Move the cursor down a line. Returns `false` when already on the last
+    /// line.
+    pub fn move_down(&mut self) -> bool {
+        if self.row + 1 >= self.lines.len() {
+            return false;
+        }
+        self.row += 1;
+        self.col = self.col.min(self.line_len());
+        true
+    }
+
+    /// Show the next-older history entry. Returns `false` when there is none.
+    pub fn history_prev(&mut self) -> bool {
+        let next = self.history_idx.map_or(0, |i| i + 1);
+        let Some(entry) = self.history.get(next).cloned() else {
+            return false;
+        };
+        if self.history_idx.is_none() {
+            self.parked = Some(self.text());
+        }
+        self.history_idx = Some(next);
+        self.set_text(&entry);
+        true
+    }
+
+    /// Show the next-newer history entry, or the parked draft after the newest.
+    /// Returns `false` when not browsing history.
+    pub fn history_next(&mut self) -> bool {
+        match self.history_idx {
+            None => false,
+            Some(0) => {
+                self.history_idx = None;
+                let parked = self.parked.take().unwrap_or_default();
+                self.set_text(&parked);
+                true
+            }
+            Some(i) => {
+                self.history_idx = Some(i - 1);
+                let entry = self.history[i - 1].clone();
+                self.set_text(&entry);
+                true
+            }
+        }
+    }
+
+    /// Apply an editing key: characters, Enter (new line), Backspace/Delete,
+    /// ←/→, Home/End, and the Emacs-style Ctrl-a/Ctrl-e/Ctrl-u/Ctrl-k line
+    /// motions. ↑/↓ and history are left to the caller, which decides whether
+    /// they move within the text or out of the field. Returns whether the key
+    /// was consumed.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('a') if ctrl => self.col = 0,
+            KeyCode::Char('e') if ctrl => self.col = self.line_len(),
+            KeyCode::Char('u') if ctrl => {
+                let at = self.byte_offset();
+                self.lines[self.row].replace_range(..at, "");
+                self.col = 0;
+            }
+            KeyCode::Char('k') if ctrl => {
+                let at = self.byte_offset();
+                self.lines[self.row].truncate(at);
+            }
+            KeyCode::Char(c) if !ctrl && !key.modifiers.contains(KeyModifiers::ALT) => {
+                self.insert_char(c)
+            }
+            KeyCode::Enter => self.newline(),
+            KeyCode::Backspace => self.backspace(),
+            KeyCode::Delete => self.delete(),
+            KeyCode::Left if self.col > 0 => self.col -= 1,
+            KeyCode::Left if self.row > 0 => {
+                self.row -= 1;
+                self.col = self.line_len();
+            }
+            KeyCode::Right if self.col < self.line_len() => self.col += 1,
+            KeyCode::Right if self.row + 1 < self.lines.len() => {
+                self.row += 1;
+                self.col = 0;
+            }
+            KeyCode::Left | KeyCode::Right => {}
+            KeyCode::Home => self.col = 0,
+            KeyCode::End => self.col = self.line_len(),
+            _ => return false,
+        }
+        // Any edit makes the shown text the user's own again.
+        self.history_idx = None;
+        self.parked = None;
+        true
+    }
+
+    fn line_len(&self) -> usize {
+        self.lines[self.row].chars().count()
+    }
+
+    fn byte_offset(&self) -> usize {
+        let line = &self.lines[self.row];
+        line.char_indices()
+            .nth(self.col)
+            .map_or(line.len(), |(i, _)| i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn typed(text: &str) -> PromptEditor {
+        let mut ed = PromptEditor::default();
+        ed.insert_str(text);
+        ed
+    }
+
+    #[test]
+    fn enter_splits_and_backspace_joins_lines() {
+        let mut ed = typed("fix the bug");
+        for _ in 0..4 {
+            ed.handle_key(key(KeyCode::Left));
+        }
+        ed.handle_key(key(KeyCode::Enter));
+        assert_eq!(ed.text(), "fix the\n bug");
+        assert_eq!(ed.cursor(), (1, 0));
+        ed.handle_key(key(KeyCode::Backspace));
+        assert_eq!(ed.text(), "fix the bug");
+        assert_eq!(ed.cursor(), (0, 7));
+    }
+
+    #[test]
+    fn paste_keeps_newlines_and_drops_carriage_returns() {
+        let mut ed = typed("» ");
+        ed.insert_str("a\r\nb\r\n");
+        assert_eq!(ed.text(), "» a\nb\n");
+        assert_eq!(ed.lines().len(), 3);
+        assert_eq!(ed.cursor(), (2, 0));
+    }
+
+    #[test]
+    fn vertical_moves_clamp_column_and_report_edges() {
+        let mut ed = typed("long first line\nab");
+        assert!(!ed.move_down());
+        assert!(ed.move_up());
+        assert_eq!(ed.cursor(), (0, 2));
+        ed.handle_key(key(KeyCode::End));
+        assert!(ed.move_down());
+        assert_eq!(ed.cursor(), (1, 2));
+        assert!(ed.move_up());
+        assert!(!ed.move_up());
+    }
+
+    #[test]
+    fn history_browsing_parks_and_restores_the_draft() {
+        let mut ed = PromptEditor::new(vec!["newest".into(), "older".into()]);
+        ed.insert_str("draft");
+        assert!(ed.history_prev());
+        assert_eq!(ed.text(), "newest");
+        assert!(ed.history_prev());
+        assert_eq!(ed.text(), "older");
+        assert!(!ed.history_prev());
+        assert!(ed.history_next());
+        assert!(ed.history_next());
+        assert_eq!(ed.text(), "draft");
+        assert!(!ed.history_next());
+    }
+
+    #[test]
+    fn set_text_drops_one_trailing_newline() {
+        let mut ed = PromptEditor::default();
+        ed.set_text("line one\nline two\n");
+        assert_eq!(ed.text(), "line one\nline two");
+        assert_eq!(ed.cursor(), (1, 8));
+        ed.set_text("");
+        assert!(ed.is_blank());
+        assert_eq!(ed.cursor(), (0, 0));
+    }
+}
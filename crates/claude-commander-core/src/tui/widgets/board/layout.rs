@@ -112,6 +112,21 @@ pub fn card_line_ranges(card_row_counts: &[usize]) -> Vec<Range<usize>> {
     ranges
 }
 
+/// Compute the display-line range each swimlane occupies, stacked vertically:
+/// one heading line, then (unless the lane is collapsed, i.e. has no cards) a
+/// band as tall as the lane's tallest column slice. `lane_card_counts[i]` is
+/// that slice's card count; every card is one session row, so three lines.
+pub fn lane_line_ranges(lane_card_counts: &[usize]) -> Vec<Range<usize>> {
+    let mut ranges = Vec::with_capacity(lane_card_counts.len());
+    let mut start = 0;
+    for &cards in lane_card_counts {
+        let height = 1 + cards * 3;
+        ranges.push(start..start + height);
+        start += height;
+    }
+    ranges
+}
+
 /// Minimal scroll offset (in display lines) so the selected row is visible in a
 /// `viewport_h`-line viewport.
 ///
@@ -303,6 +318,13 @@ mod tests {
         assert!(card_line_ranges(&[]).is_empty());
     }
 
+    #[test]
+    fn lane_line_ranges_stack_a_heading_over_each_band() {
+        // A lane of 2 cards is 1 + 6 lines; a collapsed lane is its heading.
+        let ranges = lane_line_ranges(&[2, 0, 1]);
+        assert_eq!(ranges, vec![0..7, 7..8, 8..12]);
+    }
+
     // --- ensure_visible --------------------------------------------------
 
     #[test]
@@ -37,14 +37,16 @@ use ratatui::{
 };
 
 use crate::git::BlockReason;
-use crate::session::{Board, BoardPos, ProjectId, SessionId, SessionListItem};
+use crate::session::{
+    Board, BoardCard, BoardLane, BoardPos, ProjectId, SessionId, SessionListItem,
+};
 use crate::tui::theme::Theme;
 use crate::tui::widgets::{pr_colors, status_glyph};
 
 use super::layout::{self, BoardRects};
 use super::state::BoardState;
 
-use status_glyph::{COMMENT_MARKER, KEEP_ALIVE_MARKER, LFS_MARKER};
+use status_glyph::{COMMENT_MARKER, KEEP_ALIVE_MARKER, LFS_MARKER, VIEWERS_MARKER};
 
 /// Horizontal shift (and width reduction) applied to a stacked child's card so
 /// it reads as nested one level under the base card directly above it.
@@ -113,6 +115,9 @@ pub struct BoardRenderOutput {
     /// server's Settings → Programs tab (the whole heading row, including its
     /// `⚙` affordance, is the target).
     pub heading_regions: Vec<(Rect, crate::backend::BackendId)>,
+    /// One region per visible swimlane heading, with the lane's index in
+    /// [`Board::lanes`]; a click folds or unfolds the lane.
+    pub lane_regions: Vec<(Rect, usize)>,
     pub rects: BoardRects,
 }
 
@@ -127,6 +132,7 @@ pub struct BoardWidget<'a> {
     invert_pr_label_color: bool,
     show_session_program: bool,
     comment_sessions: Option<&'a HashSet<SessionId>>,
+    viewers: Option<&'a HashMap<SessionId, u32>>,
     pull_blocked_projects: Option<&'a HashMap<ProjectId, BlockReason>>,
     project_colors: Option<&'a HashMap<ProjectId, (Color, Color)>>,
     /// Precomputed column-major session numbering (id → 1-based number), built
@@ -151,6 +157,7 @@ impl<'a> BoardWidget<'a> {
             invert_pr_label_color: false,
             show_session_program: true,
             comment_sessions: None,
+            viewers: None,
             pull_blocked_projects: None,
             project_colors: None,
             session_numbers: None,
@@ -192,6 +199,12 @@ impl<'a> BoardWidget<'a> {
         self
     }
 
+    /// Attached-client count per session (renders `◉N` on viewed sessions).
+    pub fn session_viewers(mut self, viewers: &'a HashMap<SessionId, u32>) -> Self {
+        self.viewers = Some(viewers);
+        self
+    }
+
     /// Mark projects whose most recent auto-pull was held back (renders a `⚠` on
     /// the card border title and the sidebar entry). Borrows the app's
     /// `project_pull_blocked` map directly — only membership matters here, so
@@ -260,6 +273,7 @@ impl<'a> BoardWidget<'a> {
         let mut hit_regions = Vec::new();
         let mut button_regions = Vec::new();
         let mut heading_regions = Vec::new();
+        let mut lane_regions = Vec::new();
         let n_cols = self.board.columns.len();
         let rects = layout::column_rects(area, n_cols);
 
@@ -268,6 +282,7 @@ impl<'a> BoardWidget<'a> {
                 hit_regions,
                 button_regions,
                 heading_regions,
+                lane_regions,
                 rects,
             };
         }
@@ -279,8 +294,6 @@ impl<'a> BoardWidget<'a> {
             state.scroll.resize(n_cols + 1, 0);
         }
 
-        let numbers = self.session_numbers;
-        let show_program = self.show_session_program && self.mixed_programs;
         let selected = self.selected;
 
         // Thin vertical separators (sidebar/column and column/column dividers).
@@ -303,19 +316,29 @@ impl<'a> BoardWidget<'a> {
             &mut heading_regions,
         );
 
-        for (i, column) in self.board.columns.iter().enumerate() {
-            let col_rect = rects.columns[i];
-            self.render_column(
-                i,
-                column,
-                col_rect,
+        if self.board.lanes.is_empty() {
+            for (i, column) in self.board.columns.iter().enumerate() {
+                let col_rect = rects.columns[i];
+                self.render_column(
+                    i,
+                    column,
+                    col_rect,
+                    buf,
+                    state,
+                    selected,
+                    &mut hit_regions,
+                    &mut button_regions,
+                );
+            }
+        } else {
+            self.render_lanes(
+                area,
+                &rects,
                 buf,
                 state,
-                selected,
-                numbers,
-                show_program,
                 &mut hit_regions,
                 &mut button_regions,
+                &mut lane_regions,
             );
         }
 
@@ -323,6 +346,7 @@ impl<'a> BoardWidget<'a> {
             hit_regions,
             button_regions,
             heading_regions,
+            lane_regions,
             rects,
         }
     }
@@ -556,8 +580,6 @@ impl<'a> BoardWidget<'a> {
         buf: &mut Buffer,
         state: &mut BoardState,
         selected: Option<BoardPos>,
-        numbers: Option<&HashMap<SessionId, usize>>,
-        show_program: bool,
         hit_regions: &mut Vec<BoardHitRegion>,
         button_regions: &mut Vec<BoardButtonRegion>,
     ) {
@@ -565,7 +587,7 @@ impl<'a> BoardWidget<'a> {
             return;
         }
 
-        self.render_column_header(column, rect, buf);
+        self.render_column_header(col_idx, column, rect, buf);
         if rect.height <= 1 {
             return; // only the header fits
         }
@@ -597,130 +619,344 @@ impl<'a> BoardWidget<'a> {
         sc = sc.min(total_lines.saturating_sub(viewport_h));
         state.scroll[addr_col] = sc;
 
-        let content_top = content.y as isize;
-        let content_bottom = (content.y + content.height) as isize;
+        let column_buttons_fit = self.column_buttons_fit(column, rect.width);
+        let clip = (content.y as isize, (content.y + content.height) as isize);
+        for (row, card) in column.cards.iter().enumerate() {
+            let range = &ranges[row];
+            let card_top = clip.0 + range.start as isize - sc as isize;
+            self.render_card(
+                card,
+                BoardPos { col: addr_col, row },
+                card_top,
+                (range.end - range.start) as u16,
+                clip,
+                rect,
+                buf,
+                column_buttons_fit,
+                hit_regions,
+                button_regions,
+            );
+        }
 
-        // Buttons show/hide uniformly per column, sized to the NARROWEST card
-        // (an indented stacked child). Deciding per card would let a base card
-        // keep its buttons while its own child loses them at borderline
-        // widths — a confusing asymmetry within one stack.
-        let column_min_inner = rect.width.saturating_sub(
+        // An empty column has no session cards to click, yet the keyboard can
+        // land on it (row 0 — the header position). Mirror that for the mouse:
+        // map any click in the empty lane to the column's row-0 position.
+        // Emitted only for empty columns, so a populated column's
+        // below-last-card space keeps selecting nothing and never shadows a
+        // real row region.
+        if column.cards.is_empty() {
+            hit_regions.push(BoardHitRegion {
+                rect: content,
+                pos: BoardPos {
+                    col: addr_col,
+                    row: 0,
+                },
+            });
+        }
+    }
+
+    /// Whether a column's cards have room for their action buttons.
+    ///
+    /// Buttons show/hide uniformly per column, sized to the NARROWEST card
+    /// (an indented stacked child). Deciding per card would let a base card
+    /// keep its buttons while its own child loses them at borderline
+    /// widths — a confusing asymmetry within one stack.
+    fn column_buttons_fit(&self, column: &crate::session::BoardColumn, width: u16) -> bool {
+        let column_min_inner = width.saturating_sub(
             2 + if column.cards.iter().any(|c| c.indent) {
                 CHILD_INDENT
             } else {
                 0
             },
         );
-        let column_buttons_fit =
-            column_min_inner as usize > card_buttons_width() + (2 * CARD_PAD) as usize;
+        column_min_inner as usize > card_buttons_width() + (2 * CARD_PAD) as usize
+    }
 
-        for (row, card) in column.cards.iter().enumerate() {
-            let range = &ranges[row];
-            let card_top = content_top + range.start as isize - sc as isize;
-            let card_h = (range.end - range.start) as u16;
-            let card_bottom = card_top + card_h as isize;
-
-            // Visible slice of this card, clipped to the lane's content area.
-            let vis_top = card_top.max(content_top);
-            let vis_bottom = card_bottom.min(content_bottom);
-            if vis_bottom <= vis_top {
-                continue;
+    /// Render one card whose box starts at `card_top` (possibly above or below
+    /// the visible area), clipped to the `clip` line span, in column `rect`.
+    #[allow(clippy::too_many_arguments)]
+    fn render_card(
+        &self,
+        card: &BoardCard,
+        pos: BoardPos,
+        card_top: isize,
+        card_h: u16,
+        clip: (isize, isize),
+        rect: Rect,
+        buf: &mut Buffer,
+        buttons_fit: bool,
+        hit_regions: &mut Vec<BoardHitRegion>,
+        button_regions: &mut Vec<BoardButtonRegion>,
+    ) {
+        let (content_top, content_bottom) = clip;
+        let card_bottom = card_top + card_h as isize;
+
+        // Visible slice of this card, clipped to the lane's content area.
+        let vis_top = card_top.max(content_top);
+        let vis_bottom = card_bottom.min(content_bottom);
+        if vis_bottom <= vis_top {
+            return;
+        }
+
+        // Stacked children shift right and narrow by CHILD_INDENT so they
+        // read as nested under their base.
+        let indent = if card.indent { CHILD_INDENT } else { 0 };
+        let box_x = rect.x + indent.min(rect.width);
+        let box_w = rect.width.saturating_sub(indent);
+        let visible_rect = Rect {
+            x: box_x,
+            y: vis_top as u16,
+            width: box_w,
+            height: (vis_bottom - vis_top) as u16,
+        };
+
+        let SessionListItem::Worktree { id, .. } = &card.row else {
+            unreachable!("board rows are always Worktree")
+        };
+        let number = self
+            .session_numbers
+            .and_then(|m| m.get(id))
+            .copied()
+            .unwrap_or(0);
+        let (border_color, _) = self.project_color(card.project_id);
+        self.render_card_border(&card.row, visible_rect, buf, number, border_color);
+
+        // The interior line only renders when strictly inside the visible
+        // box's top/bottom borders.
+        let y = card_top + 1;
+        if y > vis_top && y < vis_bottom - 1 {
+            let yy = y as u16;
+            let inner_x = box_x + 1;
+            let inner_w = box_w.saturating_sub(2);
+            self.render_card_interior(
+                &card.row,
+                yy,
+                inner_x,
+                inner_w,
+                buf,
+                self.show_session_program && self.mixed_programs,
+                self.selected == Some(pos),
+                pos,
+                buttons_fit,
+                button_regions,
+            );
+            hit_regions.push(BoardHitRegion {
+                rect: Rect {
+                    x: rect.x,
+                    y: yy,
+                    width: rect.width,
+                    height: 1,
+                },
+                pos,
+            });
+        }
+    }
+
+    /// Render the section columns split into swimlanes. Column headers stay on
+    /// the first line; below them the lanes stack top to bottom — a heading
+    /// over a band of cards aligned across the columns — and scroll together
+    /// via [`BoardState::lane_scroll`] so a lane's band lines up in every
+    /// column.
+    #[allow(clippy::too_many_arguments)]
+    fn render_lanes(
+        &self,
+        area: Rect,
+        rects: &BoardRects,
+        buf: &mut Buffer,
+        state: &mut BoardState,
+        hit_regions: &mut Vec<BoardHitRegion>,
+        button_regions: &mut Vec<BoardButtonRegion>,
+        lane_regions: &mut Vec<(Rect, usize)>,
+    ) {
+        for (i, column) in self.board.columns.iter().enumerate() {
+            let rect = rects.columns[i];
+            if rect.width > 0 {
+                self.render_column_header(i, column, rect, buf);
             }
+        }
+        let Some(first) = rects.columns.first() else {
+            return;
+        };
+        if area.height <= 1 {
+            return; // only the headers fit
+        }
+        let body = Rect {
+            x: first.x,
+            y: area.y + 1,
+            width: (area.x + area.width).saturating_sub(first.x),
+            height: area.height - 1,
+        };
+        let viewport_h = body.height as usize;
 
-            // Stacked children shift right and narrow by CHILD_INDENT so they
-            // read as nested under their base.
-            let indent = if card.indent { CHILD_INDENT } else { 0 };
-            let box_x = content.x + indent.min(rect.width);
-            let box_w = rect.width.saturating_sub(indent);
-            let visible_rect = Rect {
-                x: box_x,
-                y: vis_top as u16,
-                width: box_w,
-                height: (vis_bottom - vis_top) as u16,
-            };
+        // A lane's band is as tall as its busiest column.
+        let tallest: Vec<usize> = self
+            .board
+            .lanes
+            .iter()
+            .map(|l| l.rows.iter().map(|r| r.len()).max().unwrap_or(0))
+            .collect();
+        let ranges = layout::lane_line_ranges(&tallest);
 
-            let SessionListItem::Worktree { id, .. } = &card.row else {
-                unreachable!("board rows are always Worktree")
+        // Scroll so the selected card is visible; a lane's first card brings
+        // the lane heading along with it.
+        let mut sc = state.lane_scroll;
+        if let Some(pos) = self.selected
+            && let Some(li) = self.board.lane_at(pos)
+        {
+            let offset = pos.row - self.board.lanes[li].rows[pos.col - 1].start;
+            let card_top = ranges[li].start + 1 + 3 * offset;
+            let from = if offset == 0 {
+                ranges[li].start
+            } else {
+                card_top
             };
-            let number = numbers.and_then(|m| m.get(id)).copied().unwrap_or(0);
-            let (border_color, _) = self.project_color(card.project_id);
-            self.render_card_border(&card.row, visible_rect, buf, number, border_color);
-
-            // The interior line only renders when strictly inside the visible
-            // box's top/bottom borders.
-            let y = card_top + 1;
-            if y > vis_top && y < vis_bottom - 1 {
-                let yy = y as u16;
-                let inner_x = box_x + 1;
-                let inner_w = box_w.saturating_sub(2);
-                let pos = BoardPos { col: addr_col, row };
-                let is_selected = sel_row == Some(row);
-                self.render_card_interior(
-                    &card.row,
-                    yy,
-                    inner_x,
-                    inner_w,
-                    buf,
-                    show_program,
-                    is_selected,
-                    pos,
-                    column_buttons_fit,
-                    button_regions,
-                );
+            sc = layout::ensure_visible(sc, &[from..card_top + 3], 0, 0, viewport_h);
+        }
+        let total_lines = ranges.last().map(|r| r.end).unwrap_or(0);
+        sc = sc.min(total_lines.saturating_sub(viewport_h));
+        state.lane_scroll = sc;
+
+        let clip = (body.y as isize, (body.y + body.height) as isize);
+        let buttons_fit: Vec<bool> = self
+            .board
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| self.column_buttons_fit(c, rects.columns[i].width))
+            .collect();
+        for (li, lane) in self.board.lanes.iter().enumerate() {
+            let lane_top = clip.0 + ranges[li].start as isize - sc as isize;
+            if lane_top >= clip.1 {
+                break;
+            }
+            if lane_top >= clip.0 {
+                let heading = Rect {
+                    x: body.x,
+                    y: lane_top as u16,
+                    width: body.width,
+                    height: 1,
+                };
+                self.render_lane_heading(lane, rects, heading.y, buf);
+                lane_regions.push((heading, li));
+            }
+            for (ci, column) in self.board.columns.iter().enumerate() {
+                let rect = rects.columns[ci];
+                if rect.width == 0 {
+                    continue;
+                }
+                for (k, row) in lane.rows[ci].clone().enumerate() {
+                    self.render_card(
+                        &column.cards[row],
+                        BoardPos { col: ci + 1, row },
+                        lane_top + 1 + 3 * k as isize,
+                        3,
+                        clip,
+                        rect,
+                        buf,
+                        buttons_fit[ci],
+                        hit_regions,
+                        button_regions,
+                    );
+                }
+            }
+        }
+
+        // Empty columns stay clickable, as in the lane-less layout.
+        for (ci, column) in self.board.columns.iter().enumerate() {
+            if column.cards.is_empty() {
                 hit_regions.push(BoardHitRegion {
                     rect: Rect {
-                        x: rect.x,
-                        y: yy,
-                        width: rect.width,
-                        height: 1,
+                        x: rects.columns[ci].x,
+                        y: body.y,
+                        width: rects.columns[ci].width,
+                        height: body.height,
+                    },
+                    pos: BoardPos {
+                        col: ci + 1,
+                        row: 0,
                     },
-                    pos,
                 });
             }
         }
+    }
 
-        // An empty column has no session cards to click, yet the keyboard can
-        // land on it (row 0 — the header position). Mirror that for the mouse:
-        // map any click in the empty lane to the column's row-0 position.
-        // Emitted only for empty columns, so a populated column's
-        // below-last-card space keeps selecting nothing and never shadows a
-        // real row region.
-        if column.cards.is_empty() {
-            hit_regions.push(BoardHitRegion {
-                rect: content,
-                pos: BoardPos {
-                    col: addr_col,
-                    row: 0,
-                },
-            });
+    /// Render a swimlane heading: a rule across the columns with the fold
+    /// marker, lane name and total over the first column, and the lane's
+    /// count (WIP-coloured against the column's per-lane limit) at the right
+    /// of each column.
+    fn render_lane_heading(&self, lane: &BoardLane, rects: &BoardRects, y: u16, buf: &mut Buffer) {
+        let rule = Style::default().fg(self.theme.border_unfocused);
+        for (ci, column) in self.board.columns.iter().enumerate() {
+            let rect = rects.columns[ci];
+            if rect.width == 0 {
+                continue;
+            }
+            buf.set_string(rect.x, y, "─".repeat(rect.width as usize), rule);
+            let limit = column.lane_max_sessions;
+            let count = lane.counts.get(ci).copied().unwrap_or(0);
+            let text = match limit {
+                Some(limit) => format!(" {}/{} ", count, limit),
+                None => format!(" {} ", count),
+            };
+            let w = text.chars().count() as u16;
+            if w < rect.width {
+                let color = self.wip_color(count, limit);
+                buf.set_string(rect.x + rect.width - w, y, text, Style::default().fg(color));
+            }
+        }
+        let Some(first) = rects.columns.first() else {
+            return;
+        };
+        let marker = if lane.collapsed { "▸" } else { "▾" };
+        let line = Line::from(vec![
+            Span::styled(
+                format!("{} {}", marker, lane.name),
+                Style::default()
+                    .fg(self.theme.text_accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(" ({}) ", lane.total()),
+                Style::default().fg(self.theme.text_secondary),
+            ),
+        ]);
+        // Leave the first column's own count visible.
+        let count = lane.counts.first().copied().unwrap_or(0);
+        let count_w = match self.board.columns.first().and_then(|c| c.lane_max_sessions) {
+            Some(limit) => format!(" {}/{} ", count, limit),
+            None => format!(" {} ", count),
+        }
+        .chars()
+        .count() as u16;
+        buf.set_line(first.x, y, &line, first.width.saturating_sub(count_w));
+    }
+
+    /// Advisory WIP colouring: over the limit reads as an error, exactly at
+    /// it as a warning, under it (or unlimited) as normal.
+    fn wip_color(&self, count: usize, limit: Option<u32>) -> Color {
+        match limit.map(|l| l as usize) {
+            Some(limit) if count > limit => self.theme.modal_error,
+            Some(limit) if count == limit => self.theme.modal_warning,
+            _ => self.theme.text_secondary,
         }
     }
 
     /// Render a column's header line: `Name (count)` or `(count/max)` in the
-    /// warning colour at/over the WIP limit.
+    /// warning colour at/over the WIP limit. Sessions folded away in a
+    /// collapsed swimlane still count.
     fn render_column_header(
         &self,
+        col_idx: usize,
         column: &crate::session::BoardColumn,
         rect: Rect,
         buf: &mut Buffer,
     ) {
-        let count: usize = column.cards.len();
-        let (count_text, count_color) = match column.max_sessions {
-            Some(limit) => {
-                // Advisory WIP colouring: over the limit reads as an error,
-                // exactly at it as a warning, under it as normal.
-                let limit = limit as usize;
-                let color = if count > limit {
-                    self.theme.modal_error
-                } else if count == limit {
-                    self.theme.modal_warning
-                } else {
-                    self.theme.text_secondary
-                };
-                (format!(" ({}/{})", count, limit), color)
-            }
-            None => (format!(" ({})", count), self.theme.text_secondary),
+        let count = self.board.column_total(col_idx);
+        let count_text = match column.max_sessions {
+            Some(limit) => format!(" ({}/{})", count, limit),
+            None => format!(" ({})", count),
         };
+        let count_color = self.wip_color(count, column.max_sessions);
         let line = Line::from(vec![
             Span::styled(
                 column.name.clone(),
@@ -812,6 +1048,8 @@ impl<'a> BoardWidget<'a> {
             unread,
             keep_alive,
             lfs_pulling,
+            task_status,
+            tags,
             ..
         } = item
         else {
@@ -849,6 +1087,25 @@ impl<'a> BoardWidget<'a> {
                 Style::default().fg(self.theme.text_accent),
             ));
         }
+        if let Some(n) = self.viewers.and_then(|v| v.get(id)) {
+            spans.push(Span::styled(
+                format!(" {VIEWERS_MARKER}{n}"),
+                Style::default().fg(self.theme.text_secondary),
+            ));
+        }
+        if let Some(task_status) = task_status {
+            let (glyph, color) = status_glyph::task_badge(self.theme, *task_status);
+            spans.push(Span::styled(
+                format!(" {glyph} tasks"),
+                Style::default().fg(color),
+            ));
+        }
+        for tag in tags {
+            spans.push(Span::styled(
+                format!(" #{tag}"),
+                Style::default().fg(self.theme.text_secondary),
+            ));
+        }
         if let Some(shown_branch) = crate::session::display_branch(title, branch) {
             spans.push(Span::styled(
                 format!(" [{}]", shown_branch),
@@ -1010,6 +1267,8 @@ mod tests {
             unread: false,
             keep_alive: false,
             lfs_pulling: false,
+            task_status: None,
+            tags: Vec::new(),
             stacked_child,
         }
     }
@@ -1040,6 +1299,7 @@ mod tests {
         BoardColumn {
             name: name.to_string(),
             max_sessions: max,
+            lane_max_sessions: None,
             cards,
         }
     }
@@ -1137,6 +1397,7 @@ mod tests {
                 None,
                 vec![card(pid, wt(pid, "feature", false))],
             )],
+            lanes: vec![],
         };
         let (buf, out) = render(&board, 80, 20, None);
 
@@ -1187,6 +1448,7 @@ mod tests {
                 ),
                 column("Open", None, vec![card(pid, wt(pid, "c", false))]),
             ],
+            lanes: vec![],
         };
         let (buf, out) = render(&board, 90, 20, None);
 
@@ -1218,6 +1480,7 @@ mod tests {
                     card(pid, wt(pid, "b", false)),
                 ],
             )],
+            lanes: vec![],
         };
         let (buf, out) = render(&board, 60, 20, None);
 
@@ -1246,6 +1509,7 @@ mod tests {
                 Some(5),
                 vec![card(pid, wt(pid, "a", false))],
             )],
+            lanes: vec![],
         };
         let (buf, out) = render(&board, 60, 20, None);
         let col = out.rects.columns[0];
@@ -1271,6 +1535,7 @@ mod tests {
                 // Two separate cards now: the base, then its indented child.
                 vec![card(pid, base), card(pid, child)],
             )],
+            lanes: vec![],
         };
         let (buf, out) = render(&board, 80, 20, None);
 
@@ -1302,6 +1567,7 @@ mod tests {
                 None,
                 vec![card(pid, wt(pid, "a", false))],
             )],
+            lanes: vec![],
         };
         let sel = Some(BoardPos { col: 1, row: 0 });
         let (buf, out) = render(&board, 80, 20, sel);
@@ -1329,6 +1595,7 @@ mod tests {
                 None,
                 vec![card(pid, wt(pid, "a", false))],
             )],
+            lanes: vec![],
         };
         let sel = Some(BoardPos { col: 0, row: 0 });
         let (buf, out) = render(&board, 80, 20, sel);
@@ -1353,6 +1620,7 @@ mod tests {
                 None,
                 vec![card(a, wt(a, "s", false))],
             )],
+            lanes: vec![],
         };
         let mut blocked: HashMap<ProjectId, BlockReason> = HashMap::new();
         blocked.insert(z, BlockReason::Diverged);
@@ -1397,6 +1665,7 @@ mod tests {
                 ),
                 column("Open", None, vec![card(pid, c)]),
             ],
+            lanes: vec![],
         };
         let (_buf, out) = render(&board, 90, 20, None);
 
@@ -1441,6 +1710,7 @@ mod tests {
             servers: vec![],
             projects: vec![entry(pid, "P", 6)],
             columns: vec![column(crate::session::IN_PROGRESS, None, cards)],
+            lanes: vec![],
         };
         // Select the last row so the viewport scrolls to it.
         let sel = Some(BoardPos { col: 1, row: 5 });
@@ -1475,6 +1745,7 @@ mod tests {
             servers: vec![],
             projects: vec![entry(pid, "P", 20)],
             columns: vec![column(crate::session::IN_PROGRESS, None, cards)],
+            lanes: vec![],
         };
         let sel = Some(BoardPos { col: 1, row: 15 });
 
@@ -1525,6 +1796,7 @@ mod tests {
                 ),
                 column("Open", None, vec![]),
             ],
+            lanes: vec![],
         };
         let (_buf, out) = render(&board, 90, 20, None);
 
@@ -1560,6 +1832,7 @@ mod tests {
                 None,
                 vec![card(pid, wt(pid, "a", false))],
             )],
+            lanes: vec![],
         };
         let (_buf, out) = render(&board, 60, 20, None);
 
@@ -1586,6 +1859,7 @@ mod tests {
                 column(crate::session::IN_PROGRESS, None, vec![]),
                 column("Open", None, vec![]),
             ],
+            lanes: vec![],
         };
         let (buf, out) = render(&board, 90, 12, None);
 
@@ -1612,6 +1886,7 @@ mod tests {
                 column("In Review", None, vec![]),
                 column("Merged", None, vec![]),
             ],
+            lanes: vec![],
         };
         // 20x10 with a 24-wide sidebar leaves near-zero column width.
         let _ = render(&board, 20, 10, Some(BoardPos { col: 1, row: 0 }));
@@ -1655,8 +1930,10 @@ mod tests {
             columns: vec![BoardColumn {
                 name: crate::session::IN_PROGRESS.to_string(),
                 max_sessions: None,
+                lane_max_sessions: None,
                 cards: Vec::new(),
             }],
+            lanes: vec![],
         };
         let theme = Theme::default();
         let mut state = BoardState::default();
@@ -1699,6 +1976,7 @@ mod tests {
                 None,
                 vec![card(pid, wt(pid, "feature", false))],
             )],
+            lanes: vec![],
         };
         let (buf, out) = render(&board, 80, 20, None);
 
@@ -1720,6 +1998,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn card_body_shows_session_tags() {
+        let pid = ProjectId::new();
+        let mut row = wt(pid, "feature", false);
+        if let SessionListItem::Worktree { tags, .. } = &mut row {
+            *tags = vec!["blocked".to_string()];
+        }
+        let board = Board {
+            servers: vec![],
+            projects: vec![entry(pid, "P", 1)],
+            columns: vec![column(
+                crate::session::IN_PROGRESS,
+                None,
+                vec![card(pid, row)],
+            )],
+            lanes: vec![],
+        };
+        let (buf, out) = render(&board, 100, 20, None);
+
+        let col = out.rects.columns[0];
+        let body = text_in_rect(
+            &buf,
+            Rect {
+                x: col.x,
+                y: col.y + 2,
+                width: col.width,
+                height: 1,
+            },
+        );
+        assert!(body.contains("#blocked"), "tag chip on the card: {body}");
+    }
+
     #[test]
     fn card_renders_three_action_buttons_with_hit_regions() {
         let pid = ProjectId::new();
@@ -1731,6 +2041,7 @@ mod tests {
                 None,
                 vec![card(pid, wt(pid, "feature", false))],
             )],
+            lanes: vec![],
         };
         let (buf, out) = render(&board, 80, 20, None);
 
@@ -1774,6 +2085,7 @@ mod tests {
                     card(pid, wt(pid, "b", false)),
                 ],
             )],
+            lanes: vec![],
         };
         let (_buf, out) = render(&board, 80, 20, None);
 
@@ -1813,6 +2125,7 @@ mod tests {
                     card(pid, wt(pid, "child", true)),
                 ],
             )],
+            lanes: vec![],
         };
         let (buf, out) = render(&board, 80, 20, None);
 
@@ -1874,8 +2187,10 @@ mod tests {
             columns: vec![BoardColumn {
                 name: crate::session::IN_PROGRESS.to_string(),
                 max_sessions: None,
+                lane_max_sessions: None,
                 cards: vec![base, child],
             }],
+            lanes: vec![],
         };
         let theme = Theme::default();
         // Width chosen so base inner (col-2) > buttons_w but child inner
@@ -1924,8 +2239,10 @@ mod tests {
             columns: vec![BoardColumn {
                 name: crate::session::IN_PROGRESS.to_string(),
                 max_sessions: None,
+                lane_max_sessions: None,
                 cards: Vec::new(),
             }],
+            lanes: vec![],
         };
         let theme = Theme::default();
         let mut state = BoardState::default();
@@ -1967,8 +2284,10 @@ mod tests {
             columns: vec![BoardColumn {
                 name: crate::session::IN_PROGRESS.to_string(),
                 max_sessions: None,
+                lane_max_sessions: None,
                 cards: vec![card],
             }],
+            lanes: vec![],
         };
         let theme = Theme::default();
         let mut state = BoardState::default();
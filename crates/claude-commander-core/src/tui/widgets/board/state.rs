@@ -9,6 +9,12 @@
 //! sidebar and `col` in `1..=N` are the section columns. `counts[0]` is the
 //! sidebar's project count and `counts[1..]` are the per-column selectable row
 //! counts (the flattened Worktree-row count of each section column).
+//!
+//! On a board split into swimlanes, rows still run straight down each column
+//! (so `j`/`k` step from one lane into the next), while column moves land in
+//! the same lane — see [`sync_lanes`](BoardState::sync_lanes).
+
+use std::ops::Range;
 
 use crate::session::BoardPos;
 
@@ -29,6 +35,13 @@ pub struct BoardState {
     /// project count; `counts[1..]` are the section columns' flattened row
     /// counts. Captured at the last `sync`.
     counts: Vec<usize>,
+    /// Swimlane card ranges, `lanes[lane][column]` with `column` indexing the
+    /// section columns (addressable column `column + 1`). Empty when the board
+    /// has no lanes. Captured at the last [`sync_lanes`](Self::sync_lanes).
+    lanes: Vec<Vec<Range<usize>>>,
+    /// Vertical scroll offset, in display lines, of the swimlane body. Lanes
+    /// line up across columns, so they scroll together rather than per column.
+    pub lane_scroll: usize,
 }
 
 impl BoardState {
@@ -87,6 +100,17 @@ impl BoardState {
         }
     }
 
+    /// Record the board's swimlanes (per lane, its card range in each section
+    /// column — [`Board::lane_rows`](crate::session::Board::lane_rows)) so
+    /// column moves stay in the selected card's lane. Call after
+    /// [`sync`](Self::sync); an empty list turns lane-aware moves off.
+    pub fn sync_lanes(&mut self, lanes: Vec<Vec<Range<usize>>>) {
+        if lanes.is_empty() {
+            self.lane_scroll = 0;
+        }
+        self.lanes = lanes;
+    }
+
     /// Clamp a position to the current `counts`: column into range, then row to
     /// that column's count (0 when the column is empty — the header position).
     fn clamp(&self, pos: BoardPos) -> BoardPos {
@@ -178,7 +202,7 @@ impl BoardState {
                 return;
             }
             let col = (pos.col + 1) % ncols;
-            self.selected = Some(self.land_in_column(col, pos.row));
+            self.selected = Some(self.land_in_column(col, pos));
         }
     }
 
@@ -192,22 +216,56 @@ impl BoardState {
                 return;
             }
             let col = (pos.col + ncols - 1) % ncols;
-            self.selected = Some(self.land_in_column(col, pos.row));
+            self.selected = Some(self.land_in_column(col, pos));
         }
     }
 
-    /// Compute the landing position in `col`, keeping `preferred_row` but
+    /// Compute the landing position in `col` for a move from `from`: in the
+    /// same lane when the board has lanes, otherwise keeping `from.row` but
     /// clamping it to the column's count (row 0 for an empty column).
-    fn land_in_column(&self, col: usize, preferred_row: usize) -> BoardPos {
+    fn land_in_column(&self, col: usize, from: BoardPos) -> BoardPos {
+        if let Some(pos) = self.land_in_lane(col, from) {
+            return pos;
+        }
         let count = self.counts.get(col).copied().unwrap_or(0);
         let row = if count == 0 {
             0
         } else {
-            preferred_row.min(count - 1)
+            from.row.min(count - 1)
         };
         BoardPos { col, row }
     }
 
+    /// The lane-aware landing position in section column `col`: the same
+    /// offset within `from`'s lane (clamped to the lane's slice there), or,
+    /// when that lane has nothing in `col`, the nearest lane that does —
+    /// entering a lane below at its top and a lane above at its bottom.
+    /// `None` without lanes, when either end is the sidebar, or when `col`
+    /// holds no cards at all.
+    fn land_in_lane(&self, col: usize, from: BoardPos) -> Option<BoardPos> {
+        if self.lanes.is_empty() || col == 0 || from.col == 0 {
+            return None;
+        }
+        let slice =
+            |lane: usize, col: usize| self.lanes[lane].get(col - 1).cloned().unwrap_or(0..0);
+        let lane = (0..self.lanes.len()).find(|&l| slice(l, from.col).contains(&from.row))?;
+        let offset = from.row - slice(lane, from.col).start;
+        let mut by_distance: Vec<usize> = (0..self.lanes.len()).collect();
+        by_distance.sort_by_key(|&l| (l.abs_diff(lane), l));
+        by_distance.into_iter().find_map(|l| {
+            let range = slice(l, col);
+            if range.is_empty() {
+                return None;
+            }
+            let row = match l.cmp(&lane) {
+                std::cmp::Ordering::Equal => range.start + offset.min(range.len() - 1),
+                std::cmp::Ordering::Greater => range.start,
+                std::cmp::Ordering::Less => range.end - 1,
+            };
+            Some(BoardPos { col, row })
+        })
+    }
+
     /// Select the first row of the current column. No-op without a selection.
     pub fn select_first(&mut self) {
         if let Some(pos) = self.selected {
@@ -510,6 +568,48 @@ mod tests {
         assert_eq!(state.selected_column(), Some(2));
     }
 
+    // --- swimlanes -------------------------------------------------------
+
+    #[test]
+    fn column_moves_stay_in_the_selected_lane() {
+        let mut state = BoardState::new();
+        // Two lanes: lane 0 has rows 0..2 / 0..1, lane 1 has rows 2..4 / 1..3.
+        state.sync(vec![1, 4, 3]);
+        state.sync_lanes(vec![vec![0..2, 0..1], vec![2..4, 1..3]]);
+        state.select(Some(pos(1, 3)));
+        state.next_column();
+        assert_eq!(
+            state.selected(),
+            Some(pos(2, 2)),
+            "second card of lane 1 lands on lane 1's second card"
+        );
+        state.previous_column();
+        assert_eq!(state.selected(), Some(pos(1, 3)));
+
+        // Row moves still run straight down the column, across lanes.
+        state.select(Some(pos(1, 1)));
+        state.next_row();
+        assert_eq!(state.selected(), Some(pos(1, 2)));
+    }
+
+    #[test]
+    fn column_move_into_a_lane_gap_lands_in_the_nearest_lane() {
+        let mut state = BoardState::new();
+        // Lane 1 is empty in column 2: a move from it goes to the nearest
+        // lane, preferring the one above (entered at its bottom).
+        state.sync(vec![1, 3, 3]);
+        state.sync_lanes(vec![vec![0..1, 0..2], vec![1..2, 2..2], vec![2..3, 2..3]]);
+        state.select(Some(pos(1, 1)));
+        state.next_column();
+        assert_eq!(state.selected(), Some(pos(2, 1)));
+
+        // Clearing the lanes restores plain row-keeping moves.
+        state.sync_lanes(Vec::new());
+        state.select(Some(pos(1, 2)));
+        state.next_column();
+        assert_eq!(state.selected(), Some(pos(2, 2)));
+    }
+
     #[test]
     fn page_moves_a_screenful_within_the_column() {
         let mut state = BoardState::new();
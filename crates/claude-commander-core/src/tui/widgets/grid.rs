@@ -0,0 +1,524 @@
+//! Watch grid: live agent-pane captures of many sessions, tiled.
+//!
+//! The grid is a full-screen view ([`ViewMode::Grid`](crate::config::ViewMode))
+//! built from the same [`Board`] model as the kanban board, so tiles come in the
+//! board's column-major order and carry the same session numbers. Only sessions
+//! with a live pane get a tile (stopped ones have nothing to show).
+//!
+//! The tiles are paged rather than shrunk: [`grid_layout`] picks as many
+//! columns and rows as fit at [`MIN_TILE_WIDTH`] × [`MIN_TILE_HEIGHT`], and the
+//! page holding the selection is the one drawn. Only the drawn tiles are
+//! captured, which is what keeps a large fleet within the tmux budget.
+
+use std::collections::HashMap;
+
+use ansi_to_tui::IntoText;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Paragraph, Widget},
+};
+
+use super::status_glyph::{session_status_glyph, status_label};
+use crate::session::{AgentState, Board, ProjectId, SessionId, SessionListItem, SessionStatus};
+use crate::tui::theme::Theme;
+
+/// Narrowest a tile gets before the grid drops a column.
+pub const MIN_TILE_WIDTH: u16 = 40;
+
+/// Shortest a tile gets (borders plus a few lines of output) before the grid
+/// drops a row and pages instead.
+pub const MIN_TILE_HEIGHT: u16 = 8;
+
+/// Which sessions the grid shows. Cycled with `CycleGridFilter` (default `f`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GridFilter {
+    /// Every session with a live pane.
+    #[default]
+    All,
+    /// Only sessions whose agent is working.
+    Working,
+    /// Only sessions waiting on the user (a prompt, or unread output).
+    NeedsInput,
+}
+
+impl GridFilter {
+    /// The next filter in the cycle: all → working → needs input → all.
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::Working,
+            Self::Working => Self::NeedsInput,
+            Self::NeedsInput => Self::All,
+        }
+    }
+
+    /// Short name for the top bar and toasts.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Working => "working",
+            Self::NeedsInput => "needs input",
+        }
+    }
+
+    /// Whether a session in this state gets a tile.
+    pub fn admits(self, agent_state: Option<AgentState>, unread: bool) -> bool {
+        match self {
+            Self::All => true,
+            Self::Working => agent_state == Some(AgentState::Working),
+            Self::NeedsInput => {
+                agent_state == Some(AgentState::WaitingForInput)
+                    || (unread && agent_state != Some(AgentState::Working))
+            }
+        }
+    }
+}
+
+/// One tile: the session it watches and what its header shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridTile {
+    pub session_id: SessionId,
+    pub project_id: ProjectId,
+    pub project_name: String,
+    pub title: String,
+    pub status: SessionStatus,
+    pub agent_state: Option<AgentState>,
+    pub unread: bool,
+}
+
+/// The tiles for `board` under `filter`, in the board's column-major order.
+/// Sessions folded away in collapsed swimlanes still get tiles.
+pub fn grid_tiles(board: &Board, filter: GridFilter) -> Vec<GridTile> {
+    board
+        .cards()
+        .filter_map(|card| {
+            let SessionListItem::Worktree {
+                id,
+                project_id,
+                title,
+                status,
+                agent_state,
+                unread,
+                ..
+            } = &card.row
+            else {
+                unreachable!("board rows are always Worktree")
+            };
+            (*status != SessionStatus::Stopped && filter.admits(*agent_state, *unread)).then(|| {
+                GridTile {
+                    session_id: *id,
+                    project_id: *project_id,
+                    project_name: card.project_name.clone(),
+                    title: title.clone(),
+                    status: *status,
+                    agent_state: *agent_state,
+                    unread: *unread,
+                }
+            })
+        })
+        .collect()
+}
+
+/// Columns and rows of tiles on one page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridLayout {
+    pub cols: usize,
+    pub rows: usize,
+}
+
+impl GridLayout {
+    /// Tiles per page (never zero).
+    pub fn page_size(self) -> usize {
+        (self.cols * self.rows).max(1)
+    }
+}
+
+/// The page layout for `count` tiles in `area`: roughly square (columns first,
+/// since terminals are wider than tall), capped by what fits at the minimum
+/// tile size. Always at least 1×1.
+pub fn grid_layout(area: Rect, count: usize) -> GridLayout {
+    let max_cols = usize::from((area.width / MIN_TILE_WIDTH).max(1));
+    let max_rows = usize::from((area.height / MIN_TILE_HEIGHT).max(1));
+    let count = count.max(1);
+    let mut cols = 1;
+    while cols * cols < count {
+        cols += 1;
+    }
+    let cols = cols.min(max_cols);
+    let rows = count.div_ceil(cols).min(max_rows);
+    GridLayout { cols, rows }
+}
+
+/// The rect of each slot on a page, row-major, splitting `area` evenly (the
+/// last column and row absorb the remainder).
+pub fn tile_rects(area: Rect, layout: GridLayout) -> Vec<Rect> {
+    let (cols, rows) = (layout.cols as u16, layout.rows as u16);
+    let (w, h) = (area.width / cols, area.height / rows);
+    let mut rects = Vec::with_capacity(layout.page_size());
+    for r in 0..rows {
+        for c in 0..cols {
+            let x = area.x + c * w;
+            let y = area.y + r * h;
+            rects.push(Rect {
+                x,
+                y,
+                width: if c + 1 == cols { area.right() - x } else { w },
+                height: if r + 1 == rows { area.bottom() - y } else { h },
+            });
+        }
+    }
+    rects
+}
+
+/// Cursor, zoom and filter for the grid. The layout is recorded by the widget
+/// on each render so keyboard motion matches what is on screen.
+#[derive(Debug, Clone, Default)]
+pub struct GridState {
+    selected: Option<usize>,
+    len: usize,
+    layout: Option<GridLayout>,
+    /// Whether the selected tile fills the whole grid area.
+    pub zoomed: bool,
+    pub filter: GridFilter,
+}
+
+impl GridState {
+    /// The selected tile index, or `None` when the grid is empty.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Select a tile (clamped to the tile count).
+    pub fn select(&mut self, idx: usize) {
+        self.selected = (self.len > 0).then(|| idx.min(self.len - 1));
+    }
+
+    /// Reconcile with a rebuilt tile list of `len` tiles: clamp the cursor,
+    /// default it to the first tile, and unzoom when nothing is left.
+    pub fn sync(&mut self, len: usize) {
+        self.len = len;
+        self.selected = match self.selected {
+            _ if len == 0 => None,
+            Some(i) => Some(i.min(len - 1)),
+            None => Some(0),
+        };
+        if len == 0 {
+            self.zoomed = false;
+        }
+    }
+
+    /// Record the layout drawn on the last frame.
+    pub fn set_layout(&mut self, layout: GridLayout) {
+        self.layout = Some(layout);
+    }
+
+    fn layout(&self) -> GridLayout {
+        self.layout.unwrap_or(GridLayout { cols: 1, rows: 1 })
+    }
+
+    /// Index of the first tile on the page holding the selection.
+    pub fn page_start(&self) -> usize {
+        let page = self.layout().page_size();
+        self.selected.map_or(0, |i| i / page * page)
+    }
+
+    /// Step the cursor by `delta` tiles, clamped to the ends.
+    fn step(&mut self, delta: isize) {
+        if let Some(i) = self.selected {
+            let last = self.len.saturating_sub(1) as isize;
+            self.selected = Some((i as isize + delta).clamp(0, last) as usize);
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.step(1);
+    }
+
+    pub fn previous(&mut self) {
+        self.step(-1);
+    }
+
+    /// Down one row of tiles (onto the next page from the bottom row).
+    pub fn down(&mut self) {
+        self.step(self.layout().cols as isize);
+    }
+
+    /// Up one row of tiles.
+    pub fn up(&mut self) {
+        self.step(-(self.layout().cols as isize));
+    }
+
+    /// A whole page forward or back.
+    pub fn page(&mut self, down: bool) {
+        let page = self.layout().page_size() as isize;
+        self.step(if down { page } else { -page });
+    }
+
+    pub fn select_first(&mut self) {
+        self.select(0);
+    }
+
+    pub fn select_last(&mut self) {
+        self.select(self.len.saturating_sub(1));
+    }
+}
+
+/// The last `height` lines of a capture, with trailing blank lines dropped
+/// (an idle agent's pane ends in empty rows below its prompt).
+pub fn capture_tail(content: &str, height: usize) -> &str {
+    let trimmed = content.trim_end_matches(['\n', ' ']);
+    let start = trimmed
+        .match_indices('\n')
+        .rev()
+        .nth(height.saturating_sub(1))
+        .map_or(0, |(i, _)| i + 1);
+    &trimmed[start..]
+}
+
+/// Renders one page of tiles (or the zoomed tile).
+pub struct GridWidget<'a> {
+    tiles: &'a [GridTile],
+    captures: &'a HashMap<SessionId, String>,
+    numbers: &'a HashMap<SessionId, usize>,
+    theme: &'a Theme,
+    tick: u64,
+    rounded: bool,
+}
+
+/// What the widget drew, for hit-testing and capture scheduling.
+#[derive(Debug, Default)]
+pub struct GridOutput {
+    /// Screen rect → tile index, for mouse clicks.
+    pub hit_regions: Vec<(Rect, usize)>,
+    /// Sessions whose tiles are on screen, so only those are captured.
+    pub visible: Vec<SessionId>,
+    /// Inner height of the tallest drawn tile: how many lines to capture.
+    pub capture_lines: u16,
+}
+
+impl<'a> GridWidget<'a> {
+    pub fn new(
+        tiles: &'a [GridTile],
+        captures: &'a HashMap<SessionId, String>,
+        numbers: &'a HashMap<SessionId, usize>,
+        theme: &'a Theme,
+    ) -> Self {
+        Self {
+            tiles,
+            captures,
+            numbers,
+            theme,
+            tick: 0,
+            rounded: false,
+        }
+    }
+
+    /// Animation tick for the working spinner.
+    pub fn tick(mut self, tick: u64) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// Draw rounded tile borders.
+    pub fn rounded(mut self, rounded: bool) -> Self {
+        self.rounded = rounded;
+        self
+    }
+
+    pub fn render(self, area: Rect, buf: &mut Buffer, state: &mut GridState) -> GridOutput {
+        let mut out = GridOutput::default();
+        if area.width == 0 || area.height == 0 || self.tiles.is_empty() {
+            return out;
+        }
+        let selected = state.selected();
+        let slots: Vec<(Rect, usize)> = match selected {
+            Some(i) if state.zoomed => vec![(area, i)],
+            _ => {
+                let layout = grid_layout(area, self.tiles.len());
+                state.set_layout(layout);
+                let start = state.page_start();
+                tile_rects(area, layout)
+                    .into_iter()
+                    .zip(start..self.tiles.len())
+                    .collect()
+            }
+        };
+        for (rect, idx) in slots {
+            let tile = &self.tiles[idx];
+            let inner = self.render_tile(tile, rect, buf, selected == Some(idx));
+            out.capture_lines = out.capture_lines.max(inner.height);
+            out.visible.push(tile.session_id);
+            out.hit_regions.push((rect, idx));
+        }
+        out
+    }
+
+    /// Draw one tile and return its inner (capture) area.
+    fn render_tile(&self, tile: &GridTile, area: Rect, buf: &mut Buffer, selected: bool) -> Rect {
+        let theme = self.theme;
+        let (glyph, glyph_color) =
+            session_status_glyph(theme, self.tick, tile.status, tile.agent_state, tile.unread)
+                .unwrap_or_else(|| (" ".to_string(), theme.text_secondary));
+        let mut header = vec![Span::styled(
+            format!(" {glyph} "),
+            Style::default().fg(glyph_color),
+        )];
+        if let Some(n) = self.numbers.get(&tile.session_id) {
+            header.push(Span::styled(
+                format!("{n} "),
+                Style::default().fg(theme.text_secondary),
+            ));
+        }
+        let title_style = if selected {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        header.push(Span::styled(tile.title.clone(), title_style));
+        header.push(Span::styled(
+            format!(" · {} ", tile.project_name),
+            Style::default().fg(theme.text_secondary),
+        ));
+        let state_label = Line::from(Span::styled(
+            format!(
+                " {} ",
+                status_label(tile.status, tile.agent_state, tile.unread)
+            ),
+            Style::default().fg(glyph_color),
+        ))
+        .right_aligned();
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(if self.rounded {
+                BorderType::Rounded
+            } else {
+                BorderType::Plain
+            })
+            .border_style(if selected {
+                theme.border_focused()
+            } else {
+                theme.border_unfocused()
+            })
+            .title(Line::from(header))
+            .title(state_label);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let body = match self.captures.get(&tile.session_id) {
+            Some(content) => {
+                let tail = capture_tail(content, usize::from(inner.height));
+                tail.into_text().unwrap_or_else(|_| Text::raw(tail))
+            }
+            None => Text::styled("…", Style::default().fg(theme.text_secondary)),
+        };
+        Paragraph::new(body).render(inner, buf);
+        inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(width: u16, height: u16) -> Rect {
+        Rect {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn filters_split_working_from_waiting() {
+        use AgentState::*;
+        assert!(GridFilter::All.admits(None, false));
+        assert!(GridFilter::Working.admits(Some(Working), true));
+        assert!(!GridFilter::Working.admits(Some(WaitingForInput), false));
+        assert!(GridFilter::NeedsInput.admits(Some(WaitingForInput), false));
+        assert!(GridFilter::NeedsInput.admits(Some(Idle), true));
+        // Unread output from an agent still working isn't waiting on anyone.
+        assert!(!GridFilter::NeedsInput.admits(Some(Working), true));
+        assert!(!GridFilter::NeedsInput.admits(Some(Idle), false));
+        assert_eq!(GridFilter::NeedsInput.next(), GridFilter::All);
+    }
+
+    #[test]
+    fn layout_is_squarish_and_capped_by_minimum_tile_size() {
+        assert_eq!(
+            grid_layout(area(200, 60), 1),
+            GridLayout { cols: 1, rows: 1 }
+        );
+        assert_eq!(
+            grid_layout(area(200, 60), 4),
+            GridLayout { cols: 2, rows: 2 }
+        );
+        assert_eq!(
+            grid_layout(area(200, 60), 5),
+            GridLayout { cols: 3, rows: 2 }
+        );
+        // 80 columns fit two tiles across; 24 rows fit three down.
+        assert_eq!(
+            grid_layout(area(80, 24), 9),
+            GridLayout { cols: 2, rows: 3 }
+        );
+        // A tiny terminal still gets one tile.
+        assert_eq!(grid_layout(area(10, 3), 9), GridLayout { cols: 1, rows: 1 });
+    }
+
+    #[test]
+    fn tile_rects_cover_the_area() {
+        let rects = tile_rects(area(101, 25), GridLayout { cols: 2, rows: 2 });
+        assert_eq!(rects.len(), 4);
+        assert_eq!(rects[0], Rect::new(0, 0, 50, 12));
+        assert_eq!(rects[3], Rect::new(50, 12, 51, 13));
+    }
+
+    #[test]
+    fn navigation_moves_by_rows_and_pages() {
+        let mut state = GridState::default();
+        state.sync(7);
+        state.set_layout(GridLayout { cols: 2, rows: 2 });
+        assert_eq!(state.selected(), Some(0));
+        state.down();
+        assert_eq!(state.selected(), Some(2));
+        state.next();
+        state.down();
+        // Index 5 sits on the second page.
+        assert_eq!(state.selected(), Some(5));
+        assert_eq!(state.page_start(), 4);
+        state.page(true);
+        assert_eq!(state.selected(), Some(6));
+        state.up();
+        state.up();
+        state.up();
+        state.up();
+        assert_eq!(state.selected(), Some(0));
+        state.select_last();
+        state.sync(3);
+        assert_eq!(state.selected(), Some(2));
+    }
+
+    #[test]
+    fn sync_to_empty_clears_selection_and_zoom() {
+        let mut state = GridState {
+            zoomed: true,
+            ..GridState::default()
+        };
+        state.sync(2);
+        assert!(state.zoomed);
+        state.sync(0);
+        assert_eq!(state.selected(), None);
+        assert!(!state.zoomed);
+    }
+
+    #[test]
+    fn capture_tail_keeps_last_lines_without_trailing_blanks() {
+        assert_eq!(capture_tail("a\nb\nc\n\n  \n", 2), "b\nc");
+        assert_eq!(capture_tail("a\nb", 5), "a\nb");
+        assert_eq!(capture_tail("", 3), "");
+    }
+}
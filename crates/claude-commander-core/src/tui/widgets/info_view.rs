@@ -14,10 +14,12 @@ use ratatui::{
     widgets::{Paragraph, Widget, Wrap},
 };
 
+use crate::api::ResourceUsage;
 use crate::git::{AiSummary, ChecksStatus, DiffInfo, EnrichedPrInfo, PrState};
-use crate::session::SessionStatus;
+use crate::session::{HookOutcome, HookRun, PortBlock, SessionStatus, TaskRun, TaskStatus};
 use crate::tui::app::StackChainEntry;
 use crate::tui::theme::Theme;
+use crate::tui::widgets::status_glyph;
 
 /// Data required to render the Info modal for a session.
 pub struct InfoSessionData<'a> {
@@ -37,8 +39,24 @@ pub struct InfoSessionData<'a> {
     pub summary_key_hint: Option<String>,
     /// Pre-computed stack chain (empty if session is not stacked).
     pub stack_chain: &'a [StackChainEntry],
+    /// Latest sampled RSS/CPU of the session's pane; `None` while stopped or
+    /// before the first sample lands.
+    pub resources: Option<ResourceUsage>,
+    /// Recent lifecycle-hook runs, oldest first. Empty hides the section.
+    pub hook_runs: &'a [HookRun],
+    /// Ports reserved for the session; `None` hides the line.
+    pub ports: Option<PortBlock>,
+    /// Latest run of each project task. Empty hides the section.
+    pub task_runs: &'a [TaskRun],
+    /// User tags; empty hides the line.
+    pub tags: &'a [String],
+    /// Free-form user notes; empty hides the line.
+    pub notes: &'a str,
 }
 
+/// Output lines shown under a failed hook or task run.
+const HOOK_FAILURE_TAIL_LINES: usize = 3;
+
 /// Data required to render the Info surface for a project row. Projects are
 /// selectable in the list views (and in the board's sidebar), so the Info tab
 /// has to say something useful about them.
@@ -146,6 +164,33 @@ impl<'a> InfoView<'a> {
             Span::styled(" Path:    ", label),
             Span::styled(data.worktree_path.clone(), value),
         ]));
+        if let Some(ports) = data.ports {
+            lines.push(Line::from(vec![
+                Span::styled(" Ports:   ", label),
+                Span::styled(format!("{ports}"), value),
+                Span::styled(format!("  (PORT={})", ports.start), self.secondary_style()),
+            ]));
+        }
+        if let Some(ref usage) = data.resources {
+            lines.push(Line::from(vec![
+                Span::styled(" Usage:   ", label),
+                Span::styled(crate::cli::format_resource_usage(usage), value),
+            ]));
+        }
+        if !data.tags.is_empty() {
+            let tags: Vec<String> = data.tags.iter().map(|t| format!("#{t}")).collect();
+            lines.push(Line::from(vec![
+                Span::styled(" Tags:    ", label),
+                Span::styled(tags.join(" "), value),
+            ]));
+        }
+        // Multi-line notes continue under the value column.
+        for (i, note_line) in data.notes.lines().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(if i == 0 { " Notes:   " } else { "          " }, label),
+                Span::styled(note_line.to_string(), value),
+            ]));
+        }
 
         if data.diff_info.has_changes() {
             lines.push(Line::from(vec![
@@ -190,6 +235,18 @@ impl<'a> InfoView<'a> {
         // PR section
         self.build_pr_lines(data, &mut lines);
 
+        if !data.hook_runs.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(separator());
+            self.build_hook_lines(data.hook_runs, &mut lines);
+        }
+
+        if !data.task_runs.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(separator());
+            self.build_task_lines(data.task_runs, &mut lines);
+        }
+
         // AI summary section (only when AI is enabled, i.e. key hint is present)
         if let Some(ref key_hint) = data.summary_key_hint {
             lines.push(Line::from(""));
@@ -345,6 +402,79 @@ impl<'a> InfoView<'a> {
         }
     }
 
+    /// Newest run first; a failed run also shows the end of its output.
+    fn build_hook_lines(&self, runs: &[HookRun], lines: &mut Vec<Line<'static>>) {
+        let value = self.value_style();
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(" Hooks:", self.label_style())));
+        for run in runs.iter().rev() {
+            let (icon, color) = match run.outcome {
+                HookOutcome::Succeeded => ("✓", self.theme.diff_added),
+                HookOutcome::TimedOut => ("◌", self.theme.modal_warning),
+                HookOutcome::Failed { .. } | HookOutcome::SpawnFailed => {
+                    ("✗", self.theme.diff_removed)
+                }
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {icon} "), Style::default().fg(color)),
+                Span::styled(format!("{} ", run.event), self.secondary_style()),
+                Span::styled(run.command.clone(), value),
+                Span::styled(
+                    format!(
+                        "  {} · {:.1}s · {}",
+                        run.outcome,
+                        run.duration_ms as f64 / 1000.0,
+                        run.finished_at.format("%m-%d %H:%M")
+                    ),
+                    self.secondary_style(),
+                ),
+            ]));
+            if !run.outcome.is_success() {
+                let tail: Vec<&str> = run.output_tail.lines().collect();
+                for out in &tail[tail.len().saturating_sub(HOOK_FAILURE_TAIL_LINES)..] {
+                    lines.push(Line::from(Span::styled(
+                        format!("     {out}"),
+                        self.secondary_style(),
+                    )));
+                }
+            }
+        }
+    }
+
+    /// One line per task; a failed run also shows the end of its output.
+    fn build_task_lines(&self, runs: &[TaskRun], lines: &mut Vec<Line<'static>>) {
+        let value = self.value_style();
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(" Tasks:", self.label_style())));
+        for run in runs {
+            let (icon, color) = status_glyph::task_badge(self.theme, run.status);
+            let outcome = match (run.status, run.exit_code) {
+                (TaskStatus::Failed, Some(code)) => format!("exit {code}"),
+                (TaskStatus::Failed, None) => "killed".to_string(),
+                (status, _) => status.to_string(),
+            };
+            let when = run.finished_at.unwrap_or(run.started_at);
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {icon} "), Style::default().fg(color)),
+                Span::styled(format!("{} ", run.name), self.secondary_style()),
+                Span::styled(run.command.clone(), value),
+                Span::styled(
+                    format!("  {outcome} · {}", when.format("%m-%d %H:%M")),
+                    self.secondary_style(),
+                ),
+            ]));
+            if run.status == TaskStatus::Failed {
+                let tail: Vec<&str> = run.output_tail.lines().collect();
+                for out in &tail[tail.len().saturating_sub(HOOK_FAILURE_TAIL_LINES)..] {
+                    lines.push(Line::from(Span::styled(
+                        format!("     {out}"),
+                        self.secondary_style(),
+                    )));
+                }
+            }
+        }
+    }
+
     fn build_summary_lines(&self, summary: &AiSummary, lines: &mut Vec<Line<'static>>) {
         let label = self.label_style();
 
@@ -507,6 +637,12 @@ mod tests {
             ai_summary: None,
             summary_key_hint: Some("g".into()),
             stack_chain: &[],
+            resources: None,
+            hook_runs: &[],
+            ports: None,
+            task_runs: &[],
+            tags: &[],
+            notes: "",
         };
         let view = InfoView::new(InfoContent::Session(data), &theme);
         let lines = view.build_lines();
@@ -548,6 +684,12 @@ mod tests {
             }),
             summary_key_hint: Some("g".into()),
             stack_chain: &[],
+            resources: None,
+            hook_runs: &[],
+            ports: None,
+            task_runs: &[],
+            tags: &[],
+            notes: "",
         };
         let view = InfoView::new(InfoContent::Session(data), &theme);
         let lines = view.build_lines();
@@ -573,6 +715,12 @@ mod tests {
             ai_summary: Some(&AiSummary::Loading),
             summary_key_hint: Some("g".into()),
             stack_chain: &[],
+            resources: None,
+            hook_runs: &[],
+            ports: None,
+            task_runs: &[],
+            tags: &[],
+            notes: "",
         };
         let view = InfoView::new(InfoContent::Session(data), &theme);
         let lines = view.build_lines();
@@ -604,6 +752,12 @@ mod tests {
             ai_summary: Some(&summary),
             summary_key_hint: Some("g".into()),
             stack_chain: &[],
+            resources: None,
+            hook_runs: &[],
+            ports: None,
+            task_runs: &[],
+            tags: &[],
+            notes: "",
         };
         let view = InfoView::new(InfoContent::Session(data), &theme);
         let lines = view.build_lines();
@@ -615,6 +769,213 @@ mod tests {
         assert!(text.contains("timed out"));
     }
 
+    #[test]
+    fn test_info_view_shows_resource_usage_and_ports() {
+        let theme = test_theme();
+        let diff = empty_diff();
+        let data = InfoSessionData {
+            title: "test".into(),
+            branch: "test".into(),
+            created_at: "now".into(),
+            status: SessionStatus::Running,
+            program: "claude".into(),
+            worktree_path: "/tmp".into(),
+            diff_info: &diff,
+            pr_number: None,
+            pr_url: None,
+            pr_merged: false,
+            enriched_pr: None,
+            ai_summary: None,
+            summary_key_hint: None,
+            stack_chain: &[],
+            resources: Some(ResourceUsage {
+                rss_bytes: 256 * 1024 * 1024,
+                cpu_time_ms: 1000,
+                cpu_percent: Some(12.0),
+                scoped: false,
+            }),
+            hook_runs: &[],
+            ports: Some(PortBlock {
+                start: 20005,
+                count: 5,
+            }),
+            task_runs: &[],
+            tags: &[],
+            notes: "",
+        };
+        let view = InfoView::new(InfoContent::Session(data), &theme);
+        let text: String = view
+            .build_lines()
+            .iter()
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("Usage:   256.0 MiB RSS, 12% CPU"));
+        assert!(text.contains("Ports:   20005–20009  (PORT=20005)"));
+    }
+
+    #[test]
+    fn test_info_view_shows_tags_and_notes() {
+        let theme = test_theme();
+        let diff = empty_diff();
+        let tags = vec!["blocked".to_string(), "wip".to_string()];
+        let data = InfoSessionData {
+            title: "test".into(),
+            branch: "test".into(),
+            created_at: "now".into(),
+            status: SessionStatus::Running,
+            program: "claude".into(),
+            worktree_path: "/tmp".into(),
+            diff_info: &diff,
+            pr_number: None,
+            pr_url: None,
+            pr_merged: false,
+            enriched_pr: None,
+            ai_summary: None,
+            summary_key_hint: None,
+            stack_chain: &[],
+            resources: None,
+            hook_runs: &[],
+            ports: None,
+            task_runs: &[],
+            tags: &tags,
+            notes: "waiting on API key\nthen rebase",
+        };
+        let view = InfoView::new(InfoContent::Session(data), &theme);
+        let text: String = view
+            .build_lines()
+            .iter()
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("Tags:    #blocked #wip"));
+        assert!(text.contains("Notes:   waiting on API key"));
+        assert!(text.contains("          then rebase"));
+    }
+
+    #[test]
+    fn test_info_view_shows_hook_runs_newest_first() {
+        use crate::session::HookEvent;
+        let theme = test_theme();
+        let diff = empty_diff();
+        let run = |event, command: &str, outcome, output_tail: &str| HookRun {
+            event,
+            command: command.to_string(),
+            outcome,
+            finished_at: chrono::Utc::now(),
+            duration_ms: 1500,
+            output_tail: output_tail.to_string(),
+        };
+        let runs = [
+            run(
+                HookEvent::PostCreate,
+                "npm ci",
+                HookOutcome::Succeeded,
+                "added 3 packages",
+            ),
+            run(
+                HookEvent::PostMerge,
+                "make check",
+                HookOutcome::Failed { code: Some(2) },
+                "a\nb\nc\nlint failed",
+            ),
+        ];
+        let data = InfoSessionData {
+            title: "test".into(),
+            branch: "test".into(),
+            created_at: "now".into(),
+            status: SessionStatus::Running,
+            program: "claude".into(),
+            worktree_path: "/tmp".into(),
+            diff_info: &diff,
+            pr_number: None,
+            pr_url: None,
+            pr_merged: false,
+            enriched_pr: None,
+            ai_summary: None,
+            summary_key_hint: None,
+            stack_chain: &[],
+            resources: None,
+            hook_runs: &runs,
+            ports: None,
+            task_runs: &[],
+            tags: &[],
+            notes: "",
+        };
+        let view = InfoView::new(InfoContent::Session(data), &theme);
+        let text: String = view
+            .build_lines()
+            .iter()
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let merge = text.find("post_merge make check  exit 2").unwrap();
+        let create = text.find("post_create npm ci  ok").unwrap();
+        assert!(merge < create, "newest run should come first:\n{text}");
+        // A failure shows its output tail; a success doesn't.
+        assert!(text.contains("lint failed"));
+        assert!(!text.contains("     a\n"));
+        assert!(!text.contains("added 3 packages"));
+    }
+
+    #[test]
+    fn test_info_view_shows_task_runs() {
+        use crate::session::TaskStatus;
+        let theme = test_theme();
+        let diff = empty_diff();
+        let run = |name: &str, status, exit_code, output_tail: &str| TaskRun {
+            name: name.to_string(),
+            command: format!("cargo {name}"),
+            status,
+            exit_code,
+            started_at: chrono::Utc::now(),
+            finished_at: None,
+            output_tail: output_tail.to_string(),
+        };
+        let runs = [
+            run("build", TaskStatus::Passed, Some(0), "Finished"),
+            run(
+                "test",
+                TaskStatus::Failed,
+                Some(101),
+                "a\nb\nc\ntest failed",
+            ),
+        ];
+        let data = InfoSessionData {
+            title: "test".into(),
+            branch: "test".into(),
+            created_at: "now".into(),
+            status: SessionStatus::Running,
+            program: "claude".into(),
+            worktree_path: "/tmp".into(),
+            diff_info: &diff,
+            pr_number: None,
+            pr_url: None,
+            pr_merged: false,
+            enriched_pr: None,
+            ai_summary: None,
+            summary_key_hint: None,
+            stack_chain: &[],
+            resources: None,
+            hook_runs: &[],
+            ports: None,
+            task_runs: &runs,
+            tags: &[],
+            notes: "",
+        };
+        let view = InfoView::new(InfoContent::Session(data), &theme);
+        let text: String = view
+            .build_lines()
+            .iter()
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("build cargo build  passed"), "{text}");
+        assert!(text.contains("test cargo test  exit 101"), "{text}");
+        assert!(text.contains("test failed"));
+        assert!(!text.contains("Finished"));
+    }
+
     #[test]
     fn test_parse_hex_color_valid() {
         assert_eq!(
@@ -665,6 +1026,12 @@ mod tests {
             ai_summary: None,
             summary_key_hint: Some("g".into()),
             stack_chain: &[],
+            resources: None,
+            hook_runs: &[],
+            ports: None,
+            task_runs: &[],
+            tags: &[],
+            notes: "",
         };
         let view = InfoView::new(InfoContent::Session(data), &theme);
         let lines = view.build_lines();
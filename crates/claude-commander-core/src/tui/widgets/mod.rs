@@ -2,12 +2,15 @@
 //!
 //! Custom ratatui widgets for the application:
 //! - `board` - Kanban board navigation state, layout geometry, and widget
+//! - `grid` - Watch grid of live pane captures (tiles, paging, filter)
 //! - `InfoView` - Session info, PR details, AI summary (Info modal)
-//! - `Preview` - scrollable ANSI pane capture for the list views' right pane
+//! - `Preview` - scrollable ANSI pane capture for the list views' right pane,
+//!   and `InspectView` for its read-only inspection mode
 //! - `tree_list` - session-list widget for the list views
 //! - `status_glyph` / `pr_colors` - row-rendering helpers shared by both
 
 pub mod board;
+pub mod grid;
 mod info_view;
 pub(crate) mod pr_colors;
 mod preview;
@@ -15,5 +18,5 @@ pub mod status_glyph;
 pub mod tree_list;
 
 pub use info_view::*;
-pub use preview::{Preview, PreviewState};
+pub use preview::{InspectView, Preview, PreviewInspector, PreviewState};
 pub use tree_list::{TreeList, TreeListState, list_has_mixed_programs, worktree_display_info};
@@ -1,17 +1,23 @@
 //! Preview pane widget
 //!
-//! Displays captured pane content with scrolling support.
+//! Displays captured pane content with scrolling support, and the read-only
+//! inspection mode ([`PreviewInspector`] / [`InspectView`]) that lets the
+//! reader move a cursor through captured scrollback, search it and select
+//! lines to copy — without attaching, so no key can reach the agent.
+
+use std::ops::{Range, RangeInclusive};
 
 use ansi_to_tui::IntoText;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::Modifier,
-    text::Text,
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
     widgets::{Block, Paragraph, ScrollbarState, Widget},
 };
 
-use crate::tui::theme::dim_color;
+use crate::scrollback::{ScrollbackPattern, ScrollbackSearch};
+use crate::tui::theme::{Theme, dim_color};
 
 /// Preview widget for displaying pane content
 pub struct Preview<'a> {
@@ -66,7 +72,7 @@ impl<'a> Widget for Preview<'a> {
         if let Some(opacity) = self.dim_opacity {
             for line in &mut text.lines {
                 for span in &mut line.spans {
-                    let fg = span.style.fg.unwrap_or(ratatui::style::Color::Reset);
+                    let fg = span.style.fg.unwrap_or(Color::Reset);
                     span.style = span
                         .style
                         .fg(dim_color(fg, opacity))
@@ -248,6 +254,442 @@ impl PreviewState {
     }
 }
 
+/// An in-progress or committed `/` search inside a [`PreviewInspector`].
+#[derive(Debug, Clone, Default)]
+pub struct InspectSearch {
+    /// The text searched for (literal, smart case).
+    pub query: String,
+    /// Still typing: keys edit the query rather than move the cursor.
+    pub editing: bool,
+    /// Every match as `(line, byte range)`, top to bottom.
+    matches: Vec<(usize, Range<usize>)>,
+    /// Index into `matches` of the one the cursor was moved to.
+    current: Option<usize>,
+    /// Cursor line when the search began: each edit of the query searches
+    /// from here, and cancelling returns here.
+    origin: usize,
+}
+
+impl InspectSearch {
+    /// How many matches the query has.
+    pub fn match_count(&self) -> usize {
+        self.matches.len()
+    }
+}
+
+/// Read-only inspection of a captured pane: a frozen copy of its lines with a
+/// line cursor, an optional line selection and an incremental search.
+///
+/// The capture is taken once (and may be replaced by a deeper one, see
+/// [`Self::replace_content`]) rather than tracking the live tail, so the text
+/// under the cursor doesn't move while it's being read. Searching runs upward
+/// from the cursor like tmux's `?` — the interesting output is usually the
+/// most recent.
+#[derive(Debug, Clone)]
+pub struct PreviewInspector {
+    /// The captured lines, escape sequences stripped. Never empty.
+    lines: Vec<String>,
+    /// The line under the cursor.
+    cursor: usize,
+    /// Where a line selection started; it runs from here to the cursor.
+    anchor: Option<usize>,
+    search: Option<InspectSearch>,
+    /// First line shown.
+    scroll: usize,
+    /// Rows shown, as of the last render.
+    height: usize,
+}
+
+impl PreviewInspector {
+    /// Inspect `content` (ANSI allowed), cursor on its last non-blank line.
+    pub fn new(content: &str) -> Self {
+        let lines = inspect_lines(content);
+        let cursor = lines.len() - 1;
+        Self {
+            lines,
+            cursor,
+            anchor: None,
+            search: None,
+            scroll: 0,
+            height: 0,
+        }
+    }
+
+    /// Swap in a fresh capture of the same pane — typically the deeper
+    /// history fetched after inspection opened on the visible screen. Both
+    /// captures end at the pane's bottom, so the cursor, selection and search
+    /// origin keep their distance from the end and stay on the same text.
+    pub fn replace_content(&mut self, content: &str) {
+        let old_len = self.lines.len();
+        self.lines = inspect_lines(content);
+        let new_len = self.lines.len();
+        let remap = |line: usize| (new_len - 1).saturating_sub(old_len - 1 - line);
+        self.cursor = remap(self.cursor);
+        self.anchor = self.anchor.map(remap);
+        if let Some(search) = &mut self.search {
+            search.origin = remap(search.origin);
+        }
+        self.rematch(false);
+        self.keep_cursor_visible();
+    }
+
+    /// Number of captured lines.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// The line under the cursor (0-based).
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// First line shown.
+    pub fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    /// Record the rows available to the view, keeping the cursor inside them.
+    pub fn set_height(&mut self, height: u16) {
+        self.height = height as usize;
+        self.keep_cursor_visible();
+    }
+
+    /// Move the cursor by `delta` lines, clamped to the capture.
+    pub fn move_cursor(&mut self, delta: isize) {
+        let last = self.lines.len() - 1;
+        self.cursor = self.cursor.saturating_add_signed(delta).min(last);
+        self.keep_cursor_visible();
+    }
+
+    /// Move the cursor a page (the view height less two lines of context).
+    pub fn page(&mut self, down: bool) {
+        let page = self.height.saturating_sub(2).max(1) as isize;
+        self.move_cursor(if down { page } else { -page });
+    }
+
+    /// Jump to the first line.
+    pub fn to_top(&mut self) {
+        self.cursor = 0;
+        self.keep_cursor_visible();
+    }
+
+    /// Jump to the last line.
+    pub fn to_bottom(&mut self) {
+        self.cursor = self.lines.len() - 1;
+        self.keep_cursor_visible();
+    }
+
+    /// Start a selection at the cursor, or drop the one in progress.
+    pub fn toggle_selection(&mut self) {
+        self.anchor = match self.anchor {
+            Some(_) => None,
+            None => Some(self.cursor),
+        };
+    }
+
+    /// Drop the selection. Returns whether there was one.
+    pub fn clear_selection(&mut self) -> bool {
+        self.anchor.take().is_some()
+    }
+
+    /// The selected lines, when a selection is in progress.
+    pub fn selection(&self) -> Option<RangeInclusive<usize>> {
+        self.anchor.map(|a| a.min(self.cursor)..=a.max(self.cursor))
+    }
+
+    /// What a copy takes: the selected lines, or the cursor line without a
+    /// selection. Trailing whitespace is trimmed from each line — a capture
+    /// pads lines out to the pane width.
+    pub fn copy_text(&self) -> String {
+        let range = self.selection().unwrap_or(self.cursor..=self.cursor);
+        self.lines[range]
+            .iter()
+            .map(|l| l.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// How many lines [`Self::copy_text`] covers.
+    pub fn copy_line_count(&self) -> usize {
+        self.selection().map_or(1, |r| r.count())
+    }
+
+    /// The search, if one is open.
+    pub fn search(&self) -> Option<&InspectSearch> {
+        self.search.as_ref()
+    }
+
+    /// Whether keys should edit the search query.
+    pub fn is_editing_search(&self) -> bool {
+        self.search.as_ref().is_some_and(|s| s.editing)
+    }
+
+    /// Open the search prompt with an empty query, searching up from the
+    /// cursor.
+    pub fn begin_search(&mut self) {
+        self.search = Some(InspectSearch {
+            editing: true,
+            origin: self.cursor,
+            ..InspectSearch::default()
+        });
+    }
+
+    /// Type a character into the search query and jump to the nearest match.
+    pub fn search_push(&mut self, c: char) {
+        if let Some(search) = &mut self.search {
+            search.query.push(c);
+            self.rematch(true);
+        }
+    }
+
+    /// Delete the last query character and re-run the search.
+    pub fn search_pop(&mut self) {
+        if let Some(search) = &mut self.search {
+            search.query.pop();
+            self.rematch(true);
+        }
+    }
+
+    /// Finish typing the query. An empty query (or one with no matches)
+    /// closes the search; otherwise its highlights stay and `n`/`N` step
+    /// through them.
+    pub fn commit_search(&mut self) {
+        match &mut self.search {
+            Some(search) if search.match_count() > 0 => search.editing = false,
+            _ => self.search = None,
+        }
+    }
+
+    /// Abandon the search, returning the cursor to where it started if the
+    /// query was still being typed.
+    pub fn cancel_search(&mut self) {
+        if let Some(search) = self.search.take()
+            && search.editing
+        {
+            self.cursor = search.origin;
+            self.keep_cursor_visible();
+        }
+    }
+
+    /// Step to the next match in the search direction (upward, toward older
+    /// output) or, with `reverse`, back down. Wraps at either end. Returns
+    /// false when there is nothing to step to.
+    pub fn search_next(&mut self, reverse: bool) -> bool {
+        let Some(search) = &mut self.search else {
+            return false;
+        };
+        let count = search.matches.len();
+        if count == 0 {
+            return false;
+        }
+        let next = match (search.current, reverse) {
+            (None, _) => count - 1,
+            (Some(i), false) => (i + count - 1) % count,
+            (Some(i), true) => (i + 1) % count,
+        };
+        search.current = Some(next);
+        self.cursor = search.matches[next].0;
+        self.keep_cursor_visible();
+        true
+    }
+
+    /// The search matches on `line`, each flagged when it is the current one.
+    pub fn matches_on(&self, line: usize) -> impl Iterator<Item = (Range<usize>, bool)> + '_ {
+        let (matches, current) = match &self.search {
+            Some(s) if !s.query.is_empty() => (s.matches.as_slice(), s.current),
+            _ => (&[][..], None),
+        };
+        let first = matches.partition_point(|(l, _)| *l < line);
+        matches[first..]
+            .iter()
+            .take_while(move |(l, _)| *l == line)
+            .enumerate()
+            .map(move |(i, (_, range))| (range.clone(), current == Some(first + i)))
+    }
+
+    /// The one-line summary shown along the bottom border: the search prompt
+    /// while typing, otherwise the position, match count and selection size.
+    pub fn status(&self) -> String {
+        if let Some(search) = &self.search
+            && search.editing
+        {
+            let found = match (search.query.is_empty(), search.match_count()) {
+                (true, _) => String::new(),
+                (false, 0) => "  no matches".to_string(),
+                (false, n) => format!("  {n} match{}", if n == 1 { "" } else { "es" }),
+            };
+            return format!("/{}{found}", search.query);
+        }
+        let mut status = format!("line {}/{}", self.cursor + 1, self.lines.len());
+        if let Some(search) = &self.search {
+            let at = search.current.map_or(0, |i| i + 1);
+            status.push_str(&format!(
+                " \u{00b7} /{} {at}/{}",
+                search.query,
+                search.match_count()
+            ));
+        }
+        if let Some(range) = self.selection() {
+            let n = range.count();
+            status.push_str(&format!(
+                " \u{00b7} {n} line{} selected",
+                if n == 1 { "" } else { "s" }
+            ));
+        }
+        status
+    }
+
+    /// Recompute the matches for the current query. With `jump`, move the
+    /// cursor to the nearest match at or above the search origin (wrapping to
+    /// the bottom-most), or back to the origin when nothing matches; without,
+    /// keep the cursor and mark the match on its line, if any, as current.
+    fn rematch(&mut self, jump: bool) {
+        let Some(search) = &mut self.search else {
+            return;
+        };
+        search.matches.clear();
+        search.current = None;
+        if !search.query.is_empty()
+            && let Ok(pattern) = ScrollbackPattern::new(&ScrollbackSearch::literal(&search.query))
+        {
+            for (idx, line) in self.lines.iter().enumerate() {
+                search
+                    .matches
+                    .extend(pattern.find_all(line).map(|(s, e)| (idx, s..e)));
+            }
+        }
+        if jump {
+            let above = search.matches.partition_point(|(l, _)| *l <= search.origin);
+            search.current = match above {
+                0 => search.matches.len().checked_sub(1),
+                n => Some(n - 1),
+            };
+            self.cursor = search
+                .current
+                .map_or(search.origin, |i| search.matches[i].0);
+        } else {
+            search.current = search.matches.iter().position(|(l, _)| *l == self.cursor);
+        }
+        self.keep_cursor_visible();
+    }
+
+    /// Scroll just enough to bring the cursor into view.
+    fn keep_cursor_visible(&mut self) {
+        let height = self.height.max(1);
+        if self.cursor < self.scroll {
+            self.scroll = self.cursor;
+        } else if self.cursor >= self.scroll + height {
+            self.scroll = self.cursor + 1 - height;
+        }
+        let max_scroll = self.lines.len().saturating_sub(height);
+        self.scroll = self.scroll.min(max_scroll);
+    }
+}
+
+/// Split a capture into plain lines, dropping the blank rows tmux pads the
+/// bottom of the pane with. Always at least one (possibly empty) line, so the
+/// cursor has somewhere to be.
+fn inspect_lines(content: &str) -> Vec<String> {
+    let plain = crate::agent::strip_ansi(content);
+    let mut lines: Vec<String> = plain.lines().map(str::to_string).collect();
+    while lines.last().is_some_and(|l| l.trim().is_empty()) {
+        lines.pop();
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+/// Renders a [`PreviewInspector`]: the captured lines with a cursor marker in
+/// a one-column gutter, the selection shaded, search matches highlighted (the
+/// current one emphasised), and the inspector's status along the bottom
+/// border.
+pub struct InspectView<'a> {
+    inspector: &'a PreviewInspector,
+    theme: &'a Theme,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> InspectView<'a> {
+    pub fn new(inspector: &'a PreviewInspector, theme: &'a Theme) -> Self {
+        Self {
+            inspector,
+            theme,
+            block: None,
+        }
+    }
+
+    /// Set the block; the status is added as its bottom title.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl Widget for InspectView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inspector = self.inspector;
+        let theme = self.theme;
+        let inner = match self.block {
+            Some(block) => {
+                let status = Line::from(Span::styled(
+                    format!(" {} ", inspector.status()),
+                    Style::default().fg(theme.text_accent),
+                ));
+                let block = block.title_bottom(status);
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        if inner.width == 0 {
+            return;
+        }
+
+        let selection = inspector.selection();
+        let match_style = Style::default().fg(Color::Black).bg(theme.modal_warning);
+        let current_style = match_style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        let visible = inspector
+            .lines
+            .iter()
+            .enumerate()
+            .skip(inspector.scroll)
+            .take(inner.height as usize);
+        for (row, (idx, text)) in visible.enumerate() {
+            let is_cursor = idx == inspector.cursor;
+            let base = if selection.as_ref().is_some_and(|r| r.contains(&idx)) {
+                Style::default().bg(theme.selection_bg)
+            } else {
+                Style::default()
+            };
+            let mut spans = vec![if is_cursor {
+                Span::styled("\u{258c}", Style::default().fg(theme.text_accent))
+            } else {
+                Span::raw(" ")
+            }];
+            let mut at = 0;
+            for (range, current) in inspector.matches_on(idx) {
+                spans.push(Span::raw(&text[at..range.start]));
+                spans.push(Span::styled(
+                    &text[range.clone()],
+                    if current { current_style } else { match_style },
+                ));
+                at = range.end;
+            }
+            spans.push(Span::raw(&text[at..]));
+            let mut line = Line::from(spans).style(base);
+            if is_cursor {
+                line = line.patch_style(Style::default().add_modifier(Modifier::BOLD));
+            }
+            let y = inner.y + row as u16;
+            buf.set_style(Rect::new(inner.x, y, inner.width, 1), base);
+            buf.set_line(inner.x, y, &line, inner.width);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -673,4 +1115,156 @@ mod tests {
         state.scroll_offset = 80;
         assert!(!state.can_scroll_down());
     }
+
+    // ---------------------------------------------------------------------
+    // Inspection mode
+    // ---------------------------------------------------------------------
+
+    fn inspector(lines: &[&str]) -> PreviewInspector {
+        PreviewInspector::new(&lines.join("\n"))
+    }
+
+    #[test]
+    fn inspector_opens_on_the_last_non_blank_line_with_escapes_stripped() {
+        let ins = PreviewInspector::new("\x1b[31mred\x1b[0m\nplain\n\n\n");
+        assert_eq!(ins.line_count(), 2);
+        assert_eq!(ins.cursor(), 1);
+        assert_eq!(ins.copy_text(), "plain");
+
+        // An empty capture still has a line for the cursor.
+        let empty = PreviewInspector::new("");
+        assert_eq!(empty.line_count(), 1);
+        assert_eq!(empty.copy_text(), "");
+    }
+
+    #[test]
+    fn inspector_cursor_moves_are_clamped_and_kept_in_view() {
+        let content = (0..100)
+            .map(|i| format!("Line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut ins = PreviewInspector::new(&content);
+        ins.set_height(20);
+        assert_eq!(ins.scroll(), 80);
+
+        ins.move_cursor(-25);
+        assert_eq!(ins.cursor(), 74);
+        assert_eq!(ins.scroll(), 74, "the view follows the cursor up");
+
+        ins.page(true);
+        assert_eq!(ins.cursor(), 92);
+        assert_eq!(ins.scroll(), 74, "still on screen, so no scroll");
+
+        ins.move_cursor(1000);
+        assert_eq!(ins.cursor(), 99);
+        ins.to_top();
+        assert_eq!((ins.cursor(), ins.scroll()), (0, 0));
+        ins.move_cursor(-3);
+        assert_eq!(ins.cursor(), 0);
+    }
+
+    #[test]
+    fn inspector_search_runs_upward_from_the_cursor_and_wraps() {
+        let mut ins = inspector(&["error one", "ok", "error two", "ok", "tail"]);
+        ins.move_cursor(-1); // line 3
+        ins.begin_search();
+        for c in "error".chars() {
+            ins.search_push(c);
+        }
+        assert_eq!(ins.cursor(), 2, "nearest match at or above the cursor");
+        assert_eq!(ins.search().unwrap().match_count(), 2);
+
+        ins.commit_search();
+        assert!(!ins.is_editing_search());
+        assert!(ins.search_next(false));
+        assert_eq!(ins.cursor(), 0, "n continues upward");
+        assert!(ins.search_next(false));
+        assert_eq!(ins.cursor(), 2, "and wraps to the bottom-most match");
+        assert!(ins.search_next(true));
+        assert_eq!(ins.cursor(), 0, "N steps back down, wrapping to the top");
+    }
+
+    #[test]
+    fn inspector_search_is_smart_case_and_marks_every_match() {
+        let mut ins = inspector(&["Foo foo FOO"]);
+        ins.begin_search();
+        ins.search_push('f');
+        let ranges: Vec<_> = ins.matches_on(0).map(|(r, _)| r).collect();
+        assert_eq!(ranges, vec![0..1, 4..5, 8..9]);
+
+        ins.search_pop();
+        ins.search_push('F');
+        let ranges: Vec<_> = ins.matches_on(0).map(|(r, _)| r).collect();
+        assert_eq!(
+            ranges,
+            vec![0..1, 8..9],
+            "an uppercase letter makes it exact"
+        );
+        assert_eq!(ins.matches_on(0).filter(|(_, current)| *current).count(), 1);
+    }
+
+    #[test]
+    fn inspector_cancelled_search_returns_to_its_origin() {
+        let mut ins = inspector(&["needle", "a", "b"]);
+        ins.begin_search();
+        ins.search_push('n');
+        assert_eq!(ins.cursor(), 0);
+        ins.cancel_search();
+        assert_eq!(ins.cursor(), 2);
+        assert!(ins.search().is_none());
+
+        // A query with no matches is dropped on commit rather than left open.
+        ins.begin_search();
+        ins.search_push('z');
+        assert_eq!(ins.cursor(), 2);
+        ins.commit_search();
+        assert!(ins.search().is_none());
+    }
+
+    #[test]
+    fn inspector_copies_the_selection_or_the_cursor_line() {
+        let mut ins = inspector(&["one   ", "two", "three  "]);
+        assert_eq!(ins.copy_text(), "three");
+        assert_eq!(ins.copy_line_count(), 1);
+
+        ins.toggle_selection();
+        ins.move_cursor(-2);
+        assert_eq!(ins.selection(), Some(0..=2));
+        assert_eq!(ins.copy_text(), "one\ntwo\nthree");
+        assert_eq!(ins.copy_line_count(), 3);
+
+        assert!(ins.clear_selection());
+        assert!(!ins.clear_selection());
+        assert_eq!(ins.copy_text(), "one");
+    }
+
+    #[test]
+    fn inspector_keeps_its_place_when_deeper_history_arrives() {
+        let mut ins = inspector(&["screen a", "screen b", "screen c"]);
+        ins.move_cursor(-1);
+        ins.toggle_selection();
+        ins.move_cursor(1);
+        ins.replace_content("old 1\nold 2\nscreen a\nscreen b\nscreen c\n\n");
+        assert_eq!(ins.line_count(), 5);
+        assert_eq!(ins.copy_text(), "screen b\nscreen c");
+    }
+
+    #[test]
+    fn inspector_status_reports_prompt_position_and_selection() {
+        let mut ins = inspector(&["err", "ok", "err"]);
+        assert_eq!(ins.status(), "line 3/3");
+        ins.begin_search();
+        assert_eq!(ins.status(), "/");
+        ins.search_push('e');
+        assert_eq!(ins.status(), "/e  2 matches");
+        ins.search_push('x');
+        assert_eq!(ins.status(), "/ex  no matches");
+        ins.search_pop();
+        ins.commit_search();
+        ins.toggle_selection();
+        assert_eq!(
+            ins.status(),
+            "line 3/3 \u{00b7} /e 2/2 \u{00b7} 1 line selected"
+        );
+    }
 }
@@ -7,7 +7,7 @@
 
 use ratatui::style::Color;
 
-use crate::session::{AgentState, SessionStatus};
+use crate::session::{AgentState, SessionStatus, TaskStatus};
 use crate::tui::theme::Theme;
 
 /// Braille spinner frames for the Creating / Working status indicators.
@@ -21,9 +21,22 @@ pub const COMMENT_MARKER: char = '*';
 /// auto-hibernation) — an anchor: the session stays put and won't hibernate.
 pub const KEEP_ALIVE_MARKER: char = '⚓';
 
+/// Marker shown, followed by the count, on a session row whose agent pane has
+/// clients attached — the TUI, a terminal, or remote viewers and spectators.
+pub const VIEWERS_MARKER: char = '◉';
+
 /// Suffix shown on a session row whose worktree is pulling Git LFS objects.
 pub const LFS_MARKER: &str = " ⇣ LFS";
 
+/// Badge glyph and colour for a session's project-task summary.
+pub fn task_badge(theme: &Theme, status: TaskStatus) -> (&'static str, Color) {
+    match status {
+        TaskStatus::Running => ("⟳", theme.status_creating),
+        TaskStatus::Passed => ("✓", theme.diff_added),
+        TaskStatus::Failed => ("✗", theme.diff_removed),
+    }
+}
+
 /// Pick the single status glyph and colour for a worktree row.
 ///
 /// Priority (first wins):
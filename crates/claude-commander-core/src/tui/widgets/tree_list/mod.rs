@@ -63,6 +63,9 @@ pub struct TreeList<'a> {
     /// Sessions with at least one pending review comment. A `*` marker is
     /// rendered on each matching session row.
     comment_sessions: HashSet<SessionId>,
+    /// Attached-client count per session, from the agent-state snapshots. A
+    /// `◉N` marker is rendered on each session with viewers.
+    viewers: HashMap<SessionId, u32>,
     /// Number + session colour for each worktree, precomputed over the full
     /// list. Recent-session rows look their values up here so they match the
     /// real row's number/colour even though the recents panel renders only its
@@ -85,6 +88,7 @@ impl<'a> TreeList<'a> {
             show_program_override: None,
             pull_blocked_projects: HashMap::new(),
             comment_sessions: HashSet::new(),
+            viewers: HashMap::new(),
             recent_display_info: HashMap::new(),
         }
     }
@@ -101,6 +105,18 @@ impl<'a> TreeList<'a> {
         self.comment_sessions.contains(id)
     }
 
+    /// Set the attached-client count per session. Renders a `◉N` marker on
+    /// each session with viewers.
+    pub fn session_viewers(mut self, viewers: HashMap<SessionId, u32>) -> Self {
+        self.viewers = viewers;
+        self
+    }
+
+    /// How many clients are viewing a session (0 when unknown).
+    pub(crate) fn session_viewer_count(&self, id: &SessionId) -> u32 {
+        self.viewers.get(id).copied().unwrap_or(0)
+    }
+
     /// Mark a set of projects as having a held-back background pull.
     /// Renders a ⚠ badge on each matching project row.
     pub fn pull_blocked_projects(mut self, blocked: HashMap<ProjectId, &'a str>) -> Self {
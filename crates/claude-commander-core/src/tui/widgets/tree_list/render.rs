@@ -70,6 +70,7 @@ struct SessionRow<'r> {
     show_program: bool,
     keep_alive: bool,
     has_comments: bool,
+    viewers: u32,
     lfs_pulling: bool,
     pr_number: Option<u32>,
     pr_state: Option<crate::git::PrState>,
@@ -129,6 +130,12 @@ impl<'a> TreeList<'a> {
                 Style::default().fg(self.theme.text_accent),
             ));
         }
+        if row.viewers > 0 {
+            spans.push(Span::styled(
+                format!(" {}{}", status_glyph::VIEWERS_MARKER, row.viewers),
+                Style::default().fg(self.theme.text_secondary),
+            ));
+        }
         if let Some(shown_branch) = crate::session::display_branch(row.title, row.branch) {
             spans.push(Span::styled(
                 format!(" [{}]", shown_branch),
@@ -349,6 +356,7 @@ impl<'a> TreeList<'a> {
                         show_program,
                         keep_alive: *keep_alive,
                         has_comments: self.session_has_comments(&session.id),
+                        viewers: self.session_viewer_count(&session.id),
                         lfs_pulling: *lfs_pulling,
                         pr_number: *pr_number,
                         pr_state: *pr_state,
@@ -391,6 +399,7 @@ impl<'a> TreeList<'a> {
                         show_program,
                         keep_alive: *keep_alive,
                         has_comments: self.session_has_comments(id),
+                        viewers: self.session_viewer_count(id),
                         lfs_pulling: *lfs_pulling,
                         pr_number: *pr_number,
                         pr_state: *pr_state,
@@ -41,6 +41,8 @@ fn make_worktree_with_stack(title: &str, stacked_child: bool) -> SessionListItem
         unread: false,
         keep_alive: false,
         lfs_pulling: false,
+        task_status: None,
+        tags: Vec::new(),
         stacked_child,
     }
 }
@@ -414,6 +416,27 @@ fn worktree_shows_pending_comment_marker() {
     );
 }
 
+#[test]
+fn test_viewer_count_marker_renders_on_viewed_sessions() {
+    let wt = make_worktree("Feature");
+    let id = match &wt {
+        SessionListItem::Worktree { id, .. } => *id,
+        _ => unreachable!(),
+    };
+    let items = vec![make_project("proj", 1), wt];
+    let marker = crate::tui::widgets::status_glyph::VIEWERS_MARKER;
+
+    let plain = render_tree(&items, 40, 4).join("\n");
+    assert!(!plain.contains(marker), "unexpected marker:\n{plain}");
+
+    let viewers: HashMap<SessionId, u32> = [(id, 3)].into_iter().collect();
+    let marked = render_tree_with(&items, 40, 4, |t| t.session_viewers(viewers.clone())).join("\n");
+    assert!(
+        marked.contains(&format!("{marker}3")),
+        "expected viewer count:\n{marked}"
+    );
+}
+
 #[test]
 fn test_worktree_rows_use_number_prefix() {
     let items = vec![
@@ -18,8 +18,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::comment::{Comment, CommentSide};
 use crate::diff::ParsedDiff;
-use crate::pr::{PrState, ReviewDecision};
-use crate::session::{AgentState, ProjectId, SessionId, SessionStatus};
+use crate::hook::HookRun;
+use crate::pr::{ChecksStatus, PrState, ReviewDecision};
+use crate::session::{AgentState, ArchiveInfo, PortBlock, ProjectId, SessionId, SessionStatus};
+use crate::task::TaskRun;
 
 /// A session as returned by the list/find/detail endpoints.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -86,6 +88,39 @@ pub struct SessionInfo {
     /// tmux session name backing this session.
     #[serde(default)]
     pub tmux_session_name: String,
+    /// Most recent lifecycle-hook runs, oldest first. FLUTTER: mirror lags;
+    /// field is #[serde(default)].
+    #[serde(default)]
+    pub hook_runs: Vec<HookRun>,
+    /// Ports reserved for this session (exported to its panes as `PORT` and
+    /// `COMMANDER_PORT_<n>`). FLUTTER: mirror lags; field is #[serde(default)].
+    #[serde(default)]
+    pub ports: Option<PortBlock>,
+    /// Latest run of each project task (`test`, `lint`, …), by name.
+    /// FLUTTER: mirror lags; field is #[serde(default)].
+    #[serde(default)]
+    pub task_runs: Vec<TaskRun>,
+    /// Login of the PR's author (`None` without a PR). FLUTTER: mirror lags;
+    /// field is #[serde(default)].
+    #[serde(default)]
+    pub pr_author: Option<String>,
+    /// User tags, normalised lowercase. FLUTTER: mirror lags; field is
+    /// #[serde(default)].
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Free-form user notes; empty when unset. FLUTTER: mirror lags; field is
+    /// #[serde(default)].
+    #[serde(default)]
+    pub notes: String,
+    /// Set while the session is archived (worktree removed, branch and
+    /// uncommitted work kept); its status is then `Stopped`. FLUTTER: mirror
+    /// lags; field is #[serde(default)].
+    #[serde(default)]
+    pub archived: Option<ArchiveInfo>,
+    /// Aggregate CI status of the session's PR (`None` without a PR, or before
+    /// it was first polled). FLUTTER: mirror lags; field is #[serde(default)].
+    #[serde(default)]
+    pub pr_checks: Option<ChecksStatus>,
 }
 
 /// A session plus its live detail: agent sub-state, diff summary, and a pane
@@ -97,6 +132,30 @@ pub struct SessionDetail {
     pub agent_state: AgentState,
     pub diff_stat: Option<String>,
     pub pane_content: Option<String>,
+    /// Live resource usage of the session's pane process tree. `None` when
+    /// the session isn't running or usage couldn't be read (non-Linux host,
+    /// pane gone). Additive; FLUTTER: mirror lags, field is #[serde(default)].
+    #[serde(default)]
+    pub resources: Option<ResourceUsage>,
+}
+
+/// A session's resource consumption, totalled over its agent pane, shell pane
+/// and task windows: each from its systemd scope's cgroup when it runs in one,
+/// otherwise summed over the pane's process tree.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    /// Resident memory in bytes.
+    pub rss_bytes: u64,
+    /// Cumulative CPU time consumed, in milliseconds.
+    pub cpu_time_ms: u64,
+    /// Recent CPU utilisation, as a percentage of one core (`150.0` = one and
+    /// a half cores busy). `None` when the host couldn't measure it.
+    #[serde(default)]
+    pub cpu_percent: Option<f32>,
+    /// Whether the numbers come from a limited per-session scope (`true`) or
+    /// a best-effort process-tree walk (`false`).
+    #[serde(default)]
+    pub scoped: bool,
 }
 
 /// Request to stage a new comment on a session's review diff.
@@ -118,6 +177,26 @@ pub struct ToggleReviewed {
     pub display_path: String,
 }
 
+/// Request to issue a share token for one session. `ttl_secs` defaults to an
+/// hour and is capped at a day server-side.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShareRequest {
+    #[serde(default)]
+    pub ttl_secs: Option<u64>,
+}
+
+/// A share token: lets whoever holds it make a read-only
+/// ([`Spectator`](crate::ws::AttachKind::Spectator)) `/ws/attach` to
+/// `session_id` until `expires_at`, and nothing else — it is not accepted by
+/// the `/api` surface. Tokens live in the server's memory, so a restart
+/// revokes them all.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareToken {
+    pub token: String,
+    pub session_id: SessionId,
+    pub expires_at: DateTime<Utc>,
+}
+
 /// Which side of a diff a binary blob fetch refers to: the base ("before") or
 /// the working tree ("after").
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -334,6 +413,12 @@ pub struct AgentStatesSnapshot {
     /// Whether a commander agent process appears to be running anywhere (used
     /// by the client to distinguish "no data yet" from "nothing running").
     pub commander_running: bool,
+    /// How many tmux clients are attached to each session's agent pane — a
+    /// terminal, the TUI, or a `/ws/attach` socket, spectators included.
+    /// Sessions nobody is viewing are absent. FLUTTER: mirror lags; field is
+    /// #[serde(default)].
+    #[serde(default)]
+    pub viewers: BTreeMap<SessionId, u32>,
 }
 
 /// Preview payload for a session or project: the agent pane snapshot, the diff
@@ -422,6 +507,20 @@ pub struct SetSection {
     pub section: Option<String>,
 }
 
+/// Request body for replacing a session's tags (`PATCH /sessions/{id}`). The
+/// server normalises them (lowercase, `#` and blanks dropped, deduped).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetTags {
+    pub tags: Vec<String>,
+}
+
+/// Request body for replacing a session's notes (`PATCH /sessions/{id}`). An
+/// empty string clears them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetNotes {
+    pub notes: String,
+}
+
 /// Request body for changing a session's launch program (`PATCH /sessions/{id}`).
 /// The new program is the command that will be relaunched in the pane; the
 /// owning host relaunches the agent fresh so it takes effect.
@@ -430,6 +529,21 @@ pub struct ChangeProgram {
     pub program: String,
 }
 
+/// Request body for the task routes (`POST /sessions/{id}/tasks/run` and
+/// `/tasks/send-failure`). `name: None` means every task for `run`, and the
+/// most recent failure for `send-failure`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaskRequest {
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Response body of `POST /undo`: what was undone, e.g. `deleted session "x"`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UndoResult {
+    pub summary: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -466,6 +580,16 @@ mod tests {
         assert_eq!(back.stack_parent, Some(parent));
     }
 
+    #[test]
+    fn resource_usage_optional_fields_default() {
+        let usage: ResourceUsage =
+            serde_json::from_str(r#"{"rss_bytes":1048576,"cpu_time_ms":2500}"#).unwrap();
+        assert_eq!(usage.rss_bytes, 1_048_576);
+        assert_eq!(usage.cpu_time_ms, 2500);
+        assert!(usage.cpu_percent.is_none());
+        assert!(!usage.scoped);
+    }
+
     #[test]
     fn new_comment_requires_all_fields() {
         // Unlike CreateSessionOpts, NewComment fields are all required.
@@ -491,11 +615,13 @@ mod tests {
         let a = serde_json::to_vec(&AgentStatesSnapshot {
             states: forward,
             commander_running: true,
+            viewers: BTreeMap::new(),
         })
         .unwrap();
         let b = serde_json::to_vec(&AgentStatesSnapshot {
             states: reverse,
             commander_running: true,
+            viewers: BTreeMap::new(),
         })
         .unwrap();
         assert_eq!(a, b, "insertion order leaked into the wire bytes");
@@ -543,6 +669,9 @@ mod tests {
         assert!(info.last_attached_at.is_none());
         assert_eq!(info.worktree_path, String::new());
         assert_eq!(info.tmux_session_name, "");
+        assert!(info.pr_author.is_none());
+        assert!(info.tags.is_empty());
+        assert_eq!(info.notes, "");
     }
 
     #[test]
@@ -659,11 +788,18 @@ mod tests {
         let snap = AgentStatesSnapshot {
             states,
             commander_running: true,
+            viewers: BTreeMap::from([(sid, 2)]),
         };
         let json = serde_json::to_string(&snap).unwrap();
         let back: AgentStatesSnapshot = serde_json::from_str(&json).unwrap();
         assert_eq!(back.states.get(&sid), Some(&AgentState::Working));
         assert!(back.commander_running);
+        assert_eq!(back.viewers.get(&sid), Some(&2));
+
+        // A server that predates viewer counts omits the field.
+        let old: AgentStatesSnapshot =
+            serde_json::from_str(r#"{"states":{},"commander_running":false}"#).unwrap();
+        assert!(old.viewers.is_empty());
     }
 
     #[test]
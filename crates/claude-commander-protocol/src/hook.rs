@@ -0,0 +1,126 @@
+//! Lifecycle-hook wire types.
+//!
+//! Which lifecycle event a hook ran for and how its run ended. The hook
+//! resolution and runner live in `claude-commander-core`; only the recorded
+//! runs cross the network, so a remote client's Info view can show them.
+
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A session lifecycle event that can trigger hook commands.
+///
+/// FLUTTER: mirror this enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEvent {
+    /// The worktree exists, the agent hasn't started yet.
+    PostCreate,
+    /// The session is about to be deleted; the worktree is still there.
+    PreDelete,
+    /// Something was merged: the session's own PR, or a cascade merge that
+    /// brought new commits into its worktree.
+    PostMerge,
+    /// The agent's detected state changed (e.g. working → idle).
+    AgentStateChange,
+    /// The session's PR state changed (opened, merged, closed, gone).
+    PrStateChange,
+}
+
+impl HookEvent {
+    /// Every event, in lifecycle order.
+    pub const ALL: [HookEvent; 5] = [
+        HookEvent::PostCreate,
+        HookEvent::PreDelete,
+        HookEvent::PostMerge,
+        HookEvent::AgentStateChange,
+        HookEvent::PrStateChange,
+    ];
+}
+
+impl fmt::Display for HookEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PostCreate => write!(f, "post_create"),
+            Self::PreDelete => write!(f, "pre_delete"),
+            Self::PostMerge => write!(f, "post_merge"),
+            Self::AgentStateChange => write!(f, "agent_state_change"),
+            Self::PrStateChange => write!(f, "pr_state_change"),
+        }
+    }
+}
+
+/// How a hook command's run ended.
+///
+/// FLUTTER: mirror this enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum HookOutcome {
+    /// Exited 0.
+    Succeeded,
+    /// Exited non-zero (`code` is `None` when killed by a signal).
+    Failed { code: Option<i32> },
+    /// Overran its timeout and was killed along with its children.
+    TimedOut,
+    /// `sh` itself could not be spawned.
+    SpawnFailed,
+}
+
+impl HookOutcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Succeeded)
+    }
+}
+
+impl fmt::Display for HookOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Succeeded => write!(f, "ok"),
+            Self::Failed { code: Some(code) } => write!(f, "exit {code}"),
+            Self::Failed { code: None } => write!(f, "killed"),
+            Self::TimedOut => write!(f, "timed out"),
+            Self::SpawnFailed => write!(f, "failed to start"),
+        }
+    }
+}
+
+/// One recorded hook run, kept on the session so the Info view can show the
+/// most recent results.
+///
+/// FLUTTER: mirror this DTO.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HookRun {
+    pub event: HookEvent,
+    pub command: String,
+    pub outcome: HookOutcome,
+    pub finished_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    /// The last lines of combined stdout/stderr, trimmed to a bounded size.
+    #[serde(default)]
+    pub output_tail: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_serializes_snake_case() {
+        assert_eq!(
+            serde_json::to_string(&HookEvent::AgentStateChange).unwrap(),
+            r#""agent_state_change""#
+        );
+        // Display matches the config key for the same event.
+        assert_eq!(HookEvent::PostCreate.to_string(), "post_create");
+    }
+
+    #[test]
+    fn outcome_round_trips_tagged() {
+        let json = serde_json::to_string(&HookOutcome::Failed { code: Some(2) }).unwrap();
+        assert_eq!(json, r#"{"kind":"failed","code":2}"#);
+        let back: HookOutcome = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, HookOutcome::Failed { code: Some(2) });
+        assert_eq!(back.to_string(), "exit 2");
+    }
+}
@@ -18,7 +18,10 @@ pub mod comment;
 pub mod connection;
 pub mod diff;
 pub mod github;
+pub mod hook;
 pub mod paste;
 pub mod pr;
+pub mod scrollback;
 pub mod session;
+pub mod task;
 pub mod ws;
@@ -1,8 +1,8 @@
 //! Pull-request wire enums.
 //!
-//! The PR *state* and *review decision* a client renders as badges. The PR
-//! fetch/derivation logic (and richer types like check status) stays in
-//! `claude-commander-core`; only these two enums cross the network.
+//! The PR *state*, *review decision* and CI *checks* status a client renders as
+//! badges. The PR fetch/derivation logic stays in `claude-commander-core`; only
+//! these enums cross the network.
 
 use serde::{Deserialize, Serialize};
 
@@ -27,6 +27,27 @@ pub enum ReviewDecision {
     ChangesRequested,
 }
 
+/// Aggregate CI/checks status derived from `statusCheckRollup`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChecksStatus {
+    Passing,
+    Failing,
+    Pending,
+    None,
+}
+
+impl std::fmt::Display for ChecksStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Passing => write!(f, "Passing"),
+            Self::Failing => write!(f, "Failing"),
+            Self::Pending => write!(f, "Pending"),
+            Self::None => write!(f, "None"),
+        }
+    }
+}
+
 impl std::fmt::Display for ReviewDecision {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -0,0 +1,115 @@
+//! Scrollback search wire types.
+//!
+//! Each session's tmux history is indexed where the session runs (see
+//! `claude-commander-core`'s `scrollback` module); only queries and hits cross
+//! the network. A hit carries enough to find its line again in a live pane —
+//! the text and its distance from the newest indexed line — because absolute
+//! line numbers shift as tmux trims the oldest history.
+
+use serde::{Deserialize, Serialize};
+
+use crate::session::SessionId;
+
+/// Hits returned when a search names no limit.
+pub const DEFAULT_SEARCH_LIMIT: usize = 200;
+
+/// A scrollback search (`GET /scrollback/search`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScrollbackSearch {
+    /// What to look for. Case-insensitive unless it contains an uppercase
+    /// letter ("smart case").
+    pub pattern: String,
+    /// Treat `pattern` as a regular expression rather than literal text.
+    #[serde(default)]
+    pub regex: bool,
+    /// Cap on the hits returned; [`DEFAULT_SEARCH_LIMIT`] when absent.
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+impl ScrollbackSearch {
+    /// A literal-text search with the default limit.
+    pub fn literal(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            regex: false,
+            limit: None,
+        }
+    }
+
+    /// The effective hit cap.
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_SEARCH_LIMIT)
+    }
+}
+
+/// One matching scrollback line.
+///
+/// FLUTTER: mirror this DTO.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScrollbackHit {
+    pub session_id: SessionId,
+    /// 1-based line number within the session's indexed scrollback.
+    pub line: usize,
+    /// Lines between this one and the newest indexed line (0 = the newest).
+    /// Stable while the session prints, unlike [`Self::line`] once tmux
+    /// starts trimming history — so it is what a reveal navigates by.
+    pub from_end: usize,
+    /// The line's text, escape sequences stripped.
+    pub text: String,
+    /// Byte range of the first match within [`Self::text`], for highlighting.
+    pub match_start: usize,
+    pub match_end: usize,
+}
+
+impl ScrollbackHit {
+    /// What to send to reveal this hit in its pane.
+    pub fn reveal(&self) -> ScrollbackReveal {
+        ScrollbackReveal {
+            text: self.text.clone(),
+            from_end: self.from_end,
+        }
+    }
+}
+
+/// Request body for `POST /sessions/{id}/scrollback/reveal`: put the pane in
+/// copy-mode scrolled to the line with this text nearest `from_end` lines
+/// above the newest history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScrollbackReveal {
+    pub text: String,
+    pub from_end: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_defaults_to_literal_with_the_default_limit() {
+        let search: ScrollbackSearch = serde_json::from_str(r#"{"pattern":"panic"}"#).unwrap();
+        assert_eq!(search, ScrollbackSearch::literal("panic"));
+        assert_eq!(search.limit(), DEFAULT_SEARCH_LIMIT);
+    }
+
+    #[test]
+    fn hit_reveal_carries_text_and_distance() {
+        let hit = ScrollbackHit {
+            session_id: SessionId::new(),
+            line: 12,
+            from_end: 3,
+            text: "error: boom".to_string(),
+            match_start: 0,
+            match_end: 5,
+        };
+        let json = serde_json::to_string(&hit).unwrap();
+        assert_eq!(serde_json::from_str::<ScrollbackHit>(&json).unwrap(), hit);
+        assert_eq!(
+            hit.reveal(),
+            ScrollbackReveal {
+                text: "error: boom".to_string(),
+                from_end: 3,
+            }
+        );
+    }
+}
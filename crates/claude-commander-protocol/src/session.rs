@@ -7,6 +7,7 @@
 
 use std::fmt;
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -159,6 +160,7 @@ pub enum AgentState {
     /// Claude has finished and is at the input prompt
     Idle,
     /// Claude is waiting for user permission or input
+    #[serde(alias = "waiting")]
     WaitingForInput,
     /// State could not be determined (non-Claude program, detection failure, etc.)
     Unknown,
@@ -175,10 +177,94 @@ impl fmt::Display for AgentState {
     }
 }
 
+/// A contiguous block of TCP ports reserved for one session, so concurrent
+/// sessions of the same app can each run a dev server without colliding.
+///
+/// FLUTTER: mirror this DTO.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PortBlock {
+    /// First port of the block; also what `PORT` is set to.
+    pub start: u16,
+    /// Number of ports in the block (`start..start + count`).
+    pub count: u16,
+}
+
+impl PortBlock {
+    /// Every port in the block, in order.
+    pub fn ports(&self) -> impl Iterator<Item = u16> + use<> {
+        let (start, count) = (self.start, self.count);
+        (0..count).filter_map(move |i| start.checked_add(i))
+    }
+
+    /// Whether the two blocks share any port.
+    pub fn overlaps(&self, other: &PortBlock) -> bool {
+        let end = u32::from(self.start) + u32::from(self.count);
+        let other_end = u32::from(other.start) + u32::from(other.count);
+        u32::from(self.start) < other_end && u32::from(other.start) < end
+    }
+}
+
+impl fmt::Display for PortBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.count {
+            0 | 1 => write!(f, "{}", self.start),
+            n => write!(
+                f,
+                "{}–{}",
+                self.start,
+                u32::from(self.start) + u32::from(n) - 1
+            ),
+        }
+    }
+}
+
+/// What an archived session keeps in place of its worktree: the commit its
+/// branch was at and the hidden ref pinning any uncommitted work. Present only
+/// while the session is archived; restoring clears it.
+///
+/// FLUTTER: mirror this DTO.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchiveInfo {
+    /// When the session was archived.
+    pub archived_at: DateTime<Utc>,
+    /// The branch's commit at archive time. Restore recreates the branch here
+    /// if it has since been deleted.
+    pub head: String,
+    /// Hidden ref (`refs/commander/archive/<session-id>`) pinning the archive,
+    /// so neither `head` nor the snapshot is garbage-collected.
+    pub archive_ref: String,
+    /// Whether `archive_ref` is a snapshot commit of uncommitted changes on top
+    /// of `head` (re-applied on restore) rather than `head` itself.
+    pub has_snapshot: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn port_block_ranges_and_overlap() {
+        let a = PortBlock {
+            start: 20000,
+            count: 5,
+        };
+        assert_eq!(
+            a.ports().collect::<Vec<_>>(),
+            (20000..20005).collect::<Vec<_>>()
+        );
+        assert_eq!(a.to_string(), "20000–20004");
+        let touching = PortBlock {
+            start: 20005,
+            count: 5,
+        };
+        assert!(!a.overlaps(&touching));
+        let straddling = PortBlock {
+            start: 20004,
+            count: 2,
+        };
+        assert!(a.overlaps(&straddling) && straddling.overlaps(&a));
+    }
+
     #[test]
     fn session_status_round_trips_and_aliases_paused() {
         // Canonical snake_case wire form.
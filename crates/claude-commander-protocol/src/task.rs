@@ -0,0 +1,131 @@
+//! Project-task wire types.
+//!
+//! Named project tasks (`test`, `lint`, …) run in a session's task window;
+//! the runner lives in `claude-commander-core`. Only each task's latest run
+//! crosses the network, so remote clients can badge cards and show output.
+
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Where a task run stands.
+///
+/// FLUTTER: mirror this enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatus {
+    /// Still running in its task window.
+    Running,
+    /// Exited 0.
+    Passed,
+    /// Exited non-zero, was killed, or its window disappeared.
+    Failed,
+}
+
+impl TaskStatus {
+    /// One status for a set of runs, as shown on a session's card: any
+    /// failure wins (it's what needs attention), then anything still
+    /// running, then passed. `None` when no task has run.
+    pub fn summarize<'a>(runs: impl IntoIterator<Item = &'a TaskRun>) -> Option<TaskStatus> {
+        runs.into_iter().map(|r| r.status).max_by_key(|s| match s {
+            Self::Passed => 0,
+            Self::Running => 1,
+            Self::Failed => 2,
+        })
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Running => write!(f, "running"),
+            Self::Passed => write!(f, "passed"),
+            Self::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+/// The latest run of one named task in a session.
+///
+/// FLUTTER: mirror this DTO.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskRun {
+    /// Task name, as configured (`test`, `lint`, …).
+    pub name: String,
+    /// The command that ran.
+    pub command: String,
+    pub status: TaskStatus,
+    /// Exit code once finished; `None` while running or when killed by a
+    /// signal.
+    #[serde(default)]
+    pub exit_code: Option<i32>,
+    pub started_at: DateTime<Utc>,
+    #[serde(default)]
+    pub finished_at: Option<DateTime<Utc>>,
+    /// The last lines of the task window's output, trimmed to a bounded size.
+    #[serde(default)]
+    pub output_tail: String,
+}
+
+/// The outcome of starting one task from a run-tasks request. Every task is
+/// attempted, so one that can't start doesn't keep the rest from running.
+///
+/// FLUTTER: mirror this DTO.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskStart {
+    /// Task name, as configured.
+    pub name: String,
+    /// Why the task couldn't be started; `None` when it is now running.
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl TaskStart {
+    pub fn started(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(name: &str, status: TaskStatus) -> TaskRun {
+        TaskRun {
+            name: name.to_string(),
+            command: "true".to_string(),
+            status,
+            exit_code: None,
+            started_at: Utc::now(),
+            finished_at: None,
+            output_tail: String::new(),
+        }
+    }
+
+    #[test]
+    fn summary_prefers_failure_then_running() {
+        assert_eq!(TaskStatus::summarize(&[]), None);
+        let passed = run("lint", TaskStatus::Passed);
+        let running = run("build", TaskStatus::Running);
+        let failed = run("test", TaskStatus::Failed);
+        assert_eq!(
+            TaskStatus::summarize([&passed, &running]),
+            Some(TaskStatus::Running)
+        );
+        assert_eq!(
+            TaskStatus::summarize([&passed, &running, &failed]),
+            Some(TaskStatus::Failed)
+        );
+        assert_eq!(TaskStatus::summarize([&passed]), Some(TaskStatus::Passed));
+    }
+
+    #[test]
+    fn run_round_trips_with_defaults() {
+        let json = r#"{"name":"test","command":"cargo test","status":"running","started_at":"2026-01-01T00:00:00Z"}"#;
+        let run: TaskRun = serde_json::from_str(json).unwrap();
+        assert_eq!(run.status, TaskStatus::Running);
+        assert_eq!(run.exit_code, None);
+        assert!(run.output_tail.is_empty());
+    }
+}
@@ -75,9 +75,12 @@ pub enum ClientControl {
     Auth { token: String },
     /// Second frame: attach to a session. `session_id` is resolved exactly like
     /// the HTTP API's `find_session` (full UUID, ID prefix, or exact title).
-    /// `kind` selects the agent pane (default) or the paired shell pane; it is
-    /// omitted on the wire for an agent attach, so an old client's
-    /// `{"type":"attach","session_id":…}` frame parses unchanged.
+    /// `kind` selects the agent pane (default), the paired shell pane, or a
+    /// read-only view of the agent pane; it is omitted on the wire for an agent
+    /// attach, so an old client's `{"type":"attach","session_id":…}` frame
+    /// parses unchanged. A socket authenticated with a share token may only
+    /// make a [`Spectator`](AttachKind::Spectator) attach, and only to the
+    /// session the token was issued for — by its full UUID.
     ///
     /// `cols`/`rows` are the client's terminal size. They are carried *here*,
     /// in the handshake, rather than left to the first [`Resize`](Self::Resize)
@@ -153,10 +156,15 @@ pub enum ServerControl {
 pub const WS_ERR_AUTH: &str = "authentication failed";
 
 /// Fixed [`ServerControl::Error`] handshake message for an attach to a session
-/// that doesn't exist. Shared by the server (which sends it) and the client
-/// (which classifies it), so the wording is a single source of truth.
+/// that doesn't exist — or, for a spectator, one whose agent pane isn't live.
+/// Shared by the server (which sends it) and the client (which classifies it),
+/// so the wording is a single source of truth.
 pub const WS_ERR_NO_SESSION: &str = "no such session";
 
+/// Fixed [`ServerControl::Error`] handshake message for an attach a share token
+/// doesn't cover: a different session, or anything but a spectator attach.
+pub const WS_ERR_SHARE_SCOPE: &str = "share token does not cover this attach";
+
 /// Which pane of a session to attach to. Mirrors core's `backend::AttachKind`
 /// but lives here so the wire shape has one source of truth. Serialized inside
 /// [`ClientControl::Attach`]; [`Agent`](Self::Agent) is the default and is
@@ -170,6 +178,15 @@ pub enum AttachKind {
     Agent,
     /// The paired shell pane (Ctrl+\ toggles here), created on demand.
     Shell,
+    /// The agent pane, read-only. The server drops every input frame and
+    /// attaches its tmux client with `-r`, so a spectator watches the agent
+    /// live but can't type into it. Its PTY is sized to the window it watches
+    /// and `resize` is ignored, so a viewer never shrinks or reflows the
+    /// owner's pane; the attach frame's `cols`/`rows` are unused. `refresh`
+    /// and `detach` still work. Unlike the other kinds it never revives
+    /// a stopped session or counts as an attach for MRU ordering: a session
+    /// that isn't running gets [`WS_ERR_NO_SESSION`].
+    Spectator,
 }
 
 impl AttachKind {
@@ -178,6 +195,11 @@ impl AttachKind {
     pub fn is_agent(&self) -> bool {
         matches!(self, AttachKind::Agent)
     }
+
+    /// Whether input from this attach must be dropped.
+    pub fn is_read_only(&self) -> bool {
+        matches!(self, AttachKind::Spectator)
+    }
 }
 
 /// Why an attach ended. Serialized as part of [`ServerControl::Detached`].
@@ -190,6 +212,8 @@ pub enum DetachReason {
     SessionEnded,
     /// The transport dropped (socket closed, heartbeat timed out).
     Transport,
+    /// The share token a spectator attached with expired or was revoked.
+    ShareEnded,
 }
 
 impl ClientControl {
@@ -267,6 +291,24 @@ mod tests {
         assert_eq!(ClientControl::from_text(&json).unwrap(), msg);
     }
 
+    #[test]
+    fn client_attach_spectator_round_trip() {
+        let msg = ClientControl::Attach {
+            session_id: "abc123".into(),
+            kind: AttachKind::Spectator,
+            cols: Some(100),
+            rows: Some(30),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"attach","session_id":"abc123","kind":"spectator","cols":100,"rows":30}"#
+        );
+        assert_eq!(ClientControl::from_text(&json).unwrap(), msg);
+        assert!(AttachKind::Spectator.is_read_only());
+        assert!(!AttachKind::Agent.is_read_only() && !AttachKind::Shell.is_read_only());
+    }
+
     /// The handshake must be able to carry the client's geometry, so the server
     /// can size the PTY *before* spawning `tmux attach-session` and tmux never
     /// paints a screen at a width the client will re-wrap.
@@ -386,6 +428,7 @@ mod tests {
             (DetachReason::ClientRequest, "client_request"),
             (DetachReason::SessionEnded, "session_ended"),
             (DetachReason::Transport, "transport"),
+            (DetachReason::ShareEnded, "share_ended"),
         ] {
             let msg = ServerControl::Detached { reason };
             let json = msg.to_text();
@@ -30,6 +30,8 @@ use claude_commander_core::backend::{
 use claude_commander_core::comment::{ApplyOutcome, Comment};
 use claude_commander_core::session::{ProjectId, ScanResult, SessionId};
 use claude_commander_protocol::github::{CloneJob, CloneJobId, CloneRequest, GithubRepo};
+use claude_commander_protocol::scrollback::{ScrollbackHit, ScrollbackReveal, ScrollbackSearch};
+use claude_commander_protocol::task::TaskStart;
 use claude_commander_protocol::ws::AttachKind as WsAttachKind;
 use uuid::Uuid;
 
@@ -165,6 +167,13 @@ impl CommanderBackend for RemoteBackend {
             .map_err(into_backend_error)
     }
 
+    async fn pane_content(&self, id: SessionId, lines: usize) -> BResult<Option<String>> {
+        self.client
+            .session_pane(id, lines)
+            .await
+            .map_err(into_backend_error)
+    }
+
     async fn preview(&self, target: PreviewTarget) -> BResult<PreviewData> {
         let result = match target {
             PreviewTarget::Session { id, lines } => self.client.session_preview(id, lines).await,
@@ -245,6 +254,24 @@ impl CommanderBackend for RemoteBackend {
             .map_err(into_backend_error)
     }
 
+    async fn archive_session(&self, id: SessionId) -> BResult<()> {
+        self.client
+            .archive_session(id)
+            .await
+            .map_err(into_backend_error)
+    }
+
+    async fn restore_session(&self, id: SessionId) -> BResult<()> {
+        self.client
+            .restore_session(id)
+            .await
+            .map_err(into_backend_error)
+    }
+
+    async fn undo(&self) -> BResult<String> {
+        self.client.undo().await.map_err(into_backend_error)
+    }
+
     async fn rename_session(&self, id: SessionId, title: String) -> BResult<()> {
         self.client
             .rename_session(id, title)
@@ -258,6 +285,20 @@ impl CommanderBackend for RemoteBackend {
             .await
             .map_err(into_backend_error)
     }
+
+    async fn set_tags(&self, id: SessionId, tags: Vec<String>) -> BResult<()> {
+        self.client
+            .set_tags(id, tags)
+            .await
+            .map_err(into_backend_error)
+    }
+
+    async fn set_notes(&self, id: SessionId, notes: String) -> BResult<()> {
+        self.client
+            .set_notes(id, notes)
+            .await
+            .map_err(into_backend_error)
+    }
     async fn change_program(&self, id: SessionId, program: String) -> BResult<()> {
         self.client
             .change_program(id, program)
@@ -282,6 +323,34 @@ impl CommanderBackend for RemoteBackend {
             .map_err(into_backend_error)
     }
 
+    async fn run_tasks(&self, id: SessionId, name: Option<String>) -> BResult<Vec<TaskStart>> {
+        self.client
+            .run_tasks(id, name)
+            .await
+            .map_err(into_backend_error)
+    }
+
+    async fn send_task_failure(&self, id: SessionId, name: Option<String>) -> BResult<String> {
+        self.client
+            .send_task_failure(id, name)
+            .await
+            .map_err(into_backend_error)
+    }
+
+    async fn search_scrollback(&self, search: ScrollbackSearch) -> BResult<Vec<ScrollbackHit>> {
+        self.client
+            .search_scrollback(&search)
+            .await
+            .map_err(into_backend_error)
+    }
+
+    async fn reveal_scrollback_line(&self, id: SessionId, reveal: ScrollbackReveal) -> BResult<()> {
+        self.client
+            .reveal_scrollback_line(id, &reveal)
+            .await
+            .map_err(into_backend_error)
+    }
+
     async fn mark_unread(&self, ids: Vec<SessionId>) -> BResult<()> {
         self.client
             .mark_unread(ids)
@@ -713,6 +782,48 @@ mod tests {
         assert_eq!(s.title, "renamed-over-http");
     }
 
+    #[tokio::test]
+    async fn tags_and_notes_round_trip_over_http() {
+        let (addr, service, _d, _w) = serve_disabled().await;
+        let project = Project::new("repo", PathBuf::from("/tmp/repo"), "main");
+        let pid = project.id;
+        let session = WorktreeSession::new(
+            pid,
+            "task",
+            "branch-task",
+            PathBuf::from("/tmp/wt"),
+            "claude",
+        );
+        let sid = session.id;
+        service
+            .store()
+            .mutate(move |state| {
+                state.add_project(project);
+                state.add_session(session);
+            })
+            .await
+            .unwrap();
+
+        let backend = RemoteBackend::with_config(spec(addr, None), idle_config()).unwrap();
+        backend
+            .set_tags(sid, vec!["Needs Review".to_string()])
+            .await
+            .unwrap();
+        backend
+            .set_notes(sid, "ping the API team".to_string())
+            .await
+            .unwrap();
+
+        let snap = backend.workspace_snapshot().await.unwrap();
+        let s = snap
+            .sessions
+            .iter()
+            .find(|s| s.session_id == sid)
+            .expect("session present");
+        assert_eq!(s.tags, vec!["needs-review"]);
+        assert_eq!(s.notes, "ping the API team");
+    }
+
     #[tokio::test]
     async fn mark_unread_flags_sessions_over_http() {
         // Wire check for `POST /api/sessions/unread`: a seeded, read session is
@@ -30,7 +30,8 @@
 //! | `kill_session` / `restart_session` | `POST /api/sessions/{id}/kill` / `…/restart` |
 //! | `restart_session_fresh` | `POST /api/sessions/{id}/restart-fresh` (no resume) |
 //! | `delete_session` | `DELETE /api/sessions/{id}` |
-//! | `rename_session` / `set_section` | `PATCH /api/sessions/{id}` (tagged `op`) |
+//! | `archive_session` / `restore_session` | `POST /api/sessions/{id}/archive` / `…/restore` |
+//! | `rename_session` / `set_section` / `set_tags` / `set_notes` | `PATCH /api/sessions/{id}` (tagged `op`) |
 //! | `mark_read` | `POST /api/sessions/{id}/read` |
 //! | `mark_unread` | `POST /api/sessions/unread` (batch) |
 //! | `add_project` | `POST /api/projects` → `{id}` |
@@ -38,6 +39,7 @@
 //! | `scan_directory` | `POST /api/projects/scan` → `{path}` |
 //! | `cascade_merge` / `push_stack` | `POST /api/sessions/{id}/cascade` / `…/push-stack` |
 //! | `cascade_resume` / `cascade_abandon` | `POST /api/cascade/resume` / `…/abandon` |
+//! | `undo` | `POST /api/undo` → `{summary}` |
 //! | `list_comments` / `open_review` | `GET /api/sessions/{id}/comments` / `…/review` |
 //! | `refresh_review_if_changed` | `GET /api/sessions/{id}/review/refresh?prev_hash=` (204 → `None`) |
 //! | `create_comment` / `delete_comment` | `POST` / `DELETE /api/sessions/{id}/comments[/{cid}]` |
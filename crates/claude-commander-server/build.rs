@@ -0,0 +1,23 @@
+//! Check that the xterm.js files the web UI embeds with `include_bytes!` are
+//! vendored, so a checkout without them fails with the fix rather than a bare
+//! "couldn't read" error (see `web/vendor/README.md`).
+
+use std::path::Path;
+
+const VENDOR_DIR: &str = "web/vendor";
+const VENDORED: [&str; 4] = ["xterm.js", "xterm.css", "addon-fit.js", "LICENSE"];
+
+fn main() {
+    println!("cargo::rerun-if-changed={VENDOR_DIR}");
+    let dir = Path::new(VENDOR_DIR);
+    let missing: Vec<&str> = VENDORED
+        .into_iter()
+        .filter(|file| !dir.join(file).is_file())
+        .collect();
+    if !missing.is_empty() {
+        panic!(
+            "{VENDOR_DIR} is missing {}: run scripts/vendor-xterm.sh and commit what it writes",
+            missing.join(", ")
+        );
+    }
+}
@@ -53,6 +53,10 @@ pub struct ServerConfig {
     /// CORS allowlist of permitted origins. Empty means same-origin/deny
     /// (browsers can't call `/api` cross-origin unless listed here).
     pub cors_allowed_origins: Vec<String>,
+    /// Serve the browser UI at `/`. Defaults to `true`; the page holds no data
+    /// of its own, everything it shows goes through the bearer-authenticated
+    /// API.
+    pub web_ui: bool,
 }
 
 impl Default for ServerConfig {
@@ -64,6 +68,7 @@ impl Default for ServerConfig {
             tls_cert_path: None,
             tls_key_path: None,
             cors_allowed_origins: Vec::new(),
+            web_ui: true,
         }
     }
 }
@@ -161,6 +166,7 @@ mod tests {
         assert_eq!(cfg.port, 7878);
         assert!(cfg.token.is_none());
         assert!(cfg.cors_allowed_origins.is_empty());
+        assert!(cfg.web_ui);
     }
 
     #[test]
@@ -69,17 +69,26 @@ impl ApiError {
             CoreError::Session(SessionError::NotFound(_))
             | CoreError::Session(SessionError::ProjectNotFound(_))
             | CoreError::Session(SessionError::TmuxSessionNotFound(_))
-            | CoreError::Session(SessionError::FileNotInDiff(_)) => StatusCode::NOT_FOUND,
+            | CoreError::Session(SessionError::FileNotInDiff(_))
+            | CoreError::Session(SessionError::TaskNotFound(_))
+            | CoreError::Session(SessionError::LineNotInScrollback(_))
+            // An empty (or fully expired) undo journal.
+            | CoreError::Session(SessionError::NothingToUndo) => StatusCode::NOT_FOUND,
 
             // Conflicting existing state → 409.
             CoreError::Session(SessionError::AlreadyExists(_))
             | CoreError::Session(SessionError::InvalidState(_))
-            | CoreError::Session(SessionError::MaxSessionsReached(_)) => StatusCode::CONFLICT,
+            | CoreError::Session(SessionError::Archived(_))
+            | CoreError::Session(SessionError::MaxSessionsReached(_))
+            // A blocking lifecycle hook refused the operation: the session is
+            // untouched, and the request can succeed once the hook passes.
+            | CoreError::Session(SessionError::HookFailed { .. }) => StatusCode::CONFLICT,
 
             // Bad client input → 400.
             CoreError::Session(SessionError::InvalidName { .. })
             | CoreError::Session(SessionError::InvalidProgram(_))
             | CoreError::Session(SessionError::InvalidImage(_))
+            | CoreError::Session(SessionError::InvalidSearchPattern(_))
             // A refused clone source/destination name is the client's mistake, not
             // a git failure — which is exactly why core gives it its own variant.
             // Its message is redacted at construction (`clone_source_rejected`),
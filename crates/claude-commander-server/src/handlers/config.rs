@@ -48,12 +48,13 @@ pub async fn read(State(state): State<AppState>) -> Json<Config> {
 /// a conservative allow-list of benign UI/timing/behaviour options — may be
 /// changed. Filesystem-path fields (`worktrees_dir`, `log_file`,
 /// `commander_dir`, `per_repo_worktree_dirs`), program-launch fields
-/// (`programs`, `shell_program`, `editor`, `editor_gui`,
-/// `commander_program`, `commander_enabled`, `nix_develop`), and complex nested
-/// tables (`keybindings`, `theme`, `sections`, `conversation`, `stt`,
-/// `telemetry`) are intentionally absent, so a request can neither set nor
-/// reset them *here* — `programs` is editable, but only via its own dedicated
-/// route [`put_programs`], never this general patch.
+/// (`programs`, `shell_program`, `editor`, `editor_gui`, `commander_program`,
+/// `commander_enabled`, `nix_develop`, `tasks`, `custom_commands`), and
+/// complex nested tables (`keybindings`, `theme`, `sections`, `conversation`,
+/// `stt`, `telemetry`, `resource_limits`, `hooks`, `ports`) are intentionally
+/// absent, so a request can neither set nor reset them *here* — `programs` is
+/// editable, but only via its own dedicated route [`put_programs`], never this
+/// general patch.
 /// `deny_unknown_fields` means a body that even *mentions* such a
 /// field is rejected (400) rather than silently dropped — a clear signal to the
 /// caller that the field is off-limits.
@@ -80,6 +81,7 @@ pub struct ConfigPatch {
     pub rounded_borders: Option<bool>,
     pub precompute_review_caches: Option<bool>,
     pub in_progress_limit: Option<Option<u32>>,
+    pub in_progress_lane_limit: Option<Option<u32>>,
 }
 
 impl ConfigPatch {
@@ -112,6 +114,7 @@ impl ConfigPatch {
         set!(rounded_borders);
         set!(precompute_review_caches);
         set!(in_progress_limit);
+        set!(in_progress_lane_limit);
     }
 }
 
@@ -10,7 +10,9 @@ pub mod health;
 pub mod paste;
 pub mod projects;
 pub mod review;
+pub mod scrollback;
 pub mod sessions;
+pub mod web;
 pub mod workspace;
 
 #[cfg(test)]
@@ -0,0 +1,93 @@
+//! Scrollback search handlers.
+//!
+//! [`search`] queries the server's scrollback index across every session;
+//! [`reveal`] scrolls one session's pane to a hit ahead of an attach. Both reach
+//! tmux (a search refreshes the index on demand when the background loop is
+//! off), so both run on the local pool like the other tmux-driving handlers.
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use claude_commander_protocol::scrollback::{ScrollbackHit, ScrollbackReveal, ScrollbackSearch};
+
+use crate::error::ApiError;
+use crate::state::AppState;
+
+use super::{parse_session_id, run_local};
+
+/// `GET /scrollback/search?pattern=&regex=&limit=` → `search_scrollback` →
+/// the hits, newest first per session. 400 for an empty pattern or a regex
+/// that doesn't compile.
+pub async fn search(
+    State(state): State<AppState>,
+    Query(search): Query<ScrollbackSearch>,
+) -> Result<Json<Vec<ScrollbackHit>>, ApiError> {
+    let hits =
+        run_local(move || async move { state.service.search_scrollback(&search).await }).await?;
+    Ok(Json(hits))
+}
+
+/// `POST /sessions/{id}/scrollback/reveal` with a [`ScrollbackReveal`] →
+/// `reveal_scrollback_line` → 204. 404 when the session is unknown or the
+/// line has since been trimmed from its history.
+pub async fn reveal(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(body): Json<ScrollbackReveal>,
+) -> Result<StatusCode, ApiError> {
+    let id = parse_session_id(&id)?;
+    run_local(move || async move { state.service.reveal_scrollback_line(&id, &body).await })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::{Router, routing::get, routing::post};
+    use claude_commander_protocol::scrollback::ScrollbackHit;
+    use tempfile::TempDir;
+
+    use crate::handlers::test_support::{get as do_get, json, send, test_state};
+
+    fn router(dir: &TempDir) -> Router {
+        Router::new()
+            .route("/scrollback/search", get(super::search))
+            .route("/sessions/{id}/scrollback/reveal", post(super::reveal))
+            .with_state(test_state(dir))
+    }
+
+    #[tokio::test]
+    async fn search_with_no_sessions_is_empty() {
+        let dir = TempDir::new().unwrap();
+        let (status, body) = do_get(router(&dir), "/scrollback/search?pattern=panic").await;
+        assert_eq!(status, 200, "body={}", String::from_utf8_lossy(&body));
+        assert!(json::<Vec<ScrollbackHit>>(&body).is_empty());
+    }
+
+    #[tokio::test]
+    async fn search_rejects_a_bad_regex() {
+        let dir = TempDir::new().unwrap();
+        let (status, _) = do_get(
+            router(&dir),
+            "/scrollback/search?pattern=%28unclosed&regex=true",
+        )
+        .await;
+        assert_eq!(status, 400);
+    }
+
+    #[tokio::test]
+    async fn reveal_of_an_unknown_session_is_404() {
+        let dir = TempDir::new().unwrap();
+        let id = uuid::Uuid::new_v4();
+        let req = Request::post(format!("/sessions/{id}/scrollback/reveal"))
+            .header("content-type", "application/json")
+            .body(Body::from(r#"{"text":"boom","from_end":0}"#))
+            .unwrap();
+        let (status, _) = send(router(&dir), req).await;
+        assert_eq!(status, 404);
+    }
+}
@@ -3,7 +3,8 @@
 //! Thin wrappers over `CommanderService`: `list_sessions`,
 //! `find_session`/`find_session_exact`, `get_session_detail`,
 //! `get_pane_content`, `create_session`, `kill_session`, `restart_session`,
-//! `restart_session_fresh`, `delete_session`.
+//! `restart_session_fresh`, `delete_session`, `archive_session`,
+//! `restore_session`, plus issuing and revoking spectator share tokens.
 
 use axum::{
     Json,
@@ -13,9 +14,11 @@ use axum::{
 };
 use claude_commander_core::api::{
     ChangeProgram, CreateSessionOpts, PreviewData, PreviewTarget, RenameSession, SessionInfo,
-    SetSection,
+    SetNotes, SetSection, SetTags, ShareRequest, TaskRequest,
 };
 use claude_commander_core::cli::SessionLookup;
+use claude_commander_core::error::SessionError;
+use claude_commander_core::session::TaskStart;
 use serde::Deserialize;
 use serde_json::json;
 
@@ -153,6 +156,69 @@ pub async fn restart_fresh(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// `POST /sessions/{id}/archive` → `archive_session` → 204. An archived (or
+/// mid-operation) session is a 409.
+pub async fn archive(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let id = parse_session_id(&id)?;
+    run_local(move || async move { state.service.archive_session(&id).await }).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `POST /sessions/{id}/restore` → `restore_session` → 204. A session that
+/// isn't archived is a 409.
+pub async fn restore(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let id = parse_session_id(&id)?;
+    run_local(move || async move { state.service.restore_session(&id).await }).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `POST /sessions/{id}/share` → 201
+/// [`ShareToken`](claude_commander_core::api::ShareToken).
+///
+/// Mints a token that lets its holder spectate this one session over
+/// `/ws/attach` (see [`crate::share`]). The body is optional; `ttl_secs`
+/// defaults to an hour and is capped at a day. An unknown session is a 404, so
+/// a typo doesn't hand out a link to nothing.
+pub async fn share(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    body: Option<Json<ShareRequest>>,
+) -> Result<Response, ApiError> {
+    let id = parse_session_id(&id)?;
+    let req = body.map(|Json(req)| req).unwrap_or_default();
+    let exists = state
+        .service
+        .list_sessions(true)
+        .await?
+        .iter()
+        .any(|s| s.session_id == id);
+    if !exists {
+        return Err(ApiError(SessionError::NotFound(id).into()));
+    }
+    let share = state
+        .shares
+        .issue(id, req.ttl_secs.map(std::time::Duration::from_secs));
+    Ok((StatusCode::CREATED, Json(share)).into_response())
+}
+
+/// `DELETE /sessions/{id}/share` → 204. Revokes every share token issued for
+/// the session and closes the spectator attaches made with them. Revoking a
+/// session with no outstanding tokens is a no-op, not an error.
+pub async fn revoke_share(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let id = parse_session_id(&id)?;
+    state.shares.revoke_session(id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
 /// `DELETE /sessions/{id}` → `delete_session` → 204.
 pub async fn delete(
     State(state): State<AppState>,
@@ -193,18 +259,21 @@ pub async fn branch_diff(
     Ok(state.service.branch_diff(&id).await?)
 }
 
-/// PATCH body for a session: rename it, or move it to a section (`section:
-/// null` clears the manual override). Tagged by `op` so a section clear
-/// (`null`) is unambiguous.
+/// PATCH body for a session: rename it, move it to a section (`section:
+/// null` clears the manual override), change its program, or replace its
+/// tags or notes. Tagged by `op` so a section clear (`null`) is unambiguous.
 #[derive(Debug, Deserialize)]
 #[serde(tag = "op", rename_all = "snake_case")]
 pub enum PatchSession {
     Rename(RenameSession),
     SetSection(SetSection),
     ChangeProgram(ChangeProgram),
+    SetTags(SetTags),
+    SetNotes(SetNotes),
 }
 
-/// `PATCH /sessions/{id}` → `rename_session` / `set_section` / `change_program` → 204.
+/// `PATCH /sessions/{id}` → `rename_session` / `set_section` / `change_program`
+/// / `set_tags` / `set_notes` → 204.
 pub async fn patch(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -214,6 +283,8 @@ pub async fn patch(
     match body {
         PatchSession::Rename(r) => state.service.rename_session(&id, r.title).await?,
         PatchSession::SetSection(s) => state.service.set_section(&id, s.section).await?,
+        PatchSession::SetTags(t) => state.service.set_tags(&id, t.tags).await?,
+        PatchSession::SetNotes(n) => state.service.set_notes(&id, n.notes).await?,
         // Relaunches the pane (tmux) → not `Send`; run on the local pool.
         PatchSession::ChangeProgram(c) => {
             run_local(move || async move { state.service.change_program(&id, c.program).await })
@@ -243,6 +314,41 @@ pub async fn keep_alive(
     Ok(Json(state.service.toggle_keep_alive(&id).await?))
 }
 
+/// `POST /sessions/{id}/tasks/run` with a [`TaskRequest`] → `run_tasks` →
+/// one [`TaskStart`] per task attempted (404 for an unknown task name).
+pub async fn run_tasks(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(body): Json<TaskRequest>,
+) -> Result<Json<Vec<TaskStart>>, ApiError> {
+    let id = parse_session_id(&id)?;
+    // Spawns the task window (tmux) → run on the local pool like restart.
+    let started =
+        run_local(move || async move { state.service.run_tasks(&id, body.name.as_deref()).await })
+            .await?;
+    Ok(Json(started))
+}
+
+/// `POST /sessions/{id}/tasks/send-failure` with a [`TaskRequest`] →
+/// `send_task_failure` → the name of the task sent. 404 when there is no such
+/// failed run; 409 when the agent is stopped or stuck at a prompt.
+pub async fn send_task_failure(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(body): Json<TaskRequest>,
+) -> Result<Json<String>, ApiError> {
+    let id = parse_session_id(&id)?;
+    // Pastes into the agent pane (tmux) → run on the local pool like run_tasks.
+    let sent = run_local(move || async move {
+        state
+            .service
+            .send_task_failure(&id, body.name.as_deref())
+            .await
+    })
+    .await?;
+    Ok(Json(sent))
+}
+
 /// Body for the batch mark-unread route: the session ids to flag.
 #[derive(Debug, Deserialize)]
 pub struct UnreadBody {
@@ -287,6 +393,8 @@ mod tests {
             .route("/sessions/{id}/kill", post(super::kill))
             .route("/sessions/{id}/restart", post(super::restart))
             .route("/sessions/{id}/restart-fresh", post(super::restart_fresh))
+            .route("/sessions/{id}/archive", post(super::archive))
+            .route("/sessions/{id}/restore", post(super::restore))
             .route(
                 "/sessions/{id}",
                 axum::routing::delete(super::delete).patch(super::patch),
@@ -295,6 +403,10 @@ mod tests {
             .route("/sessions/{id}/branch-diff", get(super::branch_diff))
             .route("/sessions/{id}/read", post(super::read))
             .route("/sessions/unread", post(super::unread))
+            .route(
+                "/sessions/{id}/share",
+                post(super::share).delete(super::revoke_share),
+            )
             .with_state(state)
     }
 
@@ -469,6 +581,31 @@ mod tests {
         assert_eq!(status, 404);
     }
 
+    /// Archive and restore are wired, and restoring a session that was never
+    /// archived is a conflict rather than a silent relaunch.
+    #[tokio::test]
+    async fn archive_and_restore_are_wired() {
+        use axum::body::Body;
+        use axum::http::Request;
+        let dir = TempDir::new().unwrap();
+
+        for action in ["archive", "restore"] {
+            let req = Request::post(format!("/sessions/{}/{action}", uuid::Uuid::new_v4()))
+                .body(Body::empty())
+                .unwrap();
+            let (status, _) =
+                crate::handlers::test_support::send(router(test_state(&dir)), req).await;
+            assert_eq!(status, 404, "{action}");
+        }
+
+        let (state, sid) = seeded_state(&dir);
+        let req = Request::post(format!("/sessions/{}/restore", sid.as_uuid()))
+            .body(Body::empty())
+            .unwrap();
+        let (status, _) = crate::handlers::test_support::send(router(state), req).await;
+        assert_eq!(status, 409);
+    }
+
     /// Preview for an unknown session id is a 404.
     #[tokio::test]
     async fn preview_unknown_is_404() {
@@ -539,4 +676,69 @@ mod tests {
         let (status, _) = crate::handlers::test_support::send(router(test_state(&dir)), req).await;
         assert_eq!(status, 404);
     }
+
+    /// `share` on an unknown session id is a 404 — no token for a session that
+    /// doesn't exist.
+    #[tokio::test]
+    async fn share_unknown_is_404() {
+        use axum::body::Body;
+        use axum::http::Request;
+        let dir = TempDir::new().unwrap();
+        let req = Request::post(format!("/sessions/{}/share", uuid::Uuid::new_v4()))
+            .body(Body::empty())
+            .unwrap();
+        let (status, _) = crate::handlers::test_support::send(router(test_state(&dir)), req).await;
+        assert_eq!(status, 404);
+    }
+
+    /// `share` issues a token the state can resolve back to the session, with
+    /// or without a body; a requested TTL is honoured.
+    #[tokio::test]
+    async fn share_issues_a_token_scoped_to_the_session() {
+        use axum::body::Body;
+        use axum::http::Request;
+        use claude_commander_core::api::ShareToken;
+        let dir = TempDir::new().unwrap();
+        let (state, sid) = seeded_state(&dir);
+        let uri = format!("/sessions/{}/share", sid.as_uuid());
+
+        let req = Request::post(&uri).body(Body::empty()).unwrap();
+        let (status, body) = crate::handlers::test_support::send(router(state.clone()), req).await;
+        assert_eq!(status, 201);
+        let share: ShareToken = crate::handlers::test_support::json(&body);
+        assert_eq!(share.session_id, sid);
+        assert_eq!(
+            state.shares.grant_for(&share.token).map(|g| g.session_id),
+            Some(sid)
+        );
+
+        let req = Request::post(&uri)
+            .header("content-type", "application/json")
+            .body(Body::from(r#"{"ttl_secs":60}"#))
+            .unwrap();
+        let (status, body) = crate::handlers::test_support::send(router(state.clone()), req).await;
+        assert_eq!(status, 201);
+        let short: ShareToken = crate::handlers::test_support::json(&body);
+        assert!(short.expires_at <= chrono::Utc::now() + chrono::Duration::seconds(60));
+        assert_ne!(short.token, share.token);
+    }
+
+    /// `DELETE …/share` revokes the session's tokens, and is a 204 even when
+    /// there is nothing left to revoke.
+    #[tokio::test]
+    async fn revoke_share_drops_the_sessions_tokens() {
+        use axum::body::Body;
+        use axum::http::Request;
+        let dir = TempDir::new().unwrap();
+        let (state, sid) = seeded_state(&dir);
+        let share = state.shares.issue(sid, None);
+        let uri = format!("/sessions/{}/share", sid.as_uuid());
+
+        for _ in 0..2 {
+            let req = Request::delete(&uri).body(Body::empty()).unwrap();
+            let (status, _) = crate::handlers::test_support::send(router(state.clone()), req).await;
+            assert_eq!(status, 204);
+            assert!(state.shares.grant_for(&share.token).is_none());
+        }
+    }
 }
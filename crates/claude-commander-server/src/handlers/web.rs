@@ -0,0 +1,142 @@
+//! Browser UI handlers.
+//!
+//! The web UI is three static files under `web/`, embedded at compile time so
+//! the binary stays self-contained: `GET /` serves the page and `/ui/app.js` +
+//! `/ui/app.css` its script and styles. The shell itself carries no data and
+//! sits outside the bearer layer like `/health`; everything it shows is fetched
+//! from `/api` (token from the page's login form) and `/ws/attach` (the same
+//! in-band auth frame every client sends). Being same-origin, it needs no CORS
+//! allowlist entry.
+//!
+//! The terminal is xterm.js, vendored under `web/vendor/` (refreshed by
+//! `scripts/vendor-xterm.sh`) and served from `/ui/vendor/` the same way, so the
+//! page loads nothing from another origin and works on a LAN without internet
+//! access.
+
+use axum::{
+    http::header::{
+        CACHE_CONTROL, CONTENT_SECURITY_POLICY, CONTENT_TYPE, HeaderName, X_CONTENT_TYPE_OPTIONS,
+    },
+    response::IntoResponse,
+};
+
+const INDEX_HTML: &str = include_str!("../../web/index.html");
+const APP_JS: &str = include_str!("../../web/app.js");
+const APP_CSS: &str = include_str!("../../web/app.css");
+// The vendored files are embedded byte-for-byte as published.
+const XTERM_JS: &[u8] = include_bytes!("../../web/vendor/xterm.js");
+const XTERM_CSS: &[u8] = include_bytes!("../../web/vendor/xterm.css");
+const ADDON_FIT_JS: &[u8] = include_bytes!("../../web/vendor/addon-fit.js");
+
+/// The page's CSP: scripts, styles and connections all limited to this origin,
+/// so the page can only ever run code from and talk to the server that served
+/// it. Inline styles stay allowed because xterm.js injects its theme as a
+/// `<style>` element.
+const CSP: &str = "default-src 'self'; script-src 'self'; style-src 'self' 'unsafe-inline'; \
+                   connect-src 'self'; img-src 'self' data:";
+
+const NOSNIFF: (HeaderName, &str) = (X_CONTENT_TYPE_OPTIONS, "nosniff");
+
+const JS: &str = "text/javascript; charset=utf-8";
+const CSS: &str = "text/css; charset=utf-8";
+
+/// `GET /` → the UI page.
+pub async fn index() -> impl IntoResponse {
+    (
+        [
+            (CONTENT_TYPE, "text/html; charset=utf-8"),
+            (CACHE_CONTROL, "no-cache"),
+            (CONTENT_SECURITY_POLICY, CSP),
+        ],
+        INDEX_HTML,
+    )
+}
+
+/// `GET /ui/app.js`.
+pub async fn app_js() -> impl IntoResponse {
+    asset(JS, APP_JS.as_bytes())
+}
+
+/// `GET /ui/app.css`.
+pub async fn app_css() -> impl IntoResponse {
+    asset(CSS, APP_CSS.as_bytes())
+}
+
+/// `GET /ui/vendor/xterm.js`.
+pub async fn xterm_js() -> impl IntoResponse {
+    asset(JS, XTERM_JS)
+}
+
+/// `GET /ui/vendor/xterm.css`.
+pub async fn xterm_css() -> impl IntoResponse {
+    asset(CSS, XTERM_CSS)
+}
+
+/// `GET /ui/vendor/addon-fit.js`.
+pub async fn addon_fit_js() -> impl IntoResponse {
+    asset(JS, ADDON_FIT_JS)
+}
+
+/// An embedded script or stylesheet, revalidated on every load so a rebuilt
+/// binary's files replace the cached ones.
+fn asset(content_type: &'static str, body: &'static [u8]) -> impl IntoResponse {
+    (
+        [
+            (CONTENT_TYPE, content_type),
+            (CACHE_CONTROL, "no-cache"),
+            NOSNIFF,
+        ],
+        body,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every script and stylesheet the page pulls in is one of ours, so the
+    /// `'self'`-only CSP never blocks the page's own terminal.
+    #[test]
+    fn page_loads_nothing_cross_origin() {
+        for attr in ["src=\"", "href=\""] {
+            for (at, _) in INDEX_HTML.match_indices(attr) {
+                let url = &INDEX_HTML[at + attr.len()..];
+                assert!(url.starts_with("/ui/"), "index.html loads {url:.40}");
+            }
+        }
+    }
+
+    /// The element ids `app.js` looks up must exist in the page, so a renamed
+    /// id fails here rather than as a blank view in the browser.
+    #[test]
+    fn script_ids_exist_in_the_page() {
+        for id in [
+            "status",
+            "login",
+            "login-form",
+            "login-token",
+            "login-error",
+            "board",
+            "terminal-view",
+            "term-back",
+            "term-title",
+            "term-kind",
+            "term-detach",
+            "terminal",
+            "review-view",
+            "review-back",
+            "review-title",
+            "review-summary",
+            "review-refresh",
+            "review-apply",
+            "review",
+            "settings",
+            "comment-form",
+        ] {
+            assert!(
+                INDEX_HTML.contains(&format!("id=\"{id}\"")),
+                "index.html has no #{id}"
+            );
+        }
+    }
+}
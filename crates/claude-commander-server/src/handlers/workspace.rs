@@ -2,19 +2,24 @@
 //!
 //! Thin wrappers over `CommanderService`: the whole-workspace snapshot the
 //! session tree renders from (`workspace_snapshot`), the bulk agent-state poll
-//! (`agent_states`), and the new-session dialog options (`create_options`).
+//! (`agent_states`), the new-session dialog options (`create_options`), and
+//! workspace-wide undo (`undo`).
 
 use axum::{
     Json,
     extract::{Query, State},
     http::StatusCode,
 };
-use claude_commander_core::api::{AgentStatesSnapshot, CreateOptions, WorkspaceSnapshot};
+use claude_commander_core::api::{
+    AgentStatesSnapshot, CreateOptions, UndoResult, WorkspaceSnapshot,
+};
 use serde::Deserialize;
 
 use crate::error::ApiError;
 use crate::state::AppState;
 
+use super::run_local;
+
 /// `GET /workspace` → `workspace_snapshot`.
 pub async fn snapshot(State(state): State<AppState>) -> Result<Json<WorkspaceSnapshot>, ApiError> {
     Ok(Json(state.service.workspace_snapshot().await?))
@@ -48,12 +53,34 @@ pub async fn pr_refresh(State(state): State<AppState>) -> Result<StatusCode, Api
     Ok(StatusCode::ACCEPTED)
 }
 
+/// `POST /undo` → `undo` → 200 + `{summary}`. An empty undo journal is a 404.
+/// Recreating worktrees runs git, so it goes through `run_local`.
+pub async fn undo(State(state): State<AppState>) -> Result<Json<UndoResult>, ApiError> {
+    let summary = run_local(move || async move { state.service.undo().await }).await?;
+    Ok(Json(UndoResult { summary }))
+}
+
 #[cfg(test)]
 mod tests {
-    use axum::{Router, routing::get};
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::{
+        Router,
+        routing::{get, post},
+    };
     use tempfile::TempDir;
 
-    use crate::handlers::test_support::{get as do_get, json, test_state};
+    use crate::handlers::test_support::{get as do_get, json, send, test_state};
+
+    #[tokio::test]
+    async fn undo_with_an_empty_journal_is_404() {
+        let dir = TempDir::new().unwrap();
+        let router = Router::new()
+            .route("/undo", post(super::undo))
+            .with_state(test_state(&dir));
+        let (status, _) = send(router, Request::post("/undo").body(Body::empty()).unwrap()).await;
+        assert_eq!(status, 404);
+    }
 
     #[tokio::test]
     async fn workspace_empty_is_200_with_empty_lists() {
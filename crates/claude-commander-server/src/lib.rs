@@ -12,6 +12,7 @@ pub mod error;
 pub mod extract;
 pub mod handlers;
 pub mod router;
+pub mod share;
 pub mod state;
 pub mod ws;
 
@@ -141,12 +141,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // as the TUI does. Without this a server-only deployment — the many-idle-
     // sessions case hibernation targets — would never hibernate.
     service.start_hibernation_loop();
-    let state = AppState::new(service, auth).with_cors(cfg.cors_allowed_origins.clone());
+    let mut state = AppState::new(service, auth).with_cors(cfg.cors_allowed_origins.clone());
+    if cfg.web_ui {
+        state = state.with_web_ui();
+    }
     let app = build_router(state);
 
     let addr = SocketAddr::new(cfg.bind, cfg.port);
     let listener = tokio::net::TcpListener::bind(addr).await?;
     info!("claude-commander-server listening on http://{addr}");
+    if cfg.web_ui {
+        info!("web UI at http://{addr}/");
+    }
 
     axum::serve(listener, app).await?;
     Ok(())
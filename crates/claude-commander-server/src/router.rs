@@ -1,5 +1,6 @@
 //! Router construction: the `/api` surface (behind bearer auth + a CORS layer),
-//! the `/ws` upgrade, and a lightweight `/health` liveness probe.
+//! the `/ws` upgrade, a lightweight `/health` liveness probe, and (when enabled)
+//! the browser UI at `/`.
 
 use axum::{
     Router,
@@ -16,7 +17,8 @@ use tracing::warn;
 
 use crate::auth::require_bearer;
 use crate::handlers::{
-    blobs, cascade, config, github, health, paste, projects, review, sessions, workspace,
+    blobs, cascade, config, github, health, paste, projects, review, scrollback, sessions, web,
+    workspace,
 };
 use crate::state::AppState;
 use crate::ws;
@@ -67,6 +69,10 @@ pub fn build_router(state: AppState) -> Router {
         // -- cascade / push-stack --
         .route("/cascade/resume", post(cascade::resume))
         .route("/cascade/abandon", post(cascade::abandon))
+        // -- undo --
+        .route("/undo", post(workspace::undo))
+        // -- scrollback search --
+        .route("/scrollback/search", get(scrollback::search))
         // -- sessions --
         .route("/sessions", get(sessions::list).post(sessions::create))
         .route("/sessions/find", get(sessions::find))
@@ -79,6 +85,8 @@ pub fn build_router(state: AppState) -> Router {
             "/sessions/{id}/restart-fresh",
             post(sessions::restart_fresh),
         )
+        .route("/sessions/{id}/archive", post(sessions::archive))
+        .route("/sessions/{id}/restore", post(sessions::restore))
         .route(
             "/sessions/{id}",
             delete(sessions::delete).patch(sessions::patch),
@@ -87,6 +95,16 @@ pub fn build_router(state: AppState) -> Router {
         .route("/sessions/{id}/branch-diff", get(sessions::branch_diff))
         .route("/sessions/{id}/read", post(sessions::read))
         .route("/sessions/{id}/keep-alive", post(sessions::keep_alive))
+        .route(
+            "/sessions/{id}/share",
+            post(sessions::share).delete(sessions::revoke_share),
+        )
+        .route("/sessions/{id}/tasks/run", post(sessions::run_tasks))
+        .route(
+            "/sessions/{id}/tasks/send-failure",
+            post(sessions::send_task_failure),
+        )
+        .route("/sessions/{id}/scrollback/reveal", post(scrollback::reveal))
         .route("/sessions/{id}/cascade", post(cascade::cascade))
         .route("/sessions/{id}/push-stack", post(cascade::push_stack))
         // -- review + comments --
@@ -152,11 +170,24 @@ pub fn build_router(state: AppState) -> Router {
     // upgrade), so `/ws` sits outside the `/api` bearer layer.
     let ws = Router::new().route("/attach", get(ws::attach));
 
-    Router::new()
+    let mut router = Router::new()
         .nest("/api", api)
         .nest("/ws", ws)
         // Lightweight liveness probe, outside the auth layer.
-        .route("/health", get(health::live))
+        .route("/health", get(health::live));
+    // The browser UI's static shell is also outside the auth layer: it holds no
+    // data, and the login form it shows is how a browser gets the token to send.
+    if state.web_ui {
+        router = router
+            .route("/", get(web::index))
+            .route("/ui/app.js", get(web::app_js))
+            .route("/ui/app.css", get(web::app_css))
+            .route("/ui/vendor/xterm.js", get(web::xterm_js))
+            .route("/ui/vendor/xterm.css", get(web::xterm_css))
+            .route("/ui/vendor/addon-fit.js", get(web::addon_fit_js));
+    }
+
+    router
         // Defense-in-depth: a panicking handler returns 500 instead of dropping
         // the connection (complements `run_local`'s explicit 500 mapping).
         .layer(CatchPanicLayer::new())
@@ -261,6 +292,68 @@ mod tests {
         );
     }
 
+    /// The browser UI and its vendored xterm.js are served without a token —
+    /// under a CSP that admits only this origin — while the API it calls still
+    /// demands one.
+    #[tokio::test]
+    async fn web_ui_is_public_but_api_is_not() {
+        let dir = TempDir::new().unwrap();
+        let mut state = test_state(&dir).with_web_ui();
+        state.auth = std::sync::Arc::new(crate::auth::AuthConfig::Token("sekret".into()));
+        let app = super::build_router(state);
+
+        let resp = app
+            .clone()
+            .oneshot(Request::get("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+        let csp = resp.headers()[header::CONTENT_SECURITY_POLICY]
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(csp.contains("script-src 'self';"), "{csp}");
+        assert!(!csp.contains("https://"), "{csp}");
+
+        for path in [
+            "/ui/app.js",
+            "/ui/vendor/xterm.js",
+            "/ui/vendor/addon-fit.js",
+        ] {
+            let resp = app
+                .clone()
+                .oneshot(Request::get(path).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(resp.status(), 200, "{path}");
+            assert!(
+                resp.headers()[header::CONTENT_TYPE]
+                    .to_str()
+                    .unwrap()
+                    .starts_with("text/javascript"),
+                "{path}"
+            );
+        }
+
+        let resp = app
+            .oneshot(Request::get("/api/workspace").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 401);
+    }
+
+    /// Without `with_web_ui` the UI routes don't exist.
+    #[tokio::test]
+    async fn web_ui_can_be_turned_off() {
+        let dir = TempDir::new().unwrap();
+        let app = super::build_router(test_state(&dir));
+        let resp = app
+            .oneshot(Request::get("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 404);
+    }
+
     /// With an empty allowlist (default), even a syntactically valid origin is
     /// denied — same-origin only.
     #[tokio::test]
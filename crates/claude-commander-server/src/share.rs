@@ -0,0 +1,187 @@
+//! Share tokens: time-limited, spectator-only access to one session.
+//!
+//! `POST /api/sessions/{id}/share` mints a token that a viewer presents in the
+//! `/ws/attach` `auth` frame instead of the server token. It authorises exactly
+//! one thing — a [`Spectator`](claude_commander_protocol::ws::AttachKind::Spectator)
+//! attach to the session it was issued for — and is never accepted by the
+//! `/api` bearer layer. An attach made with a token lasts only as long as the
+//! grant: it is closed when the token expires, or when
+//! `DELETE /api/sessions/{id}/share` revokes the session's tokens. Grants live
+//! in memory only, so a restart revokes them all too.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use claude_commander_core::api::ShareToken;
+use claude_commander_core::session::SessionId;
+use tokio::sync::watch;
+
+/// Lifetime of a token when the request doesn't ask for one.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Longest lifetime a token can be issued with; longer requests are clamped.
+pub const MAX_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug)]
+struct Grant {
+    session_id: SessionId,
+    expires_at: DateTime<Utc>,
+    /// Never sent on; dropping it (revocation, or pruning once expired) is
+    /// what wakes every [`ShareGrant::ended`] watching this grant.
+    live: watch::Sender<()>,
+}
+
+/// A share token as resolved at the `/ws/attach` handshake. The attach keeps
+/// it for its lifetime and closes once [`Self::ended`] resolves.
+#[derive(Debug)]
+pub struct ShareGrant {
+    pub session_id: SessionId,
+    pub expires_at: DateTime<Utc>,
+    live: watch::Receiver<()>,
+}
+
+impl ShareGrant {
+    /// Resolves once the grant expires or is revoked.
+    pub async fn ended(&mut self) {
+        let left = (self.expires_at - Utc::now()).to_std().unwrap_or_default();
+        tokio::select! {
+            () = tokio::time::sleep(left) => {}
+            // Nothing is ever sent, so this only returns once the sender is gone.
+            _ = self.live.changed() => {}
+        }
+    }
+}
+
+/// The live share grants, keyed by token.
+#[derive(Debug, Default)]
+pub struct ShareTokens {
+    grants: Mutex<HashMap<String, Grant>>,
+}
+
+impl ShareTokens {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Issue a token for `session_id` lasting `ttl` (default [`DEFAULT_TTL`],
+    /// clamped to [`MAX_TTL`]). Expired grants are pruned on the way in, so the
+    /// map never outgrows the tokens that could still be used.
+    pub fn issue(&self, session_id: SessionId, ttl: Option<Duration>) -> ShareToken {
+        let ttl = ttl.unwrap_or(DEFAULT_TTL).min(MAX_TTL);
+        let now = Utc::now();
+        let expires_at = now + chrono::Duration::from_std(ttl).unwrap_or(chrono::Duration::zero());
+        let token = format!(
+            "{}{}",
+            uuid::Uuid::new_v4().simple(),
+            uuid::Uuid::new_v4().simple()
+        );
+
+        let mut grants = self.grants.lock().unwrap_or_else(|e| e.into_inner());
+        grants.retain(|_, g| g.expires_at > now);
+        grants.insert(
+            token.clone(),
+            Grant {
+                session_id,
+                expires_at,
+                live: watch::channel(()).0,
+            },
+        );
+        ShareToken {
+            token,
+            session_id,
+            expires_at,
+        }
+    }
+
+    /// The grant behind `token`, or `None` if it was never issued, has expired
+    /// or was revoked.
+    pub fn grant_for(&self, token: &str) -> Option<ShareGrant> {
+        let now = Utc::now();
+        let mut grants = self.grants.lock().unwrap_or_else(|e| e.into_inner());
+        grants.retain(|_, g| g.expires_at > now);
+        grants.get(token).map(|g| ShareGrant {
+            session_id: g.session_id,
+            expires_at: g.expires_at,
+            live: g.live.subscribe(),
+        })
+    }
+
+    /// Revoke every token issued for `session_id`, closing any attach made
+    /// with one. Returns how many were revoked.
+    pub fn revoke_session(&self, session_id: SessionId) -> usize {
+        let mut grants = self.grants.lock().unwrap_or_else(|e| e.into_inner());
+        let before = grants.len();
+        grants.retain(|_, g| g.session_id != session_id);
+        before - grants.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issued_token_resolves_to_its_session() {
+        let shares = ShareTokens::new();
+        let id = SessionId::new();
+        let share = shares.issue(id, None);
+        assert_eq!(share.session_id, id);
+        assert_eq!(
+            shares.grant_for(&share.token).map(|g| g.session_id),
+            Some(id)
+        );
+        assert!(shares.grant_for("not-a-token").is_none());
+    }
+
+    #[test]
+    fn ttl_defaults_and_is_clamped() {
+        let shares = ShareTokens::new();
+        let default = shares.issue(SessionId::new(), None);
+        let left = default.expires_at - Utc::now();
+        assert!(left <= chrono::Duration::hours(1) && left > chrono::Duration::minutes(59));
+
+        let long = shares.issue(
+            SessionId::new(),
+            Some(Duration::from_secs(7 * 24 * 60 * 60)),
+        );
+        assert!(long.expires_at - Utc::now() <= chrono::Duration::hours(24));
+    }
+
+    #[test]
+    fn expired_token_is_rejected_and_pruned() {
+        let shares = ShareTokens::new();
+        let share = shares.issue(SessionId::new(), Some(Duration::ZERO));
+        assert!(shares.grant_for(&share.token).is_none());
+        assert!(shares.grants.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn grant_ends_at_expiry() {
+        let shares = ShareTokens::new();
+        let share = shares.issue(SessionId::new(), Some(Duration::from_millis(200)));
+        let mut grant = shares.grant_for(&share.token).unwrap();
+        tokio::time::timeout(Duration::from_secs(5), grant.ended())
+            .await
+            .expect("the grant should end when its TTL runs out");
+    }
+
+    #[tokio::test]
+    async fn revoking_a_session_ends_its_grants_only() {
+        let shares = ShareTokens::new();
+        let id = SessionId::new();
+        let first = shares.issue(id, None);
+        let second = shares.issue(id, None);
+        let other = shares.issue(SessionId::new(), None);
+        let mut grant = shares.grant_for(&first.token).unwrap();
+
+        assert_eq!(shares.revoke_session(id), 2);
+        tokio::time::timeout(Duration::from_secs(5), grant.ended())
+            .await
+            .expect("a revoked grant should end straight away");
+        assert!(shares.grant_for(&first.token).is_none());
+        assert!(shares.grant_for(&second.token).is_none());
+        assert!(shares.grant_for(&other.token).is_some());
+    }
+}
@@ -5,6 +5,7 @@ use std::sync::Arc;
 use claude_commander_core::api::CommanderService;
 
 use crate::auth::AuthConfig;
+use crate::share::ShareTokens;
 
 /// State shared across all handlers. `CommanderService` is already
 /// `Arc`-backed and cheap to clone; `auth` is shared behind an `Arc`.
@@ -16,17 +17,25 @@ pub struct AppState {
     /// no cross-origin access (same-origin only). Consumed by `build_router`
     /// when assembling the `/api` CORS layer.
     pub cors_allowed_origins: Arc<Vec<String>>,
+    /// Whether to serve the browser UI (from `ServerConfig`); `false` leaves
+    /// the UI routes unmounted. Consumed by `build_router`.
+    pub web_ui: bool,
+    /// Outstanding spectator share tokens, issued by the `/share` route and
+    /// checked by the `/ws/attach` handshake.
+    pub shares: Arc<ShareTokens>,
 }
 
 impl AppState {
-    /// Build state with no CORS allowlist (same-origin only). Tests and the
-    /// default path use this; the server overrides the allowlist via
-    /// [`Self::with_cors`].
+    /// Build state with no CORS allowlist (same-origin only) and no browser UI.
+    /// Tests and the default path use this; the server overrides both via
+    /// [`Self::with_cors`] and [`Self::with_web_ui`].
     pub fn new(service: CommanderService, auth: AuthConfig) -> Self {
         Self {
             service,
             auth: Arc::new(auth),
             cors_allowed_origins: Arc::new(Vec::new()),
+            web_ui: false,
+            shares: Arc::new(ShareTokens::new()),
         }
     }
 
@@ -36,4 +45,10 @@ impl AppState {
         self.cors_allowed_origins = Arc::new(origins);
         self
     }
+
+    /// Serve the browser UI.
+    pub fn with_web_ui(mut self) -> Self {
+        self.web_ui = true;
+        self
+    }
 }
@@ -6,6 +6,15 @@
 //! session, and spawns the bridge; steady state pumps raw bytes both ways and
 //! honours `resize`/`detach` control frames.
 //!
+//! Spectators: a [`AttachKind::Spectator`] attach spawns tmux with
+//! `attach-session -r` at the watched window's size, and the pump drops every
+//! binary (input) frame and `resize` it receives, so a viewer can watch the
+//! agent pane but never type into it or change its size. The
+//! `auth` frame may carry a share token (see [`crate::share`]) instead of the
+//! server token; that authorises only a spectator attach to the one session
+//! the token was issued for, and only until the token expires or is revoked —
+//! the pump then ends the attach with [`DetachReason::ShareEnded`].
+//!
 //! Detach semantics: a client `detach`, a closed socket, or a heartbeat-ping
 //! timeout kills the `tmux attach-session` child **only** — the tmux session and
 //! the program inside it keep running. The bridge's [`ChildGuard`] guarantees
@@ -21,15 +30,17 @@ use axum::{
     },
     response::Response,
 };
+use claude_commander_core::session::SessionId;
 use claude_commander_core::tmux::HeadlessAttach;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tracing::{debug, info, warn};
 
 use claude_commander_protocol::ws::{
     ATTACH_MISSED_PONG_LIMIT, ATTACH_PING_INTERVAL, AttachKind, ClientControl, DetachReason,
-    ServerControl, WS_ERR_AUTH, WS_ERR_NO_SESSION,
+    ServerControl, WS_ERR_AUTH, WS_ERR_NO_SESSION, WS_ERR_SHARE_SCOPE,
 };
 
+use crate::share::ShareGrant;
 use crate::state::AppState;
 
 /// How long to wait for the mandatory `auth` then `attach` handshake frames
@@ -52,6 +63,16 @@ const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(30);
 const DEFAULT_COLS: u16 = 80;
 const DEFAULT_ROWS: u16 = 24;
 
+/// What the `auth` frame's token entitles the socket to.
+#[derive(Debug)]
+enum Access {
+    /// The server token: any session, any attach kind.
+    Full,
+    /// A share token: a spectator attach to the grant's session only, for as
+    /// long as the grant lasts.
+    Share(ShareGrant),
+}
+
 /// Axum handler for `GET /ws/attach`. Performs the protocol upgrade; all real
 /// work happens in [`handle_socket`] once the socket is established.
 pub async fn attach(ws: WebSocketUpgrade, State(state): State<AppState>) -> Response {
@@ -67,14 +88,15 @@ async fn send_control(socket: &mut WebSocket, msg: &ServerControl) -> Result<(),
 /// Drive a single attached socket through handshake → steady state → teardown.
 async fn handle_socket(mut socket: WebSocket, state: AppState) {
     // -- Handshake: auth frame --
-    if !authenticate(&mut socket, &state).await {
+    let Some(access) = authenticate(&mut socket, &state).await else {
         // `authenticate` has already sent an error frame where appropriate.
         return;
-    }
+    };
 
     // -- Handshake: attach frame → resolve session → spawn bridge --
-    let (session_name, bridge) = match attach_session(&mut socket, &state).await {
-        Some(pair) => pair,
+    let (session_name, bridge, read_only) = match attach_session(&mut socket, &state, &access).await
+    {
+        Some(attached) => attached,
         None => return,
     };
 
@@ -92,18 +114,25 @@ async fn handle_socket(mut socket: WebSocket, state: AppState) {
     }
 
     // -- Steady state --
-    let reason = pump(socket, bridge).await;
+    let share = match access {
+        Access::Full => None,
+        Access::Share(grant) => Some(grant),
+    };
+    let reason = pump(socket, bridge, read_only, share).await;
     debug!("WS attach loop ended: {:?}", reason);
 }
 
-/// Read the mandatory first `auth` frame and validate the token. The token is
-/// **never logged**. Returns true on success; on failure sends an `error` frame
-/// and returns false.
-async fn authenticate(socket: &mut WebSocket, state: &AppState) -> bool {
+/// Read the mandatory first `auth` frame and validate the token — the server
+/// token first, then the outstanding share tokens. The token is **never
+/// logged**. Returns the granted [`Access`] on success; on failure sends an
+/// `error` frame and returns `None`.
+async fn authenticate(socket: &mut WebSocket, state: &AppState) -> Option<Access> {
     match next_control(socket).await {
         Some(ClientControl::Auth { token }) => {
             if state.auth.authorizes_token(&token) {
-                true
+                Some(Access::Full)
+            } else if let Some(grant) = state.shares.grant_for(&token) {
+                Some(Access::Share(grant))
             } else {
                 warn!("WS auth rejected: invalid token");
                 let _ = send_control(
@@ -113,7 +142,7 @@ async fn authenticate(socket: &mut WebSocket, state: &AppState) -> bool {
                     },
                 )
                 .await;
-                false
+                None
             }
         }
         Some(_) => {
@@ -124,19 +153,21 @@ async fn authenticate(socket: &mut WebSocket, state: &AppState) -> bool {
                 },
             )
             .await;
-            false
+            None
         }
-        None => false,
+        None => None,
     }
 }
 
-/// Read the `attach` frame, resolve the session to its tmux name, and spawn the
-/// bridge. Returns `(tmux_session_name, bridge)` on success; on failure sends an
-/// `error` frame and returns `None`.
+/// Read the `attach` frame, check it against `access`, resolve the session to
+/// its tmux name, and spawn the bridge. Returns `(tmux_session_name, bridge,
+/// read_only)` on success; on failure sends an `error` frame and returns
+/// `None`.
 async fn attach_session(
     socket: &mut WebSocket,
     state: &AppState,
-) -> Option<(String, HeadlessAttach)> {
+    access: &Access,
+) -> Option<(String, HeadlessAttach, bool)> {
     let (session_query, kind, cols, rows) = match next_control(socket).await {
         Some(ClientControl::Attach {
             session_id,
@@ -162,19 +193,46 @@ async fn attach_session(
         None => return None,
     };
 
+    if let Access::Share(grant) = access
+        && !share_covers(grant.session_id, &session_query, kind)
+    {
+        warn!("WS attach rejected: share token used outside its scope");
+        let _ = send_control(
+            socket,
+            &ServerControl::Error {
+                message: WS_ERR_SHARE_SCOPE.into(),
+            },
+        )
+        .await;
+        return None;
+    }
+
     // Resolve the requested pane to a tmux session name through the same service
     // method `LocalBackend::attach` uses, so both transports get identical
     // revive-on-attach (a dead agent tmux session is recreated) and MRU-stamp
     // (`last_attached_at`) behaviour. The agent pane is the session's primary
-    // tmux session; the shell pane (`Ctrl+\` partner) is created on demand.
-    let core_kind = match kind {
-        AttachKind::Agent => claude_commander_core::backend::AttachKind::Agent,
-        AttachKind::Shell => claude_commander_core::backend::AttachKind::Shell,
+    // tmux session; the shell pane (`Ctrl+\` partner) is created on demand. A
+    // spectator only watches: it resolves the agent pane if it's already live,
+    // and never revives a session or stamps it.
+    let resolved = match kind {
+        AttachKind::Spectator => {
+            state
+                .service
+                .resolve_spectator_session(&session_query)
+                .await
+        }
+        AttachKind::Agent | AttachKind::Shell => {
+            let core_kind = if kind == AttachKind::Shell {
+                claude_commander_core::backend::AttachKind::Shell
+            } else {
+                claude_commander_core::backend::AttachKind::Agent
+            };
+            state
+                .service
+                .resolve_attach_session(&session_query, core_kind)
+                .await
+        }
     };
-    let resolved = state
-        .service
-        .resolve_attach_session(&session_query, core_kind)
-        .await;
     let tmux_name = match resolved {
         Ok(Some(name)) => name,
         Ok(None) => {
@@ -202,8 +260,28 @@ async fn attach_session(
     // Honour the socket-dir isolation knob so a hermetic test attaches to the
     // same throwaway tmux server its session was created on, not the real one.
     let tmux_tmpdir = state.service.read_config().tmux_tmpdir;
-    match HeadlessAttach::spawn(&tmux_name, cols, rows, tmux_tmpdir.as_deref()) {
-        Ok(bridge) => Some((tmux_name, bridge)),
+    let read_only = kind.is_read_only();
+    // A spectator attaches at the window's size, not its own: tmux ignores a
+    // read-only client's size only while another client is attached, so a
+    // lone viewer with a small screen would otherwise shrink the owner's pane.
+    let (cols, rows) = if read_only {
+        match state.service.spectator_size(&tmux_name).await {
+            Ok(size) => size,
+            Err(e) => {
+                warn!("WS spectator: couldn't read the window size of {tmux_name}: {e}");
+                (cols, rows)
+            }
+        }
+    } else {
+        (cols, rows)
+    };
+    let spawned = if read_only {
+        HeadlessAttach::spawn_read_only(&tmux_name, cols, rows, tmux_tmpdir.as_deref())
+    } else {
+        HeadlessAttach::spawn(&tmux_name, cols, rows, tmux_tmpdir.as_deref())
+    };
+    match spawned {
+        Ok(bridge) => Some((tmux_name, bridge, read_only)),
         Err(e) => {
             let _ = send_control(
                 socket,
@@ -217,6 +295,14 @@ async fn attach_session(
     }
 }
 
+/// Whether a share token for `shared` covers an attach of `kind` to
+/// `session_query`: only a spectator attach, and only by the session's full id
+/// (a title or prefix could match some other session).
+fn share_covers(shared: SessionId, session_query: &str, kind: AttachKind) -> bool {
+    kind == AttachKind::Spectator
+        && uuid::Uuid::parse_str(session_query).is_ok_and(|u| u == *shared.as_uuid())
+}
+
 /// Steady-state pump: WS binary → PTY, PTY → WS binary, `resize`/`detach`
 /// control frames, and a pong-tracked heartbeat. Each interval sends a ping and
 /// counts it as outstanding; any inbound frame (a pong, or real traffic) clears
@@ -224,8 +310,16 @@ async fn attach_session(
 /// declared dead and the loop tears down — so a half-open socket whose sends
 /// still nominally succeed is still detected, not just one where `send` errors.
 /// Returns once any teardown condition fires; the bridge's `ChildGuard` reaps
-/// the attach child on the way out.
-async fn pump(mut socket: WebSocket, bridge: HeadlessAttach) -> DetachReason {
+/// the attach child on the way out. With `read_only` (a spectator), inbound
+/// binary frames are dropped rather than written to the PTY, and `resize`
+/// frames are ignored so the client stays at the window's size. With a `share`
+/// grant, the attach also ends when that grant expires or is revoked.
+async fn pump(
+    mut socket: WebSocket,
+    bridge: HeadlessAttach,
+    read_only: bool,
+    mut share: Option<ShareGrant>,
+) -> DetachReason {
     // Take the repaint handle before `split` consumes the bridge. It answers the
     // client's `refresh` frame, which is how a client that drew over its own
     // terminal (the TUI's in-session switcher) gets the covered region back.
@@ -271,6 +365,9 @@ async fn pump(mut socket: WebSocket, bridge: HeadlessAttach) -> DetachReason {
             frame = socket.recv() => {
                 missed_pongs = 0;
                 match frame {
+                    Some(Ok(Message::Binary(_))) if read_only => {
+                        debug!("dropping input frame from a read-only attach");
+                    }
                     Some(Ok(Message::Binary(bytes))) => {
                         if pty_writer.write_all(&bytes).await.is_err() {
                             break DetachReason::SessionEnded;
@@ -278,6 +375,9 @@ async fn pump(mut socket: WebSocket, bridge: HeadlessAttach) -> DetachReason {
                         let _ = pty_writer.flush().await;
                     }
                     Some(Ok(Message::Text(text))) => match ClientControl::from_text(&text) {
+                        Ok(ClientControl::Resize { .. }) if read_only => {
+                            debug!("ignoring resize from a read-only attach");
+                        }
                         Ok(ClientControl::Resize { cols, rows }) => resize.resize(cols, rows),
                         Ok(ClientControl::Refresh) => refresh.refresh().await,
                         Ok(ClientControl::Detach) => break DetachReason::ClientRequest,
@@ -306,6 +406,14 @@ async fn pump(mut socket: WebSocket, bridge: HeadlessAttach) -> DetachReason {
                 }
                 missed_pongs += 1;
             }
+
+            // The share token this spectator attached with has expired or
+            // been revoked: the TTL holds for the whole attach, not just the
+            // handshake.
+            () = share_ended(share.as_mut()) => {
+                info!("WS spectator share ended; closing the attach");
+                break DetachReason::ShareEnded;
+            }
         }
     };
 
@@ -318,6 +426,14 @@ async fn pump(mut socket: WebSocket, bridge: HeadlessAttach) -> DetachReason {
     reason
 }
 
+/// Resolves when `share` ends; never for an attach made with the server token.
+async fn share_ended(share: Option<&mut ShareGrant>) {
+    match share {
+        Some(grant) => grant.ended().await,
+        None => std::future::pending().await,
+    }
+}
+
 /// Read frames until the next control (text) frame arrives, parsing it. Binary
 /// frames during the handshake are unexpected and ignored. Returns `None` on
 /// close, transport error, parse failure, or handshake timeout.
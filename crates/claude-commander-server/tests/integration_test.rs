@@ -16,7 +16,10 @@
 
 use std::time::Duration;
 
+use claude_commander_core::api::ShareToken;
 use claude_commander_core::tmux::TmuxExecutor;
+use claude_commander_protocol::ws::{WS_ERR_AUTH, WS_ERR_SHARE_SCOPE};
+use claude_commander_server::AuthConfig;
 use claude_commander_test_support::{create_test_repo, spawn_server, test_state, tmux_available};
 use futures::{SinkExt, StreamExt};
 use tempfile::TempDir;
@@ -687,6 +690,384 @@ async fn http_paste_image_writes_file_and_injects_path() {
     drop(worktrees_dir);
 }
 
+/// Share-token spectating: the token is no API credential and can't make an
+/// input-carrying attach, while the spectator attach it does allow streams the
+/// pane, is counted as a viewer, and drops keystrokes — a command typed into it
+/// never reaches the shell.
+#[tokio::test]
+async fn ws_share_token_spectates_read_only() {
+    if !tmux_available().await {
+        eprintln!("Skipping test: tmux not available");
+        return;
+    }
+
+    let (repo_temp_dir, repo_path) = create_test_repo().await;
+    let data_dir = TempDir::new().unwrap();
+    let worktrees_dir = TempDir::new().unwrap();
+    let mut state = test_state(&data_dir, &worktrees_dir);
+    state.auth = std::sync::Arc::new(AuthConfig::Token("server-token".to_string()));
+    let service = state.service.clone();
+    let addr = spawn_server(state).await;
+
+    service.add_project(repo_path.clone()).await.unwrap();
+    let session_id = service
+        .create_session(claude_commander_core::api::CreateSessionOpts {
+            project_path: repo_path.clone(),
+            title: "ws-spectate".to_string(),
+            program: Some("bash".to_string()),
+            initial_prompt: None,
+            effort: None,
+            mode: None,
+            model: None,
+            base_branch: None,
+            section: None,
+            stack_parent: None,
+        })
+        .await
+        .unwrap();
+    let full_id = session_id.as_uuid().to_string();
+
+    // -- issue a share token over HTTP with the server token --
+    let base = format!("http://{addr}/api");
+    let client = reqwest::Client::new();
+    let resp = client
+        .post(format!("{base}/sessions/{full_id}/share"))
+        .bearer_auth("server-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::CREATED);
+    let share: ShareToken = resp.json().await.unwrap();
+    assert_eq!(share.session_id, session_id);
+
+    // -- the share token is not an API credential --
+    let resp = client
+        .get(format!("{base}/workspace"))
+        .bearer_auth(&share.token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    // -- nor good for a normal agent attach --
+    let mut ws = connect_attach(
+        addr,
+        &share.token,
+        serde_json::json!({"type":"attach","session_id": full_id, "kind":"agent"}),
+    )
+    .await;
+    let err = next_text_frame(&mut ws)
+        .await
+        .expect("an out-of-scope attach should get an error frame");
+    assert!(err.contains(WS_ERR_SHARE_SCOPE), "got: {err}");
+
+    // -- a spectator attach is allowed, streams output, and counts as a viewer --
+    let mut ws = connect_attach(
+        addr,
+        &share.token,
+        serde_json::json!({"type":"attach","session_id": full_id, "kind":"spectator"}),
+    )
+    .await;
+    let ready = next_text_frame(&mut ws)
+        .await
+        .expect("spectator attach should reach ready");
+    let parsed: serde_json::Value = serde_json::from_str(&ready).unwrap();
+    assert_eq!(parsed["type"], "ready", "got: {ready}");
+    assert!(wait_for_binary_output(&mut ws, Duration::from_secs(5)).await);
+
+    let mut viewers = 0;
+    for _ in 0..50 {
+        viewers = service
+            .agent_states(true)
+            .await
+            .viewers
+            .get(&session_id)
+            .copied()
+            .unwrap_or(0);
+        if viewers > 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert!(viewers >= 1, "the spectator should be counted as a viewer");
+
+    // -- typed input is dropped: the command never shows up in the pane --
+    ws.send(Message::Binary(
+        b"echo cc_spectator_marker\n".to_vec().into(),
+    ))
+    .await
+    .unwrap();
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let pane = service
+        .get_pane_content(&full_id, None)
+        .await
+        .unwrap()
+        .unwrap_or_default();
+    assert!(
+        !pane.contains("cc_spectator_marker"),
+        "spectator input reached the pane:\n{pane}"
+    );
+
+    ws.send(Message::Text(r#"{"type":"detach"}"#.to_string().into()))
+        .await
+        .unwrap();
+    drain_until_close(&mut ws, Duration::from_secs(5)).await;
+
+    service.kill_session(&session_id).await.unwrap();
+
+    drop(repo_temp_dir);
+    drop(data_dir);
+    drop(worktrees_dir);
+}
+
+/// A spectator's size never reaches the window: it's attached at the window's
+/// own size and its `resize` frames are dropped, so a viewer — even the only
+/// client attached, when tmux would otherwise size the window to it — never
+/// shrinks or reflows the pane the agent is running in. Red against a bridge
+/// that spawns spectators at the attach frame's size or applies their resizes.
+#[tokio::test]
+async fn ws_spectator_resize_leaves_window_size_alone() {
+    if !tmux_available().await {
+        eprintln!("Skipping test: tmux not available");
+        return;
+    }
+
+    let (repo_temp_dir, repo_path) = create_test_repo().await;
+    let data_dir = TempDir::new().unwrap();
+    let worktrees_dir = TempDir::new().unwrap();
+    let state = test_state(&data_dir, &worktrees_dir);
+    let service = state.service.clone();
+    let addr = spawn_server(state).await;
+
+    service.add_project(repo_path.clone()).await.unwrap();
+    let session_id = service
+        .create_session(claude_commander_core::api::CreateSessionOpts {
+            project_path: repo_path.clone(),
+            title: "ws-spectator-size".to_string(),
+            program: Some("bash".to_string()),
+            initial_prompt: None,
+            effort: None,
+            mode: None,
+            model: None,
+            base_branch: None,
+            section: None,
+            stack_parent: None,
+        })
+        .await
+        .unwrap();
+    let tmux_name = service
+        .resolve_tmux_session(&session_id.to_string())
+        .await
+        .unwrap()
+        .expect("session should resolve to a tmux name");
+
+    let tmux = TmuxExecutor::new().with_tmux_tmpdir(service.read_config().tmux_tmpdir);
+    let before = window_size(&tmux, &tmux_name).await;
+    assert!(!before.is_empty(), "tmux should report the window size");
+
+    // A phone-sized spectator, alone on the session, that then shrinks
+    // further.
+    let mut ws = connect_attach(
+        addr,
+        "unused",
+        serde_json::json!({
+            "type": "attach",
+            "session_id": session_id.to_string(),
+            "kind": "spectator",
+            "cols": 39,
+            "rows": 20,
+        }),
+    )
+    .await;
+    let ready = next_text_frame(&mut ws)
+        .await
+        .expect("spectator attach should reach ready");
+    assert!(ready.contains(r#""type":"ready""#), "got: {ready}");
+
+    // Wait for tmux to register the spectator's client before resizing it.
+    let mut clients = String::new();
+    for _ in 0..60 {
+        clients = tmux
+            .execute(&["list-clients", "-t", &tmux_name, "-F", "#{client_name}"])
+            .await
+            .unwrap_or_default();
+        if !clients.trim().is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert!(
+        !clients.trim().is_empty(),
+        "the spectator should be attached"
+    );
+    ws.send(Message::Text(
+        r#"{"type":"resize","cols":30,"rows":10}"#.to_string().into(),
+    ))
+    .await
+    .unwrap();
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(
+        window_size(&tmux, &tmux_name).await,
+        before,
+        "a spectator must not change the window's size"
+    );
+
+    ws.send(Message::Text(r#"{"type":"detach"}"#.to_string().into()))
+        .await
+        .unwrap();
+    drain_until_close(&mut ws, Duration::from_secs(5)).await;
+    service.kill_session(&session_id).await.unwrap();
+
+    drop(repo_temp_dir);
+    drop(data_dir);
+    drop(worktrees_dir);
+}
+
+/// A share token bounds the whole spectator attach, not just its handshake: the
+/// socket is closed with `share_ended` when a short TTL runs out mid-attach, and
+/// when `DELETE …/share` revokes the token under a live viewer — after which the
+/// token no longer authenticates at all.
+#[tokio::test]
+async fn ws_share_attach_ends_on_expiry_and_revocation() {
+    if !tmux_available().await {
+        eprintln!("Skipping test: tmux not available");
+        return;
+    }
+
+    let (repo_temp_dir, repo_path) = create_test_repo().await;
+    let data_dir = TempDir::new().unwrap();
+    let worktrees_dir = TempDir::new().unwrap();
+    let mut state = test_state(&data_dir, &worktrees_dir);
+    state.auth = std::sync::Arc::new(AuthConfig::Token("server-token".to_string()));
+    let service = state.service.clone();
+    let addr = spawn_server(state).await;
+
+    service.add_project(repo_path.clone()).await.unwrap();
+    let session_id = service
+        .create_session(claude_commander_core::api::CreateSessionOpts {
+            project_path: repo_path.clone(),
+            title: "ws-share-ends".to_string(),
+            program: Some("bash".to_string()),
+            initial_prompt: None,
+            effort: None,
+            mode: None,
+            model: None,
+            base_branch: None,
+            section: None,
+            stack_parent: None,
+        })
+        .await
+        .unwrap();
+    let full_id = session_id.as_uuid().to_string();
+    let share_url = format!("http://{addr}/api/sessions/{full_id}/share");
+    let client = reqwest::Client::new();
+    let spectate = serde_json::json!({"type":"attach","session_id": full_id, "kind":"spectator"});
+
+    // -- a short-TTL token: the attach is cut off when it runs out --
+    let resp = client
+        .post(&share_url)
+        .bearer_auth("server-token")
+        .json(&serde_json::json!({"ttl_secs": 2}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::CREATED);
+    let short: ShareToken = resp.json().await.unwrap();
+
+    let mut ws = connect_attach(addr, &short.token, spectate.clone()).await;
+    let ready = next_text_frame(&mut ws)
+        .await
+        .expect("spectator attach should reach ready");
+    assert!(ready.contains(r#""type":"ready""#), "got: {ready}");
+    let detached = next_text_frame(&mut ws)
+        .await
+        .expect("the attach should end once the token expires");
+    assert!(
+        detached.contains(r#""reason":"share_ended""#),
+        "got: {detached}"
+    );
+    drain_until_close(&mut ws, Duration::from_secs(5)).await;
+
+    // -- a revoked token: the live attach is dropped and the token is dead --
+    let resp = client
+        .post(&share_url)
+        .bearer_auth("server-token")
+        .send()
+        .await
+        .unwrap();
+    let long: ShareToken = resp.json().await.unwrap();
+
+    let mut ws = connect_attach(addr, &long.token, spectate.clone()).await;
+    let ready = next_text_frame(&mut ws)
+        .await
+        .expect("spectator attach should reach ready");
+    assert!(ready.contains(r#""type":"ready""#), "got: {ready}");
+    let resp = client
+        .delete(&share_url)
+        .bearer_auth("server-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::NO_CONTENT);
+    let detached = next_text_frame(&mut ws)
+        .await
+        .expect("revoking the token should end the attach");
+    assert!(
+        detached.contains(r#""reason":"share_ended""#),
+        "got: {detached}"
+    );
+    drain_until_close(&mut ws, Duration::from_secs(5)).await;
+
+    let mut ws = connect_attach(addr, &long.token, spectate).await;
+    let err = next_text_frame(&mut ws)
+        .await
+        .expect("a revoked token should get an error frame");
+    assert!(err.contains(WS_ERR_AUTH), "got: {err}");
+
+    service.kill_session(&session_id).await.unwrap();
+
+    drop(repo_temp_dir);
+    drop(data_dir);
+    drop(worktrees_dir);
+}
+
+/// Open `/ws/attach` and send the `auth` frame with `token` followed by the
+/// given `attach` frame.
+async fn connect_attach(
+    addr: std::net::SocketAddr,
+    token: &str,
+    attach: serde_json::Value,
+) -> tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>> {
+    let url = format!("ws://{addr}/ws/attach");
+    let (mut ws, _resp) = tokio_tungstenite::connect_async(&url).await.unwrap();
+    ws.send(Message::Text(
+        serde_json::json!({"type":"auth","token": token})
+            .to_string()
+            .into(),
+    ))
+    .await
+    .unwrap();
+    ws.send(Message::Text(attach.to_string().into()))
+        .await
+        .unwrap();
+    ws
+}
+
+/// `target`'s window size as `WIDTHxHEIGHT`, or empty if tmux can't say.
+async fn window_size(tmux: &TmuxExecutor, target: &str) -> String {
+    tmux.execute(&[
+        "display-message",
+        "-p",
+        "-t",
+        target,
+        "#{window_width}x#{window_height}",
+    ])
+    .await
+    .unwrap_or_default()
+    .trim()
+    .to_string()
+}
+
 /// Receive frames until the next TEXT frame, returning its payload. `None` on
 /// close/error/timeout.
 async fn next_text_frame(
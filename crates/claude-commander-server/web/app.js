@@ -0,0 +1,687 @@
+// Browser client for claude-commander-server.
+//
+// Plain script, no build step: the server embeds this file and serves it at
+// /ui/app.js. Everything it shows comes from the same bearer-authenticated
+// /api surface and /ws/attach protocol the other clients use; the token lives
+// in localStorage under `cc.token`. A spectator link (`/#spectate=<share
+// token>&session=<id>`) skips all of that and opens a read-only terminal.
+"use strict";
+
+const TOKEN_KEY = "cc.token";
+const BOARD_POLL_MS = 3000;
+// `WS_ERR_AUTH` in claude_commander_protocol::ws.
+const WS_ERR_AUTH = "authentication failed";
+// `WS_ERR_SHARE_SCOPE` in claude_commander_protocol::ws.
+const WS_ERR_SHARE_SCOPE = "share token does not cover this attach";
+
+// Fields `PATCH /api/config` accepts (the server's ConfigPatch allow-list), in
+// display order. `kind` picks the input; "list" is a comma-separated string.
+const SETTINGS = [
+  ["branch_prefix", "text", "Branch prefix"],
+  ["fetch_before_create", "bool", "Fetch before creating a session"],
+  ["resume_session", "bool", "Resume the agent's session on restart"],
+  ["in_progress_limit", "number", "In-progress limit"],
+  ["in_progress_lane_limit", "number", "In-progress limit per lane"],
+  ["pr_check_interval_secs", "number", "PR check interval (s)"],
+  ["pr_review_labels", "list", "PR review labels"],
+  ["invert_pr_label_color", "bool", "Invert PR label colour"],
+  ["project_pull_enabled", "bool", "Auto-pull projects"],
+  ["project_pull_interval_secs", "number", "Project pull interval (s)"],
+  ["ai_summary_enabled", "bool", "AI summaries"],
+  ["show_session_program", "bool", "Show each session's program"],
+  ["rounded_borders", "bool", "Rounded borders"],
+  ["precompute_review_caches", "bool", "Precompute review caches"],
+  ["max_concurrent_tmux", "number", "Max concurrent tmux calls"],
+  ["capture_cache_ttl_ms", "number", "Pane capture cache TTL (ms)"],
+  ["diff_cache_ttl_ms", "number", "Diff cache TTL (ms)"],
+  ["ui_refresh_fps", "number", "UI refresh rate (fps)"],
+  ["state_sync_interval_ms", "number", "State sync interval (ms)"],
+  ["agent_state_poll_interval_ms", "number", "Agent state poll interval (ms)"],
+  ["session_number_debounce_ms", "number", "Session number debounce (ms)"],
+];
+
+const $ = (sel) => document.querySelector(sel);
+
+function el(tag, attrs = {}, ...children) {
+  const node = document.createElement(tag);
+  for (const [key, value] of Object.entries(attrs)) {
+    if (key === "class") node.className = value;
+    else if (key.startsWith("on")) node.addEventListener(key.slice(2), value);
+    else if (value !== undefined && value !== null && value !== false) node.setAttribute(key, value);
+  }
+  for (const child of children.flat()) {
+    if (child === null || child === undefined || child === false) continue;
+    node.append(child instanceof Node ? child : String(child));
+  }
+  return node;
+}
+
+function setStatus(text) {
+  $("#status").textContent = text || "";
+}
+
+// -- API --
+
+class Unauthorized extends Error {}
+
+async function api(path, { method = "GET", body } = {}) {
+  const headers = {};
+  const token = localStorage.getItem(TOKEN_KEY);
+  if (token) headers.Authorization = `Bearer ${token}`;
+  if (body !== undefined) headers["Content-Type"] = "application/json";
+  const resp = await fetch(`/api${path}`, {
+    method,
+    headers,
+    body: body === undefined ? undefined : JSON.stringify(body),
+  });
+  if (resp.status === 401) throw new Unauthorized();
+  if (!resp.ok) {
+    const text = await resp.text();
+    let message = text;
+    try {
+      message = JSON.parse(text).error.message || text;
+    } catch (_) {
+      // Not JSON; show it as is.
+    }
+    throw new Error(message || `${resp.status} ${resp.statusText}`);
+  }
+  if (resp.status === 204) return null;
+  const type = resp.headers.get("content-type") || "";
+  return type.includes("application/json") ? resp.json() : resp.text();
+}
+
+// Run an async action, routing a rejected token to the login view and any
+// other failure to the status line.
+async function guarded(action) {
+  try {
+    return await action();
+  } catch (e) {
+    if (e instanceof Unauthorized) showLogin("The server rejected the token.");
+    else setStatus(e.message);
+    return undefined;
+  }
+}
+
+// -- Views --
+
+const VIEWS = ["login", "board", "terminal-view", "review-view", "settings"];
+let boardTimer = null;
+
+function show(view) {
+  for (const id of VIEWS) $(`#${id}`).hidden = id !== view;
+  for (const button of document.querySelectorAll("nav button")) {
+    button.classList.toggle("active", button.dataset.view === view);
+  }
+  if (view !== "terminal-view") terminal.close();
+  clearInterval(boardTimer);
+  boardTimer = null;
+  if (view === "board") {
+    refreshBoard();
+    boardTimer = setInterval(refreshBoard, BOARD_POLL_MS);
+  }
+  if (view === "settings") loadSettings();
+}
+
+function showLogin(message) {
+  $("#login-error").textContent = message || "";
+  show("login");
+  $("#login-token").focus();
+}
+
+// -- Board --
+
+let sectionOrder = [];
+
+async function refreshBoard() {
+  const data = await guarded(() =>
+    Promise.all([api("/workspace"), api("/agent-states")]),
+  );
+  if (!data) return;
+  const [workspace, agents] = data;
+  setStatus(agents.commander_running ? "commander running" : "");
+  renderBoard(workspace, agents.states || {}, agents.viewers || {});
+}
+
+function renderBoard(workspace, states, viewers) {
+  const pending = new Set(workspace.pending_comment_sessions || []);
+  const columns = new Map(sectionOrder.map((name) => [name, []]));
+  for (const session of workspace.sessions) {
+    const name = session.current_section || "Other";
+    if (!columns.has(name)) columns.set(name, []);
+    columns.get(name).push(session);
+  }
+  const board = $("#board");
+  board.replaceChildren();
+  for (const [name, sessions] of columns) {
+    if (sessions.length === 0) continue;
+    board.append(
+      el(
+        "div",
+        { class: "column" },
+        el("h3", {}, `${name} · ${sessions.length}`),
+        sessions.map((s) => card(s, states[s.id], pending.has(s.id), viewers[s.id] || 0)),
+      ),
+    );
+  }
+  if (!board.hasChildNodes()) {
+    board.append(el("p", { class: "muted" }, "No sessions yet."));
+  }
+}
+
+function card(session, agentState, hasPending, viewerCount) {
+  const state = agentState || "unknown";
+  const pr = session.pr_number
+    ? el(
+        "a",
+        { href: session.pr_url, target: "_blank", rel: "noopener" },
+        `#${session.pr_number}`,
+        session.pr_draft ? " draft" : ` ${session.pr_state}`,
+      )
+    : null;
+  const checks =
+    session.pr_checks && session.pr_checks !== "none"
+      ? el("span", { class: `checks-${session.pr_checks}` }, ` · checks ${session.pr_checks}`)
+      : null;
+  const running = session.status === "running";
+  return el(
+    "div",
+    { class: "card" },
+    el("div", { class: "title" }, session.title),
+    el(
+      "div",
+      { class: "meta" },
+      `${session.project_name} · ${session.branch}`,
+      el("br"),
+      el("span", { class: `state-${state}` }, running ? state.replaceAll("_", " ") : session.status),
+      pr ? " · " : null,
+      pr,
+      checks,
+      hasPending ? el("span", { class: "badge" }, "comments pending") : null,
+      viewerCount > 0 ? el("span", { class: "badge" }, `${viewerCount} attached`) : null,
+    ),
+    el(
+      "div",
+      { class: "actions" },
+      el("button", { onclick: () => openTerminal(session, "agent") }, "Attach"),
+      el("button", { onclick: () => openTerminal(session, "shell") }, "Shell"),
+      el("button", { onclick: () => openReview(session) }, "Review"),
+      el("button", { onclick: () => shareSession(session) }, "Share"),
+      el("button", { onclick: () => unshareSession(session) }, "Unshare"),
+    ),
+  );
+}
+
+// Issue a share token and show the spectator link built from it. A prompt
+// rather than the clipboard API, which browsers withhold from plain-http pages.
+async function shareSession(session) {
+  const share = await guarded(() => api(`/sessions/${session.id}/share`, { method: "POST" }));
+  if (!share) return;
+  const params = new URLSearchParams({
+    spectate: share.token,
+    session: share.session_id,
+    title: session.title,
+  });
+  const expires = new Date(share.expires_at).toLocaleString();
+  window.prompt(`Read-only link to ${session.title}, valid until ${expires}:`, `${location.origin}/#${params}`);
+}
+
+// Revoke every share link to the session, disconnecting anyone watching
+// through one. `api` resolves a 204 to null; `guarded` yields undefined on error.
+async function unshareSession(session) {
+  const done = await guarded(() => api(`/sessions/${session.id}/share`, { method: "DELETE" }));
+  if (done !== undefined) setStatus(`Share links to ${session.title} revoked.`);
+}
+
+// -- Terminal --
+
+const terminal = {
+  term: null,
+  fit: null,
+  socket: null,
+  session: null,
+  kind: "agent",
+  // Set when the page was opened from a spectator link; used instead of the
+  // stored token, and only ever for a "spectator" attach.
+  shareToken: null,
+
+  open(session, kind) {
+    this.close();
+    this.session = session;
+    this.kind = kind;
+    const label = { agent: "agent", shell: "shell", spectator: "watching" }[kind];
+    $("#term-title").textContent = `${session.title} — ${label}`;
+    $("#term-kind").textContent = kind === "shell" ? "Agent" : "Shell";
+    const container = $("#terminal");
+    container.replaceChildren();
+    if (!window.Terminal || !window.FitAddon) {
+      container.append(
+        el("p", { class: "error" }, "The terminal library failed to load."),
+      );
+      return;
+    }
+    this.term = new window.Terminal({
+      cursorBlink: kind !== "spectator",
+      disableStdin: kind === "spectator",
+      fontSize: 13,
+      scrollback: 5000,
+    });
+    this.fit = new window.FitAddon.FitAddon();
+    this.term.loadAddon(this.fit);
+    this.term.open(container);
+    this.fit.fit();
+    this.connect();
+  },
+
+  connect() {
+    const scheme = location.protocol === "https:" ? "wss" : "ws";
+    const socket = new WebSocket(`${scheme}://${location.host}/ws/attach`);
+    socket.binaryType = "arraybuffer";
+    this.socket = socket;
+    const encoder = new TextEncoder();
+    const term = this.term;
+
+    socket.onopen = () => {
+      const token = this.shareToken || localStorage.getItem(TOKEN_KEY) || "";
+      socket.send(JSON.stringify({ type: "auth", token }));
+      socket.send(
+        JSON.stringify({
+          type: "attach",
+          session_id: this.session.id,
+          kind: this.kind,
+          cols: term.cols,
+          rows: term.rows,
+        }),
+      );
+    };
+    socket.onmessage = (event) => {
+      if (typeof event.data !== "string") {
+        term.write(new Uint8Array(event.data));
+        return;
+      }
+      const msg = JSON.parse(event.data);
+      if (msg.type === "ready") {
+        setStatus(`attached to ${msg.session}`);
+        term.focus();
+      } else if (msg.type === "detached" && msg.reason === "share_ended") {
+        setStatus("This share link has expired or was revoked.");
+      } else if (msg.type === "detached") {
+        setStatus(`detached (${msg.reason.replaceAll("_", " ")})`);
+      } else if (msg.type === "error") {
+        if (this.shareToken && (msg.message === WS_ERR_AUTH || msg.message === WS_ERR_SHARE_SCOPE)) {
+          setStatus("This share link has expired or is not valid for this session.");
+        } else if (msg.message === WS_ERR_AUTH) showLogin("The server rejected the token.");
+        else setStatus(msg.message);
+      }
+    };
+    socket.onclose = () => {
+      if (this.socket === socket) this.socket = null;
+    };
+    term.onData((data) => {
+      if (socket.readyState === WebSocket.OPEN) socket.send(encoder.encode(data));
+    });
+    term.onResize(({ cols, rows }) => {
+      if (socket.readyState === WebSocket.OPEN) {
+        socket.send(JSON.stringify({ type: "resize", cols, rows }));
+      }
+    });
+  },
+
+  detach() {
+    if (this.socket && this.socket.readyState === WebSocket.OPEN) {
+      this.socket.send(JSON.stringify({ type: "detach" }));
+    }
+  },
+
+  close() {
+    if (this.socket) {
+      this.detach();
+      this.socket.close();
+      this.socket = null;
+    }
+    if (this.term) {
+      this.term.dispose();
+      this.term = null;
+      this.fit = null;
+    }
+  },
+
+  refit() {
+    if (this.fit) this.fit.fit();
+  },
+};
+
+function openTerminal(session, kind) {
+  show("terminal-view");
+  terminal.open(session, kind);
+}
+
+// -- Review --
+
+const review = {
+  session: null,
+  snapshot: null,
+  anchor: null, // { file, side, line, row } of the last plain click
+};
+
+async function openReview(session) {
+  review.session = session;
+  $("#review-title").textContent = session.title;
+  show("review-view");
+  await loadReview();
+}
+
+async function loadReview() {
+  const snapshot = await guarded(() => api(`/sessions/${review.session.id}/review`));
+  if (!snapshot) return;
+  review.snapshot = snapshot;
+  renderReview();
+}
+
+function renderReview() {
+  const { diff, comments } = review.snapshot;
+  const files = diff.files;
+  const added = files.reduce((n, f) => n + f.added, 0);
+  const removed = files.reduce((n, f) => n + f.removed, 0);
+  const staged = comments.filter((c) => c.status !== "applied").length;
+  $("#review-summary").textContent =
+    `${files.length} files · +${added} −${removed} · ${staged} comments against ${review.snapshot.base}`;
+  // The old rows are gone, so a pending range anchor is too.
+  review.anchor = null;
+  const root = $("#review");
+  root.replaceChildren();
+  if (files.length === 0) {
+    root.append(el("p", { class: "muted" }, "No changes against the base branch."));
+  }
+  for (const file of files) root.append(renderFile(file, comments));
+}
+
+// Comments address a file by its new path, or its old path once deleted.
+function commentPath(file) {
+  return file.status === "deleted" ? file.old_path : file.new_path;
+}
+
+function renderFile(file, comments) {
+  const path = commentPath(file);
+  const label =
+    file.status === "renamed" ? `${file.old_path} → ${file.new_path}` : path;
+  const head = el(
+    "div",
+    { class: "file-head" },
+    el("span", { class: "path" }, label),
+    el("span", { class: "muted" }, file.status),
+    el("span", { class: "stat-add" }, `+${file.added}`),
+    el("span", { class: "stat-del" }, `−${file.removed}`),
+  );
+  if (file.binary) {
+    return el("div", { class: "file" }, head, el("p", { class: "muted" }, " Binary file"));
+  }
+  const table = el("table", { class: "diff" });
+  const mine = comments.filter((c) => c.file === path);
+  for (const hunk of file.hunks) {
+    table.append(
+      el("tr", { class: "hunk" }, el("td", { colspan: 3 }, hunk.header)),
+    );
+    for (const line of hunk.lines) {
+      const side = line.origin === "deletion" ? "old" : "new";
+      const number = side === "old" ? line.old_lineno : line.new_lineno;
+      const row = el(
+        "tr",
+        { class: line.origin },
+        el("td", { class: "ln" }, line.old_lineno ?? ""),
+        el("td", { class: "ln" }, line.new_lineno ?? ""),
+        el("td", { class: "code" }, prefix(line.origin) + line.content),
+      );
+      row.dataset.side = side;
+      row.dataset.line = number;
+      for (const cell of row.querySelectorAll("td.ln")) {
+        cell.addEventListener("click", (event) => selectLine(path, row, event.shiftKey));
+      }
+      table.append(row);
+      for (const c of mine) {
+        if (c.side === side && c.line_range[1] === number) table.append(noteRow(c));
+      }
+    }
+  }
+  return el("div", { class: "file" }, head, table);
+}
+
+function prefix(origin) {
+  return origin === "addition" ? "+" : origin === "deletion" ? "-" : " ";
+}
+
+function noteRow(comment) {
+  const [a, b] = comment.line_range;
+  return el(
+    "tr",
+    { class: "note" },
+    el(
+      "td",
+      { colspan: 3 },
+      el(
+        "div",
+        { class: "note-body" },
+        el("span", { class: "muted" }, a === b ? `L${a}` : `L${a}–${b}`),
+        comment.status === "drifted" ? el("span", { class: "drifted" }, "drifted") : null,
+        comment.status === "applied" ? el("span", { class: "muted" }, "sent") : null,
+        el("span", {}, comment.comment),
+        el("span", { class: "spacer" }),
+        el("button", { onclick: () => deleteComment(comment.id) }, "Delete"),
+      ),
+    ),
+  );
+}
+
+// A plain click anchors a one-line comment; a shift-click on the same side of
+// the same file extends it into a range.
+function selectLine(path, row, extend) {
+  const side = row.dataset.side;
+  const line = Number(row.dataset.line);
+  const anchor = review.anchor;
+  let start = row;
+  let end = row;
+  if (extend && anchor && anchor.file === path && anchor.side === side) {
+    [start, end] = anchor.line <= line ? [anchor.row, row] : [row, anchor.row];
+  } else {
+    review.anchor = { file: path, side, line, row };
+  }
+  openCommentForm(path, side, start, end);
+}
+
+function openCommentForm(path, side, startRow, endRow) {
+  for (const old of document.querySelectorAll("#review tr.selected")) {
+    old.classList.remove("selected");
+  }
+  for (const old of document.querySelectorAll("#review tr.form-row")) old.remove();
+
+  // Rows between the two ends on the comment's side make up the snippet.
+  const rows = [];
+  for (let row = startRow; row; row = row.nextElementSibling) {
+    if (row.dataset.side === side) {
+      rows.push(row);
+      row.classList.add("selected");
+    }
+    if (row === endRow) break;
+  }
+  const first = Number(startRow.dataset.line);
+  const last = Number(endRow.dataset.line);
+  const snippet = rows.map((r) => r.querySelector("td.code").textContent.slice(1)).join("\n");
+
+  const form = $("#comment-form").content.firstElementChild.cloneNode(true);
+  form.querySelector(".range").textContent =
+    `${path} · ${side} side · ${first === last ? `line ${first}` : `lines ${first}–${last}`}`;
+  const textarea = form.querySelector("textarea");
+  const formRow = el("tr", { class: "note form-row" }, el("td", { colspan: 3 }, form));
+  form.querySelector(".cancel").addEventListener("click", () => {
+    formRow.remove();
+    for (const row of rows) row.classList.remove("selected");
+  });
+  form.addEventListener("submit", async (event) => {
+    event.preventDefault();
+    const comment = textarea.value.trim();
+    if (!comment) return;
+    const created = await guarded(() =>
+      api(`/sessions/${review.session.id}/comments`, {
+        method: "POST",
+        body: { file: path, side, line_range: [first, last], snippet, comment },
+      }),
+    );
+    if (created) await loadReview();
+  });
+  endRow.after(formRow);
+  textarea.focus();
+}
+
+async function deleteComment(id) {
+  const done = await guarded(async () => {
+    await api(`/sessions/${review.session.id}/comments/${id}`, { method: "DELETE" });
+    return true;
+  });
+  if (done) await loadReview();
+}
+
+async function applyComments() {
+  const outcome = await guarded(() =>
+    api(`/sessions/${review.session.id}/comments/apply`, { method: "POST" }),
+  );
+  if (!outcome) return;
+  const messages = {
+    nothing: "No staged comments to send.",
+    blocked: `${(outcome.drifted || []).length} comments drifted; delete or re-anchor them first.`,
+    applied: `Sent ${outcome.count} comments to the agent.`,
+    deferred: `Queued ${outcome.count} comments; they go out when the agent is idle.`,
+  };
+  setStatus(messages[outcome.outcome] || outcome.outcome);
+  await loadReview();
+}
+
+// -- Settings --
+
+async function loadSettings() {
+  const config = await guarded(() => api("/config"));
+  if (!config) return;
+  const form = el("form");
+  const inputs = new Map();
+  for (const [key, kind, label] of SETTINGS) {
+    const value = config[key];
+    let input;
+    if (kind === "bool") {
+      input = el("input", { type: "checkbox" });
+      input.checked = Boolean(value);
+    } else if (kind === "number") {
+      input = el("input", { type: "number", min: 0 });
+      input.value = value ?? "";
+    } else {
+      input = el("input", { type: "text" });
+      input.value = kind === "list" ? (value || []).join(", ") : (value ?? "");
+    }
+    inputs.set(key, { kind, input, initial: value });
+    form.append(
+      el("label", {}, label, el("span", { class: "key" }, key)),
+      input,
+    );
+  }
+  const note = el("span", { class: "muted" });
+  form.append(
+    el(
+      "div",
+      { class: "actions" },
+      el("button", { type: "submit" }, "Save"),
+      el("button", { type: "button", onclick: logOut }, "Forget token"),
+      note,
+    ),
+  );
+  form.addEventListener("submit", async (event) => {
+    event.preventDefault();
+    const patch = settingsPatch(inputs);
+    if (Object.keys(patch).length === 0) {
+      note.textContent = "Nothing changed.";
+      return;
+    }
+    const saved = await guarded(async () => {
+      await api("/config", { method: "PATCH", body: patch });
+      return true;
+    });
+    if (saved) {
+      note.textContent = `Saved ${Object.keys(patch).join(", ")}.`;
+      for (const [key, value] of Object.entries(patch)) inputs.get(key).initial = value;
+    }
+  });
+  $("#settings").replaceChildren(form);
+}
+
+// Only the fields the user changed go into the PATCH, so a concurrent edit of
+// another field (from the TUI, say) isn't overwritten with a stale value.
+function settingsPatch(inputs) {
+  const patch = {};
+  for (const [key, { kind, input, initial }] of inputs) {
+    let value;
+    if (kind === "bool") value = input.checked;
+    else if (kind === "number") {
+      // An emptied field can't be sent: the patch treats null as "unchanged".
+      if (input.value === "") continue;
+      value = Number(input.value);
+    } else if (kind === "list") {
+      value = input.value.split(",").map((s) => s.trim()).filter(Boolean);
+    } else value = input.value;
+    if (JSON.stringify(value) !== JSON.stringify(initial ?? (kind === "list" ? [] : null))) {
+      patch[key] = value;
+    }
+  }
+  return patch;
+}
+
+function logOut() {
+  localStorage.removeItem(TOKEN_KEY);
+  showLogin();
+}
+
+// -- Wiring --
+
+async function loadSectionOrder() {
+  const config = await guarded(() => api("/config"));
+  if (config && Array.isArray(config.sections)) {
+    sectionOrder = config.sections.map((s) => s.name);
+  }
+  return config !== undefined;
+}
+
+async function start() {
+  if (await loadSectionOrder()) show("board");
+}
+
+// Open the read-only terminal a spectator link points at, without the board,
+// settings or login. Returns false when the page wasn't opened from one.
+function startSpectating() {
+  const params = new URLSearchParams(location.hash.slice(1));
+  const token = params.get("spectate");
+  const id = params.get("session");
+  if (!token || !id) return false;
+  document.querySelector("header nav").hidden = true;
+  $("#term-back").hidden = true;
+  $("#term-kind").hidden = true;
+  terminal.shareToken = token;
+  openTerminal({ id, title: params.get("title") || id }, "spectator");
+  return true;
+}
+
+document.addEventListener("DOMContentLoaded", () => {
+  for (const button of document.querySelectorAll("nav button")) {
+    button.addEventListener("click", () => show(button.dataset.view));
+  }
+  $("#login-form").addEventListener("submit", async (event) => {
+    event.preventDefault();
+    localStorage.setItem(TOKEN_KEY, $("#login-token").value.trim());
+    $("#login-token").value = "";
+    await start();
+  });
+  $("#term-back").addEventListener("click", () => show("board"));
+  $("#term-detach").addEventListener("click", () => terminal.detach());
+  $("#term-kind").addEventListener("click", () => {
+    if (terminal.session) openTerminal(terminal.session, terminal.kind === "shell" ? "agent" : "shell");
+  });
+  $("#review-back").addEventListener("click", () => show("board"));
+  $("#review-refresh").addEventListener("click", loadReview);
+  $("#review-apply").addEventListener("click", applyComments);
+  window.addEventListener("resize", () => terminal.refit());
+  if (!startSpectating()) start();
+});
@@ -74,6 +74,25 @@ pub enum Commands {
         force: bool,
     },
 
+    /// Undo the most recent session delete, project removal or cascade abandon
+    /// (within 15 minutes): the session comes back with its worktree and any
+    /// uncommitted changes, and the agent resumes.
+    Undo,
+
+    /// Archive a session: stop it and remove its worktree, keeping the branch,
+    /// any uncommitted changes (on a hidden ref) and its resume info.
+    Archive {
+        /// Session name or ID prefix
+        session: String,
+    },
+
+    /// Restore an archived session: recreate its worktree and relaunch the
+    /// agent with `--resume`.
+    Restore {
+        /// Session name or ID prefix
+        session: String,
+    },
+
     /// Toggle (or explicitly set) keep-alive on a session, exempting it from
     /// automatic idle-hibernation. With no flag, toggles the current value.
     KeepAlive {
@@ -89,6 +108,53 @@ pub enum Commands {
         off: bool,
     },
 
+    /// Add or remove tags on a session, then print its tags. With no tags
+    /// or flags, just prints them.
+    Tag {
+        /// Session name or ID prefix
+        session: String,
+
+        /// Tags to add (a leading `#` is optional)
+        tags: Vec<String>,
+
+        /// Tag to remove (repeatable)
+        #[arg(long, value_name = "TAG")]
+        remove: Vec<String>,
+
+        /// Remove every tag before adding any given ones
+        #[arg(long)]
+        clear: bool,
+    },
+
+    /// Set a session's notes, or print them when no text is given
+    Note {
+        /// Session name or ID prefix
+        session: String,
+
+        /// Note text (replaces the existing notes)
+        #[arg(conflicts_with = "clear")]
+        text: Option<String>,
+
+        /// Clear the session's notes
+        #[arg(long)]
+        clear: bool,
+    },
+
+    /// Sum up how a session is getting on: agent state, recent output,
+    /// diffstat, review comments not yet sent, and PR/CI status
+    Brief {
+        /// Session name or ID prefix
+        session: String,
+
+        /// Number of pane lines to include (default: 40, max: 10000)
+        #[arg(short, long, default_value_t = 40)]
+        lines: usize,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Dump recent terminal output from a session
     Log {
         /// Session name or ID prefix
@@ -99,6 +165,32 @@ pub enum Commands {
         lines: usize,
     },
 
+    /// Search every session's scrollback (up to 50000 lines of tmux history
+    /// each). Prints `session:line: text` per hit, newest first per session;
+    /// exits 1 when nothing matches.
+    Grep {
+        /// Text to search for; case-insensitive unless it contains an
+        /// uppercase letter
+        pattern: String,
+
+        /// Treat the pattern as a regular expression
+        #[arg(short = 'E', long)]
+        regex: bool,
+
+        /// Maximum number of hits (default: 200)
+        #[arg(short = 'm', long)]
+        limit: Option<usize>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Search a configured remote server's sessions (by name from
+        /// `[[remote_servers]]`) instead of local ones.
+        #[arg(long)]
+        remote: Option<String>,
+    },
+
     /// Create a new session
     New {
         /// Session name
@@ -174,18 +266,39 @@ pub enum Commands {
         init: bool,
     },
 
+    /// Trust a project's `.commander.toml` so its hooks and tasks may run.
+    /// Prints the file, then records the digest of its current contents in
+    /// `trusted_project_files`; any later edit needs a fresh `trust`.
+    Trust {
+        /// The project file, or a directory containing one (default: current directory)
+        path: Option<std::path::PathBuf>,
+
+        /// Remove the file's current contents from the trusted list instead
+        #[arg(long)]
+        revoke: bool,
+    },
+
     /// Toggle voice input in the running TUI. Intended for binding to a desktop
     /// global keyboard shortcut (e.g. a KDE Plasma custom shortcut) so STT can be
     /// triggered system-wide, not just when the terminal is focused. Talks to the
     /// already-running instance over a local socket; with no flag it toggles.
     ListenToggle {
         /// Start recording (instead of toggling)
-        #[arg(long, conflicts_with = "stop")]
+        #[arg(long, conflicts_with_all = ["stop", "interrupt"])]
         start: bool,
 
         /// Stop recording and transcribe (instead of toggling)
-        #[arg(long, conflicts_with = "start")]
+        #[arg(long, conflicts_with_all = ["start", "interrupt"])]
         stop: bool,
+
+        /// Stop the spoken reply without touching the microphone
+        #[arg(long, conflicts_with_all = ["start", "stop"])]
+        interrupt: bool,
+
+        /// Send the recording to this conversation agent (a profile name, or
+        /// the default agent's), switching to it
+        #[arg(long, value_name = "NAME", conflicts_with = "interrupt")]
+        agent: Option<String>,
     },
 }
 
@@ -202,9 +315,15 @@ pub fn cli_reference() -> String {
     generate_cli_reference(&cli_command())
 }
 
+/// Subcommands left out of the agent-facing reference even though `--help`
+/// shows them. `trust` approves repo-supplied commands to run, which is the
+/// user's call to make, never an agent's.
+const NOT_FOR_AGENTS: &[&str] = &["trust"];
+
 /// Render a markdown CLI reference by walking a clap command tree and emitting
 /// each visible subcommand's long help verbatim, labelled with the tree's
-/// program name. Hidden subcommands (e.g. internal popup helpers) are skipped.
+/// program name. Hidden subcommands (e.g. internal popup helpers) and
+/// [`NOT_FOR_AGENTS`] are skipped.
 ///
 /// Split from [`cli_reference`] so the walk is testable against a synthetic tree
 /// with a known shape.
@@ -212,7 +331,7 @@ fn generate_cli_reference(cmd: &clap::Command) -> String {
     let bin = cmd.get_name().to_string();
     let mut out = String::new();
     for sub in cmd.get_subcommands() {
-        if sub.is_hide_set() {
+        if sub.is_hide_set() || NOT_FOR_AGENTS.contains(&sub.get_name()) {
             continue;
         }
         out.push_str(&format!("### `{bin} {}`\n\n", sub.get_name()));
@@ -297,6 +416,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cli_reference_leaves_out_trust() {
+        assert!(cli_command().find_subcommand("trust").is_some());
+        assert!(
+            !cli_reference().contains("claude-commander trust"),
+            "agents must not be told how to trust project files"
+        );
+    }
+
     #[test]
     fn cli_command_exposes_known_subcommands() {
         let cmd = cli_command();
@@ -304,13 +432,17 @@ mod tests {
         for expected in [
             "list",
             "status",
+            "brief",
             "log",
+            "grep",
             "new",
             "attach",
             "config",
             "commander",
             "listen-toggle",
             "keep-alive",
+            "tag",
+            "note",
         ] {
             assert!(
                 names.contains(&expected),
@@ -10,7 +10,7 @@ use tracing::info;
 use tracing_subscriber::{EnvFilter, fmt, prelude::*};
 
 use claude_commander_core::{
-    config::{AppState, Config, ConfigStore, StateStore},
+    config::{AppState, Config, ConfigStore, ProjectFile, StateStore},
     tmux::{AttachResult, attach_to_session},
     tui::App,
 };
@@ -173,6 +173,41 @@ fn raise_fd_limit() {
 #[cfg(not(unix))]
 fn raise_fd_limit() {}
 
+/// Render a session's tags for CLI output: `#a #b`, or `(no tags)`.
+fn format_tags(tags: &[String]) -> String {
+    if tags.is_empty() {
+        "(no tags)".to_string()
+    } else {
+        tags.iter()
+            .map(|t| format!("#{t}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Resolve `session` by exact title or full ID for a CLI command that edits
+/// it, exiting with a hint when it is missing or ambiguous.
+async fn find_session_or_exit(
+    service: &claude_commander_core::api::CommanderService,
+    session: &str,
+) -> Result<claude_commander_core::api::SessionInfo> {
+    match service.find_session_exact(session).await? {
+        claude_commander_core::cli::SessionLookup::Found(i) => Ok(i),
+        claude_commander_core::cli::SessionLookup::NotFound => {
+            eprintln!("Session not found: {}", session);
+            eprintln!("Use 'claude-commander list' to see available sessions.");
+            std::process::exit(1);
+        }
+        claude_commander_core::cli::SessionLookup::Ambiguous(n) => {
+            eprintln!(
+                "\"{}\" matches {} sessions. Use the exact title or full ID.",
+                session, n
+            );
+            std::process::exit(1);
+        }
+    }
+}
+
 /// Execute async PTY-based attach to a tmux session
 async fn execute_attach(session_name: &str, editor_triggers: Vec<Vec<u8>>) {
     // CLI `attach` resolves a Claude session by title/ID, never a shell. The
@@ -317,6 +352,7 @@ async fn main() -> Result<()> {
                     } else {
                         for session in sessions {
                             let status_icon = match session.status {
+                                _ if session.archived.is_some() => "◇",
                                 claude_commander_core::SessionStatus::Creating
                                 | claude_commander_core::SessionStatus::Merging
                                 | claude_commander_core::SessionStatus::Pushing => "⠋",
@@ -418,30 +454,61 @@ async fn main() -> Result<()> {
             }
 
             service.delete_session(&info.session_id).await?;
-            println!("Session deleted: {}", info.title);
+            println!(
+                "Session deleted: {} (undo with 'claude-commander undo')",
+                info.title
+            );
         }
 
-        Some(Commands::KeepAlive { session, on, off }) => {
+        Some(Commands::Undo) => {
             setup_logging(cli.debug, false)?;
 
             let service =
                 claude_commander_core::api::CommanderService::for_cli(config, frontend())?;
-
-            let info = match service.find_session_exact(&session).await? {
-                claude_commander_core::cli::SessionLookup::Found(i) => i,
-                claude_commander_core::cli::SessionLookup::NotFound => {
-                    eprintln!("Session not found: {}", session);
-                    eprintln!("Use 'claude-commander list' to see available sessions.");
-                    std::process::exit(1);
-                }
-                claude_commander_core::cli::SessionLookup::Ambiguous(n) => {
-                    eprintln!(
-                        "\"{}\" matches {} sessions. Use the exact title or full ID.",
-                        session, n
-                    );
+            match service.undo().await {
+                Ok(summary) => println!("Undid: {summary}"),
+                Err(
+                    e @ claude_commander_core::Error::Session(
+                        claude_commander_core::error::SessionError::NothingToUndo,
+                    ),
+                ) => {
+                    eprintln!("{e}");
                     std::process::exit(1);
                 }
-            };
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Some(Commands::Archive { session }) => {
+            setup_logging(cli.debug, false)?;
+
+            let service =
+                claude_commander_core::api::CommanderService::for_cli(config, frontend())?;
+            let info = find_session_or_exit(&service, &session).await?;
+            service.archive_session(&info.session_id).await?;
+            println!(
+                "Session archived: {} (restore with 'claude-commander restore')",
+                info.title
+            );
+        }
+
+        Some(Commands::Restore { session }) => {
+            setup_logging(cli.debug, false)?;
+
+            let service =
+                claude_commander_core::api::CommanderService::for_cli(config, frontend())?;
+            let info = find_session_or_exit(&service, &session).await?;
+            service.restore_session(&info.session_id).await?;
+            println!("Session restored: {}", info.title);
+        }
+
+        Some(Commands::KeepAlive { session, on, off }) => {
+            setup_logging(cli.debug, false)?;
+
+            let service =
+                claude_commander_core::api::CommanderService::for_cli(config, frontend())?;
+
+            let info = find_session_or_exit(&service, &session).await?;
 
             // `on`/`off` set explicitly; with neither flag, toggle.
             let keep_alive = if on {
@@ -458,6 +525,86 @@ async fn main() -> Result<()> {
             );
         }
 
+        Some(Commands::Tag {
+            session,
+            tags,
+            remove,
+            clear,
+        }) => {
+            setup_logging(cli.debug, false)?;
+
+            let service =
+                claude_commander_core::api::CommanderService::for_cli(config, frontend())?;
+            let info = find_session_or_exit(&service, &session).await?;
+
+            let mut current = if clear { Vec::new() } else { info.tags };
+            if !clear && tags.is_empty() && remove.is_empty() {
+                println!("{}", format_tags(&current));
+                return Ok(());
+            }
+            let removed: Vec<String> = remove
+                .iter()
+                .map(|t| claude_commander_core::session::normalize_tag(t))
+                .collect();
+            current.retain(|t| !removed.contains(t));
+            current.extend(tags);
+            let current = claude_commander_core::session::normalize_tags(current);
+            service.set_tags(&info.session_id, current.clone()).await?;
+            println!("{}", format_tags(&current));
+        }
+
+        Some(Commands::Note {
+            session,
+            text,
+            clear,
+        }) => {
+            setup_logging(cli.debug, false)?;
+
+            let service =
+                claude_commander_core::api::CommanderService::for_cli(config, frontend())?;
+            let info = find_session_or_exit(&service, &session).await?;
+
+            match (text, clear) {
+                (Some(text), _) => {
+                    service.set_notes(&info.session_id, text).await?;
+                    println!("Notes updated for \"{}\"", info.title);
+                }
+                (None, true) => {
+                    service.set_notes(&info.session_id, "").await?;
+                    println!("Notes cleared for \"{}\"", info.title);
+                }
+                (None, false) if info.notes.is_empty() => {
+                    println!("(no notes)");
+                }
+                (None, false) => println!("{}", info.notes),
+            }
+        }
+
+        Some(Commands::Brief {
+            session,
+            lines,
+            json,
+        }) => {
+            setup_logging(cli.debug, false)?;
+
+            let service =
+                claude_commander_core::api::CommanderService::for_cli(config, frontend())?;
+            let brief = match service.session_brief(&session, lines).await? {
+                Some(b) => b,
+                None => {
+                    eprintln!("Session not found: {}", session);
+                    eprintln!("Use 'claude-commander list' to see available sessions.");
+                    std::process::exit(1);
+                }
+            };
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&brief)?);
+            } else {
+                println!("{}", brief.to_text());
+            }
+        }
+
         Some(Commands::Log { session, lines }) => {
             setup_logging(cli.debug, false)?;
 
@@ -479,6 +626,66 @@ async fn main() -> Result<()> {
             }
         }
 
+        Some(Commands::Grep {
+            pattern,
+            regex,
+            limit,
+            json,
+            remote,
+        }) => {
+            setup_logging(cli.debug, false)?;
+
+            let backend = resolve_cli_backend(config, remote.as_deref())?;
+            let search = claude_commander_core::scrollback::ScrollbackSearch {
+                pattern,
+                regex,
+                limit,
+            };
+            let hits = match backend.search_scrollback(search).await {
+                Ok(hits) => hits,
+                Err(claude_commander_core::backend::BackendError::InvalidRequest(msg)) => {
+                    clap::Error::raw(clap::error::ErrorKind::InvalidValue, format!("{msg}\n"))
+                        .exit();
+                }
+                Err(e) => return Err(e.into()),
+            };
+            let titles: std::collections::HashMap<_, _> = backend
+                .workspace_snapshot()
+                .await?
+                .sessions
+                .into_iter()
+                .map(|s| (s.session_id, s.title))
+                .collect();
+            let title_of = |hit: &claude_commander_core::scrollback::ScrollbackHit| {
+                titles
+                    .get(&hit.session_id)
+                    .cloned()
+                    .unwrap_or_else(|| hit.session_id.to_string())
+            };
+
+            if json {
+                let entries: Vec<_> = hits
+                    .iter()
+                    .map(|hit| {
+                        serde_json::json!({
+                            "session": title_of(hit),
+                            "session_id": hit.session_id,
+                            "line": hit.line,
+                            "text": hit.text,
+                        })
+                    })
+                    .collect();
+                println!("{}", serde_json::to_string_pretty(&entries)?);
+            } else {
+                for hit in &hits {
+                    println!("{}:{}: {}", title_of(hit), hit.line, hit.text);
+                }
+            }
+            if hits.is_empty() {
+                std::process::exit(1);
+            }
+        }
+
         Some(Commands::New {
             name,
             program,
@@ -613,18 +820,42 @@ async fn main() -> Result<()> {
             }
         }
 
-        Some(Commands::ListenToggle { start, stop }) => {
+        Some(Commands::ListenToggle {
+            start,
+            stop,
+            interrupt,
+            agent,
+        }) => {
             setup_logging(cli.debug, false)?;
 
+            // Catch a mistyped agent here rather than in the TUI, where it
+            // would only be logged.
+            let conv = &config.conversation;
+            if let Some(name) = &agent
+                && !name.eq_ignore_ascii_case(&conv.name)
+                && conv.profile(name).is_none()
+            {
+                let names: Vec<&str> = std::iter::once(conv.name.as_str())
+                    .chain(conv.profiles.iter().map(|p| p.name.as_str()))
+                    .collect();
+                eprintln!(
+                    "No conversation agent named {name:?} (agents: {})",
+                    names.join(", ")
+                );
+                std::process::exit(1);
+            }
+
             use claude_commander_core::conversation::{ListenAction, ipc};
             let action = if start {
                 ListenAction::Start
             } else if stop {
                 ListenAction::Stop
+            } else if interrupt {
+                ListenAction::Interrupt
             } else {
                 ListenAction::Toggle
             };
-            match ipc::send_default(action).await {
+            match ipc::send_default(action, agent.as_deref()).await {
                 Ok(reply) => println!("{reply}"),
                 Err(e) => {
                     eprintln!(
@@ -653,6 +884,43 @@ async fn main() -> Result<()> {
                 println!("State file: {:?}", Config::state_file_path()?);
             }
         }
+
+        Some(Commands::Trust { path, revoke }) => {
+            setup_logging(cli.debug, false)?;
+
+            let path = match path {
+                Some(p) => p,
+                None => std::env::current_dir()?,
+            };
+            let file = ProjectFile::resolve_path(&path);
+            let contents = match std::fs::read_to_string(&file) {
+                Ok(c) => c,
+                Err(e) => {
+                    eprintln!("Cannot read {}: {e}", file.display());
+                    std::process::exit(1);
+                }
+            };
+            let digest = ProjectFile::digest(&contents);
+
+            // Load afresh rather than reuse `config`: that one falls back to
+            // defaults on a load error, and saving it would clobber the file.
+            let mut config = Config::load()?;
+            if revoke {
+                config.trusted_project_files.retain(|d| *d != digest);
+                config.save()?;
+                println!("No longer trusting {}", file.display());
+            } else {
+                println!("{}:\n\n{}\n", file.display(), contents.trim_end());
+                if !config.trusted_project_files.contains(&digest) {
+                    config.trusted_project_files.push(digest);
+                }
+                config.save()?;
+                println!(
+                    "Trusted {}. Its hooks and tasks will run until the file changes.",
+                    file.display()
+                );
+            }
+        }
     }
 
     Ok(())
@@ -57,8 +57,13 @@
               # crates/, or README/docs/CLAUDE.md invalidate the build too.
               isCrateMarkdown =
                 pkgs.lib.hasPrefix "crates/" rel && pkgs.lib.hasSuffix ".md" rel;
+              # The server embeds its browser UI (and the vendored xterm.js)
+              # from web/ with include_str!/include_bytes!, so that tree must
+              # survive too.
+              isServerWebAsset = pkgs.lib.hasPrefix "crates/claude-commander-server/web/" rel;
             in
-            !prunedTopDir && (isCrateMarkdown || craneLib.filterCargoSources path type);
+            !prunedTopDir
+            && (isCrateMarkdown || isServerWebAsset || craneLib.filterCargoSources path type);
           name = "source";
         };
 
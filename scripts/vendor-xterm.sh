@@ -0,0 +1,42 @@
+#!/usr/bin/env bash
+#
+# Refresh the xterm.js files the browser UI embeds. The server compiles them in
+# with include_bytes! and serves them same-origin, so the page loads no script
+# from a CDN; bump the pins below and re-run this to upgrade, then commit the
+# result.
+#
+#   scripts/vendor-xterm.sh
+#
+# `npm pack` checks each tarball against the registry's published integrity
+# hash before we unpack it.
+
+set -euo pipefail
+
+XTERM_VERSION="5.5.0"
+ADDON_FIT_VERSION="0.10.0"
+
+REPO_ROOT="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+VENDOR="${REPO_ROOT}/crates/claude-commander-server/web/vendor"
+
+WORK="$(mktemp -d)"
+trap 'rm -rf "${WORK}"' EXIT
+
+# unpack NAME VERSION DIR: fetch NAME@VERSION from npm and extract it into DIR.
+unpack() {
+    local tarball
+    tarball="$(cd "${WORK}" && npm pack --silent "$1@$2")"
+    mkdir -p "$3"
+    tar -xzf "${WORK}/${tarball}" -C "$3" --strip-components=1
+}
+
+unpack @xterm/xterm "${XTERM_VERSION}" "${WORK}/xterm"
+unpack @xterm/addon-fit "${ADDON_FIT_VERSION}" "${WORK}/addon-fit"
+
+mkdir -p "${VENDOR}"
+# The trailing sourceMappingURL would point devtools at a .map we don't serve.
+sed '/^\/\/# sourceMappingURL=/d' "${WORK}/xterm/lib/xterm.js" > "${VENDOR}/xterm.js"
+sed '/^\/\/# sourceMappingURL=/d' "${WORK}/addon-fit/lib/addon-fit.js" > "${VENDOR}/addon-fit.js"
+cp "${WORK}/xterm/css/xterm.css" "${VENDOR}/xterm.css"
+cp "${WORK}/xterm/LICENSE" "${VENDOR}/LICENSE"
+
+echo "vendored @xterm/xterm@${XTERM_VERSION} and @xterm/addon-fit@${ADDON_FIT_VERSION} into ${VENDOR#"${REPO_ROOT}"/}"